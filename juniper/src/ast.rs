@@ -1,6 +1,6 @@
 use std::{borrow::Cow, fmt, hash::Hash, slice, vec};
 
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 
 use crate::{
     executor::Variables,
@@ -34,7 +34,19 @@ pub enum Type<'a> {
 ///
 /// Lists and objects variants are _spanned_, i.e. they contain a reference to
 /// their position in the source file, if available.
-#[derive(Clone, Debug, PartialEq)]
+///
+/// # Equality
+///
+/// As [input objects are unordered][0] in GraphQL, two objects are equal if
+/// they have the same fields with equal values, regardless of the order the
+/// fields are listed in. Lists remain order-sensitive. Source positions are
+/// compared as well, so use [`InputValue::unlocated_eq()`] to ignore them.
+///
+/// To get a stable representation of an object (for serialization, for
+/// example), sort its fields with [`InputValue::canonicalize()`].
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Input-Object-Values
+#[derive(Clone, Debug)]
 #[allow(missing_docs)]
 pub enum InputValue<S = DefaultScalarValue> {
     Null,
@@ -45,6 +57,7 @@ pub enum InputValue<S = DefaultScalarValue> {
     Object(Vec<(Spanning<String>, Spanning<InputValue<S>>)>),
 }
 
+#[allow(missing_docs)]
 #[derive(Clone, PartialEq, Debug)]
 pub struct VariableDefinition<'a, S> {
     pub var_type: Spanning<Type<'a>>,
@@ -52,35 +65,50 @@ pub struct VariableDefinition<'a, S> {
     pub directives: Option<Vec<Spanning<Directive<'a, S>>>>,
 }
 
+#[allow(missing_docs)]
 #[derive(Clone, PartialEq, Debug)]
 pub struct Arguments<'a, S> {
     pub items: Vec<(Spanning<&'a str>, Spanning<InputValue<S>>)>,
 }
 
+#[allow(missing_docs)]
 #[derive(Clone, PartialEq, Debug)]
 pub struct VariableDefinitions<'a, S> {
     pub items: Vec<(Spanning<&'a str>, VariableDefinition<'a, S>)>,
 }
 
+/// Field requested in a selection set, e.g. `alias: field(arg: 1) { subField }`.
 #[derive(Clone, PartialEq, Debug)]
 pub struct Field<'a, S> {
+    /// Alias of this field in the response, if any.
     pub alias: Option<Spanning<&'a str>>,
+    /// Name of this field in its parent type.
     pub name: Spanning<&'a str>,
+    /// Arguments provided to this field, if any.
     pub arguments: Option<Spanning<Arguments<'a, S>>>,
+    /// Directives applied to this field, if any.
     pub directives: Option<Vec<Spanning<Directive<'a, S>>>>,
+    /// Selection set of this field, if it has sub-fields.
     pub selection_set: Option<Vec<Selection<'a, S>>>,
 }
 
+/// Spread of a named [`Fragment`] in a selection set, e.g. `...fragmentName`.
 #[derive(Clone, PartialEq, Debug)]
 pub struct FragmentSpread<'a, S> {
+    /// Name of the spread [`Fragment`].
     pub name: Spanning<&'a str>,
+    /// Directives applied to this spread, if any.
     pub directives: Option<Vec<Spanning<Directive<'a, S>>>>,
 }
 
+/// Fragment defined inline in a selection set, e.g. `... on User { name }`.
 #[derive(Clone, PartialEq, Debug)]
 pub struct InlineFragment<'a, S> {
+    /// Name of the type this fragment applies to, if restricted.
     pub type_condition: Option<Spanning<&'a str>>,
+    /// Directives applied to this fragment, if any.
     pub directives: Option<Vec<Spanning<Directive<'a, S>>>>,
+    /// Selection set of this fragment.
     pub selection_set: Vec<Selection<'a, S>>,
 }
 
@@ -107,6 +135,7 @@ pub enum Selection<'a, S = DefaultScalarValue> {
     InlineFragment(Spanning<InlineFragment<'a, S>>),
 }
 
+#[allow(missing_docs)]
 #[derive(Clone, PartialEq, Debug)]
 pub struct Directive<'a, S> {
     pub name: Spanning<&'a str>,
@@ -114,41 +143,66 @@ pub struct Directive<'a, S> {
 }
 
 #[allow(missing_docs)]
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub enum OperationType {
     Query,
     Mutation,
     Subscription,
 }
 
-#[allow(missing_docs)]
+/// Operation definition, e.g. `query Name($var: Int) { field }`.
 #[derive(Clone, PartialEq, Debug)]
 pub struct Operation<'a, S> {
+    /// Type of this operation.
+    ///
+    /// The shorthand `{ field }` syntax defines a [`OperationType::Query`].
     pub operation_type: OperationType,
+    /// Name of this operation, if it's not anonymous.
     pub name: Option<Spanning<&'a str>>,
+    /// Variables defined by this operation, if any.
     pub variable_definitions: Option<Spanning<VariableDefinitions<'a, S>>>,
+    /// Directives applied to this operation, if any.
     pub directives: Option<Vec<Spanning<Directive<'a, S>>>>,
+    /// Selection set of this operation.
     pub selection_set: Vec<Selection<'a, S>>,
 }
 
+/// Named fragment definition, e.g. `fragment Name on User { name }`.
 #[derive(Clone, PartialEq, Debug)]
 pub struct Fragment<'a, S> {
+    /// Name of this fragment.
     pub name: Spanning<&'a str>,
+    /// Name of the type this fragment applies to.
     pub type_condition: Spanning<&'a str>,
+    /// Directives applied to this fragment, if any.
     pub directives: Option<Vec<Spanning<Directive<'a, S>>>>,
+    /// Selection set of this fragment.
     pub selection_set: Vec<Selection<'a, S>>,
 }
 
-#[doc(hidden)]
+/// Top-level definition of a GraphQL [`Document`]: either an operation or a
+/// named fragment.
 #[derive(Clone, PartialEq, Debug)]
 pub enum Definition<'a, S> {
+    /// [`Operation`] definition.
     Operation(Spanning<Operation<'a, S>>),
+    /// Named [`Fragment`] definition.
     Fragment(Spanning<Fragment<'a, S>>),
 }
 
-#[doc(hidden)]
+/// Parsed GraphQL document: a list of [`Definition`]s.
+///
+/// All the nodes of a document are [`Spanning`], i.e. they reference the
+/// location in the source they were parsed from. Names and other string slices
+/// borrow the source directly.
+///
+/// Parse it with [`parse_document_source_without_schema()`] to analyze a query
+/// without executing it.
+///
+/// [`parse_document_source_without_schema()`]: crate::parser::parse_document_source_without_schema
 pub type Document<'a, S> = [Definition<'a, S>];
-#[doc(hidden)]
+
+/// Owned [`Document`], as returned by the parser.
 pub type OwnedDocument<'a, S> = Vec<Definition<'a, S>>;
 
 /// Parsing of an unstructured input value into a Rust data type.
@@ -186,6 +240,25 @@ pub trait FromInputValue<S = DefaultScalarValue>: Sized {
     fn from_implicit_null() -> Result<Self, Self::Error> {
         Self::from_input_value(&InputValue::<S>::Null)
     }
+
+    /// Indicates whether this type wants to handle `null`s by itself, when
+    /// being wrapped into an [`Option`].
+    ///
+    /// If `true`, then an explicit `null` is passed to the
+    /// [`from_input_value()`] and an implicit one to the
+    /// [`from_implicit_null()`] of this type, instead of being converted into
+    /// [`None`] right away. This allows to compute a default value for an
+    /// omitted nullable argument or input object field.
+    ///
+    /// The default implementation returns `false`, so the
+    /// [`from_input_value()`] is never invoked with a `null` for a nullable
+    /// value.
+    ///
+    /// [`from_implicit_null()`]: FromInputValue::from_implicit_null
+    /// [`from_input_value()`]: FromInputValue::from_input_value
+    fn accepts_null() -> bool {
+        false
+    }
 }
 
 /// Losslessly clones a Rust data type into an InputValue.
@@ -444,10 +517,13 @@ impl<S> InputValue<S> {
             (Self::Null, Self::Null) => true,
             (Self::Scalar(s1), Self::Scalar(s2)) => s1 == s2,
             (Self::Enum(s1), Self::Enum(s2)) | (Self::Variable(s1), Self::Variable(s2)) => s1 == s2,
-            (Self::List(l1), Self::List(l2)) => l1
-                .iter()
-                .zip(l2.iter())
-                .all(|(v1, v2)| v1.item.unlocated_eq(&v2.item)),
+            (Self::List(l1), Self::List(l2)) => {
+                l1.len() == l2.len()
+                    && l1
+                        .iter()
+                        .zip(l2.iter())
+                        .all(|(v1, v2)| v1.item.unlocated_eq(&v2.item))
+            }
             (Self::Object(o1), Self::Object(o2)) => {
                 o1.len() == o2.len()
                     && o1.iter().all(|(sk1, sv1)| {
@@ -459,6 +535,57 @@ impl<S> InputValue<S> {
             _ => false,
         }
     }
+
+    /// Sorts fields of all the objects in this [`InputValue`] (including the
+    /// nested ones) by their names, so equal values (see the [`PartialEq`]
+    /// implementation) have the same representation.
+    ///
+    /// Fields with the same name (invalid in GraphQL) keep their relative
+    /// order. Lists are not reordered.
+    ///
+    /// ```
+    /// # use juniper::{graphql_input_value, InputValue};
+    /// #
+    /// let value: InputValue = graphql_input_value!({"b": [{"d": 1, "c": 2}], "a": null});
+    /// assert_eq!(value.canonicalize().to_string(), "{a: null, b: [{c: 2, d: 1}]}");
+    /// ```
+    #[must_use]
+    pub fn canonicalize(self) -> Self {
+        match self {
+            Self::List(l) => Self::List(l.into_iter().map(|v| v.map(Self::canonicalize)).collect()),
+            Self::Object(o) => {
+                let mut o = o
+                    .into_iter()
+                    .map(|(k, v)| (k, v.map(Self::canonicalize)))
+                    .collect::<Vec<_>>();
+                o.sort_by(|(k1, _), (k2, _)| k1.item.cmp(&k2.item));
+                Self::Object(o)
+            }
+            v => v,
+        }
+    }
+}
+
+impl<S: PartialEq> PartialEq for InputValue<S> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Null, Self::Null) => true,
+            (Self::Scalar(s1), Self::Scalar(s2)) => s1 == s2,
+            (Self::Enum(s1), Self::Enum(s2)) | (Self::Variable(s1), Self::Variable(s2)) => s1 == s2,
+            (Self::List(l1), Self::List(l2)) => l1 == l2,
+            (Self::Object(o1), Self::Object(o2)) => {
+                // Fields are looked up in both directions, so objects with
+                // duplicated fields are compared consistently.
+                let contains = |o: &[(Spanning<String>, Spanning<Self>)], (k, v): &(_, _)| {
+                    o.iter().any(|(k2, v2)| k == k2 && v == v2)
+                };
+                o1.len() == o2.len()
+                    && o1.iter().all(|f| contains(o2, f))
+                    && o2.iter().all(|f| contains(o1, f))
+            }
+            _ => false,
+        }
+    }
 }
 
 impl<S: ScalarValue> fmt::Display for InputValue<S> {
@@ -511,6 +638,15 @@ where
     }
 }
 
+impl<S, T> From<Vec<T>> for InputValue<S>
+where
+    Self: From<T>,
+{
+    fn from(v: Vec<T>) -> Self {
+        Self::list(v.into_iter().map(Into::into).collect())
+    }
+}
+
 impl<'a, S: From<String>> From<&'a str> for InputValue<S> {
     fn from(s: &'a str) -> Self {
         Self::scalar(s.to_owned())
@@ -574,16 +710,102 @@ impl<'a, S> Arguments<'a, S> {
 }
 
 impl<'a, S> VariableDefinitions<'a, S> {
+    /// Iterates over the defined variables along with their names.
     pub fn iter(&self) -> slice::Iter<(Spanning<&'a str>, VariableDefinition<S>)> {
         self.items.iter()
     }
 }
 
+impl<'a, S> Operation<'a, S> {
+    /// Returns names of all the variables referenced by this [`Operation`],
+    /// including the ones referenced by the [`Fragment`]s of the provided
+    /// `document` it spreads (transitively).
+    ///
+    /// Variables are looked up in arguments of fields and directives, at any
+    /// depth of lists and input objects. Every name is returned only once, in
+    /// order of its first reference.
+    pub fn referenced_variables<'d>(&'d self, document: &'d Document<'a, S>) -> Vec<&'d str> {
+        let mut collector = VariablesCollector {
+            document,
+            fragments: Vec::new(),
+            names: IndexSet::new(),
+        };
+        collector.directives(&self.directives);
+        collector.selection_set(&self.selection_set);
+        collector.names.into_iter().collect()
+    }
+}
+
+/// Walker over an [`Operation`] collecting the variables it references.
+struct VariablesCollector<'d, 'a, S> {
+    document: &'d Document<'a, S>,
+
+    /// Names of the [`Fragment`]s already walked through.
+    fragments: Vec<&'a str>,
+
+    /// Names of the collected variables.
+    names: IndexSet<&'d str>,
+}
+
+impl<'d, 'a, S> VariablesCollector<'d, 'a, S> {
+    fn selection_set(&mut self, set: &'d [Selection<'a, S>]) {
+        for selection in set {
+            match selection {
+                Selection::Field(f) => {
+                    self.arguments(&f.item.arguments);
+                    self.directives(&f.item.directives);
+                    if let Some(set) = &f.item.selection_set {
+                        self.selection_set(set);
+                    }
+                }
+                Selection::FragmentSpread(s) => {
+                    self.directives(&s.item.directives);
+
+                    let name = s.item.name.item;
+                    if self.fragments.contains(&name) {
+                        continue;
+                    }
+                    self.fragments.push(name);
+
+                    let fragment = self.document.iter().find_map(|def| match def {
+                        Definition::Fragment(f) if f.item.name.item == name => Some(&f.item),
+                        _ => None,
+                    });
+                    if let Some(fragment) = fragment {
+                        self.directives(&fragment.directives);
+                        self.selection_set(&fragment.selection_set);
+                    }
+                }
+                Selection::InlineFragment(f) => {
+                    self.directives(&f.item.directives);
+                    self.selection_set(&f.item.selection_set);
+                }
+            }
+        }
+    }
+
+    fn directives(&mut self, directives: &'d Option<Vec<Spanning<Directive<'a, S>>>>) {
+        for directive in directives.iter().flatten() {
+            self.arguments(&directive.item.arguments);
+        }
+    }
+
+    fn arguments(&mut self, arguments: &'d Option<Spanning<Arguments<'a, S>>>) {
+        for (_, value) in arguments.iter().flat_map(|args| args.item.iter()) {
+            self.names.extend(value.item.referenced_variables());
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::graphql_input_value;
+    use crate::{
+        graphql_input_value,
+        parser::{parse_document_source_without_schema, ParseOptions},
+        DefaultScalarValue,
+    };
 
-    use super::InputValue;
+    use super::{Definition, InputValue};
 
     #[test]
     fn test_input_value_fmt() {
@@ -614,4 +836,99 @@ mod tests {
         let value: InputValue = graphql_input_value!({"foo": 1,"bar": 2});
         assert_eq!(value.to_string(), "{foo: 1, bar: 2}");
     }
+
+    #[test]
+    fn test_input_value_eq_ignores_object_field_order() {
+        let value: InputValue = graphql_input_value!({"foo": 1, "bar": {"a": [1, 2], "b": null}});
+
+        assert_eq!(
+            value,
+            graphql_input_value!({"bar": {"b": null, "a": [1, 2]}, "foo": 1}),
+        );
+        assert_ne!(
+            value,
+            graphql_input_value!({"bar": {"b": null, "a": [2, 1]}, "foo": 1}),
+        );
+        assert_ne!(value, graphql_input_value!({"foo": 1}));
+        assert_ne!(
+            value,
+            graphql_input_value!({"foo": 1, "bar": {"a": [1, 2], "b": null}, "baz": 3}),
+        );
+
+        let dup: InputValue = graphql_input_value!({"a": 1, "a": 1});
+        assert_ne!(dup, graphql_input_value!({"a": 1, "a": 2}));
+        assert_ne!(graphql_input_value!({"a": 1, "a": 2}), dup);
+    }
+
+    #[test]
+    fn test_input_value_unlocated_eq_compares_list_lengths() {
+        let value: InputValue = graphql_input_value!([1, 2]);
+
+        assert!(value.unlocated_eq(&graphql_input_value!([1, 2])));
+        assert!(!value.unlocated_eq(&graphql_input_value!([1])));
+        assert!(!value.unlocated_eq(&graphql_input_value!([1, 2, 3])));
+    }
+
+    #[test]
+    fn test_input_value_canonicalize() {
+        let value: InputValue = graphql_input_value!({
+            "foo": [{"d": 1, "c": 2}, {"e": 3}],
+            "bar": {"z": BAZ, "y": @var},
+        });
+        let canonical = value.clone().canonicalize();
+
+        assert_eq!(
+            canonical.to_string(),
+            "{bar: {y: $var, z: BAZ}, foo: [{c: 2, d: 1}, {e: 3}]}",
+        );
+        assert_eq!(canonical, value);
+        assert_eq!(
+            canonical.clone().canonicalize().to_string(),
+            canonical.to_string()
+        );
+    }
+
+    #[test]
+    fn test_operation_referenced_variables() {
+        let doc = parse_document_source_without_schema::<DefaultScalarValue>(
+            r#"
+            query Q($a: Int, $b: [Int], $c: Boolean!, $d: Int, $e: Boolean!, $f: Int)
+                @defer(if: $e)
+            {
+                field(arg: $a, list: [1, $b, [$a]]) @include(if: $c) {
+                    nested(obj: {x: {y: $d}, z: $a})
+                    ...Frag @skip(if: $c)
+                }
+                ... @include(if: $e) {
+                    other(arg: $f)
+                }
+            }
+
+            fragment Frag on Type {
+                deep(arg: $f)
+                ...Cycle
+            }
+
+            fragment Cycle on Type {
+                deeper(arg: $g)
+                ...Frag
+            }
+
+            fragment Unused on Type {
+                field(arg: $h)
+            }
+            "#,
+            ParseOptions::default(),
+        )
+        .unwrap();
+        let op = match &doc[0] {
+            Definition::Operation(op) => &op.item,
+            _ => unreachable!(),
+        };
+
+        assert_eq!(
+            op.referenced_variables(&doc),
+            ["e", "a", "b", "c", "d", "f", "g"],
+        );
+    }
 }
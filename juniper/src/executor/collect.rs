@@ -0,0 +1,280 @@
+//! Lazy collecting of [`Stream`]s into lists, limited by the current selection.
+
+use futures::{Stream, StreamExt as _, TryStreamExt as _};
+
+use crate::{
+    Executor, FieldError, FieldResult, IntoFieldError, LookAheadMethods as _, ScalarValue,
+};
+
+/// Name of the field argument limiting the number of collected items.
+const FIRST_ARGUMENT: &str = "first";
+
+/// Collects the provided [`Stream`] of [`Result`]s into a [`Vec`], pulling no
+/// more items from it than requested by the `first` argument of the field
+/// currently resolved by the provided [`Executor`].
+///
+/// The `first` argument is looked up via [`Executor::look_ahead()`], so its
+/// default value and variables are respected. If it's absent or `null`, the
+/// whole [`Stream`] is collected. Once the requested number of items has been
+/// collected, the [`Stream`] is dropped without being polled anymore, so the
+/// expensive resolution of the rest items is never even started.
+///
+/// Collecting stops on the first [`Err`] item too, which is returned as the
+/// [`FieldError`] of the resolved field.
+///
+/// # Errors
+///
+/// - If the `first` argument is not an `Int`, or is a negative one.
+/// - If the [`Stream`] yields an [`Err`] before the requested number of items
+///   has been collected.
+///
+/// # Example
+///
+/// ```rust
+/// # use futures::stream::{self, StreamExt as _};
+/// # use juniper::{
+/// #     collect_for_selection, graphql_object, graphql_value, graphql_vars, DefaultScalarValue,
+/// #     EmptyMutation, EmptySubscription, Executor, FieldResult, RootNode,
+/// # };
+/// #
+/// struct Query;
+///
+/// #[graphql_object(scalar = DefaultScalarValue)]
+/// impl Query {
+///     async fn squares(
+///         first: Option<i32>,
+///         executor: &Executor<'_, '_, ()>,
+///     ) -> FieldResult<Vec<i32>> {
+///         // The `first` argument is only declared here, as it's read by
+///         // `collect_for_selection()` via look-ahead.
+///         let _ = first;
+///         // Imagine every item being expensive to resolve.
+///         let items = stream::iter(1..).then(|i| async move { Ok::<_, String>(i * i) });
+///         collect_for_selection(items, executor).await
+///     }
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+///
+/// assert_eq!(
+///     juniper::execute("{ squares(first: 3) }", None, &schema, &graphql_vars! {}, &()).await,
+///     Ok((graphql_value!({"squares": [1, 4, 9]}), vec![])),
+/// );
+/// # }
+/// ```
+pub async fn collect_for_selection<St, T, E, CtxT, S>(
+    stream: St,
+    executor: &Executor<'_, '_, CtxT, S>,
+) -> FieldResult<Vec<T>, S>
+where
+    St: Stream<Item = Result<T, E>>,
+    E: IntoFieldError<S>,
+    S: ScalarValue,
+{
+    let stream = stream.map_err(IntoFieldError::into_field_error);
+    match requested_count(executor)? {
+        Some(count) => stream.take(count).try_collect().await,
+        None => stream.try_collect().await,
+    }
+}
+
+/// Returns the number of items requested by the `first` argument of the field
+/// currently resolved by the provided [`Executor`], if any.
+fn requested_count<CtxT, S: ScalarValue>(
+    executor: &Executor<'_, '_, CtxT, S>,
+) -> FieldResult<Option<usize>, S> {
+    use crate::LookAheadValue as Value;
+
+    let selection = executor.look_ahead();
+    match selection.argument(FIRST_ARGUMENT).map(|a| a.value()) {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::Scalar(s)) => s
+            .as_int()
+            .and_then(|i| usize::try_from(i).ok())
+            .map(Some)
+            .ok_or_else(|| {
+                FieldError::from(format!(
+                    "Expected non-negative `Int` as `{FIRST_ARGUMENT}` argument, found: {s}",
+                ))
+            }),
+        Some(v) => Err(FieldError::from(format!(
+            "Expected non-negative `Int` as `{FIRST_ARGUMENT}` argument, found: {v:?}",
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use futures::stream::{self, StreamExt as _};
+
+    use crate::{
+        execute, graphql_object, graphql_value, graphql_vars, DefaultScalarValue, EmptyMutation,
+        EmptySubscription, Executor, FieldResult, RootNode,
+    };
+
+    use super::collect_for_selection;
+
+    #[derive(Default)]
+    struct Context {
+        resolved: AtomicUsize,
+    }
+
+    impl crate::Context for Context {}
+
+    struct Query;
+
+    #[graphql_object(context = Context, scalar = DefaultScalarValue)]
+    impl Query {
+        async fn numbers(
+            first: Option<i32>,
+            executor: &Executor<'_, '_, Context>,
+        ) -> FieldResult<Vec<i32>> {
+            let _ = first;
+            let resolved = &executor.context().resolved;
+            let items = stream::iter(1..=5).then(|i| async move {
+                resolved.fetch_add(1, Ordering::SeqCst);
+                if i == 4 {
+                    Err("Failed to resolve 4")
+                } else {
+                    Ok(i)
+                }
+            });
+            collect_for_selection(items, executor).await
+        }
+
+        async fn defaulted(
+            #[graphql(default = 2)] first: i32,
+            executor: &Executor<'_, '_, Context>,
+        ) -> FieldResult<Vec<i32>> {
+            let _ = first;
+            collect_for_selection(stream::iter(1..).map(Ok::<_, String>), executor).await
+        }
+    }
+
+    fn schema() -> RootNode<'static, Query, EmptyMutation<Context>, EmptySubscription<Context>> {
+        RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+    }
+
+    #[tokio::test]
+    async fn stops_after_requested_count() {
+        let ctx = Context::default();
+
+        assert_eq!(
+            execute(
+                "{ numbers(first: 2) }",
+                None,
+                &schema(),
+                &graphql_vars! {},
+                &ctx,
+            )
+            .await,
+            Ok((graphql_value!({"numbers": [1, 2]}), vec![])),
+        );
+        assert_eq!(ctx.resolved.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn respects_variables() {
+        let ctx = Context::default();
+
+        assert_eq!(
+            execute(
+                "query ($first: Int) { numbers(first: $first) }",
+                None,
+                &schema(),
+                &graphql_vars! {"first": 3},
+                &ctx,
+            )
+            .await,
+            Ok((graphql_value!({"numbers": [1, 2, 3]}), vec![])),
+        );
+        assert_eq!(ctx.resolved.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn respects_default_value() {
+        assert_eq!(
+            execute(
+                "{ defaulted }",
+                None,
+                &schema(),
+                &graphql_vars! {},
+                &Context::default(),
+            )
+            .await,
+            Ok((graphql_value!({"defaulted": [1, 2]}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn zero_polls_nothing() {
+        let ctx = Context::default();
+
+        assert_eq!(
+            execute(
+                "{ numbers(first: 0) }",
+                None,
+                &schema(),
+                &graphql_vars! {},
+                &ctx,
+            )
+            .await,
+            Ok((graphql_value!({"numbers": []}), vec![])),
+        );
+        assert_eq!(ctx.resolved.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn stops_on_error() {
+        let ctx = Context::default();
+
+        let (res, errs) = execute("{ numbers }", None, &schema(), &graphql_vars! {}, &ctx)
+            .await
+            .unwrap();
+
+        assert_eq!(res, graphql_value!(null));
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].error().message(), "Failed to resolve 4");
+        assert_eq!(ctx.resolved.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn ignores_error_after_requested_count() {
+        let ctx = Context::default();
+
+        assert_eq!(
+            execute(
+                "{ numbers(first: 3) }",
+                None,
+                &schema(),
+                &graphql_vars! {},
+                &ctx,
+            )
+            .await,
+            Ok((graphql_value!({"numbers": [1, 2, 3]}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_negative_count() {
+        let (res, errs) = execute(
+            "{ numbers(first: -1) }",
+            None,
+            &schema(),
+            &graphql_vars! {},
+            &Context::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(res, graphql_value!(null));
+        assert_eq!(
+            errs[0].error().message(),
+            "Expected non-negative `Int` as `first` argument, found: -1",
+        );
+    }
+}
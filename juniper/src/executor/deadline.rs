@@ -0,0 +1,175 @@
+//! Cooperative cancellation of an asynchronous execution once its deadline is
+//! exceeded.
+
+use std::{
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    task::{Context, Poll, Waker},
+};
+
+use futures::future::{self, Either};
+
+use crate::{ExecutionResult, FieldError, ScalarValue};
+
+/// Code put into the `extensions` of a [`FieldError`] of a field, which hasn't
+/// been resolved before the execution deadline was exceeded.
+const TIMEOUT_ERROR_CODE: &str = "TIMEOUT";
+
+/// Deadline of an asynchronous execution, shared by all its [`Executor`]s.
+///
+/// [`Executor`]: super::Executor
+#[derive(Debug, Default)]
+pub(crate) struct Deadline {
+    expired: AtomicBool,
+    wakers: Mutex<Wakers>,
+}
+
+/// [`Waker`]s of the fields awaiting a [`Deadline`].
+#[derive(Debug, Default)]
+struct Wakers {
+    /// ID to register the next awaiting field with.
+    next_id: usize,
+
+    /// [`Waker`]s of the currently awaiting fields, keyed by their IDs.
+    ///
+    /// A field is removed once it stops awaiting, so only the pending fields
+    /// are kept here.
+    registered: HashMap<usize, Waker>,
+}
+
+impl Deadline {
+    /// Marks this [`Deadline`] as exceeded, waking all the fields awaiting it.
+    fn expire(&self) {
+        self.expired.store(true, Ordering::Release);
+        for (_, waker) in self.wakers.lock().unwrap().registered.drain() {
+            waker.wake();
+        }
+    }
+
+    /// Resolves the provided field `resolver`, unless this [`Deadline`] is
+    /// exceeded before it's done, in which case the `resolver` is dropped and
+    /// a [`TIMEOUT_ERROR_CODE`] error is returned instead.
+    ///
+    /// The `resolver` is always polled first, so the fields nested into it
+    /// return their (partial) results before this one times out.
+    pub(crate) async fn race<S: ScalarValue>(
+        &self,
+        resolver: impl Future<Output = ExecutionResult<S>>,
+    ) -> ExecutionResult<S> {
+        futures::pin_mut!(resolver);
+        let expired = Expired {
+            deadline: self,
+            id: None,
+        };
+        match future::select(resolver, expired).await {
+            Either::Left((res, _)) => res,
+            Either::Right(((), _)) => Err(FieldError::builder()
+                .message("Execution deadline exceeded before the field was resolved")
+                .code(TIMEOUT_ERROR_CODE)
+                .build()),
+        }
+    }
+
+    /// Drives the provided `execution` until it completes, exceeding this
+    /// [`Deadline`] once the provided `timer` completes.
+    pub(crate) async fn drive<T>(
+        &self,
+        execution: impl Future<Output = T>,
+        timer: impl Future<Output = ()>,
+    ) -> T {
+        let timer = async {
+            timer.await;
+            self.expire();
+            future::pending::<()>().await
+        };
+        futures::pin_mut!(execution, timer);
+        match future::select(execution, timer).await {
+            Either::Left((res, _)) => res,
+            Either::Right(((), _)) => unreachable!("`future::pending()` never completes"),
+        }
+    }
+}
+
+/// [`Future`] completing once its [`Deadline`] is exceeded.
+struct Expired<'d> {
+    deadline: &'d Deadline,
+
+    /// ID of the [`Waker`] registered in the [`Deadline`], if any.
+    id: Option<usize>,
+}
+
+impl<'d> Future for Expired<'d> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let this = self.get_mut();
+        if this.deadline.expired.load(Ordering::Acquire) {
+            return Poll::Ready(());
+        }
+
+        {
+            let mut wakers = this.deadline.wakers.lock().unwrap();
+            let wakers = &mut *wakers;
+            match this.id {
+                Some(id) => {
+                    // Missing only if the deadline has been exceeded already.
+                    if let Some(waker) = wakers.registered.get_mut(&id) {
+                        if !waker.will_wake(cx.waker()) {
+                            *waker = cx.waker().clone();
+                        }
+                    }
+                }
+                None => {
+                    let id = wakers.next_id;
+                    wakers.next_id = id.wrapping_add(1);
+                    wakers.registered.insert(id, cx.waker().clone());
+                    this.id = Some(id);
+                }
+            }
+        }
+
+        // Re-check, as the deadline may be exceeded while registering.
+        if this.deadline.expired.load(Ordering::Acquire) {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<'d> Drop for Expired<'d> {
+    fn drop(&mut self) {
+        if let Some(id) = self.id {
+            self.deadline.wakers.lock().unwrap().registered.remove(&id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DefaultScalarValue, Value};
+
+    use super::Deadline;
+
+    #[tokio::test]
+    async fn unregisters_wakers_of_resolved_fields() {
+        let deadline = Deadline::default();
+
+        for _ in 0..3 {
+            let res = deadline
+                .race::<DefaultScalarValue>(async {
+                    tokio::task::yield_now().await;
+                    Ok(Value::null())
+                })
+                .await;
+            assert_eq!(res, Ok(Value::null()));
+        }
+
+        assert!(deadline.wakers.lock().unwrap().registered.is_empty());
+    }
+}
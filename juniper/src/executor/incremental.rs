@@ -0,0 +1,877 @@
+//! Resolving of [incremental delivery][0] payloads.
+//!
+//! [0]: https://github.com/graphql/graphql-spec/pull/742
+
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    future::Future,
+    mem,
+    sync::{Arc, Mutex, RwLock},
+    task::{Context, Poll, Waker},
+};
+
+use futures::future;
+
+use crate::{
+    ast::{Definition, Directive, Document, Field, Fragment, InputValue, Operation, Selection},
+    executor::{ExecutionError, PathSegment, Variables},
+    parser::Spanning,
+    value::{DefaultScalarValue, Object, ScalarValue, Value},
+    BoxFuture,
+};
+
+/// Single payload of an [incremental delivery][0] response, as yielded by
+/// [`execute_incremental()`].
+///
+/// [`execute_incremental()`]: crate::execute_incremental
+/// [0]: https://github.com/graphql/graphql-spec/pull/742
+#[derive(Debug, PartialEq)]
+pub enum IncrementalPayload<S = DefaultScalarValue> {
+    /// Initial payload, containing the response data with all the `@stream`ed
+    /// lists truncated to their `initialCount`, and all the fields of `@defer`ed
+    /// fragments omitted.
+    Initial {
+        /// Initially delivered response data.
+        data: Value<S>,
+
+        /// Errors occurred in the initially delivered response data.
+        errors: Vec<ExecutionError<S>>,
+
+        /// Indicator whether any subsequent payloads follow.
+        has_next: bool,
+    },
+
+    /// Subsequent payload, delivering items of a `@stream`ed list or fields of a
+    /// `@defer`ed fragment.
+    Subsequent {
+        /// Delivered results along with their location in the response.
+        incremental: Vec<IncrementalResult<S>>,
+
+        /// Indicator whether any subsequent payloads follow.
+        has_next: bool,
+    },
+}
+
+/// Result delivered in a [`IncrementalPayload::Subsequent`].
+#[derive(Debug, PartialEq)]
+pub enum IncrementalResult<S = DefaultScalarValue> {
+    /// Items of a `@stream`ed list.
+    Items(IncrementalItems<S>),
+
+    /// Fields of a `@defer`ed fragment.
+    Data(IncrementalData<S>),
+}
+
+impl<S> IncrementalResult<S> {
+    /// Returns the [response path][0] this result is delivered at.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Errors.Error-result-format
+    pub fn path(&self) -> &[PathSegment] {
+        match self {
+            Self::Items(items) => &items.path,
+            Self::Data(data) => &data.path,
+        }
+    }
+
+    /// Returns the `label` argument of the directive delivering this result.
+    pub fn label(&self) -> Option<&str> {
+        match self {
+            Self::Items(items) => items.label.as_deref(),
+            Self::Data(data) => data.label.as_deref(),
+        }
+    }
+
+    /// Returns errors occurred in this result.
+    pub fn errors(&self) -> &[ExecutionError<S>] {
+        match self {
+            Self::Items(items) => &items.errors,
+            Self::Data(data) => &data.errors,
+        }
+    }
+
+    /// Indicates whether the value at the provided [response path][0] is
+    /// delivered in this result.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Errors.Error-result-format
+    fn delivers(&self, path: &[PathSegment]) -> bool {
+        match self {
+            Self::Items(items) => path.starts_with(&items.path),
+            Self::Data(data) => {
+                path.starts_with(&data.path)
+                    && match (path.get(data.path.len()), &data.data) {
+                        (Some(PathSegment::Field(name)), Value::Object(obj)) => {
+                            obj.contains_field(name)
+                        }
+                        _ => false,
+                    }
+            }
+        }
+    }
+
+    /// Pushes the provided `error` into the errors of this result.
+    fn push_error(&mut self, error: ExecutionError<S>) {
+        match self {
+            Self::Items(items) => items.errors.push(error),
+            Self::Data(data) => data.errors.push(error),
+        }
+    }
+}
+
+/// Items of a `@stream`ed list, delivered in a
+/// [`IncrementalPayload::Subsequent`].
+#[derive(Debug, PartialEq)]
+pub struct IncrementalItems<S = DefaultScalarValue> {
+    /// Delivered list items.
+    pub items: Vec<Value<S>>,
+
+    /// [Response path][0] of the first delivered item.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Errors.Error-result-format
+    pub path: Vec<PathSegment>,
+
+    /// `label` argument of the `@stream` directive delivering these items.
+    pub label: Option<String>,
+
+    /// Errors occurred in the delivered items.
+    pub errors: Vec<ExecutionError<S>>,
+}
+
+/// Fields of a `@defer`ed fragment, delivered in a
+/// [`IncrementalPayload::Subsequent`].
+#[derive(Debug, PartialEq)]
+pub struct IncrementalData<S = DefaultScalarValue> {
+    /// Delivered fields, to be merged into the object at the `path`.
+    pub data: Value<S>,
+
+    /// [Response path][0] of the object the delivered fields belong to.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Errors.Error-result-format
+    pub path: Vec<PathSegment>,
+
+    /// `label` argument of the `@defer` directive delivering these fields.
+    pub label: Option<String>,
+
+    /// Errors occurred in the delivered fields.
+    pub errors: Vec<ExecutionError<S>>,
+}
+
+/// Incremental delivery being resolved, queueing its payloads until they're
+/// yielded by the [`payloads()`] stream.
+pub(crate) struct Delivery<S> {
+    /// Queue of the resolved, but not yet yielded payloads.
+    queue: Mutex<DeliveryQueue<S>>,
+
+    /// [`Gate`] opened once the initial payload is yielded.
+    initial: Arc<Gate>,
+}
+
+/// Queue of the payloads of a [`Delivery`].
+struct DeliveryQueue<S> {
+    /// Resolved initial payload, unless it's yielded already or not resolved
+    /// yet.
+    initial: Option<IncrementalPayload<S>>,
+
+    /// Indicator whether the initial payload is yielded already.
+    started: bool,
+
+    /// Indicator whether the last payload is yielded already.
+    ended: bool,
+
+    /// Resolved subsequent results, not yielded yet.
+    results: VecDeque<IncrementalResult<S>>,
+}
+
+impl<S> Delivery<S> {
+    /// Creates a new [`Delivery`] with nothing resolved yet.
+    pub(crate) fn new() -> Self {
+        Self {
+            queue: Mutex::new(DeliveryQueue {
+                initial: None,
+                started: false,
+                ended: false,
+                results: VecDeque::new(),
+            }),
+            initial: Arc::new(Gate::default()),
+        }
+    }
+
+    /// Queues the initial payload with the provided `data` and `errors`.
+    pub(crate) fn deliver_initial(&self, data: Value<S>, errors: Vec<ExecutionError<S>>) {
+        self.queue.lock().unwrap().initial = Some(IncrementalPayload::Initial {
+            data,
+            errors,
+            has_next: false,
+        });
+    }
+
+    /// Queues the provided subsequent `result`.
+    fn deliver(&self, result: IncrementalResult<S>) {
+        self.queue.lock().unwrap().results.push_back(result);
+    }
+
+    /// Queues the provided `errors`, occurred after the payload containing the
+    /// `path` has been resolved, as a subsequent result with no data.
+    pub(crate) fn deliver_late_errors(
+        &self,
+        path: Vec<PathSegment>,
+        label: Option<String>,
+        errors: Vec<ExecutionError<S>>,
+    ) {
+        if !errors.is_empty() {
+            self.deliver(IncrementalResult::Data(IncrementalData {
+                data: Value::Object(Object::with_capacity(0)),
+                path,
+                label,
+                errors,
+            }));
+        }
+    }
+
+    /// Takes the next queued payload, if any, considering whether the whole
+    /// response is `finished` resolving.
+    fn poll_payload(&self, finished: bool) -> Poll<Option<IncrementalPayload<S>>> {
+        let mut queue = self.queue.lock().unwrap();
+        if queue.ended {
+            return Poll::Ready(None);
+        }
+
+        if !queue.started {
+            return match queue.initial.take() {
+                Some(IncrementalPayload::Initial { data, errors, .. }) => {
+                    queue.started = true;
+                    queue.ended = finished;
+                    drop(queue);
+                    self.initial.open();
+                    Poll::Ready(Some(IncrementalPayload::Initial {
+                        data,
+                        errors,
+                        has_next: !finished,
+                    }))
+                }
+                _ if finished => Poll::Ready(None),
+                _ => Poll::Pending,
+            };
+        }
+
+        match queue.results.pop_front() {
+            Some(result) => {
+                queue.ended = finished && queue.results.is_empty();
+                Poll::Ready(Some(IncrementalPayload::Subsequent {
+                    incremental: vec![result],
+                    has_next: !queue.ended,
+                }))
+            }
+            // Everything delivered in subsequent results turned out to be
+            // already delivered, so just the end of the response is reported.
+            None if finished => {
+                queue.ended = true;
+                Poll::Ready(Some(IncrementalPayload::Subsequent {
+                    incremental: vec![],
+                    has_next: false,
+                }))
+            }
+            None => Poll::Pending,
+        }
+    }
+}
+
+/// Returns a [`Stream`] of the payloads queued by the provided `delivery`,
+/// while driving its `execution`, resolving the payloads and returning the
+/// errors not delivered by any of them.
+///
+/// [`Stream`]: futures::Stream
+pub(crate) fn payloads<'f, S, F>(
+    delivery: Arc<Delivery<S>>,
+    execution: F,
+) -> impl futures::Stream<Item = IncrementalPayload<S>> + 'f
+where
+    F: Future<Output = Vec<ExecutionError<S>>> + 'f,
+    S: 'f,
+{
+    let mut execution = Some(Box::pin(execution));
+    futures::stream::poll_fn(move |cx| {
+        if let Some(resolving) = &mut execution {
+            if let Poll::Ready(errors) = resolving.as_mut().poll(cx) {
+                execution = None;
+                delivery.deliver_late_errors(vec![], None, errors);
+            }
+        }
+        delivery.poll_payload(execution.is_none())
+    })
+}
+
+/// State of resolving an incremental delivery, postponing the `@defer`ed
+/// fragments and the `@stream`ed list items beyond their `initialCount` until
+/// the payload containing their parent is resolved.
+pub(crate) struct Postponement<S> {
+    /// [`Delivery`] the postponed results are delivered into.
+    delivery: Arc<Delivery<S>>,
+
+    /// [`Gate`] opened once the payload being resolved currently is delivered.
+    gate: Arc<Gate>,
+
+    /// Slot to report the value being resolved currently into, as soon as
+    /// it's resolved without its postponed parts.
+    early: Option<Arc<EarlyValue<S>>>,
+}
+
+impl<S> Clone for Postponement<S> {
+    fn clone(&self) -> Self {
+        Self {
+            delivery: Arc::clone(&self.delivery),
+            gate: Arc::clone(&self.gate),
+            early: self.early.clone(),
+        }
+    }
+}
+
+impl<S> Postponement<S> {
+    /// Creates a new [`Postponement`] of resolving the initial payload of the
+    /// provided `delivery`, reporting its data `early`.
+    pub(crate) fn initial(delivery: Arc<Delivery<S>>, early: Arc<EarlyValue<S>>) -> Self {
+        Self {
+            gate: Arc::clone(&delivery.initial),
+            delivery,
+            early: Some(early),
+        }
+    }
+
+    /// Returns this [`Postponement`] for resolving a sub-value of the current
+    /// value, which doesn't report itself early unless asked to.
+    pub(crate) fn sub_value(&self) -> Self {
+        Self {
+            delivery: Arc::clone(&self.delivery),
+            gate: Arc::clone(&self.gate),
+            early: None,
+        }
+    }
+
+    /// Returns this [`Postponement`] reporting the current value into the
+    /// provided `early` slot.
+    #[must_use]
+    pub(crate) fn reporting_into(mut self, early: &Arc<EarlyValue<S>>) -> Self {
+        self.early = Some(Arc::clone(early));
+        self
+    }
+
+    /// Creates a new [`Postponement`] of resolving a postponed result, along
+    /// with the slot its value is reported early into.
+    pub(crate) fn postponed(&self) -> (Self, Arc<EarlyValue<S>>) {
+        let early = Arc::new(EarlyValue::default());
+        let postponement = Self {
+            delivery: Arc::clone(&self.delivery),
+            gate: Arc::default(),
+            early: Some(Arc::clone(&early)),
+        };
+        (postponement, early)
+    }
+
+    /// Reports the provided `value` as the current one, resolved without its
+    /// postponed parts.
+    pub(crate) fn report(&self, value: &Value<S>)
+    where
+        S: Clone,
+    {
+        if let Some(early) = &self.early {
+            *early.0.lock().unwrap() = Some(value.clone());
+        }
+    }
+
+    /// Waits until the payload being resolved currently is delivered, so the
+    /// parts postponed in it may be resolved.
+    pub(crate) async fn delivered(&self) {
+        self.gate.opened().await
+    }
+
+    /// Delivers the provided postponed `result`, and allows the parts
+    /// postponed in it to be resolved.
+    pub(crate) fn deliver(&self, result: IncrementalResult<S>) {
+        self.delivery.deliver(result);
+        self.gate.open();
+    }
+
+    /// Allows the parts postponed in the payload being resolved currently to
+    /// be resolved, without delivering anything.
+    pub(crate) fn skip(&self) {
+        self.gate.open();
+    }
+
+    /// Returns the [`Delivery`] of this [`Postponement`].
+    pub(crate) fn delivery(&self) -> &Delivery<S> {
+        &self.delivery
+    }
+}
+
+/// Slot for a value to be reported into, as soon as it's resolved without its
+/// postponed parts, while it's still being resolved completely.
+pub(crate) struct EarlyValue<S>(Mutex<Option<Value<S>>>);
+
+impl<S> Default for EarlyValue<S> {
+    fn default() -> Self {
+        Self(Mutex::new(None))
+    }
+}
+
+/// Signal allowing the futures waiting for it to proceed once opened.
+#[derive(Debug, Default)]
+pub(crate) struct Gate {
+    /// Indicator whether this [`Gate`] is opened, along with the [`Waker`]s of
+    /// the futures waiting for it otherwise.
+    state: Mutex<(bool, Vec<Waker>)>,
+}
+
+impl Gate {
+    /// Opens this [`Gate`], waking all the futures waiting for it.
+    fn open(&self) {
+        let wakers = {
+            let mut state = self.state.lock().unwrap();
+            state.0 = true;
+            mem::take(&mut state.1)
+        };
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+
+    /// Waits until this [`Gate`] is opened.
+    async fn opened(&self) {
+        future::poll_fn(|cx| {
+            let mut state = self.state.lock().unwrap();
+            if state.0 {
+                return Poll::Ready(());
+            }
+            if !state.1.iter().any(|w| w.will_wake(cx.waker())) {
+                state.1.push(cx.waker().clone());
+            }
+            Poll::Pending
+        })
+        .await
+    }
+}
+
+/// Part of a value being resolved incrementally, which may report its value
+/// early, before being resolved completely.
+pub(crate) struct Part<'f, S> {
+    /// Slot the value of this [`Part`] is reported early into.
+    early: Arc<EarlyValue<S>>,
+
+    /// Future resolving this [`Part`] completely, unless it's completed
+    /// already.
+    future: Option<BoxFuture<'f, Value<S>>>,
+
+    /// Value of this [`Part`], if it's known already.
+    value: Option<Value<S>>,
+}
+
+impl<'f, S> Part<'f, S> {
+    /// Creates a new [`Part`] resolved by the provided `future`, reporting its
+    /// value `early`.
+    pub(crate) fn new(early: Arc<EarlyValue<S>>, future: BoxFuture<'f, Value<S>>) -> Self {
+        Self {
+            early,
+            future: Some(future),
+            value: None,
+        }
+    }
+
+    /// Drives this [`Part`], returning whether its value is known already.
+    fn poll_value(&mut self, cx: &mut Context<'_>) -> bool {
+        if let Some(future) = &mut self.future {
+            if let Poll::Ready(value) = future.as_mut().poll(cx) {
+                self.future = None;
+                if self.value.is_none() {
+                    let early = self.early.0.lock().unwrap().take();
+                    self.value = Some(early.unwrap_or(value));
+                }
+            }
+        }
+        if self.value.is_none() {
+            self.value = self.early.0.lock().unwrap().take();
+        }
+        self.value.is_some()
+    }
+
+    /// Drives this [`Part`] until its value is known.
+    pub(crate) async fn known(&mut self) {
+        future::poll_fn(|cx| {
+            if self.poll_value(cx) {
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            }
+        })
+        .await
+    }
+
+    /// Drives this [`Part`] until its value is known, returning it.
+    pub(crate) async fn value(&mut self) -> Value<S> {
+        self.known().await;
+        self.value.take().expect("value is known")
+    }
+
+    /// Drives this [`Part`] until it's resolved completely.
+    pub(crate) async fn complete(self) {
+        complete(vec![self]).await
+    }
+}
+
+/// Drives all the provided `parts` until the value of each of them is known,
+/// returning these values.
+pub(crate) async fn values<S>(parts: &mut [Part<'_, S>]) -> Vec<Value<S>> {
+    future::poll_fn(|cx| {
+        // Every part is polled, so no one stays without the `cx` registered.
+        let mut known = true;
+        for part in parts.iter_mut() {
+            known &= part.poll_value(cx);
+        }
+        if known {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    })
+    .await;
+    parts
+        .iter_mut()
+        .map(|part| part.value.take().expect("value is known"))
+        .collect()
+}
+
+/// Drives all the provided `parts` until they're resolved completely.
+pub(crate) async fn complete<S>(mut parts: Vec<Part<'_, S>>) {
+    future::poll_fn(|cx| {
+        parts.retain_mut(|part| match &mut part.future {
+            Some(future) => future.as_mut().poll(cx).is_pending(),
+            None => false,
+        });
+        if parts.is_empty() {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    })
+    .await
+}
+
+/// Takes all the `errors` occurred so far, sorted.
+pub(crate) fn take_errors<S>(errors: &RwLock<Vec<ExecutionError<S>>>) -> Vec<ExecutionError<S>>
+where
+    ExecutionError<S>: Ord,
+{
+    let mut errors = mem::take(&mut *errors.write().unwrap());
+    errors.sort();
+    errors
+}
+
+/// Parses the `@stream` directive amongst the provided `directives`, if it has
+/// any effect.
+pub(crate) fn stream<S: ScalarValue>(
+    directives: &Option<Vec<Spanning<Directive<'_, S>>>>,
+    variables: &Variables<S>,
+) -> Option<Stream> {
+    let directive = enabled_directive(directives, "stream", variables)?;
+
+    Some(Stream {
+        initial_count: initial_count(directive, variables),
+        label: label(directive, variables),
+    })
+}
+
+/// Parses the `@defer` directive amongst the provided `directives`, if it has
+/// any effect.
+pub(crate) fn defer<S: ScalarValue>(
+    directives: &Option<Vec<Spanning<Directive<'_, S>>>>,
+    variables: &Variables<S>,
+) -> Option<Defer> {
+    let directive = enabled_directive(directives, "defer", variables)?;
+
+    Some(Defer {
+        label: label(directive, variables),
+    })
+}
+
+/// Splits the `data` resolved for the provided `operation` into
+/// [`IncrementalPayload`]s, according to the `@stream` and `@defer` directives
+/// in the `operation`.
+///
+/// Every `@stream`ed list item beyond the `initialCount` is delivered in its
+/// own [`IncrementalPayload::Subsequent`], followed by the items of the
+/// `@stream`ed lists nested into it. Similarly, fields of every `@defer`ed
+/// fragment are delivered in their own [`IncrementalPayload::Subsequent`],
+/// unless they are already delivered by the non-deferred selections.
+pub(crate) fn split_response<'a, S>(
+    document: &'a Document<'a, S>,
+    operation: &'a Spanning<Operation<'a, S>>,
+    variables: &Variables<S>,
+    mut data: Value<S>,
+    errors: Vec<ExecutionError<S>>,
+) -> Vec<IncrementalPayload<S>>
+where
+    S: ScalarValue,
+{
+    let mut all_vars = variables.clone();
+    let defaults = operation.item.variable_definitions.iter().flat_map(|defs| {
+        defs.item.items.iter().filter_map(|(name, def)| {
+            def.default_value
+                .as_ref()
+                .map(|v| (name.item.to_owned(), v.item.clone()))
+        })
+    });
+    for (name, value) in defaults {
+        all_vars.entry(name).or_insert(value);
+    }
+
+    let fragments = document
+        .iter()
+        .filter_map(|def| match def {
+            Definition::Fragment(f) => Some((f.item.name.item, &f.item)),
+            Definition::Operation(_) => None,
+        })
+        .collect();
+
+    let splitter = Splitter {
+        fragments,
+        variables: &all_vars,
+    };
+    let mut streamed = vec![];
+    splitter.split(
+        &operation.item.selection_set,
+        &mut data,
+        &mut vec![],
+        &mut streamed,
+    );
+
+    let mut initial_errors = vec![];
+    for err in errors {
+        let patch = streamed
+            .iter_mut()
+            .filter(|res| res.delivers(err.path()))
+            .max_by_key(|res| res.path().len());
+        match patch {
+            Some(res) => res.push_error(err),
+            None => initial_errors.push(err),
+        }
+    }
+
+    let total = streamed.len();
+    let mut payloads = Vec::with_capacity(total + 1);
+    payloads.push(IncrementalPayload::Initial {
+        data,
+        errors: initial_errors,
+        has_next: total > 0,
+    });
+    payloads.extend(streamed.into_iter().enumerate().map(|(i, res)| {
+        IncrementalPayload::Subsequent {
+            incremental: vec![res],
+            has_next: i + 1 < total,
+        }
+    }));
+    payloads
+}
+
+/// Parsed arguments of a `@stream` directive.
+#[derive(Clone, Debug)]
+pub(crate) struct Stream {
+    /// `initialCount` argument of the directive.
+    pub(crate) initial_count: usize,
+
+    /// `label` argument of the directive.
+    pub(crate) label: Option<String>,
+}
+
+/// Parsed arguments of a `@defer` directive.
+#[derive(Clone, Debug)]
+pub(crate) struct Defer {
+    /// `label` argument of the directive.
+    pub(crate) label: Option<String>,
+}
+
+/// Walker over the executed operation, detaching `@stream`ed list items and
+/// `@defer`ed fragments.
+struct Splitter<'a, S> {
+    fragments: HashMap<&'a str, &'a Fragment<'a, S>>,
+    variables: &'a Variables<S>,
+}
+
+impl<'a, S: ScalarValue> Splitter<'a, S> {
+    /// Detaches `@stream`ed list items and `@defer`ed fragments from the
+    /// provided `value` resolved for the given `selection_set`, putting them
+    /// into the `streamed` results.
+    fn split(
+        &self,
+        selection_set: &'a [Selection<'a, S>],
+        value: &mut Value<S>,
+        path: &mut Vec<PathSegment>,
+        streamed: &mut Vec<IncrementalResult<S>>,
+    ) {
+        match value {
+            Value::List(items) => {
+                for (i, item) in items.iter_mut().enumerate() {
+                    path.push(PathSegment::Index(i));
+                    self.split(selection_set, item, path, streamed);
+                    path.pop();
+                }
+            }
+            Value::Object(_) => {
+                let (mut fields, mut deferred) = (vec![], vec![]);
+                self.flatten(selection_set, &mut fields, &mut deferred);
+
+                let mut delivered = HashSet::new();
+                for field in fields {
+                    let name = field.alias.as_ref().unwrap_or(&field.name).item;
+                    delivered.insert(name);
+                    let field_value = match value
+                        .as_mut_object_value()
+                        .and_then(|obj| obj.get_mut_field_value(name))
+                    {
+                        Some(v) => v,
+                        None => continue,
+                    };
+                    let selection_set = field.selection_set.as_deref().unwrap_or(&[]);
+
+                    let stream = stream(&field.directives, self.variables);
+                    let rest = match (&stream, &mut *field_value) {
+                        (Some(stream), Value::List(items)) => {
+                            items.split_off(stream.initial_count.min(items.len()))
+                        }
+                        _ => vec![],
+                    };
+
+                    path.push(PathSegment::Field(name.into()));
+                    self.split(selection_set, field_value, path, streamed);
+                    if let Some(stream) = stream {
+                        for (i, mut item) in rest.into_iter().enumerate() {
+                            path.push(PathSegment::Index(stream.initial_count + i));
+                            let mut nested = vec![];
+                            self.split(selection_set, &mut item, path, &mut nested);
+                            streamed.push(IncrementalResult::Items(IncrementalItems {
+                                items: vec![item],
+                                path: path.clone(),
+                                label: stream.label.clone(),
+                                errors: vec![],
+                            }));
+                            streamed.append(&mut nested);
+                            path.pop();
+                        }
+                    }
+                    path.pop();
+                }
+
+                for (defer, selection_set) in deferred {
+                    let mut fields = vec![];
+                    self.flatten(selection_set, &mut fields, &mut vec![]);
+
+                    let mut data = Object::with_capacity(fields.len());
+                    for field in fields {
+                        let name = field.alias.as_ref().unwrap_or(&field.name).item;
+                        if !delivered.insert(name) {
+                            continue;
+                        }
+                        if let Some(v) = value
+                            .as_mut_object_value()
+                            .and_then(|obj| obj.remove_field(name))
+                        {
+                            data.add_field(name, v);
+                        }
+                    }
+                    if data.field_count() == 0 {
+                        continue;
+                    }
+
+                    let mut data = Value::Object(data);
+                    let mut nested = vec![];
+                    self.split(selection_set, &mut data, path, &mut nested);
+                    streamed.push(IncrementalResult::Data(IncrementalData {
+                        data,
+                        path: path.clone(),
+                        label: defer.label,
+                        errors: vec![],
+                    }));
+                    streamed.append(&mut nested);
+                }
+            }
+            Value::Null | Value::Scalar(_) => {}
+        }
+    }
+
+    /// Collects the fields of the provided `selection_set` into the `fields`,
+    /// flattening its non-deferred fragments, and collecting its `@defer`ed
+    /// fragments into the `deferred` ones.
+    fn flatten(
+        &self,
+        selection_set: &'a [Selection<'a, S>],
+        fields: &mut Vec<&'a Field<'a, S>>,
+        deferred: &mut Vec<(Defer, &'a [Selection<'a, S>])>,
+    ) {
+        for selection in selection_set {
+            let (directives, selection_set) = match selection {
+                Selection::Field(field) => {
+                    fields.push(&field.item);
+                    continue;
+                }
+                Selection::FragmentSpread(spread) => {
+                    match self.fragments.get(spread.item.name.item) {
+                        Some(fragment) => (&spread.item.directives, &fragment.selection_set[..]),
+                        None => continue,
+                    }
+                }
+                Selection::InlineFragment(fragment) => {
+                    (&fragment.item.directives, &fragment.item.selection_set[..])
+                }
+            };
+            match defer(directives, self.variables) {
+                Some(defer) => deferred.push((defer, selection_set)),
+                None => self.flatten(selection_set, fields, deferred),
+            }
+        }
+    }
+}
+
+/// Looks up the directive with the provided `name` amongst the `directives`,
+/// unless its `if` argument is `false`.
+fn enabled_directive<'d, 'a, S: ScalarValue>(
+    directives: &'d Option<Vec<Spanning<Directive<'a, S>>>>,
+    name: &str,
+    variables: &Variables<S>,
+) -> Option<&'d Directive<'a, S>> {
+    let directive = &directives
+        .iter()
+        .flatten()
+        .find(|d| d.item.name.item == name)?
+        .item;
+
+    if let Some(false) = argument(directive, "if", variables).and_then(|v| v.convert::<bool>().ok())
+    {
+        return None;
+    }
+    Some(directive)
+}
+
+/// Returns the value of the `directive` argument with the provided `name`, with
+/// all its variables substituted.
+fn argument<S: ScalarValue>(
+    directive: &Directive<'_, S>,
+    name: &str,
+    variables: &Variables<S>,
+) -> Option<InputValue<S>> {
+    directive
+        .arguments
+        .as_ref()?
+        .item
+        .get(name)?
+        .item
+        .clone()
+        .into_const(variables)
+}
+
+/// Returns the `initialCount` argument of the provided `@stream` `directive`.
+fn initial_count<S: ScalarValue>(directive: &Directive<'_, S>, variables: &Variables<S>) -> usize {
+    argument(directive, "initialCount", variables)
+        .and_then(|v| v.as_int_value())
+        .map_or(0, |c| c.max(0) as usize)
+}
+
+/// Returns the `label` argument of the provided `directive`, if any.
+fn label<S: ScalarValue>(directive: &Directive<'_, S>, variables: &Variables<S>) -> Option<String> {
+    argument(directive, "label", variables).and_then(|v| v.as_string_value().map(Into::into))
+}
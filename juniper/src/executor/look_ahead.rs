@@ -1,8 +1,12 @@
 use std::collections::HashMap;
 
 use crate::{
-    ast::{Directive, Fragment, InputValue, Selection},
+    ast::{Arguments, Directive, Fragment, InputValue, Selection},
     parser::Spanning,
+    schema::{
+        meta::{Field, MetaType},
+        model::SchemaType,
+    },
     value::ScalarValue,
 };
 
@@ -85,6 +89,50 @@ where
         }
     }
 
+    /// Builds all the [`LookAheadArgument`]s of a field from the provided
+    /// `arguments`, filling the omitted ones with the default values of the
+    /// `field` definition, if any.
+    ///
+    /// An argument provided via an omitted variable is considered omitted too.
+    fn build_all(
+        arguments: Option<&'a Arguments<'a, S>>,
+        field: Option<&'a Field<'a, S>>,
+        vars: &'a Variables<S>,
+    ) -> Vec<Self> {
+        let is_provided = |value: &InputValue<S>| match value {
+            InputValue::Variable(name) => vars.contains_key(name),
+            _ => true,
+        };
+        let has_default = |name: &str| {
+            field
+                .and_then(|f| f.arguments.as_ref())
+                .and_then(|args| args.iter().find(|a| a.name == name))
+                .map_or(false, |a| a.default_value.is_some())
+        };
+
+        let mut provided = arguments
+            .into_iter()
+            .flat_map(|a| a.items.iter())
+            .filter(|(name, value)| is_provided(&value.item) || !has_default(name.item))
+            .map(|p| LookAheadArgument::new(p, vars))
+            .collect::<Vec<_>>();
+
+        let defaults = field
+            .and_then(|f| f.arguments.as_ref())
+            .into_iter()
+            .flatten()
+            .filter(|a| provided.iter().all(|p| p.name != a.name))
+            .filter_map(|a| {
+                a.default_value.as_ref().map(|v| LookAheadArgument {
+                    name: &a.name,
+                    value: LookAheadValue::from_input_value(v, vars),
+                })
+            })
+            .collect::<Vec<_>>();
+        provided.extend(defaults);
+        provided
+    }
+
     /// The argument's name
     pub fn name(&'a self) -> &str {
         self.name
@@ -175,26 +223,34 @@ where
                             .unwrap_or(false),
                         ("skip", &None) => false,
                         ("include", &None) => true,
-                        (_, _) => unreachable!(),
+                        // Custom directives don't affect field inclusion.
+                        (_, _) => true,
                     }
                 })
             })
             .unwrap_or(true)
     }
 
+    #[cfg(test)]
     pub(super) fn build_from_selection(
         s: &'a Selection<'a, S>,
         vars: &'a Variables<S>,
         fragments: &'a HashMap<&'a str, Fragment<'a, S>>,
     ) -> Option<LookAheadSelection<'a, S>> {
-        Self::build_from_selection_with_parent(s, None, vars, fragments)
+        Self::build_from_selection_with_parent(s, None, vars, fragments, None)
     }
 
+    /// Builds a [`LookAheadSelection`] from the provided [`Selection`].
+    ///
+    /// If the `on_type` (the [`MetaType`] the [`Selection`] is performed on,
+    /// along with its [`SchemaType`]) is provided, then the omitted arguments
+    /// are filled with their default values from the schema.
     pub(super) fn build_from_selection_with_parent(
         s: &'a Selection<'a, S>,
         parent: Option<&mut Self>,
         vars: &'a Variables<S>,
         fragments: &'a HashMap<&'a str, Fragment<'a, S>>,
+        on_type: Option<(&'a SchemaType<'a, S>, &'a MetaType<'a, S>)>,
     ) -> Option<LookAheadSelection<'a, S>> {
         let empty: &[Selection<S>] = &[];
         match *s {
@@ -206,17 +262,15 @@ where
                 }
                 let name = field.name.item;
                 let alias = field.alias.as_ref().map(|a| a.item);
-                let arguments = field
-                    .arguments
-                    .as_ref()
-                    .map(|a| &a.item)
-                    .map(|a| {
-                        a.items
-                            .iter()
-                            .map(|p| LookAheadArgument::new(p, vars))
-                            .collect()
-                    })
-                    .unwrap_or_else(Vec::new);
+                let meta_field = on_type.and_then(|(_, t)| t.field_by_name(name));
+                let arguments = LookAheadArgument::build_all(
+                    field.arguments.as_ref().map(|a| &a.item),
+                    meta_field,
+                    vars,
+                );
+                let children_on_type = on_type.zip(meta_field).and_then(|((schema, _), f)| {
+                    schema.lookup_type(&f.field_type).map(|t| (schema, t))
+                });
                 let mut ret = LookAheadSelection {
                     name,
                     alias,
@@ -235,6 +289,7 @@ where
                         Some(&mut ret),
                         vars,
                         fragments,
+                        children_on_type,
                     );
                     assert!(s.is_none());
                 }
@@ -254,6 +309,7 @@ where
                     return None;
                 }
                 let f = fragments.get(&fragment.item.name.item).expect("a fragment");
+                let on_type = Self::narrow_type(on_type, Some(f.type_condition.item));
                 if let Some(parent) = parent {
                     for c in f.selection_set.iter() {
                         let s = LookAheadSelection::build_from_selection_with_parent(
@@ -261,13 +317,14 @@ where
                             Some(parent),
                             vars,
                             fragments,
+                            on_type,
                         );
                         assert!(s.is_none());
                     }
                 } else {
                     for c in f.selection_set.iter() {
                         let s = LookAheadSelection::build_from_selection_with_parent(
-                            c, None, vars, fragments,
+                            c, None, vars, fragments, on_type,
                         );
                         assert!(s.is_some());
                     }
@@ -280,16 +337,25 @@ where
                     return None;
                 }
                 let parent = parent.unwrap();
+                let on_type =
+                    Self::narrow_type(on_type, inline.item.type_condition.as_ref().map(|t| t.item));
+                let first_child = parent.children.len();
                 for c in inline.item.selection_set.iter() {
                     let s = LookAheadSelection::build_from_selection_with_parent(
                         c,
                         Some(parent),
                         vars,
                         fragments,
+                        on_type,
                     );
                     assert!(s.is_none());
-                    if let Some(c) = inline.item.type_condition.as_ref().map(|t| t.item) {
-                        if let Some(p) = parent.children.last_mut() {
+                }
+                // Only the children collected from this fragment are restricted
+                // by its type condition, while the ones restricted by nested
+                // fragments already are.
+                if let Some(c) = inline.item.type_condition.as_ref().map(|t| t.item) {
+                    for p in &mut parent.children[first_child..] {
+                        if p.applies_for == Applies::All {
                             p.applies_for = Applies::OnlyType(c);
                         }
                     }
@@ -300,6 +366,19 @@ where
         }
     }
 
+    /// Narrows the provided `on_type` to the one of the fragment's
+    /// `type_condition`, if any.
+    fn narrow_type(
+        on_type: Option<(&'a SchemaType<'a, S>, &'a MetaType<'a, S>)>,
+        type_condition: Option<&str>,
+    ) -> Option<(&'a SchemaType<'a, S>, &'a MetaType<'a, S>)> {
+        match type_condition {
+            Some(name) => on_type
+                .and_then(|(schema, _)| schema.concrete_type_by_name(name).map(|t| (schema, t))),
+            None => on_type,
+        }
+    }
+
     /// Convert a eventually type independent selection into one for a concrete type
     pub fn for_explicit_type(&self, type_name: &str) -> ConcreteLookAheadSelection<'a, S> {
         ConcreteLookAheadSelection {
@@ -338,10 +417,33 @@ pub trait LookAheadMethods<'sel, S> {
     /// Get the (potentially aliased) name of the field represented by the current selection
     fn field_name(&self) -> &'sel str;
 
+    /// Get the alias of the field represented by the current selection, if it's aliased
+    ///
+    /// Returns [`None`] by default, so should be overridden by implementations
+    /// supporting aliased selections.
+    fn alias(&self) -> Option<&'sel str> {
+        None
+    }
+
+    /// Get the key of the field represented by the current selection in the response: its alias,
+    /// if it's aliased, or its name otherwise
+    ///
+    /// Same as [`LookAheadMethods::field_name()`].
+    fn response_name(&self) -> &'sel str {
+        self.field_name()
+    }
+
     /// Get the the child selection for a given field
     /// If a child has an alias, it will only match if the alias matches `name`
     fn select_child(&self, name: &str) -> Option<&Self>;
 
+    /// Get the nested child selection for a given path of field names
+    /// If a child has an alias, it will only match if the alias matches the path segment
+    fn select_path(&self, path: &[&str]) -> Option<&Self> {
+        path.iter()
+            .try_fold(self, |sel, name| sel.select_child(name))
+    }
+
     /// Check if a given child selection with a name exists
     /// If a child has an alias, it will only match if the alias matches `name`
     fn has_child(&self, name: &str) -> bool {
@@ -355,6 +457,9 @@ pub trait LookAheadMethods<'sel, S> {
     fn has_children(&self) -> bool;
 
     /// Get the top level arguments for the current selection
+    ///
+    /// Variables are already substituted with their values, and the omitted
+    /// arguments having default values in the schema are included too.
     fn arguments(&self) -> &[LookAheadArgument<S>];
 
     /// Get the top level argument with a given name from the current selection
@@ -374,6 +479,10 @@ impl<'a, S> LookAheadMethods<'a, S> for ConcreteLookAheadSelection<'a, S> {
         self.alias.unwrap_or(self.name)
     }
 
+    fn alias(&self) -> Option<&'a str> {
+        self.alias
+    }
+
     fn select_child(&self, name: &str) -> Option<&Self> {
         self.children.iter().find(|c| c.field_name() == name)
     }
@@ -404,6 +513,10 @@ impl<'a, S> LookAheadMethods<'a, S> for LookAheadSelection<'a, S> {
         self.alias.unwrap_or(self.name)
     }
 
+    fn alias(&self) -> Option<&'a str> {
+        self.alias
+    }
+
     fn select_child(&self, name: &str) -> Option<&Self> {
         self.children
             .iter()
@@ -1248,6 +1361,63 @@ fragment comparisonFields on Character {
         }
     }
 
+    #[test]
+    fn check_query_with_excluded_inline_fragment_fields() {
+        let docs = parse_document_source::<DefaultScalarValue>(
+            "
+query Hero($withFunction: Boolean!) {
+    hero {
+        name
+        ... on Droid {
+            primaryFunction @include(if: $withFunction)
+        }
+        ... on Human {
+            height
+            ... {
+                homePlanet
+                id
+            }
+        }
+    }
+}",
+        )
+        .unwrap();
+        let fragments = extract_fragments(&docs);
+
+        if let crate::ast::Definition::Operation(ref op) = docs[0] {
+            let vars = graphql_vars! {"withFunction": false};
+            let look_ahead = LookAheadSelection::build_from_selection(
+                &op.item.selection_set[0],
+                &vars,
+                &fragments,
+            )
+            .unwrap();
+            let child = |name, applies_for| ChildSelection {
+                inner: LookAheadSelection {
+                    name,
+                    alias: None,
+                    arguments: Vec::new(),
+                    children: Vec::new(),
+                },
+                applies_for,
+            };
+            let expected = LookAheadSelection {
+                name: "hero",
+                alias: None,
+                arguments: Vec::new(),
+                children: vec![
+                    child("name", Applies::All),
+                    child("height", Applies::OnlyType("Human")),
+                    child("homePlanet", Applies::OnlyType("Human")),
+                    child("id", Applies::OnlyType("Human")),
+                ],
+            };
+            assert_eq!(look_ahead, expected);
+        } else {
+            panic!("No Operation found");
+        }
+    }
+
     #[test]
     fn check_resolve_concrete_type() {
         let docs = parse_document_source::<DefaultScalarValue>(
@@ -1541,4 +1711,125 @@ query Hero {
             panic!("No Operation found");
         }
     }
+
+    #[test]
+    fn check_aliases_of_same_field() {
+        let docs = parse_document_source::<DefaultScalarValue>(
+            "
+query Hero {
+    hero {
+        a: name
+        b: name
+        name
+        c: friends {
+            d: name
+        }
+    }
+}
+",
+        )
+        .unwrap();
+        let fragments = extract_fragments(&docs);
+
+        if let crate::ast::Definition::Operation(ref op) = docs[0] {
+            let vars = graphql_vars! {};
+            let look_ahead = LookAheadSelection::build_from_selection(
+                &op.item.selection_set[0],
+                &vars,
+                &fragments,
+            )
+            .unwrap();
+
+            assert_eq!(look_ahead.alias(), None);
+            assert_eq!(look_ahead.response_name(), "hero");
+
+            let children = look_ahead.children();
+            assert_eq!(
+                children
+                    .iter()
+                    .map(|c| (c.name, c.alias(), c.response_name()))
+                    .collect::<Vec<_>>(),
+                [
+                    ("name", Some("a"), "a"),
+                    ("name", Some("b"), "b"),
+                    ("name", None, "name"),
+                    ("friends", Some("c"), "c"),
+                ],
+            );
+            assert_eq!(look_ahead.select_child("a"), Some(children[0]));
+            assert_eq!(look_ahead.select_child("b"), Some(children[1]));
+
+            let concrete = look_ahead.for_explicit_type("Human");
+            let friends = concrete.select_child("c").unwrap();
+            assert_eq!(friends.alias(), Some("c"));
+            assert_eq!(friends.response_name(), "c");
+            assert_eq!(friends.children()[0].alias(), Some("d"));
+            assert_eq!(friends.children()[0].response_name(), "d");
+        } else {
+            panic!("No Operation found");
+        }
+    }
+
+    #[test]
+    fn check_query_with_default_arguments() {
+        let schema = SchemaType::new::<QueryRoot, MutationRoot, SubscriptionRoot>(&(), &(), &());
+        let docs = crate::parse_document_source::<DefaultScalarValue>(
+            "
+query Args($req2: Int!, $opt1: Int) {
+    complicatedArgs {
+        multipleOptAndReq(req1: 1, req2: $req2, opt1: $opt1)
+    }
+}
+",
+            &schema,
+        )
+        .unwrap();
+        let fragments = extract_fragments(&docs);
+
+        if let crate::ast::Definition::Operation(ref op) = docs[0] {
+            let vars = graphql_vars! {"req2": 2};
+            let look_ahead = LookAheadSelection::build_from_selection_with_parent(
+                &op.item.selection_set[0],
+                None,
+                &vars,
+                &fragments,
+                Some((&schema, schema.query_type().innermost_concrete())),
+            )
+            .unwrap();
+            let expected = LookAheadSelection {
+                name: "complicatedArgs",
+                alias: None,
+                arguments: Vec::new(),
+                children: vec![ChildSelection {
+                    inner: LookAheadSelection {
+                        name: "multipleOptAndReq",
+                        alias: None,
+                        arguments: vec![
+                            LookAheadArgument {
+                                name: "req1",
+                                value: LookAheadValue::Scalar(&DefaultScalarValue::Int(1)),
+                            },
+                            LookAheadArgument {
+                                name: "req2",
+                                value: LookAheadValue::Scalar(&DefaultScalarValue::Int(2)),
+                            },
+                            LookAheadArgument {
+                                name: "opt1",
+                                value: LookAheadValue::Scalar(&DefaultScalarValue::Int(0)),
+                            },
+                            LookAheadArgument {
+                                name: "opt2",
+                                value: LookAheadValue::Scalar(&DefaultScalarValue::Int(0)),
+                            },
+                        ],
+                        children: Vec::new(),
+                    },
+                    applies_for: Applies::All,
+                }],
+            };
+            assert_eq!(look_ahead, expected);
+        } else {
+            panic!("No Operation found");
+        }
+    }
 }
@@ -0,0 +1,155 @@
+//! Extension point for wrapping resolution of every field.
+
+use std::fmt;
+
+use crate::{BoxFuture, ExecutionResult};
+
+use super::{Executor, FieldPath, PathSegment};
+
+/// User-defined middleware, wrapping resolution of every field executed
+/// against the schema it's registered in (see
+/// [`RootNode::with_field_middleware()`]).
+///
+/// Sees the resolved field via [`FieldInfo`] and the resulting [`Value`] or
+/// [`FieldError`] returned by its `next` resolver, so is suitable for measuring
+/// latency, emitting tracing spans or even replacing the result.
+///
+/// [`FieldMiddleware::around_field()`] wraps fields resolved synchronously
+/// (via [`execute_sync()`], for example), while
+/// [`FieldMiddleware::around_field_async()`] wraps fields resolved
+/// asynchronously (via [`execute()`]). Both just call `next` by default, so
+/// only the ones used by the application need to be implemented.
+///
+/// A field of `__typename` isn't passed to the middleware. Neither are the
+/// root fields of a `subscription` operation (resolved via
+/// [`resolve_into_stream()`]), as they resolve into a stream of values rather
+/// than a single [`Value`], while the fields of the values yielded by the
+/// stream are still passed to [`FieldMiddleware::around_field_async()`]. In
+/// case multiple middlewares are registered, the first one registered is the
+/// outermost one.
+///
+/// [`execute()`]: crate::execute
+/// [`execute_sync()`]: crate::execute_sync
+/// [`FieldError`]: crate::FieldError
+/// [`resolve_into_stream()`]: crate::resolve_into_stream
+/// [`RootNode::with_field_middleware()`]: crate::RootNode::with_field_middleware
+/// [`Value`]: crate::Value
+pub trait FieldMiddleware<S>: Send + Sync {
+    /// Wraps synchronous resolution of the described `field`, performed by
+    /// calling `next`.
+    fn around_field(
+        &self,
+        field: &FieldInfo<'_>,
+        next: &mut dyn FnMut() -> ExecutionResult<S>,
+    ) -> ExecutionResult<S> {
+        let _ = field;
+        next()
+    }
+
+    /// Wraps asynchronous resolution of the described `field`, performed by
+    /// awaiting `next`.
+    fn around_field_async<'f>(
+        &'f self,
+        field: &'f FieldInfo<'f>,
+        next: BoxFuture<'f, ExecutionResult<S>>,
+    ) -> BoxFuture<'f, ExecutionResult<S>> {
+        let _ = field;
+        next
+    }
+}
+
+impl<'a, S> fmt::Debug for dyn FieldMiddleware<S> + 'a {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("dyn FieldMiddleware")
+    }
+}
+
+/// Description of a field being resolved, passed to a [`FieldMiddleware`].
+#[derive(Clone, Copy)]
+pub struct FieldInfo<'f> {
+    parent_type_name: &'f str,
+    field_name: &'f str,
+    field_path: &'f FieldPath<'f>,
+}
+
+impl<'f> FieldInfo<'f> {
+    /// Returns name of the type the resolved field belongs to.
+    pub fn parent_type_name(&self) -> &'f str {
+        self.parent_type_name
+    }
+
+    /// Returns name of the resolved field, as defined in the schema.
+    pub fn field_name(&self) -> &'f str {
+        self.field_name
+    }
+
+    /// Returns [response name][0] of the resolved field (its alias, if any, or
+    /// its name otherwise).
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Field-Alias
+    pub fn response_name(&self) -> &'f str {
+        self.field_path.field_name().unwrap_or(self.field_name)
+    }
+
+    /// Returns [response path][0] leading from the executed operation to the
+    /// resolved field (inclusive), as it would be reported in the errors of
+    /// this field.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Errors.Error-result-format
+    pub fn path(&self) -> Vec<PathSegment> {
+        let mut path = Vec::new();
+        self.field_path.construct_path(&mut path);
+        path
+    }
+}
+
+impl<'f> fmt::Debug for FieldInfo<'f> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FieldInfo")
+            .field("parent_type_name", &self.parent_type_name)
+            .field("field_name", &self.field_name)
+            .field("path", &self.path())
+            .finish()
+    }
+}
+
+impl<'r, 'a, CtxT, S> Executor<'r, 'a, CtxT, S> {
+    /// Describes the `field_name` field resolved by this sub-[`Executor`] (as
+    /// returned by [`Executor::field_sub_executor()`]) for [`FieldMiddleware`]s.
+    pub(crate) fn field_info(&self, field_name: &'a str) -> FieldInfo<'_> {
+        FieldInfo {
+            parent_type_name: self
+                .parent_type
+                .and_then(|t| t.name())
+                .expect("Resolving field of named type"),
+            field_name,
+            field_path: &self.field_path,
+        }
+    }
+}
+
+/// Resolves a field via the provided `next` resolver, wrapped into all the
+/// provided `middlewares` synchronously.
+pub(crate) fn around_field<S>(
+    middlewares: &[Box<dyn FieldMiddleware<S> + '_>],
+    field: &FieldInfo<'_>,
+    next: &mut dyn FnMut() -> ExecutionResult<S>,
+) -> ExecutionResult<S> {
+    match middlewares.split_first() {
+        None => next(),
+        Some((outer, rest)) => outer.around_field(field, &mut || around_field(rest, field, next)),
+    }
+}
+
+/// Resolves a field via the provided `next` future, wrapped into all the
+/// provided `middlewares` asynchronously.
+pub(crate) fn around_field_async<'f, S>(
+    middlewares: &'f [Box<dyn FieldMiddleware<S> + 'f>],
+    field: &'f FieldInfo<'f>,
+    next: BoxFuture<'f, ExecutionResult<S>>,
+) -> BoxFuture<'f, ExecutionResult<S>> {
+    middlewares
+        .iter()
+        .rev()
+        .fold(next, |next, m| m.around_field_async(field, next))
+}
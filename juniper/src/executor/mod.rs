@@ -1,10 +1,13 @@
 //! Resolve the document to values
 
 use std::{
+    any::{Any, TypeId},
     borrow::Cow,
     cmp::Ordering,
     collections::HashMap,
-    fmt::{Debug, Display},
+    error::Error as StdError,
+    fmt::{self, Debug, Display},
+    future::Future,
     sync::{Arc, RwLock},
 };
 
@@ -13,13 +16,16 @@ use futures::Stream;
 
 use crate::{
     ast::{
-        Definition, Document, Fragment, FromInputValue, InputValue, Operation, OperationType,
-        Selection, ToInputValue, Type,
+        self, Definition, Directive, Document, Fragment, FromInputValue, InputValue, Operation,
+        OperationType, Selection, ToInputValue, Type,
     },
-    parser::{SourcePosition, Spanning},
+    introspection::{
+        IntrospectionFormat, INTROSPECTION_QUERY, INTROSPECTION_QUERY_WITHOUT_DESCRIPTIONS,
+    },
+    parser::{parse_document_source_with_options, ParseOptions, SourcePosition, Spanning},
     schema::{
         meta::{
-            Argument, DeprecationStatus, EnumMeta, EnumValue, Field, InputObjectMeta,
+            Argument, Complexity, DeprecationStatus, EnumMeta, EnumValue, Field, InputObjectMeta,
             InterfaceMeta, ListMeta, MetaType, NullableMeta, ObjectMeta, PlaceholderMeta,
             ScalarMeta, UnionMeta,
         },
@@ -27,24 +33,40 @@ use crate::{
     },
     types::{
         async_await::{GraphQLTypeAsync, GraphQLValueAsync},
-        base::{GraphQLType, GraphQLValue},
+        base::{Arguments, GraphQLType, GraphQLValue},
         name::Name,
         subscriptions::{GraphQLSubscriptionType, GraphQLSubscriptionValue},
     },
-    value::{DefaultScalarValue, ParseScalarValue, ScalarValue, Value},
+    value::{DefaultScalarValue, Object, ParseScalarValue, ScalarValue, Value},
     GraphQLError,
 };
 
+use self::{
+    deadline::Deadline,
+    incremental::{Delivery, EarlyValue, Postponement},
+};
+
 pub use self::{
+    collect::collect_for_selection,
+    incremental::{IncrementalData, IncrementalItems, IncrementalPayload, IncrementalResult},
     look_ahead::{
         Applies, ChildSelection, ConcreteLookAheadSelection, LookAheadArgument, LookAheadMethods,
         LookAheadSelection, LookAheadValue,
     },
+    middleware::{FieldInfo, FieldMiddleware},
+    naive_executor::NaiveExecutor,
     owned_executor::OwnedExecutor,
+    pre_execution::{OperationInfo, PreExecution},
 };
 
+mod collect;
+mod deadline;
+pub(crate) mod incremental;
 mod look_ahead;
+pub(crate) mod middleware;
+mod naive_executor;
 mod owned_executor;
+pub(crate) mod pre_execution;
 
 /// A type registry used to build schemas
 ///
@@ -61,6 +83,7 @@ pub struct Registry<'r, S = DefaultScalarValue> {
 pub enum FieldPath<'a> {
     Root(SourcePosition),
     Field(&'a str, SourcePosition, Arc<FieldPath<'a>>),
+    Index(usize, Arc<FieldPath<'a>>),
 }
 
 /// Query execution engine
@@ -76,11 +99,56 @@ where
     variables: &'r Variables<S>,
     current_selection_set: Option<&'r [Selection<'a, S>]>,
     parent_selection_set: Option<&'r [Selection<'a, S>]>,
+    root_selection_set: Option<&'r [Selection<'a, S>]>,
+    root_type: &'a MetaType<'a, S>,
+    parent_type: Option<&'a MetaType<'a, S>>,
     current_type: TypeType<'a, S>,
     schema: &'a SchemaType<'a, S>,
     context: &'a CtxT,
     errors: &'r RwLock<Vec<ExecutionError<S>>>,
+    deadline: Option<Arc<Deadline>>,
+    postponement: Option<Postponement<S>>,
+    stream: Option<incremental::Stream>,
     field_path: Arc<FieldPath<'a>>,
+    field_arguments: Option<FieldArguments<'r, 'a, S>>,
+}
+
+/// Arguments of the field resolved by an [`Executor`], as they're specified in
+/// the query, along with their declarations in the schema.
+struct FieldArguments<'r, 'a, S> {
+    specified: Option<&'r Spanning<ast::Arguments<'a, S>>>,
+    declared: &'a Option<Vec<Argument<'a, S>>>,
+}
+
+impl<'r, 'a, S> Clone for FieldArguments<'r, 'a, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'r, 'a, S> Copy for FieldArguments<'r, 'a, S> {}
+
+/// Crate-internal parameters of executing an already validated operation.
+pub(crate) struct ExecutionParams<S> {
+    /// Already estimated cost of the operation, if any.
+    pub(crate) cost: Option<u64>,
+
+    /// [`Deadline`] of the execution, if any.
+    pub(crate) deadline: Option<Arc<Deadline>>,
+
+    /// [`Delivery`] to resolve the operation incrementally into, postponing its
+    /// `@defer`ed fragments and `@stream`ed list items, if any.
+    pub(crate) delivery: Option<Arc<Delivery<S>>>,
+}
+
+impl<S> Default for ExecutionParams<S> {
+    fn default() -> Self {
+        Self {
+            cost: None,
+            deadline: None,
+            delivery: None,
+        }
+    }
 }
 
 /// Error type for errors that occur during query execution
@@ -90,12 +158,64 @@ where
 #[derive(Debug, PartialEq)]
 pub struct ExecutionError<S> {
     location: SourcePosition,
-    path: Vec<String>,
+    path: Vec<PathSegment>,
     error: FieldError<S>,
 }
 
+/// Segment of a [response path][0] leading to the field an [`ExecutionError`]
+/// occurred at.
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Errors.Error-result-format
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PathSegment {
+    /// Response name of a field (its alias, if any, or its name otherwise).
+    Field(String),
+
+    /// Zero-based index of an element in a list.
+    Index(usize),
+}
+
+impl fmt::Display for PathSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Field(name) => write!(f, "{name}"),
+            Self::Index(idx) => write!(f, "{idx}"),
+        }
+    }
+}
+
+impl From<&str> for PathSegment {
+    fn from(name: &str) -> Self {
+        Self::Field(name.into())
+    }
+}
+
+impl From<String> for PathSegment {
+    fn from(name: String) -> Self {
+        Self::Field(name)
+    }
+}
+
+impl From<usize> for PathSegment {
+    fn from(idx: usize) -> Self {
+        Self::Index(idx)
+    }
+}
+
 impl<S> Eq for ExecutionError<S> where Self: PartialEq {}
 
+impl<S> Display for ExecutionError<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.error.message)
+    }
+}
+
+impl<S: Debug> StdError for ExecutionError<S> {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.error.source().map(|e| e as _)
+    }
+}
+
 impl<S> ExecutionError<S> {
     /// Construct a new execution error occuring at the beginning of the query
     pub fn at_origin(error: FieldError<S>) -> ExecutionError<S> {
@@ -149,10 +269,19 @@ where
 ///     Ok(s)
 /// }
 /// ```
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct FieldError<S = DefaultScalarValue> {
     message: String,
     extensions: Value<S>,
+    source: Option<Arc<dyn StdError + Send + Sync>>,
+}
+
+/// Compares the `"message"` and the `"extensions"` only, as the
+/// [`FieldError::source()`] is not a part of the response.
+impl<S: PartialEq> PartialEq for FieldError<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.message == other.message && self.extensions == other.extensions
+    }
 }
 
 impl<T: Display, S> From<T> for FieldError<S> {
@@ -160,6 +289,7 @@ impl<T: Display, S> From<T> for FieldError<S> {
         Self {
             message: e.to_string(),
             extensions: Value::Null,
+            source: None,
         }
     }
 }
@@ -200,9 +330,40 @@ impl<S> FieldError<S> {
         Self {
             message: e.to_string(),
             extensions,
+            source: None,
         }
     }
 
+    /// Attaches the provided `source` error to this [`FieldError`], preserving
+    /// the original typed error it has been created from.
+    ///
+    /// The `source` doesn't affect the response in any way, but is available
+    /// via [`FieldError::source()`] (and [`std::error::Error::source()`] of the
+    /// [`ExecutionError`]), so may be logged or downcast by a
+    /// [`FieldMiddleware`], for example.
+    ///
+    /// ```rust
+    /// use std::io;
+    ///
+    /// use juniper::FieldError;
+    ///
+    /// let io_err = io::Error::new(io::ErrorKind::NotFound, "no such file");
+    /// let err: FieldError = FieldError::from(&io_err).with_source(io_err);
+    ///
+    /// let source = err.source().unwrap();
+    /// assert_eq!(
+    ///     source.downcast_ref::<io::Error>().map(io::Error::kind),
+    ///     Some(io::ErrorKind::NotFound),
+    /// );
+    /// ```
+    ///
+    /// [`FieldMiddleware`]: crate::FieldMiddleware
+    #[must_use]
+    pub fn with_source(mut self, source: impl Into<Box<dyn StdError + Send + Sync>>) -> Self {
+        self.source = Some(source.into().into());
+        self
+    }
+
     /// Returns `"message"` field of this [`FieldError`].
     #[must_use]
     pub fn message(&self) -> &str {
@@ -217,6 +378,13 @@ impl<S> FieldError<S> {
         &self.extensions
     }
 
+    /// Returns the source error of this [`FieldError`], if it has been
+    /// attached via [`FieldError::with_source()`].
+    #[must_use]
+    pub fn source(&self) -> Option<&(dyn StdError + Send + Sync + 'static)> {
+        self.source.as_deref()
+    }
+
     /// Maps the [`ScalarValue`] type of this [`FieldError`] into the specified
     /// one.
     #[must_use]
@@ -228,6 +396,7 @@ impl<S> FieldError<S> {
         FieldError {
             message: self.message,
             extensions: self.extensions.map_scalar_value(),
+            source: self.source,
         }
     }
 
@@ -237,6 +406,90 @@ impl<S> FieldError<S> {
         Self {
             message: f(self.message),
             extensions: self.extensions,
+            source: self.source,
+        }
+    }
+
+    /// Starts building a new [`FieldError`] with its message, `"code"` and
+    /// other `"extensions"` provided one by one.
+    ///
+    /// ```rust
+    /// use juniper::{graphql_value, FieldError};
+    ///
+    /// let err: FieldError = FieldError::builder()
+    ///     .message("User `42` is not found")
+    ///     .code("NOT_FOUND")
+    ///     .extension("id", 42)
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     err,
+    ///     FieldError::new(
+    ///         "User `42` is not found",
+    ///         graphql_value!({"code": "NOT_FOUND", "id": 42}),
+    ///     ),
+    /// );
+    /// ```
+    pub fn builder() -> FieldErrorBuilder<S> {
+        FieldErrorBuilder {
+            message: String::new(),
+            extensions: Object::with_capacity(0),
+        }
+    }
+}
+
+/// Builder of a [`FieldError`], created via [`FieldError::builder()`].
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct FieldErrorBuilder<S = DefaultScalarValue> {
+    message: String,
+    extensions: Object<S>,
+}
+
+impl<S> FieldErrorBuilder<S> {
+    /// Sets the `"message"` of the built [`FieldError`].
+    ///
+    /// If not set, the message is empty.
+    pub fn message(mut self, message: impl Display) -> Self {
+        self.message = message.to_string();
+        self
+    }
+
+    /// Sets the `"code"` extension of the built [`FieldError`], as commonly
+    /// used to identify the kind of an error by clients.
+    ///
+    /// Same as calling [`FieldErrorBuilder::extension()`] with the `"code"`
+    /// key.
+    pub fn code(self, code: impl Into<String>) -> Self
+    where
+        S: From<String>,
+    {
+        self.extension("code", Value::scalar(code.into()))
+    }
+
+    /// Adds an extension with the provided `key` and `value` to the built
+    /// [`FieldError`].
+    ///
+    /// The `value` of an already added `key` is replaced.
+    pub fn extension(mut self, key: impl Into<String>, value: impl Into<Value<S>>) -> Self {
+        self.extensions.add_field(key.into(), value.into());
+        self
+    }
+
+    /// Builds the [`FieldError`].
+    ///
+    /// If no extensions have been added, then its [`FieldError::extensions()`]
+    /// are [`Value::Null`].
+    #[must_use]
+    pub fn build(self) -> FieldError<S> {
+        FieldError {
+            message: self.message,
+            extensions: if self.extensions.field_count() == 0 {
+                Value::Null
+            } else {
+                Value::Object(self.extensions)
+            },
+            source: None,
         }
     }
 }
@@ -254,6 +507,90 @@ pub type ValuesStream<'a, S = DefaultScalarValue> =
 /// The map of variables used for substitution during query execution
 pub type Variables<S = DefaultScalarValue> = HashMap<String, InputValue<S>>;
 
+/// Builder of [`Variables`] constructed at runtime, as an alternative to the
+/// [`graphql_vars!`] macro.
+///
+/// ```rust
+/// # use juniper::{graphql_vars, Variables, VariablesBuilder};
+/// #
+/// let vars: Variables = VariablesBuilder::new()
+///     .insert("id", 5)
+///     .insert("tags", vec!["new", "hot"])
+///     .insert_enum("status", "ACTIVE")
+///     .insert_null("cursor")
+///     .build();
+///
+/// assert_eq!(
+///     vars,
+///     graphql_vars! {
+///         "id": 5,
+///         "tags": ["new", "hot"],
+///         "status": ACTIVE,
+///         "cursor": null,
+///     },
+/// );
+/// ```
+///
+/// [`graphql_vars!`]: crate::graphql_vars
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct VariablesBuilder<S = DefaultScalarValue> {
+    variables: Variables<S>,
+}
+
+impl<S> Default for VariablesBuilder<S> {
+    fn default() -> Self {
+        Self {
+            variables: Variables::new(),
+        }
+    }
+}
+
+impl<S> VariablesBuilder<S> {
+    /// Creates a new [`VariablesBuilder`] without any variables.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a variable with the provided `name`, converting its `value` via
+    /// the [`ToInputValue`] implementation.
+    ///
+    /// The value of an already added variable is replaced.
+    pub fn insert(self, name: impl Into<String>, value: impl ToInputValue<S>) -> Self {
+        self.insert_input_value(name, value.to_input_value())
+    }
+
+    /// Adds a variable with the provided `name`, holding the GraphQL enum
+    /// `value` of the given name.
+    ///
+    /// The value of an already added variable is replaced.
+    pub fn insert_enum(self, name: impl Into<String>, value: impl AsRef<str>) -> Self {
+        self.insert_input_value(name, InputValue::enum_value(value))
+    }
+
+    /// Adds a variable with the provided `name`, explicitly set to `null`.
+    ///
+    /// The value of an already added variable is replaced.
+    pub fn insert_null(self, name: impl Into<String>) -> Self {
+        self.insert_input_value(name, InputValue::null())
+    }
+
+    /// Adds a variable with the provided `name` and the already constructed
+    /// [`InputValue`].
+    ///
+    /// The value of an already added variable is replaced.
+    pub fn insert_input_value(mut self, name: impl Into<String>, value: InputValue<S>) -> Self {
+        self.variables.insert(name.into(), value);
+        self
+    }
+
+    /// Builds the [`Variables`].
+    #[must_use]
+    pub fn build(self) -> Variables<S> {
+        self.variables
+    }
+}
+
 /// Custom error handling trait to enable error types other than [`FieldError`]
 /// to be specified as return value.
 ///
@@ -295,6 +632,82 @@ impl<'a, S> IntoFieldError<S> for Cow<'a, str> {
     }
 }
 
+/// Extensions of a custom error type, attached as `"extensions"` to the
+/// [`FieldError`] it's converted into.
+///
+/// Implementing this trait along with [`Display`] provides [`IntoFieldError`]
+/// for the error type, using its [`Display`] representation as the error
+/// message. This way, resolvers returning `Result<T, E>` populate the
+/// `"extensions"` of the error response automatically, even when using `?`
+/// operator.
+///
+/// ```rust
+/// # use std::fmt;
+/// #
+/// use juniper::{graphql_object, graphql_value, ErrorExtensions, Value};
+///
+/// enum ApiError {
+///     Unauthenticated,
+///     NotFound(String),
+/// }
+///
+/// impl fmt::Display for ApiError {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         match self {
+///             Self::Unauthenticated => write!(f, "Authentication is required"),
+///             Self::NotFound(id) => write!(f, "User `{id}` is not found"),
+///         }
+///     }
+/// }
+///
+/// impl ErrorExtensions for ApiError {
+///     fn extend(&self) -> Value {
+///         match self {
+///             Self::Unauthenticated => graphql_value!({"code": "UNAUTHENTICATED"}),
+///             Self::NotFound(_) => graphql_value!({"code": "NOT_FOUND"}),
+///         }
+///     }
+/// }
+///
+/// fn authenticate(token: &str) -> Result<(), ApiError> {
+///     if token != "secret" {
+///         return Err(ApiError::Unauthenticated);
+///     }
+///     Ok(())
+/// }
+///
+/// struct Query;
+///
+/// #[graphql_object]
+/// impl Query {
+///     fn user_name(token: String, id: String) -> Result<String, ApiError> {
+///         authenticate(&token)?;
+///         Err(ApiError::NotFound(id))
+///     }
+/// }
+/// ```
+///
+/// Note, that a [`FieldError`] is constructed from any [`Display`] type
+/// without extensions, so they're lost when `?` operator is used in a resolver
+/// returning [`FieldResult`].
+pub trait ErrorExtensions {
+    /// Returns extensions of this error, attached to the [`FieldError`] as
+    /// [`FieldError::extensions()`].
+    ///
+    /// Returning [`Value::Null`] means no extensions.
+    fn extend(&self) -> Value;
+}
+
+impl<S, T> IntoFieldError<S> for T
+where
+    S: ScalarValue,
+    T: ErrorExtensions + Display,
+{
+    fn into_field_error(self) -> FieldError<S> {
+        FieldError::new(&self, self.extend().map_scalar_value())
+    }
+}
+
 #[doc(hidden)]
 pub trait IntoResolvable<'a, S, T, C>
 where
@@ -334,6 +747,22 @@ where
     }
 }
 
+impl<'a, S, T, C, E: IntoFieldError<S>> IntoResolvable<'a, S, Option<T>, C> for Option<Result<T, E>>
+where
+    S: ScalarValue,
+    T: GraphQLValue<S>,
+    T::Context: FromContext<C>,
+{
+    type Type = Option<T>;
+
+    #[allow(clippy::type_complexity)]
+    fn into_resolvable(self, ctx: &'a C) -> FieldResult<Option<(&'a T::Context, Option<T>)>, S> {
+        self.transpose()
+            .map(|v| Some((<T::Context as FromContext<C>>::from(ctx), v)))
+            .map_err(IntoFieldError::into_field_error)
+    }
+}
+
 impl<'a, S, T, C> IntoResolvable<'a, S, T, C> for (&'a T::Context, T)
 where
     S: ScalarValue,
@@ -399,15 +828,95 @@ where
 /// The empty tuple `()` can be converted into from any context type,
 /// making it suitable for `GraphQL` that don't need _any_ context to
 /// work, e.g. scalars or enums.
+///
+/// The conversion is performed whenever a field resolves into an object with
+/// a context type different from the one of the object declaring the field:
+/// ```
+/// # use juniper::{graphql_object, Context, FromContext};
+/// #
+/// struct Database {
+///     user_name: String,
+/// }
+///
+/// impl Context for Database {}
+///
+/// struct AppContext {
+///     db: Database,
+/// }
+///
+/// impl Context for AppContext {}
+///
+/// impl FromContext<AppContext> for Database {
+///     fn from(ctx: &AppContext) -> &Self {
+///         &ctx.db
+///     }
+/// }
+///
+/// struct User;
+///
+/// #[graphql_object(context = Database)]
+/// impl User {
+///     fn name(context: &Database) -> &str {
+///         &context.user_name
+///     }
+/// }
+///
+/// struct Query;
+///
+/// #[graphql_object(context = AppContext)]
+/// impl Query {
+///     // Resolving the `User` converts the `AppContext` into the `Database`.
+///     fn user() -> User {
+///         User
+///     }
+/// }
+/// ```
 pub trait FromContext<T> {
     /// Perform the conversion
     fn from(value: &T) -> &Self;
 }
 
 /// Marker trait for types that can act as context objects for `GraphQL` types.
-pub trait Context {}
+///
+/// Besides being converted statically via [`FromContext`], a context may
+/// expose sub-contexts of arbitrary types at runtime via the
+/// [`Context::sub_context()`] method, so they can be looked up with the
+/// [`Executor::context_as()`] method by resolvers being agnostic to the
+/// concrete context type.
+pub trait Context {
+    /// Returns the sub-context of the type identified by the provided
+    /// `type_id`, if this context holds one.
+    ///
+    /// The returned value must be of the type identified by the `type_id`,
+    /// otherwise it's ignored by the [`Executor::context_as()`] method.
+    ///
+    /// By default, no sub-contexts are exposed.
+    ///
+    /// ```
+    /// # use std::{any::{Any, TypeId}, collections::HashMap};
+    /// # use juniper::Context;
+    /// #
+    /// struct AppContext {
+    ///     extensions: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
+    /// }
+    ///
+    /// impl Context for AppContext {
+    ///     fn sub_context(&self, type_id: TypeId) -> Option<&dyn Any> {
+    ///         self.extensions.get(&type_id).map(|ext| &**ext as &dyn Any)
+    ///     }
+    /// }
+    /// ```
+    fn sub_context(&self, type_id: TypeId) -> Option<&dyn Any> {
+        let _ = type_id;
+        None
+    }
+}
 
-impl<'a, C: Context> Context for &'a C {}
+impl<'a, C: Context> Context for &'a C {
+    fn sub_context(&self, type_id: TypeId) -> Option<&dyn Any> {
+        (**self).sub_context(type_id)
+    }
+}
 
 static NULL_CONTEXT: () = ();
 
@@ -430,6 +939,15 @@ impl<'r, 'a, CtxT, S> Executor<'r, 'a, CtxT, S>
 where
     S: ScalarValue,
 {
+    /// Creates a [`NaiveExecutor`] for unit-testing resolvers with the
+    /// provided `context` and `variables`, without building a whole
+    /// [`RootNode`].
+    ///
+    /// See [`NaiveExecutor`] for its limitations.
+    pub fn for_test(context: &'a CtxT, variables: Variables<S>) -> NaiveExecutor<'a, CtxT, S> {
+        NaiveExecutor { context, variables }
+    }
+
     /// Resolve a single arbitrary value into a stream of [`Value`]s.
     /// If a field fails to resolve, pushes error to `Executor`
     /// and returns `Value::Null`.
@@ -560,11 +1078,18 @@ where
             variables: self.variables,
             current_selection_set: self.current_selection_set,
             parent_selection_set: self.parent_selection_set,
+            root_selection_set: self.root_selection_set,
+            root_type: self.root_type,
+            parent_type: self.parent_type,
             current_type: self.current_type.clone(),
             schema: self.schema,
             context: ctx,
             errors: self.errors,
+            deadline: self.deadline.clone(),
+            postponement: self.postponement.clone(),
+            stream: self.stream.clone(),
             field_path: self.field_path.clone(),
+            field_arguments: self.field_arguments,
         }
     }
 
@@ -575,28 +1100,64 @@ where
         field_name: &'s str,
         location: SourcePosition,
         selection_set: Option<&'s [Selection<'a, S>]>,
+        arguments: Option<&'s Spanning<ast::Arguments<'a, S>>>,
     ) -> Executor<'s, 'a, CtxT, S> {
+        let field = self
+            .current_type
+            .innermost_concrete()
+            .field_by_name(field_name)
+            .expect("Field not found on inner type");
+
         Executor {
             fragments: self.fragments,
             variables: self.variables,
             current_selection_set: selection_set,
             parent_selection_set: self.current_selection_set,
-            current_type: self.schema.make_type(
-                &self
-                    .current_type
-                    .innermost_concrete()
-                    .field_by_name(field_name)
-                    .expect("Field not found on inner type")
-                    .field_type,
-            ),
+            root_selection_set: self.root_selection_set,
+            root_type: self.root_type,
+            parent_type: Some(self.current_type.innermost_concrete()),
+            current_type: self.schema.make_type(&field.field_type),
             schema: self.schema,
             context: self.context,
             errors: self.errors,
+            deadline: self.deadline.clone(),
+            postponement: self.postponement.as_ref().map(Postponement::sub_value),
+            stream: None,
             field_path: Arc::new(FieldPath::Field(
                 field_alias,
                 location,
                 Arc::clone(&self.field_path),
             )),
+            field_arguments: Some(FieldArguments {
+                specified: arguments,
+                declared: &field.arguments,
+            }),
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn index_sub_executor(&self, index: usize) -> Executor<'r, 'a, CtxT, S> {
+        Executor {
+            fragments: self.fragments,
+            variables: self.variables,
+            current_selection_set: self.current_selection_set,
+            parent_selection_set: self.parent_selection_set,
+            root_selection_set: self.root_selection_set,
+            root_type: self.root_type,
+            parent_type: self.parent_type,
+            current_type: self
+                .current_type
+                .list_contents()
+                .unwrap_or(&self.current_type)
+                .clone(),
+            schema: self.schema,
+            context: self.context,
+            errors: self.errors,
+            deadline: self.deadline.clone(),
+            postponement: self.postponement.as_ref().map(Postponement::sub_value),
+            stream: None,
+            field_path: Arc::new(FieldPath::Index(index, Arc::clone(&self.field_path))),
+            field_arguments: self.field_arguments,
         }
     }
 
@@ -611,6 +1172,9 @@ where
             variables: self.variables,
             current_selection_set: selection_set,
             parent_selection_set: self.current_selection_set,
+            root_selection_set: self.root_selection_set,
+            root_type: self.root_type,
+            parent_type: self.parent_type,
             current_type: match type_name {
                 Some(type_name) => self.schema.type_by_name(type_name).expect("Type not found"),
                 None => self.current_type.clone(),
@@ -618,7 +1182,11 @@ where
             schema: self.schema,
             context: self.context,
             errors: self.errors,
+            deadline: self.deadline.clone(),
+            postponement: self.postponement.as_ref().map(Postponement::sub_value),
+            stream: self.stream.clone(),
             field_path: self.field_path.clone(),
+            field_arguments: self.field_arguments,
         }
     }
 
@@ -627,6 +1195,85 @@ where
         self.current_selection_set
     }
 
+    /// [`Deadline`] of the current execution, if any.
+    pub(crate) fn deadline(&self) -> Option<&Deadline> {
+        self.deadline.as_deref()
+    }
+
+    /// [`Postponement`] of the incremental delivery resolved by this
+    /// [`Executor`], if any.
+    pub(crate) fn postponement(&self) -> Option<&Postponement<S>> {
+        self.postponement.as_ref()
+    }
+
+    /// Makes this [`Executor`] report the value it resolves into the provided
+    /// `early` slot, in case it resolves an incremental delivery.
+    #[must_use]
+    pub(crate) fn reporting_into(mut self, early: &Arc<EarlyValue<S>>) -> Self {
+        self.postponement = self.postponement.map(|p| p.reporting_into(early));
+        self
+    }
+
+    /// Makes this [`Executor`] resolve a postponed result of an incremental
+    /// delivery, according to the provided `postponement`, while collecting
+    /// its own `errors`.
+    pub(crate) fn postponed<'e>(
+        self,
+        postponement: Postponement<S>,
+        errors: &'e RwLock<Vec<ExecutionError<S>>>,
+    ) -> Executor<'e, 'a, CtxT, S>
+    where
+        'r: 'e,
+    {
+        Executor {
+            fragments: self.fragments,
+            variables: self.variables,
+            current_selection_set: self.current_selection_set,
+            parent_selection_set: self.parent_selection_set,
+            root_selection_set: self.root_selection_set,
+            root_type: self.root_type,
+            parent_type: self.parent_type,
+            current_type: self.current_type,
+            schema: self.schema,
+            context: self.context,
+            errors,
+            deadline: self.deadline,
+            postponement: Some(postponement),
+            stream: self.stream,
+            field_path: self.field_path,
+            field_arguments: self.field_arguments,
+        }
+    }
+
+    /// Makes this [`Executor`] postpone the items of the list field with the
+    /// provided `directives` beyond the `initialCount`, in case it's
+    /// `@stream`ed while resolving an incremental delivery.
+    #[must_use]
+    pub(crate) fn with_stream(mut self, directives: &Option<Vec<Spanning<Directive<S>>>>) -> Self
+    where
+        S: ScalarValue,
+    {
+        if self.postponement.is_some() {
+            self.stream = incremental::stream(directives, self.variables);
+        }
+        self
+    }
+
+    /// Parsed `@stream` directive of the list field resolved by this
+    /// [`Executor`], in case it's resolving an incremental delivery.
+    pub(crate) fn stream(&self) -> Option<&incremental::Stream> {
+        self.stream.as_ref()
+    }
+
+    /// [Response path][0] of the value resolved by this [`Executor`].
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Errors.Error-result-format
+    pub(crate) fn response_path(&self) -> Vec<PathSegment> {
+        let mut path = Vec::new();
+        self.field_path.construct_path(&mut path);
+        path
+    }
+
     /// Access the current context
     ///
     /// You usually provide the context when calling the top-level `execute`
@@ -635,8 +1282,64 @@ where
         self.context
     }
 
+    /// Downcasts the current context to the concrete type `T`, or looks up its
+    /// sub-context of type `T` (see [`Context::sub_context()`]) otherwise.
+    ///
+    /// Returns [`None`] if the current context is neither of type `T`, nor
+    /// holds a sub-context of it.
+    ///
+    /// Unlike [`FromContext`], which converts contexts statically when the
+    /// context type is switched, this lookup happens at runtime, allowing
+    /// resolvers to be agnostic to the concrete context type.
+    pub fn context_as<T: Any>(&self) -> Option<&'r T>
+    where
+        CtxT: Context + 'static,
+    {
+        let context: &'r CtxT = self.context;
+        if let Some(ctx) = (context as &dyn Any).downcast_ref::<T>() {
+            return Some(ctx);
+        }
+        context
+            .sub_context(TypeId::of::<T>())
+            .and_then(<dyn Any>::downcast_ref)
+    }
+
+    /// Returns the [`Arguments`] of the currently resolved field, as they've
+    /// been specified in the query, before being coerced into any Rust types.
+    ///
+    /// Variable references are already replaced with their values, and the
+    /// omitted arguments having default values are present. Otherwise, every
+    /// [`InputValue`] is kept as is, so enum values stay [`InputValue::Enum`]s
+    /// rather than becoming strings, for example. This allows forwarding the
+    /// arguments somewhere further (like to a downstream service) without
+    /// re-serializing their coerced Rust types.
+    ///
+    /// Empty, unless this [`Executor`] resolves a field.
+    ///
+    /// The [`Arguments`] are built on every call, so nothing is spent on them
+    /// while resolving the fields, which don't need them.
+    pub fn raw_arguments(&self) -> Arguments<'_, S> {
+        match self.field_arguments {
+            Some(args) => Arguments::new(
+                args.specified.map(|m| {
+                    m.item
+                        .iter()
+                        .filter_map(|(k, v)| {
+                            v.item
+                                .clone()
+                                .into_const(self.variables)
+                                .map(|v| (k.item, v))
+                        })
+                        .collect()
+                }),
+                args.declared,
+            ),
+            None => Arguments::new(None, &None),
+        }
+    }
+
     /// The currently executing schema
-    pub fn schema(&self) -> &'a SchemaType<S> {
+    pub fn schema(&self) -> &'a SchemaType<'a, S> {
         self.schema
     }
 
@@ -696,10 +1399,7 @@ where
     /// This allows seeing the whole selection and perform operations
     /// affecting the children.
     pub fn look_ahead(&'a self) -> LookAheadSelection<'a, S> {
-        let field_name = match *self.field_path {
-            FieldPath::Field(x, ..) => x,
-            FieldPath::Root(_) => unreachable!(),
-        };
+        let field_name = self.field_path.field_name().unwrap();
         self.parent_selection_set
             .and_then(|p| {
                 // Search the parent's fields to find this field within the set
@@ -716,7 +1416,13 @@ where
                     }
                 });
                 if let Some(p) = found_field {
-                    LookAheadSelection::build_from_selection(p, self.variables, self.fragments)
+                    LookAheadSelection::build_from_selection_with_parent(
+                        p,
+                        None,
+                        self.variables,
+                        self.fragments,
+                        self.parent_type.map(|t| (self.schema, t)),
+                    )
                 } else {
                     None
                 }
@@ -739,6 +1445,7 @@ where
                             Some(&mut ret),
                             self.variables,
                             self.fragments,
+                            Some((self.schema, self.current_type.innermost_concrete())),
                         );
                     }
                 }
@@ -746,6 +1453,47 @@ where
             })
     }
 
+    /// Constructs a lookahead selection rooted at the executed operation.
+    ///
+    /// Unlike [`Executor::look_ahead()`], this allows inspecting the whole
+    /// selection of the operation from any resolver (for example, the fields
+    /// selected by a parent or a sibling of the current field). The returned
+    /// [`LookAheadSelection`] represents the operation itself, so its
+    /// [`field_name()`] is empty, while its children are the root fields of the
+    /// operation. Use [`Executor::look_ahead_path()`] to find the current
+    /// field in it.
+    ///
+    /// Same as the one returned by [`Executor::look_ahead()`], the returned
+    /// [`LookAheadSelection`] borrows this [`Executor`], so cannot outlive it.
+    ///
+    /// [`field_name()`]: LookAheadMethods::field_name
+    pub fn root_look_ahead(&'a self) -> LookAheadSelection<'a, S> {
+        let mut ret = LookAheadSelection::default();
+        for c in self.root_selection_set.into_iter().flatten() {
+            LookAheadSelection::build_from_selection_with_parent(
+                c,
+                Some(&mut ret),
+                self.variables,
+                self.fragments,
+                Some((self.schema, self.root_type)),
+            );
+        }
+        ret
+    }
+
+    /// Returns [response names][0] of the fields leading from the executed
+    /// operation to the current field (inclusive), omitting list indices.
+    ///
+    /// Suitable for locating the current field in the selection returned by
+    /// [`Executor::root_look_ahead()`] via the [`LookAheadMethods::select_path()`].
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Field-Alias
+    pub fn look_ahead_path(&self) -> Vec<&'a str> {
+        let mut path = Vec::new();
+        self.field_path.construct_field_names(&mut path);
+        path
+    }
+
     /// Create new `OwnedExecutor` and clone all current data
     /// (except for errors) there
     ///
@@ -759,29 +1507,65 @@ where
             variables: self.variables.clone(),
             current_selection_set: self.current_selection_set.map(|x| x.to_vec()),
             parent_selection_set: self.parent_selection_set.map(|x| x.to_vec()),
+            root_selection_set: self.root_selection_set.map(|x| x.to_vec()),
+            root_type: self.root_type,
+            parent_type: self.parent_type,
             current_type: self.current_type.clone(),
             schema: self.schema,
             context: self.context,
             errors: RwLock::new(vec![]),
+            deadline: self.deadline.clone(),
             field_path: Arc::clone(&self.field_path),
+            specified_arguments: self
+                .field_arguments
+                .and_then(|args| args.specified)
+                .cloned(),
+            declared_arguments: self.field_arguments.map(|args| args.declared),
         }
     }
 }
 
 impl<'a> FieldPath<'a> {
-    fn construct_path(&self, acc: &mut Vec<String>) {
+    fn construct_path(&self, acc: &mut Vec<PathSegment>) {
         match self {
             FieldPath::Root(_) => (),
             FieldPath::Field(name, _, parent) => {
                 parent.construct_path(acc);
-                acc.push((*name).into());
+                acc.push(PathSegment::Field((*name).into()));
+            }
+            FieldPath::Index(idx, parent) => {
+                parent.construct_path(acc);
+                acc.push(PathSegment::Index(*idx));
+            }
+        }
+    }
+
+    /// Collects the names of all the fields of this [`FieldPath`] into the
+    /// provided `acc`umulator, starting from the outermost one.
+    fn construct_field_names(&self, acc: &mut Vec<&'a str>) {
+        match *self {
+            FieldPath::Root(_) => (),
+            FieldPath::Field(name, _, ref parent) => {
+                parent.construct_field_names(acc);
+                acc.push(name);
             }
+            FieldPath::Index(_, ref parent) => parent.construct_field_names(acc),
         }
     }
 
     fn location(&self) -> &SourcePosition {
         match *self {
             FieldPath::Root(ref pos) | FieldPath::Field(_, ref pos, _) => pos,
+            FieldPath::Index(_, ref parent) => parent.location(),
+        }
+    }
+
+    /// Returns the name of the innermost field of this [`FieldPath`], if any.
+    fn field_name(&self) -> Option<&'a str> {
+        match *self {
+            FieldPath::Root(_) => None,
+            FieldPath::Field(name, ..) => Some(name),
+            FieldPath::Index(_, ref parent) => parent.field_name(),
         }
     }
 }
@@ -791,7 +1575,23 @@ impl<S> ExecutionError<S> {
     pub fn new(location: SourcePosition, path: &[&str], error: FieldError<S>) -> ExecutionError<S> {
         ExecutionError {
             location,
-            path: path.iter().map(|s| (*s).into()).collect(),
+            path: path
+                .iter()
+                .map(|s| PathSegment::Field((*s).into()))
+                .collect(),
+            error,
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn new_with_path(
+        location: SourcePosition,
+        path: &[PathSegment],
+        error: FieldError<S>,
+    ) -> ExecutionError<S> {
+        ExecutionError {
+            location,
+            path: path.to_vec(),
             error,
         }
     }
@@ -806,8 +1606,11 @@ impl<S> ExecutionError<S> {
         &self.location
     }
 
-    /// The path of fields leading to the field that generated this error
-    pub fn path(&self) -> &[String] {
+    /// The [response path][0] leading to the field that generated this
+    /// error, consisting of field names and list indices
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Errors.Error-result-format
+    pub fn path(&self) -> &[PathSegment] {
         &self.path
     }
 }
@@ -821,6 +1624,25 @@ pub fn execute_validated_query<'b, QueryT, MutationT, SubscriptionT, S>(
     variables: &Variables<S>,
     context: &QueryT::Context,
 ) -> Result<(Value<S>, Vec<ExecutionError<S>>), GraphQLError>
+where
+    S: ScalarValue,
+    QueryT: GraphQLType<S>,
+    MutationT: GraphQLType<S, Context = QueryT::Context>,
+    SubscriptionT: GraphQLType<S, Context = QueryT::Context>,
+{
+    execute_validated_query_impl(document, operation, root_node, variables, context, None)
+}
+
+/// Same as [`execute_validated_query()`], but reuses the already estimated
+/// `cost` of the `operation`, if any.
+pub(crate) fn execute_validated_query_impl<'b, QueryT, MutationT, SubscriptionT, S>(
+    document: &'b Document<S>,
+    operation: &'b Spanning<Operation<S>>,
+    root_node: &RootNode<QueryT, MutationT, SubscriptionT, S>,
+    variables: &Variables<S>,
+    context: &QueryT::Context,
+    cost: Option<u64>,
+) -> Result<(Value<S>, Vec<ExecutionError<S>>), GraphQLError>
 where
     S: ScalarValue,
     QueryT: GraphQLType<S>,
@@ -831,6 +1653,12 @@ where
         return Err(GraphQLError::IsSubscription);
     }
 
+    if let Some(e) =
+        pre_execution::before_execution(root_node, document, operation, variables, context, cost)?
+    {
+        return Ok((Value::null(), vec![e]));
+    }
+
     let mut fragments = vec![];
     for def in document.iter() {
         if let Definition::Fragment(f) = def {
@@ -884,11 +1712,18 @@ where
             variables: final_vars,
             current_selection_set: Some(&operation.item.selection_set[..]),
             parent_selection_set: None,
+            root_selection_set: Some(&operation.item.selection_set[..]),
+            root_type: root_type.innermost_concrete(),
+            parent_type: None,
             current_type: root_type,
             schema: &root_node.schema,
             context,
             errors: &errors,
+            deadline: None,
+            postponement: None,
+            stream: None,
             field_path: Arc::new(FieldPath::Root(operation.start)),
+            field_arguments: None,
         };
 
         value = match operation.item.operation_type {
@@ -906,6 +1741,167 @@ where
     Ok((value, errors))
 }
 
+/// Resolves the canonical introspection query in the provided `format` against
+/// the provided `schema` directly, without validating the query and without
+/// requiring the context of the schema's root types.
+pub(crate) fn resolve_introspection<S>(
+    schema: &SchemaType<S>,
+    format: IntrospectionFormat,
+) -> Value<S>
+where
+    S: ScalarValue,
+{
+    let query = match format {
+        IntrospectionFormat::All => INTROSPECTION_QUERY,
+        IntrospectionFormat::WithoutDescriptions => INTROSPECTION_QUERY_WITHOUT_DESCRIPTIONS,
+    };
+    resolve_introspection_query(schema, query, None)
+}
+
+/// Resolves the provided introspection `query` against the provided `schema`
+/// directly, the same way as [`resolve_introspection()`] does.
+///
+/// Only the `__schema` and `__type` top-level fields are supported, the latter
+/// with a literal `name` argument only.
+///
+/// If the `types` names are provided, the `types` field of `__schema` is
+/// resolved to these types only, each one directly as a `__Type`, so none of
+/// the other types of the `schema` is resolved.
+pub(crate) fn resolve_introspection_query<S>(
+    schema: &SchemaType<S>,
+    query: &str,
+    types: Option<&[&str]>,
+) -> Value<S>
+where
+    S: ScalarValue,
+{
+    // The limits of the schema are meant for the queries of its users, not
+    // for the internal ones.
+    let document = parse_document_source_with_options(query, schema, ParseOptions::default())
+        .expect("introspection query should be parsable");
+    let operation =
+        get_operation(&document, None).expect("introspection query should have one operation");
+
+    let fragments = document
+        .iter()
+        .filter_map(|def| match def {
+            Definition::Fragment(f) => Some((f.item.name.item, f.item.clone())),
+            Definition::Operation(_) => None,
+        })
+        .collect();
+
+    let mut result = Object::with_capacity(operation.item.selection_set.len());
+    for selection in &operation.item.selection_set {
+        let field = match selection {
+            Selection::Field(f) => f,
+            Selection::FragmentSpread(_) | Selection::InlineFragment(_) => continue,
+        };
+
+        let variables = Variables::new();
+        let errors = RwLock::new(Vec::new());
+        let executor = |current_type, current_selection_set| Executor {
+            fragments: &fragments,
+            variables: &variables,
+            current_selection_set,
+            parent_selection_set: Some(&operation.item.selection_set[..]),
+            root_selection_set: Some(&operation.item.selection_set[..]),
+            root_type: schema.concrete_query_type(),
+            parent_type: Some(schema.concrete_query_type()),
+            current_type,
+            schema,
+            context: schema,
+            errors: &errors,
+            deadline: None,
+            postponement: None,
+            stream: None,
+            field_path: Arc::new(FieldPath::Field(
+                field.item.name.item,
+                field.start,
+                Arc::new(FieldPath::Root(operation.start)),
+            )),
+            field_arguments: None,
+        };
+        let type_type = || {
+            schema
+                .type_by_name("__Type")
+                .expect("`__Type` type is always registered")
+        };
+        let schema_type = || {
+            schema
+                .type_by_name("__Schema")
+                .expect("`__Schema` type is always registered")
+        };
+        let value = match (field.item.name.item, types) {
+            ("__type", _) => {
+                let name = field
+                    .item
+                    .arguments
+                    .as_ref()
+                    .and_then(|args| args.item.get("name"))
+                    .and_then(|name| name.item.as_string_value())
+                    .expect("`__type` field should have a literal `name` argument");
+                executor(type_type(), field.item.selection_set.as_deref())
+                    .resolve_into_value(&(), &schema.type_by_name(name))
+            }
+            (_, None) => executor(schema_type(), field.item.selection_set.as_deref())
+                .resolve_into_value(&(), schema),
+            (_, Some(names)) => {
+                let selection_set = field.item.selection_set.as_deref().unwrap_or_default();
+                let is_types = |s: &Selection<S>| matches!(s, Selection::Field(f) if f.item.name.item == "types");
+                let rest = selection_set
+                    .iter()
+                    .filter(|s| !is_types(s))
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                let mut resolved = match executor(schema_type(), Some(&rest[..]))
+                    .resolve_into_value(&(), schema)
+                {
+                    Value::Object(o) => o,
+                    v => unreachable!("`__Schema` should be resolved to an object, got: {v}"),
+                };
+                // Preserve the order of the fields in the `selection_set`.
+                let mut object = Object::with_capacity(resolved.field_count() + 1);
+                for f in selection_set.iter().filter_map(|s| match s {
+                    Selection::Field(f) => Some(&f.item),
+                    Selection::FragmentSpread(_) | Selection::InlineFragment(_) => None,
+                }) {
+                    let key = f.alias.unwrap_or(f.name).item;
+                    let value = if f.name.item == "types" {
+                        Value::list(
+                            names
+                                .iter()
+                                .map(|name| {
+                                    executor(type_type(), f.selection_set.as_deref())
+                                        .resolve_into_value(&(), &schema.type_by_name(name))
+                                })
+                                .collect(),
+                        )
+                    } else if let Some(value) = resolved.remove_field(key) {
+                        value
+                    } else {
+                        continue;
+                    };
+                    object.add_field(key, value);
+                }
+                for (key, value) in resolved {
+                    object.add_field(key, value);
+                }
+                Value::Object(object)
+            }
+        };
+
+        debug_assert!(
+            errors.into_inner().unwrap().is_empty(),
+            "introspection resolvers shouldn't fail",
+        );
+
+        result.add_field(field.item.alias.unwrap_or(field.item.name).item, value);
+    }
+
+    Value::Object(result)
+}
+
 /// Create new `Executor` and start asynchronous query execution.
 /// Returns `IsSubscription` error if subscription is passed.
 pub async fn execute_validated_query_async<'a, 'b, QueryT, MutationT, SubscriptionT, S>(
@@ -915,6 +1911,126 @@ pub async fn execute_validated_query_async<'a, 'b, QueryT, MutationT, Subscripti
     variables: &Variables<S>,
     context: &QueryT::Context,
 ) -> Result<(Value<S>, Vec<ExecutionError<S>>), GraphQLError>
+where
+    QueryT: GraphQLTypeAsync<S>,
+    QueryT::TypeInfo: Sync,
+    QueryT::Context: Sync,
+    MutationT: GraphQLTypeAsync<S, Context = QueryT::Context>,
+    MutationT::TypeInfo: Sync,
+    SubscriptionT: GraphQLType<S, Context = QueryT::Context> + Sync,
+    SubscriptionT::TypeInfo: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    execute_validated_query_async_impl(
+        document,
+        operation,
+        root_node,
+        variables,
+        context,
+        ExecutionParams::default(),
+    )
+    .await
+}
+
+/// Create new `Executor` and start asynchronous query execution, which stops
+/// awaiting the unresolved fields once the provided `deadline` completes.
+/// Returns `IsSubscription` error if subscription is passed.
+///
+/// See [`execute_with_deadline()`] for details.
+///
+/// [`execute_with_deadline()`]: crate::execute_with_deadline
+pub async fn execute_validated_query_async_with_deadline<
+    'a,
+    'b,
+    QueryT,
+    MutationT,
+    SubscriptionT,
+    S,
+>(
+    document: &'b Document<'a, S>,
+    operation: &'b Spanning<Operation<'_, S>>,
+    root_node: &RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+    variables: &Variables<S>,
+    context: &QueryT::Context,
+    deadline: impl Future<Output = ()>,
+) -> Result<(Value<S>, Vec<ExecutionError<S>>), GraphQLError>
+where
+    QueryT: GraphQLTypeAsync<S>,
+    QueryT::TypeInfo: Sync,
+    QueryT::Context: Sync,
+    MutationT: GraphQLTypeAsync<S, Context = QueryT::Context>,
+    MutationT::TypeInfo: Sync,
+    SubscriptionT: GraphQLType<S, Context = QueryT::Context> + Sync,
+    SubscriptionT::TypeInfo: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    execute_validated_query_async_with_deadline_impl(
+        document, operation, root_node, variables, context, None, deadline,
+    )
+    .await
+}
+
+/// Same as [`execute_validated_query_async_with_deadline()`], but reuses the
+/// already estimated `cost` of the `operation`, if any.
+pub(crate) async fn execute_validated_query_async_with_deadline_impl<
+    'a,
+    'b,
+    QueryT,
+    MutationT,
+    SubscriptionT,
+    S,
+>(
+    document: &'b Document<'a, S>,
+    operation: &'b Spanning<Operation<'_, S>>,
+    root_node: &RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+    variables: &Variables<S>,
+    context: &QueryT::Context,
+    cost: Option<u64>,
+    deadline: impl Future<Output = ()>,
+) -> Result<(Value<S>, Vec<ExecutionError<S>>), GraphQLError>
+where
+    QueryT: GraphQLTypeAsync<S>,
+    QueryT::TypeInfo: Sync,
+    QueryT::Context: Sync,
+    MutationT: GraphQLTypeAsync<S, Context = QueryT::Context>,
+    MutationT::TypeInfo: Sync,
+    SubscriptionT: GraphQLType<S, Context = QueryT::Context> + Sync,
+    SubscriptionT::TypeInfo: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    let state = Arc::new(Deadline::default());
+    let execution = execute_validated_query_async_impl(
+        document,
+        operation,
+        root_node,
+        variables,
+        context,
+        ExecutionParams {
+            cost,
+            deadline: Some(Arc::clone(&state)),
+            ..ExecutionParams::default()
+        },
+    );
+    state.drive(execution, deadline).await
+}
+
+/// Same as [`execute_validated_query_async()`], but executes the `operation`
+/// according to the provided [`ExecutionParams`].
+pub(crate) async fn execute_validated_query_async_impl<
+    'a,
+    'b,
+    QueryT,
+    MutationT,
+    SubscriptionT,
+    S,
+>(
+    document: &'b Document<'a, S>,
+    operation: &'b Spanning<Operation<'_, S>>,
+    root_node: &RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+    variables: &Variables<S>,
+    context: &QueryT::Context,
+    params: ExecutionParams<S>,
+) -> Result<(Value<S>, Vec<ExecutionError<S>>), GraphQLError>
 where
     QueryT: GraphQLTypeAsync<S>,
     QueryT::TypeInfo: Sync,
@@ -929,6 +2045,49 @@ where
         return Err(GraphQLError::IsSubscription);
     }
 
+    if let Some(e) = pre_execution::before_execution(
+        root_node,
+        document,
+        operation,
+        variables,
+        context,
+        params.cost,
+    )? {
+        return Ok((Value::null(), vec![e]));
+    }
+
+    Ok(resolve_validated_operation_async(
+        document, operation, root_node, variables, context, params,
+    )
+    .await)
+}
+
+/// Resolves the already validated query or mutation `operation`, which has
+/// already passed the [`PreExecution`] hooks, according to the provided
+/// [`ExecutionParams`].
+///
+/// In case of resolving the `operation` into a [`Delivery`], the returned
+/// [`Value`] is always [`Value::Null`], while the resolved data is delivered
+/// by the [`Delivery`] along with the errors, except the ones occurred after
+/// the payload containing them has been delivered already.
+pub(crate) async fn resolve_validated_operation_async<'a, 'b, QueryT, MutationT, SubscriptionT, S>(
+    document: &'b Document<'a, S>,
+    operation: &'b Spanning<Operation<'_, S>>,
+    root_node: &RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+    variables: &Variables<S>,
+    context: &QueryT::Context,
+    params: ExecutionParams<S>,
+) -> (Value<S>, Vec<ExecutionError<S>>)
+where
+    QueryT: GraphQLTypeAsync<S>,
+    QueryT::TypeInfo: Sync,
+    QueryT::Context: Sync,
+    MutationT: GraphQLTypeAsync<S, Context = QueryT::Context>,
+    MutationT::TypeInfo: Sync,
+    SubscriptionT: GraphQLType<S, Context = QueryT::Context> + Sync,
+    SubscriptionT::TypeInfo: Sync,
+    S: ScalarValue + Send + Sync,
+{
     let mut fragments = vec![];
     for def in document.iter() {
         if let Definition::Fragment(f) = def {
@@ -949,6 +2108,7 @@ where
     });
 
     let errors = RwLock::new(Vec::new());
+    let early = Arc::new(EarlyValue::default());
     let value;
 
     {
@@ -982,32 +2142,58 @@ where
             variables: final_vars,
             current_selection_set: Some(&operation.item.selection_set[..]),
             parent_selection_set: None,
+            root_selection_set: Some(&operation.item.selection_set[..]),
+            root_type: root_type.innermost_concrete(),
+            parent_type: None,
             current_type: root_type,
             schema: &root_node.schema,
             context,
             errors: &errors,
+            deadline: params.deadline,
+            postponement: params
+                .delivery
+                .as_ref()
+                .map(|d| Postponement::initial(Arc::clone(d), Arc::clone(&early))),
+            stream: None,
             field_path: Arc::new(FieldPath::Root(operation.start)),
+            field_arguments: None,
         };
 
-        value = match operation.item.operation_type {
-            OperationType::Query => {
-                executor
-                    .resolve_into_value_async(&root_node.query_info, &root_node)
-                    .await
+        let resolve = async {
+            match operation.item.operation_type {
+                OperationType::Query => {
+                    executor
+                        .resolve_into_value_async(&root_node.query_info, &root_node)
+                        .await
+                }
+                OperationType::Mutation => {
+                    executor
+                        .resolve_into_value_async(
+                            &root_node.mutation_info,
+                            &root_node.mutation_type,
+                        )
+                        .await
+                }
+                OperationType::Subscription => unreachable!(),
             }
-            OperationType::Mutation => {
-                executor
-                    .resolve_into_value_async(&root_node.mutation_info, &root_node.mutation_type)
-                    .await
+        };
+
+        value = match &params.delivery {
+            Some(delivery) => {
+                let mut root = incremental::Part::new(early, Box::pin(resolve));
+                let data = root.value().await;
+                delivery.deliver_initial(data, incremental::take_errors(&errors));
+                root.complete().await;
+                Value::null()
             }
-            OperationType::Subscription => unreachable!(),
+            None => resolve.await,
         };
     }
 
     let mut errors = errors.into_inner().unwrap();
     errors.sort();
 
-    Ok((value, errors))
+    (value, errors)
 }
 
 #[doc(hidden)]
@@ -1059,6 +2245,42 @@ pub async fn resolve_validated_subscription<
     variables: &Variables<S>,
     context: &'r QueryT::Context,
 ) -> Result<(Value<ValuesStream<'r, S>>, Vec<ExecutionError<S>>), GraphQLError>
+where
+    'r: 'exec_ref,
+    'd: 'r,
+    'op: 'd,
+    QueryT: GraphQLTypeAsync<S>,
+    QueryT::TypeInfo: Sync,
+    QueryT::Context: Sync + 'r,
+    MutationT: GraphQLTypeAsync<S, Context = QueryT::Context>,
+    MutationT::TypeInfo: Sync,
+    SubscriptionT: GraphQLSubscriptionType<S, Context = QueryT::Context>,
+    SubscriptionT::TypeInfo: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    resolve_validated_subscription_impl(document, operation, root_node, variables, context, None)
+        .await
+}
+
+/// Same as [`resolve_validated_subscription()`], but reuses the already
+/// estimated `cost` of the `operation`, if any.
+pub(crate) async fn resolve_validated_subscription_impl<
+    'r,
+    'exec_ref,
+    'd,
+    'op,
+    QueryT,
+    MutationT,
+    SubscriptionT,
+    S,
+>(
+    document: &Document<'d, S>,
+    operation: &Spanning<Operation<'op, S>>,
+    root_node: &'r RootNode<'r, QueryT, MutationT, SubscriptionT, S>,
+    variables: &Variables<S>,
+    context: &'r QueryT::Context,
+    cost: Option<u64>,
+) -> Result<(Value<ValuesStream<'r, S>>, Vec<ExecutionError<S>>), GraphQLError>
 where
     'r: 'exec_ref,
     'd: 'r,
@@ -1076,6 +2298,12 @@ where
         return Err(GraphQLError::NotSubscription);
     }
 
+    if let Some(e) =
+        pre_execution::before_execution(root_node, document, operation, variables, context, cost)?
+    {
+        return Ok((Value::null(), vec![e]));
+    }
+
     let mut fragments = vec![];
     for def in document.iter() {
         if let Definition::Fragment(f) = def {
@@ -1128,11 +2356,18 @@ where
             variables: final_vars,
             current_selection_set: Some(&operation.item.selection_set[..]),
             parent_selection_set: None,
+            root_selection_set: Some(&operation.item.selection_set[..]),
+            root_type: root_type.innermost_concrete(),
+            parent_type: None,
             current_type: root_type,
             schema: &root_node.schema,
             context,
             errors: &errors,
+            deadline: None,
+            postponement: None,
+            stream: None,
             field_path: Arc::new(FieldPath::Root(operation.start)),
+            field_arguments: None,
         };
 
         value = match operation.item.operation_type {
@@ -1195,6 +2430,7 @@ impl<'r, S: 'r> Registry<'r, S> {
             arguments: None,
             field_type: self.get_type::<T>(info),
             deprecation_status: DeprecationStatus::Current,
+            complexity: Complexity::default(),
         }
     }
 
@@ -1214,6 +2450,7 @@ impl<'r, S: 'r> Registry<'r, S> {
             arguments: None,
             field_type: self.get_type::<I>(info),
             deprecation_status: DeprecationStatus::Current,
+            complexity: Complexity::default(),
         }
     }
 
@@ -0,0 +1,214 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use crate::{
+    ast::{Definition, Document, Fragment, OwnedDocument},
+    executor::{get_operation, FieldPath},
+    parser::{parse_document_source, SourcePosition},
+    schema::model::SchemaType,
+    types::{async_await::GraphQLTypeAsync, base::GraphQLType},
+    validation::{known_fragment_names, visit, MultiVisitorNil, ValidatorContext},
+    value::{DefaultScalarValue, ScalarValue, Value},
+    ExecutionError, Executor, GraphQLError, Variables,
+};
+
+/// [`Executor`] resolving values of a single type without building a whole
+/// [`RootNode`] for it, intended for unit-testing resolvers.
+///
+/// Created via [`Executor::for_test()`]. Values are resolved via the same
+/// codepath as when executing a query, while the schema is made up of the
+/// resolved type (and the types it references) only.
+///
+/// # Limitations
+///
+/// - Selections are not validated before resolving them, except checking all
+///   the spread fragments to be defined.
+/// - Introspection fields (`__schema` and `__type`) are not available, while
+///   `__typename` is.
+///
+/// # Example
+///
+/// ```rust
+/// # use juniper::{graphql_object, graphql_value, graphql_vars, Executor, NaiveExecutor};
+/// #
+/// struct Context {
+///     greeting: &'static str,
+/// }
+///
+/// impl juniper::Context for Context {}
+///
+/// struct User;
+///
+/// #[graphql_object(context = Context)]
+/// impl User {
+///     fn greet(name: String, context: &Context) -> String {
+///         format!("{}, {name}!", context.greeting)
+///     }
+/// }
+///
+/// let context = Context { greeting: "Hello" };
+/// let executor: NaiveExecutor<'_, _> =
+///     Executor::for_test(&context, graphql_vars! {"name": "Alice"});
+///
+/// let (value, errors) = executor
+///     .resolve(&(), &User, Some("query($name: String!) { greet(name: $name) }"))
+///     .unwrap();
+/// assert_eq!(value, graphql_value!({"greet": "Hello, Alice!"}));
+/// assert!(errors.is_empty());
+/// ```
+///
+/// [`RootNode`]: crate::RootNode
+pub struct NaiveExecutor<'a, CtxT, S = DefaultScalarValue> {
+    pub(super) context: &'a CtxT,
+    pub(super) variables: Variables<S>,
+}
+
+impl<'a, CtxT, S> NaiveExecutor<'a, CtxT, S>
+where
+    S: ScalarValue,
+{
+    /// Resolves the provided `value` of the `T` type, returning the resolved
+    /// [`Value`] along with all the errors occurred while resolving it.
+    ///
+    /// The `selection` is a GraphQL operation (like `{ id friends { name } }`)
+    /// selecting fields of an object, interface or union `T` type, and should
+    /// be [`None`] for other types.
+    ///
+    /// # Errors
+    ///
+    /// If the `selection` cannot be parsed, or contains multiple operations.
+    pub fn resolve<T>(
+        &self,
+        info: &T::TypeInfo,
+        value: &T,
+        selection: Option<&str>,
+    ) -> Result<(Value<S>, Vec<ExecutionError<S>>), GraphQLError>
+    where
+        T: GraphQLType<S, Context = CtxT>,
+    {
+        let schema = SchemaType::new_standalone::<T>(info);
+        let document = parse_selection(selection, &schema)?;
+        let fragments = collect_fragments(document.as_deref());
+        let errors = RwLock::new(Vec::new());
+        let value = self
+            .executor(&schema, document.as_deref(), &fragments, &errors)?
+            .resolve_into_value(info, value);
+
+        Ok((value, sorted(errors)))
+    }
+
+    /// Resolves the provided `value` of the `T` type asynchronously, returning
+    /// the resolved [`Value`] along with all the errors occurred while
+    /// resolving it.
+    ///
+    /// See [`NaiveExecutor::resolve()`] for details.
+    ///
+    /// # Errors
+    ///
+    /// If the `selection` cannot be parsed, or contains multiple operations.
+    pub async fn resolve_async<T>(
+        &self,
+        info: &T::TypeInfo,
+        value: &T,
+        selection: Option<&str>,
+    ) -> Result<(Value<S>, Vec<ExecutionError<S>>), GraphQLError>
+    where
+        T: GraphQLTypeAsync<S, Context = CtxT>,
+        T::TypeInfo: Sync,
+        CtxT: Sync,
+        S: Send + Sync,
+    {
+        let schema = SchemaType::new_standalone::<T>(info);
+        let document = parse_selection(selection, &schema)?;
+        let fragments = collect_fragments(document.as_deref());
+        let errors = RwLock::new(Vec::new());
+        let value = self
+            .executor(&schema, document.as_deref(), &fragments, &errors)?
+            .resolve_into_value_async(info, value)
+            .await;
+
+        Ok((value, sorted(errors)))
+    }
+
+    /// Creates a new [`Executor`] resolving the only operation of the provided
+    /// `document` (if any).
+    fn executor<'r>(
+        &'r self,
+        schema: &'r SchemaType<'r, S>,
+        document: Option<&'r Document<'r, S>>,
+        fragments: &'r HashMap<&'r str, Fragment<'r, S>>,
+        errors: &'r RwLock<Vec<ExecutionError<S>>>,
+    ) -> Result<Executor<'r, 'r, CtxT, S>, GraphQLError> {
+        let operation = document.map(|doc| get_operation(doc, None)).transpose()?;
+        let selection_set = operation.map(|op| &op.item.selection_set[..]);
+        Ok(Executor {
+            fragments,
+            variables: &self.variables,
+            current_selection_set: selection_set,
+            parent_selection_set: None,
+            root_selection_set: selection_set,
+            root_type: schema.concrete_query_type(),
+            parent_type: None,
+            current_type: schema.query_type(),
+            schema,
+            context: self.context,
+            errors,
+            deadline: None,
+            postponement: None,
+            stream: None,
+            field_path: Arc::new(FieldPath::Root(
+                operation.map_or_else(SourcePosition::new_origin, |op| op.start),
+            )),
+            field_arguments: None,
+        })
+    }
+}
+
+/// Parses the provided `selection` against the provided `schema`, checking
+/// all the fragments spread in it to be defined.
+fn parse_selection<'a, S: ScalarValue>(
+    selection: Option<&'a str>,
+    schema: &SchemaType<S>,
+) -> Result<Option<OwnedDocument<'a, S>>, GraphQLError> {
+    let document = match selection {
+        Some(s) => parse_document_source(s, schema)?,
+        None => return Ok(None),
+    };
+
+    let mut ctx = ValidatorContext::new(schema, &document);
+    visit(
+        &mut MultiVisitorNil.with(known_fragment_names::factory()),
+        &mut ctx,
+        &document,
+    );
+    let errors = ctx.into_errors();
+    if !errors.is_empty() {
+        return Err(GraphQLError::ValidationError(errors));
+    }
+
+    Ok(Some(document))
+}
+
+/// Collects all the fragments defined in the provided `document`, by their
+/// names.
+fn collect_fragments<'a, S: Clone>(
+    document: Option<&Document<'a, S>>,
+) -> HashMap<&'a str, Fragment<'a, S>> {
+    document
+        .into_iter()
+        .flatten()
+        .filter_map(|def| match def {
+            Definition::Fragment(f) => Some((f.item.name.item, f.item.clone())),
+            Definition::Operation(_) => None,
+        })
+        .collect()
+}
+
+/// Unwraps the provided [`ExecutionError`]s, sorting them.
+fn sorted<S: ScalarValue>(errors: RwLock<Vec<ExecutionError<S>>>) -> Vec<ExecutionError<S>> {
+    let mut errors = errors.into_inner().unwrap();
+    errors.sort();
+    errors
+}
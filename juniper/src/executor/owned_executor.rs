@@ -4,10 +4,13 @@ use std::{
 };
 
 use crate::{
-    ast::Fragment,
-    executor::FieldPath,
-    parser::SourcePosition,
-    schema::model::{SchemaType, TypeType},
+    ast::{self, Fragment},
+    executor::{deadline::Deadline, FieldArguments, FieldPath},
+    parser::{SourcePosition, Spanning},
+    schema::{
+        meta::{Argument, MetaType},
+        model::{SchemaType, TypeType},
+    },
     ExecutionError, Executor, Selection, Variables,
 };
 
@@ -18,11 +21,17 @@ pub struct OwnedExecutor<'a, CtxT, S> {
     pub(super) variables: Variables<S>,
     pub(super) current_selection_set: Option<Vec<Selection<'a, S>>>,
     pub(super) parent_selection_set: Option<Vec<Selection<'a, S>>>,
+    pub(super) root_selection_set: Option<Vec<Selection<'a, S>>>,
+    pub(super) root_type: &'a MetaType<'a, S>,
+    pub(super) parent_type: Option<&'a MetaType<'a, S>>,
     pub(super) current_type: TypeType<'a, S>,
     pub(super) schema: &'a SchemaType<'a, S>,
     pub(super) context: &'a CtxT,
     pub(super) errors: RwLock<Vec<ExecutionError<S>>>,
+    pub(super) deadline: Option<Arc<Deadline>>,
     pub(super) field_path: Arc<FieldPath<'a>>,
+    pub(super) specified_arguments: Option<Spanning<ast::Arguments<'a, S>>>,
+    pub(super) declared_arguments: Option<&'a Option<Vec<Argument<'a, S>>>>,
 }
 
 impl<'a, CtxT, S> Clone for OwnedExecutor<'a, CtxT, S>
@@ -35,11 +44,17 @@ where
             variables: self.variables.clone(),
             current_selection_set: self.current_selection_set.clone(),
             parent_selection_set: self.parent_selection_set.clone(),
+            root_selection_set: self.root_selection_set.clone(),
+            root_type: self.root_type,
+            parent_type: self.parent_type,
             current_type: self.current_type.clone(),
             schema: self.schema,
             context: self.context,
             errors: RwLock::new(vec![]),
+            deadline: self.deadline.clone(),
             field_path: self.field_path.clone(),
+            specified_arguments: self.specified_arguments.clone(),
+            declared_arguments: self.declared_arguments,
         }
     }
 }
@@ -60,6 +75,9 @@ where
             variables: self.variables.clone(),
             current_selection_set: selection_set,
             parent_selection_set: self.current_selection_set.clone(),
+            root_selection_set: self.root_selection_set.clone(),
+            root_type: self.root_type,
+            parent_type: self.parent_type,
             current_type: match type_name {
                 Some(type_name) => self.schema.type_by_name(type_name).expect("Type not found"),
                 None => self.current_type.clone(),
@@ -67,7 +85,10 @@ where
             schema: self.schema,
             context: self.context,
             errors: RwLock::new(vec![]),
+            deadline: self.deadline.clone(),
             field_path: self.field_path.clone(),
+            specified_arguments: self.specified_arguments.clone(),
+            declared_arguments: self.declared_arguments,
         }
     }
 
@@ -84,28 +105,34 @@ where
         field_name: &'a str,
         location: SourcePosition,
         selection_set: Option<Vec<Selection<'a, S>>>,
+        arguments: Option<Spanning<ast::Arguments<'a, S>>>,
     ) -> OwnedExecutor<'a, CtxT, S> {
+        let field = self
+            .current_type
+            .innermost_concrete()
+            .field_by_name(field_name)
+            .expect("Field not found on inner type");
+
         OwnedExecutor {
             fragments: self.fragments.clone(),
             variables: self.variables.clone(),
             current_selection_set: selection_set,
             parent_selection_set: self.current_selection_set.clone(),
-            current_type: self.schema.make_type(
-                &self
-                    .current_type
-                    .innermost_concrete()
-                    .field_by_name(field_name)
-                    .expect("Field not found on inner type")
-                    .field_type,
-            ),
+            root_selection_set: self.root_selection_set.clone(),
+            root_type: self.root_type,
+            parent_type: Some(self.current_type.innermost_concrete()),
+            current_type: self.schema.make_type(&field.field_type),
             schema: self.schema,
             context: self.context,
             errors: RwLock::new(vec![]),
+            deadline: self.deadline.clone(),
             field_path: Arc::new(FieldPath::Field(
                 field_alias,
                 location,
                 Arc::clone(&self.field_path),
             )),
+            specified_arguments: arguments,
+            declared_arguments: Some(&field.arguments),
         }
     }
 
@@ -116,11 +143,21 @@ where
             variables: &self.variables,
             current_selection_set: self.current_selection_set.as_deref(),
             parent_selection_set: self.parent_selection_set.as_deref(),
+            root_selection_set: self.root_selection_set.as_deref(),
+            root_type: self.root_type,
+            parent_type: self.parent_type,
             current_type: self.current_type.clone(),
             schema: self.schema,
             context: self.context,
             errors: &self.errors,
+            deadline: self.deadline.clone(),
+            postponement: None,
+            stream: None,
             field_path: Arc::clone(&self.field_path),
+            field_arguments: self.declared_arguments.map(|declared| FieldArguments {
+                specified: self.specified_arguments.as_ref(),
+                declared,
+            }),
         }
     }
 }
@@ -0,0 +1,142 @@
+//! Extension point for inspecting operations right before executing them.
+
+use std::fmt;
+
+use crate::{
+    ast::{Document, Operation},
+    parser::Spanning,
+    schema::model::RootNode,
+    types::base::GraphQLType,
+    validation::{operation_cost, RuleError},
+    value::ScalarValue,
+    GraphQLError,
+};
+
+use super::{ExecutionError, FieldError, Variables};
+
+/// User-defined hook, called once the operation executed against the schema
+/// it's registered in (see [`RootNode::with_pre_execution()`]) is validated,
+/// but before any of its fields is resolved.
+///
+/// Sees the executed operation along with its estimated cost via
+/// [`OperationInfo`] and the execution context, so is suitable for enforcing
+/// per-client quotas, like rate limiting by the query complexity.
+///
+/// Returning a [`FieldError`] aborts the execution: the operation results in
+/// `null` data with the returned error (located at the operation), and none of
+/// its resolvers is run. In case multiple hooks are registered, they're called
+/// in the order of registration, until the first one returning an error.
+///
+/// [`RootNode::with_pre_execution()`]: crate::RootNode::with_pre_execution
+pub trait PreExecution<CtxT, S>: Send + Sync {
+    /// Inspects the described `operation` executed with the provided
+    /// `context`, allowing its execution by returning `Ok(())`.
+    fn before_execution(
+        &self,
+        operation: &OperationInfo<'_, S>,
+        context: &CtxT,
+    ) -> Result<(), FieldError<S>>;
+}
+
+impl<'a, CtxT, S> fmt::Debug for dyn PreExecution<CtxT, S> + 'a {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("dyn PreExecution")
+    }
+}
+
+/// Description of an operation about to be executed, passed to a
+/// [`PreExecution`] hook.
+pub struct OperationInfo<'o, S> {
+    document: &'o Document<'o, S>,
+    operation: &'o Spanning<Operation<'o, S>>,
+    variables: &'o Variables<S>,
+    cost: u64,
+}
+
+impl<'o, S> OperationInfo<'o, S> {
+    /// Returns the whole [`Document`] containing the executed operation.
+    pub fn document(&self) -> &'o Document<'o, S> {
+        self.document
+    }
+
+    /// Returns the executed [`Operation`].
+    pub fn operation(&self) -> &'o Spanning<Operation<'o, S>> {
+        self.operation
+    }
+
+    /// Returns the [`Variables`] the operation is executed with, as provided
+    /// (without the default values of its variable definitions applied).
+    pub fn variables(&self) -> &'o Variables<S> {
+        self.variables
+    }
+
+    /// Returns the cost of the executed operation, as estimated by the
+    /// [`RootNode::estimate_cost()`].
+    ///
+    /// [`RootNode::estimate_cost()`]: crate::RootNode::estimate_cost
+    pub fn estimated_cost(&self) -> u64 {
+        self.cost
+    }
+}
+
+impl<'o, S> fmt::Debug for OperationInfo<'o, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OperationInfo")
+            .field("name", &self.operation.item.name.as_ref().map(|n| n.item))
+            .field("operation_type", &self.operation.item.operation_type)
+            .field("estimated_cost", &self.cost)
+            .finish()
+    }
+}
+
+/// Calls all the [`PreExecution`] hooks of the provided `root_node` before
+/// executing the `operation`, returning the error of the first one aborting it,
+/// if any.
+///
+/// Reuses the already estimated `cost` of the `operation`, if provided (as
+/// checked against the [`RootNode::with_max_cost()`] limit), and estimates it
+/// otherwise.
+///
+/// # Errors
+///
+/// If the cost of the `operation` cannot be estimated (due to unknown or
+/// cyclic fragment spreads in a non-validated `document`).
+pub(crate) fn before_execution<'o, QueryT, MutationT, SubscriptionT, S>(
+    root_node: &RootNode<'_, QueryT, MutationT, SubscriptionT, S>,
+    document: &'o Document<'o, S>,
+    operation: &'o Spanning<Operation<'o, S>>,
+    variables: &'o Variables<S>,
+    context: &QueryT::Context,
+    cost: Option<u64>,
+) -> Result<Option<ExecutionError<S>>, GraphQLError>
+where
+    QueryT: GraphQLType<S>,
+    MutationT: GraphQLType<S>,
+    SubscriptionT: GraphQLType<S>,
+    S: ScalarValue,
+{
+    let hooks = &root_node.pre_executions;
+    if hooks.is_empty() {
+        return Ok(None);
+    }
+
+    let cost = if let Some(cost) = cost {
+        cost
+    } else {
+        operation_cost(&root_node.schema, document, &operation.item, variables).map_err(|e| {
+            GraphQLError::ValidationError(vec![RuleError::new(&e.to_string(), &[operation.start])])
+        })?
+    };
+    let info = OperationInfo {
+        document,
+        operation,
+        variables,
+        cost,
+    };
+
+    Ok(hooks.iter().find_map(|hook| {
+        hook.before_execution(&info, context)
+            .err()
+            .map(|e| ExecutionError::new(operation.start, &[], e))
+    }))
+}
@@ -0,0 +1,168 @@
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+
+use tokio::time::sleep;
+
+use crate::{
+    execute_with_deadline, graphql_object, graphql_value, graphql_vars, parser::SourcePosition,
+    DefaultScalarValue, EmptyMutation, EmptySubscription, ExecutionError, FieldError, RootNode,
+};
+
+struct Context {
+    slow_started: AtomicBool,
+    slow_dropped: AtomicBool,
+}
+
+impl crate::Context for Context {}
+
+impl Context {
+    fn new() -> Self {
+        Self {
+            slow_started: AtomicBool::new(false),
+            slow_dropped: AtomicBool::new(false),
+        }
+    }
+}
+
+struct Query;
+
+#[graphql_object(context = Context)]
+impl Query {
+    fn fast() -> i32 {
+        1
+    }
+
+    async fn delayed() -> i32 {
+        sleep(Duration::from_millis(10)).await;
+        2
+    }
+
+    async fn slow(context: &Context) -> Option<i32> {
+        struct DropGuard<'a>(&'a AtomicBool);
+
+        impl<'a> Drop for DropGuard<'a> {
+            fn drop(&mut self) {
+                self.0.store(true, Ordering::SeqCst);
+            }
+        }
+
+        context.slow_started.store(true, Ordering::SeqCst);
+        let _guard = DropGuard(&context.slow_dropped);
+        sleep(Duration::from_secs(60)).await;
+        Some(3)
+    }
+
+    async fn slow_non_null() -> i32 {
+        sleep(Duration::from_secs(60)).await;
+        4
+    }
+
+    fn nested() -> Option<Query> {
+        Some(Query)
+    }
+}
+
+fn schema() -> RootNode<'static, Query, EmptyMutation<Context>, EmptySubscription<Context>> {
+    RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+}
+
+fn timeout_error(column: usize, path: &[&str]) -> ExecutionError<DefaultScalarValue> {
+    ExecutionError::new(
+        SourcePosition::new(column, 0, column),
+        path,
+        FieldError::new(
+            "Execution deadline exceeded before the field was resolved",
+            graphql_value!({"code": "TIMEOUT"}),
+        ),
+    )
+}
+
+#[tokio::test]
+async fn completes_before_deadline() {
+    let ctx = Context::new();
+
+    assert_eq!(
+        execute_with_deadline(
+            "{ fast delayed nested { fast } }",
+            None,
+            &schema(),
+            &graphql_vars! {},
+            &ctx,
+            sleep(Duration::from_secs(60)),
+        )
+        .await,
+        Ok((
+            graphql_value!({"fast": 1, "delayed": 2, "nested": {"fast": 1}}),
+            vec![],
+        )),
+    );
+}
+
+#[tokio::test]
+async fn returns_partial_data_with_timeout_errors() {
+    let ctx = Context::new();
+
+    let (res, errors) = execute_with_deadline(
+        "{ fast slow nested { delayed slow } }",
+        None,
+        &schema(),
+        &graphql_vars! {},
+        &ctx,
+        sleep(Duration::from_millis(100)),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        res,
+        graphql_value!({"fast": 1, "slow": null, "nested": {"delayed": 2, "slow": null}}),
+    );
+    assert_eq!(
+        errors,
+        vec![
+            timeout_error(7, &["slow"]),
+            timeout_error(29, &["nested", "slow"]),
+        ],
+    );
+}
+
+#[tokio::test]
+async fn propagates_timed_out_non_null_field() {
+    let ctx = Context::new();
+
+    let (res, errors) = execute_with_deadline(
+        "{ fast nested { fast slowNonNull } }",
+        None,
+        &schema(),
+        &graphql_vars! {},
+        &ctx,
+        sleep(Duration::from_millis(10)),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(res, graphql_value!({"fast": 1, "nested": null}));
+    assert_eq!(errors, vec![timeout_error(21, &["nested", "slowNonNull"])]);
+}
+
+#[tokio::test]
+async fn drops_running_resolvers() {
+    let ctx = Context::new();
+
+    let (res, _) = execute_with_deadline(
+        "{ slow }",
+        None,
+        &schema(),
+        &graphql_vars! {},
+        &ctx,
+        sleep(Duration::from_millis(10)),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(res, graphql_value!({"slow": null}));
+    assert!(ctx.slow_started.load(Ordering::SeqCst));
+    assert!(ctx.slow_dropped.load(Ordering::SeqCst));
+}
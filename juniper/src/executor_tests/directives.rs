@@ -246,3 +246,119 @@ async fn scalar_include_false_skip_false() {
     })
     .await;
 }
+
+mod nested_fragments_on_interface {
+    use crate::{
+        execute, execute_sync, graphql_interface, graphql_object, graphql_value, graphql_vars,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+        GraphQLObject, Value, Variables,
+    };
+
+    #[graphql_interface(for = [Human, Droid])]
+    trait Character {
+        fn id(&self) -> &str;
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(impl = CharacterValue)]
+    struct Human {
+        id: String,
+        home_planet: String,
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(impl = CharacterValue)]
+    struct Droid {
+        id: String,
+        primary_function: String,
+    }
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn characters() -> Vec<CharacterValue> {
+            vec![
+                Human {
+                    id: "1000".into(),
+                    home_planet: "Tatooine".into(),
+                }
+                .into(),
+                Droid {
+                    id: "2001".into(),
+                    primary_function: "Astromech".into(),
+                }
+                .into(),
+            ]
+        }
+    }
+
+    const DOC: &str = r"
+        query($details: Boolean!, $skipDroid: Boolean!) {
+            characters {
+                id
+                ... on Character @include(if: $details) {
+                    ... on Human {
+                        homePlanet
+                    }
+                    ... on Droid @skip(if: $skipDroid) {
+                        ...droidDetails
+                    }
+                }
+            }
+        }
+
+        fragment droidDetails on Droid {
+            primaryFunction @include(if: $details)
+        }
+    ";
+
+    async fn run(vars: Variables) -> Value {
+        let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+
+        let (res, errs) = execute(DOC, None, &schema, &vars, &())
+            .await
+            .expect("Execution failed");
+        assert_eq!(errs, []);
+
+        let (sync_res, errs) =
+            execute_sync(DOC, None, &schema, &vars, &()).expect("Execution failed");
+        assert_eq!(errs, []);
+        assert_eq!(res, sync_res);
+
+        res
+    }
+
+    #[tokio::test]
+    async fn includes_all() {
+        assert_eq!(
+            run(graphql_vars! {"details": true, "skipDroid": false}).await,
+            graphql_value!({"characters": [
+                {"id": "1000", "homePlanet": "Tatooine"},
+                {"id": "2001", "primaryFunction": "Astromech"},
+            ]}),
+        );
+    }
+
+    #[tokio::test]
+    async fn skips_nested_inline_fragment() {
+        assert_eq!(
+            run(graphql_vars! {"details": true, "skipDroid": true}).await,
+            graphql_value!({"characters": [
+                {"id": "1000", "homePlanet": "Tatooine"},
+                {"id": "2001"},
+            ]}),
+        );
+    }
+
+    #[tokio::test]
+    async fn excludes_outer_inline_fragment() {
+        for skip_droid in [true, false] {
+            assert_eq!(
+                run(graphql_vars! {"details": false, "skipDroid": (skip_droid)}).await,
+                graphql_value!({"characters": [{"id": "1000"}, {"id": "2001"}]}),
+            );
+        }
+    }
+}
@@ -673,9 +673,111 @@ mod dynamic_context_switching {
     }
 }
 
+mod context_downcasting {
+    use std::{
+        any::{Any, TypeId},
+        collections::HashMap,
+    };
+
+    use crate::{
+        executor::Context,
+        graphql_object, graphql_value, graphql_vars,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+        Executor, ScalarValue,
+    };
+
+    struct CurrentUser(&'static str);
+
+    struct Locale(&'static str);
+
+    #[derive(Default)]
+    struct AppContext {
+        extensions: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
+    }
+
+    impl AppContext {
+        fn with<T: Any + Send + Sync>(mut self, ext: T) -> Self {
+            self.extensions.insert(TypeId::of::<T>(), Box::new(ext));
+            self
+        }
+    }
+
+    impl Context for AppContext {
+        fn sub_context(&self, type_id: TypeId) -> Option<&dyn Any> {
+            self.extensions.get(&type_id).map(|ext| &**ext as &dyn Any)
+        }
+    }
+
+    // Resolver being agnostic to the concrete context type.
+    fn user_name<C: Context + 'static, S: ScalarValue>(
+        executor: &Executor<'_, '_, C, S>,
+    ) -> Option<&'static str> {
+        executor.context_as::<CurrentUser>().map(|u| u.0)
+    }
+
+    struct Query;
+
+    #[graphql_object(context = AppContext)]
+    impl Query {
+        fn user_name<S: ScalarValue>(
+            executor: &Executor<'_, '_, AppContext, S>,
+        ) -> Option<&'static str> {
+            user_name(executor)
+        }
+
+        fn locale<S: ScalarValue>(
+            executor: &Executor<'_, '_, AppContext, S>,
+        ) -> Option<&'static str> {
+            executor.context_as::<Locale>().map(|l| l.0)
+        }
+
+        fn extensions_count<S: ScalarValue>(executor: &Executor<'_, '_, AppContext, S>) -> i32 {
+            executor
+                .context_as::<AppContext>()
+                .map_or(-1, |c| c.extensions.len() as i32)
+        }
+    }
+
+    #[tokio::test]
+    async fn downcasts_context_and_sub_contexts() {
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<AppContext>::new(),
+            EmptySubscription::<AppContext>::new(),
+        );
+        let doc = r"{ userName locale extensionsCount }";
+        let vars = graphql_vars! {};
+
+        let ctx = AppContext::default().with(CurrentUser("luke"));
+        let (result, errs) = crate::execute(doc, None, &schema, &vars, &ctx)
+            .await
+            .expect("Execution failed");
+
+        assert_eq!(errs, []);
+        assert_eq!(
+            result,
+            graphql_value!({"userName": "luke", "locale": null, "extensionsCount": 1}),
+        );
+
+        let ctx = AppContext::default()
+            .with(CurrentUser("leia"))
+            .with(Locale("en-US"));
+        let (result, errs) = crate::execute(doc, None, &schema, &vars, &ctx)
+            .await
+            .expect("Execution failed");
+
+        assert_eq!(errs, []);
+        assert_eq!(
+            result,
+            graphql_value!({"userName": "leia", "locale": "en-US", "extensionsCount": 2}),
+        );
+    }
+}
+
 mod propagates_errors_to_nullable_fields {
     use crate::{
-        executor::{ExecutionError, FieldError, FieldResult, IntoFieldError},
+        executor::{ExecutionError, FieldError, FieldResult, IntoFieldError, PathSegment},
         graphql_object, graphql_value, graphql_vars,
         parser::SourcePosition,
         schema::model::RootNode,
@@ -932,9 +1034,13 @@ mod propagates_errors_to_nullable_fields {
 
         assert_eq!(
             errs,
-            vec![ExecutionError::new(
+            vec![ExecutionError::new_with_path(
                 SourcePosition::new(11, 0, 11),
-                &["inners", "nonNullableErrorField"],
+                &[
+                    PathSegment::Field("inners".into()),
+                    PathSegment::Index(0),
+                    PathSegment::Field("nonNullableErrorField".into()),
+                ],
                 FieldError::new("Error for nonNullableErrorField", graphql_value!(null)),
             )],
         );
@@ -963,33 +1069,301 @@ mod propagates_errors_to_nullable_fields {
 
         assert_eq!(
             errs,
-            vec![
-                ExecutionError::new(
-                    SourcePosition::new(19, 0, 19),
-                    &["nullableInners", "nonNullableErrorField"],
-                    FieldError::new("Error for nonNullableErrorField", graphql_value!(null)),
-                ),
-                ExecutionError::new(
-                    SourcePosition::new(19, 0, 19),
-                    &["nullableInners", "nonNullableErrorField"],
-                    FieldError::new("Error for nonNullableErrorField", graphql_value!(null)),
-                ),
-                ExecutionError::new(
-                    SourcePosition::new(19, 0, 19),
-                    &["nullableInners", "nonNullableErrorField"],
-                    FieldError::new("Error for nonNullableErrorField", graphql_value!(null)),
-                ),
-                ExecutionError::new(
+            (0..5)
+                .map(|i| ExecutionError::new_with_path(
                     SourcePosition::new(19, 0, 19),
-                    &["nullableInners", "nonNullableErrorField"],
-                    FieldError::new("Error for nonNullableErrorField", graphql_value!(null)),
-                ),
-                ExecutionError::new(
-                    SourcePosition::new(19, 0, 19),
-                    &["nullableInners", "nonNullableErrorField"],
+                    &[
+                        PathSegment::Field("nullableInners".into()),
+                        PathSegment::Index(i),
+                        PathSegment::Field("nonNullableErrorField".into()),
+                    ],
                     FieldError::new("Error for nonNullableErrorField", graphql_value!(null)),
-                ),
-            ],
+                ))
+                .collect::<Vec<_>>(),
+        );
+    }
+}
+
+mod propagates_list_errors_to_nullable_parents {
+    use futures::future;
+
+    use crate::{
+        ast::Selection,
+        execute, execute_sync,
+        executor::{
+            ExecutionError, ExecutionResult, Executor, FieldError, FieldResult, PathSegment,
+            Registry,
+        },
+        graphql_object, graphql_value, graphql_vars,
+        macros::reflect,
+        parser::SourcePosition,
+        schema::{meta::MetaType, model::RootNode},
+        types::{
+            async_await::GraphQLValueAsync,
+            base::{GraphQLType, GraphQLValue},
+            marker::IsOutputType,
+            scalars::{EmptyMutation, EmptySubscription},
+        },
+        value::{DefaultScalarValue, Value},
+        BoxFuture,
+    };
+
+    struct Query;
+    struct Wrapper;
+    struct Item(i32);
+
+    // Object failing to resolve as a whole, rather than in any of its fields.
+    struct Broken;
+
+    impl GraphQLType for Broken {
+        fn name(_: &()) -> Option<&'static str> {
+            Some("Broken")
+        }
+
+        fn meta<'r>(info: &(), registry: &mut Registry<'r>) -> MetaType<'r>
+        where
+            DefaultScalarValue: 'r,
+        {
+            let fields = [registry.field::<i32>("value", info)];
+            registry
+                .build_object_type::<Self>(info, &fields)
+                .into_meta()
+        }
+    }
+
+    impl GraphQLValue for Broken {
+        type Context = ();
+        type TypeInfo = ();
+
+        fn type_name<'i>(&self, info: &'i ()) -> Option<&'i str> {
+            <Self as GraphQLType>::name(info)
+        }
+
+        fn resolve(
+            &self,
+            _: &(),
+            _: Option<&[Selection<'_>]>,
+            _: &Executor<'_, '_, ()>,
+        ) -> ExecutionResult {
+            Err("Broken element".into())
+        }
+    }
+
+    impl GraphQLValueAsync for Broken {
+        fn resolve_async<'a>(
+            &'a self,
+            info: &'a (),
+            selection_set: Option<&'a [Selection<'_>]>,
+            executor: &'a Executor<'_, '_, ()>,
+        ) -> BoxFuture<'a, ExecutionResult> {
+            Box::pin(future::ready(self.resolve(info, selection_set, executor)))
+        }
+    }
+
+    impl IsOutputType<DefaultScalarValue> for Broken {}
+
+    impl reflect::BaseType<DefaultScalarValue> for Broken {
+        const NAME: reflect::Type = "Broken";
+    }
+
+    impl reflect::BaseSubTypes<DefaultScalarValue> for Broken {
+        const NAMES: reflect::Types = &[<Self as reflect::BaseType<DefaultScalarValue>>::NAME];
+    }
+
+    impl reflect::WrappedType<DefaultScalarValue> for Broken {
+        const VALUE: reflect::WrappedValue = 1;
+    }
+
+    fn items() -> Vec<Item> {
+        (0..3).map(Item).collect()
+    }
+
+    #[graphql_object(scalar = DefaultScalarValue)]
+    impl Query {
+        fn sibling() -> &'static str {
+            "ok"
+        }
+
+        fn nullable_list() -> Option<Vec<Item>> {
+            Some(items())
+        }
+
+        fn non_null_list() -> Vec<Item> {
+            items()
+        }
+
+        fn nested_list() -> Vec<Vec<Item>> {
+            vec![vec![Item(0)], items()]
+        }
+
+        fn nested_nullable_list() -> Vec<Option<Vec<Item>>> {
+            vec![Some(vec![Item(0)]), Some(items())]
+        }
+
+        fn wrapper() -> Option<Wrapper> {
+            Some(Wrapper)
+        }
+
+        fn broken_elements() -> Vec<Option<Broken>> {
+            vec![None, Some(Broken)]
+        }
+    }
+
+    #[graphql_object]
+    impl Wrapper {
+        fn nested_list() -> Vec<Vec<Item>> {
+            vec![items()]
+        }
+    }
+
+    #[graphql_object]
+    impl Item {
+        fn value(&self) -> FieldResult<i32> {
+            if self.0 == 1 {
+                Err("Item 1 is broken")?
+            }
+            Ok(self.0)
+        }
+    }
+
+    /// Executes the provided `doc` both synchronously and asynchronously,
+    /// ensuring the results are the same.
+    async fn run(doc: &str) -> (Value, Vec<ExecutionError<DefaultScalarValue>>) {
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+        let vars = graphql_vars! {};
+
+        let sync_res = execute_sync(doc, None, &schema, &vars, &()).expect("Execution failed");
+        let async_res = execute(doc, None, &schema, &vars, &())
+            .await
+            .expect("Execution failed");
+
+        assert_eq!(sync_res, async_res, "sync and async results differ");
+        sync_res
+    }
+
+    fn item_error(col: usize, path: &[PathSegment]) -> ExecutionError<DefaultScalarValue> {
+        ExecutionError::new_with_path(
+            SourcePosition::new(col, 0, col),
+            path,
+            FieldError::new("Item 1 is broken", graphql_value!(null)),
+        )
+    }
+
+    fn field(name: &str) -> PathSegment {
+        PathSegment::Field(name.into())
+    }
+
+    #[tokio::test]
+    async fn nullable_list_of_non_null() {
+        let (res, errs) = run("{ sibling nullableList { value } }").await;
+
+        assert_eq!(res, graphql_value!({"sibling": "ok", "nullableList": null}));
+        assert_eq!(
+            errs,
+            [item_error(
+                25,
+                &[field("nullableList"), PathSegment::Index(1), field("value")],
+            )],
+        );
+    }
+
+    #[tokio::test]
+    async fn non_null_list_of_non_null() {
+        let (res, errs) = run("{ sibling nonNullList { value } }").await;
+
+        assert_eq!(res, graphql_value!(null));
+        assert_eq!(
+            errs,
+            [item_error(
+                24,
+                &[field("nonNullList"), PathSegment::Index(1), field("value")],
+            )],
+        );
+    }
+
+    #[tokio::test]
+    async fn non_null_nested_list_of_non_null() {
+        let (res, errs) = run("{ sibling nestedList { value } }").await;
+
+        assert_eq!(res, graphql_value!(null));
+        assert_eq!(
+            errs,
+            [item_error(
+                23,
+                &[
+                    field("nestedList"),
+                    PathSegment::Index(1),
+                    PathSegment::Index(1),
+                    field("value"),
+                ],
+            )],
+        );
+    }
+
+    #[tokio::test]
+    async fn non_null_nested_list_of_nullable_lists() {
+        let (res, errs) = run("{ sibling nestedNullableList { value } }").await;
+
+        assert_eq!(
+            res,
+            graphql_value!({
+                "sibling": "ok",
+                "nestedNullableList": [[{"value": 0}], null],
+            }),
+        );
+        assert_eq!(
+            errs,
+            [item_error(
+                31,
+                &[
+                    field("nestedNullableList"),
+                    PathSegment::Index(1),
+                    PathSegment::Index(1),
+                    field("value"),
+                ],
+            )],
+        );
+    }
+
+    #[tokio::test]
+    async fn non_null_nested_list_in_nullable_parent() {
+        let (res, errs) = run("{ sibling wrapper { nestedList { value } } }").await;
+
+        assert_eq!(res, graphql_value!({"sibling": "ok", "wrapper": null}));
+        assert_eq!(
+            errs,
+            [item_error(
+                33,
+                &[
+                    field("wrapper"),
+                    field("nestedList"),
+                    PathSegment::Index(0),
+                    PathSegment::Index(1),
+                    field("value"),
+                ],
+            )],
+        );
+    }
+
+    #[tokio::test]
+    async fn nullable_element_failing_as_whole() {
+        let (res, errs) = run("{ sibling brokenElements { value } }").await;
+
+        assert_eq!(
+            res,
+            graphql_value!({"sibling": "ok", "brokenElements": [null, null]}),
+        );
+        assert_eq!(
+            errs,
+            [ExecutionError::new_with_path(
+                SourcePosition::new(10, 0, 10),
+                &[field("brokenElements"), PathSegment::Index(1)],
+                FieldError::new("Broken element", graphql_value!(null)),
+            )],
         );
     }
 }
@@ -1101,3 +1475,449 @@ mod named_operations {
         assert_eq!(err, GraphQLError::UnknownOperationName);
     }
 }
+
+mod error_extensions {
+    use std::fmt;
+
+    use crate::{
+        executor::{ErrorExtensions, ExecutionError, FieldError},
+        graphql_object, graphql_value, graphql_vars,
+        parser::SourcePosition,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+        value::Value,
+    };
+
+    enum ApiError {
+        Unauthenticated,
+        Internal,
+    }
+
+    impl fmt::Display for ApiError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Self::Unauthenticated => write!(f, "Authentication is required"),
+                Self::Internal => write!(f, "Internal error"),
+            }
+        }
+    }
+
+    impl ErrorExtensions for ApiError {
+        fn extend(&self) -> Value {
+            match self {
+                Self::Unauthenticated => graphql_value!({"code": "UNAUTHENTICATED"}),
+                Self::Internal => graphql_value!(null),
+            }
+        }
+    }
+
+    fn authenticate(token: &str) -> Result<(), ApiError> {
+        if token != "secret" {
+            return Err(ApiError::Unauthenticated);
+        }
+        Ok(())
+    }
+
+    struct Schema;
+
+    #[graphql_object]
+    impl Schema {
+        fn secret(token: String) -> Result<i32, ApiError> {
+            authenticate(&token)?;
+            Ok(42)
+        }
+
+        async fn failing() -> Result<Option<i32>, ApiError> {
+            Err(ApiError::Internal)
+        }
+    }
+
+    #[tokio::test]
+    async fn attaches_extensions() {
+        let schema = RootNode::new(
+            Schema,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+        let doc = r#"{ secret(token: "wrong") }"#;
+        let vars = graphql_vars! {};
+
+        let (result, errs) = crate::execute(doc, None, &schema, &vars, &())
+            .await
+            .expect("Execution failed");
+
+        assert_eq!(result, graphql_value!(null));
+        assert_eq!(
+            errs,
+            vec![ExecutionError::new(
+                SourcePosition::new(2, 0, 2),
+                &["secret"],
+                FieldError::new(
+                    "Authentication is required",
+                    graphql_value!({"code": "UNAUTHENTICATED"}),
+                ),
+            )],
+        );
+    }
+
+    #[tokio::test]
+    async fn omits_null_extensions() {
+        let schema = RootNode::new(
+            Schema,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+        let doc = r#"{ secret(token: "secret") failing }"#;
+        let vars = graphql_vars! {};
+
+        let (result, errs) = crate::execute(doc, None, &schema, &vars, &())
+            .await
+            .expect("Execution failed");
+
+        assert_eq!(result, graphql_value!({"secret": 42, "failing": null}));
+        assert_eq!(
+            errs,
+            vec![ExecutionError::new(
+                SourcePosition::new(26, 0, 26),
+                &["failing"],
+                FieldError::new("Internal error", graphql_value!(null)),
+            )],
+        );
+    }
+}
+
+mod field_error_builder {
+    use crate::{
+        executor::FieldError,
+        graphql_object, graphql_value, graphql_vars,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+        value::Value,
+    };
+
+    #[test]
+    fn builds_extensions() {
+        let err: FieldError = FieldError::builder()
+            .message("Too many requests")
+            .code("RATE_LIMITED")
+            .extension("retryAfter", 30)
+            .extension("limits", graphql_value!({"perMinute": 60}))
+            .build();
+
+        assert_eq!(err.message(), "Too many requests");
+        assert_eq!(
+            err.extensions(),
+            &graphql_value!({
+                "code": "RATE_LIMITED",
+                "retryAfter": 30,
+                "limits": {"perMinute": 60},
+            }),
+        );
+    }
+
+    #[test]
+    fn replaces_extensions() {
+        let err: FieldError = FieldError::builder()
+            .code("FIRST")
+            .extension("code", "SECOND")
+            .extension("tries", 1)
+            .extension("tries", 2)
+            .build();
+
+        assert_eq!(err.message(), "");
+        assert_eq!(
+            err.extensions(),
+            &graphql_value!({"code": "SECOND", "tries": 2}),
+        );
+    }
+
+    #[test]
+    fn omits_empty_extensions() {
+        let err: FieldError = FieldError::builder().message("Oops").build();
+
+        assert_eq!(err, FieldError::new("Oops", Value::null()));
+    }
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn forbidden() -> Result<i32, FieldError> {
+            Err(FieldError::builder()
+                .message("Access denied")
+                .code("FORBIDDEN")
+                .build())
+        }
+    }
+
+    #[tokio::test]
+    async fn serializes_extensions() {
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+
+        let (_, errs) = crate::execute("{ forbidden }", None, &schema, &graphql_vars! {}, &())
+            .await
+            .expect("Execution failed");
+
+        assert_eq!(
+            serde_json::to_value(&errs).unwrap(),
+            serde_json::json!([{
+                "message": "Access denied",
+                "locations": [{"line": 1, "column": 3}],
+                "path": ["forbidden"],
+                "extensions": {"code": "FORBIDDEN"},
+            }]),
+        );
+    }
+}
+
+mod field_error_source {
+    use std::{error::Error as _, fmt};
+
+    use crate::{
+        executor::{FieldError, IntoFieldError},
+        graphql_object, graphql_value, graphql_vars,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+        value::{DefaultScalarValue, Value},
+    };
+
+    #[derive(Debug, PartialEq)]
+    struct DbError {
+        table: &'static str,
+    }
+
+    impl fmt::Display for DbError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "Failed to query `{}` table", self.table)
+        }
+    }
+
+    impl std::error::Error for DbError {}
+
+    impl<S> IntoFieldError<S> for DbError {
+        fn into_field_error(self) -> FieldError<S> {
+            FieldError::from("Internal error").with_source(self)
+        }
+    }
+
+    #[test]
+    fn preserves_source() {
+        let err: FieldError = DbError { table: "users" }.into_field_error();
+
+        assert_eq!(err.message(), "Internal error");
+        assert_eq!(err.extensions(), &Value::null());
+        assert_eq!(
+            err.source().and_then(|e| e.downcast_ref::<DbError>()),
+            Some(&DbError { table: "users" }),
+        );
+    }
+
+    #[test]
+    fn is_absent_by_default() {
+        let err: FieldError = FieldError::new("Oops", graphql_value!({"code": "OOPS"}));
+
+        assert!(err.source().is_none());
+    }
+
+    #[test]
+    fn is_kept_on_mapping() {
+        let err: FieldError = DbError { table: "users" }.into_field_error();
+        let err = err
+            .map_message(|m| format!("{m}!"))
+            .map_scalar_value::<DefaultScalarValue>();
+
+        assert_eq!(err.message(), "Internal error!");
+        assert!(err.source().unwrap().is::<DbError>());
+    }
+
+    #[test]
+    fn is_ignored_on_comparison() {
+        let err: FieldError = DbError { table: "users" }.into_field_error();
+
+        assert_eq!(err, FieldError::from("Internal error"));
+    }
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn users() -> Result<i32, DbError> {
+            Err(DbError { table: "users" })
+        }
+    }
+
+    #[tokio::test]
+    async fn is_exposed_by_execution_error() {
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+
+        let (_, errs) = crate::execute("{ users }", None, &schema, &graphql_vars! {}, &())
+            .await
+            .expect("Execution failed");
+
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].to_string(), "Internal error");
+        assert_eq!(
+            errs[0].source().and_then(|e| e.downcast_ref::<DbError>()),
+            Some(&DbError { table: "users" }),
+        );
+        assert_eq!(
+            serde_json::to_value(&errs).unwrap(),
+            serde_json::json!([{
+                "message": "Internal error",
+                "locations": [{"line": 1, "column": 3}],
+                "path": ["users"],
+            }]),
+        );
+    }
+}
+
+mod raw_arguments {
+    use crate::{
+        execute, execute_sync, graphql_object, graphql_value, graphql_vars,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+        Executor, GraphQLEnum, ScalarValue,
+    };
+
+    #[derive(Clone, Copy, Debug, GraphQLEnum)]
+    enum Color {
+        Red,
+        Blue,
+    }
+
+    /// Forwards the raw arguments of the currently resolved field, as if they
+    /// were passed to a downstream service.
+    fn forwarded<S: ScalarValue>(executor: &Executor<'_, '_, (), S>) -> String {
+        executor
+            .raw_arguments()
+            .iter()
+            .map(|(name, value)| format!("{name}: {value}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn forward<S: ScalarValue>(
+            color: Color,
+            name: Option<String>,
+            #[graphql(default = 10)] limit: i32,
+            executor: &Executor<'_, '_, (), S>,
+        ) -> String {
+            let _ = (color, name, limit);
+            forwarded(executor)
+        }
+
+        async fn forward_async<S: ScalarValue>(
+            color: Color,
+            executor: &Executor<'_, '_, (), S>,
+        ) -> String {
+            let _ = color;
+            forwarded(executor)
+        }
+
+        fn forward_owned<S: ScalarValue>(
+            color: Color,
+            executor: &Executor<'_, '_, (), S>,
+        ) -> String {
+            let _ = color;
+            forwarded(&executor.as_owned_executor().as_executor())
+        }
+
+        fn nested(id: i32) -> Nested {
+            Nested(id)
+        }
+    }
+
+    struct Nested(i32);
+
+    #[graphql_object]
+    impl Nested {
+        fn forward<S: ScalarValue>(
+            &self,
+            color: Color,
+            executor: &Executor<'_, '_, (), S>,
+        ) -> String {
+            let _ = (self.0, color);
+            forwarded(executor)
+        }
+    }
+
+    fn schema() -> RootNode<'static, Query, EmptyMutation, EmptySubscription> {
+        RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+    }
+
+    #[test]
+    fn preserves_enum_literal() {
+        let doc = r#"{ forward(color: RED, name: "BLUE") }"#;
+
+        assert_eq!(
+            execute_sync(doc, None, &schema(), &graphql_vars! {}, &()),
+            Ok((
+                graphql_value!({"forward": r#"color: RED, name: "BLUE", limit: 10"#}),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn resolves_variables() {
+        let doc = r#"query ($color: Color!, $limit: Int!) {
+            forward(color: $color, limit: $limit)
+        }"#;
+
+        assert_eq!(
+            execute_sync(
+                doc,
+                None,
+                &schema(),
+                &graphql_vars! {"color": BLUE, "limit": 3},
+                &(),
+            ),
+            Ok((graphql_value!({"forward": "color: BLUE, limit: 3"}), vec![])),
+        );
+    }
+
+    #[test]
+    fn are_scoped_to_current_field() {
+        let doc = r#"{ nested(id: 1) { forward(color: BLUE) } }"#;
+
+        assert_eq!(
+            execute_sync(doc, None, &schema(), &graphql_vars! {}, &()),
+            Ok((
+                graphql_value!({"nested": {"forward": "color: BLUE"}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn are_kept_by_owned_executor() {
+        let doc = r#"{ forwardOwned(color: RED) }"#;
+
+        assert_eq!(
+            execute_sync(doc, None, &schema(), &graphql_vars! {}, &()),
+            Ok((graphql_value!({"forwardOwned": "color: RED"}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn preserves_enum_literal_async() {
+        let doc = r#"{ forwardAsync(color: RED) }"#;
+
+        assert_eq!(
+            execute(doc, None, &schema(), &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"forwardAsync": "color: RED"}), vec![])),
+        );
+    }
+}
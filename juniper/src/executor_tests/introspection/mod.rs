@@ -7,9 +7,12 @@ use self::input_object::{NamedPublic, NamedPublicWithDescription};
 
 use crate::{
     graphql_interface, graphql_object, graphql_value, graphql_vars,
+    introspection::IntrospectionFormat,
+    parser::SourcePosition,
     schema::model::RootNode,
     types::scalars::{EmptyMutation, EmptySubscription},
-    GraphQLEnum, GraphQLScalar,
+    validation::RuleError,
+    GraphQLEnum, GraphQLError, GraphQLScalar,
 };
 
 #[derive(GraphQLEnum)]
@@ -521,3 +524,54 @@ async fn scalar_introspection() {
         }),
     );
 }
+
+#[tokio::test]
+async fn disabled_introspection() {
+    let doc = r#"
+    {
+        __typename
+        __type(name: "SampleScalar") {
+            name
+        }
+    }
+    "#;
+    let mut schema = RootNode::new(
+        Root,
+        EmptyMutation::<()>::new(),
+        EmptySubscription::<()>::new(),
+    )
+    .with_introspection_disabled();
+    assert!(schema.is_introspection_disabled());
+
+    assert_eq!(
+        crate::execute(doc, None, &schema, &graphql_vars! {}, &()).await,
+        Err(GraphQLError::ValidationError(vec![RuleError::new(
+            "GraphQL introspection is not allowed, but the operation contained `__type`",
+            &[SourcePosition::new(34, 3, 8)],
+        )])),
+    );
+    assert!(crate::introspect(&schema, &(), IntrospectionFormat::default()).is_err());
+
+    let (result, errs) = crate::execute("{ __typename }", None, &schema, &graphql_vars! {}, &())
+        .await
+        .expect("Execution failed");
+
+    assert_eq!(errs, []);
+    assert_eq!(result, graphql_value!({"__typename": "Root"}));
+
+    schema.enable_introspection();
+    assert!(!schema.is_introspection_disabled());
+
+    let (result, errs) = crate::execute(doc, None, &schema, &graphql_vars! {}, &())
+        .await
+        .expect("Execution failed");
+
+    assert_eq!(errs, []);
+    assert_eq!(
+        result,
+        graphql_value!({
+            "__typename": "Root",
+            "__type": {"name": "SampleScalar"},
+        }),
+    );
+}
@@ -1,3 +1,4 @@
+mod deadline;
 mod directives;
 mod enums;
 mod executor;
@@ -1156,6 +1156,68 @@ mod floats {
         .await;
     }
 
+    #[tokio::test]
+    async fn coercion_from_integer_literals_should_work() {
+        for (literal, expected) in [
+            ("10", "value: 10"),
+            ("10.5", "value: 10.5"),
+            ("-3", "value: -3"),
+        ] {
+            run_query(&format!("{{ floatInput(value: {literal}) }}"), |result| {
+                assert_eq!(
+                    result.get_field_value("floatInput"),
+                    Some(&graphql_value!(expected)),
+                );
+            })
+            .await;
+        }
+    }
+
+    #[tokio::test]
+    async fn coercion_from_integer_variables_should_work() {
+        for (var, expected) in [
+            (graphql_vars! {"var": 10}, "value: 10"),
+            (graphql_vars! {"var": 10.5}, "value: 10.5"),
+            (graphql_vars! {"var": -3}, "value: -3"),
+        ] {
+            run_variable_query(
+                r#"query q($var: Float!) { floatInput(value: $var) }"#,
+                var,
+                |result| {
+                    assert_eq!(
+                        result.get_field_value("floatInput"),
+                        Some(&graphql_value!(expected)),
+                    );
+                },
+            )
+            .await;
+        }
+    }
+
+    #[tokio::test]
+    async fn does_not_coerce_from_boolean_literal() {
+        let schema = RootNode::new(
+            TestType,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+
+        let query = r#"{ floatInput(value: true) }"#;
+        let vars = graphql_vars! {};
+
+        let error = crate::execute(query, None, &schema, &vars, &())
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            error,
+            ValidationError(vec![RuleError::new(
+                r#"Invalid value for argument "value", expected type "Float!""#,
+                &[SourcePosition::new(20, 0, 20)],
+            )]),
+        );
+    }
+
     #[tokio::test]
     async fn does_not_coerce_from_string() {
         let schema = RootNode::new(
@@ -1182,3 +1244,105 @@ mod floats {
         );
     }
 }
+
+mod builder {
+    use crate::{
+        execute, graphql_input_value, graphql_object, graphql_value, EmptyMutation,
+        EmptySubscription, GraphQLEnum, GraphQLInputObject, RootNode, VariablesBuilder,
+    };
+
+    #[derive(Clone, Copy, Debug, GraphQLEnum)]
+    enum Status {
+        Active,
+        Archived,
+    }
+
+    #[derive(Debug, GraphQLInputObject)]
+    struct Filter {
+        status: Status,
+        tags: Vec<String>,
+    }
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn echo(id: i32, name: Option<String>, status: Status, cursor: Option<String>) -> String {
+            format!("{id} {name:?} {status:?} {cursor:?}")
+        }
+
+        fn filter(filter: Filter) -> String {
+            format!("{filter:?}")
+        }
+    }
+
+    fn schema() -> RootNode<'static, Query, EmptyMutation, EmptySubscription> {
+        RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+    }
+
+    #[tokio::test]
+    async fn executes_with_typed_variables() {
+        const DOC: &str = r#"query($id: Int!, $name: String, $status: Status!, $cursor: String) {
+            echo(id: $id, name: $name, status: $status, cursor: $cursor)
+        }"#;
+
+        let vars = VariablesBuilder::new()
+            .insert("id", 5)
+            .insert("name", "Luke")
+            .insert_enum("status", "ARCHIVED")
+            .insert_null("cursor")
+            .build();
+
+        assert_eq!(
+            execute(DOC, None, &schema(), &vars, &()).await,
+            Ok((
+                graphql_value!({"echo": r#"5 Some("Luke") Archived None"#}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn executes_with_input_object_variable() {
+        const DOC: &str = r#"query($filter: Filter!) {
+            filter(filter: $filter)
+        }"#;
+
+        let vars = VariablesBuilder::new()
+            .insert_input_value(
+                "filter",
+                graphql_input_value!({"status": ACTIVE, "tags": ["new", "hot"]}),
+            )
+            .build();
+
+        assert_eq!(
+            execute(DOC, None, &schema(), &vars, &()).await,
+            Ok((
+                graphql_value!({
+                    "filter": r#"Filter { status: Active, tags: ["new", "hot"] }"#,
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn replaces_inserted_variables() {
+        const DOC: &str = r#"query($id: Int!, $status: Status!) {
+            echo(id: $id, status: $status)
+        }"#;
+
+        let vars = VariablesBuilder::new()
+            .insert("id", 1)
+            .insert_null("status")
+            .insert("id", 2)
+            .insert_enum("status", "ACTIVE")
+            .build();
+
+        assert_eq!(vars.len(), 2);
+        assert_eq!(
+            execute(DOC, None, &schema(), &vars, &()).await,
+            Ok((graphql_value!({"echo": "2 None Active None"}), vec![])),
+        );
+    }
+}
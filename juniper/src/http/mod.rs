@@ -1,6 +1,7 @@
 //! Utilities for building HTTP endpoints in a library-agnostic manner
 
 pub mod graphiql;
+pub mod persisted_query;
 pub mod playground;
 
 use serde::{
@@ -190,6 +191,66 @@ where
     }
 }
 
+#[cfg(feature = "json")]
+impl<S> GraphQLResponse<S>
+where
+    S: ScalarValue,
+{
+    /// Serializes this [`GraphQLResponse`] as JSON into the provided
+    /// [`io::Write`] incrementally, without building the whole JSON document
+    /// in memory.
+    ///
+    /// The `writer` isn't flushed, so consider wrapping it into an
+    /// [`io::BufWriter`] if it performs a syscall on each write.
+    ///
+    /// # Errors
+    ///
+    /// If writing into the `writer` fails.
+    ///
+    /// [`io::BufWriter`]: std::io::BufWriter
+    /// [`io::Write`]: std::io::Write
+    pub fn serialize_to_writer<W>(&self, writer: &mut W) -> std::io::Result<()>
+    where
+        W: std::io::Write + ?Sized,
+    {
+        serde_json::to_writer(writer, self).map_err(Into::into)
+    }
+
+    /// Serializes this [`GraphQLResponse`] as JSON into the provided
+    /// [`AsyncWrite`] incrementally, buffering at most a few kilobytes of the
+    /// `data` at once.
+    ///
+    /// The `writer` isn't flushed.
+    ///
+    /// # Errors
+    ///
+    /// If writing into the `writer` fails.
+    ///
+    /// [`AsyncWrite`]: futures::io::AsyncWrite
+    pub async fn serialize_to_async_writer<W>(&self, writer: &mut W) -> std::io::Result<()>
+    where
+        W: futures::io::AsyncWrite + Unpin + ?Sized,
+    {
+        use futures::io::AsyncWriteExt as _;
+
+        match &self.0 {
+            Ok((data, errs)) => {
+                writer.write_all(br#"{"data":"#).await?;
+                let mut chunks = crate::integrations::json::Chunks::new(data);
+                while let Some(chunk) = chunks.next_chunk()? {
+                    writer.write_all(chunk).await?;
+                }
+                if !errs.is_empty() {
+                    writer.write_all(br#","errors":"#).await?;
+                    writer.write_all(&serde_json::to_vec(errs)?).await?;
+                }
+                writer.write_all(b"}").await
+            }
+            Err(_) => writer.write_all(&serde_json::to_vec(self)?).await,
+        }
+    }
+}
+
 impl<T> Serialize for GraphQLResponse<T>
 where
     T: Serialize + ScalarValue,
@@ -0,0 +1,249 @@
+//! Utilities for supporting [Automatic Persisted Queries][0] (APQ), allowing
+//! clients to send a hash of a query instead of the whole query text.
+//!
+//! [0]: https://www.apollographql.com/docs/apollo-server/performance/apq
+
+use std::collections::HashMap;
+
+use crate::{
+    ast::OwnedDocument,
+    parser::{parse_document_source, ParseError, Spanning},
+    value::ScalarValue,
+    GraphQLType, RootNode,
+};
+
+/// Calculates the hash identifying the provided `query` in the [Automatic
+/// Persisted Queries][0] protocol: its SHA-256 digest, encoded as lowercase
+/// hex string.
+///
+/// ```
+/// # use juniper::http::persisted_query::persisted_query_hash;
+/// assert_eq!(
+///     persisted_query_hash("{__typename}"),
+///     "ecf4edb46db40b5132295c0291d62fb65d6759a9eedfa4d5d612dd5ec54a6b38",
+/// );
+/// ```
+///
+/// [0]: https://www.apollographql.com/docs/apollo-server/performance/apq
+#[cfg(feature = "sha2")]
+pub fn persisted_query_hash(query: &str) -> String {
+    use std::fmt::Write as _;
+
+    use sha2::{Digest as _, Sha256};
+
+    Sha256::digest(query.as_bytes())
+        .iter()
+        .fold(String::with_capacity(64), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        })
+}
+
+/// Cache of persisted queries, mapping their hashes to their sources.
+#[cfg_attr(
+    feature = "sha2",
+    doc = "",
+    doc = "The hashes are expected to be calculated via [`persisted_query_hash()`]."
+)]
+///
+/// Queries are parsed on insertion, so the ones failing to parse are never
+/// persisted. As a [`Document`] borrows from its source, it's parsed again on
+/// each [`PersistedQueries::document()`] call.
+///
+/// No interior mutability is provided, so wrap it into a [`RwLock`] (or
+/// similar) to share it between concurrently handled requests.
+///
+/// [`Document`]: crate::ast::Document
+/// [`RwLock`]: std::sync::RwLock
+#[derive(Clone, Debug, Default)]
+pub struct PersistedQueries {
+    queries: HashMap<String, String>,
+}
+
+impl PersistedQueries {
+    /// Creates a new empty [`PersistedQueries`] cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses the provided `query` for the provided `root_node` schema and
+    /// persists it under the provided `hash`, returning the source of the query
+    /// previously persisted under it, if any.
+    ///
+    /// Nothing is persisted if the `query` fails to parse.
+    ///
+    /// The `hash` is trusted to identify the `query`, so should be verified
+    /// beforehand when it comes from a client.
+    pub fn insert<QueryT, MutationT, SubscriptionT, S>(
+        &mut self,
+        hash: impl Into<String>,
+        query: impl Into<String>,
+        root_node: &RootNode<'_, QueryT, MutationT, SubscriptionT, S>,
+    ) -> Result<Option<String>, Spanning<ParseError>>
+    where
+        QueryT: GraphQLType<S>,
+        MutationT: GraphQLType<S>,
+        SubscriptionT: GraphQLType<S>,
+        S: ScalarValue,
+    {
+        let query = query.into();
+        parse_document_source(&query, &root_node.schema)?;
+        Ok(self.queries.insert(hash.into(), query))
+    }
+
+    /// Parses the provided `query` for the provided `root_node` schema and
+    /// persists it under its [`persisted_query_hash()`], returning the hash.
+    #[cfg(feature = "sha2")]
+    pub fn insert_query<QueryT, MutationT, SubscriptionT, S>(
+        &mut self,
+        query: impl Into<String>,
+        root_node: &RootNode<'_, QueryT, MutationT, SubscriptionT, S>,
+    ) -> Result<String, Spanning<ParseError>>
+    where
+        QueryT: GraphQLType<S>,
+        MutationT: GraphQLType<S>,
+        SubscriptionT: GraphQLType<S>,
+        S: ScalarValue,
+    {
+        let query = query.into();
+        let hash = persisted_query_hash(&query);
+        self.insert(hash.clone(), query, root_node)?;
+        Ok(hash)
+    }
+
+    /// Returns the source of the query persisted under the provided `hash`,
+    /// if any.
+    pub fn get(&self, hash: &str) -> Option<&str> {
+        self.queries.get(hash).map(String::as_str)
+    }
+
+    /// Parses the query persisted under the provided `hash` (if any) for the
+    /// provided `root_node` schema into an [`OwnedDocument`].
+    ///
+    /// Parsing fails only if the `root_node` differs from the one the query
+    /// has been inserted with.
+    pub fn document<'q, QueryT, MutationT, SubscriptionT, S>(
+        &'q self,
+        hash: &str,
+        root_node: &RootNode<'_, QueryT, MutationT, SubscriptionT, S>,
+    ) -> Option<Result<OwnedDocument<'q, S>, Spanning<ParseError>>>
+    where
+        QueryT: GraphQLType<S>,
+        MutationT: GraphQLType<S>,
+        SubscriptionT: GraphQLType<S>,
+        S: ScalarValue,
+    {
+        self.queries
+            .get(hash)
+            .map(|query| parse_document_source(query, &root_node.schema))
+    }
+
+    /// Removes the query persisted under the provided `hash`, returning its
+    /// source, if any.
+    pub fn remove(&mut self, hash: &str) -> Option<String> {
+        self.queries.remove(hash)
+    }
+
+    /// Returns the number of the persisted queries.
+    pub fn len(&self) -> usize {
+        self.queries.len()
+    }
+
+    /// Indicates whether no queries are persisted.
+    pub fn is_empty(&self) -> bool {
+        self.queries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ast::{Definition, Operation, OperationType},
+        graphql_object, EmptyMutation, EmptySubscription, RootNode,
+    };
+
+    use super::PersistedQueries;
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn hello() -> &'static str {
+            "world"
+        }
+    }
+
+    fn schema() -> RootNode<'static, Query, EmptyMutation, EmptySubscription> {
+        RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+    }
+
+    #[test]
+    fn looks_up_persisted_query() {
+        let schema = schema();
+        let mut queries = PersistedQueries::new();
+        assert!(queries.is_empty());
+
+        assert_eq!(queries.insert("abc", "{ hello }", &schema), Ok(None));
+        assert_eq!(
+            queries.insert("abc", "{ hello }", &schema),
+            Ok(Some("{ hello }".into())),
+        );
+
+        assert_eq!(queries.len(), 1);
+        assert_eq!(queries.get("abc"), Some("{ hello }"));
+        assert_eq!(queries.get("def"), None);
+
+        assert_eq!(queries.remove("abc"), Some("{ hello }".into()));
+        assert_eq!(queries.get("abc"), None);
+    }
+
+    #[test]
+    fn parses_persisted_query_on_insertion() {
+        let schema = schema();
+        let mut queries = PersistedQueries::new();
+        queries
+            .insert("valid", "query Greet { hello }", &schema)
+            .unwrap();
+        assert!(queries
+            .insert("invalid", "query Greet { hello", &schema)
+            .is_err());
+
+        let doc = queries.document("valid", &schema).unwrap().unwrap();
+        assert!(matches!(
+            &doc[..],
+            [Definition::Operation(op)] if matches!(
+                op.item,
+                Operation {
+                    operation_type: OperationType::Query,
+                    name: Some(ref name),
+                    ..
+                } if name.item == "Greet",
+            ),
+        ));
+
+        assert!(queries.get("invalid").is_none());
+        assert!(queries.document("unknown", &schema).is_none());
+    }
+
+    #[cfg(feature = "sha2")]
+    #[test]
+    fn hashes_persisted_query() {
+        use super::persisted_query_hash;
+
+        assert_eq!(
+            persisted_query_hash(""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        );
+
+        let schema = schema();
+        let mut queries = PersistedQueries::new();
+        let hash = queries.insert_query("{__typename}", &schema).unwrap();
+
+        assert_eq!(
+            hash,
+            "ecf4edb46db40b5132295c0291d62fb65d6759a9eedfa4d5d612dd5ec54a6b38",
+        );
+        assert_eq!(queries.get(&hash), Some("{__typename}"));
+        assert!(matches!(queries.document(&hash, &schema), Some(Ok(_))));
+    }
+}
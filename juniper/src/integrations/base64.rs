@@ -0,0 +1,149 @@
+//! GraphQL support for binary data encoded with [base64].
+//!
+//! # Supported types
+//!
+//! | Rust type  | Format                  | GraphQL scalar |
+//! |------------|-------------------------|----------------|
+//! | [`Base64`] | [RFC 4648] base64       | `Base64`       |
+//!
+//! [base64]: https://docs.rs/base64
+//! [RFC 4648]: https://datatracker.ietf.org/doc/html/rfc4648#section-4
+
+use std::ops::{Deref, DerefMut};
+
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+
+use crate::{GraphQLScalar, InputValue, ScalarValue, Value};
+
+/// Binary data, represented as a string in the standard padded
+/// [base64 encoding][0] (`SGVsbG8=`, for example).
+///
+/// Invalid base64 strings (containing characters outside the standard
+/// alphabet, or missing the padding) are rejected on input.
+///
+/// This is a wrapper type rather than a direct implementation for [`Vec`] of
+/// [`u8`]s, so it doesn't get confused with a list of integers.
+///
+/// [0]: https://datatracker.ietf.org/doc/html/rfc4648#section-4
+#[derive(Clone, Debug, Default, Eq, GraphQLScalar, Hash, PartialEq)]
+#[graphql(
+    parse_token(String),
+    specified_by_url = "https://datatracker.ietf.org/doc/html/rfc4648#section-4"
+)]
+pub struct Base64(pub Vec<u8>);
+
+impl Base64 {
+    fn to_output<S: ScalarValue>(&self) -> Value<S> {
+        Value::scalar(STANDARD.encode(&self.0))
+    }
+
+    fn from_input<S: ScalarValue>(v: &InputValue<S>) -> Result<Self, String> {
+        v.as_string_value()
+            .ok_or_else(|| format!("Expected `String`, found: {v}"))
+            .and_then(|s| {
+                STANDARD
+                    .decode(s)
+                    .map(Self)
+                    .map_err(|e| format!("Invalid `Base64`: {e}"))
+            })
+    }
+
+    /// Unwraps the underlying bytes.
+    #[must_use]
+    pub fn into_inner(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl From<Vec<u8>> for Base64 {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<Base64> for Vec<u8> {
+    fn from(b: Base64) -> Self {
+        b.0
+    }
+}
+
+impl Deref for Base64 {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Base64 {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{graphql_input_value, FromInputValue as _, InputValue, ToInputValue as _};
+
+    use super::Base64;
+
+    #[test]
+    fn parses_correct_input() {
+        for (raw, expected) in [
+            ("", &b""[..]),
+            ("Zg==", b"f"),
+            ("Zm8=", b"fo"),
+            ("Zm9v", b"foo"),
+            ("SGVsbG8sIFdvcmxkIQ==", b"Hello, World!"),
+            ("AP8Q/+8=", &[0x00, 0xFF, 0x10, 0xFF, 0xEF]),
+        ] {
+            let input: InputValue = graphql_input_value!((raw));
+            let parsed = Base64::from_input_value(&input);
+
+            assert!(
+                parsed.is_ok(),
+                "failed to parse `{raw}`: {:?}",
+                parsed.unwrap_err(),
+            );
+            assert_eq!(parsed.unwrap().0, expected, "input: {raw}");
+        }
+    }
+
+    #[test]
+    fn fails_on_invalid_input() {
+        for input in [
+            graphql_input_value!("Zg"),
+            graphql_input_value!("Zg="),
+            graphql_input_value!("Zm9v!"),
+            graphql_input_value!("Zm9v-_"),
+            graphql_input_value!("Z m9v"),
+            graphql_input_value!("i'm not even base64"),
+            graphql_input_value!(2.32),
+            graphql_input_value!(1),
+            graphql_input_value!(null),
+            graphql_input_value!(false),
+        ] {
+            let input: InputValue = input;
+            let parsed = Base64::from_input_value(&input);
+
+            assert!(parsed.is_err(), "allows input: {input:?}");
+        }
+    }
+
+    #[test]
+    fn formats_correctly() {
+        for (val, expected) in [
+            (Base64(vec![]), graphql_input_value!("")),
+            (Base64(b"f".to_vec()), graphql_input_value!("Zg==")),
+            (Base64(b"foo".to_vec()), graphql_input_value!("Zm9v")),
+            (
+                Base64(vec![0x00, 0xFF, 0x10, 0xFF, 0xEF]),
+                graphql_input_value!("AP8Q/+8="),
+            ),
+        ] {
+            let actual: InputValue = val.to_input_value();
+
+            assert_eq!(actual, expected, "on value: {val:?}");
+        }
+    }
+}
@@ -0,0 +1,241 @@
+//! GraphQL support for [`std::time::Duration`].
+//!
+//! # Supported types
+//!
+//! | Rust type    | Format                | GraphQL scalar      |
+//! |--------------|-----------------------|---------------------|
+//! | [`Duration`] | [ISO 8601] duration   | [`Duration`][s1]    |
+//!
+//! [`Duration`]: std::time::Duration
+//! [ISO 8601]: https://en.wikipedia.org/wiki/ISO_8601#Durations
+//! [s1]: https://graphql-scalars.dev/docs/scalars/duration
+
+use crate::{graphql_scalar, InputValue, ScalarValue, Value};
+
+/// Non-negative span of time in [ISO 8601 duration format][0]
+/// (`PT1H30M`, for example).
+///
+/// On output, the duration is always expressed in hours, minutes and
+/// (possibly fractional) seconds. On input, weeks (`W`) and days (`D`) are
+/// accepted too, being treated as exactly 7 and 24 hours respectively, while
+/// years (`Y`) and months (`M` before `T`) are rejected, as they don't have
+/// a fixed length.
+///
+/// [`Duration` scalar][1] compliant.
+///
+/// See also [`std::time::Duration`][2] for details.
+///
+/// [0]: https://en.wikipedia.org/wiki/ISO_8601#Durations
+/// [1]: https://graphql-scalars.dev/docs/scalars/duration
+/// [2]: https://doc.rust-lang.org/std/time/struct.Duration.html
+#[graphql_scalar(
+    with = iso8601,
+    parse_token(String),
+    specified_by_url = "https://graphql-scalars.dev/docs/scalars/duration",
+)]
+pub type Duration = std::time::Duration;
+
+mod iso8601 {
+    use std::fmt::Write as _;
+
+    use super::*;
+
+    pub(super) fn to_output<S: ScalarValue>(v: &Duration) -> Value<S> {
+        let secs = v.as_secs();
+        let (hours, minutes, seconds) = (secs / 3600, secs % 3600 / 60, secs % 60);
+        let nanos = v.subsec_nanos();
+
+        let mut out = String::from("PT");
+        if hours > 0 {
+            write!(out, "{hours}H").unwrap();
+        }
+        if minutes > 0 {
+            write!(out, "{minutes}M").unwrap();
+        }
+        if seconds > 0 || nanos > 0 || secs == 0 {
+            write!(out, "{seconds}").unwrap();
+            if nanos > 0 {
+                write!(out, ".{}", format!("{nanos:09}").trim_end_matches('0')).unwrap();
+            }
+            out.push('S');
+        }
+        Value::scalar(out)
+    }
+
+    pub(super) fn from_input<S: ScalarValue>(v: &InputValue<S>) -> Result<Duration, String> {
+        v.as_string_value()
+            .ok_or_else(|| format!("Expected `String`, found: {v}"))
+            .and_then(|s| parse(s).map_err(|e| format!("Invalid `Duration`: {e}")))
+    }
+
+    /// Parses the provided ISO 8601 duration string into a [`Duration`].
+    fn parse(s: &str) -> Result<Duration, String> {
+        if s.starts_with('-') {
+            return Err("negative durations are not supported".into());
+        }
+        let s = s
+            .strip_prefix('P')
+            .ok_or_else(|| format!("`{s}` doesn't start with `P`"))?;
+        let (date, time) = match s.split_once('T') {
+            Some((_, "")) => return Err("no components after `T`".into()),
+            Some((date, time)) => (date, time),
+            None if s.is_empty() => return Err("no components after `P`".into()),
+            None => (s, ""),
+        };
+
+        let mut secs = 0_u64;
+        let mut nanos = 0_u32;
+        for (number, designator) in components(date, "WD")?
+            .into_iter()
+            .chain(components(time, "HMS")?)
+        {
+            let (integer, fraction) = match number.split_once(['.', ',']) {
+                Some((i, f)) if designator == "S" => (i, Some(f)),
+                Some(_) => {
+                    return Err(format!(
+                        "only seconds may be fractional, found: `{number}{designator}`",
+                    ));
+                }
+                None => (number, None),
+            };
+            if integer.is_empty() || fraction.map_or(false, str::is_empty) {
+                return Err(format!("malformed number `{number}`"));
+            }
+            if let Some(f) = fraction {
+                if f.len() > 9 {
+                    return Err(format!("precision of `{number}` exceeds nanoseconds"));
+                }
+                nanos = format!("{f:0<9}")
+                    .parse()
+                    .map_err(|_| format!("malformed number `{number}`"))?;
+            }
+            let multiplier = match designator {
+                "W" => 7 * 24 * 3600,
+                "D" => 24 * 3600,
+                "H" => 3600,
+                "M" => 60,
+                _ => 1,
+            };
+            secs = integer
+                .parse::<u64>()
+                .ok()
+                .and_then(|n| n.checked_mul(multiplier))
+                .and_then(|n| n.checked_add(secs))
+                .ok_or_else(|| format!("`{number}{designator}` is out of range"))?;
+        }
+        Ok(Duration::new(secs, nanos))
+    }
+
+    /// Splits the provided `part` of an ISO 8601 duration into its
+    /// `(number, designator)` components, ensuring that designators are unique
+    /// and follow in the order of the provided `allowed` ones.
+    fn components<'a>(
+        mut part: &'a str,
+        mut allowed: &str,
+    ) -> Result<Vec<(&'a str, &'a str)>, String> {
+        let mut out = Vec::new();
+        while !part.is_empty() {
+            let end = part
+                .find(|c: char| !c.is_ascii_digit() && c != '.' && c != ',')
+                .ok_or_else(|| format!("no designator after `{part}`"))?;
+            let (number, rest) = part.split_at(end);
+            let len = rest.chars().next().map_or(0, char::len_utf8);
+            let (designator, rest) = rest.split_at(len);
+            let pos = allowed
+                .find(designator)
+                .ok_or_else(|| format!("unexpected designator `{designator}`"))?;
+            if number.is_empty() {
+                return Err(format!("no number before `{designator}`"));
+            }
+            allowed = &allowed[pos + 1..];
+            out.push((number, designator));
+            part = rest;
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use crate::{graphql_input_value, FromInputValue as _, InputValue, ToInputValue as _};
+
+    #[test]
+    fn parses_correct_input() {
+        for (raw, expected) in [
+            ("PT1H30M", Duration::from_secs(5400)),
+            ("PT0S", Duration::ZERO),
+            ("P0D", Duration::ZERO),
+            ("PT90M", Duration::from_secs(5400)),
+            ("PT1.5S", Duration::from_millis(1500)),
+            ("PT0,000000001S", Duration::from_nanos(1)),
+            ("P1W2DT3H4M5.06S", Duration::new(788_645, 60_000_000)),
+            ("P1D", Duration::from_secs(86_400)),
+        ] {
+            let input: InputValue = graphql_input_value!((raw));
+            let parsed = Duration::from_input_value(&input);
+
+            assert!(
+                parsed.is_ok(),
+                "failed to parse `{raw}`: {:?}",
+                parsed.unwrap_err(),
+            );
+            assert_eq!(parsed.unwrap(), expected, "input: {raw}");
+        }
+    }
+
+    #[test]
+    fn fails_on_invalid_input() {
+        for input in [
+            graphql_input_value!("-PT1H"),
+            graphql_input_value!("PT-1H"),
+            graphql_input_value!("P"),
+            graphql_input_value!("PT"),
+            graphql_input_value!("P1DT"),
+            graphql_input_value!("1H30M"),
+            graphql_input_value!("PT1H30"),
+            graphql_input_value!("PT30M1H"),
+            graphql_input_value!("PT1H1H"),
+            graphql_input_value!("P1Y"),
+            graphql_input_value!("P1M"),
+            graphql_input_value!("PT1.5H"),
+            graphql_input_value!("PT.5S"),
+            graphql_input_value!("PT1.S"),
+            graphql_input_value!("PT0.0000000001S"),
+            graphql_input_value!("PTH"),
+            graphql_input_value!("PT1Ж"),
+            graphql_input_value!("PT99999999999999999999S"),
+            graphql_input_value!("P99999999999999999W"),
+            graphql_input_value!("i'm not even a duration"),
+            graphql_input_value!(2.32),
+            graphql_input_value!(1),
+            graphql_input_value!(null),
+            graphql_input_value!(false),
+        ] {
+            let input: InputValue = input;
+            let parsed = Duration::from_input_value(&input);
+
+            assert!(parsed.is_err(), "allows input: {input:?}");
+        }
+    }
+
+    #[test]
+    fn formats_correctly() {
+        for (val, expected) in [
+            (Duration::ZERO, graphql_input_value!("PT0S")),
+            (Duration::from_secs(5400), graphql_input_value!("PT1H30M")),
+            (Duration::from_secs(3605), graphql_input_value!("PT1H5S")),
+            (Duration::from_secs(90_000), graphql_input_value!("PT25H")),
+            (Duration::from_millis(1500), graphql_input_value!("PT1.5S")),
+            (
+                Duration::from_nanos(1),
+                graphql_input_value!("PT0.000000001S"),
+            ),
+        ] {
+            let actual: InputValue = val.to_input_value();
+
+            assert_eq!(actual, expected, "on value: {val:?}");
+        }
+    }
+}
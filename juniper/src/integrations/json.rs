@@ -0,0 +1,671 @@
+//! Incremental JSON serialization of GraphQL [`Value`]s, conversions of
+//! [`InputValue`]s from/into [`serde_json::Value`]s, and a [`Json`] scalar.
+//!
+//! Allows writing huge results directly into an [`io::Write`] or an
+//! [`AsyncWrite`] without building any intermediate representation of the
+//! whole JSON document in memory.
+
+use std::{io, slice};
+
+use futures::io::{AsyncWrite, AsyncWriteExt as _};
+use serde::{Deserialize, Serialize};
+
+use crate::{DefaultScalarValue, GraphQLScalar, InputValue, Object, ScalarValue, Value};
+
+/// Size of a buffer, after reaching which the [`Chunks`] are flushed into an
+/// [`AsyncWrite`].
+const CHUNK_SIZE: usize = 8 * 1024;
+
+impl<S: Serialize> Value<S> {
+    /// Serializes this [`Value`] as JSON into the provided [`io::Write`]
+    /// incrementally, without building the whole JSON document in memory.
+    ///
+    /// The `writer` isn't flushed, so consider wrapping it into an
+    /// [`io::BufWriter`] if it performs a syscall on each write.
+    ///
+    /// # Errors
+    ///
+    /// If writing into the `writer` fails.
+    pub fn serialize_to_writer<W: io::Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        serde_json::to_writer(writer, self).map_err(Into::into)
+    }
+
+    /// Serializes this [`Value`] as JSON into the provided [`AsyncWrite`]
+    /// incrementally, buffering at most a few kilobytes of JSON at once.
+    ///
+    /// The `writer` isn't flushed.
+    ///
+    /// # Errors
+    ///
+    /// If writing into the `writer` fails.
+    pub async fn serialize_to_async_writer<W: AsyncWrite + Unpin + ?Sized>(
+        &self,
+        writer: &mut W,
+    ) -> io::Result<()> {
+        let mut chunks = Chunks::new(self);
+        while let Some(chunk) = chunks.next_chunk()? {
+            writer.write_all(chunk).await?;
+        }
+        Ok(())
+    }
+}
+
+impl<S: ScalarValue> InputValue<S> {
+    /// Converts the provided [`serde_json::Value`] into an [`InputValue`].
+    ///
+    /// JSON numbers, strings and booleans become [`InputValue::Scalar`]s the
+    /// same way they do when deserializing [`InputValue`] from JSON (so a
+    /// number becomes an `Int` if fits into it, or a `Float` otherwise, for
+    /// the [`DefaultScalarValue`]). If the [`ScalarValue`] fails to
+    /// deserialize from a JSON number, then it becomes an `Int` if fits into
+    /// [`i32`], or a `Float` otherwise.
+    ///
+    /// Since JSON has no notion of GraphQL enums and variables, the result
+    /// never contains [`InputValue::Enum`]s or [`InputValue::Variable`]s:
+    /// JSON strings always become string scalars, which are coerced into enum
+    /// values when used as [variables][0].
+    ///
+    /// The order of the object keys is the one of the [`serde_json::Map`],
+    /// which is preserved only with the `preserve_order` feature of the
+    /// [`serde_json`] crate enabled (being sorted otherwise).
+    ///
+    /// [`DefaultScalarValue`]: crate::DefaultScalarValue
+    /// [0]: https://spec.graphql.org/October2021#sec-Coercing-Variable-Values
+    #[must_use]
+    pub fn from_json(json: serde_json::Value) -> Self {
+        use serde_json::Value as Json;
+
+        match json {
+            Json::Null => Self::Null,
+            Json::Bool(b) => Self::scalar(b),
+            Json::Number(n) => Self::Scalar(scalar_from_json_number(&n)),
+            Json::String(s) => Self::scalar(s),
+            Json::Array(l) => Self::list(l.into_iter().map(Self::from_json).collect()),
+            Json::Object(o) => Self::object(
+                o.into_iter()
+                    .map(|(k, v)| (k, Self::from_json(v)))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Converts this [`InputValue`] into a [`serde_json::Value`].
+    ///
+    /// [`InputValue::Scalar`]s are converted with their [`Serialize`]
+    /// implementation, while [`InputValue::Enum`]s become JSON strings, so
+    /// cannot be distinguished from string scalars anymore. As JSON cannot
+    /// represent a reference to a variable, [`InputValue::Variable`]s become
+    /// JSON `null`s, so consider [resolving them][0] beforehand.
+    ///
+    /// The order of the object keys is preserved only with the
+    /// `preserve_order` feature of the [`serde_json`] crate enabled (being
+    /// sorted otherwise).
+    ///
+    /// [0]: InputValue::into_const
+    #[must_use]
+    pub fn to_json(&self) -> serde_json::Value {
+        use serde_json::Value as Json;
+
+        match self {
+            Self::Null | Self::Variable(_) => Json::Null,
+            Self::Scalar(s) => serde_json::to_value(s).unwrap_or(Json::Null),
+            Self::Enum(e) => Json::String(e.clone()),
+            Self::List(l) => Json::Array(l.iter().map(|v| v.item.to_json()).collect()),
+            Self::Object(o) => Json::Object(
+                o.iter()
+                    .map(|(k, v)| (k.item.clone(), v.item.to_json()))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl DefaultScalarValue {
+    /// Converts the provided [`serde_json::Number`] into a [`DefaultScalarValue`].
+    ///
+    /// The conversion is the same as for any other [`ScalarValue`] in
+    /// [`InputValue::from_json()`] and [`Json`] (via its [`Deserialize`]
+    /// implementation), so custom [`ScalarValue`]s having wider integer
+    /// variants keep [`i64`]s and [`u64`]s as is.
+    ///
+    /// Integral numbers fitting into [`i32`] become [`DefaultScalarValue::Int`]s,
+    /// while fractional ones (even having zero fractional part, like `1.0`)
+    /// become [`DefaultScalarValue::Float`]s, so the kind of the number is
+    /// preserved.
+    ///
+    /// As [`DefaultScalarValue`] has no wider integer variant, integral numbers
+    /// outside [`i32`] range fall back to [`DefaultScalarValue::Float`]s. Their
+    /// magnitude is preserved, but they're represented exactly only up to
+    /// 2<sup>53</sup> by absolute value, being rounded to the nearest [`f64`]
+    /// otherwise.
+    #[must_use]
+    pub fn from_json_number(n: &serde_json::Number) -> Self {
+        scalar_from_json_number(n)
+    }
+
+    /// Converts this [`DefaultScalarValue`] into a [`serde_json::Number`], if
+    /// it's a numeric one.
+    ///
+    /// [`DefaultScalarValue::Int`]s become integral numbers, and
+    /// [`DefaultScalarValue::Float`]s become fractional ones, so converting a
+    /// [`DefaultScalarValue::from_json_number()`] result back produces the
+    /// same number, unless it was an integer outside [`i32`] range (which comes
+    /// back as a float of the same magnitude).
+    ///
+    /// Returns [`None`] for non-numeric values, and for infinite and NaN
+    /// [`DefaultScalarValue::Float`]s, as JSON cannot represent them.
+    #[must_use]
+    pub fn to_json_number(&self) -> Option<serde_json::Number> {
+        match self {
+            Self::Int(i) => Some((*i).into()),
+            Self::Float(f) => serde_json::Number::from_f64(*f),
+            Self::String(_) | Self::Boolean(_) => None,
+        }
+    }
+}
+
+/// Converts the provided JSON number into a [`ScalarValue`], falling back to an
+/// `Int` if it fits into [`i32`], or a `Float` otherwise, if the [`ScalarValue`]
+/// fails to deserialize from it.
+fn scalar_from_json_number<S: ScalarValue>(n: &serde_json::Number) -> S {
+    S::deserialize(n).unwrap_or_else(|_| {
+        n.as_i64()
+            .and_then(|i| i32::try_from(i).ok())
+            .map_or_else(|| n.as_f64().unwrap_or(f64::NAN).into(), Into::into)
+    })
+}
+
+/// [GraphQL scalar][0] representing an arbitrary JSON value (including nested
+/// lists and objects) as a single `JSON` scalar.
+///
+/// Accepts any input value: literal lists and objects are allowed in queries,
+/// and variables are passed as is. As GraphQL enum values have no JSON
+/// counterpart, they become JSON strings, while any variables nested into a
+/// literal become JSON `null`s.
+///
+/// > __WARNING:__ This scalar bypasses the type safety guaranteed by GraphQL,
+/// >              as neither the shape of the value is described in the schema,
+/// >              nor can it be queried selectively. Prefer dedicated
+/// >              [GraphQL objects][1] and [input objects][2] wherever
+/// >              possible, and use it sparingly.
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Scalars
+/// [1]: https://spec.graphql.org/October2021#sec-Objects
+/// [2]: https://spec.graphql.org/October2021#sec-Input-Objects
+#[derive(Clone, Debug, Default, Deserialize, GraphQLScalar, PartialEq, Serialize)]
+#[graphql(
+    name = "JSON",
+    to_output_with = Self::to_output,
+    from_input_with = Self::from_input,
+    parse_token(String, i32, f64),
+    structured,
+)]
+#[serde(transparent)]
+pub struct Json(pub serde_json::Value);
+
+impl Json {
+    fn to_output<S: ScalarValue>(&self) -> Value<S> {
+        fn convert<S: ScalarValue>(json: &serde_json::Value) -> Value<S> {
+            use serde_json::Value as Json;
+
+            match json {
+                Json::Null => Value::null(),
+                Json::Bool(b) => Value::scalar(*b),
+                Json::Number(n) => Value::Scalar(scalar_from_json_number(n)),
+                Json::String(s) => Value::scalar(s.clone()),
+                Json::Array(l) => Value::list(l.iter().map(convert).collect()),
+                Json::Object(o) => {
+                    Value::object(o.iter().map(|(k, v)| (k.as_str(), convert(v))).collect())
+                }
+            }
+        }
+
+        convert(&self.0)
+    }
+
+    fn from_input<S: ScalarValue>(v: &InputValue<S>) -> Result<Self, String> {
+        Ok(Self(v.to_json()))
+    }
+}
+
+impl From<serde_json::Value> for Json {
+    fn from(json: serde_json::Value) -> Self {
+        Self(json)
+    }
+}
+
+impl From<Json> for serde_json::Value {
+    fn from(json: Json) -> Self {
+        json.0
+    }
+}
+
+/// Encoder of a [`Value`] into JSON, producing it in chunks of roughly
+/// [`CHUNK_SIZE`] bytes.
+pub(crate) struct Chunks<'a, S> {
+    /// Parts of the [`Value`] remaining to be encoded, with the next one on
+    /// top.
+    stack: Vec<Step<'a, S>>,
+
+    /// Buffer of the currently encoded chunk.
+    buf: Vec<u8>,
+}
+
+/// Single step of encoding a [`Value`] into JSON.
+enum Step<'a, S> {
+    /// Encoding of the whole [`Value`].
+    Value(&'a Value<S>),
+
+    /// Encoding of the remaining elements of a [`Value::List`].
+    List {
+        items: slice::Iter<'a, Value<S>>,
+        is_first: bool,
+    },
+
+    /// Encoding of the remaining entries of a [`Value::Object`].
+    Object {
+        entries: Box<dyn Iterator<Item = (&'a String, &'a Value<S>)> + 'a>,
+        is_first: bool,
+    },
+}
+
+impl<'a, S: Serialize> Chunks<'a, S> {
+    /// Creates a new [`Chunks`] encoder of the provided [`Value`].
+    pub(crate) fn new(value: &'a Value<S>) -> Self {
+        Self {
+            stack: vec![Step::Value(value)],
+            buf: Vec::with_capacity(CHUNK_SIZE),
+        }
+    }
+
+    /// Encodes the next chunk of JSON, returning [`None`] once the whole
+    /// [`Value`] has been encoded.
+    pub(crate) fn next_chunk(&mut self) -> io::Result<Option<&[u8]>> {
+        self.buf.clear();
+        while self.buf.len() < CHUNK_SIZE {
+            let step = if let Some(step) = self.stack.pop() {
+                step
+            } else {
+                break;
+            };
+            match step {
+                Step::Value(Value::Null) => self.buf.extend_from_slice(b"null"),
+                Step::Value(Value::Scalar(s)) => serde_json::to_writer(&mut self.buf, s)?,
+                Step::Value(Value::List(l)) => {
+                    self.buf.push(b'[');
+                    self.stack.push(Step::List {
+                        items: l.iter(),
+                        is_first: true,
+                    });
+                }
+                Step::Value(Value::Object(o)) => {
+                    self.buf.push(b'{');
+                    self.stack.push(Step::Object {
+                        entries: Box::new(Object::iter(o)),
+                        is_first: true,
+                    });
+                }
+                Step::List {
+                    mut items,
+                    is_first,
+                } => {
+                    if let Some(item) = items.next() {
+                        if !is_first {
+                            self.buf.push(b',');
+                        }
+                        self.stack.push(Step::List {
+                            items,
+                            is_first: false,
+                        });
+                        self.stack.push(Step::Value(item));
+                    } else {
+                        self.buf.push(b']');
+                    }
+                }
+                Step::Object {
+                    mut entries,
+                    is_first,
+                } => {
+                    if let Some((key, value)) = entries.next() {
+                        if !is_first {
+                            self.buf.push(b',');
+                        }
+                        serde_json::to_writer(&mut self.buf, key)?;
+                        self.buf.push(b':');
+                        self.stack.push(Step::Object {
+                            entries,
+                            is_first: false,
+                        });
+                        self.stack.push(Step::Value(value));
+                    } else {
+                        self.buf.push(b'}');
+                    }
+                }
+            }
+        }
+        Ok((!self.buf.is_empty()).then_some(self.buf.as_slice()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    use crate::{
+        execute_sync, graphql_input_value, graphql_object, graphql_value, graphql_vars,
+        http::GraphQLResponse, DefaultScalarValue, EmptyMutation, EmptySubscription, FieldError,
+        GraphQLError, InputValue, RootNode, ScalarValue, Value,
+    };
+
+    use super::Json;
+
+    fn huge_value() -> Value {
+        let item = graphql_value!({
+            "id": "quote\" backslash\\ newline\n unicode\u{1F600} control\u{1}",
+            "num": 42,
+            "float": 1.5,
+            "flag": true,
+            "nothing": null,
+            "nested": {"empty": [], "list": [[1, 2], {}]},
+        });
+        Value::list(vec![item; 1000])
+    }
+
+    #[test]
+    fn writes_same_json_as_serde() {
+        let value = huge_value();
+        let mut out = Vec::new();
+
+        value.serialize_to_writer(&mut out).unwrap();
+
+        assert_eq!(out, serde_json::to_vec(&value).unwrap());
+    }
+
+    #[tokio::test]
+    async fn writes_same_json_as_serde_asynchronously() {
+        for value in [
+            huge_value(),
+            Value::<DefaultScalarValue>::null(),
+            graphql_value!("string"),
+            graphql_value!([]),
+            graphql_value!({}),
+        ] {
+            let mut out = Vec::new();
+
+            value.serialize_to_async_writer(&mut out).await.unwrap();
+
+            assert_eq!(out, serde_json::to_vec(&value).unwrap());
+        }
+    }
+
+    #[tokio::test]
+    async fn writes_response_data_before_errors() {
+        for response in [
+            GraphQLResponse::from_result(Ok((huge_value(), vec![]))),
+            GraphQLResponse::error(FieldError::from("boom")),
+            GraphQLResponse::from_result(Err(GraphQLError::NoOperationProvided)),
+        ] {
+            let expected = serde_json::to_vec(&response).unwrap();
+            let mut out = Vec::new();
+
+            response.serialize_to_writer(&mut out).unwrap();
+            assert_eq!(out, expected);
+
+            out.clear();
+            response.serialize_to_async_writer(&mut out).await.unwrap();
+            assert_eq!(out, expected);
+        }
+
+        let mut out = Vec::new();
+        GraphQLResponse::<DefaultScalarValue>::error(FieldError::from("boom"))
+            .serialize_to_async_writer(&mut out)
+            .await
+            .unwrap();
+        assert!(out.starts_with(br#"{"data":null,"errors":[{"message":"boom""#));
+    }
+
+    #[test]
+    fn converts_input_value_from_json() {
+        // Keys are sorted, so the order is the same regardless of the
+        // `serde_json/preserve_order` feature.
+        let json = json!({
+            "flag": false,
+            "float": 1.5,
+            "int": 42,
+            "negative": -5,
+            "nested": [[], {"empty": {}}],
+            "nothing": null,
+            "string": "NEW_HOPE",
+            "wide": 4_294_967_296_u64,
+        });
+
+        assert_eq!(
+            InputValue::<DefaultScalarValue>::from_json(json),
+            graphql_input_value!({
+                "flag": false,
+                "float": 1.5,
+                "int": 42,
+                "negative": -5,
+                "nested": [[], {"empty": {}}],
+                "nothing": null,
+                "string": "NEW_HOPE",
+                "wide": 4_294_967_296.0,
+            }),
+        );
+    }
+
+    #[test]
+    fn converts_input_value_into_json() {
+        let input: InputValue = graphql_input_value!({
+            "int": 42,
+            "float": 1.5,
+            "string": "str",
+            "enum": NEW_HOPE,
+            "var": @id,
+            "nothing": null,
+            "list": [true, {}],
+        });
+
+        assert_eq!(
+            input.to_json(),
+            json!({
+                "int": 42,
+                "float": 1.5,
+                "string": "str",
+                "enum": "NEW_HOPE",
+                "var": null,
+                "nothing": null,
+                "list": [true, {}],
+            }),
+        );
+    }
+
+    #[test]
+    fn converts_default_scalar_value_from_json_number() {
+        for (json, expected) in [
+            (json!(42), DefaultScalarValue::Int(42)),
+            (json!(-2_147_483_648_i64), DefaultScalarValue::Int(i32::MIN)),
+            (json!(1.0), DefaultScalarValue::Float(1.0)),
+            (json!(-0.5), DefaultScalarValue::Float(-0.5)),
+            (
+                json!(2_147_483_648_i64),
+                DefaultScalarValue::Float(2_147_483_648.0),
+            ),
+            (
+                json!(9_007_199_254_740_993_u64),
+                DefaultScalarValue::Float(9_007_199_254_740_992.0),
+            ),
+            (json!(u64::MAX), DefaultScalarValue::Float(u64::MAX as f64)),
+        ] {
+            let n = json.as_number().unwrap();
+
+            assert_eq!(
+                DefaultScalarValue::from_json_number(n),
+                expected,
+                "wrong conversion of {n}",
+            );
+        }
+    }
+
+    #[test]
+    fn keeps_wide_integers_of_custom_scalar_value() {
+        #[derive(Clone, Debug, Deserialize, PartialEq, ScalarValue, Serialize)]
+        #[serde(untagged)]
+        enum WideScalarValue {
+            #[value(as_int)]
+            Int(i32),
+            Long(i64),
+            Unsigned(u64),
+            #[value(as_float)]
+            Float(f64),
+            #[value(as_str, as_string, into_string)]
+            String(String),
+            #[value(as_bool)]
+            Boolean(bool),
+        }
+
+        assert_eq!(
+            InputValue::<WideScalarValue>::from_json(json!([
+                42,
+                -4_294_967_296_i64,
+                u64::MAX,
+                1.0,
+            ])),
+            InputValue::list(vec![
+                InputValue::scalar(42),
+                InputValue::scalar(-4_294_967_296_i64),
+                InputValue::scalar(u64::MAX),
+                InputValue::scalar(1.0),
+            ]),
+        );
+    }
+
+    #[test]
+    fn converts_default_scalar_value_into_json_number() {
+        assert_eq!(
+            DefaultScalarValue::Int(-7).to_json_number(),
+            Some((-7).into()),
+        );
+        assert_eq!(
+            DefaultScalarValue::Float(1.0).to_json_number(),
+            serde_json::Number::from_f64(1.0),
+        );
+        assert_eq!(DefaultScalarValue::Float(f64::NAN).to_json_number(), None);
+        assert_eq!(DefaultScalarValue::from("1").to_json_number(), None);
+        assert_eq!(DefaultScalarValue::Boolean(true).to_json_number(), None);
+
+        for json in [json!(0), json!(i32::MAX), json!(1.0), json!(2.5e300)] {
+            let n = json.as_number().unwrap();
+
+            assert_eq!(
+                DefaultScalarValue::from_json_number(n)
+                    .to_json_number()
+                    .as_ref(),
+                Some(n),
+            );
+        }
+    }
+
+    #[test]
+    fn round_trips_input_value_through_json() {
+        let input: InputValue = graphql_input_value!({
+            "int": 1,
+            "float": 2.5,
+            "list": ["a", null, {"b": false}],
+        });
+
+        assert_eq!(InputValue::from_json(input.to_json()), input);
+    }
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn echo(value: Json) -> Json {
+            value
+        }
+
+        fn maybe(value: Option<Json>) -> Option<Json> {
+            value
+        }
+
+        fn config() -> Json {
+            json!({"name": "juniper", "tags": ["graphql", 1, 2.5, true, null]}).into()
+        }
+    }
+
+    fn schema() -> RootNode<'static, Query, EmptyMutation, EmptySubscription> {
+        RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+    }
+
+    #[test]
+    fn resolves_json_scalar() {
+        assert_eq!(
+            execute_sync("{ config }", None, &schema(), &graphql_vars! {}, &()),
+            Ok((
+                graphql_value!({
+                    "config": {"name": "juniper", "tags": ["graphql", 1, 2.5, true, null]},
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn accepts_structured_literals() {
+        const DOC: &str = r#"{
+            object: echo(value: {nested: {list: [1, "two", {three: 3.0}]}, e: ENUM})
+            list: echo(value: [[], {}, null])
+            scalar: echo(value: "string")
+            nothing: maybe(value: null)
+        }"#;
+
+        assert_eq!(
+            execute_sync(DOC, None, &schema(), &graphql_vars! {}, &()),
+            Ok((
+                graphql_value!({
+                    "object": {"nested": {"list": [1, "two", {"three": 3.0}]}, "e": "ENUM"},
+                    "list": [[], {}, null],
+                    "scalar": "string",
+                    "nothing": null,
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn accepts_structured_variables() {
+        const DOC: &str = r#"query ($value: JSON!) {
+            echo(value: $value)
+        }"#;
+
+        assert_eq!(
+            execute_sync(
+                DOC,
+                None,
+                &schema(),
+                &graphql_vars! {"value": {"list": [1, {"flag": false}]}},
+                &(),
+            ),
+            Ok((
+                graphql_value!({"echo": {"list": [1, {"flag": false}]}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn rejects_null_for_non_null_json() {
+        let res = execute_sync(
+            "{ echo(value: null) }",
+            None,
+            &schema(),
+            &graphql_vars! {},
+            &(),
+        );
+
+        assert!(res.is_err(), "expected validation error, got: {res:?}");
+    }
+}
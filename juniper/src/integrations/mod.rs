@@ -1,5 +1,7 @@
 //! Provides GraphQLType implementations for some external types
 
+#[cfg(feature = "base64")]
+pub mod base64;
 #[cfg(feature = "bigdecimal")]
 pub mod bigdecimal;
 #[cfg(feature = "bson")]
@@ -8,6 +10,14 @@ pub mod bson;
 pub mod chrono;
 #[cfg(feature = "chrono-tz")]
 pub mod chrono_tz;
+#[cfg(feature = "duration")]
+pub mod duration;
+#[cfg(feature = "json")]
+pub mod json;
+#[cfg(feature = "net")]
+pub mod net;
+#[cfg(feature = "ordered-map")]
+pub mod ordered_map;
 #[cfg(feature = "rust_decimal")]
 pub mod rust_decimal;
 #[doc(hidden)]
@@ -0,0 +1,326 @@
+//! GraphQL support for [`std::net`] IP address types.
+//!
+//! # Supported types
+//!
+//! | Rust type    | Format                     | GraphQL scalar   |
+//! |--------------|----------------------------|------------------|
+//! | [`IpAddr`]   | IPv4 or IPv6 address       | [`IP`][s1]       |
+//! | [`Ipv4Addr`] | `192.168.0.1`              | [`IPv4`][s2]     |
+//! | [`Ipv6Addr`] | [RFC 5952] (`2001:db8::1`) | [`IPv6`][s3]     |
+//!
+//! [`IpAddr`]: std::net::IpAddr
+//! [`Ipv4Addr`]: std::net::Ipv4Addr
+//! [`Ipv6Addr`]: std::net::Ipv6Addr
+//! [RFC 5952]: https://tools.ietf.org/html/rfc5952
+//! [s1]: https://graphql-scalars.dev/docs/scalars/ip
+//! [s2]: https://graphql-scalars.dev/docs/scalars/ipv4
+//! [s3]: https://graphql-scalars.dev/docs/scalars/ipv6
+
+use crate::{graphql_scalar, InputValue, ScalarValue, Value};
+
+/// IPv4 or IPv6 address, represented in its canonical string form
+/// (`192.168.0.1` or `2001:db8::1`, for example).
+///
+/// [`IP` scalar][1] compliant.
+///
+/// See also [`std::net::IpAddr`][2] for details.
+///
+/// [1]: https://graphql-scalars.dev/docs/scalars/ip
+/// [2]: https://doc.rust-lang.org/std/net/enum.IpAddr.html
+#[graphql_scalar(
+    name = "IP",
+    with = ip_addr,
+    parse_token(String),
+    specified_by_url = "https://graphql-scalars.dev/docs/scalars/ip",
+)]
+pub type IpAddr = std::net::IpAddr;
+
+mod ip_addr {
+    use super::*;
+
+    pub(super) fn to_output<S: ScalarValue>(v: &IpAddr) -> Value<S> {
+        Value::scalar(v.to_string())
+    }
+
+    pub(super) fn from_input<S: ScalarValue>(v: &InputValue<S>) -> Result<IpAddr, String> {
+        parse(v, "IP")
+    }
+}
+
+/// IPv4 address, represented in its dotted decimal form (`192.168.0.1`, for
+/// example).
+///
+/// [`IPv4` scalar][1] compliant.
+///
+/// See also [`std::net::Ipv4Addr`][2] for details.
+///
+/// [1]: https://graphql-scalars.dev/docs/scalars/ipv4
+/// [2]: https://doc.rust-lang.org/std/net/struct.Ipv4Addr.html
+#[graphql_scalar(
+    name = "IPv4",
+    with = ipv4_addr,
+    parse_token(String),
+    specified_by_url = "https://graphql-scalars.dev/docs/scalars/ipv4",
+)]
+pub type Ipv4Addr = std::net::Ipv4Addr;
+
+mod ipv4_addr {
+    use super::*;
+
+    pub(super) fn to_output<S: ScalarValue>(v: &Ipv4Addr) -> Value<S> {
+        Value::scalar(v.to_string())
+    }
+
+    pub(super) fn from_input<S: ScalarValue>(v: &InputValue<S>) -> Result<Ipv4Addr, String> {
+        parse(v, "IPv4")
+    }
+}
+
+/// IPv6 address, represented in its canonical [RFC 5952][0] form
+/// (`2001:db8::1`, for example).
+///
+/// Any textual form of an IPv6 address is accepted as an input, while the
+/// output is always the canonical one.
+///
+/// [`IPv6` scalar][1] compliant.
+///
+/// See also [`std::net::Ipv6Addr`][2] for details.
+///
+/// [0]: https://tools.ietf.org/html/rfc5952
+/// [1]: https://graphql-scalars.dev/docs/scalars/ipv6
+/// [2]: https://doc.rust-lang.org/std/net/struct.Ipv6Addr.html
+#[graphql_scalar(
+    name = "IPv6",
+    with = ipv6_addr,
+    parse_token(String),
+    specified_by_url = "https://graphql-scalars.dev/docs/scalars/ipv6",
+)]
+pub type Ipv6Addr = std::net::Ipv6Addr;
+
+mod ipv6_addr {
+    use super::*;
+
+    pub(super) fn to_output<S: ScalarValue>(v: &Ipv6Addr) -> Value<S> {
+        Value::scalar(v.to_string())
+    }
+
+    pub(super) fn from_input<S: ScalarValue>(v: &InputValue<S>) -> Result<Ipv6Addr, String> {
+        parse(v, "IPv6")
+    }
+}
+
+/// Parses the provided string [`InputValue`] into an address of type `T`,
+/// named as `scalar` in error messages.
+fn parse<T, S>(v: &InputValue<S>, scalar: &str) -> Result<T, String>
+where
+    T: std::str::FromStr,
+    S: ScalarValue,
+{
+    v.as_string_value()
+        .ok_or_else(|| format!("Expected `String`, found: {v}"))
+        .and_then(|s| {
+            s.parse()
+                .map_err(|_| format!("Invalid `{scalar}` address: `{s}`"))
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    use crate::{graphql_input_value, FromInputValue as _, InputValue, ToInputValue as _};
+
+    #[test]
+    fn parses_ipv4_input() {
+        for (raw, expected) in [
+            ("192.168.0.1", Ipv4Addr::new(192, 168, 0, 1)),
+            ("0.0.0.0", Ipv4Addr::UNSPECIFIED),
+            ("255.255.255.255", Ipv4Addr::BROADCAST),
+        ] {
+            let input: InputValue = graphql_input_value!((raw));
+
+            assert_eq!(Ipv4Addr::from_input_value(&input), Ok(expected));
+            assert_eq!(IpAddr::from_input_value(&input), Ok(expected.into()));
+        }
+    }
+
+    #[test]
+    fn parses_ipv6_input() {
+        for (raw, expected) in [
+            (
+                "2001:db8::1",
+                Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1),
+            ),
+            (
+                "2001:0DB8:0000:0000:0000:0000:0000:0001",
+                Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1),
+            ),
+            ("::", Ipv6Addr::UNSPECIFIED),
+            ("::1", Ipv6Addr::LOCALHOST),
+            (
+                "::ffff:192.168.0.1",
+                Ipv4Addr::new(192, 168, 0, 1).to_ipv6_mapped(),
+            ),
+        ] {
+            let input: InputValue = graphql_input_value!((raw));
+
+            assert_eq!(Ipv6Addr::from_input_value(&input), Ok(expected));
+            assert_eq!(IpAddr::from_input_value(&input), Ok(expected.into()));
+        }
+    }
+
+    #[test]
+    fn fails_on_invalid_input() {
+        for input in [
+            graphql_input_value!("256.0.0.1"),
+            graphql_input_value!("192.168.0"),
+            graphql_input_value!("192.168.0.1/24"),
+            graphql_input_value!(" 192.168.0.1"),
+            graphql_input_value!("2001:db8::1::1"),
+            graphql_input_value!("2001:db8::g"),
+            graphql_input_value!("[::1]"),
+            graphql_input_value!(""),
+            graphql_input_value!("localhost"),
+            graphql_input_value!(1),
+            graphql_input_value!(null),
+            graphql_input_value!(false),
+        ] {
+            let input: InputValue = input;
+
+            assert!(
+                IpAddr::from_input_value(&input).is_err(),
+                "allows input: {input:?}",
+            );
+            assert!(
+                Ipv4Addr::from_input_value(&input).is_err(),
+                "allows input: {input:?}",
+            );
+            assert!(
+                Ipv6Addr::from_input_value(&input).is_err(),
+                "allows input: {input:?}",
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_other_address_family() {
+        let v4: InputValue = graphql_input_value!("192.168.0.1");
+        let v6: InputValue = graphql_input_value!("::1");
+
+        assert_eq!(
+            Ipv6Addr::from_input_value(&v4).unwrap_err().message(),
+            "Invalid `IPv6` address: `192.168.0.1`",
+        );
+        assert_eq!(
+            Ipv4Addr::from_input_value(&v6).unwrap_err().message(),
+            "Invalid `IPv4` address: `::1`",
+        );
+    }
+
+    #[test]
+    fn formats_canonically() {
+        for (val, expected) in [
+            (
+                IpAddr::from(Ipv4Addr::new(10, 0, 0, 1)),
+                graphql_input_value!("10.0.0.1"),
+            ),
+            (
+                IpAddr::from(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)),
+                graphql_input_value!("2001:db8::1"),
+            ),
+            (
+                IpAddr::from(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 1, 0, 0, 1)),
+                graphql_input_value!("2001:db8::1:0:0:1"),
+            ),
+        ] {
+            let actual: InputValue = val.to_input_value();
+
+            assert_eq!(actual, expected, "on value: {val:?}");
+        }
+
+        let v6: InputValue = Ipv6Addr::LOCALHOST.to_input_value();
+        assert_eq!(v6, graphql_input_value!("::1"));
+    }
+}
+
+#[cfg(test)]
+mod integration_test {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    use crate::{
+        execute, graphql_object, graphql_value, graphql_vars,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+    };
+
+    struct Root;
+
+    #[graphql_object]
+    impl Root {
+        fn ip(ip: IpAddr) -> IpAddr {
+            ip
+        }
+
+        fn v4(ip: Ipv4Addr) -> Ipv4Addr {
+            ip
+        }
+
+        fn v6(ip: Ipv6Addr) -> Ipv6Addr {
+            ip
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_as_arguments_and_fields() {
+        let schema = RootNode::new(
+            Root,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+
+        assert_eq!(
+            execute(
+                r#"{
+                    ip(ip: "2001:DB8:0:0::1")
+                    v4(ip: "127.0.0.1")
+                    v6(ip: "0:0:0:0:0:0:0:1")
+                }"#,
+                None,
+                &schema,
+                &graphql_vars! {},
+                &(),
+            )
+            .await,
+            Ok((
+                graphql_value!({"ip": "2001:db8::1", "v4": "127.0.0.1", "v6": "::1"}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_malformed_arguments() {
+        let schema = RootNode::new(
+            Root,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+
+        let res = execute(
+            r#"query($ip: IPv4!) { v4(ip: $ip) }"#,
+            None,
+            &schema,
+            &graphql_vars! {"ip": "::1"},
+            &(),
+        )
+        .await;
+
+        assert!(
+            res.as_ref()
+                .unwrap_err()
+                .to_string()
+                .contains("Invalid `IPv4` address: `::1`"),
+            "wrong result: {res:?}",
+        );
+    }
+}
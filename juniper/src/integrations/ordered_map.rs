@@ -0,0 +1,321 @@
+//! GraphQL support for [`IndexMap`]s, represented as object-shaped scalars
+//! preserving the insertion order of their entries.
+//!
+//! # Supported types
+//!
+//! | Rust type                     | Format                 | GraphQL scalar |
+//! |-------------------------------|------------------------|----------------|
+//! | [`OrderedMap`]`<V>`           | `{"key": V, ...}`      | `OrderedMap`   |
+//!
+//! [`IndexMap`]: indexmap::IndexMap
+
+use std::ops::{Deref, DerefMut};
+
+use indexmap::IndexMap;
+
+use crate::{
+    parser::{ParseError, ScalarToken, Token},
+    value::ParseScalarResult,
+    FieldError, FromInputValue, GraphQLScalar, InputValue, IntoFieldError, ScalarValue,
+    ToInputValue, Value,
+};
+
+/// [GraphQL scalar][0] representing an [`IndexMap`] with [`String`] keys as an
+/// object, whose fields follow the insertion order of the map entries.
+///
+/// On output, every value of the map is converted via its [`ToInputValue`]
+/// implementation, so it may be any scalar, list or nested object. On input,
+/// only literal objects and variables holding objects are accepted, and every
+/// field of them is converted via the [`FromInputValue`] implementation of the
+/// value type, keeping the order the fields are provided in.
+///
+/// > __WARNING:__ This is a [scalar][0], not a [GraphQL object][1] or an
+/// >              [input object][2], so the type information of the map values
+/// >              is lost: the schema describes neither the keys nor the type
+/// >              of the values, and the fields cannot be queried selectively.
+/// >              For the same reason, every [`OrderedMap`] is exposed as the
+/// >              same `OrderedMap` scalar regardless of its value type.
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Scalars
+/// [1]: https://spec.graphql.org/October2021#sec-Objects
+/// [2]: https://spec.graphql.org/October2021#sec-Input-Objects
+#[derive(Clone, Debug, Eq, GraphQLScalar, PartialEq)]
+#[graphql(
+    to_output_with = Self::to_output,
+    from_input_with = Self::from_input,
+    parse_token_with = Self::parse_token,
+    scalar = S: ScalarValue,
+    where(V: ToInputValue<S> + FromInputValue<S>, V::Error: IntoFieldError<S>),
+    structured,
+)]
+pub struct OrderedMap<V>(pub IndexMap<String, V>);
+
+impl<V> OrderedMap<V> {
+    fn to_output<S>(&self) -> Value<S>
+    where
+        S: ScalarValue,
+        V: ToInputValue<S>,
+    {
+        Value::object(
+            self.0
+                .iter()
+                .map(|(k, v)| (k.as_str(), output_value(v.to_input_value())))
+                .collect(),
+        )
+    }
+
+    fn from_input<S>(v: &InputValue<S>) -> Result<Self, FieldError<S>>
+    where
+        S: ScalarValue,
+        V: FromInputValue<S>,
+        V::Error: IntoFieldError<S>,
+    {
+        let obj = v
+            .to_object_value()
+            .ok_or_else(|| format!("Expected input object, found: {v}"))?;
+        obj.into_iter()
+            .map(|(k, v)| {
+                V::from_input_value(v)
+                    .map(|v| (k.to_owned(), v))
+                    .map_err(IntoFieldError::into_field_error)
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+
+    fn parse_token<S: ScalarValue>(value: ScalarToken<'_>) -> ParseScalarResult<S> {
+        Err(ParseError::unexpected_token(Token::Scalar(value)))
+    }
+
+    /// Unwraps the underlying [`IndexMap`].
+    #[must_use]
+    pub fn into_inner(self) -> IndexMap<String, V> {
+        self.0
+    }
+}
+
+impl<V> Default for OrderedMap<V> {
+    fn default() -> Self {
+        Self(IndexMap::new())
+    }
+}
+
+impl<V> From<IndexMap<String, V>> for OrderedMap<V> {
+    fn from(map: IndexMap<String, V>) -> Self {
+        Self(map)
+    }
+}
+
+impl<V> From<OrderedMap<V>> for IndexMap<String, V> {
+    fn from(map: OrderedMap<V>) -> Self {
+        map.0
+    }
+}
+
+impl<V> FromIterator<(String, V)> for OrderedMap<V> {
+    fn from_iter<I: IntoIterator<Item = (String, V)>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<V> Deref for OrderedMap<V> {
+    type Target = IndexMap<String, V>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<V> DerefMut for OrderedMap<V> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Converts the provided [`InputValue`] into an output [`Value`], keeping the
+/// order of object fields.
+///
+/// As there is no output counterpart for them, [`InputValue::Enum`]s become
+/// strings, while [`InputValue::Variable`]s become `null`s.
+fn output_value<S: ScalarValue>(v: InputValue<S>) -> Value<S> {
+    match v {
+        InputValue::Null | InputValue::Variable(_) => Value::null(),
+        InputValue::Scalar(s) => Value::Scalar(s),
+        InputValue::Enum(e) => Value::scalar(e),
+        InputValue::List(l) => Value::list(l.into_iter().map(|i| output_value(i.item)).collect()),
+        InputValue::Object(o) => Value::object(
+            o.into_iter()
+                .map(|(k, v)| (k.item, output_value(v.item)))
+                .collect(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        execute_sync, graphql_input_value, graphql_object, graphql_value, graphql_vars,
+        EmptyMutation, EmptySubscription, FromInputValue as _, InputValue, RootNode,
+        ToInputValue as _,
+    };
+
+    use super::OrderedMap;
+
+    fn map<V>(entries: impl IntoIterator<Item = (&'static str, V)>) -> OrderedMap<V> {
+        entries
+            .into_iter()
+            .map(|(k, v)| (k.to_owned(), v))
+            .collect()
+    }
+
+    #[test]
+    fn parses_object_in_order() {
+        let input: InputValue = graphql_input_value!({"z": 1, "a": 2, "m": 3});
+
+        let parsed = OrderedMap::<i32>::from_input_value(&input).unwrap();
+
+        assert_eq!(parsed, map([("z", 1), ("a", 2), ("m", 3)]));
+        assert_eq!(
+            parsed.keys().map(String::as_str).collect::<Vec<_>>(),
+            ["z", "a", "m"],
+        );
+    }
+
+    #[test]
+    fn fails_on_invalid_input() {
+        for input in [
+            graphql_input_value!({"z": 1, "a": "two"}),
+            graphql_input_value!([1, 2]),
+            graphql_input_value!("string"),
+            graphql_input_value!(1),
+            graphql_input_value!(null),
+        ] {
+            let input: InputValue = input;
+            let parsed = OrderedMap::<i32>::from_input_value(&input);
+
+            assert!(parsed.is_err(), "allows input: {input:?}");
+        }
+    }
+
+    #[test]
+    fn formats_in_order() {
+        let val = map([("z", vec![1]), ("a", vec![]), ("m", vec![2, 3])]);
+
+        let actual: InputValue = val.to_input_value();
+
+        assert_eq!(
+            actual,
+            graphql_input_value!({"z": [1], "a": [], "m": [2, 3]})
+        );
+        assert_eq!(
+            actual
+                .to_object_value()
+                .unwrap()
+                .into_iter()
+                .map(|(k, _)| k)
+                .collect::<Vec<_>>(),
+            ["z", "a", "m"],
+        );
+    }
+
+    #[test]
+    fn round_trips_in_order() {
+        let val = map([("b", 2), ("c", 3), ("a", 1)]);
+
+        let input: InputValue = val.to_input_value();
+        let parsed = OrderedMap::<i32>::from_input_value(&input).unwrap();
+
+        assert_eq!(
+            parsed.into_inner().into_iter().collect::<Vec<_>>(),
+            [
+                ("b".to_owned(), 2),
+                ("c".to_owned(), 3),
+                ("a".to_owned(), 1)
+            ],
+        );
+    }
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn echo(value: OrderedMap<Option<String>>) -> OrderedMap<Option<String>> {
+            value
+        }
+
+        fn scores() -> OrderedMap<i32> {
+            map([("zeta", 3), ("alpha", 1), ("mu", 2)])
+        }
+    }
+
+    fn schema() -> RootNode<'static, Query, EmptyMutation, EmptySubscription> {
+        RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+    }
+
+    /// Collects field names of the top-level objects in the provided result.
+    fn field_names(res: &crate::Value, field: &str) -> Vec<String> {
+        res.as_object_value()
+            .and_then(|o| o.get_field_value(field))
+            .and_then(crate::Value::as_object_value)
+            .unwrap()
+            .iter()
+            .map(|(k, _)| k.clone())
+            .collect()
+    }
+
+    #[test]
+    fn resolves_in_order() {
+        let (res, errs) =
+            execute_sync("{ scores }", None, &schema(), &graphql_vars! {}, &()).unwrap();
+
+        assert_eq!(errs, []);
+        assert_eq!(
+            res,
+            graphql_value!({"scores": {"zeta": 3, "alpha": 1, "mu": 2}}),
+        );
+        assert_eq!(field_names(&res, "scores"), ["zeta", "alpha", "mu"]);
+    }
+
+    #[test]
+    fn echoes_literal_in_order() {
+        let (res, errs) = execute_sync(
+            r#"{ echo(value: {b: "x", a: null, c: "y"}) }"#,
+            None,
+            &schema(),
+            &graphql_vars! {},
+            &(),
+        )
+        .unwrap();
+
+        assert_eq!(errs, []);
+        assert_eq!(
+            res,
+            graphql_value!({"echo": {"b": "x", "a": null, "c": "y"}}),
+        );
+        assert_eq!(field_names(&res, "echo"), ["b", "a", "c"]);
+    }
+
+    #[test]
+    fn echoes_variable_in_order() {
+        let (res, errs) = execute_sync(
+            "query ($value: OrderedMap!) { echo(value: $value) }",
+            None,
+            &schema(),
+            &graphql_vars! {"value": {"y": "1", "x": "2"}},
+            &(),
+        )
+        .unwrap();
+
+        assert_eq!(errs, []);
+        assert_eq!(field_names(&res, "echo"), ["y", "x"]);
+    }
+
+    #[test]
+    fn rejects_non_object() {
+        for doc in [r#"{ echo(value: "string") }"#, "{ echo(value: [1]) }"] {
+            let res = execute_sync(doc, None, &schema(), &graphql_vars! {}, &());
+
+            assert!(res.is_err(), "expected validation error, got: {res:?}");
+        }
+    }
+}
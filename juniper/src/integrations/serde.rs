@@ -9,7 +9,10 @@ use serde::{
 
 use crate::{
     ast::InputValue,
-    executor::ExecutionError,
+    executor::{
+        ExecutionError, IncrementalData, IncrementalItems, IncrementalPayload, IncrementalResult,
+        PathSegment,
+    },
     parser::{ParseError, SourcePosition, Spanning},
     validation::RuleError,
     DefaultScalarValue, GraphQLError, Object, Value,
@@ -38,6 +41,109 @@ impl<T: Serialize> Serialize for ExecutionError<T> {
     }
 }
 
+impl Serialize for PathSegment {
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Field(name) => ser.serialize_str(name),
+            Self::Index(idx) => ser.serialize_u64(*idx as u64),
+        }
+    }
+}
+
+impl<T: Serialize> Serialize for IncrementalPayload<T> {
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        let mut map = ser.serialize_map(None)?;
+
+        match self {
+            Self::Initial {
+                data,
+                errors,
+                has_next,
+            } => {
+                map.serialize_key("data")?;
+                map.serialize_value(data)?;
+
+                if !errors.is_empty() {
+                    map.serialize_key("errors")?;
+                    map.serialize_value(errors)?;
+                }
+
+                map.serialize_key("hasNext")?;
+                map.serialize_value(has_next)?;
+            }
+            Self::Subsequent {
+                incremental,
+                has_next,
+            } => {
+                map.serialize_key("incremental")?;
+                map.serialize_value(incremental)?;
+
+                map.serialize_key("hasNext")?;
+                map.serialize_value(has_next)?;
+            }
+        }
+
+        map.end()
+    }
+}
+
+impl<T: Serialize> Serialize for IncrementalResult<T> {
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Items(items) => items.serialize(ser),
+            Self::Data(data) => data.serialize(ser),
+        }
+    }
+}
+
+impl<T: Serialize> Serialize for IncrementalData<T> {
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        let mut map = ser.serialize_map(None)?;
+
+        map.serialize_key("data")?;
+        map.serialize_value(&self.data)?;
+
+        map.serialize_key("path")?;
+        map.serialize_value(&self.path)?;
+
+        if let Some(label) = &self.label {
+            map.serialize_key("label")?;
+            map.serialize_value(label)?;
+        }
+
+        if !self.errors.is_empty() {
+            map.serialize_key("errors")?;
+            map.serialize_value(&self.errors)?;
+        }
+
+        map.end()
+    }
+}
+
+impl<T: Serialize> Serialize for IncrementalItems<T> {
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        let mut map = ser.serialize_map(None)?;
+
+        map.serialize_key("items")?;
+        map.serialize_value(&self.items)?;
+
+        map.serialize_key("path")?;
+        map.serialize_value(&self.path)?;
+
+        if let Some(label) = &self.label {
+            map.serialize_key("label")?;
+            map.serialize_value(label)?;
+        }
+
+        if !self.errors.is_empty() {
+            map.serialize_key("errors")?;
+            map.serialize_value(&self.errors)?;
+        }
+
+        map.end()
+    }
+}
+
 impl Serialize for GraphQLError {
     fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
         #[derive(Serialize)]
@@ -215,7 +321,7 @@ impl<T: Serialize> Serialize for InputValue<T> {
 
 impl Serialize for RuleError {
     fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
-        let mut map = ser.serialize_map(Some(2))?;
+        let mut map = ser.serialize_map(Some(3))?;
 
         map.serialize_key("message")?;
         map.serialize_value(self.message())?;
@@ -223,6 +329,11 @@ impl Serialize for RuleError {
         map.serialize_key("locations")?;
         map.serialize_value(self.locations())?;
 
+        if !self.extensions().is_null() {
+            map.serialize_key("extensions")?;
+            map.serialize_value(self.extensions())?;
+        }
+
         map.end()
     }
 }
@@ -353,12 +464,13 @@ mod tests {
 
     use crate::{
         ast::InputValue,
-        graphql_input_value,
+        graphql_input_value, graphql_value,
+        parser::SourcePosition,
         value::{DefaultScalarValue, Object},
         FieldError, Value,
     };
 
-    use super::{ExecutionError, GraphQLError};
+    use super::{ExecutionError, GraphQLError, PathSegment, RuleError};
 
     #[test]
     fn int() {
@@ -402,4 +514,41 @@ mod tests {
             r#"{"message":"foo error","locations":[{"line":1,"column":1}],"path":[],"extensions":{"foo":"bar"}}"#,
         );
     }
+
+    #[test]
+    fn error_path_with_indices() {
+        assert_eq!(
+            to_string(&ExecutionError::new_with_path(
+                SourcePosition::new(2, 0, 2),
+                &[
+                    PathSegment::Field("users".into()),
+                    PathSegment::Index(1),
+                    PathSegment::Field("name".into()),
+                ],
+                FieldError::<DefaultScalarValue>::from("name error"),
+            ))
+            .unwrap(),
+            r#"{"message":"name error","locations":[{"line":1,"column":3}],"path":["users",1,"name"]}"#,
+        );
+    }
+
+    #[test]
+    fn rule_error_extensions() {
+        assert_eq!(
+            to_string(&RuleError::new(
+                "foo error",
+                &[SourcePosition::new(0, 0, 0)],
+            ))
+            .unwrap(),
+            r#"{"message":"foo error","locations":[{"line":1,"column":1}]}"#,
+        );
+        assert_eq!(
+            to_string(
+                &RuleError::new("foo error", &[SourcePosition::new(0, 0, 0)])
+                    .with_extensions(graphql_value!({"foo": "bar"})),
+            )
+            .unwrap(),
+            r#"{"message":"foo error","locations":[{"line":1,"column":1}],"extensions":{"foo":"bar"}}"#,
+        );
+    }
 }
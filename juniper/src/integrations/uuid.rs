@@ -4,14 +4,25 @@
 
 use crate::{graphql_scalar, InputValue, ScalarValue, Value};
 
-#[graphql_scalar(with = uuid_scalar, parse_token(String))]
+/// [Universally unique identifier][1], represented as a hyphenated lowercase
+/// string (like `123e4567-e89b-12d3-a456-426655440000`).
+///
+/// Any of the formats supported by [`uuid::Uuid::parse_str()`] is accepted as
+/// an input.
+///
+/// [1]: https://tools.ietf.org/html/rfc4122
+#[graphql_scalar(
+    with = uuid_scalar,
+    parse_token(String),
+    specified_by_url = "https://tools.ietf.org/html/rfc4122",
+)]
 type Uuid = uuid::Uuid;
 
 mod uuid_scalar {
     use super::*;
 
     pub(super) fn to_output<S: ScalarValue>(v: &Uuid) -> Value<S> {
-        Value::scalar(v.to_string())
+        Value::scalar(v.hyphenated().to_string())
     }
 
     pub(super) fn from_input<S: ScalarValue>(v: &InputValue<S>) -> Result<Uuid, String> {
@@ -25,7 +36,7 @@ mod uuid_scalar {
 mod test {
     use uuid::Uuid;
 
-    use crate::{graphql_input_value, FromInputValue, InputValue};
+    use crate::{graphql_input_value, FromInputValue, InputValue, ToInputValue};
 
     #[test]
     fn uuid_from_input() {
@@ -37,4 +48,108 @@ mod test {
 
         assert_eq!(parsed, id);
     }
+
+    #[test]
+    fn fails_on_invalid_input() {
+        for input in [
+            graphql_input_value!("123e4567-e89b-12d3-a456"),
+            graphql_input_value!("not a uuid"),
+            graphql_input_value!(1),
+            graphql_input_value!(null),
+        ] {
+            let input: InputValue = input;
+            let parsed = <Uuid as FromInputValue>::from_input_value(&input);
+
+            assert!(parsed.is_err(), "allows input: {input:?}");
+        }
+
+        let input: InputValue = graphql_input_value!("not a uuid");
+        let err = <Uuid as FromInputValue>::from_input_value(&input).unwrap_err();
+
+        assert!(
+            err.message().starts_with("Failed to parse `Uuid`: "),
+            "wrong error: {}",
+            err.message(),
+        );
+    }
+
+    #[test]
+    fn formats_hyphenated_lowercase() {
+        let id = Uuid::parse_str("123E4567E89B12D3A456426655440000").unwrap();
+
+        let output: InputValue = id.to_input_value();
+
+        assert_eq!(
+            output,
+            graphql_input_value!("123e4567-e89b-12d3-a456-426655440000"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod integration_test {
+    use uuid::Uuid;
+
+    use crate::{
+        execute, graphql_object, graphql_value, graphql_vars,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+    };
+
+    struct Root;
+
+    #[graphql_object]
+    impl Root {
+        fn echo(id: Uuid) -> Uuid {
+            id
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_as_argument_and_field() {
+        let schema = RootNode::new(
+            Root,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+
+        assert_eq!(
+            execute(
+                r#"{ echo(id: "{123E4567-E89B-12D3-A456-426655440000}") }"#,
+                None,
+                &schema,
+                &graphql_vars! {},
+                &(),
+            )
+            .await,
+            Ok((
+                graphql_value!({"echo": "123e4567-e89b-12d3-a456-426655440000"}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn has_specified_by_url() {
+        let schema = RootNode::new(
+            Root,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+
+        assert_eq!(
+            execute(
+                r#"{ __type(name: "Uuid") { specifiedByUrl } }"#,
+                None,
+                &schema,
+                &graphql_vars! {},
+                &(),
+            )
+            .await,
+            Ok((
+                graphql_value!({"__type": {"specifiedByUrl": "https://tools.ietf.org/html/rfc4122"}}),
+                vec![],
+            )),
+        );
+    }
 }
@@ -9,7 +9,9 @@
 extern crate core;
 extern crate self as juniper;
 
-use std::fmt;
+use std::{fmt, sync::Arc};
+
+use futures::StreamExt as _;
 
 // These are required by the code generated via the `juniper_codegen` macros.
 #[doc(hidden)]
@@ -24,6 +26,7 @@ pub use futures::future::{BoxFuture, LocalBoxFuture};
 pub use juniper_codegen::{
     graphql_interface, graphql_object, graphql_scalar, graphql_subscription, graphql_union,
     GraphQLEnum, GraphQLInputObject, GraphQLInterface, GraphQLObject, GraphQLScalar, GraphQLUnion,
+    GraphQLVariables,
 };
 
 #[doc(hidden)]
@@ -55,32 +58,41 @@ mod executor_tests;
 pub use crate::util::to_camel_case;
 
 use crate::{
-    executor::{execute_validated_query, get_operation},
+    executor::{get_operation, incremental::Delivery, ExecutionParams},
     introspection::{INTROSPECTION_QUERY, INTROSPECTION_QUERY_WITHOUT_DESCRIPTIONS},
     parser::parse_document_source,
-    validation::{validate_input_values, visit_all_rules, ValidatorContext},
+    validation::{validate_cost, validate_input_values, visit_all_rules, ValidatorContext},
 };
 
 pub use crate::{
     ast::{
-        Definition, Document, FromInputValue, InputValue, Operation, OperationType, Selection,
-        ToInputValue, Type,
+        Definition, Directive, Document, Field, Fragment, FragmentSpread, FromInputValue,
+        InlineFragment, InputValue, Operation, OperationType, OwnedDocument, Selection,
+        ToInputValue, Type, VariableDefinition, VariableDefinitions,
     },
     executor::{
-        Applies, Context, ExecutionError, ExecutionResult, Executor, FieldError, FieldResult,
-        FromContext, IntoFieldError, IntoResolvable, LookAheadArgument, LookAheadMethods,
-        LookAheadSelection, LookAheadValue, OwnedExecutor, Registry, ValuesStream, Variables,
+        collect_for_selection, Applies, Context, ErrorExtensions, ExecutionError, ExecutionResult,
+        Executor, FieldError, FieldErrorBuilder, FieldInfo, FieldMiddleware, FieldResult,
+        FromContext, IncrementalData, IncrementalItems, IncrementalPayload, IncrementalResult,
+        IntoFieldError, IntoResolvable, LookAheadArgument, LookAheadMethods, LookAheadSelection,
+        LookAheadValue, NaiveExecutor, OperationInfo, OwnedExecutor, PathSegment, PreExecution,
+        Registry, ValuesStream, Variables, VariablesBuilder,
     },
     introspection::IntrospectionFormat,
     macros::helper::subscription::{ExtractTypeFromStream, IntoFieldResult},
     parser::{ParseError, ScalarToken, Spanning},
     schema::{
+        merge::{merge_schemas, MergeError, MergeableSchema, MergedRootNode},
         meta,
-        model::{RootNode, SchemaType},
+        model::{
+            DirectiveLocation, DirectiveRegisterError, DirectiveType, RootNode,
+            RootTypeRenameError, SchemaType,
+        },
+        validation::SchemaValidationError,
     },
     types::{
         async_await::{GraphQLTypeAsync, GraphQLValueAsync},
-        base::{Arguments, GraphQLType, GraphQLValue, TypeKind},
+        base::{Arguments, GraphQLType, GraphQLTypeName, GraphQLValue, TypeKind},
         marker::{self, GraphQLInterface, GraphQLObject, GraphQLUnion},
         nullable::Nullable,
         scalars::{EmptyMutation, EmptySubscription, ID},
@@ -88,11 +100,18 @@ pub use crate::{
             ExecutionOutput, GraphQLSubscriptionType, GraphQLSubscriptionValue,
             SubscriptionConnection, SubscriptionCoordinator,
         },
+        upload::{Upload, UploadFile, UploadSource, Uploads},
+    },
+    validation::{CoercionError, CoercionErrorKind, CostError, RuleError},
+    value::{
+        DefaultScalarValue, Object, ParseScalarResult, ParseScalarValue, PrettyOptions,
+        ScalarValue, Value,
     },
-    validation::RuleError,
-    value::{DefaultScalarValue, Object, ParseScalarResult, ParseScalarValue, ScalarValue, Value},
 };
 
+#[cfg(feature = "schema-language")]
+pub use crate::schema::model::SdlOptions;
+
 /// An error that prevented query execution
 #[derive(Debug, PartialEq)]
 #[allow(missing_docs)]
@@ -140,6 +159,10 @@ impl std::error::Error for GraphQLError {
 }
 
 /// Execute a query synchronously in a provided schema
+///
+/// Resolves the query without any async runtime, so the schema is expected to
+/// have no async fields: resolving such a field results in a field error,
+/// while executing a subscription returns [`GraphQLError::IsSubscription`].
 pub fn execute_sync<'a, S, QueryT, MutationT, SubscriptionT>(
     document_source: &'a str,
     operation_name: Option<&str>,
@@ -154,28 +177,12 @@ where
     SubscriptionT: GraphQLType<S, Context = QueryT::Context>,
 {
     let document = parse_document_source(document_source, &root_node.schema)?;
+    let (operation, cost) =
+        prepare_operation(&document, operation_name, &root_node.schema, variables)?;
 
-    {
-        let mut ctx = ValidatorContext::new(&root_node.schema, &document);
-        visit_all_rules(&mut ctx, &document);
-
-        let errors = ctx.into_errors();
-        if !errors.is_empty() {
-            return Err(GraphQLError::ValidationError(errors));
-        }
-    }
-
-    let operation = get_operation(&document, operation_name)?;
-
-    {
-        let errors = validate_input_values(variables, operation, &root_node.schema);
-
-        if !errors.is_empty() {
-            return Err(GraphQLError::ValidationError(errors));
-        }
-    }
-
-    execute_validated_query(&document, operation, root_node, variables, context)
+    executor::execute_validated_query_impl(
+        &document, operation, root_node, variables, context, cost,
+    )
 }
 
 /// Execute a query in a provided schema
@@ -197,29 +204,301 @@ where
     S: ScalarValue + Send + Sync,
 {
     let document = parse_document_source(document_source, &root_node.schema)?;
+    let (operation, cost) =
+        prepare_operation(&document, operation_name, &root_node.schema, variables)?;
 
-    {
-        let mut ctx = ValidatorContext::new(&root_node.schema, &document);
-        visit_all_rules(&mut ctx, &document);
+    executor::execute_validated_query_async_impl(
+        &document,
+        operation,
+        root_node,
+        variables,
+        context,
+        ExecutionParams {
+            cost,
+            ..ExecutionParams::default()
+        },
+    )
+    .await
+}
 
-        let errors = ctx.into_errors();
-        if !errors.is_empty() {
-            return Err(GraphQLError::ValidationError(errors));
+/// Execute a query in a provided schema, giving up on the fields not resolved
+/// before the provided `deadline` completes.
+///
+/// Once the `deadline` completes, the execution stops awaiting any further
+/// fields and returns the data resolved so far. Each unfinished field is
+/// resolved to `null` (propagated to the nearest nullable parent, as for any
+/// other field error), with an error having a `TIMEOUT` code in its
+/// `extensions` added to the returned errors.
+///
+/// As juniper is agnostic to the async runtime, the `deadline` is any
+/// [`Future`] completing when it's exceeded, like the `tokio::time::sleep()`
+/// one. Its cancellation is cooperative: the execution must be polled for the
+/// unfinished fields to be reported, so a resolver blocking its thread can't
+/// be interrupted, and only the fields resolved asynchronously (with
+/// [`execute()`] semantics) respect the `deadline`.
+///
+/// Futures of the resolvers, which are still running once the `deadline` is
+/// exceeded, are dropped, cancelling them at their current `.await` point.
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use juniper::{
+/// #     execute_with_deadline, graphql_object, graphql_value, graphql_vars, EmptyMutation,
+/// #     EmptySubscription, RootNode,
+/// # };
+/// #
+/// struct Query;
+///
+/// #[graphql_object]
+/// impl Query {
+///     fn fast() -> i32 {
+///         1
+///     }
+///
+///     async fn slow() -> Option<i32> {
+///         tokio::time::sleep(Duration::from_secs(60)).await;
+///         Some(2)
+///     }
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new());
+/// let (res, errors) = execute_with_deadline(
+///     "{ fast slow }",
+///     None,
+///     &schema,
+///     &graphql_vars! {},
+///     &(),
+///     tokio::time::sleep(Duration::from_millis(10)),
+/// )
+/// .await
+/// .unwrap();
+///
+/// assert_eq!(res, graphql_value!({"fast": 1, "slow": null}));
+/// assert_eq!(
+///     errors[0].error().extensions(),
+///     &graphql_value!({"code": "TIMEOUT"}),
+/// );
+/// # }
+/// ```
+///
+/// [`Future`]: std::future::Future
+pub async fn execute_with_deadline<'a, S, QueryT, MutationT, SubscriptionT>(
+    document_source: &'a str,
+    operation_name: Option<&str>,
+    root_node: &'a RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+    variables: &Variables<S>,
+    context: &QueryT::Context,
+    deadline: impl std::future::Future<Output = ()>,
+) -> Result<(Value<S>, Vec<ExecutionError<S>>), GraphQLError>
+where
+    QueryT: GraphQLTypeAsync<S>,
+    QueryT::TypeInfo: Sync,
+    QueryT::Context: Sync,
+    MutationT: GraphQLTypeAsync<S, Context = QueryT::Context>,
+    MutationT::TypeInfo: Sync,
+    SubscriptionT: GraphQLType<S, Context = QueryT::Context> + Sync,
+    SubscriptionT::TypeInfo: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    let document = parse_document_source(document_source, &root_node.schema)?;
+    let (operation, cost) =
+        prepare_operation(&document, operation_name, &root_node.schema, variables)?;
+
+    executor::execute_validated_query_async_with_deadline_impl(
+        &document, operation, root_node, variables, context, cost, deadline,
+    )
+    .await
+}
+
+/// Single operation of a batch executed via [`execute_batch()`].
+#[derive(Clone, Debug)]
+pub struct BatchItem<'a, S = DefaultScalarValue> {
+    /// Source of the GraphQL document containing the operation.
+    pub document_source: &'a str,
+
+    /// Name of the operation to execute, if the document contains many.
+    pub operation_name: Option<&'a str>,
+
+    /// Variables to execute the operation with.
+    pub variables: Variables<S>,
+}
+
+impl<'a, S> BatchItem<'a, S> {
+    /// Creates a new [`BatchItem`] of the provided document source, without
+    /// an operation name and variables.
+    #[must_use]
+    pub fn new(document_source: &'a str) -> Self {
+        Self {
+            document_source,
+            operation_name: None,
+            variables: Variables::new(),
         }
     }
+}
+
+/// Execute a batch of queries concurrently in a provided schema, sharing the
+/// provided context between them
+///
+/// Results are returned in the order of the provided `items`. Failure of one
+/// item doesn't affect execution of the others.
+pub async fn execute_batch<'a, S, QueryT, MutationT, SubscriptionT>(
+    items: &'a [BatchItem<'a, S>],
+    root_node: &'a RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+    context: &QueryT::Context,
+) -> Vec<Result<(Value<S>, Vec<ExecutionError<S>>), GraphQLError>>
+where
+    QueryT: GraphQLTypeAsync<S>,
+    QueryT::TypeInfo: Sync,
+    QueryT::Context: Sync,
+    MutationT: GraphQLTypeAsync<S, Context = QueryT::Context>,
+    MutationT::TypeInfo: Sync,
+    SubscriptionT: GraphQLType<S, Context = QueryT::Context> + Sync,
+    SubscriptionT::TypeInfo: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    futures::future::join_all(items.iter().map(|item| {
+        execute(
+            item.document_source,
+            item.operation_name,
+            root_node,
+            &item.variables,
+            context,
+        )
+    }))
+    .await
+}
 
-    let operation = get_operation(&document, operation_name)?;
+/// Execute a query in a provided schema, delivering its response
+/// [incrementally][0] according to the `@stream` and `@defer` directives in it
+///
+/// The returned [`Stream`] yields an [`IncrementalPayload::Initial`] with the
+/// `@stream`ed lists truncated to their `initialCount` and the fields of the
+/// `@defer`ed fragments omitted, followed by an
+/// [`IncrementalPayload::Subsequent`] for each remaining list item and each
+/// deferred fragment.
+///
+/// Serialized, the payloads follow the format of the incremental delivery
+/// proposal:
+/// ```json
+/// {"data": {"hero": {"id": "1"}}, "hasNext": true}
+/// {"incremental": [{"data": {"name": "Luke"}, "path": ["hero"], "label": "name"}], "hasNext": true}
+/// {"incremental": [{"items": [{"id": "2"}], "path": ["hero", "friends", 1]}], "hasNext": false}
+/// ```
+/// where the `data` of a deferred fragment is to be merged into the object at
+/// its `path`, while the `items` of a streamed list are to be appended to the
+/// list containing the `path`. The `errors` occurred in a payload are put
+/// alongside its `data` or `items`.
+///
+/// The `@stream` and `@defer` directives must be enabled in the schema via
+/// [`RootNode::with_stream_directive()`] and
+/// [`RootNode::with_defer_directive()`], otherwise their usage fails
+/// validation. Only a subset of the incremental delivery proposal is
+/// supported:
+/// - The operation is executed only once, with every field resolved only
+///   once. The initial payload is yielded as soon as everything except the
+///   `@defer`ed fragments and the `@stream`ed list items beyond their
+///   `initialCount` is resolved. The postponed parts start resolving only
+///   once the payload containing their parent is yielded, and are yielded in
+///   the order of their resolving.
+/// - A mutation is resolved as a whole before the initial payload is yielded,
+///   so its side effects don't interleave with the delivery.
+/// - `@stream`ed fields are resolved as regular lists (like [`Vec`]), while
+///   resolving a field from a [`Stream`] is supported for subscriptions only.
+/// - Fields of a `@defer`ed fragment, which are already delivered by the
+///   non-deferred selections (or by another `@defer`ed fragment on the same
+///   object), are omitted in the deferred payload.
+/// - Values resolved synchronously (via [`GraphQLValue::resolve()`] instead of
+///   [`GraphQLValueAsync::resolve_async()`]) ignore `@stream` and `@defer`
+///   inside them, being delivered as a whole.
+/// - Errors occurred in an already delivered part (like a [`FieldMiddleware`]
+///   failing a field after its value is delivered) are delivered in a
+///   subsequent payload with empty `data`.
+/// - [`execute()`] and [`execute_sync()`] ignore `@stream` and `@defer`,
+///   delivering the whole response at once.
+///
+/// [`Stream`]: futures::Stream
+/// [0]: https://github.com/graphql/graphql-spec/pull/742
+pub async fn execute_incremental<'a, S, QueryT, MutationT, SubscriptionT>(
+    document_source: &'a str,
+    operation_name: Option<&'a str>,
+    root_node: &'a RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+    variables: &'a Variables<S>,
+    context: &'a QueryT::Context,
+) -> Result<impl futures::Stream<Item = IncrementalPayload<S>> + 'a, GraphQLError>
+where
+    QueryT: GraphQLTypeAsync<S>,
+    QueryT::TypeInfo: Sync,
+    QueryT::Context: Sync,
+    MutationT: GraphQLTypeAsync<S, Context = QueryT::Context>,
+    MutationT::TypeInfo: Sync,
+    SubscriptionT: GraphQLType<S, Context = QueryT::Context> + Sync,
+    SubscriptionT::TypeInfo: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    let document = parse_document_source(document_source, &root_node.schema)?;
+    let (operation, cost) =
+        prepare_operation(&document, operation_name, &root_node.schema, variables)?;
 
-    {
-        let errors = validate_input_values(variables, operation, &root_node.schema);
+    if operation.item.operation_type == OperationType::Subscription {
+        return Err(GraphQLError::IsSubscription);
+    }
+    if let Some(e) = executor::pre_execution::before_execution(
+        root_node, &document, operation, variables, context, cost,
+    )? {
+        return Ok(futures::stream::iter(vec![IncrementalPayload::Initial {
+            data: Value::null(),
+            errors: vec![e],
+            has_next: false,
+        }])
+        .left_stream());
+    }
 
-        if !errors.is_empty() {
-            return Err(GraphQLError::ValidationError(errors));
-        }
+    if operation.item.operation_type == OperationType::Mutation {
+        // Postponing parts of a mutation would make its side effects happen
+        // around the delivery of the initial payload, so it's resolved as a
+        // whole, and only split into payloads afterwards.
+        let (value, errors) = executor::resolve_validated_operation_async(
+            &document,
+            operation,
+            root_node,
+            variables,
+            context,
+            ExecutionParams {
+                cost,
+                ..ExecutionParams::default()
+            },
+        )
+        .await;
+        let payloads =
+            executor::incremental::split_response(&document, operation, variables, value, errors);
+        return Ok(futures::stream::iter(payloads).left_stream());
     }
 
-    executor::execute_validated_query_async(&document, operation, root_node, variables, context)
-        .await
+    let delivery = Arc::new(Delivery::new());
+    let execution = {
+        let delivery = Arc::clone(&delivery);
+        async move {
+            let operation =
+                get_operation(&document, operation_name).expect("operation is already validated");
+            let (_, errors) = executor::resolve_validated_operation_async(
+                &document,
+                operation,
+                root_node,
+                variables,
+                context,
+                ExecutionParams {
+                    cost,
+                    delivery: Some(delivery),
+                    ..ExecutionParams::default()
+                },
+            )
+            .await;
+            errors
+        }
+    };
+    Ok(executor::incremental::payloads(delivery, execution).right_stream())
 }
 
 /// Resolve subscription into `ValuesStream`
@@ -242,32 +521,18 @@ where
 {
     let document: crate::ast::OwnedDocument<'a, S> =
         parse_document_source(document_source, &root_node.schema)?;
+    let (operation, cost) =
+        prepare_operation(&document, operation_name, &root_node.schema, variables)?;
 
-    {
-        let mut ctx = ValidatorContext::new(&root_node.schema, &document);
-        visit_all_rules(&mut ctx, &document);
-
-        let errors = ctx.into_errors();
-        if !errors.is_empty() {
-            return Err(GraphQLError::ValidationError(errors));
-        }
-    }
-
-    let operation = get_operation(&document, operation_name)?;
-
-    {
-        let errors = validate_input_values(variables, operation, &root_node.schema);
-
-        if !errors.is_empty() {
-            return Err(GraphQLError::ValidationError(errors));
-        }
-    }
-
-    executor::resolve_validated_subscription(&document, operation, root_node, variables, context)
-        .await
+    executor::resolve_validated_subscription_impl(
+        &document, operation, root_node, variables, context, cost,
+    )
+    .await
 }
 
 /// Execute the reference introspection query in the provided schema
+///
+/// Use [`RootNode::introspect()`] to get the same result without a context.
 pub fn introspect<'a, S, QueryT, MutationT, SubscriptionT>(
     root_node: &'a RootNode<QueryT, MutationT, SubscriptionT, S>,
     context: &QueryT::Context,
@@ -291,6 +556,44 @@ where
     )
 }
 
+/// Validates the provided `document` and the operation with the provided
+/// `operation_name` in it, returning this operation ready to be executed,
+/// along with its cost, if it's been estimated during the validation.
+fn prepare_operation<'b, 'd, S>(
+    document: &'b Document<'d, S>,
+    operation_name: Option<&str>,
+    schema: &SchemaType<S>,
+    variables: &Variables<S>,
+) -> Result<(&'b Spanning<Operation<'d, S>>, Option<u64>), GraphQLError>
+where
+    S: ScalarValue,
+{
+    {
+        let mut ctx = ValidatorContext::new(schema, document);
+        visit_all_rules(&mut ctx, document);
+
+        let errors = ctx.into_errors();
+        if !errors.is_empty() {
+            return Err(GraphQLError::ValidationError(errors));
+        }
+    }
+
+    let operation = get_operation(document, operation_name)?;
+
+    {
+        let errors = validate_input_values(variables, operation, schema);
+
+        if !errors.is_empty() {
+            return Err(GraphQLError::ValidationError(errors));
+        }
+    }
+
+    let cost = validate_cost(operation, document, variables, schema)
+        .map_err(GraphQLError::ValidationError)?;
+
+    Ok((operation, cost))
+}
+
 impl From<Spanning<ParseError>> for GraphQLError {
     fn from(err: Spanning<ParseError>) -> Self {
         Self::ParseError(err)
@@ -610,4 +610,24 @@ mod tests {
         assert_eq!(graphql_input_value!(Some(42)), V::scalar(42));
         assert_eq!(graphql_input_value!((val)), V::scalar(42));
     }
+
+    #[test]
+    fn interpolation() {
+        let val = V::list(vec![V::scalar(1)]);
+        let vec = vec![Some(1), None];
+
+        assert_eq!(graphql_input_value!((val.clone())), val);
+        assert_eq!(
+            graphql_input_value!((vec)),
+            V::list(vec![V::scalar(1), V::Null]),
+        );
+        assert_eq!(
+            graphql_input_value!({ "key": (val) }),
+            V::object(
+                vec![("key", V::list(vec![V::scalar(1)]))]
+                    .into_iter()
+                    .collect(),
+            ),
+        );
+    }
 }
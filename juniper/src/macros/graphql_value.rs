@@ -22,6 +22,43 @@
 /// });
 /// ```
 ///
+/// # Interpolation
+///
+/// No special sigil is required to interpolate a runtime value: any Rust
+/// expression placed where a JSON value is expected is evaluated and converted
+/// via [`Value::from()`]. So, besides scalars, it may evaluate to an [`Option`],
+/// a [`Vec`] or an already built [`Value`].
+///
+/// Only an expression starting with `[` or `{`, or being exactly `null`, is
+/// treated as JSON-like syntax. To interpolate such expression (an array or a
+/// block, for example) surround it with parens: `(expr)`.
+/// ```rust
+/// # use juniper::{graphql_value, Value};
+/// #
+/// let count = 2;
+/// let names = vec!["Alice", "Bob"];
+/// let user: Value = graphql_value!({"name": "Alice"});
+///
+/// let value: Value = graphql_value!({
+///     "count": count,
+///     "names": names.clone(),
+///     "user": user,
+///     "ids": ([1, 2].to_vec()),
+///     "total": ({ count * 10 }),
+/// });
+///
+/// assert_eq!(
+///     value,
+///     graphql_value!({
+///         "count": 2,
+///         "names": ["Alice", "Bob"],
+///         "user": {"name": "Alice"},
+///         "ids": [1, 2],
+///         "total": 20,
+///     }),
+/// );
+/// ```
+///
 /// # Example
 ///
 /// Resulting JSON will look just like what you passed in.
@@ -43,6 +80,7 @@
 /// ```
 ///
 /// [`Value`]: crate::Value
+/// [`Value::from()`]: crate::Value::from
 /// [`Value::Object`]: crate::Value::Object
 #[macro_export]
 macro_rules! graphql_value {
@@ -384,4 +422,30 @@ mod tests {
         assert_eq!(graphql_value!(Some(42)), V::scalar(42));
         assert_eq!(graphql_value!(val), V::scalar(42));
     }
+
+    #[test]
+    fn interpolation() {
+        let val = V::list(vec![V::scalar(1)]);
+        let vec = vec![Some(1), None];
+
+        assert_eq!(graphql_value!(val.clone()), val);
+        assert_eq!(graphql_value!(vec), V::list(vec![V::scalar(1), V::Null]));
+        assert_eq!(
+            graphql_value!(([1, 2].to_vec())),
+            V::list(vec![V::scalar(1), V::scalar(2)]),
+        );
+        assert_eq!(graphql_value!(({ 1 + 2 })), V::scalar(3));
+
+        assert_eq!(
+            graphql_value!({ "key": val.clone(), "list": [val] }),
+            V::object(
+                vec![
+                    ("key", V::list(vec![V::scalar(1)])),
+                    ("list", V::list(vec![V::list(vec![V::scalar(1)])])),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+        );
+    }
 }
@@ -6,7 +6,7 @@ use std::fmt;
 
 use futures::future::{self, BoxFuture};
 
-use crate::FieldError;
+use crate::{FieldError, GraphQLValueAsync, ScalarValue};
 
 /// This trait is used by [`graphql_scalar!`] macro to retrieve [`Error`] type
 /// from a [`Result`].
@@ -53,3 +53,44 @@ where
 {
     Box::pin(future::err(err_unnamed_type(name)))
 }
+
+/// Supertrait of a trait behind a `#[graphql_interface(dyn)]` macro, exposing
+/// the [`GraphQLValueAsync`] of the concrete type behind its trait object.
+///
+/// It's implemented for all the [`GraphQLValueAsync`] types automatically, and
+/// is used to resolve a `dyn Trait` value as the concrete GraphQL object it's
+/// pointing to.
+pub trait AsDynGraphQLValue<S: ScalarValue + Send + Sync> {
+    /// [`GraphQLValue::Context`] of the type implementing this trait.
+    ///
+    /// [`GraphQLValue::Context`]: crate::GraphQLValue::Context
+    type Context: Sync;
+
+    /// [`GraphQLValue::TypeInfo`] of the type implementing this trait.
+    ///
+    /// [`GraphQLValue::TypeInfo`]: crate::GraphQLValue::TypeInfo
+    type TypeInfo: Sync;
+
+    /// Returns this value as a [`GraphQLValueAsync`] trait object.
+    fn as_dyn_graphql_value(
+        &self,
+    ) -> &(dyn GraphQLValueAsync<S, Context = Self::Context, TypeInfo = Self::TypeInfo> + Send);
+}
+
+impl<S, T> AsDynGraphQLValue<S> for T
+where
+    T: GraphQLValueAsync<S> + Send,
+    T::Context: Sync,
+    T::TypeInfo: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    type Context = T::Context;
+    type TypeInfo = T::TypeInfo;
+
+    fn as_dyn_graphql_value(
+        &self,
+    ) -> &(dyn GraphQLValueAsync<S, Context = Self::Context, TypeInfo = Self::TypeInfo> + Send)
+    {
+        self
+    }
+}
@@ -12,3 +12,5 @@ mod graphql_input_value;
 mod graphql_value;
 #[macro_use]
 mod graphql_vars;
+#[macro_use]
+mod partial_schema;
@@ -0,0 +1,175 @@
+//! [`partial_schema!`] macro implementation.
+//!
+//! [`partial_schema!`]: partial_schema
+
+/// Constructs a [`RootNode`] exposing only the provided fields on its query
+/// type.
+///
+/// Intended for tests exercising a few fields of a large schema in isolation:
+/// the provided field resolvers are put into a `#[graphql_object]` named
+/// `Query`, so they accept the same arguments and attributes as fields of any
+/// other [GraphQL object][1]. The produced [`RootNode`] has neither mutations
+/// nor subscriptions.
+///
+/// A context and a [`ScalarValue`] type of the query type may be specified
+/// with leading `context = Type;` and `scalar = Type;` declarations (in this
+/// order). By default, they're `()` and [`DefaultScalarValue`].
+///
+/// ```rust
+/// # use juniper::{execute_sync, graphql_value, graphql_vars, partial_schema, GraphQLObject};
+/// #
+/// #[derive(Clone, GraphQLObject)]
+/// struct User {
+///     id: i32,
+///     name: String,
+/// }
+///
+/// struct Database {
+///     users: Vec<User>,
+/// }
+///
+/// impl juniper::Context for Database {}
+///
+/// let schema = partial_schema! {
+///     context = Database;
+///
+///     /// Looks up a user by its ID.
+///     fn user(id: i32, ctx: &Database) -> Option<User> {
+///         ctx.users.iter().find(|u| u.id == id).cloned()
+///     }
+/// };
+///
+/// let db = Database {
+///     users: vec![User { id: 1, name: "Alice".into() }],
+/// };
+/// assert_eq!(
+///     execute_sync("{ user(id: 1) { name } }", None, &schema, &graphql_vars! {}, &db),
+///     Ok((graphql_value!({"user": {"name": "Alice"}}), vec![])),
+/// );
+/// ```
+///
+/// [`DefaultScalarValue`]: crate::DefaultScalarValue
+/// [`RootNode`]: crate::RootNode
+/// [`ScalarValue`]: crate::ScalarValue
+/// [1]: https://spec.graphql.org/October2021#sec-Objects
+#[macro_export]
+macro_rules! partial_schema {
+    (context = $context:ty; scalar = $scalar:ty; $($fields:tt)*) => {{
+        struct __PartialQuery;
+
+        #[$crate::graphql_object(name = "Query", context = $context, scalar = $scalar)]
+        impl __PartialQuery {
+            $($fields)*
+        }
+
+        $crate::RootNode::new_with_scalar_value(
+            __PartialQuery,
+            $crate::EmptyMutation::<$context>::new(),
+            $crate::EmptySubscription::<$context>::new(),
+        )
+    }};
+
+    (context = $context:ty; $($fields:tt)*) => {
+        $crate::partial_schema! {
+            context = $context;
+            scalar = $crate::DefaultScalarValue;
+            $($fields)*
+        }
+    };
+
+    (scalar = $scalar:ty; $($fields:tt)*) => {
+        $crate::partial_schema! {
+            context = ();
+            scalar = $scalar;
+            $($fields)*
+        }
+    };
+
+    ($($fields:tt)*) => {
+        $crate::partial_schema! {
+            context = ();
+            scalar = $crate::DefaultScalarValue;
+            $($fields)*
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{execute, execute_sync, DefaultScalarValue};
+
+    #[test]
+    fn exposes_fields() {
+        let schema = partial_schema! {
+            fn answer() -> i32 {
+                42
+            }
+
+            #[graphql(name = "greeting")]
+            fn greet(name: String) -> String {
+                format!("Hello, {name}!")
+            }
+        };
+
+        assert_eq!(
+            execute_sync(
+                r#"{ answer greeting(name: "Bob") }"#,
+                None,
+                &schema,
+                &graphql_vars! {},
+                &(),
+            ),
+            Ok((
+                graphql_value!({"answer": 42, "greeting": "Hello, Bob!"}),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn has_no_other_root_types() {
+        let schema = partial_schema! {
+            fn answer() -> i32 {
+                42
+            }
+        };
+
+        assert_eq!(
+            execute_sync(
+                "{ __schema { queryType { name } mutationType { name } } }",
+                None,
+                &schema,
+                &graphql_vars! {},
+                &(),
+            ),
+            Ok((
+                graphql_value!({"__schema": {
+                    "queryType": {"name": "Query"},
+                    "mutationType": null,
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    struct Context(i32);
+
+    impl crate::Context for Context {}
+
+    #[tokio::test]
+    async fn uses_context_and_scalar() {
+        let schema = partial_schema! {
+            context = Context;
+            scalar = DefaultScalarValue;
+
+            async fn value(ctx: &Context) -> i32 {
+                ctx.0
+            }
+        };
+
+        assert_eq!(
+            execute("{ value }", None, &schema, &graphql_vars! {}, &Context(7)).await,
+            Ok((graphql_value!({"value": 7}), vec![])),
+        );
+    }
+}
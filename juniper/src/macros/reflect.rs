@@ -1,11 +1,12 @@
 //! Compile-time reflection of Rust types into GraphQL types.
 
-use std::{rc::Rc, sync::Arc};
+use std::{borrow::Cow, rc::Rc, sync::Arc};
 
 use futures::future::BoxFuture;
 
 use crate::{
-    Arguments as FieldArguments, ExecutionResult, Executor, GraphQLValue, Nullable, ScalarValue,
+    meta::Field as MetaField, Arguments as FieldArguments, ExecutionResult, Executor, GraphQLValue,
+    Nullable, Registry, ScalarValue,
 };
 
 /// Alias for a [GraphQL object][1], [scalar][2] or [interface][3] type's name
@@ -92,6 +93,10 @@ impl<S, T: BaseType<S> + ?Sized> BaseType<S> for Rc<T> {
     const NAME: Type = T::NAME;
 }
 
+impl<'a, S, T: BaseType<S> + ToOwned + ?Sized> BaseType<S> for Cow<'a, T> {
+    const NAME: Type = T::NAME;
+}
+
 /// [Sub-types][2] of a [GraphQL object][1].
 ///
 /// This trait is transparent to [`Option`], [`Vec`] and other containers.
@@ -153,6 +158,10 @@ impl<S, T: BaseSubTypes<S> + ?Sized> BaseSubTypes<S> for Rc<T> {
     const NAMES: Types = T::NAMES;
 }
 
+impl<'a, S, T: BaseSubTypes<S> + ToOwned + ?Sized> BaseSubTypes<S> for Cow<'a, T> {
+    const NAMES: Types = T::NAMES;
+}
+
 /// Alias for a value of a [`WrappedType`] (composed GraphQL type).
 pub type WrappedValue = u128;
 
@@ -237,6 +246,36 @@ impl<S, T: WrappedType<S>, const N: usize> WrappedType<S> for [T; N] {
     const VALUE: u128 = T::VALUE * 10 + 3;
 }
 
+/// Implements [`BaseType`], [`BaseSubTypes`] and [`WrappedType`] for
+/// homogeneous tuples, being represented as fixed-length GraphQL lists.
+macro_rules! impl_for_tuple {
+    (@elem $idx:tt) => { T };
+    ($($idx:tt),+) => {
+        impl<S, T: BaseType<S>> BaseType<S> for ($(impl_for_tuple!(@elem $idx),)+) {
+            const NAME: Type = T::NAME;
+        }
+
+        impl<S, T: BaseSubTypes<S>> BaseSubTypes<S> for ($(impl_for_tuple!(@elem $idx),)+) {
+            const NAMES: Types = T::NAMES;
+        }
+
+        impl<S, T: WrappedType<S>> WrappedType<S> for ($(impl_for_tuple!(@elem $idx),)+) {
+            const VALUE: u128 = T::VALUE * 10 + 3;
+        }
+    };
+}
+
+impl_for_tuple!(0, 1, 2);
+impl_for_tuple!(0, 1, 2, 3);
+impl_for_tuple!(0, 1, 2, 3, 4);
+impl_for_tuple!(0, 1, 2, 3, 4, 5);
+impl_for_tuple!(0, 1, 2, 3, 4, 5, 6);
+impl_for_tuple!(0, 1, 2, 3, 4, 5, 6, 7);
+impl_for_tuple!(0, 1, 2, 3, 4, 5, 6, 7, 8);
+impl_for_tuple!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9);
+impl_for_tuple!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10);
+impl_for_tuple!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11);
+
 impl<'a, S, T: WrappedType<S> + ?Sized> WrappedType<S> for &'a T {
     const VALUE: u128 = T::VALUE;
 }
@@ -253,6 +292,10 @@ impl<S, T: WrappedType<S> + ?Sized> WrappedType<S> for Rc<T> {
     const VALUE: u128 = T::VALUE;
 }
 
+impl<'a, S, T: WrappedType<S> + ToOwned + ?Sized> WrappedType<S> for Cow<'a, T> {
+    const VALUE: u128 = T::VALUE;
+}
+
 /// Alias for a [GraphQL object][1] or [interface][2] [field argument][3] name.
 ///
 /// See [`Fields`] for more info.
@@ -394,6 +437,80 @@ pub trait AsyncField<S, const N: FieldName>: FieldMeta<S, N> {
     ) -> BoxFuture<'b, ExecutionResult<S>>;
 }
 
+/// Fields of a [GraphQL object][1] `T` defined outside of its main definition.
+///
+/// Implemented for the marker type declared by an
+/// `#[graphql_object(extension = Marker)]` attribute, and used by the
+/// [GraphQL object][1] listing it in its `extensions = [...]` attribute
+/// argument.
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Objects
+pub trait ObjectExtension<T: ?Sized, S>: ExtensionFields {
+    /// [`GraphQLValue::Context`] of the fields of this [`ObjectExtension`].
+    type Context;
+
+    /// Builds the [`meta::Field`]s of this [`ObjectExtension`].
+    ///
+    /// [`meta::Field`]: crate::meta::Field
+    fn meta_fields<'r>(info: &(), registry: &mut Registry<'r, S>) -> Vec<MetaField<'r, S>>
+    where
+        S: 'r;
+
+    /// Resolves the `field` of this [`ObjectExtension`] synchronously.
+    ///
+    /// Returns [`None`] if this [`ObjectExtension`] doesn't define the `field`.
+    fn resolve_field(
+        object: &T,
+        info: &(),
+        field: &str,
+        args: &FieldArguments<S>,
+        executor: &Executor<Self::Context, S>,
+    ) -> Option<ExecutionResult<S>>;
+
+    /// Resolves the `field` of this [`ObjectExtension`] asynchronously.
+    ///
+    /// Returns [`None`] if this [`ObjectExtension`] doesn't define the `field`.
+    fn resolve_field_async<'b>(
+        object: &'b T,
+        info: &'b (),
+        field: &'b str,
+        args: &'b FieldArguments<S>,
+        executor: &'b Executor<Self::Context, S>,
+    ) -> Option<BoxFuture<'b, ExecutionResult<S>>>
+    where
+        T: Sync,
+        S: Send + Sync;
+}
+
+/// [`Names`] of the fields defined by an [`ObjectExtension`].
+///
+/// Separated from the [`ObjectExtension`], so the [`Names`] may be concatenated
+/// in a `const` context (see [`concat_names()`]) without being generic over a
+/// [`ScalarValue`].
+pub trait ExtensionFields {
+    /// [`Names`] of the fields defined by this [`ObjectExtension`].
+    const NAMES: Names;
+}
+
+/// Concatenates the provided `parts` of [`Names`] in a `const` context.
+///
+/// `N` must be the total length of all the `parts`.
+#[must_use]
+pub const fn concat_names<const N: usize>(parts: &[Names]) -> [Name; N] {
+    let mut names = [""; N];
+    let (mut i, mut n) = (0, 0);
+    while i < parts.len() {
+        let mut j = 0;
+        while j < parts[i].len() {
+            names[n] = parts[i][j];
+            n += 1;
+            j += 1;
+        }
+        i += 1;
+    }
+    names
+}
+
 /// Non-cryptographic hash with good dispersion to use as a [`str`](prim@str) in
 /// `const` generics. See [spec] for more info.
 ///
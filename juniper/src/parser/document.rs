@@ -1,4 +1,9 @@
-use std::borrow::Cow;
+use std::{
+    any::{Any, TypeId},
+    borrow::Cow,
+    cell::RefCell,
+    collections::HashMap,
+};
 
 use crate::ast::{
     Arguments, Definition, Directive, Field, Fragment, FragmentSpread, InlineFragment, InputValue,
@@ -8,14 +13,19 @@ use crate::ast::{
 
 use crate::{
     parser::{
-        value::parse_value_literal, Lexer, OptionParseResult, ParseError, ParseResult, Parser,
-        Spanning, Token, UnlocatedParseResult,
+        value::parse_value_literal, Lexer, OptionParseResult, ParseError, ParseOptions,
+        ParseResult, Parser, Spanning, Token, UnlocatedParseResult,
     },
     schema::{
-        meta::{Argument, Field as MetaField},
+        meta::{Argument, Field as MetaField, MetaType},
         model::SchemaType,
     },
+    types::{
+        base::{GraphQLType, GraphQLValue},
+        scalars::{EmptyMutation, EmptySubscription, ID},
+    },
     value::ScalarValue,
+    Registry,
 };
 
 #[doc(hidden)]
@@ -26,11 +36,146 @@ pub fn parse_document_source<'a, 'b, S>(
 where
     S: ScalarValue,
 {
-    let mut lexer = Lexer::new(s);
-    let mut parser = Parser::new(&mut lexer).map_err(|s| s.map(ParseError::LexerError))?;
+    parse_document_source_with_options(s, schema, schema.parse_options)
+}
+
+/// Parses the provided `source` for the provided `schema`, limited by the
+/// provided [`ParseOptions`] instead of the `schema`'s ones.
+pub(crate) fn parse_document_source_with_options<'a, 'b, S>(
+    source: &'a str,
+    schema: &'b SchemaType<'b, S>,
+    options: ParseOptions,
+) -> UnlocatedParseResult<OwnedDocument<'a, S>>
+where
+    S: ScalarValue,
+{
+    let mut lexer = Lexer::new(source);
+    let mut parser = Parser::new_with_options(&mut lexer, options)?;
     parse_document(&mut parser, schema)
 }
 
+/// Parses the provided GraphQL `source` into a [`Document`] AST, without
+/// requiring any schema.
+///
+/// This allows analyzing a query (like extracting the requested fields or the
+/// used fragments) without executing or validating it. The document is only
+/// checked to be syntactically correct, so it may refer to types, fields or
+/// arguments that don't exist.
+///
+/// As no types are known, scalar literals are parsed by their kind only:
+/// strings into [`String`]s, integers into [`i32`]s and floats into [`f64`]s
+/// of the [`ScalarValue`] (so, for example, an integer literal out of the
+/// [`i32`] range fails to parse, even if some custom scalar would accept it).
+///
+/// Every node of the returned AST carries its location in the `source` via
+/// [`Spanning`].
+///
+/// The provided [`ParseOptions`] allow to limit the size of the parsed
+/// `source`.
+///
+/// # Example
+///
+/// ```rust
+/// # use juniper::{
+/// #     parser::{parse_document_source_without_schema, ParseOptions},
+/// #     Definition, DefaultScalarValue, Selection,
+/// # };
+/// #
+/// let doc = parse_document_source_without_schema::<DefaultScalarValue>(
+///     "query Hero { hero { name ...Friends } } fragment Friends on Character { friends { name } }",
+///     ParseOptions::default(),
+/// )
+/// .unwrap();
+///
+/// let op = match &doc[0] {
+///     Definition::Operation(op) => op,
+///     Definition::Fragment(_) => unreachable!(),
+/// };
+/// assert_eq!(op.item.name.as_ref().map(|n| n.item), Some("Hero"));
+/// assert_eq!(op.start.index(), 0);
+///
+/// let hero = match &op.item.selection_set[0] {
+///     Selection::Field(f) => f,
+///     _ => unreachable!(),
+/// };
+/// assert_eq!(hero.item.name.item, "hero");
+/// assert_eq!(hero.start.column(), 13);
+/// assert_eq!(hero.item.selection_set.as_ref().unwrap().len(), 2);
+///
+/// let frag = match &doc[1] {
+///     Definition::Fragment(f) => f,
+///     Definition::Operation(_) => unreachable!(),
+/// };
+/// assert_eq!(frag.item.name.item, "Friends");
+/// assert_eq!(frag.item.type_condition.item, "Character");
+/// ```
+///
+/// [`Document`]: crate::Document
+pub fn parse_document_source_without_schema<S>(
+    source: &str,
+    options: ParseOptions,
+) -> UnlocatedParseResult<OwnedDocument<'_, S>>
+where
+    S: ScalarValue,
+{
+    thread_local! {
+        /// Schemas without any fields, built once per thread for every
+        /// [`ScalarValue`] type.
+        static UNTYPED_SCHEMAS: RefCell<HashMap<TypeId, Box<dyn Any>>> =
+            RefCell::new(HashMap::new());
+    }
+
+    UNTYPED_SCHEMAS.with(|schemas| {
+        // A schema without any fields only provides the built-in scalars and
+        // directives, so all the literals are parsed by their kind.
+        let mut schemas = schemas.borrow_mut();
+        let schema = schemas
+            .entry(TypeId::of::<S>())
+            .or_insert_with(|| {
+                Box::new(SchemaType::<S>::new::<
+                    Untyped,
+                    EmptyMutation,
+                    EmptySubscription,
+                >(&(), &(), &()))
+            })
+            .downcast_ref::<SchemaType<'static, S>>()
+            .expect("`SchemaType` of another `ScalarValue` type");
+        parse_document_source_with_options(source, schema, options)
+    })
+}
+
+/// Root type of a schema used for parsing a document without any schema.
+///
+/// Has no fields, but registers all the built-in scalars.
+struct Untyped;
+
+impl<S: ScalarValue> GraphQLType<S> for Untyped {
+    fn name(_: &()) -> Option<&'static str> {
+        Some("_Untyped")
+    }
+
+    fn meta<'r>(_: &(), registry: &mut Registry<'r, S>) -> MetaType<'r, S>
+    where
+        S: 'r,
+    {
+        registry.get_type::<i32>(&());
+        registry.get_type::<f64>(&());
+        registry.get_type::<String>(&());
+        registry.get_type::<bool>(&());
+        registry.get_type::<ID>(&());
+        registry.build_object_type::<Self>(&(), &[]).into_meta()
+    }
+}
+
+impl<S: ScalarValue> GraphQLValue<S> for Untyped {
+    type Context = ();
+    type TypeInfo = ();
+
+    fn type_name<'i>(&self, info: &'i Self::TypeInfo) -> Option<&'i str> {
+        <Self as GraphQLType<S>>::name(info)
+    }
+}
+
 fn parse_document<'a, 'b, S>(
     parser: &mut Parser<'a>,
     schema: &'b SchemaType<'b, S>,
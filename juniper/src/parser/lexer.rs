@@ -109,31 +109,27 @@ impl<'a> Lexer<'a> {
     }
 
     fn peek_char(&mut self) -> Option<(usize, char)> {
-        assert!(self.position.index() <= self.length);
+        assert!(self.position.byte_index() <= self.length);
         assert!(!self.has_reached_eof);
 
         self.iterator.peek().map(|&(idx, ch)| (idx, ch))
     }
 
     fn next_char(&mut self) -> Option<(usize, char)> {
-        assert!(self.position.index() <= self.length);
+        assert!(self.position.byte_index() <= self.length);
         assert!(!self.has_reached_eof);
 
         let next = self.iterator.next();
 
         if let Some((_, ch)) = next {
-            if ch == '\n' {
-                self.position.advance_line();
-            } else {
-                self.position.advance_col();
-            }
+            self.position.advance_char(ch);
         }
 
         next
     }
 
     fn emit_single_char(&mut self, t: Token<'a>) -> Spanning<Token<'a>> {
-        assert!(self.position.index() <= self.length);
+        assert!(self.position.byte_index() <= self.length);
 
         let start_pos = self.position;
 
@@ -10,10 +10,14 @@ mod value;
 #[cfg(test)]
 mod tests;
 
-pub use self::document::parse_document_source;
+pub use self::document::{parse_document_source, parse_document_source_without_schema};
+
+pub(crate) use self::document::parse_document_source_with_options;
 
 pub use self::{
     lexer::{Lexer, LexerError, ScalarToken, Token},
-    parser::{OptionParseResult, ParseError, ParseResult, Parser, UnlocatedParseResult},
+    parser::{
+        OptionParseResult, ParseError, ParseOptions, ParseResult, Parser, UnlocatedParseResult,
+    },
     utils::{SourcePosition, Spanning},
 };
@@ -20,6 +20,14 @@ pub enum ParseError {
 
     /// A scalar of unexpected type occurred in the source
     ExpectedScalarError(&'static str),
+
+    /// The source contains more tokens than allowed by the
+    /// [`ParseOptions::max_tokens()`] limit (carried by this variant)
+    TooManyTokens(usize),
+
+    /// The source nests deeper than allowed by the
+    /// [`ParseOptions::max_depth()`] limit (carried by this variant)
+    TooDeep(usize),
 }
 
 impl fmt::Display for ParseError {
@@ -29,6 +37,8 @@ impl fmt::Display for ParseError {
             Self::UnexpectedEndOfFile => write!(f, "Unexpected end of input"),
             Self::LexerError(e) => e.fmt(f),
             Self::ExpectedScalarError(e) => e.fmt(f),
+            Self::TooManyTokens(max) => write!(f, "Query exceeds the maximum of {max} tokens"),
+            Self::TooDeep(max) => write!(f, "Query exceeds the maximum nesting depth of {max}"),
         }
     }
 }
@@ -37,9 +47,11 @@ impl Error for ParseError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
             Self::LexerError(e) => Some(e),
-            Self::ExpectedScalarError(_) | Self::UnexpectedToken(_) | Self::UnexpectedEndOfFile => {
-                None
-            }
+            Self::ExpectedScalarError(_)
+            | Self::UnexpectedToken(_)
+            | Self::UnexpectedEndOfFile
+            | Self::TooManyTokens(_)
+            | Self::TooDeep(_) => None,
         }
     }
 }
@@ -59,6 +71,69 @@ impl ParseError {
     }
 }
 
+/// Limits applied while parsing a GraphQL query, allowing to reject
+/// pathologically large or deeply nested sources before building their AST.
+///
+/// Both limits are checked while lexing the source, so parsing fails fast on
+/// the first token exceeding them. By default, nothing is limited.
+///
+/// ```rust
+/// # use juniper::parser::{parse_document_source_without_schema, ParseError, ParseOptions};
+/// # use juniper::DefaultScalarValue;
+/// #
+/// let options = ParseOptions::new().max_tokens(100).max_depth(2);
+///
+/// assert!(parse_document_source_without_schema::<DefaultScalarValue>(
+///     "{ hero { name } }",
+///     options,
+/// )
+/// .is_ok());
+/// assert_eq!(
+///     parse_document_source_without_schema::<DefaultScalarValue>(
+///         "{ hero { friends { name } } }",
+///         options,
+///     )
+///     .unwrap_err()
+///     .item,
+///     ParseError::TooDeep(2),
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ParseOptions {
+    max_tokens: Option<usize>,
+    max_depth: Option<usize>,
+}
+
+impl ParseOptions {
+    /// Creates new [`ParseOptions`] without any limits.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits the number of tokens in a parsed source to the provided `max`,
+    /// failing with a [`ParseError::TooManyTokens`] otherwise.
+    ///
+    /// Punctuators (like `{` or `:`), names and literals are counted as a
+    /// single token each, while whitespaces, commas and comments are not.
+    #[must_use]
+    pub fn max_tokens(mut self, max: usize) -> Self {
+        self.max_tokens = Some(max);
+        self
+    }
+
+    /// Limits the nesting depth of a parsed source to the provided `max`,
+    /// failing with a [`ParseError::TooDeep`] otherwise.
+    ///
+    /// Every selection set, list and object value opens a new nesting level,
+    /// so `{ hero { name } }` has the depth of `2`.
+    #[must_use]
+    pub fn max_depth(mut self, max: usize) -> Self {
+        self.max_depth = Some(max);
+        self
+    }
+}
+
 #[doc(hidden)]
 pub type ParseResult<T> = Result<Spanning<T>, Spanning<ParseError>>;
 
@@ -89,6 +164,37 @@ impl<'a> Parser<'a> {
         Ok(Parser { tokens })
     }
 
+    #[doc(hidden)]
+    pub fn new_with_options(
+        lexer: &mut Lexer<'a>,
+        options: ParseOptions,
+    ) -> Result<Parser<'a>, Spanning<ParseError>> {
+        let mut tokens = Vec::new();
+        let mut depth = 0;
+
+        for res in lexer {
+            let token = res.map_err(|e| e.map(ParseError::LexerError))?;
+            match token.item {
+                Token::EndOfFile => {}
+                _ if options.max_tokens.map_or(false, |max| tokens.len() >= max) => {
+                    return Err(token.map(|_| ParseError::TooManyTokens(tokens.len())));
+                }
+                Token::CurlyOpen | Token::BracketOpen => {
+                    depth += 1;
+                    if options.max_depth.map_or(false, |max| depth > max) {
+                        return Err(token.map(|_| ParseError::TooDeep(depth - 1)));
+                    }
+                }
+                // Unbalanced closings are reported by the parser itself.
+                Token::CurlyClose | Token::BracketClose => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+            tokens.push(token);
+        }
+
+        Ok(Parser { tokens })
+    }
+
     #[doc(hidden)]
     pub fn peek(&self) -> &Spanning<Token<'a>> {
         &self.tokens[0]
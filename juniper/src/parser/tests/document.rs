@@ -1,7 +1,10 @@
 use crate::{
     ast::{Arguments, Definition, Field, Operation, OperationType, OwnedDocument, Selection},
     graphql_input_value,
-    parser::{document::parse_document_source, ParseError, SourcePosition, Spanning, Token},
+    parser::{
+        document::{parse_document_source, parse_document_source_without_schema},
+        ParseError, ParseOptions, SourcePosition, Spanning, Token,
+    },
     schema::model::SchemaType,
     types::scalars::{EmptyMutation, EmptySubscription},
     validation::test_harness::{MutationRoot, QueryRoot, SubscriptionRoot},
@@ -147,6 +150,83 @@ fn errors() {
     );
 }
 
+#[test]
+fn error_byte_range_with_multi_byte_characters() {
+    let source = "# πλ\n{ field(s: \"π\") } }";
+    let err = parse_document_error::<DefaultScalarValue>(source);
+
+    assert_eq!(
+        err,
+        Spanning::start_end(
+            &SourcePosition::new_with_byte_index(23, 26, 1, 18),
+            &SourcePosition::new_with_byte_index(24, 27, 1, 19),
+            ParseError::unexpected_token(Token::CurlyClose)
+        )
+    );
+    assert_eq!(err.byte_range(), 26..27);
+    assert_eq!(&source[err.byte_range()], "}");
+}
+
+fn top_level_fields<'a>(doc: &OwnedDocument<'a, DefaultScalarValue>) -> Vec<Spanning<&'a str>> {
+    match &doc[0] {
+        Definition::Operation(op) => op
+            .item
+            .selection_set
+            .iter()
+            .map(|s| match s {
+                Selection::Field(f) => Spanning::start_end(&f.start, &f.end, f.item.name.item),
+                _ => panic!("Selection is not a field: {s:#?}"),
+            })
+            .collect(),
+        d => panic!("Definition is not an operation: {d:#?}"),
+    }
+}
+
+#[test]
+fn merges_multi_line_spans() {
+    let source = "{\n  hero { name }\n  droid\n}";
+    let doc = parse_document::<DefaultScalarValue>(source);
+    let fields = top_level_fields(&doc);
+
+    let merged = fields[0].merge_span(&fields[1]);
+
+    assert_eq!(merged.item, "hero");
+    assert_eq!(merged.start, SourcePosition::new(4, 1, 2));
+    assert_eq!(merged.end, SourcePosition::new(25, 2, 7));
+    assert_eq!(&source[merged.byte_range()], "hero { name }\n  droid");
+    assert_eq!(
+        fields[1].merge_span(&fields[0]).byte_range(),
+        merged.byte_range(),
+    );
+}
+
+#[test]
+fn maps_multi_line_spans_into_enclosing_source() {
+    let source = "{\n  hero {\n    name\n  }\n}";
+    let enclosing = format!("let q = gql(\"{source}\");");
+    let origin = SourcePosition::new(13, 0, 13);
+    let doc = parse_document::<DefaultScalarValue>(source);
+
+    let hero = top_level_fields(&doc)[0].map_span(|pos| pos.embedded_at(&origin));
+
+    assert_eq!(hero.start, SourcePosition::new(17, 1, 2));
+    assert_eq!(hero.end, SourcePosition::new(36, 3, 3));
+    assert_eq!(&enclosing[hero.byte_range()], "hero {\n    name\n  }");
+}
+
+#[test]
+fn maps_single_line_spans_into_enclosing_source() {
+    let source = r#"{ hero(name: "π") }"#;
+    let enclosing = format!("πλ: '{source}'");
+    let origin = SourcePosition::new_with_byte_index(5, 7, 0, 5);
+    let doc = parse_document::<DefaultScalarValue>(source);
+
+    let hero = top_level_fields(&doc)[0].map_span(|pos| pos.embedded_at(&origin));
+
+    assert_eq!(hero.start, SourcePosition::new_with_byte_index(7, 9, 0, 7));
+    assert_eq!(&enclosing[hero.byte_range()], r#"hero(name: "π")"#);
+}
+
 #[test]
 fn issue_427_panic_is_not_expected() {
     struct QueryWithoutFloat;
@@ -170,3 +250,98 @@ fn issue_427_panic_is_not_expected() {
         ParseError::ExpectedScalarError("There needs to be a Float type")
     );
 }
+
+#[test]
+fn parses_without_schema() {
+    let doc = parse_document_source_without_schema::<DefaultScalarValue>(
+        r#"{ unknown(int: 1, float: 2.5, str: "s", enum: FOO, obj: {list: [1]}) { ...Frag } }"#,
+        ParseOptions::default(),
+    )
+    .unwrap();
+
+    let field = match &doc[0] {
+        Definition::Operation(op) => match &op.item.selection_set[0] {
+            Selection::Field(f) => f,
+            s => panic!("Selection is not a field: {s:#?}"),
+        },
+        d => panic!("Definition is not an operation: {d:#?}"),
+    };
+    assert_eq!(field.item.name.item, "unknown");
+    assert_eq!(field.start, SourcePosition::new(2, 0, 2));
+
+    let args = &field.item.arguments.as_ref().unwrap().item;
+    assert_eq!(args.get("int").unwrap().item, graphql_input_value!(1));
+    assert_eq!(args.get("float").unwrap().item, graphql_input_value!(2.5));
+    assert_eq!(args.get("str").unwrap().item, graphql_input_value!("s"));
+    assert_eq!(args.get("enum").unwrap().item, graphql_input_value!(FOO));
+    assert!(args
+        .get("obj")
+        .unwrap()
+        .item
+        .unlocated_eq(&graphql_input_value!({"list": [1]})));
+
+    assert!(matches!(
+        &field.item.selection_set.as_ref().unwrap()[0],
+        Selection::FragmentSpread(s) if s.item.name.item == "Frag",
+    ));
+}
+
+#[test]
+fn reports_syntax_errors_without_schema() {
+    assert_eq!(
+        parse_document_source_without_schema::<DefaultScalarValue>(
+            "{ field(arg: ) }",
+            ParseOptions::default(),
+        )
+        .unwrap_err(),
+        Spanning::start_end(
+            &SourcePosition::new(13, 0, 13),
+            &SourcePosition::new(14, 0, 14),
+            ParseError::unexpected_token(Token::ParenClose),
+        ),
+    );
+}
+
+#[test]
+fn limits_tokens() {
+    let parse = |s, max| {
+        parse_document_source_without_schema::<DefaultScalarValue>(
+            s,
+            ParseOptions::new().max_tokens(max),
+        )
+    };
+
+    assert!(parse("{ a, b } # comment", 4).is_ok());
+    assert_eq!(
+        parse("{ a b c }", 3).unwrap_err(),
+        Spanning::start_end(
+            &SourcePosition::new(6, 0, 6),
+            &SourcePosition::new(7, 0, 7),
+            ParseError::TooManyTokens(3),
+        ),
+    );
+}
+
+#[test]
+fn limits_depth() {
+    let parse = |s, max| {
+        parse_document_source_without_schema::<DefaultScalarValue>(
+            s,
+            ParseOptions::new().max_depth(max),
+        )
+    };
+
+    assert!(parse("{ a { b } c { d(arg: [1]) } }", 3).is_ok());
+    assert_eq!(
+        parse("{ a { b(arg: [[1]]) } }", 3).unwrap_err(),
+        Spanning::start_end(
+            &SourcePosition::new(14, 0, 14),
+            &SourcePosition::new(15, 0, 15),
+            ParseError::TooDeep(3),
+        ),
+    );
+    assert_eq!(
+        parse("{ a(arg: {b: {c: 1}}) }", 2).unwrap_err().item,
+        ParseError::TooDeep(2),
+    );
+}
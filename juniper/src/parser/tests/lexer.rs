@@ -144,6 +144,30 @@ fn error_positions() {
     );
 }
 
+#[test]
+fn multi_byte_positions() {
+    let mut lexer = Lexer::new("\"π\"\n ππ");
+
+    let token = lexer.next().unwrap().unwrap();
+    assert_eq!(
+        token,
+        Spanning::start_end(
+            &SourcePosition::new(0, 0, 0),
+            &SourcePosition::new_with_byte_index(3, 4, 0, 3),
+            Token::Scalar(ScalarToken::String("π")),
+        ),
+    );
+    assert_eq!(token.byte_range(), 0..4);
+
+    assert_eq!(
+        lexer.next(),
+        Some(Err(Spanning::zero_width(
+            &SourcePosition::new_with_byte_index(5, 6, 1, 1),
+            LexerError::UnknownCharacter('π'),
+        ))),
+    );
+}
+
 #[test]
 fn strings() {
     assert_eq!(
@@ -327,7 +351,7 @@ fn string_errors() {
     assert_eq!(
         tokenize_error(r#""\uɠ^A"#),
         Spanning::zero_width(
-            &SourcePosition::new(5, 0, 5),
+            &SourcePosition::new_with_byte_index(5, 6, 0, 5),
             LexerError::UnterminatedString
         )
     );
@@ -1,9 +1,10 @@
-use std::fmt;
+use std::{fmt, ops::Range};
 
 /// A reference to a line and column in an input source file
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Copy)]
 pub struct SourcePosition {
     index: usize,
+    byte_index: usize,
     line: usize,
     col: usize,
 }
@@ -81,6 +82,14 @@ impl<T> Spanning<T> {
         }
     }
 
+    /// Returns the range of UTF-8 bytes in the input source this item spans.
+    ///
+    /// May be used to slice the original source, as the range never splits a
+    /// multi-byte character.
+    pub fn byte_range(&self) -> Range<usize> {
+        self.start.byte_index..self.end.byte_index
+    }
+
     /// Modify the contents of the spanned item.
     pub fn map<O, F: Fn(T) -> O>(self, f: F) -> Spanning<O> {
         Spanning {
@@ -90,6 +99,31 @@ impl<T> Spanning<T> {
         }
     }
 
+    /// Modifies both the start and the end positions of this span with the
+    /// provided function, keeping the spanned item intact.
+    ///
+    /// Useful for relocating the spanned item into an enclosing input source
+    /// (see [`SourcePosition::embedded_at()`]).
+    pub fn map_span<F: Fn(SourcePosition) -> SourcePosition>(self, f: F) -> Self {
+        Spanning {
+            item: self.item,
+            start: f(self.start),
+            end: f(self.end),
+        }
+    }
+
+    /// Extends this span to the smallest one covering both this span and the
+    /// `other` one, keeping the spanned item intact.
+    ///
+    /// Spans don't have to overlap, so anything between them is covered too.
+    pub fn merge_span<U>(self, other: &Spanning<U>) -> Self {
+        Spanning {
+            item: self.item,
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        }
+    }
+
     /// Modifies the contents of the spanned item in case `f` returns [`Some`],
     /// or returns [`None`] otherwise.
     pub fn and_then<O, F: Fn(T) -> Option<O>>(self, f: F) -> Option<Spanning<O>> {
@@ -107,17 +141,36 @@ impl<T: fmt::Display> fmt::Display for Spanning<T> {
 impl<T: std::error::Error> std::error::Error for Spanning<T> {}
 
 impl SourcePosition {
+    /// Creates a new [`SourcePosition`] in an ASCII-only input source, where
+    /// the `index` of a character is the same as its byte index.
     #[doc(hidden)]
     pub fn new(index: usize, line: usize, col: usize) -> SourcePosition {
+        Self::new_with_byte_index(index, index, line, col)
+    }
+
+    #[doc(hidden)]
+    pub fn new_with_byte_index(
+        index: usize,
+        byte_index: usize,
+        line: usize,
+        col: usize,
+    ) -> SourcePosition {
         assert!(index >= line + col);
+        assert!(byte_index >= index);
 
-        SourcePosition { index, line, col }
+        SourcePosition {
+            index,
+            byte_index,
+            line,
+            col,
+        }
     }
 
     #[doc(hidden)]
     pub fn new_origin() -> SourcePosition {
         SourcePosition {
             index: 0,
+            byte_index: 0,
             line: 0,
             col: 0,
         }
@@ -126,24 +179,68 @@ impl SourcePosition {
     #[doc(hidden)]
     pub fn advance_col(&mut self) {
         self.index += 1;
+        self.byte_index += 1;
         self.col += 1;
     }
 
     #[doc(hidden)]
     pub fn advance_line(&mut self) {
         self.index += 1;
+        self.byte_index += 1;
         self.line += 1;
         self.col = 0;
     }
 
+    /// Advances this [`SourcePosition`] past the provided character `ch`.
+    #[doc(hidden)]
+    pub fn advance_char(&mut self, ch: char) {
+        if ch == '\n' {
+            self.advance_line();
+        } else {
+            self.advance_col();
+        }
+        self.byte_index += ch.len_utf8() - 1;
+    }
+
+    /// Returns the position this [`SourcePosition`] would have if its input
+    /// source was embedded into another input source at the `origin`
+    /// position.
+    ///
+    /// Only the first line of the embedded source is shifted by the column of
+    /// the `origin`, as the following lines start at the very beginning of
+    /// their lines in the enclosing source too.
+    pub fn embedded_at(self, origin: &SourcePosition) -> SourcePosition {
+        SourcePosition {
+            index: origin.index + self.index,
+            byte_index: origin.byte_index + self.byte_index,
+            line: origin.line + self.line,
+            col: if self.line == 0 {
+                origin.col + self.col
+            } else {
+                self.col
+            },
+        }
+    }
+
     /// The index of the character in the input source
     ///
-    /// Zero-based index. Take a substring of the original source starting at
-    /// this index to access the item pointed to by this `SourcePosition`.
+    /// Zero-based index, counting characters (not bytes). Use
+    /// [`SourcePosition::byte_index()`] to take a substring of the original
+    /// source starting at the item pointed to by this `SourcePosition`.
     pub fn index(&self) -> usize {
         self.index
     }
 
+    /// The index of the first byte of the character in the input source
+    ///
+    /// Zero-based index, counting UTF-8 bytes, so it differs from the
+    /// [`SourcePosition::index()`] once a multi-byte character (like `π`) is
+    /// met. Take a substring of the original source starting at this index to
+    /// access the item pointed to by this `SourcePosition`.
+    pub fn byte_index(&self) -> usize {
+        self.byte_index
+    }
+
     /// The line of the character in the input source
     ///
     /// Zero-based index: the first line is line zero.
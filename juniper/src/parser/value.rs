@@ -3,7 +3,7 @@ use crate::ast::InputValue;
 use crate::{
     parser::{ParseError, ParseResult, Parser, ScalarToken, SourcePosition, Spanning, Token},
     schema::{
-        meta::{InputObjectMeta, MetaType},
+        meta::{InputObjectMeta, MetaType, ScalarMeta},
         model::SchemaType,
     },
     value::ScalarValue,
@@ -31,15 +31,13 @@ where
                 item: Token::CurlyOpen,
                 ..
             },
-            None,
-        ) => parse_object_literal(parser, is_const, schema, None),
-        (
-            &Spanning {
-                item: Token::CurlyOpen,
+            None
+            | Some(&MetaType::InputObject(_))
+            | Some(&MetaType::Scalar(ScalarMeta {
+                is_structured: true,
                 ..
-            },
-            Some(&MetaType::InputObject(ref o)),
-        ) => parse_object_literal(parser, is_const, schema, Some(o)),
+            })),
+        ) => parse_object_literal(parser, is_const, schema, tpe),
         (
             &Spanning {
                 item: Token::Dollar,
@@ -139,7 +137,7 @@ fn parse_object_literal<'b, S>(
     parser: &mut Parser<'_>,
     is_const: bool,
     schema: &'b SchemaType<'b, S>,
-    object_tpe: Option<&InputObjectMeta<'b, S>>,
+    tpe: Option<&MetaType<'b, S>>,
 ) -> ParseResult<InputValue<S>>
 where
     S: ScalarValue,
@@ -147,7 +145,7 @@ where
     Ok(parser
         .delimited_list(
             &Token::CurlyOpen,
-            |p| parse_object_field(p, is_const, schema, object_tpe),
+            |p| parse_object_field(p, is_const, schema, tpe),
             &Token::CurlyClose,
         )?
         .map(|items| InputValue::parsed_object(items.into_iter().map(|s| s.item).collect())))
@@ -157,16 +155,29 @@ fn parse_object_field<'b, S>(
     parser: &mut Parser<'_>,
     is_const: bool,
     schema: &'b SchemaType<'b, S>,
-    object_meta: Option<&InputObjectMeta<'b, S>>,
+    object_tpe: Option<&MetaType<'b, S>>,
 ) -> ParseResult<(Spanning<String>, Spanning<InputValue<S>>)>
 where
     S: ScalarValue,
 {
     let key = parser.expect_name()?;
 
-    let tpe = object_meta
-        .and_then(|o| o.input_fields.iter().find(|f| f.name == key.item))
-        .and_then(|f| schema.lookup_type(&f.arg_type));
+    let tpe = match object_tpe {
+        Some(&MetaType::InputObject(InputObjectMeta {
+            ref input_fields, ..
+        })) => input_fields
+            .iter()
+            .find(|f| f.is_named(key.item))
+            .and_then(|f| schema.lookup_type(&f.arg_type)),
+        // Fields of structured scalars are structured scalars themselves.
+        Some(
+            s @ &MetaType::Scalar(ScalarMeta {
+                is_structured: true,
+                ..
+            }),
+        ) => Some(s),
+        _ => None,
+    };
 
     parser.expect(&Token::Colon)?;
 
@@ -0,0 +1,625 @@
+//! Composition of multiple schemas into a single one.
+
+use std::{collections::HashMap, fmt, marker::PhantomData};
+
+use crate::{
+    ast::OperationType,
+    executor::{ExecutionResult, Executor, Registry, ValuesStream},
+    schema::{
+        meta::{Argument, EnumValue, Field, MetaType, ObjectMeta, ResolvePolicy},
+        model::{RootNode, SchemaType},
+    },
+    types::{
+        async_await::{GraphQLTypeAsync, GraphQLValueAsync},
+        base::{Arguments, GraphQLType, GraphQLValue},
+        subscriptions::{GraphQLSubscriptionType, GraphQLSubscriptionValue},
+    },
+    value::{DefaultScalarValue, ScalarValue, Value},
+    BoxFuture, FieldError,
+};
+
+/// [`RootNode`] composed of multiple schemas via [`merge_schemas()`].
+pub type MergedRootNode<'a, CtxT, S = DefaultScalarValue> =
+    RootNode<'a, MergedRoot<'a, CtxT, S>, MergedRoot<'a, CtxT, S>, MergedRoot<'a, CtxT, S>, S>;
+
+/// Schema which can be composed with other ones via [`merge_schemas()`].
+///
+/// Implemented for every [`RootNode`] with asynchronously resolvable root
+/// types, and is not meant to be implemented manually.
+pub trait MergeableSchema<CtxT, S = DefaultScalarValue>: Sync {
+    /// Returns the [`SchemaType`] of this schema.
+    #[doc(hidden)]
+    fn schema_type(&self) -> &SchemaType<'_, S>;
+
+    /// Registers the root type of the provided `operation` in the `registry`,
+    /// returning its [`ObjectMeta`], if this schema has such root type.
+    #[doc(hidden)]
+    fn root_meta<'r>(
+        &self,
+        operation: OperationType,
+        registry: &mut Registry<'r, S>,
+    ) -> Option<ObjectMeta<'r, S>>;
+
+    /// Resolves the `field_name` of the root type of the provided `operation`.
+    #[doc(hidden)]
+    fn resolve_root_field(
+        &self,
+        operation: OperationType,
+        field_name: &str,
+        arguments: &Arguments<S>,
+        executor: &Executor<CtxT, S>,
+    ) -> ExecutionResult<S>;
+
+    /// Resolves the `field_name` of the root type of the provided `operation`
+    /// asynchronously.
+    #[doc(hidden)]
+    fn resolve_root_field_async<'b>(
+        &'b self,
+        operation: OperationType,
+        field_name: &'b str,
+        arguments: &'b Arguments<S>,
+        executor: &'b Executor<CtxT, S>,
+    ) -> BoxFuture<'b, ExecutionResult<S>>;
+
+    /// Resolves the `field_name` of the subscription root type into a stream.
+    #[doc(hidden)]
+    fn resolve_subscription_field<'s, 'ft, 'args, 'e, 'ref_e, 'res, 'f>(
+        &'s self,
+        field_name: &'ft str,
+        arguments: Arguments<'args, S>,
+        executor: &'ref_e Executor<'ref_e, 'e, CtxT, S>,
+    ) -> BoxFuture<'f, Result<Value<ValuesStream<'res, S>>, FieldError<S>>>
+    where
+        's: 'f,
+        's: 'res,
+        'ft: 'f,
+        'args: 'f,
+        'ref_e: 'f,
+        'res: 'f,
+        'e: 'res;
+}
+
+impl<'a, CtxT, S, QueryT, MutationT, SubscriptionT> MergeableSchema<CtxT, S>
+    for RootNode<'a, QueryT, MutationT, SubscriptionT, S>
+where
+    QueryT: GraphQLTypeAsync<S, Context = CtxT>,
+    QueryT::TypeInfo: Sync,
+    MutationT: GraphQLTypeAsync<S, Context = CtxT>,
+    MutationT::TypeInfo: Sync,
+    SubscriptionT: GraphQLSubscriptionType<S, Context = CtxT>,
+    SubscriptionT::TypeInfo: Sync,
+    CtxT: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    fn schema_type(&self) -> &SchemaType<'_, S> {
+        &self.schema
+    }
+
+    fn root_meta<'r>(
+        &self,
+        operation: OperationType,
+        registry: &mut Registry<'r, S>,
+    ) -> Option<ObjectMeta<'r, S>> {
+        let meta = match operation {
+            OperationType::Query => QueryT::meta(&self.query_info, registry),
+            OperationType::Mutation => {
+                self.schema.mutation_type_name.as_ref()?;
+                MutationT::meta(&self.mutation_info, registry)
+            }
+            OperationType::Subscription => {
+                self.schema.subscription_type_name.as_ref()?;
+                SubscriptionT::meta(&self.subscription_info, registry)
+            }
+        };
+        match meta {
+            MetaType::Object(meta) => Some(meta),
+            _ => None,
+        }
+    }
+
+    fn resolve_root_field(
+        &self,
+        operation: OperationType,
+        field_name: &str,
+        arguments: &Arguments<S>,
+        executor: &Executor<CtxT, S>,
+    ) -> ExecutionResult<S> {
+        match operation {
+            OperationType::Query => {
+                self.query_type
+                    .resolve_field(&self.query_info, field_name, arguments, executor)
+            }
+            OperationType::Mutation => self.mutation_type.resolve_field(
+                &self.mutation_info,
+                field_name,
+                arguments,
+                executor,
+            ),
+            OperationType::Subscription => self.subscription_type.resolve_field(
+                &self.subscription_info,
+                field_name,
+                arguments,
+                executor,
+            ),
+        }
+    }
+
+    fn resolve_root_field_async<'b>(
+        &'b self,
+        operation: OperationType,
+        field_name: &'b str,
+        arguments: &'b Arguments<S>,
+        executor: &'b Executor<CtxT, S>,
+    ) -> BoxFuture<'b, ExecutionResult<S>> {
+        match operation {
+            OperationType::Query => self.query_type.resolve_field_async(
+                &self.query_info,
+                field_name,
+                arguments,
+                executor,
+            ),
+            OperationType::Mutation => self.mutation_type.resolve_field_async(
+                &self.mutation_info,
+                field_name,
+                arguments,
+                executor,
+            ),
+            OperationType::Subscription => {
+                let res = self.subscription_type.resolve_field(
+                    &self.subscription_info,
+                    field_name,
+                    arguments,
+                    executor,
+                );
+                Box::pin(futures::future::ready(res))
+            }
+        }
+    }
+
+    fn resolve_subscription_field<'s, 'ft, 'args, 'e, 'ref_e, 'res, 'f>(
+        &'s self,
+        field_name: &'ft str,
+        arguments: Arguments<'args, S>,
+        executor: &'ref_e Executor<'ref_e, 'e, CtxT, S>,
+    ) -> BoxFuture<'f, Result<Value<ValuesStream<'res, S>>, FieldError<S>>>
+    where
+        's: 'f,
+        's: 'res,
+        'ft: 'f,
+        'args: 'f,
+        'ref_e: 'f,
+        'res: 'f,
+        'e: 'res,
+    {
+        self.subscription_type.resolve_field_into_stream(
+            &self.subscription_info,
+            field_name,
+            arguments,
+            executor,
+        )
+    }
+}
+
+/// Root type of a schema composed via [`merge_schemas()`].
+///
+/// Resolves every field by delegating it to the root type of the schema
+/// defining it.
+pub struct MergedRoot<'a, CtxT, S = DefaultScalarValue>(
+    PhantomData<&'a ()>,
+    PhantomData<fn() -> (CtxT, S)>,
+);
+
+impl<'a, CtxT, S> MergedRoot<'a, CtxT, S> {
+    fn new() -> Self {
+        Self(PhantomData, PhantomData)
+    }
+}
+
+/// Type info of a [`MergedRoot`], holding the composed schemas.
+pub struct MergedRootInfo<'a, CtxT, S = DefaultScalarValue> {
+    /// Name of the [`MergedRoot`] type.
+    name: &'static str,
+
+    /// Type of the operations the [`MergedRoot`] type is a root of.
+    operation: OperationType,
+
+    /// Composed schemas, in the order they were provided.
+    schemas: Vec<&'a dyn MergeableSchema<CtxT, S>>,
+
+    /// Indices of the `schemas` defining the fields of the [`MergedRoot`] type.
+    fields: HashMap<String, usize>,
+}
+
+impl<'a, CtxT: 'a, S> GraphQLType<S> for MergedRoot<'a, CtxT, S>
+where
+    S: ScalarValue,
+{
+    fn name(info: &Self::TypeInfo) -> Option<&str> {
+        Some(info.name)
+    }
+
+    fn meta<'r>(info: &Self::TypeInfo, registry: &mut Registry<'r, S>) -> MetaType<'r, S>
+    where
+        S: 'r,
+    {
+        let mut fields = vec![];
+        let mut interface_names = vec![];
+        let mut resolve_policy = ResolvePolicy::Concurrent;
+        for schema in &info.schemas {
+            if let Some(meta) = schema.root_meta(info.operation, registry) {
+                fields.extend(meta.fields.into_iter().filter(|f| !f.is_builtin()));
+                for name in meta.interface_names {
+                    if !interface_names.contains(&name) {
+                        interface_names.push(name);
+                    }
+                }
+                if meta.resolve_policy == ResolvePolicy::Serial {
+                    resolve_policy = ResolvePolicy::Serial;
+                }
+            }
+        }
+
+        let mut meta = registry
+            .build_object_type::<Self>(info, &fields)
+            .resolve_policy(resolve_policy);
+        meta.interface_names = interface_names;
+        meta.into_meta()
+    }
+}
+
+impl<'a, CtxT: 'a, S> GraphQLValue<S> for MergedRoot<'a, CtxT, S>
+where
+    S: ScalarValue,
+{
+    type Context = CtxT;
+    type TypeInfo = MergedRootInfo<'a, CtxT, S>;
+
+    fn type_name<'i>(&self, info: &'i Self::TypeInfo) -> Option<&'i str> {
+        Some(info.name)
+    }
+
+    fn concrete_type_name(&self, _: &Self::Context, info: &Self::TypeInfo) -> String {
+        info.name.into()
+    }
+
+    fn resolve_field(
+        &self,
+        info: &Self::TypeInfo,
+        field_name: &str,
+        arguments: &Arguments<S>,
+        executor: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        match info.fields.get(field_name) {
+            Some(&idx) => info.schemas[idx].resolve_root_field(
+                info.operation,
+                field_name,
+                arguments,
+                executor,
+            ),
+            None => Err(unknown_field_error(field_name, info.name)),
+        }
+    }
+}
+
+impl<'a, CtxT, S> GraphQLValueAsync<S> for MergedRoot<'a, CtxT, S>
+where
+    CtxT: Sync + 'a,
+    S: ScalarValue + Send + Sync,
+{
+    fn resolve_field_async<'b>(
+        &'b self,
+        info: &'b Self::TypeInfo,
+        field_name: &'b str,
+        arguments: &'b Arguments<S>,
+        executor: &'b Executor<Self::Context, S>,
+    ) -> BoxFuture<'b, ExecutionResult<S>> {
+        match info.fields.get(field_name) {
+            Some(&idx) => info.schemas[idx].resolve_root_field_async(
+                info.operation,
+                field_name,
+                arguments,
+                executor,
+            ),
+            None => Box::pin(futures::future::err(unknown_field_error(
+                field_name, info.name,
+            ))),
+        }
+    }
+}
+
+impl<'a, CtxT, S> GraphQLSubscriptionValue<S> for MergedRoot<'a, CtxT, S>
+where
+    CtxT: Sync + 'a,
+    S: ScalarValue + Send + Sync,
+{
+    fn resolve_field_into_stream<'s, 'i, 'ft, 'args, 'e, 'ref_e, 'res, 'f>(
+        &'s self,
+        info: &'i Self::TypeInfo,
+        field_name: &'ft str,
+        arguments: Arguments<'args, S>,
+        executor: &'ref_e Executor<'ref_e, 'e, Self::Context, S>,
+    ) -> BoxFuture<'f, Result<Value<ValuesStream<'res, S>>, FieldError<S>>>
+    where
+        's: 'f,
+        'i: 'res,
+        'ft: 'f,
+        'args: 'f,
+        'ref_e: 'f,
+        'res: 'f,
+        'e: 'res,
+    {
+        match info.fields.get(field_name) {
+            Some(&idx) => {
+                let schema: &'i dyn MergeableSchema<CtxT, S> = info.schemas[idx];
+                schema.resolve_subscription_field(field_name, arguments, executor)
+            }
+            None => Box::pin(futures::future::err(unknown_field_error(
+                field_name, info.name,
+            ))),
+        }
+    }
+}
+
+/// Creates a [`FieldError`] about the absent `field_name` of the `type_name`.
+fn unknown_field_error<S>(field_name: &str, type_name: &str) -> FieldError<S> {
+    FieldError::from(format!(
+        "Field `{field_name}` not found on type `{type_name}`",
+    ))
+}
+
+/// Error of composing schemas via [`merge_schemas()`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MergeError {
+    /// No schemas were provided.
+    NoSchemas,
+
+    /// Field of a root type is defined by multiple schemas.
+    ConflictingField {
+        /// Name of the root type in the composed schema.
+        type_name: String,
+
+        /// Name of the conflicting field.
+        field_name: String,
+    },
+
+    /// Type with the same name is defined differently by multiple schemas.
+    ConflictingType(String),
+}
+
+impl fmt::Display for MergeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoSchemas => write!(f, "No schemas provided to merge"),
+            Self::ConflictingField {
+                type_name,
+                field_name,
+            } => write!(
+                f,
+                "Field `{field_name}` of type `{type_name}` is defined by multiple schemas",
+            ),
+            Self::ConflictingType(name) => write!(
+                f,
+                "Type `{name}` is defined differently by multiple schemas",
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MergeError {}
+
+/// Composes the provided `schemas` into a single executable and introspectable
+/// one.
+///
+/// Root types of the composed schema (named `Query`, `Mutation` and
+/// `Subscription`) contain the fields of the respective root types of all the
+/// `schemas`, and resolve them via the root objects of the schema defining
+/// them, so all the `schemas` must share the same context type. Other types
+/// with the same name are de-duplicated, if they are defined identically.
+///
+/// Only the types are composed: descriptions, custom directives, validation
+/// rules and limits of the `schemas` aren't carried over to the composed one.
+///
+/// # Errors
+///
+/// - [`MergeError::NoSchemas`] if no `schemas` are provided.
+/// - [`MergeError::ConflictingField`] if the same root field is defined by
+///   multiple `schemas`.
+/// - [`MergeError::ConflictingType`] if types with the same name are defined
+///   differently by multiple `schemas`, or some of them collide with a name of
+///   the composed root types.
+///
+/// # Example
+///
+/// ```rust
+/// # use juniper::{
+/// #     graphql_object, graphql_value, graphql_vars, merge_schemas, EmptyMutation,
+/// #     EmptySubscription, RootNode,
+/// # };
+/// #
+/// struct UsersQuery;
+///
+/// #[graphql_object]
+/// impl UsersQuery {
+///     fn user_name() -> &'static str {
+///         "Alice"
+///     }
+/// }
+///
+/// struct ProductsQuery;
+///
+/// #[graphql_object]
+/// impl ProductsQuery {
+///     fn product_name() -> &'static str {
+///         "Ball"
+///     }
+/// }
+///
+/// let users = RootNode::new(UsersQuery, EmptyMutation::new(), EmptySubscription::new());
+/// let products = RootNode::new(ProductsQuery, EmptyMutation::new(), EmptySubscription::new());
+///
+/// let schema = merge_schemas(&[&users, &products]).unwrap();
+///
+/// assert_eq!(
+///     juniper::execute_sync("{ userName productName }", None, &schema, &graphql_vars! {}, &()),
+///     Ok((graphql_value!({"userName": "Alice", "productName": "Ball"}), vec![])),
+/// );
+/// ```
+pub fn merge_schemas<'a, CtxT, S>(
+    schemas: &[&'a dyn MergeableSchema<CtxT, S>],
+) -> Result<MergedRootNode<'a, CtxT, S>, MergeError>
+where
+    S: ScalarValue,
+{
+    if schemas.is_empty() {
+        return Err(MergeError::NoSchemas);
+    }
+
+    let roots = [
+        (OperationType::Query, "Query"),
+        (OperationType::Mutation, "Mutation"),
+        (OperationType::Subscription, "Subscription"),
+    ];
+    let mut root_fields = [HashMap::new(), HashMap::new(), HashMap::new()];
+    let mut types = HashMap::<&str, &MetaType<S>>::new();
+    for (idx, schema) in schemas.iter().enumerate() {
+        let schema = schema.schema_type();
+        let root_types = [
+            Some(schema.concrete_query_type()),
+            schema.concrete_mutation_type(),
+            schema.concrete_subscription_type(),
+        ];
+
+        for ((root, fields), (_, type_name)) in root_types.iter().zip(&mut root_fields).zip(roots) {
+            let root_fields = match root {
+                Some(MetaType::Object(ObjectMeta { fields, .. })) => fields.as_slice(),
+                _ => &[],
+            };
+            for field in root_fields.iter().filter(|f| !f.is_builtin()) {
+                let field_name = field.name.to_string();
+                if fields.insert(field_name.clone(), idx).is_some() {
+                    return Err(MergeError::ConflictingField {
+                        type_name: type_name.into(),
+                        field_name,
+                    });
+                }
+            }
+        }
+
+        for meta in schema.types.values() {
+            let name = match meta.name() {
+                Some(name) => name,
+                None => continue,
+            };
+            let is_root = root_types.iter().flatten().any(|r| r.name() == Some(name));
+            if is_root || name.starts_with("__") || name.starts_with("_Empty") {
+                continue;
+            }
+            if roots.iter().any(|(_, root_name)| name == *root_name) {
+                return Err(MergeError::ConflictingType(name.to_string()));
+            }
+            if let Some(existing) = types.insert(name, meta) {
+                if !same_types(existing, meta) {
+                    return Err(MergeError::ConflictingType(name.to_string()));
+                }
+            }
+        }
+    }
+
+    let [query_fields, mutation_fields, subscription_fields] = root_fields;
+    let info = |(operation, name), empty_name, fields: HashMap<_, _>| MergedRootInfo {
+        name: if fields.is_empty() { empty_name } else { name },
+        operation,
+        schemas: schemas.to_vec(),
+        fields,
+    };
+    Ok(RootNode::new_with_info(
+        MergedRoot::new(),
+        MergedRoot::new(),
+        MergedRoot::new(),
+        info(roots[0], roots[0].1, query_fields),
+        info(roots[1], "_EmptyMutation", mutation_fields),
+        info(roots[2], "_EmptySubscription", subscription_fields),
+    ))
+}
+
+/// Checks whether the provided [`MetaType`]s are defined identically.
+fn same_types<S: ScalarValue>(a: &MetaType<'_, S>, b: &MetaType<'_, S>) -> bool {
+    match (a, b) {
+        (MetaType::Scalar(a), MetaType::Scalar(b)) => {
+            a.name == b.name
+                && a.description == b.description
+                && a.specified_by_url == b.specified_by_url
+        }
+        (MetaType::List(a), MetaType::List(b)) => {
+            a.of_type == b.of_type && a.expected_size == b.expected_size
+        }
+        (MetaType::Nullable(a), MetaType::Nullable(b)) => a.of_type == b.of_type,
+        (MetaType::Object(a), MetaType::Object(b)) => {
+            a.name == b.name
+                && a.description == b.description
+                && same_fields(&a.fields, &b.fields)
+                && a.interface_names == b.interface_names
+        }
+        (MetaType::Enum(a), MetaType::Enum(b)) => {
+            a.name == b.name
+                && a.description == b.description
+                && same_items(&a.values, &b.values, same_enum_values)
+        }
+        (MetaType::Interface(a), MetaType::Interface(b)) => {
+            a.name == b.name
+                && a.description == b.description
+                && same_fields(&a.fields, &b.fields)
+                && a.interface_names == b.interface_names
+        }
+        (MetaType::Union(a), MetaType::Union(b)) => {
+            a.name == b.name
+                && a.description == b.description
+                && same_items(&a.of_type_names, &b.of_type_names, PartialEq::eq)
+        }
+        (MetaType::InputObject(a), MetaType::InputObject(b)) => {
+            a.name == b.name
+                && a.description == b.description
+                && same_items(&a.input_fields, &b.input_fields, same_arguments)
+                && a.is_one_of == b.is_one_of
+        }
+        _ => false,
+    }
+}
+
+/// Checks whether the provided [`Field`]s are defined identically, regardless
+/// of their order.
+fn same_fields<S: ScalarValue>(a: &[Field<'_, S>], b: &[Field<'_, S>]) -> bool {
+    same_items(a, b, |a, b| {
+        a.name == b.name
+            && a.description == b.description
+            && a.field_type == b.field_type
+            && a.deprecation_status == b.deprecation_status
+            && match (&a.arguments, &b.arguments) {
+                (Some(a), Some(b)) => same_items(a, b, same_arguments),
+                (a, b) => a.iter().chain(b).all(Vec::is_empty),
+            }
+    })
+}
+
+/// Checks whether the provided [`Argument`]s are defined identically.
+fn same_arguments<S: ScalarValue>(a: &Argument<'_, S>, b: &Argument<'_, S>) -> bool {
+    a.name == b.name
+        && a.description == b.description
+        && a.arg_type == b.arg_type
+        && a.default_value == b.default_value
+        && a.deprecation_status == b.deprecation_status
+        && a.aliases == b.aliases
+}
+
+/// Checks whether the provided [`EnumValue`]s are defined identically.
+fn same_enum_values(a: &EnumValue, b: &EnumValue) -> bool {
+    a.name == b.name
+        && a.description == b.description
+        && a.deprecation_status == b.deprecation_status
+}
+
+/// Checks whether every item of `a` has an identical one in `b` (and vice
+/// versa), regardless of their order.
+fn same_items<T>(a: &[T], b: &[T], same: impl Fn(&T, &T) -> bool) -> bool {
+    a.len() == b.len() && a.iter().all(|a| b.iter().any(|b| same(a, b)))
+}
@@ -10,7 +10,7 @@ use crate::{
     ast::{FromInputValue, InputValue, Type},
     parser::{ParseError, ScalarToken},
     schema::model::SchemaType,
-    types::base::TypeKind,
+    types::base::{Arguments, TypeKind},
     value::{DefaultScalarValue, ParseScalarValue},
     FieldError,
 };
@@ -52,6 +52,7 @@ pub struct ScalarMeta<'a, S> {
     pub specified_by_url: Option<Cow<'a, str>>,
     pub(crate) try_parse_fn: InputValueParseFn<S>,
     pub(crate) parse_fn: ScalarTokenParseFn<S>,
+    pub(crate) is_structured: bool,
 }
 
 /// Shortcut for an [`InputValue`] parsing function.
@@ -88,6 +89,31 @@ pub struct ObjectMeta<'a, S> {
     pub fields: Vec<Field<'a, S>>,
     #[doc(hidden)]
     pub interface_names: Vec<String>,
+    #[doc(hidden)]
+    pub resolve_policy: ResolvePolicy,
+}
+
+/// Policy of resolving sibling fields of an [`ObjectMeta`] type.
+///
+/// Affects asynchronous execution only, as synchronous execution always
+/// resolves fields one-by-one.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ResolvePolicy {
+    /// Sibling fields are resolved concurrently.
+    ///
+    /// This is the default, allowing independent fields (and all their
+    /// sub-selections) to make progress while awaiting each other.
+    #[default]
+    Concurrent,
+
+    /// Sibling fields are resolved serially, each one being awaited before
+    /// starting to resolve the next one, in the order they're selected.
+    ///
+    /// Intended for resolvers mutating shared state, and being sensitive to the
+    /// order they're run in. Slows down resolving such an object, as awaiting
+    /// its fields (and their sub-selections) doesn't overlap. Doesn't affect
+    /// the resolving of fields of the nested objects.
+    Serial,
 }
 
 /// Enum type metadata
@@ -133,6 +159,8 @@ pub struct InputObjectMeta<'a, S> {
     pub description: Option<String>,
     #[doc(hidden)]
     pub input_fields: Vec<Argument<'a, S>>,
+    #[doc(hidden)]
+    pub is_one_of: bool,
     pub(crate) try_parse_fn: InputValueParseFn<S>,
 }
 
@@ -182,6 +210,8 @@ pub struct Field<'a, S> {
     pub field_type: Type<'a>,
     #[doc(hidden)]
     pub deprecation_status: DeprecationStatus,
+    #[doc(hidden)]
+    pub complexity: Complexity<S>,
 }
 
 impl<'a, S> Field<'a, S> {
@@ -192,6 +222,59 @@ impl<'a, S> Field<'a, S> {
     }
 }
 
+/// Complexity (cost) of resolving a [`Field`], used by query cost analysis.
+///
+/// See [`RootNode::estimate_cost()`] for details.
+///
+/// [`RootNode::estimate_cost()`]: crate::RootNode::estimate_cost
+pub enum Complexity<S> {
+    /// Fixed weight of the [`Field`] itself, added to the cost of its
+    /// sub-selection.
+    Fixed(u64),
+
+    /// Function computing the total cost of the [`Field`] from its
+    /// [`Arguments`] and the cost of its sub-selection.
+    ///
+    /// Allows to multiply the sub-selection cost by the requested number of
+    /// items for list (connection-style) fields, for example.
+    With(fn(&Arguments<'_, S>, u64) -> u64),
+}
+
+impl<S> Complexity<S> {
+    /// Computes the total cost of a [`Field`] having this [`Complexity`] with
+    /// the provided [`Arguments`] and the `child_cost` of its sub-selection.
+    pub fn cost(&self, args: &Arguments<'_, S>, child_cost: u64) -> u64 {
+        match self {
+            Self::Fixed(weight) => weight.saturating_add(child_cost),
+            Self::With(f) => f(args, child_cost),
+        }
+    }
+}
+
+impl<S> Default for Complexity<S> {
+    fn default() -> Self {
+        Self::Fixed(1)
+    }
+}
+
+impl<S> Clone for Complexity<S> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Fixed(weight) => Self::Fixed(*weight),
+            Self::With(f) => Self::With(*f),
+        }
+    }
+}
+
+impl<S> fmt::Debug for Complexity<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Fixed(weight) => f.debug_tuple("Fixed").field(weight).finish(),
+            Self::With(_) => f.debug_tuple("With").finish_non_exhaustive(),
+        }
+    }
+}
+
 /// Metadata for an argument to a field
 #[derive(Debug, Clone)]
 pub struct Argument<'a, S> {
@@ -203,6 +286,10 @@ pub struct Argument<'a, S> {
     pub arg_type: Type<'a>,
     #[doc(hidden)]
     pub default_value: Option<InputValue<S>>,
+    #[doc(hidden)]
+    pub deprecation_status: DeprecationStatus,
+    #[doc(hidden)]
+    pub aliases: Vec<String>,
 }
 
 impl<'a, S> Argument<'a, S> {
@@ -211,6 +298,18 @@ impl<'a, S> Argument<'a, S> {
         // "used exclusively by GraphQL’s introspection system"
         self.name.starts_with("__")
     }
+
+    /// Indicates whether this [`Argument`] accepts the given `name`, being
+    /// either its own name or one of its [aliases][`Argument::alias()`].
+    pub fn is_named(&self, name: &str) -> bool {
+        self.name == name || self.aliases.iter().any(|a| a == name)
+    }
+
+    /// Returns the names this [`Argument`] accepts: its own name, followed by
+    /// its [aliases][`Argument::alias()`].
+    pub fn accepted_names(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.name.as_str()).chain(self.aliases.iter().map(String::as_str))
+    }
 }
 
 /// Metadata for a single value in an enum
@@ -306,14 +405,14 @@ impl<'a, S> MetaType<'a, S> {
         }
     }
 
-    /// Access an input field's meta data given its name
+    /// Access an input field's meta data given its name, or one of its aliases
     ///
     /// Only input objects have input fields. This method always returns `None` for other types.
     pub fn input_field_by_name(&self, name: &str) -> Option<&Argument<S>> {
         match *self {
             MetaType::InputObject(InputObjectMeta {
                 ref input_fields, ..
-            }) => input_fields.iter().find(|f| f.name == name),
+            }) => input_fields.iter().find(|f| f.is_named(name)),
             _ => None,
         }
     }
@@ -447,6 +546,7 @@ impl<'a, S> ScalarMeta<'a, S> {
             specified_by_url: None,
             try_parse_fn: try_parse_fn::<S, T>,
             parse_fn: <T as ParseScalarValue<S>>::from_str,
+            is_structured: false,
         }
     }
 
@@ -470,6 +570,18 @@ impl<'a, S> ScalarMeta<'a, S> {
         self
     }
 
+    /// Makes this [`ScalarMeta`] type accept input lists and objects (in both
+    /// literals and variables), passing them to its [`FromInputValue`]
+    /// implementation as is, instead of rejecting them.
+    ///
+    /// Breaks the type safety guaranteed by GraphQL, so should be used
+    /// sparingly (for arbitrary JSON values, for example).
+    #[must_use]
+    pub fn structured(mut self) -> Self {
+        self.is_structured = true;
+        self
+    }
+
     /// Wraps this [`ScalarMeta`] type into a generic [`MetaType`].
     pub fn into_meta(self) -> MetaType<'a, S> {
         MetaType::Scalar(self)
@@ -517,6 +629,7 @@ impl<'a, S> ObjectMeta<'a, S> {
             description: None,
             fields: fields.to_vec(),
             interface_names: vec![],
+            resolve_policy: ResolvePolicy::default(),
         }
     }
 
@@ -541,6 +654,15 @@ impl<'a, S> ObjectMeta<'a, S> {
         self
     }
 
+    /// Sets the [`ResolvePolicy`] of the fields of this [`ObjectMeta`] type.
+    ///
+    /// Overwrites any previously set policy.
+    #[must_use]
+    pub fn resolve_policy(mut self, policy: ResolvePolicy) -> Self {
+        self.resolve_policy = policy;
+        self
+    }
+
     /// Wraps this [`ObjectMeta`] type into a generic [`MetaType`].
     pub fn into_meta(self) -> MetaType<'a, S> {
         MetaType::Object(self)
@@ -659,10 +781,21 @@ impl<'a, S> InputObjectMeta<'a, S> {
             name,
             description: None,
             input_fields: input_fields.to_vec(),
+            is_one_of: false,
             try_parse_fn: try_parse_fn::<S, T>,
         }
     }
 
+    /// Marks this [`InputObjectMeta`] type as a [`@oneOf`][0] one, requiring
+    /// exactly one of its fields to be provided with a non-null value.
+    ///
+    /// [0]: https://spec.graphql.org/draft#sec-OneOf-Input-Objects
+    #[must_use]
+    pub fn one_of(mut self) -> Self {
+        self.is_one_of = true;
+        self
+    }
+
     /// Set the `description` of this [`InputObjectMeta`] type.
     ///
     /// Overwrites any previously set description.
@@ -712,6 +845,27 @@ impl<'a, S> Field<'a, S> {
         self.deprecation_status = DeprecationStatus::Deprecated(reason.map(Into::into));
         self
     }
+
+    /// Sets the fixed `weight` of this [`Field`] for query cost analysis.
+    ///
+    /// The `weight` is added to the cost of this [`Field`]'s sub-selection.
+    /// Overwrites any previously set complexity.
+    #[must_use]
+    pub fn complexity(mut self, weight: u64) -> Self {
+        self.complexity = Complexity::Fixed(weight);
+        self
+    }
+
+    /// Sets the function computing the total cost of this [`Field`] from its
+    /// [`Arguments`] and the cost of its sub-selection for query cost
+    /// analysis.
+    ///
+    /// Overwrites any previously set complexity.
+    #[must_use]
+    pub fn complexity_with(mut self, f: fn(&Arguments<'_, S>, u64) -> u64) -> Self {
+        self.complexity = Complexity::With(f);
+        self
+    }
 }
 
 impl<'a, S> Argument<'a, S> {
@@ -722,6 +876,8 @@ impl<'a, S> Argument<'a, S> {
             description: None,
             arg_type,
             default_value: None,
+            deprecation_status: DeprecationStatus::Current,
+            aliases: Vec::new(),
         }
     }
 
@@ -742,6 +898,30 @@ impl<'a, S> Argument<'a, S> {
         self.default_value = Some(val);
         self
     }
+
+    /// Sets this [`Argument`] as deprecated with an optional `reason`.
+    ///
+    /// Overwrites any previously set deprecation reason.
+    #[must_use]
+    pub fn deprecated(mut self, reason: Option<&str>) -> Self {
+        self.deprecation_status = DeprecationStatus::Deprecated(reason.map(Into::into));
+        self
+    }
+
+    /// Adds an `alias` this [`Argument`] is accepted under as an
+    /// [input object][1] field, besides its own name.
+    ///
+    /// Aliases allow renaming an [input object][1] field without breaking the
+    /// clients still providing it under its old name. They're not exposed via
+    /// introspection, and providing both the field and its alias at once is
+    /// an error.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Input-Objects
+    #[must_use]
+    pub fn alias(mut self, alias: &str) -> Self {
+        self.aliases.push(alias.into());
+        self
+    }
 }
 
 impl EnumValue {
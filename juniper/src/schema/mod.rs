@@ -1,6 +1,8 @@
 #![allow(clippy::module_inception)]
 
+pub mod merge;
 pub mod meta;
 pub mod model;
 pub mod schema;
 pub mod translate;
+pub mod validation;
@@ -1,16 +1,31 @@
-use std::{borrow::Cow, fmt};
+use std::{borrow::Cow, collections::HashSet, fmt, mem};
 
 use fnv::FnvHashMap;
 #[cfg(feature = "graphql-parser")]
 use graphql_parser::schema::Document;
 
 use crate::{
-    ast::Type,
-    executor::{Context, Registry},
-    schema::meta::{Argument, InterfaceMeta, MetaType, ObjectMeta, PlaceholderMeta, UnionMeta},
+    ast::{self, OperationType, Type},
+    executor::{
+        get_operation, resolve_introspection, resolve_introspection_query, Context,
+        FieldMiddleware, PreExecution, Registry, Variables,
+    },
+    introspection::{IntrospectionFormat, INTROSPECTION_QUERY},
+    parser::{parse_document_source, ParseOptions, Spanning},
+    schema::{
+        meta::{
+            Argument, InputObjectMeta, InterfaceMeta, MetaType, ObjectMeta, PlaceholderMeta,
+            UnionMeta,
+        },
+        validation::SchemaValidationError,
+    },
     types::{base::GraphQLType, name::Name},
-    value::{DefaultScalarValue, ScalarValue},
-    GraphQLEnum,
+    validation::{
+        coerce_variable_values, document_cost, operation_fingerprint, visit_all_rules, CostError,
+        RuleError, ValidationRule, ValidatorContext,
+    },
+    value::{DefaultScalarValue, ScalarValue, Value},
+    GraphQLEnum, GraphQLError,
 };
 
 #[cfg(feature = "graphql-parser")]
@@ -44,6 +59,8 @@ pub struct RootNode<
     pub subscription_info: SubscriptionT::TypeInfo,
     #[doc(hidden)]
     pub schema: SchemaType<'a, S>,
+    #[doc(hidden)]
+    pub pre_executions: Vec<Box<dyn PreExecution<QueryT::Context, S> + 'a>>,
 }
 
 /// Metadata for a schema
@@ -54,7 +71,16 @@ pub struct SchemaType<'a, S> {
     pub(crate) query_type_name: String,
     pub(crate) mutation_type_name: Option<String>,
     pub(crate) subscription_type_name: Option<String>,
+    /// Names of the renamed types in this schema, keyed by their
+    /// [`GraphQLType::name()`]s.
+    type_renames: FnvHashMap<String, String>,
     directives: FnvHashMap<String, DirectiveType<'a, S>>,
+    pub(crate) introspection_depth_limit: Option<usize>,
+    pub(crate) introspection_disabled: bool,
+    pub(crate) max_cost: Option<u64>,
+    pub(crate) parse_options: ParseOptions,
+    pub(crate) validation_rules: Vec<Box<dyn ValidationRule<S> + 'a>>,
+    pub(crate) field_middlewares: Vec<Box<dyn FieldMiddleware<S> + 'a>>,
 }
 
 impl<'a, S> Context for SchemaType<'a, S> {}
@@ -66,23 +92,96 @@ pub enum TypeType<'a, S: 'a> {
     List(Box<TypeType<'a, S>>, Option<usize>),
 }
 
+/// Error of renaming a root type via [`RootNode::with_root_type_name()`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RootTypeRenameError {
+    /// Provided name is not a valid GraphQL name.
+    InvalidName(String),
+
+    /// Type with the provided name already exists in the schema.
+    ExistingType(String),
+}
+
+impl fmt::Display for RootTypeRenameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidName(name) => {
+                write!(f, "Root type name `{name}` is not a valid GraphQL name")
+            }
+            Self::ExistingType(name) => write!(f, "Type `{name}` already exists in the schema"),
+        }
+    }
+}
+
+impl std::error::Error for RootTypeRenameError {}
+
+/// Error of registering a custom directive via [`RootNode::with_directive()`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DirectiveRegisterError {
+    /// Argument of the directive has a type not present in the schema.
+    UnknownArgumentType {
+        /// Name of the directive (without the leading `@`).
+        directive: String,
+        /// Name of the argument.
+        argument: String,
+        /// Type of the argument.
+        arg_type: String,
+    },
+}
+
+impl fmt::Display for DirectiveRegisterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownArgumentType {
+                directive,
+                argument,
+                arg_type,
+            } => write!(
+                f,
+                "Unknown type `{arg_type}` of `{argument}` argument of `@{directive}` directive",
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DirectiveRegisterError {}
+
+/// Definition of a [GraphQL directive][0] in a schema.
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
 #[derive(Debug)]
 pub struct DirectiveType<'a, S> {
+    /// Name of this directive (without the leading `@`).
     pub name: String,
+    /// Description of this directive.
     pub description: Option<String>,
+    /// [`DirectiveLocation`]s this directive is allowed to be used at.
     pub locations: Vec<DirectiveLocation>,
+    /// Arguments accepted by this directive.
     pub arguments: Vec<Argument<'a, S>>,
+    /// Indicator whether this directive may be used multiple times at the same
+    /// location.
     pub is_repeatable: bool,
 }
 
 #[derive(Clone, PartialEq, Eq, Debug, GraphQLEnum)]
 #[graphql(name = "__DirectiveLocation", internal)]
+#[allow(missing_docs)]
 pub enum DirectiveLocation {
     Query,
     Mutation,
     Subscription,
     Field,
+    Schema,
     Scalar,
+    Object,
+    #[graphql(name = "ARGUMENT_DEFINITION")]
+    ArgumentDefinition,
+    Interface,
+    Union,
+    Enum,
+    #[graphql(name = "INPUT_OBJECT")]
+    InputObject,
     #[graphql(name = "FRAGMENT_DEFINITION")]
     FragmentDefinition,
     #[graphql(name = "FIELD_DEFINITION")]
@@ -95,6 +194,8 @@ pub enum DirectiveLocation {
     InlineFragment,
     #[graphql(name = "ENUM_VALUE")]
     EnumValue,
+    #[graphql(name = "INPUT_FIELD_DEFINITION")]
+    InputFieldDefinition,
 }
 
 impl<'a, QueryT, MutationT, SubscriptionT>
@@ -159,7 +260,635 @@ where
             query_info,
             mutation_info,
             subscription_info,
+            pre_executions: Vec::new(),
+        }
+    }
+
+    /// Limits the nesting depth of introspection queries (the ones selecting
+    /// `__schema` or `__type` fields) to the provided `limit`.
+    ///
+    /// Introspection fields nested deeper than the `limit` (counting the
+    /// `__schema`/`__type` field itself as `1`) are rejected with a validation
+    /// error before executing the query. Depth of non-introspection queries is
+    /// not affected by this setting.
+    ///
+    /// Note, that the query used by [`introspect()`] is subject to this limit
+    /// as well, so it should be set high enough if the whole schema is still
+    /// meant to be introspected. [`RootNode::introspect()`] is not affected by
+    /// this limit, as it doesn't validate the query.
+    ///
+    /// By default, depth of introspection queries is unlimited.
+    ///
+    /// [`introspect()`]: crate::introspect
+    #[must_use]
+    pub fn with_introspection_depth_limit(mut self, limit: usize) -> Self {
+        self.schema.introspection_depth_limit = Some(limit);
+        self
+    }
+
+    /// Disables introspection for this schema, so any query selecting
+    /// `__schema` or `__type` fields is rejected with a validation error before
+    /// executing it. `__typename` fields are still allowed.
+    ///
+    /// Note, that the query used by [`introspect()`] is rejected as well, while
+    /// [`RootNode::introspect()`] is not affected, as it doesn't validate the
+    /// query.
+    ///
+    /// Only flips a flag checked during validation, so doesn't rebuild the
+    /// schema, and may be reverted with [`RootNode::enable_introspection()`].
+    ///
+    /// [`introspect()`]: crate::introspect
+    pub fn disable_introspection(&mut self) {
+        self.schema.introspection_disabled = true;
+    }
+
+    /// Enables introspection for this schema back, after it was disabled with
+    /// [`RootNode::disable_introspection()`].
+    ///
+    /// Introspection is enabled by default.
+    pub fn enable_introspection(&mut self) {
+        self.schema.introspection_disabled = false;
+    }
+
+    /// Disables introspection for this schema in a builder manner.
+    ///
+    /// See [`RootNode::disable_introspection()`] for details.
+    #[must_use]
+    pub fn with_introspection_disabled(mut self) -> Self {
+        self.disable_introspection();
+        self
+    }
+
+    /// Indicates whether introspection is disabled for this schema.
+    ///
+    /// See [`RootNode::disable_introspection()`] for details.
+    pub fn is_introspection_disabled(&self) -> bool {
+        self.schema.introspection_disabled
+    }
+
+    /// Limits the estimated cost of executed operations to the provided
+    /// `limit`.
+    ///
+    /// Operations costing more than the `limit` (as estimated by the
+    /// [`RootNode::estimate_cost()`]) are rejected with a validation error
+    /// before executing them.
+    ///
+    /// By default, cost of operations is unlimited.
+    #[must_use]
+    pub fn with_max_cost(mut self, limit: u64) -> Self {
+        self.schema.max_cost = Some(limit);
+        self
+    }
+
+    /// Applies the provided [`ParseOptions`] when parsing queries executed
+    /// against this schema, so too large or too deeply nested queries are
+    /// rejected with a [`GraphQLError::ParseError`] before even building their
+    /// AST.
+    ///
+    /// ```
+    /// # use juniper::{
+    /// #     graphql_object, graphql_vars, parser::{ParseError, ParseOptions},
+    /// #     EmptyMutation, EmptySubscription, GraphQLError, RootNode,
+    /// # };
+    /// #
+    /// struct Query;
+    ///
+    /// #[graphql_object]
+    /// impl Query {
+    ///     fn ping() -> bool {
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new())
+    ///     .with_parse_options(ParseOptions::new().max_tokens(3));
+    ///
+    /// assert!(juniper::execute_sync("{ ping }", None, &schema, &graphql_vars! {}, &()).is_ok());
+    /// assert!(matches!(
+    ///     juniper::execute_sync("{ ping ping }", None, &schema, &graphql_vars! {}, &()),
+    ///     Err(GraphQLError::ParseError(e)) if e.item == ParseError::TooManyTokens(3),
+    /// ));
+    /// ```
+    ///
+    /// By default, queries are parsed without any limits.
+    ///
+    /// [`GraphQLError::ParseError`]: crate::GraphQLError::ParseError
+    #[must_use]
+    pub fn with_parse_options(mut self, options: ParseOptions) -> Self {
+        self.schema.parse_options = options;
+        self
+    }
+
+    /// Renames the root type of the provided `operation` in this schema to
+    /// the provided `name`, regardless of its [`GraphQLType::name()`].
+    ///
+    /// The new name is used everywhere in the schema: in introspection, in
+    /// type conditions of fragments, as `__typename` and in the schema
+    /// language representation.
+    ///
+    /// Does nothing if this schema has no root type for the `operation` (like
+    /// [`EmptyMutation`] or [`EmptySubscription`]).
+    ///
+    /// ```
+    /// # use juniper::{
+    /// #     graphql_object, EmptyMutation, EmptySubscription, OperationType, RootNode,
+    /// # };
+    /// #
+    /// struct Query;
+    ///
+    /// #[graphql_object]
+    /// impl Query {
+    ///     fn ping() -> bool {
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new())
+    ///     .with_root_type_name(OperationType::Query, "RootQuery")?
+    ///     .with_root_type_description(OperationType::Query, "Entry point of all queries.");
+    ///
+    /// assert_eq!(schema.schema.concrete_query_type().name(), Some("RootQuery"));
+    /// # Ok::<_, juniper::RootTypeRenameError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the `name` is not a valid GraphQL name, or is already used by another
+    /// type in this schema.
+    ///
+    /// [`EmptyMutation`]: crate::EmptyMutation
+    /// [`EmptySubscription`]: crate::EmptySubscription
+    pub fn with_root_type_name(
+        mut self,
+        operation: OperationType,
+        name: impl Into<String>,
+    ) -> Result<Self, RootTypeRenameError> {
+        self.schema.rename_root_type(operation, name.into())?;
+        Ok(self)
+    }
+
+    /// Sets the description of the root type of the provided `operation` in
+    /// this schema, overriding the one it's defined with.
+    ///
+    /// Does nothing if this schema has no root type for the `operation` (like
+    /// [`EmptyMutation`] or [`EmptySubscription`]).
+    ///
+    /// [`EmptyMutation`]: crate::EmptyMutation
+    /// [`EmptySubscription`]: crate::EmptySubscription
+    #[must_use]
+    pub fn with_root_type_description(
+        mut self,
+        operation: OperationType,
+        description: impl Into<String>,
+    ) -> Self {
+        if let Some(MetaType::Object(ObjectMeta {
+            description: ref mut d,
+            ..
+        })) = self
+            .schema
+            .root_type_name(operation)
+            .map(ToOwned::to_owned)
+            .and_then(|name| self.schema.types.get_mut(name.as_str()))
+        {
+            *d = Some(description.into());
+        }
+        self
+    }
+
+    /// Estimates the cost of executing the provided `document` with the
+    /// provided `variables`, returning the cost of its most expensive
+    /// operation.
+    ///
+    /// Every selected field costs `1` plus the cost of its sub-selection by
+    /// default, which may be changed for a field with either a fixed weight via
+    /// `#[graphql(complexity = 5)]` attribute argument, or an arbitrary
+    /// function of the field arguments and the sub-selection cost via
+    /// `#[graphql(complexity_with = path::to::fn)]` (to multiply the cost of a
+    /// paginated list by the number of requested items, for example). See
+    /// [`meta::Complexity`] for details.
+    ///
+    /// Fragments are accounted wherever they're spread, and the selections
+    /// excluded via `@skip`/`@include` directives are not accounted at all.
+    /// Selections made on different types of an abstract type are summed up,
+    /// so the estimated cost is an upper bound.
+    ///
+    /// # Errors
+    ///
+    /// If the `document` spreads an unknown fragment or contains a cycle of
+    /// fragment spreads.
+    ///
+    /// [`meta::Complexity`]: crate::meta::Complexity
+    pub fn estimate_cost(
+        &self,
+        document: &ast::Document<S>,
+        variables: &Variables<S>,
+    ) -> Result<u64, CostError> {
+        document_cost(&self.schema, document, variables)
+    }
+
+    /// Computes a fingerprint of the selection made by the operation with the
+    /// provided `operation_name` (or the only one, if [`None`]) of the
+    /// `document`, executed with the provided `variables`, suitable as a key
+    /// for caching its response.
+    ///
+    /// The fingerprint is computed over a canonical form of the operation,
+    /// which includes:
+    /// - the operation type (but not its name);
+    /// - the name, the alias and the arguments of every selected field, with
+    ///   the values of variables (or their defaults) substituted, and the
+    ///   fields of input objects sorted;
+    /// - the directives applied to every selected field (and their arguments),
+    ///   except the `@skip` and `@include` ones, which are evaluated instead,
+    ///   so the excluded selections are omitted;
+    /// - the selections of all the fragments, flattened into the selection
+    ///   sets they're spread in (or grouped by their type conditions, if these
+    ///   differ from the type of the selection set).
+    ///
+    /// So, the fingerprint doesn't depend on whitespaces, comments, the order
+    /// of the selected fields, names of the variables or how the selections
+    /// are split into fragments, while any difference in the selected fields or
+    /// in the values of their arguments changes it.
+    ///
+    /// ```rust
+    /// # use juniper::{
+    /// #     graphql_object, graphql_vars, parser::parse_document_source, EmptyMutation,
+    /// #     EmptySubscription, RootNode,
+    /// # };
+    /// #
+    /// struct Query;
+    ///
+    /// #[graphql_object]
+    /// impl Query {
+    ///     fn greet(name: String) -> String {
+    ///         format!("Hello, {name}!")
+    ///     }
+    ///
+    ///     fn ping() -> bool {
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new());
+    /// let fingerprint = |query, vars| {
+    ///     let doc = parse_document_source(query, &schema.schema).unwrap();
+    ///     schema.selection_fingerprint(&doc, &vars, None).unwrap()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     fingerprint(r#"{ ping greet(name: "Alice") }"#, graphql_vars! {}),
+    ///     fingerprint(
+    ///         "query($n: String!) { ...F ping } fragment F on Query { greet(name: $n) }",
+    ///         graphql_vars! {"n": "Alice"},
+    ///     ),
+    /// );
+    /// assert_ne!(
+    ///     fingerprint(r#"{ ping greet(name: "Alice") }"#, graphql_vars! {}),
+    ///     fingerprint(r#"{ ping greet(name: "Bob") }"#, graphql_vars! {}),
+    /// );
+    /// ```
+    ///
+    /// Fingerprints are computed with the [FNV] hash function, so they're
+    /// stable across builds of an application and versions of Rust, and may be
+    /// persisted, as long as the platform's pointer width and endianness stay
+    /// the same. However, they may change between versions of Juniper.
+    ///
+    /// # Errors
+    ///
+    /// If the `document` doesn't contain the requested operation (see
+    /// [`execute()`] for details), spreads an unknown fragment or contains a
+    /// cycle of fragment spreads.
+    ///
+    /// [`execute()`]: crate::execute
+    /// [FNV]: https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function
+    pub fn selection_fingerprint(
+        &self,
+        document: &ast::Document<S>,
+        variables: &Variables<S>,
+        operation_name: Option<&str>,
+    ) -> Result<u64, GraphQLError> {
+        let operation = get_operation(document, operation_name)?;
+        operation_fingerprint(&self.schema, document, &operation.item, variables)
+    }
+
+    /// Parses and validates the provided `query` against this schema, without
+    /// executing it, returning all the found [`RuleError`]s, each pointing to
+    /// the source positions it relates to.
+    ///
+    /// Runs the very same validation rules the query is checked with before
+    /// its execution (including the custom ones registered via
+    /// [`RootNode::with_validation_rule()`]). Failing to parse the `query` is
+    /// reported as a single [`RuleError`] at the position of the parse error.
+    ///
+    /// As no variables are provided, neither the variable values nor the cost
+    /// of the `query` (see [`RootNode::with_max_cost()`]) are validated.
+    pub fn validate_document(&self, query: &str) -> Vec<RuleError> {
+        let document = match parse_document_source(query, &self.schema) {
+            Ok(doc) => doc,
+            Err(e) => return vec![RuleError::new(&e.item.to_string(), &[e.start])],
+        };
+
+        let mut ctx = ValidatorContext::new(&self.schema, &document);
+        visit_all_rules(&mut ctx, &document);
+        ctx.into_errors()
+    }
+
+    /// Coerces the provided raw `variables` of the provided `operation` against
+    /// this schema, without executing it, as [CoerceVariableValues()][0] does:
+    /// only the declared variables are returned, with the declared defaults
+    /// applied to the absent ones, and the values coerced to the declared
+    /// types.
+    ///
+    /// The variables are validated by the very same rules applied before the
+    /// `operation` is executed, so any malformed variable value (or declared
+    /// default) is reported before any resolver runs. All the found
+    /// [`RuleError`]s are returned at once, each naming the offending variable
+    /// and pointing to the position of its declaration (or its default value).
+    ///
+    /// ```rust
+    /// # use juniper::{
+    /// #     graphql_object, graphql_input_value, graphql_vars, parser::parse_document_source,
+    /// #     Definition, EmptyMutation, EmptySubscription, RootNode,
+    /// # };
+    /// #
+    /// struct Query;
+    ///
+    /// #[graphql_object]
+    /// impl Query {
+    ///     fn add(a: i32, b: i32) -> i32 {
+    ///         a + b
+    ///     }
+    /// }
+    ///
+    /// let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new());
+    /// let doc = parse_document_source(
+    ///     "query($a: Int!, $b: Int = 2) { add(a: $a, b: $b) }",
+    ///     &schema.schema,
+    /// )
+    /// .unwrap();
+    /// let op = match &doc[0] {
+    ///     Definition::Operation(op) => op,
+    ///     Definition::Fragment(_) => unreachable!(),
+    /// };
+    ///
+    /// let vars = schema.coerce_variables(op, &graphql_vars! {"a": 1}).unwrap();
+    /// assert_eq!(vars.get("b"), Some(&graphql_input_value!(2)));
+    ///
+    /// let errs = schema.coerce_variables(op, &graphql_vars! {}).unwrap_err();
+    /// assert_eq!(errs.len(), 1);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If any of the provided `variables` or the applied defaults doesn't match
+    /// the declared type of its variable, or a required variable isn't
+    /// provided.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#CoerceVariableValues()
+    pub fn coerce_variables(
+        &self,
+        operation: &Spanning<ast::Operation<S>>,
+        variables: &Variables<S>,
+    ) -> Result<Variables<S>, Vec<RuleError>> {
+        coerce_variable_values(variables, operation, &self.schema)
+    }
+
+    /// Validates this schema against the type system rules of the
+    /// [GraphQL specification][0], returning all the found
+    /// [`SchemaValidationError`]s.
+    ///
+    /// Checks that:
+    /// - every type referenced by fields, arguments, interfaces and unions is
+    ///   registered in this schema;
+    /// - names of fields, arguments and enum values are unique in their
+    ///   parents;
+    /// - no names except the introspection ones start with the `__` prefix;
+    /// - interfaces are implemented correctly (including the transitively
+    ///   implemented ones), and unions contain only objects.
+    ///
+    /// Most of these rules are already enforced for the types defined via
+    /// `juniper` macros at compile time, but not for the manually implemented
+    /// or dynamically defined ones (see [`GraphQLType::name()`]). Calling this
+    /// method in a test makes CI fail on an invalid schema without running a
+    /// server.
+    ///
+    /// ```rust
+    /// # use juniper::{graphql_object, EmptyMutation, EmptySubscription, RootNode};
+    /// #
+    /// struct Query;
+    ///
+    /// #[graphql_object]
+    /// impl Query {
+    ///     fn ping() -> bool {
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new());
+    /// assert_eq!(schema.validate_schema(), Ok(()));
+    /// ```
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System
+    pub fn validate_schema(&self) -> Result<(), Vec<SchemaValidationError>> {
+        self.schema.validate()
+    }
+
+    /// Returns the result of the canonical introspection query of this schema
+    /// in the provided `format`, without validating it or resolving the root
+    /// types.
+    ///
+    /// The returned [`Value`] has the very same shape as the one returned by
+    /// [`introspect()`] (including its `__schema` top-level field), but no
+    /// context is required to produce it, and it's cheaper, as the query is
+    /// neither validated nor resolved via the root types of this schema.
+    ///
+    /// [`introspect()`]: crate::introspect
+    pub fn introspect(&self, format: IntrospectionFormat) -> Value<S> {
+        resolve_introspection(&self.schema, format)
+    }
+
+    /// Returns the result of the canonical introspection query of this schema,
+    /// with its `types` list narrowed to the types of the provided `names` and
+    /// their direct dependencies only.
+    ///
+    /// Direct dependencies of a type are the types of its fields, their
+    /// arguments and its input fields, the interfaces it implements, and the
+    /// possible types of an interface or a union. Types not present in this
+    /// schema are ignored.
+    ///
+    /// The returned [`Value`] has the very same shape as the one returned by
+    /// [`RootNode::introspect()`], so a large schema may be introspected by
+    /// tooling lazily, in chunks. Only the included types are resolved, so
+    /// the cost of the introspection doesn't depend on the size of the schema.
+    ///
+    /// ```rust
+    /// # use juniper::{
+    /// #     graphql_object, EmptyMutation, EmptySubscription, GraphQLObject, RootNode,
+    /// # };
+    /// #
+    /// #[derive(GraphQLObject)]
+    /// struct Human {
+    ///     name: String,
+    /// }
+    ///
+    /// struct Query;
+    ///
+    /// #[graphql_object]
+    /// impl Query {
+    ///     fn human() -> Human {
+    ///         Human { name: "Luke".into() }
+    ///     }
+    /// }
+    ///
+    /// let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new());
+    ///
+    /// let result = schema.introspect_types(&["Human"]);
+    /// let types = result.as_object_value().unwrap()
+    ///     .get_field_value("__schema").unwrap()
+    ///     .as_object_value().unwrap()
+    ///     .get_field_value("types").unwrap()
+    ///     .as_list_value().unwrap();
+    /// let mut names = types
+    ///     .iter()
+    ///     .map(|t| t.as_object_value().unwrap().get_field_value("name").unwrap())
+    ///     .map(|n| n.as_string_value().unwrap())
+    ///     .collect::<Vec<_>>();
+    /// names.sort_unstable();
+    /// assert_eq!(names, ["Human", "String"]);
+    /// ```
+    pub fn introspect_types(&self, names: &[&str]) -> Value<S> {
+        let mut included = HashSet::new();
+        for t in names
+            .iter()
+            .filter_map(|n| self.schema.concrete_type_by_name(n))
+        {
+            if let Some(name) = t.name() {
+                included.insert(name);
+            }
+            included.extend(self.schema.direct_dependencies(t));
+        }
+
+        // Preserve the order of types in the full introspection result.
+        let names = self
+            .schema
+            .types
+            .values()
+            .filter_map(MetaType::name)
+            .filter(|n| {
+                included.contains(n) && !matches!(*n, "_EmptyMutation" | "_EmptySubscription")
+            })
+            .collect::<Vec<_>>();
+
+        resolve_introspection_query(&self.schema, INTROSPECTION_QUERY, Some(&names))
+    }
+
+    /// Registers the provided custom [`ValidationRule`] in this schema, so it's
+    /// run on every query validated against this schema.
+    ///
+    /// Custom rules are run only once all the built-in validation rules pass,
+    /// so they may rely on the validated query being otherwise valid (having
+    /// no fragment cycles, for example).
+    #[must_use]
+    pub fn with_validation_rule(mut self, rule: impl ValidationRule<S> + 'a) -> Self {
+        self.schema.validation_rules.push(Box::new(rule));
+        self
+    }
+
+    /// Registers the provided [`FieldMiddleware`] in this schema, so it wraps
+    /// resolution of every field executed against this schema.
+    ///
+    /// Middlewares registered earlier wrap the ones registered later. No
+    /// overhead is imposed on field resolution while none is registered.
+    #[must_use]
+    pub fn with_field_middleware(mut self, middleware: impl FieldMiddleware<S> + 'a) -> Self {
+        self.schema.field_middlewares.push(Box::new(middleware));
+        self
+    }
+
+    /// Registers the provided [`PreExecution`] hook in this schema, so it's
+    /// called before executing every validated operation, and may abort it
+    /// before any of its fields is resolved.
+    ///
+    /// Hooks are called in the order of registration. No overhead (not even
+    /// estimating the cost of operations) is imposed on execution while none
+    /// is registered.
+    #[must_use]
+    pub fn with_pre_execution(mut self, hook: impl PreExecution<QueryT::Context, S> + 'a) -> Self {
+        self.pre_executions.push(Box::new(hook));
+        self
+    }
+
+    /// Registers the provided custom [`DirectiveType`] in this schema, making
+    /// it visible in introspection and allowed to be used in queries at its
+    /// declared [`DirectiveLocation`]s.
+    ///
+    /// Only the directive definition is registered, so the directive isn't
+    /// enforced in any way during execution. Overwrites any previously
+    /// registered directive with the same name.
+    ///
+    /// # Errors
+    ///
+    /// If any of the directive's arguments refers to a type not present in
+    /// this schema.
+    pub fn with_directive(
+        mut self,
+        directive: DirectiveType<'a, S>,
+    ) -> Result<Self, DirectiveRegisterError> {
+        if let Some(arg) = directive
+            .arguments
+            .iter()
+            .find(|arg| self.schema.lookup_type(&arg.arg_type).is_none())
+        {
+            return Err(DirectiveRegisterError::UnknownArgumentType {
+                directive: directive.name.clone(),
+                argument: arg.name.clone(),
+                arg_type: arg.arg_type.to_string(),
+            });
         }
+        self.schema.add_directive(directive);
+        Ok(self)
+    }
+
+    /// Registers the [`@stream` directive][0] in this schema, allowing list
+    /// fields selected with it to be delivered incrementally by
+    /// [`execute_incremental()`].
+    ///
+    /// The directive is defined as:
+    /// ```graphql
+    /// directive @stream(if: Boolean! = true, label: String, initialCount: Int! = 0) on FIELD
+    /// ```
+    ///
+    /// Operations using the directive are still executable via [`execute()`]
+    /// and similar functions, which simply ignore it, delivering whole lists.
+    ///
+    /// [`execute()`]: crate::execute
+    /// [`execute_incremental()`]: crate::execute_incremental
+    /// [0]: https://github.com/graphql/graphql-spec/pull/742
+    #[must_use]
+    pub fn with_stream_directive(mut self) -> Self {
+        self.schema.add_stream_directive();
+        self
+    }
+
+    /// Registers the [`@defer` directive][0] in this schema, allowing fields of
+    /// fragments marked with it to be delivered incrementally by
+    /// [`execute_incremental()`].
+    ///
+    /// The directive is defined as:
+    /// ```graphql
+    /// directive @defer(if: Boolean! = true, label: String) on FRAGMENT_SPREAD | INLINE_FRAGMENT
+    /// ```
+    ///
+    /// Operations using the directive are still executable via [`execute()`]
+    /// and similar functions, which simply ignore it, delivering all the fields
+    /// at once.
+    ///
+    /// [`execute()`]: crate::execute
+    /// [`execute_incremental()`]: crate::execute_incremental
+    /// [0]: https://github.com/graphql/graphql-spec/pull/742
+    #[must_use]
+    pub fn with_defer_directive(mut self) -> Self {
+        self.schema.add_defer_directive();
+        self
     }
 
     #[cfg(feature = "schema-language")]
@@ -170,12 +899,280 @@ where
         self.as_parser_document().to_string()
     }
 
+    #[cfg(feature = "schema-language")]
+    /// The schema definition as a `String` in the
+    /// [GraphQL Schema Language](https://graphql.org/learn/schema/#type-language)
+    /// format, printed according to the provided [`SdlOptions`].
+    ///
+    /// Sorted output is stable across builds, so is suitable for snapshot
+    /// testing of the schema.
+    ///
+    /// See [`RootNode::write_sdl()`] for writing the output into a sink
+    /// directly, without building the whole [`String`] first.
+    pub fn as_sdl(&self, opts: SdlOptions) -> String {
+        self.sdl_document(opts).to_string()
+    }
+
+    #[cfg(feature = "schema-language")]
+    /// Writes the schema definition in the
+    /// [GraphQL Schema Language](https://graphql.org/learn/schema/#type-language)
+    /// format, printed according to the provided [`SdlOptions`], into the
+    /// provided `w`riter.
+    ///
+    /// The whole schema [`Document`] is still built in memory, but its
+    /// definitions are written into the `w`riter directly one by one, so no
+    /// [`String`] of the whole output is allocated, while the output is exactly
+    /// the same as the one of [`RootNode::as_sdl()`].
+    ///
+    /// ```rust
+    /// # use juniper::{graphql_object, EmptyMutation, EmptySubscription, RootNode, SdlOptions};
+    /// #
+    /// struct Query;
+    ///
+    /// #[graphql_object]
+    /// impl Query {
+    ///     fn ping() -> bool {
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new());
+    ///
+    /// let mut sdl = String::new();
+    /// schema.write_sdl(&mut sdl, SdlOptions::default()).unwrap();
+    /// assert_eq!(sdl, schema.as_sdl(SdlOptions::default()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the provided `w`riter fails.
+    pub fn write_sdl<W: fmt::Write>(&self, w: &mut W, opts: SdlOptions) -> fmt::Result {
+        let doc = self.sdl_document(opts);
+        for (i, def) in doc.definitions.iter().enumerate() {
+            // Definitions are separated with an empty line, the same way
+            // `Document`'s `Display` implementation does.
+            if i > 0 {
+                w.write_char('\n')?;
+            }
+            write!(w, "{def}")?;
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "schema-language")]
+    /// Builds the schema [`Document`] to be printed according to the provided
+    /// [`SdlOptions`].
+    fn sdl_document(&self, opts: SdlOptions) -> Document<'_, &str> {
+        use graphql_parser::schema::{Definition, TypeDefinition};
+
+        let mut doc = self.as_parser_document();
+
+        if opts.builtin_scalars {
+            let scalars = self
+                .schema
+                .types
+                .values()
+                .filter(|t| {
+                    matches!(t, MetaType::Scalar(_))
+                        && matches!(
+                            t.name(),
+                            Some("Int" | "Float" | "String" | "Boolean" | "ID")
+                        )
+                })
+                .map(|t| Definition::TypeDefinition(GraphQLParserTranslator::translate_meta(t)));
+            doc.definitions.splice(0..0, scalars);
+        }
+        if !opts.descriptions {
+            for_each_description(&mut doc, |d| *d = None);
+        }
+        if opts.sorted {
+            sort_definitions(&mut doc);
+            for def in &mut doc.definitions {
+                if let Definition::TypeDefinition(ty) = def {
+                    match ty {
+                        TypeDefinition::Object(o) => o.fields.sort_by_key(|f| f.name),
+                        TypeDefinition::Interface(i) => i.fields.sort_by_key(|f| f.name),
+                        TypeDefinition::InputObject(io) => io.fields.sort_by_key(|f| f.name),
+                        TypeDefinition::Scalar(_)
+                        | TypeDefinition::Union(_)
+                        | TypeDefinition::Enum(_) => {}
+                    }
+                }
+            }
+        }
+
+        doc
+    }
+
     #[cfg(feature = "graphql-parser")]
     /// The schema definition as a [`graphql_parser`](https://crates.io/crates/graphql-parser)
     /// [`Document`](https://docs.rs/graphql-parser/latest/graphql_parser/schema/struct.Document.html).
     pub fn as_parser_document(&'a self) -> Document<'a, &'a str> {
         GraphQLParserTranslator::translate_schema(&self.schema)
     }
+
+    #[cfg(feature = "schema-language")]
+    /// A stable digest of the schema definition, allowing to cheaply detect
+    /// whether the schema has changed (between deployments, for example).
+    ///
+    /// The digest is computed over the canonicalized
+    /// [`RootNode::as_schema_language()`] output: type definitions are ordered
+    /// by their names, and whitespace inside descriptions is normalized. It's
+    /// deterministic across process runs.
+    pub fn schema_hash(&self) -> u64 {
+        use std::hash::Hasher as _;
+
+        let mut doc = self.as_parser_document();
+        canonicalize_document(&mut doc);
+
+        let mut hasher = fnv::FnvHasher::default();
+        hasher.write(doc.to_string().as_bytes());
+        hasher.finish()
+    }
+}
+
+/// Options of the [GraphQL Schema Language][0] output produced by
+/// [`RootNode::as_sdl()`].
+///
+/// [`Default`] options produce the same output as
+/// [`RootNode::as_schema_language()`] does.
+///
+/// [0]: https://graphql.org/learn/schema#type-language
+#[cfg(feature = "schema-language")]
+#[derive(Clone, Copy, Debug)]
+pub struct SdlOptions {
+    sorted: bool,
+    descriptions: bool,
+    builtin_scalars: bool,
+}
+
+#[cfg(feature = "schema-language")]
+impl Default for SdlOptions {
+    fn default() -> Self {
+        Self {
+            sorted: false,
+            descriptions: true,
+            builtin_scalars: false,
+        }
+    }
+}
+
+#[cfg(feature = "schema-language")]
+impl SdlOptions {
+    /// Specifies whether type and directive definitions should be ordered
+    /// alphabetically by their names, along with fields of objects, interfaces
+    /// and input objects.
+    ///
+    /// Otherwise, definitions follow the order of their registration in the
+    /// schema, which isn't stable between builds.
+    ///
+    /// Default is `false`.
+    #[must_use]
+    pub fn sorted(mut self, sorted: bool) -> Self {
+        self.sorted = sorted;
+        self
+    }
+
+    /// Specifies whether descriptions should be printed.
+    ///
+    /// Default is `true`.
+    #[must_use]
+    pub fn descriptions(mut self, descriptions: bool) -> Self {
+        self.descriptions = descriptions;
+        self
+    }
+
+    /// Specifies whether [built-in scalars][1] (`Int`, `Float`, `String`,
+    /// `Boolean` and `ID`) used in the schema should be printed.
+    ///
+    /// Default is `false`.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Scalars.Built-in-Scalars
+    #[must_use]
+    pub fn builtin_scalars(mut self, builtin_scalars: bool) -> Self {
+        self.builtin_scalars = builtin_scalars;
+        self
+    }
+}
+
+/// Calls the provided function on every description in the provided schema
+/// [`Document`].
+#[cfg(feature = "schema-language")]
+fn for_each_description<'a>(
+    doc: &mut Document<'a, &'a str>,
+    mut f: impl FnMut(&mut Option<String>),
+) {
+    use graphql_parser::schema::{Definition, TypeDefinition};
+
+    for def in &mut doc.definitions {
+        if let Definition::TypeDefinition(ty) = def {
+            match ty {
+                TypeDefinition::Scalar(s) => f(&mut s.description),
+                TypeDefinition::Object(o) => {
+                    f(&mut o.description);
+                    for fld in &mut o.fields {
+                        f(&mut fld.description);
+                        fld.arguments.iter_mut().for_each(|a| f(&mut a.description));
+                    }
+                }
+                TypeDefinition::Interface(i) => {
+                    f(&mut i.description);
+                    for fld in &mut i.fields {
+                        f(&mut fld.description);
+                        fld.arguments.iter_mut().for_each(|a| f(&mut a.description));
+                    }
+                }
+                TypeDefinition::Union(u) => f(&mut u.description),
+                TypeDefinition::Enum(e) => {
+                    f(&mut e.description);
+                    e.values.iter_mut().for_each(|v| f(&mut v.description));
+                }
+                TypeDefinition::InputObject(io) => {
+                    f(&mut io.description);
+                    io.fields.iter_mut().for_each(|fld| f(&mut fld.description));
+                }
+            }
+        } else if let Definition::DirectiveDefinition(d) = def {
+            f(&mut d.description);
+            d.arguments.iter_mut().for_each(|a| f(&mut a.description));
+        }
+    }
+}
+
+/// Orders definitions of the provided schema [`Document`] by their names,
+/// placing the schema definition last.
+#[cfg(feature = "schema-language")]
+fn sort_definitions<'a>(doc: &mut Document<'a, &'a str>) {
+    use graphql_parser::schema::{Definition, TypeDefinition};
+
+    doc.definitions.sort_by_key(|def| match def {
+        Definition::TypeDefinition(ty) => (
+            false,
+            match ty {
+                TypeDefinition::Scalar(s) => s.name,
+                TypeDefinition::Object(o) => o.name,
+                TypeDefinition::Interface(i) => i.name,
+                TypeDefinition::Union(u) => u.name,
+                TypeDefinition::Enum(e) => e.name,
+                TypeDefinition::InputObject(io) => io.name,
+            },
+        ),
+        Definition::DirectiveDefinition(d) => (false, d.name),
+        // Schema definition goes last.
+        _ => (true, ""),
+    });
+}
+
+/// Canonicalizes the provided schema [`Document`], so equivalent schemas are
+/// represented with the same [`Document`].
+#[cfg(feature = "schema-language")]
+fn canonicalize_document<'a>(doc: &mut Document<'a, &'a str>) {
+    for_each_description(doc, |description| {
+        if let Some(d) = description {
+            *d = d.split_whitespace().collect::<Vec<_>>().join(" ");
+        }
+    });
+    sort_definitions(doc);
 }
 
 impl<'a, S> SchemaType<'a, S> {
@@ -191,7 +1188,6 @@ impl<'a, S> SchemaType<'a, S> {
         MutationT: GraphQLType<S>,
         SubscriptionT: GraphQLType<S>,
     {
-        let mut directives = FnvHashMap::default();
         let mut registry = Registry::new(FnvHashMap::default());
 
         let query_type_name = registry
@@ -209,16 +1205,7 @@ impl<'a, S> SchemaType<'a, S> {
 
         registry.get_type::<SchemaType<S>>(&());
 
-        directives.insert("skip".into(), DirectiveType::new_skip(&mut registry));
-        directives.insert("include".into(), DirectiveType::new_include(&mut registry));
-        directives.insert(
-            "deprecated".into(),
-            DirectiveType::new_deprecated(&mut registry),
-        );
-        directives.insert(
-            "specifiedBy".into(),
-            DirectiveType::new_specified_by(&mut registry),
-        );
+        let directives = Self::builtin_directives(&mut registry);
 
         let mut meta_fields = vec![
             registry.field::<SchemaType<S>>("__schema", &()),
@@ -256,8 +1243,187 @@ impl<'a, S> SchemaType<'a, S> {
             } else {
                 None
             },
+            type_renames: FnvHashMap::default(),
+            directives,
+            introspection_depth_limit: None,
+            introspection_disabled: false,
+            max_cost: None,
+            parse_options: ParseOptions::default(),
+            validation_rules: Vec::new(),
+            field_middlewares: Vec::new(),
+        }
+    }
+
+    /// Creates a new schema consisting only of the provided `T` type and the
+    /// types it references, using `T` as its query type.
+    ///
+    /// Unlike [`SchemaType::new()`], doesn't require `T` to be an object, and
+    /// doesn't register any introspection types and fields.
+    pub(crate) fn new_standalone<T>(info: &T::TypeInfo) -> Self
+    where
+        S: ScalarValue + 'a,
+        T: GraphQLType<S> + ?Sized,
+    {
+        let mut registry = Registry::new(FnvHashMap::default());
+
+        let query_type_name = registry.get_type::<T>(info).innermost_name().to_owned();
+        let directives = Self::builtin_directives(&mut registry);
+
+        SchemaType {
+            description: None,
+            types: registry.types,
+            query_type_name,
+            mutation_type_name: None,
+            subscription_type_name: None,
+            type_renames: FnvHashMap::default(),
             directives,
+            introspection_depth_limit: None,
+            introspection_disabled: false,
+            max_cost: None,
+            parse_options: ParseOptions::default(),
+            validation_rules: Vec::new(),
+            field_middlewares: Vec::new(),
+        }
+    }
+
+    /// Registers the directives every schema supports in the provided
+    /// `registry`.
+    fn builtin_directives(
+        registry: &mut Registry<'a, S>,
+    ) -> FnvHashMap<String, DirectiveType<'a, S>>
+    where
+        S: ScalarValue + 'a,
+    {
+        let mut directives = FnvHashMap::default();
+        directives.insert("skip".into(), DirectiveType::new_skip(registry));
+        directives.insert("include".into(), DirectiveType::new_include(registry));
+        directives.insert("deprecated".into(), DirectiveType::new_deprecated(registry));
+        directives.insert(
+            "specifiedBy".into(),
+            DirectiveType::new_specified_by(registry),
+        );
+        // `@oneOf` is registered only once used, so schemas without any
+        // `@oneOf` input object are not affected by it.
+        if registry
+            .types
+            .values()
+            .any(|t| matches!(t, MetaType::InputObject(io) if io.is_one_of))
+        {
+            directives.insert("oneOf".into(), DirectiveType::new_one_of());
+        }
+        directives
+    }
+
+    /// Returns the name of the root type of the provided `operation`, if this
+    /// schema has one.
+    fn root_type_name(&self, operation: OperationType) -> Option<&str> {
+        match operation {
+            OperationType::Query => Some(&self.query_type_name),
+            OperationType::Mutation => self.mutation_type_name.as_deref(),
+            OperationType::Subscription => self.subscription_type_name.as_deref(),
+        }
+    }
+
+    /// Renames the root type of the provided `operation` to the provided
+    /// `name`, along with all the references to it.
+    ///
+    /// # Errors
+    ///
+    /// If the `name` is not a valid GraphQL name, or is already used by another
+    /// type in this schema.
+    fn rename_root_type(
+        &mut self,
+        operation: OperationType,
+        name: String,
+    ) -> Result<(), RootTypeRenameError> {
+        let old_name = match self.root_type_name(operation) {
+            Some(n) if n != name => n.to_owned(),
+            _ => return Ok(()),
+        };
+        let validated_name = match name.parse::<Name>() {
+            Ok(n) => n,
+            Err(_) => return Err(RootTypeRenameError::InvalidName(name)),
+        };
+        if self.types.contains_key(name.as_str()) {
+            return Err(RootTypeRenameError::ExistingType(name));
+        }
+
+        let mut meta = self
+            .types
+            .remove(old_name.as_str())
+            .expect("Root type not found");
+        if let MetaType::Object(ObjectMeta {
+            name: ref mut n, ..
+        }) = meta
+        {
+            *n = name.clone().into();
+        }
+        self.types.insert(validated_name, meta);
+
+        fn rename(ty: &mut Type<'_>, from: &str, to: &str) {
+            match ty {
+                Type::Named(n) | Type::NonNullNamed(n) => {
+                    if n == from {
+                        *n = to.to_owned().into();
+                    }
+                }
+                Type::List(inner, _) | Type::NonNullList(inner, _) => rename(inner, from, to),
+            }
+        }
+        fn rename_args<S>(args: &mut Option<Vec<Argument<'_, S>>>, from: &str, to: &str) {
+            for arg in args.iter_mut().flatten() {
+                rename(&mut arg.arg_type, from, to);
+            }
+        }
+        for meta in self.types.values_mut() {
+            match meta {
+                MetaType::Object(ObjectMeta { fields, .. })
+                | MetaType::Interface(InterfaceMeta { fields, .. }) => {
+                    for f in fields {
+                        rename(&mut f.field_type, &old_name, &name);
+                        rename_args(&mut f.arguments, &old_name, &name);
+                    }
+                }
+                MetaType::InputObject(InputObjectMeta { input_fields, .. }) => {
+                    for f in input_fields {
+                        rename(&mut f.arg_type, &old_name, &name);
+                    }
+                }
+                MetaType::Union(UnionMeta { of_type_names, .. }) => {
+                    for n in of_type_names.iter_mut().filter(|n| **n == old_name) {
+                        *n = name.clone();
+                    }
+                }
+                _ => {}
+            }
+        }
+        for d in self.directives.values_mut() {
+            for arg in &mut d.arguments {
+                rename(&mut arg.arg_type, &old_name, &name);
+            }
+        }
+
+        for n in self.type_renames.values_mut().filter(|n| **n == old_name) {
+            *n = name.clone();
+        }
+        self.type_renames
+            .entry(old_name)
+            .or_insert_with(|| name.clone());
+
+        match operation {
+            OperationType::Query => self.query_type_name = name,
+            OperationType::Mutation => self.mutation_type_name = Some(name),
+            OperationType::Subscription => self.subscription_type_name = Some(name),
         }
+        Ok(())
+    }
+
+    /// Returns the name of the type in this schema, provided its
+    /// [`GraphQLType::name()`], as they differ for renamed root types.
+    pub(crate) fn schema_type_name<'n>(&'n self, type_name: &'n str) -> &'n str {
+        self.type_renames
+            .get(type_name)
+            .map_or(type_name, String::as_str)
     }
 
     /// Add a description.
@@ -270,6 +1436,30 @@ impl<'a, S> SchemaType<'a, S> {
         self.directives.insert(directive.name.clone(), directive);
     }
 
+    /// Registers the `@stream` directive, along with the types of its
+    /// arguments, if they're not registered yet.
+    pub(crate) fn add_stream_directive(&mut self)
+    where
+        S: ScalarValue,
+    {
+        let mut registry = Registry::new(mem::take(&mut self.types));
+        let directive = DirectiveType::new_stream(&mut registry);
+        self.types = registry.types;
+        self.add_directive(directive);
+    }
+
+    /// Registers the `@defer` directive, along with the types of its
+    /// arguments, if they're not registered yet.
+    pub(crate) fn add_defer_directive(&mut self)
+    where
+        S: ScalarValue,
+    {
+        let mut registry = Registry::new(mem::take(&mut self.types));
+        let directive = DirectiveType::new_defer(&mut registry);
+        self.types = registry.types;
+        self.add_directive(directive);
+    }
+
     /// Get a type by name.
     pub fn type_by_name(&self, name: &str) -> Option<TypeType<S>> {
         self.types.get(name).map(|t| TypeType::Concrete(t))
@@ -390,6 +1580,48 @@ impl<'a, S> SchemaType<'a, S> {
         }
     }
 
+    /// Returns names of the types the given type directly depends on: the
+    /// types of its fields, their arguments and its input fields, the
+    /// interfaces it implements, and its possible types, if it's abstract.
+    fn direct_dependencies<'t>(&'t self, t: &'t MetaType<S>) -> Vec<&'t str> {
+        let mut deps = Vec::new();
+        match t {
+            MetaType::Object(ObjectMeta {
+                fields,
+                interface_names,
+                ..
+            })
+            | MetaType::Interface(InterfaceMeta {
+                fields,
+                interface_names,
+                ..
+            }) => {
+                for f in fields.iter().filter(|f| !f.is_builtin()) {
+                    deps.push(f.field_type.innermost_name());
+                    deps.extend(
+                        f.arguments
+                            .iter()
+                            .flatten()
+                            .map(|a| a.arg_type.innermost_name()),
+                    );
+                }
+                deps.extend(interface_names.iter().map(String::as_str));
+            }
+            MetaType::InputObject(InputObjectMeta { input_fields, .. }) => {
+                deps.extend(input_fields.iter().map(|f| f.arg_type.innermost_name()));
+            }
+            _ => {}
+        }
+        if matches!(t, MetaType::Interface(_) | MetaType::Union(_)) {
+            deps.extend(
+                self.possible_types(t)
+                    .into_iter()
+                    .filter_map(MetaType::name),
+            );
+        }
+        deps
+    }
+
     /// A list of possible typeees for a given type.
     pub fn possible_types(&self, t: &MetaType<S>) -> Vec<&MetaType<S>> {
         match *t {
@@ -450,6 +1682,7 @@ impl<'a, S> SchemaType<'a, S> {
 
     /// If the type is a named subtype.
     pub fn is_named_subtype(&self, sub_type_name: &str, super_type_name: &str) -> bool {
+        let sub_type_name = self.schema_type_name(sub_type_name);
         if sub_type_name == super_type_name {
             true
         } else if let (Some(sub_type), Some(super_type)) = (
@@ -473,7 +1706,7 @@ impl<'a, S> TypeType<'a, S> {
     }
 
     #[inline]
-    pub fn innermost_concrete(&self) -> &'a MetaType<S> {
+    pub fn innermost_concrete(&self) -> &'a MetaType<'a, S> {
         match *self {
             TypeType::Concrete(t) => t,
             TypeType::NonNull(ref n) | TypeType::List(ref n, _) => n.innermost_concrete(),
@@ -499,6 +1732,8 @@ impl<'a, S> DirectiveType<'a, S>
 where
     S: ScalarValue + 'a,
 {
+    /// Builds a new [`DirectiveType`] with the provided `name`, allowed
+    /// `locations` and `arguments`.
     pub fn new(
         name: &str,
         locations: &[DirectiveLocation],
@@ -555,6 +1790,7 @@ where
             &[
                 DirectiveLocation::FieldDefinition,
                 DirectiveLocation::EnumValue,
+                DirectiveLocation::InputFieldDefinition,
             ],
             &[registry.arg::<String>("reason", &())],
             false,
@@ -573,32 +1809,99 @@ where
         )
     }
 
-    pub fn description(mut self, description: &str) -> DirectiveType<'a, S> {
-        self.description = Some(description.into());
-        self
-    }
-}
-
-impl fmt::Display for DirectiveLocation {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.write_str(match self {
-            Self::Query => "query",
-            Self::Mutation => "mutation",
-            Self::Subscription => "subscription",
-            Self::Field => "field",
-            Self::FieldDefinition => "field definition",
-            Self::FragmentDefinition => "fragment definition",
-            Self::FragmentSpread => "fragment spread",
-            Self::InlineFragment => "inline fragment",
-            Self::VariableDefinition => "variable definition",
-            Self::Scalar => "scalar",
-            Self::EnumValue => "enum value",
-        })
+    fn new_one_of() -> DirectiveType<'a, S>
+    where
+        S: ScalarValue,
+    {
+        Self::new("oneOf", &[DirectiveLocation::InputObject], &[], false).description(
+            "Indicates exactly one field must be supplied and this field must not be `null`.",
+        )
     }
-}
 
-impl<'a, S> fmt::Display for TypeType<'a, S> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    fn new_stream(registry: &mut Registry<'a, S>) -> DirectiveType<'a, S>
+    where
+        S: ScalarValue,
+    {
+        Self::new(
+            "stream",
+            &[DirectiveLocation::Field],
+            &[
+                registry.arg_with_default::<bool>("if", &true, &()),
+                registry.arg::<Option<String>>("label", &()),
+                registry.arg_with_default::<i32>("initialCount", &0, &()),
+            ],
+            false,
+        )
+        .description("Delivers items of the list field incrementally.")
+    }
+
+    fn new_defer(registry: &mut Registry<'a, S>) -> DirectiveType<'a, S>
+    where
+        S: ScalarValue,
+    {
+        Self::new(
+            "defer",
+            &[
+                DirectiveLocation::FragmentSpread,
+                DirectiveLocation::InlineFragment,
+            ],
+            &[
+                registry.arg_with_default::<bool>("if", &true, &()),
+                registry.arg::<Option<String>>("label", &()),
+            ],
+            false,
+        )
+        .description("Delivers fields of the fragment after the initial response.")
+    }
+
+    /// Sets the `description` of this [`DirectiveType`].
+    ///
+    /// Overwrites any previously set description.
+    #[must_use]
+    pub fn description(mut self, description: &str) -> DirectiveType<'a, S> {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Indicates whether this [`DirectiveType`] is built into every schema.
+    pub(crate) fn is_builtin(&self) -> bool {
+        // https://spec.graphql.org/October2021#sec-Type-System.Directives.Built-in-Directives
+        // and https://spec.graphql.org/draft#sec--oneOf
+        matches!(
+            self.name.as_str(),
+            "skip" | "include" | "deprecated" | "specifiedBy" | "oneOf",
+        )
+    }
+}
+
+impl fmt::Display for DirectiveLocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Query => "query",
+            Self::Mutation => "mutation",
+            Self::Subscription => "subscription",
+            Self::Field => "field",
+            Self::FieldDefinition => "field definition",
+            Self::FragmentDefinition => "fragment definition",
+            Self::FragmentSpread => "fragment spread",
+            Self::InlineFragment => "inline fragment",
+            Self::VariableDefinition => "variable definition",
+            Self::Schema => "schema",
+            Self::Scalar => "scalar",
+            Self::Object => "object",
+            Self::ArgumentDefinition => "argument definition",
+            Self::Interface => "interface",
+            Self::Union => "union",
+            Self::Enum => "enum",
+            Self::EnumValue => "enum value",
+            Self::InputObject => "input object",
+            Self::InputFieldDefinition => "input field definition",
+        })
+    }
+}
+
+impl<'a, S> fmt::Display for TypeType<'a, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Self::Concrete(t) => f.write_str(t.name().unwrap()),
             Self::List(i, _) => write!(f, "[{i}]"),
@@ -750,4 +2053,944 @@ mod test {
             assert_eq!(ast.to_string(), schema.as_schema_language());
         }
     }
+
+    #[cfg(feature = "schema-language")]
+    mod one_of {
+        use crate::{
+            graphql_object, EmptyMutation, EmptySubscription, GraphQLInputObject, RootNode,
+        };
+
+        #[derive(GraphQLInputObject)]
+        #[graphql(one_of)]
+        struct UserBy {
+            id: Option<i32>,
+            email: Option<String>,
+        }
+
+        struct Query;
+
+        #[graphql_object]
+        impl Query {
+            fn user(by: UserBy) -> bool {
+                by.id.is_some()
+            }
+        }
+
+        struct PlainQuery;
+
+        #[graphql_object]
+        impl PlainQuery {
+            fn user(id: i32) -> bool {
+                id > 0
+            }
+        }
+
+        #[test]
+        fn marks_input_object_in_schema_language() {
+            let schema = RootNode::new(
+                Query,
+                EmptyMutation::<()>::new(),
+                EmptySubscription::<()>::new(),
+            );
+            let ast = graphql_parser::parse_schema::<&str>(
+                r#"
+                type Query {
+                  user(by: UserBy!): Boolean!
+                }
+                input UserBy @oneOf {
+                  id: Int
+                  email: String
+                }
+                schema {
+                  query: Query
+                }
+            "#,
+            )
+            .unwrap();
+            assert_eq!(ast.to_string(), schema.as_schema_language());
+        }
+
+        #[test]
+        fn registers_directive_only_once_used() {
+            let schema = RootNode::new(
+                Query,
+                EmptyMutation::<()>::new(),
+                EmptySubscription::<()>::new(),
+            );
+            assert!(schema.schema.directive_by_name("oneOf").is_some());
+
+            let schema = RootNode::new(
+                PlainQuery,
+                EmptyMutation::<()>::new(),
+                EmptySubscription::<()>::new(),
+            );
+            assert!(schema.schema.directive_by_name("oneOf").is_none());
+        }
+    }
+
+    #[cfg(feature = "schema-language")]
+    mod schema_hash {
+        use crate::{graphql_object, EmptyMutation, EmptySubscription, GraphQLObject, RootNode};
+
+        #[test]
+        fn is_equal_for_equivalent_schemas() {
+            mod first {
+                use super::*;
+
+                #[derive(GraphQLObject)]
+                /// A   cake.
+                pub struct Cake {
+                    pub fresh: bool,
+                }
+
+                pub struct Query;
+
+                #[graphql_object]
+                impl Query {
+                    /// Returns
+                    ///     a cake.
+                    fn cake() -> Cake {
+                        Cake { fresh: true }
+                    }
+                }
+            }
+
+            mod second {
+                use super::*;
+
+                #[derive(GraphQLObject)]
+                #[graphql(description = "A cake.")]
+                pub struct Cake {
+                    pub fresh: bool,
+                }
+
+                pub struct Query;
+
+                #[graphql_object]
+                impl Query {
+                    #[graphql(description = "Returns a cake.")]
+                    fn cake() -> Cake {
+                        Cake { fresh: true }
+                    }
+                }
+            }
+
+            let first = RootNode::new(
+                first::Query,
+                EmptyMutation::<()>::new(),
+                EmptySubscription::<()>::new(),
+            );
+            let second = RootNode::new(
+                second::Query,
+                EmptyMutation::<()>::new(),
+                EmptySubscription::<()>::new(),
+            );
+
+            assert_eq!(first.schema_hash(), first.schema_hash());
+            assert_eq!(first.schema_hash(), second.schema_hash());
+        }
+
+        #[test]
+        fn differs_for_different_signatures() {
+            mod first {
+                use super::*;
+
+                pub struct Query;
+
+                #[graphql_object]
+                impl Query {
+                    fn value() -> i32 {
+                        42
+                    }
+                }
+            }
+
+            mod second {
+                use super::*;
+
+                pub struct Query;
+
+                #[graphql_object]
+                impl Query {
+                    fn value() -> Option<i32> {
+                        Some(42)
+                    }
+                }
+            }
+
+            let first = RootNode::new(
+                first::Query,
+                EmptyMutation::<()>::new(),
+                EmptySubscription::<()>::new(),
+            );
+            let second = RootNode::new(
+                second::Query,
+                EmptyMutation::<()>::new(),
+                EmptySubscription::<()>::new(),
+            );
+
+            assert_ne!(first.schema_hash(), second.schema_hash());
+        }
+    }
+
+    #[cfg(feature = "schema-language")]
+    mod sdl_options {
+        use pretty_assertions::assert_eq;
+
+        use crate::{
+            graphql_object, EmptyMutation, EmptySubscription, GraphQLObject, RootNode, SdlOptions,
+        };
+
+        #[derive(GraphQLObject)]
+        /// A cake.
+        struct Cake {
+            /// Whether the cake is fresh.
+            fresh: bool,
+            amount: i32,
+        }
+
+        #[derive(GraphQLObject)]
+        struct Bun {
+            name: String,
+        }
+
+        struct Query;
+
+        #[graphql_object]
+        impl Query {
+            fn cake() -> Cake {
+                Cake {
+                    fresh: true,
+                    amount: 1,
+                }
+            }
+
+            fn bun() -> Bun {
+                Bun { name: "bun".into() }
+            }
+        }
+
+        fn schema() -> RootNode<'static, Query, EmptyMutation, EmptySubscription> {
+            RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+        }
+
+        #[test]
+        fn default_matches_schema_language() {
+            let schema = schema();
+
+            assert_eq!(
+                schema.as_sdl(SdlOptions::default()),
+                schema.as_schema_language(),
+            );
+        }
+
+        #[test]
+        fn sorts_types_and_fields() {
+            assert_eq!(
+                schema().as_sdl(SdlOptions::default().sorted(true).descriptions(false)),
+                "type Bun {\n  name: String!\n}\n\n\
+                 type Cake {\n  amount: Int!\n  fresh: Boolean!\n}\n\n\
+                 type Query {\n  bun: Bun!\n  cake: Cake!\n}\n\n\
+                 schema {\n  query: Query\n}\n",
+            );
+        }
+
+        #[test]
+        fn writes_the_same_output() {
+            let schema = schema();
+
+            for opts in [
+                SdlOptions::default(),
+                SdlOptions::default().sorted(true),
+                SdlOptions::default()
+                    .descriptions(false)
+                    .builtin_scalars(true),
+            ] {
+                let mut sdl = String::new();
+                schema.write_sdl(&mut sdl, opts).unwrap();
+
+                assert_eq!(sdl, schema.as_sdl(opts), "options: {opts:?}");
+            }
+        }
+
+        #[test]
+        fn prints_descriptions_and_builtin_scalars() {
+            assert_eq!(
+                schema().as_sdl(SdlOptions::default().sorted(true).builtin_scalars(true)),
+                "scalar Boolean\n\n\
+                 type Bun {\n  name: String!\n}\n\n\
+                 \"A cake.\"\n\
+                 type Cake {\n  amount: Int!\n  \"Whether the cake is fresh.\"\n  fresh: Boolean!\n}\n\n\
+                 scalar Int\n\n\
+                 type Query {\n  bun: Bun!\n  cake: Cake!\n}\n\n\
+                 scalar String\n\n\
+                 schema {\n  query: Query\n}\n",
+            );
+        }
+    }
+
+    mod custom_directives {
+        use crate::{
+            execute_sync, graphql_object, graphql_value, graphql_vars,
+            schema::{meta::Argument, model::DirectiveLocation},
+            DirectiveRegisterError, DirectiveType, EmptyMutation, EmptySubscription, GraphQLError,
+            RootNode, RuleError, Type,
+        };
+
+        struct Query;
+
+        #[graphql_object]
+        impl Query {
+            fn value() -> i32 {
+                42
+            }
+        }
+
+        fn schema() -> RootNode<'static, Query, EmptyMutation, EmptySubscription> {
+            RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+                .with_directive(
+                    DirectiveType::new(
+                        "cacheControl",
+                        &[DirectiveLocation::Field],
+                        &[Argument::new("maxAge", Type::Named("Int".into()))],
+                        false,
+                    )
+                    .description("Controls caching of the field."),
+                )
+                .unwrap()
+        }
+
+        #[test]
+        fn is_introspected() {
+            let (res, errs) = execute_sync(
+                r#"{
+                    __schema {
+                        directives {
+                            name
+                            description
+                            locations
+                            args { name type { name } }
+                            isRepeatable
+                        }
+                    }
+                }"#,
+                None,
+                &schema(),
+                &graphql_vars! {},
+                &(),
+            )
+            .unwrap();
+
+            assert_eq!(errs, []);
+            let directives = res
+                .as_object_value()
+                .and_then(|o| o.get_field_value("__schema"))
+                .and_then(|s| s.as_object_value())
+                .and_then(|s| s.get_field_value("directives"))
+                .and_then(|d| d.as_list_value())
+                .unwrap();
+            assert!(directives.contains(&graphql_value!({
+                "name": "cacheControl",
+                "description": "Controls caching of the field.",
+                "locations": ["FIELD"],
+                "args": [{"name": "maxAge", "type": {"name": "Int"}}],
+                "isRepeatable": false,
+            })));
+        }
+
+        #[test]
+        fn is_allowed_at_declared_location() {
+            assert_eq!(
+                execute_sync(
+                    "{ value @cacheControl(maxAge: 30) }",
+                    None,
+                    &schema(),
+                    &graphql_vars! {},
+                    &(),
+                ),
+                Ok((graphql_value!({"value": 42}), vec![])),
+            );
+        }
+
+        #[test]
+        fn is_rejected_at_undeclared_location() {
+            assert_eq!(
+                execute_sync(
+                    "query @cacheControl(maxAge: 30) { value }",
+                    None,
+                    &schema(),
+                    &graphql_vars! {},
+                    &(),
+                ),
+                Err(GraphQLError::ValidationError(vec![RuleError::new(
+                    r#"Directive "cacheControl" may not be used on query"#,
+                    &[crate::parser::SourcePosition::new(6, 0, 6)],
+                )])),
+            );
+        }
+
+        #[cfg(feature = "schema-language")]
+        #[test]
+        fn is_in_schema_language() {
+            assert!(schema().as_schema_language().contains(
+                "\"Controls caching of the field.\"\n\
+                 directive @cacheControl(maxAge: Int) on FIELD\n",
+            ));
+        }
+
+        #[test]
+        fn errors_on_unknown_argument_type() {
+            let res = RootNode::new(
+                Query,
+                EmptyMutation::<()>::new(),
+                EmptySubscription::<()>::new(),
+            )
+            .with_directive(DirectiveType::new(
+                "cacheControl",
+                &[DirectiveLocation::Field],
+                &[Argument::new("maxAge", Type::Named("Duration".into()))],
+                false,
+            ));
+
+            assert_eq!(
+                res.err(),
+                Some(DirectiveRegisterError::UnknownArgumentType {
+                    directive: "cacheControl".into(),
+                    argument: "maxAge".into(),
+                    arg_type: "Duration".into(),
+                }),
+            );
+        }
+    }
+
+    mod validate_document {
+        use crate::{
+            graphql_object, parser::SourcePosition, EmptyMutation, EmptySubscription, RootNode,
+            RuleError,
+        };
+
+        struct Query;
+
+        #[graphql_object]
+        impl Query {
+            fn value(multiplier: i32) -> i32 {
+                42 * multiplier
+            }
+        }
+
+        fn schema() -> RootNode<'static, Query, EmptyMutation, EmptySubscription> {
+            RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+        }
+
+        #[test]
+        fn accepts_valid_document() {
+            assert_eq!(schema().validate_document("{ value(multiplier: 2) }"), []);
+        }
+
+        #[test]
+        fn reports_all_errors() {
+            assert_eq!(
+                schema().validate_document("{ value(multiplier: 2, unknown: 1) missing }"),
+                [
+                    RuleError::new(
+                        r#"Unknown argument "unknown" on field "value" of type "Query""#,
+                        &[SourcePosition::new(23, 0, 23)],
+                    ),
+                    RuleError::new(
+                        r#"Unknown field "missing" on type "Query""#,
+                        &[SourcePosition::new(35, 0, 35)],
+                    ),
+                ],
+            );
+        }
+
+        #[test]
+        fn reports_parse_error() {
+            assert_eq!(
+                schema().validate_document("{ value(multiplier: 2 }"),
+                [RuleError::new(
+                    r#"Unexpected "}""#,
+                    &[SourcePosition::new(22, 0, 22)],
+                )],
+            );
+        }
+    }
+
+    mod coerce_variables {
+        use crate::{
+            graphql_input_value, graphql_object, graphql_vars, parser::SourcePosition, Definition,
+            EmptyMutation, EmptySubscription, GraphQLInputObject, RootNode, RuleError, Variables,
+        };
+
+        #[derive(GraphQLInputObject)]
+        struct Page {
+            offset: i32,
+            #[graphql(default = 10)]
+            limit: i32,
+        }
+
+        struct Query;
+
+        #[graphql_object]
+        impl Query {
+            fn sum(ids: Option<Vec<i32>>, offset: i32) -> i32 {
+                ids.unwrap_or_default().into_iter().sum::<i32>() + offset
+            }
+
+            fn window(page: Page) -> i32 {
+                page.offset + page.limit
+            }
+        }
+
+        fn coerce(query: &str, vars: Variables) -> Result<Variables, Vec<RuleError>> {
+            let schema = RootNode::new(
+                Query,
+                EmptyMutation::<()>::new(),
+                EmptySubscription::<()>::new(),
+            );
+            let doc = crate::parser::parse_document_source(query, &schema.schema).unwrap();
+            match &doc[0] {
+                Definition::Operation(op) => schema.coerce_variables(op, &vars),
+                Definition::Fragment(_) => unreachable!(),
+            }
+        }
+
+        #[test]
+        fn applies_defaults() {
+            let vars = coerce(
+                "query($ids: [Int!] = [1, 2], $offset: Int = 3) { sum(ids: $ids, offset: $offset) }",
+                graphql_vars! {"offset": 4},
+            )
+            .unwrap();
+
+            // Defaults are spanned, so compare their representations only.
+            assert_eq!(vars["ids"].to_string(), "[1, 2]");
+            assert_eq!(vars.get("offset"), Some(&graphql_input_value!(4)));
+        }
+
+        #[test]
+        fn reports_default_violating_non_null_inner_type() {
+            assert_eq!(
+                coerce(
+                    "query($ids: [Int!] = [1, null]) { sum(ids: $ids, offset: 0) }",
+                    graphql_vars! {},
+                ),
+                Err(vec![RuleError::new(
+                    r#"Variable "$ids" got invalid value. In element #1: Expected "Int!", found null."#,
+                    &[SourcePosition::new(21, 0, 21)],
+                )]),
+            );
+        }
+
+        #[test]
+        fn ignores_default_if_value_is_provided() {
+            let vars = coerce(
+                "query($ids: [Int!] = [1, null]) { sum(ids: $ids, offset: 0) }",
+                graphql_vars! {"ids": [3]},
+            )
+            .unwrap();
+
+            assert_eq!(vars.get("ids"), Some(&graphql_input_value!([3])));
+        }
+
+        #[test]
+        fn drops_undeclared_variables() {
+            let vars = coerce(
+                "query($offset: Int!) { sum(offset: $offset) }",
+                graphql_vars! {"offset": 1, "limit": 2},
+            )
+            .unwrap();
+
+            assert_eq!(vars, graphql_vars! {"offset": 1});
+        }
+
+        #[test]
+        fn coerces_values_to_declared_types() {
+            let vars = coerce(
+                "query($ids: [Int!], $page: Page!) { sum(ids: $ids, offset: 0) window(page: $page) }",
+                graphql_vars! {"ids": 1, "page": {"offset": 5}},
+            )
+            .unwrap();
+
+            assert_eq!(vars.get("ids"), Some(&graphql_input_value!([1])));
+            assert_eq!(vars["page"].to_string(), "{offset: 5, limit: 10}");
+        }
+
+        #[test]
+        fn reports_all_errors() {
+            assert_eq!(
+                coerce(
+                    "query($ids: [Int!] = [null], $offset: Int!) { sum(ids: $ids, offset: $offset) }",
+                    graphql_vars! {},
+                ),
+                Err(vec![
+                    RuleError::new(
+                        r#"Variable "$ids" got invalid value. In element #0: Expected "Int!", found null."#,
+                        &[SourcePosition::new(21, 0, 21)],
+                    ),
+                    RuleError::new(
+                        r#"Variable "$offset" of required type "Int!" was not provided."#,
+                        &[SourcePosition::new(29, 0, 29)],
+                    ),
+                ]),
+            );
+        }
+    }
+
+    mod root_type_renaming {
+        use crate::{
+            execute_sync, graphql_object, graphql_value, graphql_vars, EmptySubscription,
+            OperationType, RootNode, RootTypeRenameError,
+        };
+
+        struct Query;
+
+        #[graphql_object]
+        impl Query {
+            fn ping() -> bool {
+                true
+            }
+
+            fn root() -> Query {
+                Query
+            }
+        }
+
+        struct Mutation;
+
+        #[graphql_object]
+        impl Mutation {
+            fn touch() -> Query {
+                Query
+            }
+        }
+
+        fn schema() -> RootNode<'static, Query, Mutation, EmptySubscription> {
+            RootNode::new(Query, Mutation, EmptySubscription::new())
+                .with_root_type_name(OperationType::Query, "RootQuery")
+                .unwrap()
+                .with_root_type_description(OperationType::Query, "Root of all queries.")
+                .with_root_type_name(OperationType::Mutation, "RootMutation")
+                .unwrap()
+                .with_root_type_name(OperationType::Subscription, "RootSubscription")
+                .unwrap()
+        }
+
+        #[test]
+        fn introspects_renamed_types() {
+            let doc = r#"{
+                __schema {
+                    queryType { name description }
+                    mutationType { name }
+                    subscriptionType { name }
+                }
+                root: __type(name: "RootQuery") {
+                    fields(includeDeprecated: true) {
+                        name
+                        type { ofType { name } }
+                    }
+                }
+            }"#;
+
+            assert_eq!(
+                execute_sync(doc, None, &schema(), &graphql_vars! {}, &()),
+                Ok((
+                    graphql_value!({
+                        "__schema": {
+                            "queryType": {
+                                "name": "RootQuery",
+                                "description": "Root of all queries.",
+                            },
+                            "mutationType": {"name": "RootMutation"},
+                            "subscriptionType": null,
+                        },
+                        "root": {"fields": [
+                            {"name": "ping", "type": {"ofType": {"name": "Boolean"}}},
+                            {"name": "root", "type": {"ofType": {"name": "RootQuery"}}},
+                        ]},
+                    }),
+                    vec![],
+                )),
+            );
+        }
+
+        #[test]
+        fn resolves_renamed_types() {
+            let doc = r#"{
+                __typename
+                ... on RootQuery { ping }
+                root { __typename ...Root }
+            }
+
+            fragment Root on RootQuery { ping }"#;
+
+            assert_eq!(
+                execute_sync(doc, None, &schema(), &graphql_vars! {}, &()),
+                Ok((
+                    graphql_value!({
+                        "__typename": "RootQuery",
+                        "ping": true,
+                        "root": {"__typename": "RootQuery", "ping": true},
+                    }),
+                    vec![],
+                )),
+            );
+
+            assert_eq!(
+                execute_sync(
+                    "mutation { __typename touch { __typename } }",
+                    None,
+                    &schema(),
+                    &graphql_vars! {},
+                    &(),
+                ),
+                Ok((
+                    graphql_value!({
+                        "__typename": "RootMutation",
+                        "touch": {"__typename": "RootQuery"},
+                    }),
+                    vec![],
+                )),
+            );
+        }
+
+        #[test]
+        fn rejects_original_names() {
+            let res = execute_sync(
+                "{ ... on Query { ping } }",
+                None,
+                &schema(),
+                &graphql_vars! {},
+                &(),
+            );
+
+            assert!(res.is_err(), "expected validation error, got: {res:?}");
+        }
+
+        #[cfg(feature = "schema-language")]
+        #[test]
+        fn prints_renamed_types() {
+            let sdl = schema().as_sdl(Default::default());
+
+            assert!(sdl.contains("query: RootQuery"), "{sdl}");
+            assert!(sdl.contains("mutation: RootMutation"), "{sdl}");
+            assert!(sdl.contains("\"Root of all queries.\""), "{sdl}");
+            assert!(sdl.contains("type RootQuery {"), "{sdl}");
+            assert!(sdl.contains("root: RootQuery!"), "{sdl}");
+            assert!(sdl.contains("touch: RootQuery!"), "{sdl}");
+            assert!(!sdl.contains(" Query"), "{sdl}");
+        }
+
+        #[test]
+        fn errors_on_existing_name() {
+            let res = RootNode::new(Query, Mutation, EmptySubscription::<()>::new())
+                .with_root_type_name(OperationType::Query, "Boolean");
+
+            assert_eq!(
+                res.err(),
+                Some(RootTypeRenameError::ExistingType("Boolean".into())),
+            );
+        }
+
+        #[test]
+        fn errors_on_invalid_name() {
+            let res = RootNode::new(Query, Mutation, EmptySubscription::<()>::new())
+                .with_root_type_name(OperationType::Query, "Root-Query");
+
+            assert_eq!(
+                res.err(),
+                Some(RootTypeRenameError::InvalidName("Root-Query".into())),
+            );
+        }
+    }
+
+    mod introspect_types {
+        use std::sync::{Arc, Mutex};
+
+        use crate::{
+            graphql_object, DefaultScalarValue, EmptyMutation, EmptySubscription, ExecutionResult,
+            FieldInfo, FieldMiddleware, GraphQLEnum, GraphQLInputObject, GraphQLObject,
+            GraphQLUnion, RootNode, Value,
+        };
+
+        #[derive(GraphQLEnum)]
+        enum Episode {
+            NewHope,
+        }
+
+        #[derive(GraphQLInputObject)]
+        struct Filter {
+            episode: Episode,
+        }
+
+        #[derive(GraphQLObject)]
+        struct Human {
+            name: String,
+        }
+
+        #[derive(GraphQLObject)]
+        struct Droid {
+            serial: i32,
+        }
+
+        #[derive(GraphQLUnion)]
+        enum Character {
+            Human(Human),
+            Droid(Droid),
+        }
+
+        struct Query;
+
+        #[graphql_object]
+        impl Query {
+            fn hero(_filter: Option<Filter>) -> Character {
+                Character::Human(Human {
+                    name: "Luke".into(),
+                })
+            }
+        }
+
+        fn introspected_names(names: &[&str]) -> Vec<String> {
+            let schema = RootNode::new(
+                Query,
+                EmptyMutation::<()>::new(),
+                EmptySubscription::<()>::new(),
+            );
+            let result = schema.introspect_types(names);
+            let mut names = result
+                .as_object_value()
+                .and_then(|o| o.get_field_value("__schema"))
+                .and_then(Value::as_object_value)
+                .and_then(|o| o.get_field_value("types"))
+                .and_then(Value::as_list_value)
+                .expect("`types` list is always present")
+                .iter()
+                .map(|t| {
+                    let name = t.as_object_value().unwrap().get_field_value("name");
+                    name.unwrap().as_string_value().unwrap().to_owned()
+                })
+                .collect::<Vec<_>>();
+            names.sort_unstable();
+            names
+        }
+
+        #[test]
+        fn includes_field_and_argument_types() {
+            assert_eq!(
+                introspected_names(&["Query"]),
+                ["Character", "Filter", "Query"],
+            );
+        }
+
+        #[test]
+        fn includes_possible_types() {
+            assert_eq!(
+                introspected_names(&["Character"]),
+                ["Character", "Droid", "Human"],
+            );
+        }
+
+        #[test]
+        fn includes_input_field_types() {
+            assert_eq!(
+                introspected_names(&["Filter", "Droid"]),
+                ["Droid", "Episode", "Filter", "Int"],
+            );
+        }
+
+        #[test]
+        fn ignores_unknown_types() {
+            assert_eq!(introspected_names(&["Unknown", "Episode"]), ["Episode"]);
+            assert!(introspected_names(&[]).is_empty());
+        }
+
+        #[test]
+        fn preserves_introspection_shape() {
+            let schema = RootNode::new(
+                Query,
+                EmptyMutation::<()>::new(),
+                EmptySubscription::<()>::new(),
+            );
+            let full = schema.introspect(Default::default());
+            let partial = schema.introspect_types(&["Human"]);
+
+            let schema_field = |v: &Value, f: &str| {
+                v.as_object_value()
+                    .and_then(|o| o.get_field_value("__schema"))
+                    .and_then(Value::as_object_value)
+                    .and_then(|o| o.get_field_value(f))
+                    .cloned()
+            };
+            for field in [
+                "queryType",
+                "mutationType",
+                "subscriptionType",
+                "directives",
+            ] {
+                assert_eq!(
+                    schema_field(&partial, field),
+                    schema_field(&full, field),
+                    "field: {field}",
+                );
+            }
+
+            let fields = |v: &Value| {
+                v.as_object_value()
+                    .and_then(|o| o.get_field_value("__schema"))
+                    .and_then(Value::as_object_value)
+                    .map(|o| o.iter().map(|(k, _)| k.clone()).collect::<Vec<_>>())
+            };
+            assert_eq!(fields(&partial), fields(&full));
+
+            let types = schema_field(&full, "types").unwrap();
+            for ty in schema_field(&partial, "types")
+                .unwrap()
+                .as_list_value()
+                .unwrap()
+            {
+                assert!(types.as_list_value().unwrap().contains(ty), "type: {ty}");
+            }
+        }
+
+        #[test]
+        fn never_resolves_unrequested_types() {
+            #[derive(Clone, Default)]
+            struct TypeNames(Arc<Mutex<Vec<String>>>);
+
+            impl FieldMiddleware<DefaultScalarValue> for TypeNames {
+                fn around_field(
+                    &self,
+                    field: &FieldInfo<'_>,
+                    next: &mut dyn FnMut() -> ExecutionResult,
+                ) -> ExecutionResult {
+                    let res = next();
+                    if (field.parent_type_name(), field.field_name()) == ("__Type", "name") {
+                        if let Ok(Some(name)) = res.as_ref().map(Value::as_string_value) {
+                            self.0.lock().unwrap().push(name.into());
+                        }
+                    }
+                    res
+                }
+            }
+
+            let resolved = TypeNames::default();
+            let schema = RootNode::new(
+                Query,
+                EmptyMutation::<()>::new(),
+                EmptySubscription::<()>::new(),
+            )
+            .with_field_middleware(resolved.clone());
+
+            let _ = schema.introspect(Default::default());
+            assert!(resolved.0.lock().unwrap().contains(&"Droid".into()));
+
+            resolved.0.lock().unwrap().clear();
+            let _ = schema.introspect_types(&["Human"]);
+            let resolved = resolved.0.lock().unwrap();
+            assert!(resolved.contains(&"Human".into()));
+            for name in ["Droid", "Character", "Filter", "Episode"] {
+                assert!(!resolved.contains(&name.into()), "type: {name}");
+            }
+        }
+    }
 }
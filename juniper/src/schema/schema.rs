@@ -56,6 +56,10 @@ where
         self.query_type.concrete_type_name(context, info)
     }
 
+    fn typename_override(&self, context: &Self::Context, info: &Self::TypeInfo) -> Option<String> {
+        self.query_type.typename_override(context, info)
+    }
+
     fn resolve_field(
         &self,
         info: &Self::TypeInfo,
@@ -238,12 +242,32 @@ impl<'a, S: ScalarValue + 'a> TypeType<'a, S> {
         }
     }
 
-    fn input_fields(&self) -> Option<&[Argument<S>]> {
+    fn input_fields(
+        &self,
+        #[graphql(default = false)] include_deprecated: Option<bool>,
+    ) -> Option<Vec<&Argument<S>>> {
         match self {
             TypeType::Concrete(&MetaType::InputObject(InputObjectMeta {
                 ref input_fields,
                 ..
-            })) => Some(input_fields.as_slice()),
+            })) => Some(
+                input_fields
+                    .iter()
+                    .filter(|f| {
+                        include_deprecated.unwrap_or_default()
+                            || !f.deprecation_status.is_deprecated()
+                    })
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+
+    fn is_one_of(&self) -> Option<bool> {
+        match self {
+            Self::Concrete(MetaType::InputObject(InputObjectMeta { is_one_of, .. })) => {
+                Some(*is_one_of)
+            }
             _ => None,
         }
     }
@@ -388,6 +412,14 @@ impl<'a, S: ScalarValue + 'a> Argument<'a, S> {
     fn default_value_(&self) -> Option<String> {
         self.default_value.as_ref().map(ToString::to_string)
     }
+
+    fn is_deprecated(&self) -> bool {
+        self.deprecation_status.is_deprecated()
+    }
+
+    fn deprecation_reason(&self) -> Option<&str> {
+        self.deprecation_status.reason()
+    }
 }
 
 #[graphql_object(name = "__EnumValue", internal)]
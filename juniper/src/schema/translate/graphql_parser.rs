@@ -3,10 +3,12 @@ use std::{boxed::Box, collections::BTreeMap};
 use graphql_parser::{
     query::{Directive as ExternalDirective, Number as ExternalNumber, Type as ExternalType},
     schema::{
-        Definition, Document, EnumType as ExternalEnum, EnumValue as ExternalEnumValue,
-        Field as ExternalField, InputObjectType as ExternalInputObjectType,
-        InputValue as ExternalInputValue, InterfaceType as ExternalInterfaceType,
-        ObjectType as ExternalObjectType, ScalarType as ExternalScalarType, SchemaDefinition, Text,
+        Definition, DirectiveDefinition as ExternalDirectiveDefinition,
+        DirectiveLocation as ExternalDirectiveLocation, Document, EnumType as ExternalEnum,
+        EnumValue as ExternalEnumValue, Field as ExternalField,
+        InputObjectType as ExternalInputObjectType, InputValue as ExternalInputValue,
+        InterfaceType as ExternalInterfaceType, ObjectType as ExternalObjectType,
+        ScalarType as ExternalScalarType, SchemaDefinition, Text,
         TypeDefinition as ExternalTypeDefinition, UnionType as ExternalUnionType,
         Value as ExternalValue,
     },
@@ -17,7 +19,7 @@ use crate::{
     ast::{InputValue, Type},
     schema::{
         meta::{Argument, DeprecationStatus, EnumValue, Field, MetaType},
-        model::SchemaType,
+        model::{DirectiveLocation, DirectiveType, SchemaType},
         translate::SchemaTranslator,
     },
     value::ScalarValue,
@@ -56,6 +58,16 @@ where
             .collect();
         doc.definitions.append(&mut types);
 
+        // Translate custom directive defs.
+        let mut directives = input
+            .directive_list()
+            .into_iter()
+            .filter(|d| !d.is_builtin())
+            .map(GraphQLParserTranslator::translate_directive)
+            .map(Definition::DirectiveDefinition)
+            .collect();
+        doc.definitions.append(&mut directives);
+
         doc.definitions
             .push(Definition::SchemaDefinition(SchemaDefinition {
                 position: Pos::default(),
@@ -90,7 +102,7 @@ impl GraphQLParserTranslator {
                 .default_value
                 .as_ref()
                 .map(|x| GraphQLParserTranslator::translate_value(x)),
-            directives: vec![],
+            directives: generate_directives(&input.deprecation_status),
         }
     }
 
@@ -152,7 +164,7 @@ impl GraphQLParserTranslator {
         }
     }
 
-    fn translate_meta<'a, S, T>(input: &'a MetaType<S>) -> ExternalTypeDefinition<'a, T>
+    pub(crate) fn translate_meta<'a, S, T>(input: &'a MetaType<S>) -> ExternalTypeDefinition<'a, T>
     where
         S: ScalarValue,
         T: Text<'a>,
@@ -208,7 +220,15 @@ impl GraphQLParserTranslator {
                     position: Pos::default(),
                     description: x.description.as_ref().map(|s| From::from(s.as_str())),
                     name: From::from(x.name.as_ref()),
-                    directives: vec![],
+                    directives: if x.is_one_of {
+                        vec![ExternalDirective {
+                            position: Pos::default(),
+                            name: From::from("oneOf"),
+                            arguments: vec![],
+                        }]
+                    } else {
+                        vec![]
+                    },
                     fields: x
                         .input_fields
                         .iter()
@@ -238,6 +258,57 @@ impl GraphQLParserTranslator {
         }
     }
 
+    fn translate_directive<'a, S, T>(
+        input: &'a DirectiveType<S>,
+    ) -> ExternalDirectiveDefinition<'a, T>
+    where
+        S: ScalarValue,
+        T: Text<'a>,
+    {
+        ExternalDirectiveDefinition {
+            position: Pos::default(),
+            description: input.description.clone(),
+            name: From::from(input.name.as_str()),
+            arguments: input
+                .arguments
+                .iter()
+                .map(GraphQLParserTranslator::translate_argument)
+                .collect(),
+            repeatable: input.is_repeatable,
+            locations: input
+                .locations
+                .iter()
+                .map(GraphQLParserTranslator::translate_directive_location)
+                .collect(),
+        }
+    }
+
+    fn translate_directive_location(input: &DirectiveLocation) -> ExternalDirectiveLocation {
+        match input {
+            DirectiveLocation::Query => ExternalDirectiveLocation::Query,
+            DirectiveLocation::Mutation => ExternalDirectiveLocation::Mutation,
+            DirectiveLocation::Subscription => ExternalDirectiveLocation::Subscription,
+            DirectiveLocation::Field => ExternalDirectiveLocation::Field,
+            DirectiveLocation::Schema => ExternalDirectiveLocation::Schema,
+            DirectiveLocation::Scalar => ExternalDirectiveLocation::Scalar,
+            DirectiveLocation::Object => ExternalDirectiveLocation::Object,
+            DirectiveLocation::ArgumentDefinition => ExternalDirectiveLocation::ArgumentDefinition,
+            DirectiveLocation::Interface => ExternalDirectiveLocation::Interface,
+            DirectiveLocation::Union => ExternalDirectiveLocation::Union,
+            DirectiveLocation::Enum => ExternalDirectiveLocation::Enum,
+            DirectiveLocation::InputObject => ExternalDirectiveLocation::InputObject,
+            DirectiveLocation::FragmentDefinition => ExternalDirectiveLocation::FragmentDefinition,
+            DirectiveLocation::FieldDefinition => ExternalDirectiveLocation::FieldDefinition,
+            DirectiveLocation::VariableDefinition => ExternalDirectiveLocation::VariableDefinition,
+            DirectiveLocation::FragmentSpread => ExternalDirectiveLocation::FragmentSpread,
+            DirectiveLocation::InlineFragment => ExternalDirectiveLocation::InlineFragment,
+            DirectiveLocation::EnumValue => ExternalDirectiveLocation::EnumValue,
+            DirectiveLocation::InputFieldDefinition => {
+                ExternalDirectiveLocation::InputFieldDefinition
+            }
+        }
+    }
+
     fn translate_enum_value<'a, T>(input: &'a EnumValue) -> ExternalEnumValue<'a, T>
     where
         T: Text<'a>,
@@ -0,0 +1,781 @@
+//! Validation of [`SchemaType`]s against the type system rules of the
+//! [GraphQL specification][0].
+//!
+//! [0]: https://spec.graphql.org/October2021#sec-Type-System
+
+use std::{collections::HashSet, fmt};
+
+use crate::{
+    ast::Type,
+    schema::{
+        meta::{Argument, Field, InterfaceMeta, MetaType, ObjectMeta},
+        model::SchemaType,
+    },
+    value::ScalarValue,
+};
+
+/// Names of the introspection types [GraphQL specification][0] reserves the
+/// `__` prefix for.
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Schema-Introspection
+const INTROSPECTION_TYPES: &[&str] = &[
+    "__Schema",
+    "__Type",
+    "__TypeKind",
+    "__Field",
+    "__InputValue",
+    "__EnumValue",
+    "__Directive",
+    "__DirectiveLocation",
+];
+
+/// Violation of the type system rules found by
+/// [`RootNode::validate_schema()`].
+///
+/// Elements of a schema are referred by their [schema coordinates][0], like
+/// `Type.field(argument:)`.
+///
+/// [`RootNode::validate_schema()`]: crate::RootNode::validate_schema
+/// [0]: https://spec.graphql.org/draft#sec-Schema-Coordinates
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SchemaValidationError {
+    /// Type referenced by a schema element isn't registered in the schema.
+    UnknownType {
+        /// Coordinate of the element referencing the type.
+        coordinate: String,
+
+        /// Name of the referenced type.
+        type_name: String,
+    },
+
+    /// Multiple fields, arguments or enum values with the same name are
+    /// defined.
+    DuplicateName {
+        /// Coordinate of the duplicated element.
+        coordinate: String,
+    },
+
+    /// Name of a schema element starts with the `__` prefix reserved for
+    /// introspection.
+    ReservedName {
+        /// Coordinate of the element.
+        coordinate: String,
+    },
+
+    /// Type is declared to implement a type which is not an interface.
+    NotAnInterface {
+        /// Name of the implementing type.
+        type_name: String,
+
+        /// Name of the implemented type.
+        interface_name: String,
+    },
+
+    /// Union contains a type which is not an object.
+    NotAnObject {
+        /// Name of the union.
+        union_name: String,
+
+        /// Name of the contained type.
+        type_name: String,
+    },
+
+    /// Type implementing an interface doesn't implement the interfaces this
+    /// one implements.
+    MissingTransitiveInterface {
+        /// Name of the implementing type.
+        type_name: String,
+
+        /// Name of the directly implemented interface.
+        interface_name: String,
+
+        /// Name of the interface implemented by the `interface_name` one.
+        transitive_name: String,
+    },
+
+    /// Type implementing an interface doesn't define one of its fields.
+    MissingInterfaceField {
+        /// Coordinate of the missing field on the implementing type.
+        coordinate: String,
+
+        /// Name of the implemented interface.
+        interface_name: String,
+    },
+
+    /// Field of a type implementing an interface has a type which is not a
+    /// subtype of the one of the interface field.
+    IncompatibleInterfaceField {
+        /// Coordinate of the field on the implementing type.
+        coordinate: String,
+
+        /// Name of the implemented interface.
+        interface_name: String,
+
+        /// Type of the interface field.
+        expected: String,
+
+        /// Type of the field on the implementing type.
+        found: String,
+    },
+
+    /// Field of a type implementing an interface doesn't accept one of the
+    /// arguments of the interface field.
+    MissingInterfaceArgument {
+        /// Coordinate of the missing argument on the implementing type.
+        coordinate: String,
+
+        /// Name of the implemented interface.
+        interface_name: String,
+    },
+
+    /// Argument of a type implementing an interface has a type different from
+    /// the one of the interface field argument.
+    IncompatibleInterfaceArgument {
+        /// Coordinate of the argument on the implementing type.
+        coordinate: String,
+
+        /// Name of the implemented interface.
+        interface_name: String,
+
+        /// Type of the interface field argument.
+        expected: String,
+
+        /// Type of the argument on the implementing type.
+        found: String,
+    },
+
+    /// Field of a type implementing an interface has a required argument
+    /// missing in the interface field.
+    RequiredAdditionalArgument {
+        /// Coordinate of the argument on the implementing type.
+        coordinate: String,
+
+        /// Name of the implemented interface.
+        interface_name: String,
+    },
+
+    /// Required (non-null and without a default value) argument or input field
+    /// is deprecated.
+    DeprecatedRequired {
+        /// Coordinate of the argument or input field.
+        coordinate: String,
+    },
+}
+
+impl fmt::Display for SchemaValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownType {
+                coordinate,
+                type_name,
+            } => write!(f, "`{coordinate}` references unknown type `{type_name}`"),
+            Self::DuplicateName { coordinate } => {
+                write!(f, "`{coordinate}` is defined multiple times")
+            }
+            Self::ReservedName { coordinate } => write!(
+                f,
+                "`{coordinate}` uses the `__` prefix reserved for introspection",
+            ),
+            Self::NotAnInterface {
+                type_name,
+                interface_name,
+            } => write!(
+                f,
+                "`{type_name}` implements `{interface_name}`, which is not an interface",
+            ),
+            Self::NotAnObject {
+                union_name,
+                type_name,
+            } => write!(
+                f,
+                "Union `{union_name}` contains `{type_name}`, which is not an object",
+            ),
+            Self::MissingTransitiveInterface {
+                type_name,
+                interface_name,
+                transitive_name,
+            } => write!(
+                f,
+                "`{type_name}` implements `{interface_name}`, but not `{transitive_name}` \
+                 implemented by it",
+            ),
+            Self::MissingInterfaceField {
+                coordinate,
+                interface_name,
+            } => write!(
+                f,
+                "`{coordinate}` required by interface `{interface_name}` is not defined",
+            ),
+            Self::IncompatibleInterfaceField {
+                coordinate,
+                interface_name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "`{coordinate}` has type `{found}`, which is not a subtype of `{expected}` \
+                 required by interface `{interface_name}`",
+            ),
+            Self::MissingInterfaceArgument {
+                coordinate,
+                interface_name,
+            } => write!(
+                f,
+                "`{coordinate}` required by interface `{interface_name}` is not defined",
+            ),
+            Self::IncompatibleInterfaceArgument {
+                coordinate,
+                interface_name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "`{coordinate}` has type `{found}`, but interface `{interface_name}` requires \
+                 `{expected}`",
+            ),
+            Self::RequiredAdditionalArgument {
+                coordinate,
+                interface_name,
+            } => write!(
+                f,
+                "`{coordinate}` is required, but missing in interface `{interface_name}`",
+            ),
+            Self::DeprecatedRequired { coordinate } => {
+                write!(f, "`{coordinate}` is required, so cannot be deprecated")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SchemaValidationError {}
+
+impl<'a, S: ScalarValue> SchemaType<'a, S> {
+    /// Validates this schema against the type system rules of the
+    /// [GraphQL specification][0], returning all the found violations.
+    ///
+    /// See [`RootNode::validate_schema()`] for details.
+    ///
+    /// [`RootNode::validate_schema()`]: crate::RootNode::validate_schema
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System
+    pub fn validate(&self) -> Result<(), Vec<SchemaValidationError>> {
+        let mut validator = Validator {
+            schema: self,
+            errors: Vec::new(),
+        };
+
+        let mut types = self.types.values().collect::<Vec<_>>();
+        types.sort_by(|a, b| a.name().cmp(&b.name()));
+        for ty in types {
+            validator.validate_type(ty);
+        }
+
+        if validator.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(validator.errors)
+        }
+    }
+}
+
+/// Collector of [`SchemaValidationError`]s of a [`SchemaType`].
+struct Validator<'s, 'a, S> {
+    schema: &'s SchemaType<'a, S>,
+    errors: Vec<SchemaValidationError>,
+}
+
+impl<'s, 'a, S: ScalarValue> Validator<'s, 'a, S> {
+    fn validate_type(&mut self, ty: &MetaType<'a, S>) {
+        let name = match ty.name() {
+            Some(name) => name,
+            None => return,
+        };
+        if name.starts_with("__") && !INTROSPECTION_TYPES.contains(&name) {
+            self.errors.push(SchemaValidationError::ReservedName {
+                coordinate: name.into(),
+            });
+        }
+
+        match ty {
+            MetaType::Object(ObjectMeta {
+                fields,
+                interface_names,
+                ..
+            })
+            | MetaType::Interface(InterfaceMeta {
+                fields,
+                interface_names,
+                ..
+            }) => {
+                self.validate_fields(name, fields);
+                for interface_name in interface_names {
+                    self.validate_implementation(name, fields, interface_names, interface_name);
+                }
+            }
+            MetaType::InputObject(meta) => {
+                self.validate_arguments(&meta.input_fields, |f| format!("{name}.{f}"));
+            }
+            MetaType::Enum(meta) => {
+                let mut seen = HashSet::new();
+                for value in &meta.values {
+                    let coordinate = format!("{name}.{}", value.name);
+                    self.validate_name(&value.name, &coordinate, &mut seen);
+                }
+            }
+            MetaType::Union(meta) => {
+                for member in &meta.of_type_names {
+                    match self.schema.concrete_type_by_name(member) {
+                        Some(MetaType::Object(_)) => {}
+                        Some(_) => self.errors.push(SchemaValidationError::NotAnObject {
+                            union_name: name.into(),
+                            type_name: member.clone(),
+                        }),
+                        None => self.errors.push(SchemaValidationError::UnknownType {
+                            coordinate: name.into(),
+                            type_name: member.clone(),
+                        }),
+                    }
+                }
+            }
+            MetaType::Scalar(_)
+            | MetaType::List(_)
+            | MetaType::Nullable(_)
+            | MetaType::Placeholder(_) => {}
+        }
+    }
+
+    fn validate_fields(&mut self, type_name: &str, fields: &[Field<'a, S>]) {
+        let is_query = type_name == self.schema.query_type_name;
+        let mut seen = HashSet::new();
+        for field in fields {
+            let coordinate = format!("{type_name}.{}", field.name);
+            // `__typename` field is added to every object and interface, while
+            // `__schema` and `__type` fields are added to the query type by the
+            // schema itself.
+            let is_meta = match field.name.as_str() {
+                "__typename" => true,
+                "__schema" | "__type" => is_query,
+                _ => false,
+            };
+            if !is_meta {
+                self.validate_name(&field.name, &coordinate, &mut seen);
+            }
+            self.validate_reference(&coordinate, &field.field_type);
+            if let Some(args) = &field.arguments {
+                self.validate_arguments(args, |a| format!("{type_name}.{}({a}:)", field.name));
+            }
+        }
+    }
+
+    fn validate_arguments(
+        &mut self,
+        args: &[Argument<'a, S>],
+        coordinate: impl Fn(&str) -> String,
+    ) {
+        let mut seen = HashSet::new();
+        for arg in args {
+            let coordinate = coordinate(&arg.name);
+            self.validate_name(&arg.name, &coordinate, &mut seen);
+            self.validate_reference(&coordinate, &arg.arg_type);
+            if arg.deprecation_status.is_deprecated()
+                && arg.arg_type.is_non_null()
+                && arg.default_value.is_none()
+            {
+                self.errors
+                    .push(SchemaValidationError::DeprecatedRequired { coordinate });
+            }
+        }
+    }
+
+    fn validate_name<'n>(&mut self, name: &'n str, coordinate: &str, seen: &mut HashSet<&'n str>) {
+        if name.starts_with("__") {
+            self.errors.push(SchemaValidationError::ReservedName {
+                coordinate: coordinate.into(),
+            });
+        }
+        if !seen.insert(name) {
+            self.errors.push(SchemaValidationError::DuplicateName {
+                coordinate: coordinate.into(),
+            });
+        }
+    }
+
+    fn validate_reference(&mut self, coordinate: &str, ty: &Type<'_>) {
+        let type_name = ty.innermost_name();
+        if self.schema.concrete_type_by_name(type_name).is_none() {
+            self.errors.push(SchemaValidationError::UnknownType {
+                coordinate: coordinate.into(),
+                type_name: type_name.into(),
+            });
+        }
+    }
+
+    /// Validates the type named `type_name` with the provided `fields` and
+    /// `interface_names` to implement the `interface_name` correctly.
+    ///
+    /// See the [GraphQL specification][0] for the rules.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#IsValidImplementation()
+    fn validate_implementation(
+        &mut self,
+        type_name: &str,
+        fields: &[Field<'a, S>],
+        interface_names: &[String],
+        interface_name: &str,
+    ) {
+        let interface = match self.schema.concrete_type_by_name(interface_name) {
+            Some(MetaType::Interface(i)) => i,
+            Some(_) => {
+                return self.errors.push(SchemaValidationError::NotAnInterface {
+                    type_name: type_name.into(),
+                    interface_name: interface_name.into(),
+                })
+            }
+            None => {
+                return self.errors.push(SchemaValidationError::UnknownType {
+                    coordinate: type_name.into(),
+                    type_name: interface_name.into(),
+                })
+            }
+        };
+
+        for transitive_name in &interface.interface_names {
+            if !interface_names.contains(transitive_name) {
+                self.errors
+                    .push(SchemaValidationError::MissingTransitiveInterface {
+                        type_name: type_name.into(),
+                        interface_name: interface_name.into(),
+                        transitive_name: transitive_name.clone(),
+                    });
+            }
+        }
+
+        for iface_field in &interface.fields {
+            let coordinate = format!("{type_name}.{}", iface_field.name);
+            let field = match fields.iter().find(|f| f.name == iface_field.name) {
+                Some(f) => f,
+                None => {
+                    self.errors
+                        .push(SchemaValidationError::MissingInterfaceField {
+                            coordinate,
+                            interface_name: interface_name.into(),
+                        });
+                    continue;
+                }
+            };
+
+            if !self
+                .schema
+                .is_subtype(&field.field_type, &iface_field.field_type)
+            {
+                self.errors
+                    .push(SchemaValidationError::IncompatibleInterfaceField {
+                        coordinate: coordinate.clone(),
+                        interface_name: interface_name.into(),
+                        expected: iface_field.field_type.to_string(),
+                        found: field.field_type.to_string(),
+                    });
+            }
+
+            let args = field.arguments.as_deref().unwrap_or_default();
+            let iface_args = iface_field.arguments.as_deref().unwrap_or_default();
+            for iface_arg in iface_args {
+                let coordinate = format!("{coordinate}({}:)", iface_arg.name);
+                match args.iter().find(|a| a.name == iface_arg.name) {
+                    Some(arg) if arg.arg_type != iface_arg.arg_type => {
+                        self.errors
+                            .push(SchemaValidationError::IncompatibleInterfaceArgument {
+                                coordinate,
+                                interface_name: interface_name.into(),
+                                expected: iface_arg.arg_type.to_string(),
+                                found: arg.arg_type.to_string(),
+                            })
+                    }
+                    Some(_) => {}
+                    None => self
+                        .errors
+                        .push(SchemaValidationError::MissingInterfaceArgument {
+                            coordinate,
+                            interface_name: interface_name.into(),
+                        }),
+                }
+            }
+            for arg in args {
+                if arg.arg_type.is_non_null()
+                    && arg.default_value.is_none()
+                    && !iface_args.iter().any(|a| a.name == arg.name)
+                {
+                    self.errors
+                        .push(SchemaValidationError::RequiredAdditionalArgument {
+                            coordinate: format!("{coordinate}({}:)", arg.name),
+                            interface_name: interface_name.into(),
+                        });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ast::Type,
+        graphql_interface, graphql_object,
+        schema::meta::{Argument, Field, MetaType},
+        tests::fixtures::starwars::schema::{Database, Query as StarWarsQuery},
+        DefaultScalarValue, EmptyMutation, EmptySubscription, GraphQLEnum, GraphQLInputObject,
+        GraphQLObject, GraphQLUnion, InputValue, RootNode,
+    };
+
+    use super::SchemaValidationError as E;
+
+    #[graphql_interface(for = Human)]
+    struct Node {
+        id: i32,
+    }
+
+    #[graphql_interface(for = Human)]
+    struct Named {
+        name: String,
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(impl = [NodeValue, NamedValue])]
+    struct Human {
+        id: i32,
+        name: String,
+    }
+
+    #[derive(GraphQLUnion)]
+    enum Entity {
+        Human(Human),
+    }
+
+    #[derive(GraphQLInputObject)]
+    struct Filter {
+        name: Option<String>,
+    }
+
+    #[derive(GraphQLEnum)]
+    enum Kind {
+        Person,
+    }
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn node(filter: Option<Filter>, kind: Option<Kind>) -> Option<NodeValue> {
+            let _ = (filter, kind);
+            None
+        }
+
+        fn entity() -> Option<Entity> {
+            None
+        }
+    }
+
+    type Schema = RootNode<'static, Query, EmptyMutation, EmptySubscription>;
+
+    fn schema() -> Schema {
+        RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+    }
+
+    fn meta<'s>(schema: &'s mut Schema, name: &str) -> &'s mut MetaType<'static> {
+        schema.schema.types.get_mut(name).unwrap()
+    }
+
+    fn fields<'s>(
+        schema: &'s mut Schema,
+        name: &str,
+    ) -> &'s mut Vec<Field<'static, DefaultScalarValue>> {
+        match meta(schema, name) {
+            MetaType::Object(o) => &mut o.fields,
+            MetaType::Interface(i) => &mut i.fields,
+            _ => unreachable!(),
+        }
+    }
+
+    fn interface_names<'s>(schema: &'s mut Schema, name: &str) -> &'s mut Vec<String> {
+        match meta(schema, name) {
+            MetaType::Object(o) => &mut o.interface_names,
+            MetaType::Interface(i) => &mut i.interface_names,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn accepts_valid_schema() {
+        assert_eq!(schema().validate_schema(), Ok(()));
+
+        let star_wars = RootNode::new(
+            StarWarsQuery,
+            EmptyMutation::<Database>::new(),
+            EmptySubscription::<Database>::new(),
+        );
+        assert_eq!(star_wars.validate_schema(), Ok(()));
+    }
+
+    #[test]
+    fn reports_all_errors() {
+        let mut schema = schema();
+
+        let name = fields(&mut schema, "Human")[1].clone();
+        let mut reserved = name.clone();
+        reserved.name = "__secret".into();
+        fields(&mut schema, "Human").extend([name, reserved]);
+
+        let mut unknown = fields(&mut schema, "Query")[0].clone();
+        unknown.name = "unknown".into();
+        unknown.field_type = Type::Named("Missing".into());
+        fields(&mut schema, "Query").push(unknown);
+
+        if let MetaType::Enum(e) = meta(&mut schema, "Kind") {
+            let value = e.values[0].clone();
+            e.values.push(value);
+        }
+        if let MetaType::InputObject(i) = meta(&mut schema, "Filter") {
+            i.input_fields
+                .push(Argument::new("other", Type::Named("Unknown".into())));
+        }
+        if let MetaType::Union(u) = meta(&mut schema, "Entity") {
+            u.of_type_names.push("Kind".into());
+        }
+        interface_names(&mut schema, "Human").push("Filter".into());
+
+        assert_eq!(
+            schema.validate_schema(),
+            Err(vec![
+                E::NotAnObject {
+                    union_name: "Entity".into(),
+                    type_name: "Kind".into(),
+                },
+                E::UnknownType {
+                    coordinate: "Filter.other".into(),
+                    type_name: "Unknown".into(),
+                },
+                E::DuplicateName {
+                    coordinate: "Human.name".into(),
+                },
+                E::ReservedName {
+                    coordinate: "Human.__secret".into(),
+                },
+                E::NotAnInterface {
+                    type_name: "Human".into(),
+                    interface_name: "Filter".into(),
+                },
+                E::DuplicateName {
+                    coordinate: "Kind.PERSON".into(),
+                },
+                E::UnknownType {
+                    coordinate: "Query.unknown".into(),
+                    type_name: "Missing".into(),
+                },
+            ]),
+        );
+    }
+
+    #[test]
+    fn checks_interface_implementations() {
+        let mut schema = schema();
+
+        // `Node` requires `Named`, which `Human` doesn't implement anymore.
+        interface_names(&mut schema, "Human").retain(|i| i != "Named");
+        interface_names(&mut schema, "Node").push("Named".into());
+
+        let node_id = &mut fields(&mut schema, "Node")[0];
+        node_id.arguments = Some(vec![
+            Argument::new("first", Type::Named("Int".into())),
+            Argument::new("after", Type::Named("String".into())),
+        ]);
+
+        let human_id = &mut fields(&mut schema, "Human")[0];
+        human_id.field_type = Type::Named("Int".into());
+        human_id.arguments = Some(vec![
+            Argument::new("first", Type::NonNullNamed("Int".into())),
+            Argument::new("filter", Type::Named("Filter".into())),
+            Argument::new("kind", Type::NonNullNamed("Kind".into())),
+        ]);
+
+        let errors = schema.validate_schema().unwrap_err();
+
+        assert_eq!(
+            errors,
+            [
+                E::MissingTransitiveInterface {
+                    type_name: "Human".into(),
+                    interface_name: "Node".into(),
+                    transitive_name: "Named".into(),
+                },
+                E::IncompatibleInterfaceField {
+                    coordinate: "Human.id".into(),
+                    interface_name: "Node".into(),
+                    expected: "Int!".into(),
+                    found: "Int".into(),
+                },
+                E::IncompatibleInterfaceArgument {
+                    coordinate: "Human.id(first:)".into(),
+                    interface_name: "Node".into(),
+                    expected: "Int".into(),
+                    found: "Int!".into(),
+                },
+                E::MissingInterfaceArgument {
+                    coordinate: "Human.id(after:)".into(),
+                    interface_name: "Node".into(),
+                },
+                E::RequiredAdditionalArgument {
+                    coordinate: "Human.id(kind:)".into(),
+                    interface_name: "Node".into(),
+                },
+                E::MissingInterfaceField {
+                    coordinate: "Node.name".into(),
+                    interface_name: "Named".into(),
+                },
+            ],
+        );
+        assert_eq!(
+            errors[0].to_string(),
+            "`Human` implements `Node`, but not `Named` implemented by it",
+        );
+        assert_eq!(
+            errors[1].to_string(),
+            "`Human.id` has type `Int`, which is not a subtype of `Int!` required by interface \
+             `Node`",
+        );
+    }
+
+    #[test]
+    fn rejects_deprecated_required_arguments_and_input_fields() {
+        let mut schema = schema();
+
+        fields(&mut schema, "Query")[0].arguments = Some(vec![
+            Argument::new("required", Type::NonNullNamed("Int".into())).deprecated(None),
+            Argument::new("defaulted", Type::NonNullNamed("Int".into()))
+                .default_value(InputValue::scalar(1))
+                .deprecated(None),
+            Argument::new("optional", Type::Named("Int".into())).deprecated(Some("Old")),
+        ]);
+        if let MetaType::InputObject(i) = meta(&mut schema, "Filter") {
+            i.input_fields.push(
+                Argument::new("id", Type::NonNullNamed("Int".into())).deprecated(Some("Old")),
+            );
+        }
+
+        let errors = schema.validate_schema().unwrap_err();
+
+        assert_eq!(
+            errors,
+            [
+                E::DeprecatedRequired {
+                    coordinate: "Filter.id".into(),
+                },
+                E::DeprecatedRequired {
+                    coordinate: "Query.node(required:)".into(),
+                },
+            ],
+        );
+        assert_eq!(
+            errors[0].to_string(),
+            "`Filter.id` is required, so cannot be deprecated",
+        );
+    }
+}
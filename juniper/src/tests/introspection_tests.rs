@@ -3,6 +3,7 @@ use std::collections::HashSet;
 use crate::{
     graphql_vars,
     introspection::IntrospectionFormat,
+    parser::ParseOptions,
     schema::model::RootNode,
     tests::fixtures::starwars::schema::{Database, Query},
     types::scalars::{EmptyMutation, EmptySubscription},
@@ -213,6 +214,7 @@ async fn test_introspection_directives() {
                     "locations": [
                         "FIELD_DEFINITION",
                         "ENUM_VALUE",
+                        "INPUT_FIELD_DEFINITION",
                     ],
                 },
                 {
@@ -308,3 +310,55 @@ async fn test_builtin_introspection_query_without_descriptions() {
 
     assert_eq!(result, (expected, vec![]));
 }
+
+#[tokio::test]
+async fn test_root_node_introspect() {
+    let database = Database::new();
+    let schema = RootNode::new(
+        Query,
+        EmptyMutation::<Database>::new(),
+        EmptySubscription::<Database>::new(),
+    );
+
+    for format in [
+        IntrospectionFormat::All,
+        IntrospectionFormat::WithoutDescriptions,
+    ] {
+        let (executed, errors) = crate::introspect(&schema, &database, format).unwrap();
+        assert_eq!(errors, vec![]);
+
+        assert_eq!(schema.introspect(format), executed);
+    }
+
+    let mut result = schema.introspect(IntrospectionFormat::default());
+    sort_schema_value(&mut result);
+    assert_eq!(result, schema_introspection_result());
+
+    let mut result = schema.introspect(IntrospectionFormat::WithoutDescriptions);
+    sort_schema_value(&mut result);
+    assert_eq!(result, schema_introspection_result_without_descriptions());
+}
+
+#[test]
+fn test_root_node_introspect_ignores_parse_options() {
+    let schema = RootNode::new(
+        Query,
+        EmptyMutation::<Database>::new(),
+        EmptySubscription::<Database>::new(),
+    );
+    let limited = RootNode::new(
+        Query,
+        EmptyMutation::<Database>::new(),
+        EmptySubscription::<Database>::new(),
+    )
+    .with_parse_options(ParseOptions::new().max_depth(5).max_tokens(10));
+
+    assert_eq!(
+        limited.introspect(IntrospectionFormat::All),
+        schema.introspect(IntrospectionFormat::All),
+    );
+    assert_eq!(
+        limited.introspect_types(&["Human"]),
+        schema.introspect_types(&["Human"]),
+    );
+}
@@ -1,8 +1,10 @@
 use crate::{
     graphql_value, graphql_vars,
+    parser::SourcePosition,
     schema::model::RootNode,
     tests::fixtures::starwars::schema::{Database, Query},
     types::scalars::{EmptyMutation, EmptySubscription},
+    BatchItem, GraphQLError, RuleError,
 };
 
 #[tokio::test]
@@ -424,3 +426,65 @@ async fn interface_inline_fragment_friends() {
         ))
     );
 }
+
+#[tokio::test]
+async fn test_batch_in_request_order() {
+    let database = Database::new();
+    let schema = RootNode::new(
+        Query,
+        EmptyMutation::<Database>::new(),
+        EmptySubscription::<Database>::new(),
+    );
+
+    let items = [
+        BatchItem::new("{ hero { name } }"),
+        BatchItem {
+            document_source: "query A { hero { id } } \
+                              query B($id: String!) { human(id: $id) { name } }",
+            operation_name: Some("B"),
+            variables: graphql_vars! {"id": "1000"},
+        },
+    ];
+
+    assert_eq!(
+        crate::execute_batch(&items, &schema, &database).await,
+        vec![
+            Ok((graphql_value!({"hero": {"name": "R2-D2"}}), vec![])),
+            Ok((
+                graphql_value!({"human": {"name": "Luke Skywalker"}}),
+                vec![]
+            )),
+        ],
+    );
+}
+
+#[tokio::test]
+async fn test_batch_isolates_failures() {
+    let database = Database::new();
+    let schema = RootNode::new(
+        Query,
+        EmptyMutation::<Database>::new(),
+        EmptySubscription::<Database>::new(),
+    );
+
+    let items = [
+        BatchItem::new("{ hero { unknown } }"),
+        BatchItem::new("{ hero { name } }"),
+        BatchItem {
+            operation_name: Some("Missing"),
+            ..BatchItem::new("query A { hero { id } }")
+        },
+    ];
+
+    assert_eq!(
+        crate::execute_batch(&items, &schema, &database).await,
+        vec![
+            Err(GraphQLError::ValidationError(vec![RuleError::new(
+                r#"Unknown field "unknown" on type "Character""#,
+                &[SourcePosition::new(9, 0, 9)],
+            )])),
+            Ok((graphql_value!({"hero": {"name": "R2-D2"}}), vec![])),
+            Err(GraphQLError::UnknownOperationName),
+        ],
+    );
+}
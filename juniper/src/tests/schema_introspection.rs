@@ -221,6 +221,34 @@ pub(crate) fn schema_introspection_result() -> Value {
                   },
                   "isDeprecated": false,
                   "deprecationReason": null
+                },
+                {
+                  "name": "isDeprecated",
+                  "description": null,
+                  "args": [],
+                  "type": {
+                    "kind": "NON_NULL",
+                    "name": null,
+                    "ofType": {
+                      "kind": "SCALAR",
+                      "name": "Boolean",
+                      "ofType": null
+                    }
+                  },
+                  "isDeprecated": false,
+                  "deprecationReason": null
+                },
+                {
+                  "name": "deprecationReason",
+                  "description": null,
+                  "args": [],
+                  "type": {
+                    "kind": "SCALAR",
+                    "name": "String",
+                    "ofType": null
+                  },
+                  "isDeprecated": false,
+                  "deprecationReason": null
                 }
               ],
               "inputFields": null,
@@ -499,7 +527,18 @@ pub(crate) fn schema_introspection_result() -> Value {
                 {
                   "name": "inputFields",
                   "description": null,
-                  "args": [],
+                  "args": [
+                    {
+                      "name": "includeDeprecated",
+                      "description": null,
+                      "type": {
+                        "kind": "SCALAR",
+                        "name": "Boolean",
+                        "ofType": null
+                      },
+                      "defaultValue": "false"
+                    }
+                  ],
                   "type": {
                     "kind": "LIST",
                     "name": null,
@@ -536,6 +575,18 @@ pub(crate) fn schema_introspection_result() -> Value {
                   "isDeprecated": false,
                   "deprecationReason": null
                 },
+                {
+                  "name": "isOneOf",
+                  "description": null,
+                  "args": [],
+                  "type": {
+                    "kind": "SCALAR",
+                    "name": "Boolean",
+                    "ofType": null
+                  },
+                  "isDeprecated": false,
+                  "deprecationReason": null
+                },
                 {
                   "name": "possibleTypes",
                   "description": null,
@@ -1080,11 +1131,59 @@ pub(crate) fn schema_introspection_result() -> Value {
                   "isDeprecated": false,
                   "deprecationReason": null
                 },
+                {
+                  "name": "SCHEMA",
+                  "description": null,
+                  "isDeprecated": false,
+                  "deprecationReason": null
+                },
+                {
+                  "name": "OBJECT",
+                  "description": null,
+                  "isDeprecated": false,
+                  "deprecationReason": null
+                },
+                {
+                  "name": "ARGUMENT_DEFINITION",
+                  "description": null,
+                  "isDeprecated": false,
+                  "deprecationReason": null
+                },
+                {
+                  "name": "INTERFACE",
+                  "description": null,
+                  "isDeprecated": false,
+                  "deprecationReason": null
+                },
+                {
+                  "name": "UNION",
+                  "description": null,
+                  "isDeprecated": false,
+                  "deprecationReason": null
+                },
+                {
+                  "name": "ENUM",
+                  "description": null,
+                  "isDeprecated": false,
+                  "deprecationReason": null
+                },
+                {
+                  "name": "INPUT_OBJECT",
+                  "description": null,
+                  "isDeprecated": false,
+                  "deprecationReason": null
+                },
                 {
                   "name": "ENUM_VALUE",
                   "description": null,
                   "isDeprecated": false,
                   "deprecationReason": null
+                },
+                {
+                  "name": "INPUT_FIELD_DEFINITION",
+                  "description": null,
+                  "isDeprecated": false,
+                  "deprecationReason": null
                 }
               ],
               "possibleTypes": null
@@ -1400,7 +1499,8 @@ pub(crate) fn schema_introspection_result() -> Value {
               "isRepeatable": false,
               "locations": [
                 "FIELD_DEFINITION",
-                "ENUM_VALUE"
+                "ENUM_VALUE",
+                "INPUT_FIELD_DEFINITION"
               ],
               "args": [
                 {
@@ -1625,6 +1725,32 @@ pub(crate) fn schema_introspection_result_without_descriptions() -> Value {
                   },
                   "isDeprecated": false,
                   "deprecationReason": null
+                },
+                {
+                  "name": "isDeprecated",
+                  "args": [],
+                  "type": {
+                    "kind": "NON_NULL",
+                    "name": null,
+                    "ofType": {
+                      "kind": "SCALAR",
+                      "name": "Boolean",
+                      "ofType": null
+                    }
+                  },
+                  "isDeprecated": false,
+                  "deprecationReason": null
+                },
+                {
+                  "name": "deprecationReason",
+                  "args": [],
+                  "type": {
+                    "kind": "SCALAR",
+                    "name": "String",
+                    "ofType": null
+                  },
+                  "isDeprecated": false,
+                  "deprecationReason": null
                 }
               ],
               "inputFields": null,
@@ -1878,7 +2004,17 @@ pub(crate) fn schema_introspection_result_without_descriptions() -> Value {
                 },
                 {
                   "name": "inputFields",
-                  "args": [],
+                  "args": [
+                    {
+                      "name": "includeDeprecated",
+                      "type": {
+                        "kind": "SCALAR",
+                        "name": "Boolean",
+                        "ofType": null
+                      },
+                      "defaultValue": "false"
+                    }
+                  ],
                   "type": {
                     "kind": "LIST",
                     "name": null,
@@ -1914,6 +2050,17 @@ pub(crate) fn schema_introspection_result_without_descriptions() -> Value {
                   "isDeprecated": false,
                   "deprecationReason": null
                 },
+                {
+                  "name": "isOneOf",
+                  "args": [],
+                  "type": {
+                    "kind": "SCALAR",
+                    "name": "Boolean",
+                    "ofType": null
+                  },
+                  "isDeprecated": false,
+                  "deprecationReason": null
+                },
                 {
                   "name": "possibleTypes",
                   "args": [],
@@ -2413,10 +2560,50 @@ pub(crate) fn schema_introspection_result_without_descriptions() -> Value {
                   "isDeprecated": false,
                   "deprecationReason": null
                 },
+                {
+                  "name": "SCHEMA",
+                  "isDeprecated": false,
+                  "deprecationReason": null
+                },
+                {
+                  "name": "OBJECT",
+                  "isDeprecated": false,
+                  "deprecationReason": null
+                },
+                {
+                  "name": "ARGUMENT_DEFINITION",
+                  "isDeprecated": false,
+                  "deprecationReason": null
+                },
+                {
+                  "name": "INTERFACE",
+                  "isDeprecated": false,
+                  "deprecationReason": null
+                },
+                {
+                  "name": "UNION",
+                  "isDeprecated": false,
+                  "deprecationReason": null
+                },
+                {
+                  "name": "ENUM",
+                  "isDeprecated": false,
+                  "deprecationReason": null
+                },
+                {
+                  "name": "INPUT_OBJECT",
+                  "isDeprecated": false,
+                  "deprecationReason": null
+                },
                 {
                   "name": "ENUM_VALUE",
                   "isDeprecated": false,
                   "deprecationReason": null
+                },
+                {
+                  "name": "INPUT_FIELD_DEFINITION",
+                  "isDeprecated": false,
+                  "deprecationReason": null
                 }
               ],
               "possibleTypes": null
@@ -2713,7 +2900,8 @@ pub(crate) fn schema_introspection_result_without_descriptions() -> Value {
               "isRepeatable": false,
               "locations": [
                 "FIELD_DEFINITION",
-                "ENUM_VALUE"
+                "ENUM_VALUE",
+                "INPUT_FIELD_DEFINITION"
               ],
               "args": [
                 {
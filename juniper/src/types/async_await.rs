@@ -1,13 +1,22 @@
+use std::{
+    collections::HashSet,
+    sync::{Arc, Mutex, RwLock},
+};
+
 use crate::{
     ast::Selection,
-    executor::{ExecutionResult, Executor},
-    parser::Spanning,
+    executor::{
+        incremental::{self, IncrementalData, IncrementalResult, Postponement},
+        middleware, ExecutionResult, Executor, PathSegment,
+    },
+    parser::{SourcePosition, Spanning},
+    schema::meta::{MetaType, ObjectMeta, ResolvePolicy},
     value::{DefaultScalarValue, Object, ScalarValue, Value},
 };
 
 use crate::BoxFuture;
 
-use super::base::{is_excluded, merge_key_into, Arguments, GraphQLType, GraphQLValue};
+use super::base::{is_excluded, merge_key_into, typename, Arguments, GraphQLType, GraphQLValue};
 
 /// Extension of [`GraphQLValue`] trait with asynchronous queries/mutations resolvers.
 ///
@@ -177,7 +186,18 @@ where
     T::Context: Sync,
     S: ScalarValue + Send + Sync,
 {
-    use futures::stream::{FuturesOrdered, StreamExt as _};
+    use futures::stream::{self, FuturesOrdered, StreamExt as _};
+
+    if let Some(postponement) = executor.postponement() {
+        return resolve_selection_set_incrementally(
+            instance,
+            info,
+            selection_set,
+            executor,
+            postponement,
+        )
+        .await;
+    }
 
     #[derive(futures_enum::Future)]
     enum AsyncValueFuture<A, B, C, D> {
@@ -194,12 +214,21 @@ where
     let meta_type = executor
         .schema()
         .concrete_type_by_name(
-            instance
-                .type_name(info)
-                .expect("Resolving named type's selection set")
-                .as_ref(),
+            executor.schema().schema_type_name(
+                instance
+                    .type_name(info)
+                    .expect("Resolving named type's selection set"),
+            ),
         )
         .expect("Type not found in schema");
+    let serial = matches!(
+        meta_type,
+        MetaType::Object(ObjectMeta {
+            resolve_policy: ResolvePolicy::Serial,
+            ..
+        }),
+    );
+    let mut resolved = vec![];
 
     for selection in selection_set {
         match *selection {
@@ -215,10 +244,12 @@ where
                 let response_name = f.alias.as_ref().unwrap_or(&f.name).item;
 
                 if f.name.item == "__typename" {
-                    object.add_field(
-                        response_name,
-                        Value::scalar(instance.concrete_type_name(executor.context(), info)),
-                    );
+                    match typename(instance, info, f, *start_pos, executor) {
+                        Some(name) => {
+                            object.add_field(response_name, Value::scalar(name));
+                        }
+                        None => return Value::null(),
+                    }
                     continue;
                 }
 
@@ -237,6 +268,7 @@ where
                     f.name.item,
                     *start_pos,
                     f.selection_set.as_ref().map(|v| &v[..]),
+                    f.arguments.as_ref(),
                 );
                 let args = Arguments::new(
                     f.arguments.as_ref().map(|m| {
@@ -257,9 +289,21 @@ where
                 async_values.push(AsyncValueFuture::Field(async move {
                     // TODO: implement custom future type instead of
                     //       two-level boxing.
-                    let res = instance
-                        .resolve_field_async(info, f.name.item, &args, &sub_exec)
-                        .await;
+                    let resolve = instance.resolve_field_async(info, f.name.item, &args, &sub_exec);
+
+                    let middlewares = &sub_exec.schema().field_middlewares;
+                    let resolve = async {
+                        if middlewares.is_empty() {
+                            resolve.await
+                        } else {
+                            let field = sub_exec.field_info(f.name.item);
+                            middleware::around_field_async(middlewares, &field, resolve).await
+                        }
+                    };
+                    let res = match sub_exec.deadline() {
+                        Some(deadline) => deadline.race(resolve).await,
+                        None => resolve.await,
+                    };
 
                     let value = match res {
                         Ok(Value::Null) if is_non_null => None,
@@ -387,9 +431,22 @@ where
                 }
             }
         }
+
+        if serial {
+            // Finish resolving the current selection before the next one.
+            while let Some(item) = async_values.next().await {
+                if let AsyncValue::Field(AsyncField { value: None, .. })
+                | AsyncValue::Nested(Value::Null) = item
+                {
+                    return Value::null();
+                }
+                resolved.push(item);
+            }
+        }
     }
 
-    while let Some(item) = async_values.next().await {
+    let mut values = stream::iter(resolved).chain(async_values);
+    while let Some(item) = values.next().await {
         match item {
             AsyncValue::Field(AsyncField { name, value }) => {
                 if let Some(value) = value {
@@ -414,3 +471,419 @@ where
 
     Value::Object(object)
 }
+
+/// Same as [`resolve_selection_set_into_async_recursive()`], but resolves the
+/// `selection_set` as a part of an incremental delivery, postponing its
+/// `@defer`ed fragments.
+///
+/// Reports the resolved value to the `postponement` as soon as it's resolved
+/// without the postponed parts, and then resolves the `@defer`ed fragments,
+/// once the payload containing the value is delivered.
+async fn resolve_selection_set_incrementally<'a, T, S>(
+    instance: &'a T,
+    info: &'a T::TypeInfo,
+    selection_set: &'a [Selection<'a, S>],
+    executor: &'a Executor<'a, 'a, T::Context, S>,
+    postponement: &'a Postponement<S>,
+) -> Value<S>
+where
+    T: GraphQLValueAsync<S> + ?Sized,
+    T::TypeInfo: Sync,
+    T::Context: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    use futures::{
+        future,
+        stream::{FuturesUnordered, StreamExt as _},
+    };
+
+    /// Way of merging a resolved [`incremental::Part`] into the object.
+    enum Merge {
+        /// Field with the provided response name, nulling the whole object
+        /// in case it's non-null, but resolved to `null`.
+        Field(String, bool),
+
+        /// Fields of a fragment with a type condition, if any.
+        Fragment,
+
+        /// Fields of a fragment without a type condition, nulling the whole
+        /// object in case it's resolved to `null`.
+        Nested,
+    }
+
+    /// `@defer`ed fragment, along with the concrete type name of the
+    /// `instance`, if it has a type condition.
+    type Deferred<'a, S> = (
+        incremental::Defer,
+        Option<(&'a str, String)>,
+        &'a [Selection<'a, S>],
+        SourcePosition,
+    );
+
+    let meta_type = executor
+        .schema()
+        .concrete_type_by_name(
+            executor.schema().schema_type_name(
+                instance
+                    .type_name(info)
+                    .expect("Resolving named type's selection set"),
+            ),
+        )
+        .expect("Type not found in schema");
+    let serial = matches!(
+        meta_type,
+        MetaType::Object(ObjectMeta {
+            resolve_policy: ResolvePolicy::Serial,
+            ..
+        }),
+    );
+
+    let mut object = Object::with_capacity(selection_set.len());
+    let (mut merges, mut parts) = (vec![], vec![]);
+    let mut deferred: Vec<Deferred<S>> = vec![];
+
+    for selection in selection_set {
+        match *selection {
+            Selection::Field(Spanning {
+                item: ref f,
+                start: ref start_pos,
+                ..
+            }) => {
+                if is_excluded(&f.directives, executor.variables()) {
+                    continue;
+                }
+
+                let response_name = f.alias.as_ref().unwrap_or(&f.name).item;
+
+                if f.name.item == "__typename" {
+                    match typename(instance, info, f, *start_pos, executor) {
+                        Some(name) => {
+                            object.add_field(response_name, Value::scalar(name));
+                        }
+                        None => return Value::null(),
+                    }
+                    continue;
+                }
+
+                let meta_field = meta_type.field_by_name(f.name.item).unwrap_or_else(|| {
+                    panic!(
+                        "Field {} not found on type {:?}",
+                        f.name.item,
+                        meta_type.name(),
+                    )
+                });
+
+                let exec_vars = executor.variables();
+
+                let early = Arc::default();
+                let sub_exec = executor
+                    .field_sub_executor(
+                        response_name,
+                        f.name.item,
+                        *start_pos,
+                        f.selection_set.as_ref().map(|v| &v[..]),
+                        f.arguments.as_ref(),
+                    )
+                    .with_stream(&f.directives)
+                    .reporting_into(&early);
+                let args = Arguments::new(
+                    f.arguments.as_ref().map(|m| {
+                        m.item
+                            .iter()
+                            .filter_map(|&(ref k, ref v)| {
+                                v.item.clone().into_const(exec_vars).map(|v| (k.item, v))
+                            })
+                            .collect()
+                    }),
+                    &meta_field.arguments,
+                );
+
+                let pos = *start_pos;
+                merges.push(Merge::Field(
+                    response_name.into(),
+                    meta_field.field_type.is_non_null(),
+                ));
+                parts.push(incremental::Part::new(
+                    early,
+                    Box::pin(async move {
+                        let resolve =
+                            instance.resolve_field_async(info, f.name.item, &args, &sub_exec);
+
+                        let middlewares = &sub_exec.schema().field_middlewares;
+                        let resolve = async {
+                            if middlewares.is_empty() {
+                                resolve.await
+                            } else {
+                                let field = sub_exec.field_info(f.name.item);
+                                middleware::around_field_async(middlewares, &field, resolve).await
+                            }
+                        };
+                        let res = match sub_exec.deadline() {
+                            Some(deadline) => deadline.race(resolve).await,
+                            None => resolve.await,
+                        };
+
+                        res.unwrap_or_else(|e| {
+                            sub_exec.push_error_at(e, pos);
+                            Value::null()
+                        })
+                    }),
+                ));
+            }
+
+            Selection::FragmentSpread(Spanning {
+                item: ref spread,
+                start: ref start_pos,
+                ..
+            }) => {
+                if is_excluded(&spread.directives, executor.variables()) {
+                    continue;
+                }
+
+                let fragment = &executor
+                    .fragment_by_name(spread.name.item)
+                    .expect("Fragment could not be found");
+                let type_condition = fragment.type_condition.item;
+
+                let concrete_type_name = instance.concrete_type_name(executor.context(), info);
+                if !executor
+                    .schema()
+                    .is_named_subtype(&concrete_type_name, type_condition)
+                    && Some(type_condition) != instance.type_name(info)
+                {
+                    continue;
+                }
+
+                let selection_set = &fragment.selection_set[..];
+                if let Some(defer) = incremental::defer(&spread.directives, executor.variables()) {
+                    deferred.push((
+                        defer,
+                        Some((type_condition, concrete_type_name)),
+                        selection_set,
+                        *start_pos,
+                    ));
+                    continue;
+                }
+
+                let early = Arc::default();
+                let sub_exec = executor
+                    .type_sub_executor(Some(type_condition), Some(selection_set))
+                    .reporting_into(&early);
+                let pos = *start_pos;
+                merges.push(Merge::Fragment);
+                parts.push(incremental::Part::new(
+                    early,
+                    Box::pin(async move {
+                        instance
+                            .resolve_into_type_async(
+                                info,
+                                &concrete_type_name,
+                                Some(selection_set),
+                                &sub_exec,
+                            )
+                            .await
+                            .unwrap_or_else(|e| {
+                                sub_exec.push_error_at(e, pos);
+                                Value::null()
+                            })
+                    }),
+                ));
+            }
+
+            Selection::InlineFragment(Spanning {
+                item: ref fragment,
+                start: ref start_pos,
+                ..
+            }) => {
+                if is_excluded(&fragment.directives, executor.variables()) {
+                    continue;
+                }
+
+                let type_condition = match &fragment.type_condition {
+                    Some(type_condition) => {
+                        // Check whether the type matches the type condition.
+                        let concrete_type_name =
+                            instance.concrete_type_name(executor.context(), info);
+                        if !executor
+                            .schema()
+                            .is_named_subtype(&concrete_type_name, type_condition.item)
+                        {
+                            continue;
+                        }
+                        Some((type_condition.item, concrete_type_name))
+                    }
+                    None => None,
+                };
+
+                let selection_set = &fragment.selection_set[..];
+                if let Some(defer) = incremental::defer(&fragment.directives, executor.variables())
+                {
+                    deferred.push((defer, type_condition, selection_set, *start_pos));
+                    continue;
+                }
+
+                let early = Arc::default();
+                let sub_exec = executor
+                    .type_sub_executor(type_condition.as_ref().map(|c| c.0), Some(selection_set))
+                    .reporting_into(&early);
+                let pos = *start_pos;
+                match type_condition {
+                    Some((_, concrete_type_name)) => {
+                        merges.push(Merge::Fragment);
+                        parts.push(incremental::Part::new(
+                            early,
+                            Box::pin(async move {
+                                instance
+                                    .resolve_into_type_async(
+                                        info,
+                                        &concrete_type_name,
+                                        Some(selection_set),
+                                        &sub_exec,
+                                    )
+                                    .await
+                                    .unwrap_or_else(|e| {
+                                        sub_exec.push_error_at(e, pos);
+                                        Value::null()
+                                    })
+                            }),
+                        ));
+                    }
+                    None => {
+                        merges.push(Merge::Nested);
+                        parts.push(incremental::Part::new(
+                            early,
+                            Box::pin(async move {
+                                resolve_selection_set_into_async(
+                                    instance,
+                                    info,
+                                    selection_set,
+                                    &sub_exec,
+                                )
+                                .await
+                            }),
+                        ));
+                    }
+                }
+            }
+        }
+
+        if serial {
+            // Finish resolving the current selection before the next one.
+            if let Some(part) = parts.last_mut() {
+                part.known().await;
+            }
+        }
+    }
+
+    let values = incremental::values(&mut parts).await;
+    for (merge, value) in merges.into_iter().zip(values) {
+        match (merge, value) {
+            (Merge::Field(_, true), Value::Null) | (Merge::Nested, Value::Null) => {
+                return Value::null();
+            }
+            (Merge::Field(name, _), value) => merge_key_into(&mut object, &name, value),
+            (Merge::Fragment | Merge::Nested, Value::Object(obj)) => {
+                for (k, v) in obj {
+                    merge_key_into(&mut object, &k, v);
+                }
+            }
+            (Merge::Fragment, _) => {}
+            (Merge::Nested, _) => unreachable!(),
+        }
+    }
+
+    let delivered = Mutex::new(
+        object
+            .iter()
+            .map(|(k, _)| k.clone())
+            .collect::<HashSet<_>>(),
+    );
+    let value = Value::Object(object);
+    postponement.report(&value);
+
+    let deferred = deferred
+        .into_iter()
+        .map(|(defer, type_condition, selection_set, pos)| {
+            let delivered = &delivered;
+            async move {
+                postponement.delivered().await;
+
+                let (fragment_postponement, early) = postponement.postponed();
+                let errors = RwLock::new(vec![]);
+                let sub_exec = executor
+                    .type_sub_executor(type_condition.as_ref().map(|c| c.0), Some(selection_set))
+                    .postponed(fragment_postponement.clone(), &errors);
+                let resolve: BoxFuture<'_, Value<S>> = match &type_condition {
+                    Some((_, concrete_type_name)) => Box::pin(async {
+                        instance
+                            .resolve_into_type_async(
+                                info,
+                                concrete_type_name,
+                                Some(selection_set),
+                                &sub_exec,
+                            )
+                            .await
+                            .unwrap_or_else(|e| {
+                                sub_exec.push_error_at(e, pos);
+                                Value::null()
+                            })
+                    }),
+                    None => {
+                        resolve_selection_set_into_async(instance, info, selection_set, &sub_exec)
+                    }
+                };
+                let mut fragment = incremental::Part::new(early, resolve);
+
+                let data = fragment.value().await;
+                let path = sub_exec.response_path();
+                let mut errors_occurred = incremental::take_errors(&errors);
+                let data = match data {
+                    // Fields already delivered by the non-deferred selections
+                    // or other `@defer`ed fragments are omitted.
+                    Value::Object(obj) => {
+                        let mut delivered = delivered.lock().unwrap();
+                        let mut fields = Object::with_capacity(obj.field_count());
+                        for (k, v) in obj {
+                            if delivered.insert(k.clone()) {
+                                fields.add_field(k, v);
+                            }
+                        }
+                        errors_occurred.retain(|e| match e.path().get(path.len()) {
+                            Some(PathSegment::Field(name)) => fields.contains_field(name),
+                            _ => true,
+                        });
+                        Value::Object(fields)
+                    }
+                    data => data,
+                };
+                if errors_occurred.is_empty()
+                    && matches!(&data, Value::Object(obj) if obj.field_count() == 0)
+                {
+                    fragment_postponement.skip();
+                } else {
+                    fragment_postponement.deliver(IncrementalResult::Data(IncrementalData {
+                        data,
+                        path: path.clone(),
+                        label: defer.label.clone(),
+                        errors: errors_occurred,
+                    }));
+                }
+
+                fragment.complete().await;
+                fragment_postponement.delivery().deliver_late_errors(
+                    path,
+                    defer.label,
+                    incremental::take_errors(&errors),
+                );
+            }
+        })
+        .collect::<FuturesUnordered<_>>();
+
+    future::join(
+        incremental::complete(parts),
+        deferred.for_each(|()| future::ready(())),
+    )
+    .await;
+
+    value
+}
@@ -1,12 +1,13 @@
 use indexmap::IndexMap;
 
 use crate::{
-    ast::{Directive, FromInputValue, InputValue, Selection},
-    executor::{ExecutionResult, Executor, Registry, Variables},
-    parser::Spanning,
+    ast::{Directive, Field, FromInputValue, InputValue, Selection},
+    executor::{middleware, ExecutionResult, Executor, Registry, Variables},
+    macros::reflect,
+    parser::{SourcePosition, Spanning},
     schema::meta::{Argument, MetaType},
     value::{DefaultScalarValue, Object, ScalarValue, Value},
-    FieldResult, GraphQLEnum, IntoFieldError,
+    FieldError, FieldResult, GraphQLEnum, IntoFieldError,
 };
 
 /// GraphQL type kind
@@ -121,6 +122,25 @@ impl<'a, S> Arguments<'a, S> {
             .transpose()
             .map_err(IntoFieldError::into_field_error)
     }
+
+    /// Gets an argument by the given `name` as the raw [`InputValue`], without
+    /// converting it into any Rust type.
+    ///
+    /// Returns [`None`] if an argument with such `name` is not present, and no
+    /// default value has been provided for it.
+    #[must_use]
+    pub fn get_input_value(&self, name: &str) -> Option<&InputValue<S>> {
+        self.args.as_ref().and_then(|args| args.get(name))
+    }
+
+    /// Iterates over all the present arguments as raw [`InputValue`]s, in the
+    /// order they've been specified in the query, followed by the ones having
+    /// default values.
+    pub fn iter(&self) -> impl Iterator<Item = (&'a str, &InputValue<S>)> {
+        self.args
+            .iter()
+            .flat_map(|args| args.iter().map(|(&name, v)| (name, v)))
+    }
 }
 
 /// Primary trait used to resolve GraphQL values.
@@ -252,6 +272,21 @@ where
         );
     }
 
+    /// Returns the [`GraphQLType`] name to be reported by the `__typename` field of this
+    /// [`GraphQLValue`] being an [object][1], if it differs from the
+    /// [`GraphQLValue::concrete_type_name()`].
+    ///
+    /// The returned name must belong to a type registered in the schema, otherwise resolving the
+    /// `__typename` field fails with a field error.
+    ///
+    /// The default implementation returns [`None`].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    #[allow(unused_variables)]
+    fn typename_override(&self, context: &Self::Context, info: &Self::TypeInfo) -> Option<String> {
+        None
+    }
+
     /// Resolves the provided `selection_set` against this [`GraphQLValue`].
     ///
     /// For non-[object][3] types, the `selection_set` will be [`None`] and the value should simply
@@ -400,6 +435,49 @@ where
         S: 'r;
 }
 
+/// Compile-time name of a GraphQL type in a GraphQL schema.
+///
+/// Provides the name a type is exposed with, without constructing a
+/// [`Registry`] or providing a [`GraphQLType::TypeInfo`]. It's implemented
+/// for all the types having a statically known name, which includes all the
+/// types implemented via the derive and attribute macros of this crate
+/// (objects, interfaces, unions, scalars, enums and input objects), as well as
+/// the built-in scalars.
+///
+/// For types wrapped into [`Option`], [`Vec`] and other containers, the
+/// [`NAME`] is the one of the innermost named type (`Int` for [`Vec`] of
+/// [`i32`]s, for example).
+///
+/// ```rust
+/// use juniper::{GraphQLEnum, GraphQLObject, GraphQLTypeName};
+///
+/// #[derive(GraphQLObject)]
+/// #[graphql(name = "Human")]
+/// struct Person {
+///     name: String,
+/// }
+///
+/// #[derive(GraphQLEnum)]
+/// #[graphql(name = "Episode")]
+/// enum Film {
+///     NewHope,
+/// }
+///
+/// assert_eq!(<Person as GraphQLTypeName>::NAME, "Human");
+/// assert_eq!(<Film as GraphQLTypeName>::NAME, "Episode");
+/// assert_eq!(<Option<Vec<i32>> as GraphQLTypeName>::NAME, "Int");
+/// ```
+///
+/// [`NAME`]: GraphQLTypeName::NAME
+pub trait GraphQLTypeName<S = DefaultScalarValue> {
+    /// Name of this GraphQL type in a GraphQL schema.
+    const NAME: &'static str;
+}
+
+impl<S, T: reflect::BaseType<S> + ?Sized> GraphQLTypeName<S> for T {
+    const NAME: &'static str = T::NAME;
+}
+
 /// Resolver logic for queries'/mutations' selection set.
 /// Calls appropriate resolver method for each field or fragment found
 /// and then merges returned values into `result` or pushes errors to
@@ -420,10 +498,11 @@ where
     let meta_type = executor
         .schema()
         .concrete_type_by_name(
-            instance
-                .type_name(info)
-                .expect("Resolving named type's selection set")
-                .as_ref(),
+            executor.schema().schema_type_name(
+                instance
+                    .type_name(info)
+                    .expect("Resolving named type's selection set"),
+            ),
         )
         .expect("Type not found in schema");
 
@@ -441,10 +520,12 @@ where
                 let response_name = f.alias.as_ref().unwrap_or(&f.name).item;
 
                 if f.name.item == "__typename" {
-                    result.add_field(
-                        response_name,
-                        Value::scalar(instance.concrete_type_name(executor.context(), info)),
-                    );
+                    match typename(instance, info, f, *start_pos, executor) {
+                        Some(name) => {
+                            result.add_field(response_name, Value::scalar(name));
+                        }
+                        None => return false,
+                    }
                     continue;
                 }
 
@@ -463,24 +544,32 @@ where
                     f.name.item,
                     *start_pos,
                     f.selection_set.as_ref().map(|v| &v[..]),
+                    f.arguments.as_ref(),
                 );
 
-                let field_result = instance.resolve_field(
-                    info,
-                    f.name.item,
-                    &Arguments::new(
-                        f.arguments.as_ref().map(|m| {
-                            m.item
-                                .iter()
-                                .filter_map(|&(ref k, ref v)| {
-                                    v.item.clone().into_const(exec_vars).map(|v| (k.item, v))
-                                })
-                                .collect()
-                        }),
-                        &meta_field.arguments,
-                    ),
-                    &sub_exec,
+                let args = Arguments::new(
+                    f.arguments.as_ref().map(|m| {
+                        m.item
+                            .iter()
+                            .filter_map(|&(ref k, ref v)| {
+                                v.item.clone().into_const(exec_vars).map(|v| (k.item, v))
+                            })
+                            .collect()
+                    }),
+                    &meta_field.arguments,
                 );
+                let mut resolve = || instance.resolve_field(info, f.name.item, &args, &sub_exec);
+
+                let middlewares = &executor.schema().field_middlewares;
+                let field_result = if middlewares.is_empty() {
+                    resolve()
+                } else {
+                    middleware::around_field(
+                        middlewares,
+                        &sub_exec.field_info(f.name.item),
+                        &mut resolve,
+                    )
+                };
 
                 match field_result {
                     Ok(Value::Null) if meta_field.field_type.is_non_null() => return false,
@@ -589,6 +678,77 @@ where
     true
 }
 
+/// Resolves the `__typename` `field` of the provided `instance`.
+///
+/// Returns [`None`] if the name reported by the
+/// [`GraphQLValue::typename_override()`] doesn't belong to an object type of
+/// the schema, being a possible type of the abstract parent of the `instance`
+/// (the interface or union the `instance` is resolved as, or any one the
+/// `instance` belongs to otherwise), pushing the error to the `executor`.
+pub(super) fn typename<T, S>(
+    instance: &T,
+    info: &T::TypeInfo,
+    field: &Field<S>,
+    start_pos: SourcePosition,
+    executor: &Executor<T::Context, S>,
+) -> Option<String>
+where
+    T: GraphQLValue<S> + ?Sized,
+    S: ScalarValue,
+{
+    let schema = executor.schema();
+    match instance.typename_override(executor.context(), info) {
+        None => Some(
+            schema
+                .schema_type_name(&instance.concrete_type_name(executor.context(), info))
+                .to_owned(),
+        ),
+        Some(name) => {
+            let name = schema.schema_type_name(&name);
+            let current = executor.current_type().innermost_concrete();
+            let error = match schema.concrete_type_by_name(name) {
+                None => "doesn't exist in the schema",
+                Some(_) if name == current.name().unwrap_or_default() => {
+                    return Some(name.to_owned());
+                }
+                Some(reported @ MetaType::Object(_)) => {
+                    let is_possible = if current.is_abstract() {
+                        schema.is_possible_type(current, reported)
+                    } else {
+                        schema.concrete_type_list().into_iter().any(|t| {
+                            t.is_abstract()
+                                && schema.is_possible_type(t, current)
+                                && schema.is_possible_type(t, reported)
+                        })
+                    };
+                    if is_possible {
+                        return Some(name.to_owned());
+                    }
+                    "isn't a possible type of its abstract parent"
+                }
+                Some(_) => "isn't an object type",
+            };
+            let response_name = field.alias.as_ref().unwrap_or(&field.name).item;
+            executor
+                .field_sub_executor(
+                    response_name,
+                    field.name.item,
+                    start_pos,
+                    None,
+                    field.arguments.as_ref(),
+                )
+                .push_error_at(
+                    FieldError::from(format!(
+                        "Type `{name}` reported by `__typename` of `{}` {error}",
+                        instance.type_name(info).unwrap_or("<unnamed>"),
+                    )),
+                    start_pos,
+                );
+            None
+        }
+    }
+}
+
 pub(super) fn is_excluded<S>(
     directives: &Option<Vec<Spanning<Directive<S>>>>,
     vars: &Variables<S>,
@@ -602,6 +762,11 @@ where
             ..
         } in directives
         {
+            // Custom directives don't affect field inclusion.
+            if !matches!(directive.name.item, "skip" | "include") {
+                continue;
+            }
+
             let condition: bool = directive
                 .arguments
                 .iter()
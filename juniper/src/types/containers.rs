@@ -1,11 +1,15 @@
 use std::{
     mem::{self, MaybeUninit},
     ptr,
+    sync::{Arc, RwLock},
 };
 
 use crate::{
     ast::{FromInputValue, InputValue, Selection, ToInputValue},
-    executor::{ExecutionResult, Executor, FieldError, IntoFieldError, Registry},
+    executor::{
+        incremental::{self, IncrementalItems, IncrementalResult, Postponement},
+        ExecutionResult, Executor, FieldError, IntoFieldError, Registry,
+    },
     schema::meta::MetaType,
     types::{
         async_await::GraphQLValueAsync,
@@ -85,10 +89,18 @@ impl<S, T: FromInputValue<S>> FromInputValue<S> for Option<T> {
 
     fn from_input_value(v: &InputValue<S>) -> Result<Self, Self::Error> {
         match v {
-            InputValue::Null => Ok(None),
+            InputValue::Null if !T::accepts_null() => Ok(None),
             v => v.convert().map(Some),
         }
     }
+
+    fn from_implicit_null() -> Result<Self, Self::Error> {
+        if T::accepts_null() {
+            T::from_implicit_null().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
 }
 
 impl<S, T: ToInputValue<S>> ToInputValue<S> for Option<T> {
@@ -532,6 +544,89 @@ where
     }
 }
 
+/// Implements output [`GraphQLType`] machinery for homogeneous tuples, resolving
+/// them as fixed-length [GraphQL lists][0].
+///
+/// As a [GraphQL list][0] has a single element type, only tuples of elements of
+/// the same type `T` are supported.
+///
+/// Pairs are not supported, as `(&T::Context, T)` is already reserved for
+/// switching the context of a resolved value, so use `[T; 2]` arrays instead.
+///
+/// [0]: https://spec.graphql.org/October2021#sec-List
+macro_rules! impl_output_for_tuple {
+    (@elem $idx:tt) => { T };
+    ($len:literal: $($idx:tt),+) => {
+        impl<S, T> GraphQLType<S> for ($(impl_output_for_tuple!(@elem $idx),)+)
+        where
+            S: ScalarValue,
+            T: GraphQLType<S>,
+        {
+            fn name(_: &Self::TypeInfo) -> Option<&'static str> {
+                None
+            }
+
+            fn meta<'r>(info: &Self::TypeInfo, registry: &mut Registry<'r, S>) -> MetaType<'r, S>
+            where
+                S: 'r,
+            {
+                registry.build_list_type::<T>(info, Some($len)).into_meta()
+            }
+        }
+
+        impl<S, T> GraphQLValue<S> for ($(impl_output_for_tuple!(@elem $idx),)+)
+        where
+            S: ScalarValue,
+            T: GraphQLValue<S>,
+        {
+            type Context = T::Context;
+            type TypeInfo = T::TypeInfo;
+
+            fn type_name(&self, _: &Self::TypeInfo) -> Option<&'static str> {
+                None
+            }
+
+            fn resolve(
+                &self,
+                info: &Self::TypeInfo,
+                _: Option<&[Selection<S>]>,
+                executor: &Executor<Self::Context, S>,
+            ) -> ExecutionResult<S> {
+                resolve_into_list(executor, info, [$(&self.$idx),+].into_iter())
+            }
+        }
+
+        impl<S, T> GraphQLValueAsync<S> for ($(impl_output_for_tuple!(@elem $idx),)+)
+        where
+            T: GraphQLValueAsync<S>,
+            T::TypeInfo: Sync,
+            T::Context: Sync,
+            S: ScalarValue + Send + Sync,
+        {
+            fn resolve_async<'a>(
+                &'a self,
+                info: &'a Self::TypeInfo,
+                _: Option<&'a [Selection<S>]>,
+                executor: &'a Executor<Self::Context, S>,
+            ) -> crate::BoxFuture<'a, ExecutionResult<S>> {
+                let f = resolve_into_list_async(executor, info, [$(&self.$idx),+].into_iter());
+                Box::pin(f)
+            }
+        }
+    };
+}
+
+impl_output_for_tuple!(3: 0, 1, 2);
+impl_output_for_tuple!(4: 0, 1, 2, 3);
+impl_output_for_tuple!(5: 0, 1, 2, 3, 4);
+impl_output_for_tuple!(6: 0, 1, 2, 3, 4, 5);
+impl_output_for_tuple!(7: 0, 1, 2, 3, 4, 5, 6);
+impl_output_for_tuple!(8: 0, 1, 2, 3, 4, 5, 6, 7);
+impl_output_for_tuple!(9: 0, 1, 2, 3, 4, 5, 6, 7, 8);
+impl_output_for_tuple!(10: 0, 1, 2, 3, 4, 5, 6, 7, 8, 9);
+impl_output_for_tuple!(11: 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10);
+impl_output_for_tuple!(12: 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11);
+
 fn resolve_into_list<'t, S, T, I>(
     executor: &Executor<T::Context, S>,
     info: &T::TypeInfo,
@@ -549,8 +644,12 @@ where
         .is_non_null();
     let mut result = Vec::with_capacity(iter.len());
 
-    for o in iter {
-        let val = executor.resolve(info, o)?;
+    for (i, o) in iter.enumerate() {
+        let sub_exec = executor.index_sub_executor(i);
+        let val = sub_exec.resolve(info, o).unwrap_or_else(|e| {
+            sub_exec.push_error(e);
+            Value::null()
+        });
         if stop_on_null && val.is_null() {
             return Ok(val);
         } else {
@@ -581,8 +680,19 @@ where
         .expect("Current type is not a list type")
         .is_non_null();
 
+    if let Some(postponement) = executor.postponement() {
+        return resolve_into_list_incrementally(executor, postponement, info, items, stop_on_null)
+            .await;
+    }
+
     let mut futures = items
-        .map(|it| async move { executor.resolve_into_value_async(info, it).await })
+        .enumerate()
+        .map(|(i, it)| async move {
+            executor
+                .index_sub_executor(i)
+                .resolve_into_value_async(info, it)
+                .await
+        })
         .collect::<FuturesOrdered<_>>();
 
     let mut values = Vec::with_capacity(futures.len());
@@ -596,6 +706,110 @@ where
     Ok(Value::list(values))
 }
 
+/// Same as [`resolve_into_list_async()`], but resolves the list as a part of an
+/// incremental delivery, postponing its items beyond the `initialCount` of its
+/// `@stream` directive, if any.
+async fn resolve_into_list_incrementally<'a, 't, S, T, I>(
+    executor: &'a Executor<'a, 'a, T::Context, S>,
+    postponement: &'a Postponement<S>,
+    info: &'a T::TypeInfo,
+    items: I,
+    stop_on_null: bool,
+) -> ExecutionResult<S>
+where
+    I: Iterator<Item = &'t T> + ExactSizeIterator,
+    T: GraphQLValueAsync<S> + ?Sized + 't,
+    T::TypeInfo: Sync,
+    T::Context: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    use futures::{
+        future,
+        stream::{FuturesUnordered, StreamExt as _},
+    };
+
+    let len = executor
+        .stream()
+        .map_or(items.len(), |s| s.initial_count.min(items.len()));
+    let label = executor.stream().and_then(|s| s.label.clone());
+
+    let mut items = items.enumerate();
+    let mut parts = items
+        .by_ref()
+        .take(len)
+        .map(|(i, it)| {
+            let early = Arc::default();
+            let sub_exec = executor.index_sub_executor(i).reporting_into(&early);
+            incremental::Part::new(
+                early,
+                Box::pin(async move { sub_exec.resolve_into_value_async(info, it).await }),
+            )
+        })
+        .collect::<Vec<_>>();
+    let postponed = items.collect::<Vec<_>>();
+
+    let values = incremental::values(&mut parts).await;
+    if stop_on_null && values.iter().any(Value::is_null) {
+        return Ok(Value::null());
+    }
+    let value = Value::list(values);
+    postponement.report(&value);
+
+    let streamed = async {
+        if postponed.is_empty() {
+            return;
+        }
+        postponement.delivered().await;
+
+        // Items are resolved concurrently, but each one is delivered only
+        // after the preceding one.
+        let mut preceding = None;
+        postponed
+            .into_iter()
+            .map(|(i, it)| {
+                let (item_postponement, early) = postponement.postponed();
+                let preceding = preceding.replace(item_postponement.clone());
+                let label = label.clone();
+                async move {
+                    let errors = RwLock::new(vec![]);
+                    let sub_exec = executor
+                        .index_sub_executor(i)
+                        .postponed(item_postponement.clone(), &errors);
+                    let mut item = incremental::Part::new(
+                        early,
+                        Box::pin(sub_exec.resolve_into_value_async(info, it)),
+                    );
+
+                    let value = item.value().await;
+                    if let Some(p) = preceding {
+                        p.delivered().await;
+                    }
+                    let path = sub_exec.response_path();
+                    item_postponement.deliver(IncrementalResult::Items(IncrementalItems {
+                        items: vec![value],
+                        path: path.clone(),
+                        label: label.clone(),
+                        errors: incremental::take_errors(&errors),
+                    }));
+
+                    item.complete().await;
+                    item_postponement.delivery().deliver_late_errors(
+                        path,
+                        label,
+                        incremental::take_errors(&errors),
+                    );
+                }
+            })
+            .collect::<FuturesUnordered<_>>()
+            .for_each(|()| future::ready(()))
+            .await
+    };
+
+    future::join(incremental::complete(parts), streamed).await;
+
+    Ok(value)
+}
+
 #[cfg(test)]
 mod coercion {
     use crate::{graphql_input_value, FromInputValue as _, InputValue, IntoFieldError as _};
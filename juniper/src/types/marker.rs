@@ -5,7 +5,7 @@
 //! traits are used. Encountering an error where one of these traits
 //! is involved implies that the construct is not valid in GraphQL.
 
-use std::sync::Arc;
+use std::{borrow::Cow, rc::Rc, sync::Arc};
 
 use crate::{GraphQLType, ScalarValue};
 
@@ -66,6 +66,17 @@ where
     }
 }
 
+impl<S, T> GraphQLObject<S> for Rc<T>
+where
+    T: GraphQLObject<S> + ?Sized,
+    S: ScalarValue,
+{
+    #[inline]
+    fn mark() {
+        T::mark()
+    }
+}
+
 /// Maker trait for [GraphQL interfaces][1].
 ///
 /// This trait extends the [`GraphQLType`] and is only used to mark an [interface][1]. During
@@ -123,6 +134,17 @@ where
     }
 }
 
+impl<S, T> GraphQLInterface<S> for Rc<T>
+where
+    T: GraphQLInterface<S> + ?Sized,
+    S: ScalarValue,
+{
+    #[inline]
+    fn mark() {
+        T::mark()
+    }
+}
+
 /// Maker trait for [GraphQL unions][1].
 ///
 /// This trait extends the [`GraphQLType`] and is only used to mark an [union][1]. During compile
@@ -180,6 +202,17 @@ where
     }
 }
 
+impl<S, T> GraphQLUnion<S> for Rc<T>
+where
+    T: GraphQLUnion<S> + ?Sized,
+    S: ScalarValue,
+{
+    #[inline]
+    fn mark() {
+        T::mark()
+    }
+}
+
 /// Marker trait for types which can be used as output types.
 ///
 /// The GraphQL specification differentiates between input and output
@@ -227,6 +260,28 @@ where
     }
 }
 
+impl<S, T> IsOutputType<S> for Rc<T>
+where
+    T: IsOutputType<S> + ?Sized,
+    S: ScalarValue,
+{
+    #[inline]
+    fn mark() {
+        T::mark()
+    }
+}
+
+impl<'a, S, T> IsOutputType<S> for Cow<'a, T>
+where
+    T: IsOutputType<S> + ToOwned + ?Sized,
+    S: ScalarValue,
+{
+    #[inline]
+    fn mark() {
+        T::mark()
+    }
+}
+
 impl<S, T> IsOutputType<S> for Option<T>
 where
     T: IsOutputType<S>,
@@ -271,6 +326,35 @@ where
     }
 }
 
+/// Implements [`IsOutputType`] for homogeneous tuples, being represented as
+/// fixed-length GraphQL lists.
+macro_rules! impl_output_for_tuple {
+    (@elem $idx:tt) => { T };
+    ($($idx:tt),+) => {
+        impl<S, T> IsOutputType<S> for ($(impl_output_for_tuple!(@elem $idx),)+)
+        where
+            T: IsOutputType<S>,
+            S: ScalarValue,
+        {
+            #[inline]
+            fn mark() {
+                T::mark()
+            }
+        }
+    };
+}
+
+impl_output_for_tuple!(0, 1, 2);
+impl_output_for_tuple!(0, 1, 2, 3);
+impl_output_for_tuple!(0, 1, 2, 3, 4);
+impl_output_for_tuple!(0, 1, 2, 3, 4, 5);
+impl_output_for_tuple!(0, 1, 2, 3, 4, 5, 6);
+impl_output_for_tuple!(0, 1, 2, 3, 4, 5, 6, 7);
+impl_output_for_tuple!(0, 1, 2, 3, 4, 5, 6, 7, 8);
+impl_output_for_tuple!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9);
+impl_output_for_tuple!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10);
+impl_output_for_tuple!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11);
+
 impl<S> IsOutputType<S> for str where S: ScalarValue {}
 
 /// Marker trait for types which can be used as input types.
@@ -320,6 +404,17 @@ where
     }
 }
 
+impl<S, T> IsInputType<S> for Rc<T>
+where
+    T: IsInputType<S> + ?Sized,
+    S: ScalarValue,
+{
+    #[inline]
+    fn mark() {
+        T::mark()
+    }
+}
+
 impl<S, T> IsInputType<S> for Option<T>
 where
     T: IsInputType<S>,
@@ -1,4 +1,4 @@
-use std::{fmt, sync::Arc};
+use std::{borrow::Cow, fmt, rc::Rc, sync::Arc};
 
 use crate::{
     ast::{FromInputValue, InputValue, Selection, ToInputValue},
@@ -41,6 +41,14 @@ where
         (**self).type_name(info)
     }
 
+    fn concrete_type_name(&self, context: &Self::Context, info: &Self::TypeInfo) -> String {
+        (**self).concrete_type_name(context, info)
+    }
+
+    fn typename_override(&self, context: &Self::Context, info: &Self::TypeInfo) -> Option<String> {
+        (**self).typename_override(context, info)
+    }
+
     fn resolve_into_type(
         &self,
         info: &Self::TypeInfo,
@@ -139,6 +147,14 @@ where
         (**self).type_name(info)
     }
 
+    fn concrete_type_name(&self, context: &Self::Context, info: &Self::TypeInfo) -> String {
+        (**self).concrete_type_name(context, info)
+    }
+
+    fn typename_override(&self, context: &Self::Context, info: &Self::TypeInfo) -> Option<String> {
+        (**self).typename_override(context, info)
+    }
+
     fn resolve_into_type(
         &self,
         info: &Self::TypeInfo,
@@ -235,6 +251,14 @@ where
         (**self).type_name(info)
     }
 
+    fn concrete_type_name(&self, context: &Self::Context, info: &Self::TypeInfo) -> String {
+        (**self).concrete_type_name(context, info)
+    }
+
+    fn typename_override(&self, context: &Self::Context, info: &Self::TypeInfo) -> Option<String> {
+        (**self).typename_override(context, info)
+    }
+
     fn resolve_into_type(
         &self,
         info: &Self::TypeInfo,
@@ -303,3 +327,179 @@ where
         (**self).to_input_value()
     }
 }
+
+// `GraphQLValueAsync` is not implemented for `Rc`, as it's `!Sync`, so `Rc`s may
+// be used in synchronous execution only.
+impl<S, T> GraphQLType<S> for Rc<T>
+where
+    S: ScalarValue,
+    T: GraphQLType<S> + ?Sized,
+{
+    fn name(info: &Self::TypeInfo) -> Option<&str> {
+        T::name(info)
+    }
+
+    fn meta<'r>(info: &Self::TypeInfo, registry: &mut Registry<'r, S>) -> MetaType<'r, S>
+    where
+        S: 'r,
+    {
+        T::meta(info, registry)
+    }
+}
+
+impl<S, T> GraphQLValue<S> for Rc<T>
+where
+    S: ScalarValue,
+    T: GraphQLValue<S> + ?Sized,
+{
+    type Context = T::Context;
+    type TypeInfo = T::TypeInfo;
+
+    fn type_name<'i>(&self, info: &'i Self::TypeInfo) -> Option<&'i str> {
+        (**self).type_name(info)
+    }
+
+    fn concrete_type_name(&self, context: &Self::Context, info: &Self::TypeInfo) -> String {
+        (**self).concrete_type_name(context, info)
+    }
+
+    fn typename_override(&self, context: &Self::Context, info: &Self::TypeInfo) -> Option<String> {
+        (**self).typename_override(context, info)
+    }
+
+    fn resolve_into_type(
+        &self,
+        info: &Self::TypeInfo,
+        name: &str,
+        selection_set: Option<&[Selection<S>]>,
+        executor: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        (**self).resolve_into_type(info, name, selection_set, executor)
+    }
+
+    fn resolve_field(
+        &self,
+        info: &Self::TypeInfo,
+        field: &str,
+        args: &Arguments<S>,
+        executor: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        (**self).resolve_field(info, field, args, executor)
+    }
+
+    fn resolve(
+        &self,
+        info: &Self::TypeInfo,
+        selection_set: Option<&[Selection<S>]>,
+        executor: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        (**self).resolve(info, selection_set, executor)
+    }
+}
+
+impl<T, S> FromInputValue<S> for Rc<T>
+where
+    S: ScalarValue,
+    T: FromInputValue<S>,
+{
+    type Error = T::Error;
+
+    fn from_input_value(v: &InputValue<S>) -> Result<Rc<T>, Self::Error> {
+        <T as FromInputValue<S>>::from_input_value(v).map(Rc::new)
+    }
+}
+
+impl<T, S> ToInputValue<S> for Rc<T>
+where
+    S: fmt::Debug,
+    T: ToInputValue<S>,
+{
+    fn to_input_value(&self) -> InputValue<S> {
+        (**self).to_input_value()
+    }
+}
+
+impl<'e, S, T> GraphQLType<S> for Cow<'e, T>
+where
+    S: ScalarValue,
+    T: GraphQLType<S> + ToOwned + ?Sized,
+{
+    fn name(info: &Self::TypeInfo) -> Option<&str> {
+        T::name(info)
+    }
+
+    fn meta<'r>(info: &Self::TypeInfo, registry: &mut Registry<'r, S>) -> MetaType<'r, S>
+    where
+        S: 'r,
+    {
+        T::meta(info, registry)
+    }
+}
+
+impl<'e, S, T> GraphQLValue<S> for Cow<'e, T>
+where
+    S: ScalarValue,
+    T: GraphQLValue<S> + ToOwned + ?Sized,
+{
+    type Context = T::Context;
+    type TypeInfo = T::TypeInfo;
+
+    fn type_name<'i>(&self, info: &'i Self::TypeInfo) -> Option<&'i str> {
+        (**self).type_name(info)
+    }
+
+    fn concrete_type_name(&self, context: &Self::Context, info: &Self::TypeInfo) -> String {
+        (**self).concrete_type_name(context, info)
+    }
+
+    fn typename_override(&self, context: &Self::Context, info: &Self::TypeInfo) -> Option<String> {
+        (**self).typename_override(context, info)
+    }
+
+    fn resolve_into_type(
+        &self,
+        info: &Self::TypeInfo,
+        name: &str,
+        selection_set: Option<&[Selection<S>]>,
+        executor: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        (**self).resolve_into_type(info, name, selection_set, executor)
+    }
+
+    fn resolve_field(
+        &self,
+        info: &Self::TypeInfo,
+        field: &str,
+        args: &Arguments<S>,
+        executor: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        (**self).resolve_field(info, field, args, executor)
+    }
+
+    fn resolve(
+        &self,
+        info: &Self::TypeInfo,
+        selection_set: Option<&[Selection<S>]>,
+        executor: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        (**self).resolve(info, selection_set, executor)
+    }
+}
+
+impl<'e, S, T> GraphQLValueAsync<S> for Cow<'e, T>
+where
+    T: GraphQLValueAsync<S> + ToOwned + ?Sized,
+    T::Owned: Sync,
+    T::TypeInfo: Sync,
+    T::Context: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    fn resolve_async<'a>(
+        &'a self,
+        info: &'a Self::TypeInfo,
+        selection_set: Option<&'a [Selection<S>]>,
+        executor: &'a Executor<Self::Context, S>,
+    ) -> BoxFuture<'a, ExecutionResult<S>> {
+        (**self).resolve_async(info, selection_set, executor)
+    }
+}
@@ -320,7 +320,10 @@ mod impl_float_scalar {
     }
 
     pub(super) fn from_input<S: ScalarValue>(v: &InputValue<S>) -> Result<Float, String> {
+        // Integers are coerced into floats, as required by the spec, even if
+        // the `ScalarValue` doesn't represent them as floats on its own.
         v.as_float_value()
+            .or_else(|| v.as_int_value().map(f64::from))
             .ok_or_else(|| format!("Expected `Float`, found: {v}"))
     }
 
@@ -464,9 +467,13 @@ impl<T> Default for EmptySubscription<T> {
 
 #[cfg(test)]
 mod tests {
+    use serde::{Deserialize, Serialize};
+
     use crate::{
+        ast::{FromInputValue, InputValue},
         parser::ScalarToken,
         value::{DefaultScalarValue, ParseScalarValue},
+        ScalarValue,
     };
 
     use super::{EmptyMutation, EmptySubscription, ID};
@@ -555,6 +562,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn f64_from_int_input() {
+        #[derive(Clone, Debug, Deserialize, PartialEq, ScalarValue, Serialize)]
+        #[serde(untagged)]
+        enum StrictScalarValue {
+            #[value(as_int)]
+            Int(i32),
+            #[value(as_float)]
+            Float(f64),
+            #[value(as_str, as_string, into_string)]
+            String(String),
+            #[value(as_bool)]
+            Boolean(bool),
+        }
+
+        fn check<S: ScalarValue>() {
+            for (input, expected) in [
+                (InputValue::<S>::scalar(10), Ok(10.0)),
+                (InputValue::scalar(-3), Ok(-3.0)),
+                (InputValue::scalar(10.5), Ok(10.5)),
+                (
+                    InputValue::scalar("10".to_owned()),
+                    Err(r#"Expected `Float`, found: "10""#.to_owned()),
+                ),
+                (
+                    InputValue::scalar(true),
+                    Err("Expected `Float`, found: true".to_owned()),
+                ),
+            ] {
+                assert_eq!(
+                    f64::from_input_value(&input).map_err(|e| e.message().to_owned()),
+                    expected,
+                );
+            }
+        }
+
+        check::<DefaultScalarValue>();
+        check::<StrictScalarValue>();
+    }
+
     #[test]
     fn empty_mutation_is_send() {
         fn check_if_send<T: Send>() {}
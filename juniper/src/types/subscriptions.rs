@@ -272,10 +272,11 @@ where
     let meta_type = executor
         .schema()
         .concrete_type_by_name(
-            instance
-                .type_name(info)
-                .expect("Resolving named type's selection set")
-                .as_ref(),
+            executor.schema().schema_type_name(
+                instance
+                    .type_name(info)
+                    .expect("Resolving named type's selection set"),
+            ),
         )
         .expect("Type not found in schema");
 
@@ -310,6 +311,7 @@ where
                     f.name.item,
                     *start_pos,
                     f.selection_set.as_ref().map(|x| &x[..]),
+                    f.arguments.as_ref(),
                 );
 
                 let args = Arguments::new(
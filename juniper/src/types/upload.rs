@@ -0,0 +1,482 @@
+//! Support for uploading files according to the [GraphQL multipart request
+//! spec][0].
+//!
+//! The actual parsing of `multipart/form-data` requests is left to the server
+//! integrations, which are expected to:
+//! 1. Collect the uploaded files into [`Uploads`].
+//! 2. Substitute the files in the `operations` variables according to the
+//!    `map` via [`Uploads::map_variables()`].
+//! 3. Make the [`Uploads`] accessible from the [`Context`] implementing
+//!    [`UploadSource`], so resolvers are able to read them.
+//!
+//! [`Context`]: crate::Context
+//! [0]: https://github.com/jaydenseric/graphql-multipart-request-spec
+
+use std::{
+    collections::HashMap,
+    fmt,
+    io::{self, Read},
+    sync::{Mutex, PoisonError},
+};
+
+use crate::{
+    ast::{FromInputValue, InputValue, Selection},
+    executor::{ExecutionResult, Executor, FieldError, FieldResult, Registry, Variables},
+    macros::reflect,
+    parser::ScalarToken,
+    schema::meta::MetaType,
+    types::{
+        async_await::GraphQLValueAsync,
+        base::{GraphQLType, GraphQLValue},
+        marker::IsInputType,
+    },
+    value::{ParseScalarResult, ParseScalarValue, ScalarValue},
+    BoxFuture,
+};
+
+/// Handle of a file uploaded along with a query via the [GraphQL multipart
+/// request spec][0], to be used as an argument type.
+///
+/// It's an input-only scalar: a resolver receives it as an argument and reads
+/// the uploaded [`UploadFile`] with [`Upload::take_file()`]. Using it as an
+/// output type is rejected at compile time by the code generation, while
+/// resolving it manually always results in a field error.
+///
+/// [0]: https://github.com/jaydenseric/graphql-multipart-request-spec
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Upload {
+    key: String,
+}
+
+impl Upload {
+    /// Returns the key of the uploaded file in the `map` of the multipart
+    /// request.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Takes the [`UploadFile`] this [`Upload`] refers to out of the provided
+    /// [`UploadSource`] (usually, a [`Context`]).
+    ///
+    /// # Errors
+    ///
+    /// If the file hasn't been uploaded, or has been taken already.
+    ///
+    /// [`Context`]: crate::Context
+    pub fn take_file<U: UploadSource + ?Sized>(&self, source: &U) -> FieldResult<UploadFile> {
+        source.take_upload(&self.key).ok_or_else(|| {
+            FieldError::from(format!(
+                "File `{}` is not uploaded or has been read already",
+                self.key,
+            ))
+        })
+    }
+}
+
+impl<S: ScalarValue> FromInputValue<S> for Upload {
+    type Error = String;
+
+    fn from_input_value(v: &InputValue<S>) -> Result<Self, Self::Error> {
+        v.as_string_value()
+            .map(|key| Self { key: key.into() })
+            .ok_or_else(|| format!("Expected uploaded file, found: {v}"))
+    }
+}
+
+impl<S: ScalarValue> ParseScalarValue<S> for Upload {
+    fn from_str(value: ScalarToken<'_>) -> ParseScalarResult<S> {
+        <String as ParseScalarValue<S>>::from_str(value)
+    }
+}
+
+impl<S: ScalarValue> GraphQLType<S> for Upload {
+    fn name(_: &()) -> Option<&'static str> {
+        Some("Upload")
+    }
+
+    fn meta<'r>(info: &(), registry: &mut Registry<'r, S>) -> MetaType<'r, S>
+    where
+        S: 'r,
+    {
+        registry
+            .build_scalar_type::<Self>(info)
+            .description("File uploaded via the GraphQL multipart request spec.")
+            .specified_by_url("https://github.com/jaydenseric/graphql-multipart-request-spec")
+            .into_meta()
+    }
+}
+
+impl<S: ScalarValue> GraphQLValue<S> for Upload {
+    type Context = ();
+    type TypeInfo = ();
+
+    fn type_name<'i>(&self, info: &'i Self::TypeInfo) -> Option<&'i str> {
+        <Self as GraphQLType<S>>::name(info)
+    }
+
+    fn resolve(
+        &self,
+        _: &(),
+        _: Option<&[Selection<S>]>,
+        _: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        Err(FieldError::from(
+            "`Upload` scalar is input-only and cannot be resolved",
+        ))
+    }
+}
+
+impl<S> GraphQLValueAsync<S> for Upload
+where
+    S: ScalarValue + Send + Sync,
+{
+    fn resolve_async<'a>(
+        &'a self,
+        info: &'a Self::TypeInfo,
+        selection_set: Option<&'a [Selection<S>]>,
+        executor: &'a Executor<Self::Context, S>,
+    ) -> BoxFuture<'a, ExecutionResult<S>> {
+        Box::pin(futures::future::ready(self.resolve(
+            info,
+            selection_set,
+            executor,
+        )))
+    }
+}
+
+impl<S: ScalarValue> IsInputType<S> for Upload {}
+
+impl<S> reflect::BaseType<S> for Upload {
+    const NAME: reflect::Type = "Upload";
+}
+
+impl<S> reflect::BaseSubTypes<S> for Upload {
+    const NAMES: reflect::Types = &[<Self as reflect::BaseType<S>>::NAME];
+}
+
+impl<S> reflect::WrappedType<S> for Upload {
+    const VALUE: reflect::WrappedValue = 1;
+}
+
+/// File uploaded via the [GraphQL multipart request spec][0].
+///
+/// Reading it reads the contents of the file.
+///
+/// [0]: https://github.com/jaydenseric/graphql-multipart-request-spec
+pub struct UploadFile {
+    filename: String,
+    content_type: Option<String>,
+    stream: Box<dyn Read + Send>,
+}
+
+impl UploadFile {
+    /// Creates a new [`UploadFile`] with the provided `filename`,
+    /// `content_type` and `stream` of its contents.
+    pub fn new(
+        filename: impl Into<String>,
+        content_type: Option<String>,
+        stream: impl Read + Send + 'static,
+    ) -> Self {
+        Self {
+            filename: filename.into(),
+            content_type,
+            stream: Box::new(stream),
+        }
+    }
+
+    /// Returns the name of this [`UploadFile`], as provided by the client.
+    pub fn filename(&self) -> &str {
+        &self.filename
+    }
+
+    /// Returns the MIME type of this [`UploadFile`], if provided by the client.
+    pub fn content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+
+    /// Converts this [`UploadFile`] into the stream of its contents.
+    pub fn into_stream(self) -> Box<dyn Read + Send> {
+        self.stream
+    }
+}
+
+impl Read for UploadFile {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.stream.read(buf)
+    }
+}
+
+impl fmt::Debug for UploadFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UploadFile")
+            .field("filename", &self.filename)
+            .field("content_type", &self.content_type)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Source of [`UploadFile`]s that [`Upload`] arguments are coerced into.
+///
+/// Should be implemented for a [`Context`] providing access to the
+/// [`Uploads`] of the current request.
+///
+/// [`Context`]: crate::Context
+pub trait UploadSource {
+    /// Takes the [`UploadFile`] with the provided `key` out of this source, if
+    /// it's present.
+    fn take_upload(&self, key: &str) -> Option<UploadFile>;
+}
+
+impl<T: UploadSource + ?Sized> UploadSource for &T {
+    fn take_upload(&self, key: &str) -> Option<UploadFile> {
+        (**self).take_upload(key)
+    }
+}
+
+/// Files uploaded along with a single multipart request.
+#[derive(Debug, Default)]
+pub struct Uploads {
+    files: Mutex<HashMap<String, UploadFile>>,
+}
+
+impl Uploads {
+    /// Creates new empty [`Uploads`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the provided `file` with the provided `key` (the name of its
+    /// multipart form field).
+    ///
+    /// Overwrites any previously added file with the same `key`.
+    pub fn insert(&mut self, key: impl Into<String>, file: UploadFile) {
+        self.files
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key.into(), file);
+    }
+
+    /// Substitutes the added files into the provided `variables` according to
+    /// the `map` of the multipart request, so they're coerced into [`Upload`]
+    /// arguments.
+    ///
+    /// The `map` maps file keys to the paths of variables holding them, like
+    /// `{"0": ["variables.file"], "1": ["variables.files.0"]}`.
+    ///
+    /// # Errors
+    ///
+    /// If the `map` refers to a file not present in these [`Uploads`], or to a
+    /// path not present in the `variables`, or maps a single file to multiple
+    /// paths (an [`UploadFile`] may be read only once, so it cannot be shared
+    /// between several [`Upload`] arguments).
+    pub fn map_variables<S: ScalarValue>(
+        &self,
+        variables: &mut Variables<S>,
+        map: &HashMap<String, Vec<String>>,
+    ) -> Result<(), String> {
+        let files = self.files.lock().unwrap_or_else(PoisonError::into_inner);
+        for (key, paths) in map {
+            if !files.contains_key(key) {
+                return Err(format!("File `{key}` is mapped, but not uploaded"));
+            }
+            if paths.len() > 1 {
+                return Err(format!(
+                    "File `{key}` is mapped to multiple paths, which is not supported",
+                ));
+            }
+            for path in paths {
+                *variable_at_path(variables, path)
+                    .ok_or_else(|| format!("Invalid path `{path}` of file `{key}`"))? =
+                    InputValue::scalar(key.clone());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl UploadSource for Uploads {
+    fn take_upload(&self, key: &str) -> Option<UploadFile> {
+        self.files
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(key)
+    }
+}
+
+/// Looks up the value at the provided dot-separated `path` in the `variables`,
+/// like `variables.input.files.0`.
+fn variable_at_path<'v, S>(
+    variables: &'v mut Variables<S>,
+    path: &str,
+) -> Option<&'v mut InputValue<S>> {
+    let mut segments = path.strip_prefix("variables.")?.split('.');
+    let mut value = variables.get_mut(segments.next()?)?;
+    for segment in segments {
+        value = match value {
+            InputValue::Object(fields) => fields
+                .iter_mut()
+                .find_map(|(k, v)| (k.item == segment).then_some(&mut v.item))?,
+            InputValue::List(items) => &mut items.get_mut(segment.parse::<usize>().ok()?)?.item,
+            _ => return None,
+        };
+    }
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, io::Read as _};
+
+    use crate::{
+        execute_sync, graphql_object, graphql_value, graphql_vars, Context, EmptySubscription,
+        FieldResult, RootNode, Variables,
+    };
+
+    use super::{Upload, UploadFile, UploadSource, Uploads};
+
+    struct Ctx {
+        uploads: Uploads,
+    }
+
+    impl Context for Ctx {}
+
+    impl UploadSource for Ctx {
+        fn take_upload(&self, key: &str) -> Option<UploadFile> {
+            self.uploads.take_upload(key)
+        }
+    }
+
+    struct Query;
+
+    #[graphql_object(context = Ctx)]
+    impl Query {
+        fn ping() -> bool {
+            true
+        }
+    }
+
+    struct Mutation;
+
+    #[graphql_object(context = Ctx)]
+    impl Mutation {
+        fn upload(files: Vec<Upload>, ctx: &Ctx) -> FieldResult<Vec<String>> {
+            files
+                .iter()
+                .map(|upload| {
+                    let mut file = upload.take_file(ctx)?;
+                    let mut contents = String::new();
+                    file.read_to_string(&mut contents)?;
+                    Ok(format!(
+                        "{} ({}): {contents}",
+                        file.filename(),
+                        file.content_type().unwrap_or("unknown"),
+                    ))
+                })
+                .collect()
+        }
+    }
+
+    fn uploads() -> Uploads {
+        let mut uploads = Uploads::new();
+        uploads.insert(
+            "0",
+            UploadFile::new("a.txt", Some("text/plain".into()), "hello".as_bytes()),
+        );
+        uploads.insert("1", UploadFile::new("b.bin", None, [1_u8, 2].as_slice()));
+        uploads
+    }
+
+    fn map(entries: &[(&str, &[&str])]) -> HashMap<String, Vec<String>> {
+        entries
+            .iter()
+            .map(|(k, paths)| ((*k).into(), paths.iter().map(|&p| p.into()).collect()))
+            .collect()
+    }
+
+    #[test]
+    fn resolves_uploaded_files() {
+        const DOC: &str = "mutation($files: [Upload!]!) { upload(files: $files) }";
+
+        let schema = RootNode::new(Query, Mutation, EmptySubscription::<Ctx>::new());
+        let ctx = Ctx { uploads: uploads() };
+        let mut vars = graphql_vars! {"files": [null, null]};
+        ctx.uploads
+            .map_variables(
+                &mut vars,
+                &map(&[("0", &["variables.files.0"]), ("1", &["variables.files.1"])]),
+            )
+            .unwrap();
+
+        assert_eq!(
+            execute_sync(DOC, None, &schema, &vars, &ctx),
+            Ok((
+                graphql_value!({"upload": [
+                    "a.txt (text/plain): hello",
+                    "b.bin (unknown): \u{1}\u{2}",
+                ]}),
+                vec![],
+            )),
+        );
+
+        let res = execute_sync(DOC, None, &schema, &vars, &ctx).unwrap();
+        assert_eq!(res.0, graphql_value!(null));
+        assert_eq!(
+            res.1[0].error().message(),
+            "File `0` is not uploaded or has been read already",
+        );
+    }
+
+    #[test]
+    fn maps_nested_variables() {
+        let uploads = uploads();
+        let mut vars: Variables = graphql_vars! {"input": {"files": [null, {"file": null}]}};
+
+        uploads
+            .map_variables(
+                &mut vars,
+                &map(&[
+                    ("0", &["variables.input.files.1.file"]),
+                    ("1", &["variables.input.files.0"]),
+                ]),
+            )
+            .unwrap();
+
+        assert_eq!(
+            vars,
+            graphql_vars! {"input": {"files": ["1", {"file": "0"}]}},
+        );
+    }
+
+    #[test]
+    fn errors_on_invalid_map() {
+        let uploads = uploads();
+        let mut vars: Variables = graphql_vars! {"file": null};
+
+        for map in [
+            map(&[("2", &["variables.file"])]),
+            map(&[("0", &["variables.missing"])]),
+            map(&[("0", &["variables.file.0"])]),
+            map(&[("0", &["file"])]),
+        ] {
+            assert!(
+                uploads.map_variables(&mut vars, &map).is_err(),
+                "allows map: {map:?}",
+            );
+        }
+    }
+
+    #[test]
+    fn errors_on_file_mapped_to_multiple_paths() {
+        let uploads = uploads();
+        let mut vars: Variables = graphql_vars! {"file": null, "files": [null]};
+
+        assert_eq!(
+            uploads.map_variables(
+                &mut vars,
+                &map(&[("0", &["variables.file", "variables.files.0"])]),
+            ),
+            Err("File `0` is mapped to multiple paths, which is not supported".into()),
+        );
+        assert_eq!(vars, graphql_vars! {"file": null, "files": [null]});
+    }
+}
@@ -1,48 +1,65 @@
 use crate::{
     ast::InputValue,
+    executor::FieldError,
     schema::{
-        meta::{EnumMeta, InputObjectMeta, MetaType},
+        meta::{EnumMeta, InputObjectMeta, MetaType, ScalarMeta},
         model::{SchemaType, TypeType},
     },
     value::ScalarValue,
 };
 use std::collections::HashSet;
 
+#[doc(hidden)]
 pub fn is_valid_literal_value<S>(
     schema: &SchemaType<S>,
     arg_type: &TypeType<S>,
     arg_value: &InputValue<S>,
 ) -> bool
+where
+    S: ScalarValue,
+{
+    validate_literal_value(schema, arg_type, arg_value).is_ok()
+}
+
+/// Validates the provided `arg_value` literal against the `arg_type`.
+///
+/// Returns the [`FieldError`] of the first failed input value coercion, if
+/// the literal is invalid because of it, or [`None`] otherwise.
+pub(crate) fn validate_literal_value<S>(
+    schema: &SchemaType<S>,
+    arg_type: &TypeType<S>,
+    arg_value: &InputValue<S>,
+) -> Result<(), Option<FieldError<S>>>
 where
     S: ScalarValue,
 {
     match *arg_type {
         TypeType::NonNull(ref inner) => {
             if arg_value.is_null() {
-                false
+                Err(None)
             } else {
-                is_valid_literal_value(schema, inner, arg_value)
+                validate_literal_value(schema, inner, arg_value)
             }
         }
         TypeType::List(ref inner, expected_size) => match *arg_value {
-            InputValue::Null | InputValue::Variable(_) => true,
+            InputValue::Null | InputValue::Variable(_) => Ok(()),
             InputValue::List(ref items) => {
                 if let Some(expected) = expected_size {
                     if items.len() != expected {
-                        return false;
+                        return Err(None);
                     }
                 }
                 items
                     .iter()
-                    .all(|i| is_valid_literal_value(schema, inner, &i.item))
+                    .try_for_each(|i| validate_literal_value(schema, inner, &i.item))
             }
             ref v => {
                 if let Some(expected) = expected_size {
                     if expected != 1 {
-                        return false;
+                        return Err(None);
                     }
                 }
-                is_valid_literal_value(schema, inner, v)
+                validate_literal_value(schema, inner, v)
             }
         },
         TypeType::Concrete(t) => {
@@ -51,24 +68,49 @@ where
             if let (&InputValue::Scalar(_), Some(&MetaType::Enum(EnumMeta { .. }))) =
                 (arg_value, arg_type.to_concrete())
             {
-                return false;
+                return Err(None);
+            }
+
+            // Structured scalars accept any literal, leaving its validation
+            // to their parsing function.
+            if let MetaType::Scalar(ScalarMeta {
+                is_structured: true,
+                ..
+            }) = *t
+            {
+                return match *arg_value {
+                    InputValue::Null | InputValue::Variable(_) => Ok(()),
+                    ref v => t
+                        .input_value_parse_fn()
+                        .map_or(Err(None), |f| f(v).map_err(Some)),
+                };
             }
 
             match *arg_value {
-                InputValue::Null | InputValue::Variable(_) => true,
+                InputValue::Null | InputValue::Variable(_) => Ok(()),
                 ref v @ InputValue::Scalar(_) | ref v @ InputValue::Enum(_) => {
                     if let Some(parse_fn) = t.input_value_parse_fn() {
-                        parse_fn(v).is_ok()
+                        parse_fn(v).map_err(Some)
                     } else {
-                        false
+                        Err(None)
                     }
                 }
-                InputValue::List(_) => false,
+                InputValue::List(_) => Err(None),
                 InputValue::Object(ref obj) => {
                     if let MetaType::InputObject(InputObjectMeta {
-                        ref input_fields, ..
+                        ref input_fields,
+                        is_one_of,
+                        ..
                     }) = *t
                     {
+                        // `@oneOf` input object requires exactly one non-null
+                        // field to be provided. A variable provided as its
+                        // field is required to be of a non-null type by the
+                        // `VariablesInAllowedPosition` validation rule.
+                        if is_one_of && (obj.len() != 1 || obj[0].1.item.is_null()) {
+                            return Err(None);
+                        }
+
                         let mut remaining_required_fields = input_fields
                             .iter()
                             .filter_map(|f| {
@@ -80,23 +122,37 @@ where
                             })
                             .collect::<HashSet<_>>();
 
-                        let all_types_ok = obj.iter().all(|&(ref key, ref value)| {
-                            remaining_required_fields.remove(&key.item);
-                            if let Some(ref arg_type) = input_fields
-                                .iter()
-                                .filter(|f| f.name == key.item)
-                                .map(|f| schema.make_type(&f.arg_type))
-                                .next()
-                            {
-                                is_valid_literal_value(schema, arg_type, &value.item)
+                        // An aliased field may be provided under one of its
+                        // names only.
+                        if input_fields.iter().any(|f| {
+                            f.accepted_names()
+                                .filter(|n| obj.iter().any(|(k, _)| k.item == *n))
+                                .count()
+                                > 1
+                        }) {
+                            return Err(None);
+                        }
+
+                        obj.iter().try_for_each(|&(ref key, ref value)| {
+                            if let Some(f) = input_fields.iter().find(|f| f.is_named(&key.item)) {
+                                remaining_required_fields.remove(&f.name);
+                                validate_literal_value(
+                                    schema,
+                                    &schema.make_type(&f.arg_type),
+                                    &value.item,
+                                )
                             } else {
-                                false
+                                Err(None)
                             }
-                        });
+                        })?;
 
-                        all_types_ok && remaining_required_fields.is_empty()
+                        if remaining_required_fields.is_empty() {
+                            Ok(())
+                        } else {
+                            Err(None)
+                        }
                     } else {
-                        false
+                        Err(None)
                     }
                 }
             }
@@ -0,0 +1,407 @@
+use std::fmt;
+
+use fnv::FnvHashMap;
+
+use crate::{
+    ast::{FromInputValue, InputValue, Type},
+    executor::{FieldError, IntoFieldError, Registry},
+    schema::meta::MetaType,
+    types::base::GraphQLType,
+    value::ScalarValue,
+};
+
+/// Error of coercing an [`InputValue`] into a Rust type via
+/// [`InputValue::coerce_into()`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CoercionError {
+    kind: CoercionErrorKind,
+    path: Vec<String>,
+    message: String,
+}
+
+/// Kind of a [`CoercionError`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CoercionErrorKind {
+    /// Required field of an input object is not provided.
+    MissingField,
+
+    /// Provided field is not defined on an input object.
+    UnexpectedField,
+
+    /// Provided value has a wrong kind (an object instead of a list, a string
+    /// instead of an integer, etc).
+    WrongKind,
+
+    /// Provided value has a correct kind, but is rejected by the
+    /// [`FromInputValue`] implementation.
+    Invalid,
+}
+
+impl CoercionError {
+    fn new(kind: CoercionErrorKind, path: &[String], message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            path: path.to_vec(),
+            message: message.into(),
+        }
+    }
+
+    /// Returns the [`CoercionErrorKind`] of this [`CoercionError`].
+    pub fn kind(&self) -> CoercionErrorKind {
+        self.kind
+    }
+
+    /// Returns the path to the offending value, consisting of input object
+    /// field names and list indices.
+    ///
+    /// Empty, if the coerced [`InputValue`] itself is the offending one.
+    pub fn path(&self) -> &[String] {
+        &self.path
+    }
+
+    /// Returns the message describing this [`CoercionError`].
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for CoercionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{} at `{}`", self.message, self.path.join("."))
+        }
+    }
+}
+
+impl std::error::Error for CoercionError {}
+
+impl<S> IntoFieldError<S> for CoercionError {
+    fn into_field_error(self) -> FieldError<S> {
+        FieldError::from(self)
+    }
+}
+
+impl<S: ScalarValue> InputValue<S> {
+    /// Coerces this [`InputValue`] into the provided Rust type `T`.
+    ///
+    /// Unlike [`FromInputValue::from_input_value()`], pinpoints the offending
+    /// value in the returned [`CoercionError`], which is useful for precise
+    /// error messages when coercing nested input objects.
+    ///
+    /// # Errors
+    ///
+    /// If this [`InputValue`] doesn't represent a valid `T`.
+    pub fn coerce_into<T>(&self) -> Result<T, CoercionError>
+    where
+        T: FromInputValue<S> + GraphQLType<S, TypeInfo = ()>,
+        T::Error: IntoFieldError<S>,
+    {
+        let mut registry = Registry::new(FnvHashMap::default());
+        let ty = registry.get_type::<T>(&());
+
+        check_value(&registry, self, &ty, &mut Vec::new())?;
+
+        T::from_input_value(self).map_err(|e| {
+            CoercionError::new(
+                CoercionErrorKind::Invalid,
+                &[],
+                e.into_field_error().message(),
+            )
+        })
+    }
+}
+
+/// Checks whether the provided `value` may be coerced into the provided `ty`,
+/// tracking the `path` to the currently checked `value`.
+fn check_value<S: ScalarValue>(
+    registry: &Registry<'_, S>,
+    value: &InputValue<S>,
+    ty: &Type<'_>,
+    path: &mut Vec<String>,
+) -> Result<(), CoercionError> {
+    if let InputValue::Variable(name) = value {
+        return Err(CoercionError::new(
+            CoercionErrorKind::WrongKind,
+            path,
+            format!("Expected constant value, found variable `${name}`"),
+        ));
+    }
+
+    let of_type = match ty {
+        Type::NonNullNamed(_) | Type::NonNullList(..) if value.is_null() => {
+            return Err(CoercionError::new(
+                CoercionErrorKind::WrongKind,
+                path,
+                format!("Expected non-null `{ty}`, found null"),
+            ));
+        }
+        _ if value.is_null() => return Ok(()),
+        Type::List(of_type, _) | Type::NonNullList(of_type, _) => of_type,
+        Type::Named(name) | Type::NonNullNamed(name) => {
+            return check_named_value(registry, value, name, path);
+        }
+    };
+
+    if let InputValue::List(items) = value {
+        for (i, item) in items.iter().enumerate() {
+            path.push(i.to_string());
+            check_value(registry, &item.item, of_type, path)?;
+            path.pop();
+        }
+        Ok(())
+    } else {
+        // A single value is coerced into a list of one element.
+        check_value(registry, value, of_type, path)
+    }
+}
+
+/// Checks whether the provided non-null `value` may be coerced into the named
+/// type with the provided `name`.
+fn check_named_value<S: ScalarValue>(
+    registry: &Registry<'_, S>,
+    value: &InputValue<S>,
+    name: &str,
+    path: &mut Vec<String>,
+) -> Result<(), CoercionError> {
+    let meta = registry.types.get(name).ok_or_else(|| {
+        CoercionError::new(
+            CoercionErrorKind::WrongKind,
+            path,
+            format!("Unknown type `{name}`"),
+        )
+    })?;
+
+    if let MetaType::InputObject(meta) = meta {
+        let obj = if let Some(obj) = value.to_object_value() {
+            obj
+        } else {
+            return Err(CoercionError::new(
+                CoercionErrorKind::WrongKind,
+                path,
+                format!("Expected input object `{name}`, found: {value}"),
+            ));
+        };
+
+        for key in obj.keys() {
+            if !meta.input_fields.iter().any(|f| f.is_named(key)) {
+                path.push((*key).into());
+                return Err(CoercionError::new(
+                    CoercionErrorKind::UnexpectedField,
+                    path,
+                    format!("Field `{key}` is not defined on input object `{name}`"),
+                ));
+            }
+        }
+
+        if meta.is_one_of && (obj.len() != 1 || obj.values().any(|v| v.is_null())) {
+            return Err(CoercionError::new(
+                CoercionErrorKind::Invalid,
+                path,
+                format!(
+                    "Exactly one non-null field must be provided for `@oneOf` input object \
+                     `{name}`",
+                ),
+            ));
+        }
+
+        for field in &meta.input_fields {
+            path.push(field.name.clone());
+            let provided = field
+                .accepted_names()
+                .filter(|n| obj.contains_key(n))
+                .collect::<Vec<_>>();
+            if let [field_name, alias, ..] = provided[..] {
+                return Err(CoercionError::new(
+                    CoercionErrorKind::Invalid,
+                    path,
+                    format!(
+                        "Field `{field_name}` of input object `{name}` is provided along with \
+                         its alias `{alias}`",
+                    ),
+                ));
+            }
+            match provided.first().and_then(|n| obj.get(n)) {
+                Some(v) => check_value(registry, v, &field.arg_type, path)?,
+                None if field.arg_type.is_non_null() && field.default_value.is_none() => {
+                    return Err(CoercionError::new(
+                        CoercionErrorKind::MissingField,
+                        path,
+                        format!(
+                            "Required field `{}` of input object `{name}` is not provided",
+                            field.name,
+                        ),
+                    ));
+                }
+                None => {}
+            }
+            path.pop();
+        }
+        Ok(())
+    } else if let Some(parse) = meta.input_value_parse_fn() {
+        parse(value)
+            .map_err(|e| CoercionError::new(CoercionErrorKind::WrongKind, path, e.message()))
+    } else {
+        Err(CoercionError::new(
+            CoercionErrorKind::WrongKind,
+            path,
+            format!("Type `{name}` is not an input type"),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{graphql_input_value, GraphQLEnum, GraphQLInputObject, InputValue};
+
+    use super::{CoercionError, CoercionErrorKind};
+
+    #[derive(Debug, GraphQLEnum, PartialEq)]
+    enum Kind {
+        Home,
+        Work,
+    }
+
+    #[derive(Debug, GraphQLInputObject, PartialEq)]
+    struct Address {
+        city: String,
+        kind: Option<Kind>,
+    }
+
+    #[derive(Debug, GraphQLInputObject, PartialEq)]
+    struct Person {
+        name: String,
+        #[graphql(default = 18)]
+        age: i32,
+        addresses: Vec<Address>,
+    }
+
+    fn coerce(v: InputValue) -> Result<Person, CoercionError> {
+        v.coerce_into::<Person>()
+    }
+
+    fn error(v: InputValue) -> (CoercionErrorKind, Vec<String>) {
+        let e = coerce(v).unwrap_err();
+        (e.kind(), e.path().to_vec())
+    }
+
+    fn path(p: &[&str]) -> Vec<String> {
+        p.iter().map(|s| (*s).into()).collect()
+    }
+
+    #[test]
+    fn coerces_valid_value() {
+        assert_eq!(
+            coerce(graphql_input_value!({
+                "name": "Luke",
+                "addresses": {"city": "Mos Eisley", "kind": HOME},
+            })),
+            Ok(Person {
+                name: "Luke".into(),
+                age: 18,
+                addresses: vec![Address {
+                    city: "Mos Eisley".into(),
+                    kind: Some(Kind::Home),
+                }],
+            }),
+        );
+    }
+
+    #[test]
+    fn reports_missing_field() {
+        assert_eq!(
+            error(graphql_input_value!({
+                "name": "Luke",
+                "addresses": [{"city": "Mos Eisley"}, {"kind": WORK}],
+            })),
+            (
+                CoercionErrorKind::MissingField,
+                path(&["addresses", "1", "city"])
+            ),
+        );
+    }
+
+    #[test]
+    fn reports_unexpected_field() {
+        assert_eq!(
+            error(graphql_input_value!({
+                "name": "Luke",
+                "addresses": [{"city": "Mos Eisley", "planet": "Tatooine"}],
+            })),
+            (
+                CoercionErrorKind::UnexpectedField,
+                path(&["addresses", "0", "planet"]),
+            ),
+        );
+    }
+
+    #[test]
+    fn reports_wrong_kind() {
+        assert_eq!(
+            error(graphql_input_value!({
+                "name": "Luke",
+                "addresses": [{"city": "Mos Eisley", "kind": 1}],
+            })),
+            (
+                CoercionErrorKind::WrongKind,
+                path(&["addresses", "0", "kind"])
+            ),
+        );
+        assert_eq!(
+            error(graphql_input_value!({"name": null, "addresses": []})),
+            (CoercionErrorKind::WrongKind, path(&["name"])),
+        );
+        assert_eq!(
+            error(graphql_input_value!(["Luke"])),
+            (CoercionErrorKind::WrongKind, vec![]),
+        );
+    }
+
+    #[derive(Debug, GraphQLInputObject, PartialEq)]
+    #[graphql(one_of)]
+    struct ContactBy {
+        email: Option<String>,
+        address: Option<Address>,
+    }
+
+    #[test]
+    fn reports_invalid_one_of() {
+        let invalid: [InputValue; 3] = [
+            graphql_input_value!([{}]),
+            graphql_input_value!([{"email": null}]),
+            graphql_input_value!([{"email": "luke@tatooine", "address": {"city": "Mos Eisley"}}]),
+        ];
+        for v in invalid {
+            let e = v.coerce_into::<Vec<ContactBy>>().unwrap_err();
+
+            assert_eq!(
+                (e.kind(), e.path().to_vec()),
+                (CoercionErrorKind::Invalid, path(&["0"])),
+            );
+        }
+
+        let valid: InputValue = graphql_input_value!({"address": {"city": "Mos Eisley"}});
+        assert_eq!(
+            valid.coerce_into::<ContactBy>(),
+            Ok(ContactBy {
+                email: None,
+                address: Some(Address {
+                    city: "Mos Eisley".into(),
+                    kind: None,
+                }),
+            }),
+        );
+    }
+
+    #[test]
+    fn displays_path() {
+        let e = coerce(graphql_input_value!({"name": "Luke", "addresses": [{}]})).unwrap_err();
+
+        assert_eq!(
+            e.to_string(),
+            "Required field `city` of input object `Address` is not provided \
+             at `addresses.0.city`",
+        );
+    }
+}
@@ -0,0 +1,243 @@
+use std::{collections::HashMap, fmt};
+
+use crate::{
+    ast::{Definition, Directive, Document, Operation, OperationType, Selection},
+    executor::Variables,
+    parser::Spanning,
+    schema::{meta::MetaType, model::SchemaType},
+    types::base::Arguments,
+    validation::RuleError,
+    value::ScalarValue,
+};
+
+/// Error of estimating the cost of a query via [`RootNode::estimate_cost()`].
+///
+/// [`RootNode::estimate_cost()`]: crate::RootNode::estimate_cost
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CostError {
+    /// Query spreads a fragment with the provided name, which is not defined
+    /// in the query.
+    UnknownFragment(String),
+
+    /// Query spreads the fragment with the provided name inside itself
+    /// (directly or via other fragments).
+    FragmentCycle(String),
+}
+
+impl fmt::Display for CostError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownFragment(name) => write!(f, "Unknown fragment `{name}`"),
+            Self::FragmentCycle(name) => write!(f, "Fragment `{name}` spreads itself"),
+        }
+    }
+}
+
+impl std::error::Error for CostError {}
+
+/// Estimates the cost of the most expensive operation in the provided
+/// `document`.
+pub(crate) fn document_cost<S: ScalarValue>(
+    schema: &SchemaType<S>,
+    document: &Document<S>,
+    variables: &Variables<S>,
+) -> Result<u64, CostError> {
+    document
+        .iter()
+        .filter_map(|def| match def {
+            Definition::Operation(op) => {
+                Some(operation_cost(schema, document, &op.item, variables))
+            }
+            Definition::Fragment(_) => None,
+        })
+        .try_fold(0, |max, cost| Ok(max.max(cost?)))
+}
+
+/// Estimates the cost of the provided `operation`.
+pub(crate) fn operation_cost<S: ScalarValue>(
+    schema: &SchemaType<S>,
+    document: &Document<S>,
+    operation: &Operation<S>,
+    variables: &Variables<S>,
+) -> Result<u64, CostError> {
+    let root = match operation.operation_type {
+        OperationType::Query => Some(schema.concrete_query_type()),
+        OperationType::Mutation => schema.concrete_mutation_type(),
+        OperationType::Subscription => schema.concrete_subscription_type(),
+    };
+    Estimator {
+        schema,
+        document,
+        variables,
+        spreads: Vec::new(),
+        fragment_costs: HashMap::new(),
+    }
+    .selection_set_cost(&operation.selection_set, root)
+}
+
+/// Checks the estimated cost of the `operation` against the maximum allowed
+/// one of the `schema`, returning the estimated cost, if any was estimated.
+#[doc(hidden)]
+pub fn validate_cost<S>(
+    operation: &Spanning<Operation<S>>,
+    document: &Document<S>,
+    variables: &Variables<S>,
+    schema: &SchemaType<S>,
+) -> Result<Option<u64>, Vec<RuleError>>
+where
+    S: ScalarValue,
+{
+    let max_cost = if let Some(max_cost) = schema.max_cost {
+        max_cost
+    } else {
+        return Ok(None);
+    };
+
+    match operation_cost(schema, document, &operation.item, variables) {
+        Ok(cost) if cost > max_cost => Err(vec![RuleError::new(
+            &format!("Query cost {cost} exceeds maximum allowed cost {max_cost}"),
+            &[operation.start],
+        )]),
+        Ok(cost) => Ok(Some(cost)),
+        Err(e) => Err(vec![RuleError::new(&e.to_string(), &[operation.start])]),
+    }
+}
+
+/// Walker over selection sets of a [`Document`], summing up the costs of the
+/// selected fields.
+struct Estimator<'a, S> {
+    schema: &'a SchemaType<'a, S>,
+    document: &'a Document<'a, S>,
+    variables: &'a Variables<S>,
+
+    /// Names of the fragments being spread at the moment, for detecting cycles.
+    spreads: Vec<&'a str>,
+
+    /// Already estimated costs of the fragments, by their names, so a fragment
+    /// spread multiple times is walked only once.
+    fragment_costs: HashMap<&'a str, u64>,
+}
+
+impl<'a, S: ScalarValue> Estimator<'a, S> {
+    /// Estimates the cost of the provided selection `set` made on the
+    /// `parent` type.
+    ///
+    /// Fields of an unknown `parent` type are considered to have the default
+    /// complexity.
+    fn selection_set_cost(
+        &mut self,
+        set: &'a [Selection<'a, S>],
+        parent: Option<&'a MetaType<'a, S>>,
+    ) -> Result<u64, CostError> {
+        let mut total = 0_u64;
+        for selection in set {
+            let cost = match selection {
+                Selection::Field(Spanning { item: field, .. }) => {
+                    if is_excluded(&field.directives, self.variables) {
+                        continue;
+                    }
+
+                    let meta_field = parent.and_then(|p| p.field_by_name(field.name.item));
+                    let child_cost = if let Some(set) = &field.selection_set {
+                        let ty = meta_field.and_then(|f| {
+                            self.schema
+                                .concrete_type_by_name(f.field_type.innermost_name())
+                        });
+                        self.selection_set_cost(set, ty)?
+                    } else {
+                        0
+                    };
+
+                    if let Some(meta_field) = meta_field {
+                        let args = Arguments::new(
+                            field.arguments.as_ref().map(|m| {
+                                m.item
+                                    .iter()
+                                    .filter_map(|(k, v)| {
+                                        v.item
+                                            .clone()
+                                            .into_const(self.variables)
+                                            .map(|v| (k.item, v))
+                                    })
+                                    .collect()
+                            }),
+                            &meta_field.arguments,
+                        );
+                        meta_field.complexity.cost(&args, child_cost)
+                    } else {
+                        child_cost.saturating_add(1)
+                    }
+                }
+                Selection::FragmentSpread(Spanning { item: spread, .. }) => {
+                    if is_excluded(&spread.directives, self.variables) {
+                        continue;
+                    }
+
+                    let name = spread.name.item;
+                    if self.spreads.contains(&name) {
+                        return Err(CostError::FragmentCycle(name.into()));
+                    }
+                    if let Some(&cost) = self.fragment_costs.get(name) {
+                        total = total.saturating_add(cost);
+                        continue;
+                    }
+                    let fragment = self
+                        .document
+                        .iter()
+                        .find_map(|def| match def {
+                            Definition::Fragment(f) if f.item.name.item == name => Some(&f.item),
+                            _ => None,
+                        })
+                        .ok_or_else(|| CostError::UnknownFragment(name.into()))?;
+
+                    self.spreads.push(name);
+                    let ty = self
+                        .schema
+                        .concrete_type_by_name(fragment.type_condition.item);
+                    let cost = self.selection_set_cost(&fragment.selection_set, ty)?;
+                    self.spreads.pop();
+                    self.fragment_costs.insert(name, cost);
+                    cost
+                }
+                Selection::InlineFragment(Spanning { item: fragment, .. }) => {
+                    if is_excluded(&fragment.directives, self.variables) {
+                        continue;
+                    }
+
+                    let ty = match &fragment.type_condition {
+                        Some(cond) => self.schema.concrete_type_by_name(cond.item),
+                        None => parent,
+                    };
+                    self.selection_set_cost(&fragment.selection_set, ty)?
+                }
+            };
+            total = total.saturating_add(cost);
+        }
+        Ok(total)
+    }
+}
+
+/// Checks whether the provided `directives` exclude their selection from the
+/// query.
+///
+/// Unlike the executor, doesn't expect the `directives` to be validated, so
+/// considers the selection as included if the condition cannot be evaluated.
+pub(super) fn is_excluded<S: ScalarValue>(
+    directives: &Option<Vec<Spanning<Directive<S>>>>,
+    variables: &Variables<S>,
+) -> bool {
+    directives.iter().flatten().any(|d| {
+        let condition = || {
+            d.item
+                .arguments
+                .iter()
+                .flat_map(|m| m.item.get("if"))
+                .find_map(|v| v.item.clone().into_const(variables)?.convert().ok())
+        };
+        match d.item.name.item {
+            "skip" => condition() == Some(true),
+            "include" => condition() == Some(false),
+            _ => false,
+        }
+    })
+}
@@ -1,4 +1,5 @@
 use std::{
+    cmp::Ordering,
     collections::HashSet,
     fmt::{self, Debug},
 };
@@ -7,17 +8,23 @@ use crate::ast::{Definition, Document, Type};
 
 use crate::schema::{meta::MetaType, model::SchemaType};
 
-use crate::parser::SourcePosition;
+use crate::{parser::SourcePosition, value::Value};
 
 /// Query validation error
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+///
+/// [`RuleError::extensions`] are not taken into account when comparing
+/// [`RuleError`]s, so errors are ordered and deduplicated by their locations
+/// and messages only.
+#[derive(Debug)]
 pub struct RuleError {
     locations: Vec<SourcePosition>,
     message: String,
+    extensions: Value,
 }
 
-#[doc(hidden)]
+/// Context of a query [`Document`] validation, collecting the found errors.
 pub struct ValidatorContext<'a, S: Debug + 'a> {
+    /// Schema the query is validated against.
     pub schema: &'a SchemaType<'a, S>,
     errors: Vec<RuleError>,
     type_stack: Vec<Option<&'a MetaType<'a, S>>>,
@@ -34,9 +41,23 @@ impl RuleError {
         Self {
             message: message.into(),
             locations: locations.to_vec(),
+            extensions: Value::null(),
         }
     }
 
+    /// Attaches the provided `extensions` to this validation error.
+    ///
+    /// Usually, these are the `extensions` of a [`FieldError`] returned while
+    /// coercing an input value (see [`FromInputValue`]).
+    ///
+    /// [`FieldError`]: crate::FieldError
+    /// [`FromInputValue`]: crate::FromInputValue
+    #[must_use]
+    pub fn with_extensions(mut self, extensions: Value) -> Self {
+        self.extensions = extensions;
+        self
+    }
+
     /// Access the message for a validation error
     pub fn message(&self) -> &str {
         &self.message
@@ -49,6 +70,34 @@ impl RuleError {
     pub fn locations(&self) -> &[SourcePosition] {
         &self.locations
     }
+
+    /// Access the additional information of the validation error
+    ///
+    /// Is [`Value::Null`] unless explicitly provided via
+    /// [`RuleError::with_extensions()`].
+    pub fn extensions(&self) -> &Value {
+        &self.extensions
+    }
+}
+
+impl PartialEq for RuleError {
+    fn eq(&self, other: &Self) -> bool {
+        self.locations == other.locations && self.message == other.message
+    }
+}
+
+impl Eq for RuleError {}
+
+impl PartialOrd for RuleError {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RuleError {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.locations, &self.message).cmp(&(&other.locations, &other.message))
+    }
 }
 
 impl fmt::Display for RuleError {
@@ -93,7 +142,8 @@ impl<'a, S: Debug> ValidatorContext<'a, S> {
         self.errors.append(&mut errors);
     }
 
-    #[doc(hidden)]
+    /// Reports a validation error with the provided `message` at the given
+    /// `locations` of the query.
     pub fn report_error(&mut self, message: &str, locations: &[SourcePosition]) {
         self.errors.push(RuleError::new(message, locations))
     }
@@ -165,7 +215,8 @@ impl<'a, S: Debug> ValidatorContext<'a, S> {
         res
     }
 
-    #[doc(hidden)]
+    /// Returns the type of the currently visited node of the query, if it's
+    /// known.
     pub fn current_type(&self) -> Option<&'a MetaType<'a, S>> {
         *self.type_stack.last().unwrap_or(&None)
     }
@@ -178,7 +229,8 @@ impl<'a, S: Debug> ValidatorContext<'a, S> {
         }
     }
 
-    #[doc(hidden)]
+    /// Returns the type of the currently visited selection set's parent, if
+    /// it's known.
     pub fn parent_type(&self) -> Option<&'a MetaType<'a, S>> {
         *self.parent_type_stack.last().unwrap_or(&None)
     }
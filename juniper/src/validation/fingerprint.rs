@@ -0,0 +1,249 @@
+use std::{
+    collections::BTreeMap,
+    hash::{Hash, Hasher},
+};
+
+use fnv::FnvHasher;
+
+use crate::{
+    ast::{Definition, Directive, Document, InputValue, Operation, OperationType, Selection},
+    executor::Variables,
+    parser::Spanning,
+    schema::{meta::MetaType, model::SchemaType},
+    validation::{complexity::is_excluded, RuleError},
+    value::ScalarValue,
+    GraphQLError,
+};
+
+/// Computes the fingerprint of the provided `operation` of the `document`,
+/// executed with the provided `variables`.
+pub(crate) fn operation_fingerprint<S: ScalarValue>(
+    schema: &SchemaType<S>,
+    document: &Document<S>,
+    operation: &Operation<S>,
+    variables: &Variables<S>,
+) -> Result<u64, GraphQLError> {
+    let mut variables = variables.clone();
+    for (name, def) in operation
+        .variable_definitions
+        .iter()
+        .flat_map(|d| d.item.iter())
+    {
+        if let Some(default) = &def.default_value {
+            variables
+                .entry(name.item.into())
+                .or_insert_with(|| default.item.clone());
+        }
+    }
+
+    let root = match operation.operation_type {
+        OperationType::Query => Some(schema.concrete_query_type()),
+        OperationType::Mutation => schema.concrete_mutation_type(),
+        OperationType::Subscription => schema.concrete_subscription_type(),
+    };
+    let mut node = Node::default();
+    Collector {
+        schema,
+        document,
+        variables: &variables,
+        spreads: Vec::new(),
+    }
+    .collect(&mut node, &operation.selection_set, root)?;
+
+    let mut hasher = FnvHasher::default();
+    operation_type_index(operation.operation_type).hash(&mut hasher);
+    node.hash(&mut hasher);
+    Ok(hasher.finish())
+}
+
+fn operation_type_index(ty: OperationType) -> u8 {
+    match ty {
+        OperationType::Query => 0,
+        OperationType::Mutation => 1,
+        OperationType::Subscription => 2,
+    }
+}
+
+/// Canonical representation of a selection set, with all its fragments
+/// flattened and its fields ordered by their response keys.
+#[derive(Debug, Default, Hash)]
+struct Node {
+    /// Selected fields, keyed by their response keys (aliases or names).
+    fields: BTreeMap<String, FieldNode>,
+
+    /// Selections made on a type different from the parent one, keyed by the
+    /// name of that type.
+    conditions: BTreeMap<String, Node>,
+}
+
+/// Canonical representation of a selected field.
+#[derive(Debug, Default, Hash)]
+struct FieldNode {
+    name: String,
+    arguments: BTreeMap<String, String>,
+    directives: Vec<String>,
+    selection: Node,
+}
+
+/// Walker over selection sets of a [`Document`], collecting them into a
+/// canonical [`Node`].
+struct Collector<'a, S> {
+    schema: &'a SchemaType<'a, S>,
+    document: &'a Document<'a, S>,
+    variables: &'a Variables<S>,
+
+    /// Names of the fragments being spread at the moment, for detecting cycles.
+    spreads: Vec<&'a str>,
+}
+
+impl<'a, S: ScalarValue> Collector<'a, S> {
+    /// Collects the provided selection `set` made on the `parent` type into
+    /// the `node`.
+    fn collect(
+        &mut self,
+        node: &mut Node,
+        set: &'a [Selection<'a, S>],
+        parent: Option<&'a MetaType<'a, S>>,
+    ) -> Result<(), GraphQLError> {
+        for selection in set {
+            match selection {
+                Selection::Field(Spanning { item: field, .. }) => {
+                    if is_excluded(&field.directives, self.variables) {
+                        continue;
+                    }
+
+                    let key = field.alias.as_ref().unwrap_or(&field.name).item;
+                    let entry = node.fields.entry(key.into()).or_insert_with(|| FieldNode {
+                        name: field.name.item.into(),
+                        arguments: field
+                            .arguments
+                            .iter()
+                            .flat_map(|args| args.item.iter())
+                            .map(|(k, v)| (k.item.into(), self.const_value(&v.item)))
+                            .collect(),
+                        directives: self.directives(&field.directives),
+                        selection: Node::default(),
+                    });
+
+                    if let Some(set) = &field.selection_set {
+                        let ty = parent
+                            .and_then(|p| p.field_by_name(field.name.item))
+                            .and_then(|f| {
+                                self.schema
+                                    .concrete_type_by_name(f.field_type.innermost_name())
+                            });
+                        self.collect(&mut entry.selection, set, ty)?;
+                    }
+                }
+                Selection::FragmentSpread(Spanning {
+                    item: spread,
+                    start,
+                    ..
+                }) => {
+                    if is_excluded(&spread.directives, self.variables) {
+                        continue;
+                    }
+
+                    let name = spread.name.item;
+                    if self.spreads.contains(&name) {
+                        return Err(GraphQLError::ValidationError(vec![RuleError::new(
+                            &format!("Fragment `{name}` spreads itself"),
+                            &[*start],
+                        )]));
+                    }
+                    let fragment = self
+                        .document
+                        .iter()
+                        .find_map(|def| match def {
+                            Definition::Fragment(f) if f.item.name.item == name => Some(&f.item),
+                            _ => None,
+                        })
+                        .ok_or_else(|| {
+                            GraphQLError::ValidationError(vec![RuleError::new(
+                                &format!("Unknown fragment `{name}`"),
+                                &[*start],
+                            )])
+                        })?;
+
+                    self.spreads.push(name);
+                    self.collect_conditional(
+                        node,
+                        &fragment.selection_set,
+                        Some(fragment.type_condition.item),
+                        parent,
+                    )?;
+                    self.spreads.pop();
+                }
+                Selection::InlineFragment(Spanning { item: fragment, .. }) => {
+                    if is_excluded(&fragment.directives, self.variables) {
+                        continue;
+                    }
+
+                    self.collect_conditional(
+                        node,
+                        &fragment.selection_set,
+                        fragment.type_condition.as_ref().map(|c| c.item),
+                        parent,
+                    )?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Collects the provided selection `set` of a fragment, applied to the
+    /// `condition` type, into the `node` of the `parent` type.
+    ///
+    /// Fragments applied to the `parent` type itself are merged right into the
+    /// `node`.
+    fn collect_conditional(
+        &mut self,
+        node: &mut Node,
+        set: &'a [Selection<'a, S>],
+        condition: Option<&'a str>,
+        parent: Option<&'a MetaType<'a, S>>,
+    ) -> Result<(), GraphQLError> {
+        match condition {
+            Some(cond) if parent.and_then(MetaType::name) != Some(cond) => {
+                let node = node.conditions.entry(cond.into()).or_default();
+                let ty = self.schema.concrete_type_by_name(cond);
+                self.collect(node, set, ty)
+            }
+            _ => self.collect(node, set, parent),
+        }
+    }
+
+    /// Returns the canonical representation of the provided `directives`,
+    /// omitting the `@skip` and `@include` ones, as they're already evaluated.
+    fn directives(&self, directives: &Option<Vec<Spanning<Directive<'a, S>>>>) -> Vec<String> {
+        let mut out = directives
+            .iter()
+            .flatten()
+            .filter(|d| !matches!(d.item.name.item, "skip" | "include"))
+            .map(|d| {
+                let mut args = d
+                    .item
+                    .arguments
+                    .iter()
+                    .flat_map(|args| args.item.iter())
+                    .map(|(k, v)| format!("{}: {}", k.item, self.const_value(&v.item)))
+                    .collect::<Vec<_>>();
+                args.sort();
+                format!("@{}({})", d.item.name.item, args.join(", "))
+            })
+            .collect::<Vec<_>>();
+        out.sort();
+        out
+    }
+
+    /// Returns the canonical representation of the provided `value`, with all
+    /// its variables substituted.
+    fn const_value(&self, value: &InputValue<S>) -> String {
+        value
+            .clone()
+            .into_const(self.variables)
+            .unwrap_or_else(InputValue::null)
+            .canonicalize()
+            .to_string()
+    }
+}
@@ -1,4 +1,4 @@
-use std::{collections::HashSet, fmt};
+use std::{collections::HashSet, fmt, mem};
 
 use crate::{
     ast::{InputValue, Operation, VariableDefinitions},
@@ -19,6 +19,106 @@ enum Path<'a> {
     ObjectField(&'a str, &'a Path<'a>),
 }
 
+/// Coerces the provided `values` of the variables declared by the provided
+/// `operation`, as [CoerceVariableValues()][0] does.
+///
+/// Only the declared variables are returned, with the declared defaults
+/// applied to the absent ones, and their values coerced to the declared types:
+/// single values are wrapped into lists for list types, and the absent fields
+/// of input objects are filled with their defaults.
+///
+/// # Errors
+///
+/// Returns all the found [`RuleError`]s, if any of the provided values or the
+/// applied defaults doesn't match the declared type of its variable.
+///
+/// [0]: https://spec.graphql.org/October2021#CoerceVariableValues()
+pub fn coerce_variable_values<S>(
+    values: &Variables<S>,
+    operation: &Spanning<Operation<S>>,
+    schema: &SchemaType<S>,
+) -> Result<Variables<S>, Vec<RuleError>>
+where
+    S: ScalarValue,
+{
+    let errs = validate_input_values(values, operation, schema);
+    if !errs.is_empty() {
+        return Err(errs);
+    }
+
+    let mut coerced = Variables::new();
+    if let Some(ref vars) = operation.item.variable_definitions {
+        for (name, def) in vars.item.iter() {
+            let value = match (values.get(name.item), &def.default_value) {
+                (Some(v), _) => v.clone(),
+                (None, Some(default)) => default.item.clone(),
+                (None, None) => continue,
+            };
+            let meta_type = schema.make_type(&def.var_type.item);
+            coerced.insert(name.item.into(), coerce_value(value, &meta_type, schema));
+        }
+    }
+    Ok(coerced)
+}
+
+/// Coerces the provided already validated `value` to the provided `meta_type`
+/// according to the [input coercion rules][0] of lists and input objects.
+///
+/// [0]: https://spec.graphql.org/October2021#sec-List.Input-Coercion
+fn coerce_value<S>(
+    value: InputValue<S>,
+    meta_type: &TypeType<S>,
+    schema: &SchemaType<S>,
+) -> InputValue<S>
+where
+    S: ScalarValue,
+{
+    match meta_type {
+        TypeType::NonNull(inner) => coerce_value(value, inner, schema),
+        TypeType::List(inner, _) => match value {
+            InputValue::Null => InputValue::Null,
+            InputValue::List(items) => InputValue::List(
+                items
+                    .into_iter()
+                    .map(|item| item.map(|v| coerce_value(v, inner, schema)))
+                    .collect(),
+            ),
+            v => InputValue::List(vec![Spanning::unlocated(coerce_value(v, inner, schema))]),
+        },
+        TypeType::Concrete(MetaType::InputObject(meta)) => match value {
+            InputValue::Object(mut fields) => {
+                for (name, value) in &mut fields {
+                    let input_field = meta
+                        .input_fields
+                        .iter()
+                        .find(|f| f.accepted_names().any(|n| n == name.item));
+                    if let Some(input_field) = input_field {
+                        let v = mem::replace(&mut value.item, InputValue::Null);
+                        value.item =
+                            coerce_value(v, &schema.make_type(&input_field.arg_type), schema);
+                    }
+                }
+                for input_field in &meta.input_fields {
+                    let is_provided = input_field
+                        .accepted_names()
+                        .any(|n| fields.iter().any(|(name, _)| name.item == n));
+                    if !is_provided {
+                        if let Some(default) = &input_field.default_value {
+                            fields.push((
+                                Spanning::unlocated(input_field.name.clone()),
+                                Spanning::unlocated(default.clone()),
+                            ));
+                        }
+                    }
+                }
+                InputValue::Object(fields)
+            }
+            v => v,
+        },
+        TypeType::Concrete(_) => value,
+    }
+}
+
 #[doc(hidden)]
 pub fn validate_input_values<S>(
     values: &Variables<S>,
@@ -52,7 +152,21 @@ fn validate_var_defs<S>(
             Some(t) if t.is_input() => {
                 let ct = schema.make_type(&def.var_type.item);
 
-                if def.var_type.item.is_non_null() && is_absent_or_null(values.get(name.item)) {
+                // The declared default is applied only if no value is provided,
+                // so should be checked against the variable type in this case
+                // only.
+                if let (None, Some(default)) = (values.get(name.item), &def.default_value) {
+                    errors.append(&mut unify_value(
+                        name.item,
+                        &default.start,
+                        &default.item,
+                        &ct,
+                        schema,
+                        Path::Root,
+                    ));
+                } else if def.var_type.item.is_non_null()
+                    && is_absent_or_null(values.get(name.item))
+                {
                     errors.push(RuleError::new(
                         &format!(
                             r#"Variable "${}" of required type "{}" was not provided."#,
@@ -71,10 +185,17 @@ fn validate_var_defs<S>(
                     ));
                 }
             }
-            _ => unreachable!(
-                r#"Variable "${}" has invalid input type "{}" after document validation."#,
-                name.item, def.var_type.item,
-            ),
+            Some(_) => errors.push(RuleError::new(
+                &format!(
+                    r#"Variable "{}" cannot be of non-input type "{}""#,
+                    name.item, def.var_type.item,
+                ),
+                &[def.var_type.start],
+            )),
+            None => errors.push(RuleError::new(
+                &format!(r#"Unknown type "{raw_type_name}""#),
+                &[def.var_type.start],
+            )),
         }
     }
 }
@@ -164,18 +285,21 @@ where
                         // All the fields didn't have errors, see if there is an
                         // overall error when parsing the input value.
                         if let Err(e) = (iom.try_parse_fn)(value) {
-                            errors.push(unification_error(
-                                var_name,
-                                var_pos,
-                                &path,
-                                format!(
-                                    "Expected input of type `{}`. \
-                                     Got: `{value}`. \
-                                     Details: {}",
-                                    iom.name,
-                                    e.message(),
-                                ),
-                            ));
+                            errors.push(
+                                unification_error(
+                                    var_name,
+                                    var_pos,
+                                    &path,
+                                    format!(
+                                        "Expected input of type `{}`. \
+                                         Got: `{value}`. \
+                                         Details: {}",
+                                        iom.name,
+                                        e.message(),
+                                    ),
+                                )
+                                .with_extensions(e.extensions().clone().map_scalar_value()),
+                            );
                         }
                     } else {
                         errors.append(&mut e);
@@ -210,7 +334,12 @@ where
                 meta.name,
                 e.message(),
             ),
-        )];
+        )
+        .with_extensions(e.extensions().clone().map_scalar_value())];
+    }
+
+    if meta.is_structured {
+        return errors;
     }
 
     match *value {
@@ -295,9 +424,20 @@ where
 
         for input_field in &meta.input_fields {
             let mut has_value = false;
-            keys.remove(&input_field.name.as_str());
+            let provided = input_field
+                .accepted_names()
+                .filter(|n| keys.remove(n))
+                .collect::<Vec<_>>();
+            if let [name, alias, ..] = provided[..] {
+                errors.push(unification_error(
+                    var_name,
+                    var_pos,
+                    &Path::ObjectField(&input_field.name, path),
+                    format!(r#"Field "{name}" provided along with its alias "{alias}""#),
+                ));
+            }
 
-            if let Some(value) = obj.get(input_field.name.as_str()) {
+            if let Some(value) = provided.first().and_then(|n| obj.get(n)) {
                 if !value.is_null() {
                     has_value = true;
 
@@ -312,7 +452,9 @@ where
                 }
             }
 
-            if !has_value && input_field.arg_type.is_non_null() {
+            // An absent field is filled in with its default value, if any.
+            let has_default = provided.is_empty() && input_field.default_value.is_some();
+            if !has_value && !has_default && input_field.arg_type.is_non_null() {
                 errors.push(unification_error(
                     var_name,
                     var_pos,
@@ -1,6 +1,9 @@
 //! Query validation related methods and data structures
 
+mod coercion;
+mod complexity;
 mod context;
+mod fingerprint;
 mod input_value;
 mod multi_visitor;
 mod rules;
@@ -10,12 +13,20 @@ mod visitor;
 #[cfg(test)]
 pub(crate) mod test_harness;
 
+pub(crate) use self::{
+    complexity::{document_cost, operation_cost},
+    fingerprint::operation_fingerprint,
+    rules::known_fragment_names,
+};
+
 pub use self::{
+    coercion::{CoercionError, CoercionErrorKind},
+    complexity::{validate_cost, CostError},
     context::{RuleError, ValidatorContext},
-    input_value::validate_input_values,
+    input_value::{coerce_variable_values, validate_input_values},
     multi_visitor::MultiVisitorNil,
     rules::visit_all_rules,
-    traits::Visitor,
+    traits::{ValidationRule, Visitor},
     visitor::visit,
 };
 
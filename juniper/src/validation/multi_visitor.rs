@@ -257,4 +257,197 @@ where
     }
 }
 
+impl<'a, S, V> Visitor<'a, S> for Box<V>
+where
+    S: ScalarValue,
+    V: Visitor<'a, S> + ?Sized,
+{
+    fn enter_document(&mut self, ctx: &mut ValidatorContext<'a, S>, node: &'a Document<S>) {
+        (**self).enter_document(ctx, node);
+    }
+    fn exit_document(&mut self, ctx: &mut ValidatorContext<'a, S>, node: &'a Document<S>) {
+        (**self).exit_document(ctx, node);
+    }
+    fn enter_operation_definition(
+        &mut self,
+        ctx: &mut ValidatorContext<'a, S>,
+        node: &'a Spanning<Operation<S>>,
+    ) {
+        (**self).enter_operation_definition(ctx, node);
+    }
+    fn exit_operation_definition(
+        &mut self,
+        ctx: &mut ValidatorContext<'a, S>,
+        node: &'a Spanning<Operation<S>>,
+    ) {
+        (**self).exit_operation_definition(ctx, node);
+    }
+    fn enter_fragment_definition(
+        &mut self,
+        ctx: &mut ValidatorContext<'a, S>,
+        node: &'a Spanning<Fragment<S>>,
+    ) {
+        (**self).enter_fragment_definition(ctx, node);
+    }
+    fn exit_fragment_definition(
+        &mut self,
+        ctx: &mut ValidatorContext<'a, S>,
+        node: &'a Spanning<Fragment<S>>,
+    ) {
+        (**self).exit_fragment_definition(ctx, node);
+    }
+    fn enter_variable_definition(
+        &mut self,
+        ctx: &mut ValidatorContext<'a, S>,
+        node: &'a (Spanning<&'a str>, VariableDefinition<S>),
+    ) {
+        (**self).enter_variable_definition(ctx, node);
+    }
+    fn exit_variable_definition(
+        &mut self,
+        ctx: &mut ValidatorContext<'a, S>,
+        node: &'a (Spanning<&'a str>, VariableDefinition<S>),
+    ) {
+        (**self).exit_variable_definition(ctx, node);
+    }
+    fn enter_directive(
+        &mut self,
+        ctx: &mut ValidatorContext<'a, S>,
+        node: &'a Spanning<Directive<S>>,
+    ) {
+        (**self).enter_directive(ctx, node);
+    }
+    fn exit_directive(
+        &mut self,
+        ctx: &mut ValidatorContext<'a, S>,
+        node: &'a Spanning<Directive<S>>,
+    ) {
+        (**self).exit_directive(ctx, node);
+    }
+    fn enter_argument(
+        &mut self,
+        ctx: &mut ValidatorContext<'a, S>,
+        node: &'a (Spanning<&'a str>, Spanning<InputValue<S>>),
+    ) {
+        (**self).enter_argument(ctx, node);
+    }
+    fn exit_argument(
+        &mut self,
+        ctx: &mut ValidatorContext<'a, S>,
+        node: &'a (Spanning<&'a str>, Spanning<InputValue<S>>),
+    ) {
+        (**self).exit_argument(ctx, node);
+    }
+    fn enter_selection_set(&mut self, ctx: &mut ValidatorContext<'a, S>, node: &'a [Selection<S>]) {
+        (**self).enter_selection_set(ctx, node);
+    }
+    fn exit_selection_set(&mut self, ctx: &mut ValidatorContext<'a, S>, node: &'a [Selection<S>]) {
+        (**self).exit_selection_set(ctx, node);
+    }
+    fn enter_field(&mut self, ctx: &mut ValidatorContext<'a, S>, node: &'a Spanning<Field<S>>) {
+        (**self).enter_field(ctx, node);
+    }
+    fn exit_field(&mut self, ctx: &mut ValidatorContext<'a, S>, node: &'a Spanning<Field<S>>) {
+        (**self).exit_field(ctx, node);
+    }
+    fn enter_fragment_spread(
+        &mut self,
+        ctx: &mut ValidatorContext<'a, S>,
+        node: &'a Spanning<FragmentSpread<S>>,
+    ) {
+        (**self).enter_fragment_spread(ctx, node);
+    }
+    fn exit_fragment_spread(
+        &mut self,
+        ctx: &mut ValidatorContext<'a, S>,
+        node: &'a Spanning<FragmentSpread<S>>,
+    ) {
+        (**self).exit_fragment_spread(ctx, node);
+    }
+    fn enter_inline_fragment(
+        &mut self,
+        ctx: &mut ValidatorContext<'a, S>,
+        node: &'a Spanning<InlineFragment<S>>,
+    ) {
+        (**self).enter_inline_fragment(ctx, node);
+    }
+    fn exit_inline_fragment(
+        &mut self,
+        ctx: &mut ValidatorContext<'a, S>,
+        node: &'a Spanning<InlineFragment<S>>,
+    ) {
+        (**self).exit_inline_fragment(ctx, node);
+    }
+    fn enter_null_value(&mut self, ctx: &mut ValidatorContext<'a, S>, node: Spanning<()>) {
+        (**self).enter_null_value(ctx, node);
+    }
+    fn exit_null_value(&mut self, ctx: &mut ValidatorContext<'a, S>, node: Spanning<()>) {
+        (**self).exit_null_value(ctx, node);
+    }
+    fn enter_scalar_value(&mut self, ctx: &mut ValidatorContext<'a, S>, node: Spanning<&'a S>) {
+        (**self).enter_scalar_value(ctx, node);
+    }
+    fn exit_scalar_value(&mut self, ctx: &mut ValidatorContext<'a, S>, node: Spanning<&'a S>) {
+        (**self).exit_scalar_value(ctx, node);
+    }
+    fn enter_enum_value(&mut self, ctx: &mut ValidatorContext<'a, S>, node: Spanning<&'a String>) {
+        (**self).enter_enum_value(ctx, node);
+    }
+    fn exit_enum_value(&mut self, ctx: &mut ValidatorContext<'a, S>, node: Spanning<&'a String>) {
+        (**self).exit_enum_value(ctx, node);
+    }
+    fn enter_variable_value(
+        &mut self,
+        ctx: &mut ValidatorContext<'a, S>,
+        node: Spanning<&'a String>,
+    ) {
+        (**self).enter_variable_value(ctx, node);
+    }
+    fn exit_variable_value(
+        &mut self,
+        ctx: &mut ValidatorContext<'a, S>,
+        node: Spanning<&'a String>,
+    ) {
+        (**self).exit_variable_value(ctx, node);
+    }
+    fn enter_list_value(
+        &mut self,
+        ctx: &mut ValidatorContext<'a, S>,
+        node: Spanning<&'a Vec<Spanning<InputValue<S>>>>,
+    ) {
+        (**self).enter_list_value(ctx, node);
+    }
+    fn exit_list_value(
+        &mut self,
+        ctx: &mut ValidatorContext<'a, S>,
+        node: Spanning<&'a Vec<Spanning<InputValue<S>>>>,
+    ) {
+        (**self).exit_list_value(ctx, node);
+    }
+    fn enter_object_value(
+        &mut self,
+        ctx: &mut ValidatorContext<'a, S>,
+        node: SpannedObject<'a, S>,
+    ) {
+        (**self).enter_object_value(ctx, node);
+    }
+    fn exit_object_value(&mut self, ctx: &mut ValidatorContext<'a, S>, node: SpannedObject<'a, S>) {
+        (**self).exit_object_value(ctx, node);
+    }
+    fn enter_object_field(
+        &mut self,
+        ctx: &mut ValidatorContext<'a, S>,
+        node: &'a (Spanning<String>, Spanning<InputValue<S>>),
+    ) {
+        (**self).enter_object_field(ctx, node);
+    }
+    fn exit_object_field(
+        &mut self,
+        ctx: &mut ValidatorContext<'a, S>,
+        node: &'a (Spanning<String>, Spanning<InputValue<S>>),
+    ) {
+        (**self).exit_object_field(ctx, node);
+    }
+}
+
 type SpannedObject<'a, S> = Spanning<&'a Vec<(Spanning<String>, Spanning<InputValue<S>>)>>;
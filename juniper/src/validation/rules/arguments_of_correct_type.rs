@@ -4,8 +4,8 @@ use crate::{
     ast::{Directive, Field, InputValue},
     parser::Spanning,
     schema::meta::Argument,
-    types::utilities::is_valid_literal_value,
-    validation::{ValidatorContext, Visitor},
+    types::utilities::validate_literal_value,
+    validation::{RuleError, ValidatorContext, Visitor},
     value::ScalarValue,
 };
 
@@ -58,11 +58,15 @@ where
         {
             let meta_type = ctx.schema.make_type(&argument_meta.arg_type);
 
-            if !is_valid_literal_value(ctx.schema, &meta_type, &arg_value.item) {
-                ctx.report_error(
+            if let Err(e) = validate_literal_value(ctx.schema, &meta_type, &arg_value.item) {
+                let err = RuleError::new(
                     &error_message(arg_name.item, &argument_meta.arg_type),
                     &[arg_value.start],
                 );
+                ctx.append_errors(vec![match e {
+                    Some(e) => err.with_extensions(e.extensions().clone().map_scalar_value()),
+                    None => err,
+                }]);
             }
         }
     }
@@ -0,0 +1,287 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    ast::{
+        Directive, Document, Field, Fragment, FragmentSpread, InlineFragment, Operation,
+        OperationType,
+    },
+    parser::{SourcePosition, Spanning},
+    validation::{ValidatorContext, Visitor},
+    value::ScalarValue,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Scope<'a> {
+    Operation(Option<&'a str>),
+    Fragment(&'a str),
+}
+
+pub struct DeferDirectivesOnValidOperations<'a> {
+    /// Indicator whether the `@defer` directive is known to the schema.
+    is_defer_known: bool,
+
+    /// Non-query operations of the document, along with their types.
+    operations: Vec<(Option<&'a str>, OperationType)>,
+
+    /// Fragments spread on the root selection set of every scope.
+    root_spreads: HashMap<Scope<'a>, Vec<&'a str>>,
+
+    /// Positions of the `@defer` directives used on the root selection set of
+    /// every scope.
+    root_defers: HashMap<Scope<'a>, Vec<SourcePosition>>,
+
+    current_scope: Option<Scope<'a>>,
+
+    /// Number of fields enclosing the selection being visited.
+    field_depth: usize,
+}
+
+pub fn factory<'a>() -> DeferDirectivesOnValidOperations<'a> {
+    DeferDirectivesOnValidOperations {
+        is_defer_known: false,
+        operations: Vec::new(),
+        root_spreads: HashMap::new(),
+        root_defers: HashMap::new(),
+        current_scope: None,
+        field_depth: 0,
+    }
+}
+
+impl<'a> DeferDirectivesOnValidOperations<'a> {
+    fn collect_defer<S>(&mut self, directives: &Option<Vec<Spanning<Directive<'a, S>>>>) {
+        let scope = match &self.current_scope {
+            Some(scope) if self.field_depth == 0 => scope,
+            _ => return,
+        };
+
+        let positions = directives
+            .iter()
+            .flatten()
+            .filter(|d| d.item.name.item == "defer")
+            .map(|d| d.start);
+        self.root_defers
+            .entry(scope.clone())
+            .or_default()
+            .extend(positions);
+    }
+
+    /// Collects positions of the `@defer` directives used on the root selection
+    /// set of the provided `operation` scope, including the ones of the
+    /// fragments spread on it.
+    fn find_root_defers(&self, operation: Scope<'a>) -> Vec<SourcePosition> {
+        let mut defers = Vec::new();
+        let mut visited = HashSet::new();
+        let mut to_visit = vec![operation];
+        while let Some(scope) = to_visit.pop() {
+            if !visited.insert(scope.clone()) {
+                continue;
+            }
+            if let Some(positions) = self.root_defers.get(&scope) {
+                defers.extend(positions.iter().copied());
+            }
+            if let Some(spreads) = self.root_spreads.get(&scope) {
+                to_visit.extend(spreads.iter().map(|name| Scope::Fragment(name)));
+            }
+        }
+        defers.sort();
+        defers
+    }
+}
+
+impl<'a, S> Visitor<'a, S> for DeferDirectivesOnValidOperations<'a>
+where
+    S: ScalarValue,
+{
+    fn enter_document(&mut self, ctx: &mut ValidatorContext<'a, S>, _: &'a Document<S>) {
+        // Unknown `@defer` directive is reported by the `KnownDirectives` rule.
+        self.is_defer_known = ctx.schema.directive_by_name("defer").is_some();
+    }
+
+    fn exit_document(&mut self, ctx: &mut ValidatorContext<'a, S>, _: &'a Document<S>) {
+        if !self.is_defer_known {
+            return;
+        }
+
+        for &(op_name, op_type) in &self.operations {
+            for pos in self.find_root_defers(Scope::Operation(op_name)) {
+                ctx.report_error(&error_message(op_type), &[pos]);
+            }
+        }
+    }
+
+    fn enter_operation_definition(
+        &mut self,
+        _: &mut ValidatorContext<'a, S>,
+        op: &'a Spanning<Operation<S>>,
+    ) {
+        let op_name = op.item.name.as_ref().map(|s| s.item);
+        if op.item.operation_type != OperationType::Query {
+            self.operations.push((op_name, op.item.operation_type));
+        }
+        self.current_scope = Some(Scope::Operation(op_name));
+    }
+
+    fn enter_fragment_definition(
+        &mut self,
+        _: &mut ValidatorContext<'a, S>,
+        fragment: &'a Spanning<Fragment<S>>,
+    ) {
+        self.current_scope = Some(Scope::Fragment(fragment.item.name.item));
+    }
+
+    fn enter_field(&mut self, _: &mut ValidatorContext<'a, S>, _: &'a Spanning<Field<S>>) {
+        self.field_depth += 1;
+    }
+
+    fn exit_field(&mut self, _: &mut ValidatorContext<'a, S>, _: &'a Spanning<Field<S>>) {
+        self.field_depth -= 1;
+    }
+
+    fn enter_fragment_spread(
+        &mut self,
+        _: &mut ValidatorContext<'a, S>,
+        spread: &'a Spanning<FragmentSpread<S>>,
+    ) {
+        self.collect_defer(&spread.item.directives);
+
+        if let (Some(scope), 0) = (&self.current_scope, self.field_depth) {
+            self.root_spreads
+                .entry(scope.clone())
+                .or_default()
+                .push(spread.item.name.item);
+        }
+    }
+
+    fn enter_inline_fragment(
+        &mut self,
+        _: &mut ValidatorContext<'a, S>,
+        fragment: &'a Spanning<InlineFragment<S>>,
+    ) {
+        self.collect_defer(&fragment.item.directives);
+    }
+}
+
+fn error_message(operation_type: OperationType) -> String {
+    let operation_type = match operation_type {
+        OperationType::Query => "query",
+        OperationType::Mutation => "mutation",
+        OperationType::Subscription => "subscription",
+    };
+    format!(
+        r#"Directive "@defer" cannot be used on the root selection set of a {operation_type} operation"#,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{error_message, factory};
+
+    use crate::{
+        ast::OperationType,
+        parser::SourcePosition,
+        validation::{expect_fails_rule, expect_passes_rule, RuleError},
+        value::DefaultScalarValue,
+    };
+
+    #[test]
+    fn defer_in_query() {
+        expect_passes_rule::<_, _, DefaultScalarValue>(
+            factory,
+            r#"
+          {
+            ... @defer { dog { name } }
+            human { ...HumanFields @defer(label: "human") }
+          }
+
+          fragment HumanFields on Human { name }
+        "#,
+        );
+    }
+
+    #[test]
+    fn defer_nested_in_mutation() {
+        expect_passes_rule::<_, _, DefaultScalarValue>(
+            factory,
+            r#"
+          mutation {
+            testInput
+          }
+
+          fragment DogFields on Dog { ... @defer { name } }
+        "#,
+        );
+    }
+
+    #[test]
+    fn defer_on_mutation_root() {
+        expect_fails_rule::<_, _, DefaultScalarValue>(
+            factory,
+            r#"
+          mutation {
+            ... on MutationRoot { ... @defer { testInput } }
+          }
+        "#,
+            &[RuleError::new(
+                &error_message(OperationType::Mutation),
+                &[SourcePosition::new(60, 2, 38)],
+            )],
+        );
+    }
+
+    #[test]
+    fn defer_in_fragment_spread_on_mutation_root() {
+        expect_fails_rule::<_, _, DefaultScalarValue>(
+            factory,
+            r#"
+          mutation {
+            ...Root
+          }
+
+          fragment Root on MutationRoot { ...Deferred }
+          fragment Deferred on MutationRoot { ... @defer { testInput } }
+        "#,
+            &[RuleError::new(
+                &error_message(OperationType::Mutation),
+                &[SourcePosition::new(161, 6, 50)],
+            )],
+        );
+    }
+
+    #[test]
+    fn defer_in_fragment_spread_on_query_and_nested_in_mutation() {
+        expect_passes_rule::<_, _, DefaultScalarValue>(
+            factory,
+            r#"
+          query Q {
+            ...Deferred
+          }
+
+          mutation M {
+            testInput
+            ... on MutationRoot { ...Nested }
+          }
+
+          fragment Deferred on QueryRoot { ... @defer { dog { name } } }
+          fragment Nested on MutationRoot { testInput }
+        "#,
+        );
+    }
+
+    #[test]
+    fn defer_on_subscription_root() {
+        expect_fails_rule::<_, _, DefaultScalarValue>(
+            factory,
+            r#"
+          subscription {
+            ...Fields @defer
+          }
+
+          fragment Fields on SubscriptionRoot { __typename }
+        "#,
+            &[RuleError::new(
+                &error_message(OperationType::Subscription),
+                &[SourcePosition::new(48, 2, 22)],
+            )],
+        );
+    }
+}
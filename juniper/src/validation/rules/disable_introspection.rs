@@ -0,0 +1,121 @@
+use crate::{
+    ast::Field,
+    parser::Spanning,
+    validation::{ValidatorContext, Visitor},
+    value::ScalarValue,
+};
+
+pub struct DisableIntrospection;
+
+pub fn factory() -> DisableIntrospection {
+    DisableIntrospection
+}
+
+impl<'a, S> Visitor<'a, S> for DisableIntrospection
+where
+    S: ScalarValue,
+{
+    fn enter_field(&mut self, ctx: &mut ValidatorContext<'a, S>, field: &'a Spanning<Field<S>>) {
+        if !ctx.schema.introspection_disabled {
+            return;
+        }
+
+        let field_name = field.item.name.item;
+        if matches!(field_name, "__schema" | "__type") {
+            ctx.report_error(&error_message(field_name), &[field.start]);
+        }
+    }
+}
+
+fn error_message(field_name: &str) -> String {
+    format!("GraphQL introspection is not allowed, but the operation contained `{field_name}`")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{error_message, factory};
+
+    use crate::{
+        graphql_object,
+        parser::SourcePosition,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+        validation::{visit, MultiVisitorNil, RuleError, ValidatorContext},
+    };
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn value() -> i32 {
+            42
+        }
+    }
+
+    fn validate(disabled: bool, q: &str) -> Vec<RuleError> {
+        let mut root = RootNode::new(
+            Query,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+        if disabled {
+            root.disable_introspection();
+        }
+        let doc = crate::parse_document_source(q, &root.schema).expect("Parse error");
+        let mut ctx = ValidatorContext::new(&root.schema, &doc);
+        visit(&mut MultiVisitorNil.with(factory()), &mut ctx, &doc);
+        ctx.into_errors()
+    }
+
+    #[test]
+    fn allows_introspection_by_default() {
+        let errs = validate(
+            false,
+            r#"{ __schema { queryType { name } } __type(name: "Query") { name } }"#,
+        );
+
+        assert_eq!(errs, vec![]);
+    }
+
+    #[test]
+    fn rejects_introspection_fields() {
+        let errs = validate(
+            true,
+            r#"{ __schema { queryType { name } } __type(name: "Query") { name } }"#,
+        );
+
+        assert_eq!(
+            errs,
+            vec![
+                RuleError::new(&error_message("__schema"), &[SourcePosition::new(2, 0, 2)]),
+                RuleError::new(&error_message("__type"), &[SourcePosition::new(34, 0, 34)]),
+            ],
+        );
+    }
+
+    #[test]
+    fn rejects_introspection_fields_in_fragments() {
+        let errs = validate(
+            true,
+            r#"
+                { ...frag }
+                fragment frag on Query { __schema { queryType { name } } }
+            "#,
+        );
+
+        assert_eq!(
+            errs,
+            vec![RuleError::new(
+                &error_message("__schema"),
+                &[SourcePosition::new(70, 2, 41)],
+            )],
+        );
+    }
+
+    #[test]
+    fn allows_typename() {
+        let errs = validate(true, r#"{ value __typename }"#);
+
+        assert_eq!(errs, vec![]);
+    }
+}
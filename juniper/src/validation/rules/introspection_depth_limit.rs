@@ -0,0 +1,249 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    ast::{Definition, Document, Fragment, Selection},
+    parser::SourcePosition,
+    validation::{ValidatorContext, Visitor},
+    value::ScalarValue,
+};
+
+pub struct IntrospectionDepthLimit;
+
+pub fn factory() -> IntrospectionDepthLimit {
+    IntrospectionDepthLimit
+}
+
+impl<'a, S> Visitor<'a, S> for IntrospectionDepthLimit
+where
+    S: ScalarValue,
+{
+    fn enter_document(&mut self, ctx: &mut ValidatorContext<'a, S>, doc: &'a Document<S>) {
+        let limit = if let Some(limit) = ctx.schema.introspection_depth_limit {
+            limit
+        } else {
+            return;
+        };
+
+        let mut checker = DepthChecker {
+            fragments: doc
+                .iter()
+                .filter_map(|def| match def {
+                    Definition::Fragment(f) => Some((f.item.name.item, &f.item)),
+                    Definition::Operation(_) => None,
+                })
+                .collect(),
+            depths: HashMap::new(),
+            visited: HashSet::new(),
+            exceeding: Vec::new(),
+            limit,
+        };
+
+        for def in doc {
+            if let Definition::Operation(op) = def {
+                checker.find_introspection(&op.item.selection_set);
+            }
+        }
+
+        for pos in checker.exceeding {
+            ctx.report_error(&error_message(limit), &[pos]);
+        }
+    }
+}
+
+/// Checker of introspection fields depth in a [`Document`].
+///
+/// Relies on the [`Document`] having no fragment cycles.
+struct DepthChecker<'a, S> {
+    /// All the [`Fragment`]s defined in the [`Document`].
+    fragments: HashMap<&'a str, &'a Fragment<'a, S>>,
+
+    /// Already calculated maximum depths of the [`Fragment`]s.
+    depths: HashMap<&'a str, usize>,
+
+    /// [`Fragment`]s already searched for the introspection fields.
+    visited: HashSet<&'a str>,
+
+    /// Positions of the introspection fields exceeding the [`Self::limit`].
+    exceeding: Vec<SourcePosition>,
+
+    /// Maximum allowed depth of the introspection fields.
+    limit: usize,
+}
+
+impl<'a, S> DepthChecker<'a, S> {
+    /// Searches the provided `selection_set` for `__schema` and `__type`
+    /// fields, checking their depth.
+    fn find_introspection(&mut self, selection_set: &'a [Selection<S>]) {
+        for selection in selection_set {
+            match selection {
+                Selection::Field(f) => match f.item.name.item {
+                    "__schema" | "__type" => {
+                        let depth = 1 + f
+                            .item
+                            .selection_set
+                            .as_ref()
+                            .map_or(0, |s| self.max_depth(s));
+                        if depth > self.limit && !self.exceeding.contains(&f.start) {
+                            self.exceeding.push(f.start);
+                        }
+                    }
+                    _ => {
+                        if let Some(s) = &f.item.selection_set {
+                            self.find_introspection(s);
+                        }
+                    }
+                },
+                Selection::InlineFragment(f) => self.find_introspection(&f.item.selection_set),
+                Selection::FragmentSpread(spread) => {
+                    let name = spread.item.name.item;
+                    if self.visited.insert(name) {
+                        if let Some(f) = self.fragments.get(name).copied() {
+                            self.find_introspection(&f.selection_set);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Calculates maximum fields nesting depth of the provided
+    /// `selection_set`.
+    fn max_depth(&mut self, selection_set: &'a [Selection<S>]) -> usize {
+        selection_set
+            .iter()
+            .map(|selection| match selection {
+                Selection::Field(f) => {
+                    1 + f
+                        .item
+                        .selection_set
+                        .as_ref()
+                        .map_or(0, |s| self.max_depth(s))
+                }
+                Selection::InlineFragment(f) => self.max_depth(&f.item.selection_set),
+                Selection::FragmentSpread(spread) => {
+                    let name = spread.item.name.item;
+                    if let Some(depth) = self.depths.get(name) {
+                        *depth
+                    } else {
+                        let depth = self
+                            .fragments
+                            .get(name)
+                            .copied()
+                            .map_or(0, |f| self.max_depth(&f.selection_set));
+                        self.depths.insert(name, depth);
+                        depth
+                    }
+                }
+            })
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+fn error_message(limit: usize) -> String {
+    format!("Introspection query exceeds the maximum allowed depth of {limit}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{error_message, factory};
+
+    use crate::{
+        graphql_object,
+        parser::SourcePosition,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+        validation::{visit, MultiVisitorNil, RuleError, ValidatorContext},
+    };
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn deep() -> Query {
+            Query
+        }
+
+        fn value() -> i32 {
+            42
+        }
+    }
+
+    fn validate(limit: Option<usize>, q: &str) -> Vec<RuleError> {
+        let mut root = RootNode::new(
+            Query,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+        if let Some(limit) = limit {
+            root = root.with_introspection_depth_limit(limit);
+        }
+        let doc = crate::parse_document_source(q, &root.schema).expect("Parse error");
+        let mut ctx = ValidatorContext::new(&root.schema, &doc);
+        visit(&mut MultiVisitorNil.with(factory()), &mut ctx, &doc);
+        ctx.into_errors()
+    }
+
+    #[test]
+    fn unlimited_by_default() {
+        let errs = validate(
+            None,
+            r#"{ __type(name: "Query") { fields { type { ofType { ofType { name } } } } } }"#,
+        );
+
+        assert_eq!(errs, vec![]);
+    }
+
+    #[test]
+    fn allows_introspection_within_limit() {
+        let errs = validate(Some(3), r#"{ __type(name: "Query") { fields { name } } }"#);
+
+        assert_eq!(errs, vec![]);
+    }
+
+    #[test]
+    fn rejects_introspection_beyond_limit() {
+        let errs = validate(
+            Some(3),
+            r#"{ __type(name: "Query") { fields { type { name } } } }"#,
+        );
+
+        assert_eq!(
+            errs,
+            vec![RuleError::new(
+                &error_message(3),
+                &[SourcePosition::new(2, 0, 2)],
+            )],
+        );
+    }
+
+    #[test]
+    fn rejects_introspection_beyond_limit_via_fragments() {
+        let errs = validate(
+            Some(3),
+            r#"
+                { ...frag }
+                fragment frag on Query { __schema { ...types } }
+                fragment types on __Schema { types { fields { name } } }
+            "#,
+        );
+
+        assert_eq!(
+            errs,
+            vec![RuleError::new(
+                &error_message(3),
+                &[SourcePosition::new(70, 2, 41)],
+            )],
+        );
+    }
+
+    #[test]
+    fn ignores_non_introspection_depth() {
+        let errs = validate(
+            Some(1),
+            r#"{ deep { deep { deep { value } } } __typename }"#,
+        );
+
+        assert_eq!(errs, vec![]);
+    }
+}
@@ -1,10 +1,13 @@
 mod arguments_of_correct_type;
 mod default_values_of_correct_type;
+mod defer_directives_on_valid_operations;
+mod disable_introspection;
 mod fields_on_correct_type;
 mod fragments_on_composite_types;
+mod introspection_depth_limit;
 mod known_argument_names;
 mod known_directives;
-mod known_fragment_names;
+pub(crate) mod known_fragment_names;
 mod known_type_names;
 mod lone_anonymous_operation;
 mod no_fragment_cycles;
@@ -15,6 +18,7 @@ mod overlapping_fields_can_be_merged;
 mod possible_fragment_spreads;
 mod provided_non_null_arguments;
 mod scalar_leafs;
+mod stream_directives_on_list_fields;
 mod unique_argument_names;
 mod unique_fragment_names;
 mod unique_input_field_names;
@@ -45,6 +49,8 @@ where
     let mut stage1 = MultiVisitorNil
         .with(self::arguments_of_correct_type::factory())
         .with(self::default_values_of_correct_type::factory())
+        .with(self::defer_directives_on_valid_operations::factory())
+        .with(self::disable_introspection::factory())
         .with(self::fields_on_correct_type::factory())
         .with(self::fragments_on_composite_types::factory())
         .with(self::known_argument_names::factory())
@@ -59,6 +65,7 @@ where
         .with(self::possible_fragment_spreads::factory())
         .with(self::provided_non_null_arguments::factory())
         .with(self::scalar_leafs::factory())
+        .with(self::stream_directives_on_list_fields::factory())
         .with(self::unique_argument_names::factory())
         .with(self::unique_fragment_names::factory())
         .with(self::unique_input_field_names::factory())
@@ -71,8 +78,20 @@ where
         return;
     }
 
-    let mut stage2 = MultiVisitorNil.with(self::overlapping_fields_can_be_merged::factory());
+    let mut stage2 = MultiVisitorNil
+        .with(self::introspection_depth_limit::factory())
+        .with(self::overlapping_fields_can_be_merged::factory());
     visit(&mut stage2, ctx, doc);
+    if ctx.has_errors() {
+        return;
+    }
+
+    // User-defined rules are run last, so they may rely on the query being
+    // valid otherwise.
+    let schema = ctx.schema;
+    for rule in &schema.validation_rules {
+        visit(&mut MultiVisitorNil.with(rule.visitor()), ctx, doc);
+    }
 }
 
 #[cfg(test)]
@@ -59,6 +59,7 @@ where
         {
             for meta_arg in meta_args {
                 if meta_arg.arg_type.is_non_null()
+                    && meta_arg.default_value.is_none()
                     && directive
                         .item
                         .arguments
@@ -353,6 +354,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn with_directive_with_defaulted_types() {
+        expect_passes_rule::<_, _, DefaultScalarValue>(
+            factory,
+            r#"
+            {
+              human {
+                pets @stream {
+                  name
+                }
+              }
+            }
+        "#,
+        );
+    }
+
     #[test]
     fn with_directive_with_missing_types() {
         expect_fails_rule::<_, _, DefaultScalarValue>(
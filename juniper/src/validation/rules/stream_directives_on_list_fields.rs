@@ -0,0 +1,123 @@
+use std::fmt;
+
+use crate::{
+    ast::{Field, InputValue, Type},
+    parser::Spanning,
+    validation::{ValidatorContext, Visitor},
+    value::ScalarValue,
+};
+
+pub struct StreamDirectivesOnListFields;
+
+pub fn factory() -> StreamDirectivesOnListFields {
+    StreamDirectivesOnListFields
+}
+
+impl<'a, S> Visitor<'a, S> for StreamDirectivesOnListFields
+where
+    S: ScalarValue,
+{
+    fn enter_field(&mut self, ctx: &mut ValidatorContext<'a, S>, field: &'a Spanning<Field<S>>) {
+        // Unknown `@stream` directive is reported by the `KnownDirectives` rule.
+        if ctx.schema.directive_by_name("stream").is_none() {
+            return;
+        }
+
+        let directives = field.item.directives.iter().flatten();
+        for directive in directives.filter(|d| d.item.name.item == "stream") {
+            if let Some(field_type) = ctx.current_type_literal() {
+                if !matches!(field_type, Type::List(..) | Type::NonNullList(..)) {
+                    ctx.report_error(
+                        &non_list_error_message(field.item.name.item, field_type),
+                        &[directive.start],
+                    );
+                }
+            }
+
+            let initial_count = directive
+                .item
+                .arguments
+                .as_ref()
+                .and_then(|args| args.item.get("initialCount"));
+            if let Some(Spanning {
+                item: InputValue::Scalar(count),
+                start,
+                ..
+            }) = initial_count
+            {
+                if count.as_int().map_or(false, |c| c < 0) {
+                    ctx.report_error(&negative_initial_count_error_message(), &[*start]);
+                }
+            }
+        }
+    }
+}
+
+fn non_list_error_message(field_name: impl fmt::Display, type_name: impl fmt::Display) -> String {
+    format!(
+        r#"Directive "@stream" cannot be used on field "{field_name}" of non-list type "{type_name}""#,
+    )
+}
+
+fn negative_initial_count_error_message() -> String {
+    r#"Argument "initialCount" of directive "@stream" must be non-negative"#.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{factory, negative_initial_count_error_message, non_list_error_message};
+
+    use crate::{
+        parser::SourcePosition,
+        validation::{expect_fails_rule, expect_passes_rule, RuleError},
+        value::DefaultScalarValue,
+    };
+
+    #[test]
+    fn stream_on_list_field() {
+        expect_passes_rule::<_, _, DefaultScalarValue>(
+            factory,
+            r#"
+          {
+            human {
+              pets @stream(initialCount: 1) { name }
+            }
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn stream_on_non_list_field() {
+        expect_fails_rule::<_, _, DefaultScalarValue>(
+            factory,
+            r#"
+          {
+            dog @stream { name }
+          }
+        "#,
+            &[RuleError::new(
+                &non_list_error_message("dog", "Dog"),
+                &[SourcePosition::new(29, 2, 16)],
+            )],
+        );
+    }
+
+    #[test]
+    fn stream_with_negative_initial_count() {
+        expect_fails_rule::<_, _, DefaultScalarValue>(
+            factory,
+            r#"
+          {
+            human {
+              pets @stream(initialCount: -1) { name }
+            }
+          }
+        "#,
+            &[RuleError::new(
+                &negative_initial_count_error_message(),
+                &[SourcePosition::new(74, 3, 41)],
+            )],
+        );
+    }
+}
@@ -5,8 +5,9 @@ use std::{
 };
 
 use crate::{
-    ast::{Document, Fragment, FragmentSpread, Operation, Type, VariableDefinition},
-    parser::Spanning,
+    ast::{Document, Fragment, FragmentSpread, InputValue, Operation, Type, VariableDefinition},
+    parser::{SourcePosition, Spanning},
+    schema::meta::{InputObjectMeta, MetaType},
     validation::{ValidatorContext, Visitor},
     value::ScalarValue,
 };
@@ -22,6 +23,7 @@ pub fn factory<'a, S: fmt::Debug>() -> VariableInAllowedPosition<'a, S> {
         spreads: HashMap::new(),
         variable_usages: HashMap::new(),
         variable_defs: HashMap::new(),
+        one_of_field_variables: HashSet::new(),
         current_scope: None,
     }
 }
@@ -31,6 +33,9 @@ pub struct VariableInAllowedPosition<'a, S: fmt::Debug + 'a> {
     variable_usages: HashMap<Scope<'a>, Vec<(Spanning<&'a String>, Type<'a>)>>,
     #[allow(clippy::type_complexity)]
     variable_defs: HashMap<Scope<'a>, Vec<&'a (Spanning<&'a str>, VariableDefinition<'a, S>)>>,
+    /// Positions of the variables used as field values of `@oneOf` input
+    /// objects, so requiring a non-null type.
+    one_of_field_variables: HashSet<SourcePosition>,
     current_scope: Option<Scope<'a>>,
 }
 
@@ -160,6 +165,33 @@ where
         }
     }
 
+    fn enter_object_value(
+        &mut self,
+        ctx: &mut ValidatorContext<'a, S>,
+        obj: Spanning<&'a Vec<(Spanning<String>, Spanning<InputValue<S>>)>>,
+    ) {
+        let is_one_of = ctx
+            .current_input_type_literal()
+            .and_then(|t| ctx.schema.concrete_type_by_name(t.innermost_name()))
+            .map_or(false, |t| {
+                matches!(
+                    t,
+                    MetaType::InputObject(InputObjectMeta {
+                        is_one_of: true,
+                        ..
+                    }),
+                )
+            });
+        if is_one_of {
+            self.one_of_field_variables.extend(
+                obj.item
+                    .iter()
+                    .filter(|(_, v)| v.item.is_variable())
+                    .map(|(_, v)| v.start),
+            );
+        }
+    }
+
     fn enter_variable_value(
         &mut self,
         ctx: &mut ValidatorContext<'a, S>,
@@ -168,12 +200,22 @@ where
         if let (&Some(ref scope), Some(input_type)) =
             (&self.current_scope, ctx.current_input_type_literal())
         {
+            // Fields of `@oneOf` input objects accept non-null variables only:
+            // https://spec.graphql.org/draft#sec-All-Variable-Usages-Are-Allowed
+            let is_one_of_field = self.one_of_field_variables.contains(&var_name.start);
+            let input_type = match input_type {
+                Type::Named(name) if is_one_of_field => Type::NonNullNamed(name.clone()),
+                Type::List(inner, size) if is_one_of_field => {
+                    Type::NonNullList(inner.clone(), *size)
+                }
+                t => t.clone(),
+            };
             self.variable_usages
                 .entry(scope.clone())
                 .or_insert_with(Vec::new)
                 .push((
                     Spanning::start_end(&var_name.start, &var_name.end, var_name.item),
-                    input_type.clone(),
+                    input_type,
                 ));
         }
     }
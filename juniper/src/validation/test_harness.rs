@@ -921,6 +921,8 @@ where
         &[],
         false,
     ));
+    root.schema.add_stream_directive();
+    root.schema.add_defer_directive();
 
     let doc =
         parse_document_source(q, &root.schema).expect(&format!("Parse error on input {q:#?}"));
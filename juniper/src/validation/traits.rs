@@ -1,3 +1,5 @@
+use std::fmt;
+
 use crate::{
     ast::{
         Directive, Document, Field, Fragment, FragmentSpread, InlineFragment, InputValue,
@@ -8,20 +10,33 @@ use crate::{
     value::ScalarValue,
 };
 
-#[doc(hidden)]
+/// Visitor of a GraphQL query [`Document`] being validated.
+///
+/// Every method is called when the validation traversal enters or exits the
+/// corresponding node of the [`Document`], doing nothing by default. The found
+/// errors should be reported with [`ValidatorContext::report_error()`].
+///
+/// To run a custom [`Visitor`] on each validated query, register it in a
+/// schema via a [`ValidationRule`].
+///
+/// [`ValidationRule`]: crate::validation::ValidationRule
 pub trait Visitor<'a, S>
 where
     S: ScalarValue,
 {
+    /// Called when entering a query document.
     fn enter_document(&mut self, _: &mut ValidatorContext<'a, S>, _: &'a Document<S>) {}
+    /// Called when exiting a query document.
     fn exit_document(&mut self, _: &mut ValidatorContext<'a, S>, _: &'a Document<S>) {}
 
+    /// Called when entering an operation definition.
     fn enter_operation_definition(
         &mut self,
         _: &mut ValidatorContext<'a, S>,
         _: &'a Spanning<Operation<S>>,
     ) {
     }
+    /// Called when exiting an operation definition.
     fn exit_operation_definition(
         &mut self,
         _: &mut ValidatorContext<'a, S>,
@@ -29,12 +44,14 @@ where
     ) {
     }
 
+    /// Called when entering a fragment definition.
     fn enter_fragment_definition(
         &mut self,
         _: &mut ValidatorContext<'a, S>,
         _: &'a Spanning<Fragment<S>>,
     ) {
     }
+    /// Called when exiting a fragment definition.
     fn exit_fragment_definition(
         &mut self,
         _: &mut ValidatorContext<'a, S>,
@@ -42,12 +59,14 @@ where
     ) {
     }
 
+    /// Called when entering a variable definition.
     fn enter_variable_definition(
         &mut self,
         _: &mut ValidatorContext<'a, S>,
         _: &'a (Spanning<&'a str>, VariableDefinition<S>),
     ) {
     }
+    /// Called when exiting a variable definition.
     fn exit_variable_definition(
         &mut self,
         _: &mut ValidatorContext<'a, S>,
@@ -55,15 +74,19 @@ where
     ) {
     }
 
+    /// Called when entering a directive.
     fn enter_directive(&mut self, _: &mut ValidatorContext<'a, S>, _: &'a Spanning<Directive<S>>) {}
+    /// Called when exiting a directive.
     fn exit_directive(&mut self, _: &mut ValidatorContext<'a, S>, _: &'a Spanning<Directive<S>>) {}
 
+    /// Called when entering an argument.
     fn enter_argument(
         &mut self,
         _: &mut ValidatorContext<'a, S>,
         _: &'a (Spanning<&'a str>, Spanning<InputValue<S>>),
     ) {
     }
+    /// Called when exiting an argument.
     fn exit_argument(
         &mut self,
         _: &mut ValidatorContext<'a, S>,
@@ -71,18 +94,24 @@ where
     ) {
     }
 
+    /// Called when entering a selection set.
     fn enter_selection_set(&mut self, _: &mut ValidatorContext<'a, S>, _: &'a [Selection<S>]) {}
+    /// Called when exiting a selection set.
     fn exit_selection_set(&mut self, _: &mut ValidatorContext<'a, S>, _: &'a [Selection<S>]) {}
 
+    /// Called when entering a field.
     fn enter_field(&mut self, _: &mut ValidatorContext<'a, S>, _: &'a Spanning<Field<S>>) {}
+    /// Called when exiting a field.
     fn exit_field(&mut self, _: &mut ValidatorContext<'a, S>, _: &'a Spanning<Field<S>>) {}
 
+    /// Called when entering a fragment spread.
     fn enter_fragment_spread(
         &mut self,
         _: &mut ValidatorContext<'a, S>,
         _: &'a Spanning<FragmentSpread<S>>,
     ) {
     }
+    /// Called when exiting a fragment spread.
     fn exit_fragment_spread(
         &mut self,
         _: &mut ValidatorContext<'a, S>,
@@ -90,12 +119,14 @@ where
     ) {
     }
 
+    /// Called when entering an inline fragment.
     fn enter_inline_fragment(
         &mut self,
         _: &mut ValidatorContext<'a, S>,
         _: &'a Spanning<InlineFragment<S>>,
     ) {
     }
+    /// Called when exiting an inline fragment.
     fn exit_inline_fragment(
         &mut self,
         _: &mut ValidatorContext<'a, S>,
@@ -103,24 +134,34 @@ where
     ) {
     }
 
+    /// Called when entering a `null` input value.
     fn enter_null_value(&mut self, _: &mut ValidatorContext<'a, S>, _: Spanning<()>) {}
+    /// Called when exiting a `null` input value.
     fn exit_null_value(&mut self, _: &mut ValidatorContext<'a, S>, _: Spanning<()>) {}
 
+    /// Called when entering a scalar input value.
     fn enter_scalar_value(&mut self, _: &mut ValidatorContext<'a, S>, _: Spanning<&'a S>) {}
+    /// Called when exiting a scalar input value.
     fn exit_scalar_value(&mut self, _: &mut ValidatorContext<'a, S>, _: Spanning<&'a S>) {}
 
+    /// Called when entering an enum input value.
     fn enter_enum_value(&mut self, _: &mut ValidatorContext<'a, S>, _: Spanning<&'a String>) {}
+    /// Called when exiting an enum input value.
     fn exit_enum_value(&mut self, _: &mut ValidatorContext<'a, S>, _: Spanning<&'a String>) {}
 
+    /// Called when entering a variable input value.
     fn enter_variable_value(&mut self, _: &mut ValidatorContext<'a, S>, _: Spanning<&'a String>) {}
+    /// Called when exiting a variable input value.
     fn exit_variable_value(&mut self, _: &mut ValidatorContext<'a, S>, _: Spanning<&'a String>) {}
 
+    /// Called when entering a list input value.
     fn enter_list_value(
         &mut self,
         _: &mut ValidatorContext<'a, S>,
         _: Spanning<&'a Vec<Spanning<InputValue<S>>>>,
     ) {
     }
+    /// Called when exiting a list input value.
     fn exit_list_value(
         &mut self,
         _: &mut ValidatorContext<'a, S>,
@@ -128,15 +169,19 @@ where
     ) {
     }
 
+    /// Called when entering an object input value.
     fn enter_object_value(&mut self, _: &mut ValidatorContext<'a, S>, _: SpannedObject<'a, S>) {}
+    /// Called when exiting an object input value.
     fn exit_object_value(&mut self, _: &mut ValidatorContext<'a, S>, _: SpannedObject<'a, S>) {}
 
+    /// Called when entering a field of an object input value.
     fn enter_object_field(
         &mut self,
         _: &mut ValidatorContext<'a, S>,
         _: &'a (Spanning<String>, Spanning<InputValue<S>>),
     ) {
     }
+    /// Called when exiting a field of an object input value.
     fn exit_object_field(
         &mut self,
         _: &mut ValidatorContext<'a, S>,
@@ -146,3 +191,23 @@ where
 }
 
 type SpannedObject<'a, S> = Spanning<&'a Vec<(Spanning<String>, Spanning<InputValue<S>>)>>;
+
+/// User-defined validation rule, run on every query validated against the
+/// schema it's registered in (see [`RootNode::with_validation_rule()`]).
+///
+/// Creates a fresh [`Visitor`] for each validated query, so the [`Visitor`]
+/// may accumulate any state while traversing a single query.
+///
+/// [`RootNode::with_validation_rule()`]: crate::RootNode::with_validation_rule
+pub trait ValidationRule<S>: Send + Sync {
+    /// Creates a new [`Visitor`] validating a single query.
+    fn visitor<'a>(&self) -> Box<dyn Visitor<'a, S> + 'a>
+    where
+        S: ScalarValue;
+}
+
+impl<'a, S> fmt::Debug for dyn ValidationRule<S> + 'a {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("dyn ValidationRule")
+    }
+}
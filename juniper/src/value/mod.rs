@@ -1,15 +1,18 @@
 mod object;
+mod pretty;
 mod scalar;
 
 use std::{any::TypeId, borrow::Cow, fmt, mem};
 
 use crate::{
     ast::{InputValue, ToInputValue},
+    executor::PathSegment,
     parser::Spanning,
 };
 
 pub use self::{
     object::Object,
+    pretty::PrettyOptions,
     scalar::{DefaultScalarValue, ParseScalarResult, ParseScalarValue, ScalarValue},
 };
 
@@ -22,7 +25,14 @@ pub use self::{
 /// values or variables. Also, lists and objects do not contain any location
 /// information since they are generated by resolving fields and values rather
 /// than parsing a source query.
-#[derive(Clone, Debug, PartialEq)]
+///
+/// [`Value::List`]s are compared (and hashed) considering the order of their
+/// items, while [`Value::Object`]s are compared (and hashed) regardless of the
+/// order of their fields. [`Eq`] and [`Hash`] are implemented only when the
+/// scalar `S` implements them.
+///
+/// [`Hash`]: std::hash::Hash
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 #[allow(missing_docs)]
 pub enum Value<S = DefaultScalarValue> {
     Null,
@@ -105,6 +115,36 @@ impl<S> Value<S> {
     }
 
     /// Mutable view into the underlying object value, if present.
+    ///
+    /// Fields keep their order while being mutated in place (via
+    /// [`Object::iter_mut()`] or [`Object::get_mut_field_value()`]) or removed
+    /// (via [`Object::remove_field()`]).
+    ///
+    /// ```rust
+    /// # use juniper::{graphql_value, Value};
+    /// #
+    /// // Masks the `secret` fields of all the objects in the `value`.
+    /// fn mask(value: &mut Value) {
+    ///     if let Some(obj) = value.as_mut_object_value() {
+    ///         obj.remove_field("secret");
+    ///         obj.iter_mut().for_each(|(_, v)| mask(v));
+    ///     } else if let Some(list) = value.as_mut_list_value() {
+    ///         list.iter_mut().for_each(mask);
+    ///     }
+    /// }
+    ///
+    /// let mut data: Value = graphql_value!({
+    ///     "users": [{"name": "Alice", "secret": 1}, {"secret": 2, "name": "Bob"}],
+    ///     "secret": 3,
+    ///     "count": 2,
+    /// });
+    /// mask(&mut data);
+    ///
+    /// assert_eq!(
+    ///     data.to_string(),
+    ///     r#"{"users": [{"name": "Alice"}, {"name": "Bob"}], "count": 2}"#,
+    /// );
+    /// ```
     pub fn as_mut_object_value(&mut self) -> Option<&mut Object<S>> {
         match self {
             Self::Object(o) => Some(o),
@@ -120,6 +160,32 @@ impl<S> Value<S> {
         }
     }
 
+    /// Convert this value into a list.
+    ///
+    /// Returns None if value is not a list.
+    pub fn into_list(self) -> Option<Vec<Self>> {
+        match self {
+            Self::List(l) => Some(l),
+            _ => None,
+        }
+    }
+
+    /// Mutable view into the underlying list value, if present.
+    ///
+    /// Being a mutable borrow of this value, it doesn't allow to inspect the
+    /// value itself while the list is mutated. To rewrite a value depending on
+    /// its contents, [`mem::replace()`] it with a [`Value::Null`], and put it
+    /// back with the rewritten contents (see [`Value::into_list()`] and
+    /// [`Value::into_object()`]).
+    ///
+    /// [`mem::replace()`]: std::mem::replace
+    pub fn as_mut_list_value(&mut self) -> Option<&mut Vec<Self>> {
+        match self {
+            Self::List(l) => Some(l),
+            _ => None,
+        }
+    }
+
     /// View the underlying scalar value, if present
     pub fn as_scalar(&self) -> Option<&S> {
         match self {
@@ -136,6 +202,76 @@ impl<S> Value<S> {
         self.as_scalar_value::<String>().map(String::as_str)
     }
 
+    /// Returns the nested [`Value`] located at the provided `path` in this
+    /// [`Value`], if any.
+    ///
+    /// [`PathSegment::Field`]s select fields of [`Value::Object`]s, while
+    /// [`PathSegment::Index`]es select items of [`Value::List`]s. Returns
+    /// [`None`] if any segment of the `path` is absent, or doesn't match the
+    /// kind of the [`Value`] it's applied to. An empty `path` selects this
+    /// [`Value`] itself.
+    ///
+    /// As the path of an [`ExecutionError`] is relative to the `data` of a
+    /// response, it may be used directly to get the [`Value`] the error
+    /// occurred at.
+    ///
+    /// ```rust
+    /// # use juniper::{graphql_value, PathSegment, Value};
+    /// #
+    /// let data: Value = graphql_value!({
+    ///     "user": {"friends": [{"name": "Alice"}, {"name": "Bob"}]},
+    /// });
+    ///
+    /// let path: [PathSegment; 4] = ["user".into(), "friends".into(), 0.into(), "name".into()];
+    /// assert_eq!(data.get_path(&path), Some(&graphql_value!("Alice")));
+    ///
+    /// let path: [PathSegment; 3] = ["user".into(), "friends".into(), 2.into()];
+    /// assert_eq!(data.get_path(&path), None);
+    /// ```
+    ///
+    /// [`ExecutionError`]: crate::ExecutionError
+    pub fn get_path(&self, path: &[PathSegment]) -> Option<&Self> {
+        path.iter()
+            .try_fold(self, |value, segment| match (segment, value) {
+                (PathSegment::Field(name), Self::Object(obj)) => obj.get_field_value(name),
+                (PathSegment::Index(idx), Self::List(list)) => list.get(*idx),
+                _ => None,
+            })
+    }
+
+    /// Renders this [`Value`] as a GraphQL response JSON, formatted according
+    /// to the provided [`PrettyOptions`].
+    ///
+    /// Unlike the [`Display`] implementation, the output is a valid JSON with
+    /// properly escaped strings, and is stable for the same [`Value`] and
+    /// [`PrettyOptions`], so is suitable for snapshot testing.
+    ///
+    /// ```rust
+    /// # use juniper::{graphql_value, PrettyOptions, Value};
+    /// #
+    /// let data: Value = graphql_value!({"user": {"name": "Ann", "age": 42}});
+    ///
+    /// assert_eq!(
+    ///     data.to_pretty_string(PrettyOptions::default().sorted(true)),
+    ///     "{\n  \"user\": {\n    \"age\": 42,\n    \"name\": \"Ann\"\n  }\n}",
+    /// );
+    /// assert_eq!(
+    ///     data.to_pretty_string(PrettyOptions::default().indent(0)),
+    ///     r#"{"user":{"name":"Ann","age":42}}"#,
+    /// );
+    /// ```
+    ///
+    /// [`Display`]: fmt::Display
+    #[must_use]
+    pub fn to_pretty_string(&self, options: PrettyOptions) -> String
+    where
+        S: ScalarValue,
+    {
+        let mut out = String::new();
+        pretty::write_value(&mut out, self, options, 0);
+        out
+    }
+
     /// Maps the [`ScalarValue`] type of this [`Value`] into the specified one.
     pub fn map_scalar_value<Into>(self) -> Value<Into>
     where
@@ -242,6 +378,15 @@ where
     }
 }
 
+impl<S, T> From<Vec<T>> for Value<S>
+where
+    Self: From<T>,
+{
+    fn from(v: Vec<T>) -> Self {
+        Self::list(v.into_iter().map(Into::into).collect())
+    }
+}
+
 impl<'a, S: From<String>> From<&'a str> for Value<S> {
     fn from(s: &'a str) -> Self {
         Self::scalar(s.to_owned())
@@ -280,9 +425,14 @@ impl<S: From<bool>> From<bool> for Value<S> {
 
 #[cfg(test)]
 mod tests {
+    use std::{
+        collections::hash_map::DefaultHasher,
+        hash::{Hash, Hasher},
+    };
+
     use crate::graphql_value;
 
-    use super::Value;
+    use super::{Object, PrettyOptions, Value};
 
     #[test]
     fn display_null() {
@@ -361,4 +511,203 @@ mod tests {
         let s: Value = graphql_value!({});
         assert_eq!(s.to_string(), r#"{}"#);
     }
+
+    fn hash_of<T: Hash>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn object(fields: &[(&str, Value<i32>)]) -> Value<i32> {
+        let mut obj = Object::with_capacity(fields.len());
+        for (k, v) in fields {
+            obj.add_field(*k, v.clone());
+        }
+        Value::Object(obj)
+    }
+
+    #[test]
+    fn object_eq_and_hash_ignore_fields_order() {
+        let a = object(&[("a", Value::Scalar(1)), ("b", Value::Null)]);
+        let b = object(&[("b", Value::Null), ("a", Value::Scalar(1))]);
+
+        assert_eq!(a, b);
+        assert_eq!(hash_of(&a), hash_of(&b));
+
+        let c = object(&[("a", Value::Null), ("b", Value::Scalar(1))]);
+
+        assert_ne!(a, c);
+        assert_ne!(hash_of(&a), hash_of(&c));
+    }
+
+    #[test]
+    fn list_eq_and_hash_respect_items_order() {
+        let a = Value::List(vec![Value::Scalar(1), Value::Scalar(2)]);
+        let b = Value::List(vec![Value::Scalar(2), Value::Scalar(1)]);
+
+        assert_ne!(a, b);
+        assert_ne!(hash_of(&a), hash_of(&b));
+        assert_eq!(hash_of(&a), hash_of(&a.clone()));
+    }
+
+    #[test]
+    fn get_path() {
+        let v: Value = graphql_value!({
+            "user": {"friends": [{"name": "Alice"}, null]},
+        });
+
+        assert_eq!(v.get_path(&[]), Some(&v));
+        assert_eq!(
+            v.get_path(&["user".into(), "friends".into(), 0.into(), "name".into()]),
+            Some(&graphql_value!("Alice")),
+        );
+        assert_eq!(
+            v.get_path(&["user".into(), "friends".into(), 1.into()]),
+            Some(&graphql_value!(null)),
+        );
+    }
+
+    #[test]
+    fn get_path_absent_or_mismatched() {
+        let v: Value = graphql_value!({"user": {"friends": [{"name": "Alice"}]}});
+
+        assert_eq!(v.get_path(&["admin".into()]), None);
+        assert_eq!(
+            v.get_path(&["user".into(), "friends".into(), 1.into()]),
+            None
+        );
+        assert_eq!(v.get_path(&["user".into(), 0.into()]), None);
+        assert_eq!(
+            v.get_path(&["user".into(), "friends".into(), "name".into()]),
+            None
+        );
+        assert_eq!(
+            v.get_path(&[
+                "user".into(),
+                "friends".into(),
+                0.into(),
+                "name".into(),
+                "first".into(),
+            ]),
+            None,
+        );
+    }
+
+    #[test]
+    fn mutates_preserving_field_order() {
+        let mut v: Value = graphql_value!({"a": 1, "b": [1, 2], "c": 3, "d": 4});
+
+        let obj = v.as_mut_object_value().unwrap();
+        *obj.get_mut_field_value("a").unwrap() = graphql_value!("one");
+        assert_eq!(obj.remove_field("c"), Some(graphql_value!(3)));
+        assert_eq!(obj.remove_field("c"), None);
+        obj.add_field("b", graphql_value!([2, 1]));
+        obj.add_field("e", graphql_value!(5));
+        assert_eq!(
+            v.to_string(),
+            r#"{"a": "one", "b": [2, 1], "d": 4, "e": 5}"#,
+        );
+
+        let list = v
+            .as_mut_object_value()
+            .and_then(|o| o.get_mut_field_value("b"))
+            .and_then(Value::as_mut_list_value)
+            .unwrap();
+        list.push(graphql_value!(null));
+        list.swap(0, 1);
+        assert_eq!(
+            v.into_object()
+                .and_then(|o| o.into_iter().nth(1))
+                .and_then(|(_, b)| b.into_list()),
+            Some(vec![
+                graphql_value!(1),
+                graphql_value!(2),
+                graphql_value!(null),
+            ]),
+        );
+        assert_eq!(graphql_value!(1).into_list(), None::<Vec<Value>>);
+    }
+
+    #[test]
+    fn pretty_indents_nested_values() {
+        let v: Value = graphql_value!({
+            "user": {
+                "name": "Ann",
+                "friends": [{"id": 1}, null],
+                "tags": [],
+                "extra": {},
+            },
+        });
+        assert_eq!(
+            v.to_pretty_string(PrettyOptions::default()),
+            "{\n  \"user\": {\n    \"name\": \"Ann\",\n    \"friends\": [\n      {\n        \
+             \"id\": 1\n      },\n      null\n    ],\n    \"tags\": [],\n    \"extra\": {}\n  \
+             }\n}",
+        );
+        assert_eq!(
+            v.to_pretty_string(PrettyOptions::default().indent(4)),
+            "{\n    \"user\": {\n        \"name\": \"Ann\",\n        \"friends\": [\n            \
+             {\n                \"id\": 1\n            },\n            null\n        ],\n        \
+             \"tags\": [],\n        \"extra\": {}\n    }\n}",
+        );
+    }
+
+    #[test]
+    fn pretty_compact() {
+        let v: Value = graphql_value!({"a": [1, {"b": null}], "c": true});
+        assert_eq!(
+            v.to_pretty_string(PrettyOptions::default().indent(0)),
+            r#"{"a":[1,{"b":null}],"c":true}"#,
+        );
+    }
+
+    #[test]
+    fn pretty_sorts_keys_recursively() {
+        let v: Value = graphql_value!({"b": {"z": 1, "y": 2}, "a": [{"d": 3, "c": 4}]});
+        let opts = PrettyOptions::default().indent(0);
+
+        assert_eq!(
+            v.to_pretty_string(opts),
+            r#"{"b":{"z":1,"y":2},"a":[{"d":3,"c":4}]}"#,
+        );
+        assert_eq!(
+            v.to_pretty_string(opts.sorted(true)),
+            r#"{"a":[{"c":4,"d":3}],"b":{"y":2,"z":1}}"#,
+        );
+    }
+
+    #[test]
+    fn pretty_escapes_strings() {
+        let v: Value = graphql_value!({"quo\"te": "back\\slash\n\t\r\u{1}\u{8}\u{c} ünï"});
+        assert_eq!(
+            v.to_pretty_string(PrettyOptions::default().indent(0)),
+            r#"{"quo\"te":"back\\slash\n\t\r\u0001\b\f ünï"}"#,
+        );
+    }
+
+    #[test]
+    fn pretty_formats_numbers() {
+        let v: Value = graphql_value!([1.0, 0.1, -2.5, 1e300, 42, -7]);
+        assert_eq!(
+            v.to_pretty_string(PrettyOptions::default().indent(0)),
+            "[1.0,0.1,-2.5,1e300,42,-7]",
+        );
+
+        let v: Value = graphql_value!([(f64::NAN), (f64::INFINITY)]);
+        assert_eq!(
+            v.to_pretty_string(PrettyOptions::default().indent(0)),
+            "[null,null]",
+        );
+    }
+
+    #[test]
+    fn pretty_is_stable() {
+        let v: Value = graphql_value!({"x": [1.5, "s"], "a": {"k": false}});
+        let opts = PrettyOptions::default().sorted(true);
+        let first = v.to_pretty_string(opts);
+
+        for _ in 0..10 {
+            assert_eq!(v.clone().to_pretty_string(opts), first);
+        }
+    }
 }
@@ -1,14 +1,40 @@
-use std::mem;
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    mem,
+};
 
 use super::Value;
 use indexmap::map::{IndexMap, IntoIter};
 
 /// An Object value
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Objects are compared (and hashed) regardless of the order of their fields.
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Object<S> {
     key_value_list: IndexMap<String, Value<S>>,
 }
 
+impl<S: Hash> Hash for Object<S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Fields are hashed separately and combined with a commutative
+        // operation, so the hash doesn't depend on their order, the same way
+        // `PartialEq` doesn't.
+        let fields = self
+            .key_value_list
+            .iter()
+            .map(|field| {
+                let mut hasher = DefaultHasher::new();
+                field.hash(&mut hasher);
+                hasher.finish()
+            })
+            .fold(0_u64, u64::wrapping_add);
+
+        self.key_value_list.len().hash(state);
+        fields.hash(state);
+    }
+}
+
 impl<S> Object<S> {
     /// Create a new Object value with a fixed number of
     /// preallocated slots for field-value pairs
@@ -65,6 +91,13 @@ impl<S> Object<S> {
     pub fn get_mut_field_value<K: AsRef<str>>(&mut self, key: K) -> Option<&mut Value<S>> {
         self.key_value_list.get_mut(key.as_ref())
     }
+
+    /// Removes the field with the given name, returning its value, if any.
+    ///
+    /// The remaining fields keep their order.
+    pub fn remove_field<K: AsRef<str>>(&mut self, key: K) -> Option<Value<S>> {
+        self.key_value_list.shift_remove(key.as_ref())
+    }
 }
 
 impl<S> IntoIterator for Object<S> {
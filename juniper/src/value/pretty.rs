@@ -0,0 +1,170 @@
+//! Pretty-printing of [`Value`]s as GraphQL response JSON.
+
+use super::{ScalarValue, Value};
+
+/// Options of the output produced by [`Value::to_pretty_string()`].
+///
+/// [`Default`] options indent nested lists and objects with 2 spaces, and
+/// preserve the order of object fields.
+#[derive(Clone, Copy, Debug)]
+pub struct PrettyOptions {
+    indent: usize,
+    sorted: bool,
+}
+
+impl Default for PrettyOptions {
+    fn default() -> Self {
+        Self {
+            indent: 2,
+            sorted: false,
+        }
+    }
+}
+
+impl PrettyOptions {
+    /// Specifies the number of spaces to indent every nesting level of lists
+    /// and objects with.
+    ///
+    /// `0` produces a compact single-line output without any whitespace.
+    ///
+    /// Default is `2`.
+    #[must_use]
+    pub fn indent(mut self, indent: usize) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Specifies whether fields of objects should be ordered alphabetically by
+    /// their names.
+    ///
+    /// Otherwise, fields follow the order they've been inserted in, which is
+    /// the order of the selection set in case of an execution result.
+    ///
+    /// Default is `false`.
+    #[must_use]
+    pub fn sorted(mut self, sorted: bool) -> Self {
+        self.sorted = sorted;
+        self
+    }
+}
+
+/// Writes the provided [`Value`] into the `out`put, with the nested lists and
+/// objects being indented relatively to the given `depth`.
+pub(super) fn write_value<S: ScalarValue>(
+    out: &mut String,
+    value: &Value<S>,
+    options: PrettyOptions,
+    depth: usize,
+) {
+    match value {
+        Value::Null => out.push_str("null"),
+        Value::Scalar(s) => write_scalar(out, s),
+        Value::List(list) => {
+            if list.is_empty() {
+                return out.push_str("[]");
+            }
+            out.push('[');
+            for (i, item) in list.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_newline(out, options, depth + 1);
+                write_value(out, item, options, depth + 1);
+            }
+            write_newline(out, options, depth);
+            out.push(']');
+        }
+        Value::Object(obj) => {
+            if obj.field_count() == 0 {
+                return out.push_str("{}");
+            }
+            let mut fields: Vec<_> = obj.iter().collect();
+            if options.sorted {
+                fields.sort_by_key(|&(name, _)| name);
+            }
+            out.push('{');
+            for (i, (key, value)) in fields.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_newline(out, options, depth + 1);
+                write_string(out, key);
+                out.push(':');
+                if options.indent > 0 {
+                    out.push(' ');
+                }
+                write_value(out, value, options, depth + 1);
+            }
+            write_newline(out, options, depth);
+            out.push('}');
+        }
+    }
+}
+
+/// Starts a new line indented to the given `depth`, unless the output is
+/// compact.
+fn write_newline(out: &mut String, options: PrettyOptions, depth: usize) {
+    if options.indent > 0 {
+        out.push('\n');
+        out.extend(std::iter::repeat(' ').take(options.indent * depth));
+    }
+}
+
+/// Writes the provided [`ScalarValue`] as a JSON string, boolean or number.
+///
+/// Floats are written in their shortest representation, which is parsed back
+/// into the same value, always with a fractional part or an exponent (like
+/// `1.0`), while the non-finite ones are written as `null`. [`ScalarValue`]s,
+/// which are neither of these, are written via their [`Display`]
+/// implementation.
+///
+/// [`Display`]: std::fmt::Display
+fn write_scalar<S: ScalarValue>(out: &mut String, scalar: &S) {
+    if let Some(s) = scalar.as_str() {
+        write_string(out, s);
+    } else if let Some(b) = scalar.as_bool() {
+        out.push_str(if b { "true" } else { "false" });
+    } else if let Some(i) = as_integer(scalar) {
+        out.push_str(&i.to_string());
+    } else if let Some(f) = scalar.as_float() {
+        if f.is_finite() {
+            out.push_str(&format!("{f:?}"));
+        } else {
+            out.push_str("null");
+        }
+    } else {
+        out.push_str(&scalar.to_string());
+    }
+}
+
+/// Represents the provided [`ScalarValue`] as an integer of any supported
+/// width.
+fn as_integer<S: ScalarValue>(scalar: &S) -> Option<i128> {
+    scalar
+        .as_int()
+        .map(i128::from)
+        .or_else(|| scalar.as_i64().map(i128::from))
+        .or_else(|| scalar.as_u64().map(i128::from))
+        .or_else(|| scalar.as_i128())
+}
+
+/// Writes the provided [`str`] as a quoted and escaped JSON string.
+fn write_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{08}' => out.push_str("\\b"),
+            '\u{0C}' => out.push_str("\\f"),
+            c if (c as u32) < 0x20 => {
+                out.push_str(&format!("\\u{:04x}", c as u32));
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
@@ -10,8 +10,26 @@ pub use juniper_codegen::ScalarValue;
 pub type ParseScalarResult<S = DefaultScalarValue> = Result<S, ParseError>;
 
 /// A trait used to convert a `ScalarToken` into a certain scalar value type
+///
+/// The parser calls it for every scalar literal provided where a value of the
+/// implementing type is expected, so it may accept any number of `ScalarToken`
+/// kinds (like both `"5"` and `5` literals), by matching on the token or by
+/// delegating to other implementations:
+///
+/// ```rust
+/// # use juniper::{ParseScalarResult, ParseScalarValue, ScalarToken, ScalarValue};
+/// #
+/// struct StringOrInt;
+///
+/// impl<S: ScalarValue> ParseScalarValue<S> for StringOrInt {
+///     fn from_str(value: ScalarToken<'_>) -> ParseScalarResult<S> {
+///         <String as ParseScalarValue<S>>::from_str(value)
+///             .or_else(|_| <i32 as ParseScalarValue<S>>::from_str(value))
+///     }
+/// }
+/// ```
 pub trait ParseScalarValue<S = DefaultScalarValue> {
-    /// See the trait documentation
+    /// Parses the provided `value` token into a scalar value.
     fn from_str(value: ScalarToken<'_>) -> ParseScalarResult<S>;
 }
 
@@ -42,8 +60,9 @@ pub trait ParseScalarValue<S = DefaultScalarValue> {
 /// #[derive(Clone, Debug, PartialEq, ScalarValue, Serialize)]
 /// #[serde(untagged)]
 /// enum MyScalarValue {
-///     #[value(as_float, as_int)]
+///     #[value(as_float, as_int, as_i64)]
 ///     Int(i32),
+///     #[value(as_i64)]
 ///     Long(i64),
 ///     #[value(as_float)]
 ///     Float(f64),
@@ -164,6 +183,47 @@ pub trait ScalarValue:
     #[must_use]
     fn as_int(&self) -> Option<i32>;
 
+    /// Represents this [`ScalarValue`] as a signed 64 bit integer value.
+    ///
+    /// Implementations should convert all the supported integer types fitting
+    /// into an [`i64`], if requested. Returns [`None`] by default.
+    ///
+    /// ```
+    /// # use juniper::{ScalarValue, DefaultScalarValue};
+    /// #
+    /// assert_eq!(DefaultScalarValue::Int(-42).as_i64(), Some(-42));
+    /// assert_eq!(DefaultScalarValue::Float(4.2).as_i64(), None);
+    /// ```
+    #[must_use]
+    fn as_i64(&self) -> Option<i64> {
+        None
+    }
+
+    /// Represents this [`ScalarValue`] as an unsigned 64 bit integer value.
+    ///
+    /// Implementations should convert all the supported integer types fitting
+    /// into an [`u64`], if requested. Returns [`None`] by default.
+    ///
+    /// ```
+    /// # use juniper::{ScalarValue, DefaultScalarValue};
+    /// #
+    /// assert_eq!(DefaultScalarValue::Int(42).as_u64(), Some(42));
+    /// assert_eq!(DefaultScalarValue::Int(-42).as_u64(), None);
+    /// ```
+    #[must_use]
+    fn as_u64(&self) -> Option<u64> {
+        None
+    }
+
+    /// Represents this [`ScalarValue`] as a signed 128 bit integer value.
+    ///
+    /// Implementations should convert all the supported integer types fitting
+    /// into an [`i128`], if requested. Returns [`None`] by default.
+    #[must_use]
+    fn as_i128(&self) -> Option<i128> {
+        None
+    }
+
     /// Represents this [`ScalarValue`] as a [`String`] value.
     ///
     /// This function is used for implementing [`GraphQLValue`] for [`String`]
@@ -235,7 +295,7 @@ pub enum DefaultScalarValue {
     /// [`Int` scalar][0] as a signed 32‐bit numeric non‐fractional value.
     ///
     /// [0]: https://spec.graphql.org/October2021#sec-Int
-    #[value(as_float, as_int)]
+    #[value(as_float, as_int, as_i64, as_u64, as_i128)]
     Int(i32),
 
     /// [`Float` scalar][0] as a signed double‐precision fractional values as
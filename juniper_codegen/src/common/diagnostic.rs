@@ -18,6 +18,7 @@ pub(crate) enum Scope {
     ScalarValueDerive,
     UnionAttr,
     UnionDerive,
+    VariablesDerive,
 }
 
 impl Scope {
@@ -30,6 +31,7 @@ impl Scope {
             Self::ScalarAttr | Self::ScalarDerive => "#sec-Scalars",
             Self::ScalarValueDerive => "#sec-Scalars.Built-in-Scalars",
             Self::UnionAttr | Self::UnionDerive => "#sec-Unions",
+            Self::VariablesDerive => "#sec-Language.Variables",
         }
     }
 }
@@ -44,6 +46,7 @@ impl fmt::Display for Scope {
             Self::ScalarAttr | Self::ScalarDerive => "scalar",
             Self::ScalarValueDerive => "built-in scalars",
             Self::UnionAttr | Self::UnionDerive => "union",
+            Self::VariablesDerive => "variables",
         };
         write!(f, "GraphQL {name}")
     }
@@ -10,6 +10,7 @@ use quote::{quote, quote_spanned};
 use syn::{
     ext::IdentExt as _,
     parse::{Parse, ParseStream},
+    parse_quote,
     spanned::Spanned,
     token,
 };
@@ -52,6 +53,18 @@ pub(crate) struct Attr {
     /// [2]: https://spec.graphql.org/October2021#sec-Required-Arguments
     pub(crate) default: Option<SpanContainer<default::Value>>,
 
+    /// Explicitly specified function computing a value of this
+    /// [GraphQL argument][1] from a [`Context`], when it's omitted.
+    ///
+    /// Being dynamic, such value cannot be represented in GraphQL schema, so
+    /// this [GraphQL argument][1] is considered as an optional one without a
+    /// [default value][2].
+    ///
+    /// [`Context`]: juniper::Context
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Arguments
+    /// [2]: https://spec.graphql.org/October2021#sec-Required-Arguments
+    pub(crate) default_with: Option<SpanContainer<syn::ExprPath>>,
+
     /// Explicitly specified marker indicating that this method argument doesn't
     /// represent a [GraphQL argument][1], but is a [`Context`] being injected
     /// into a [GraphQL field][2] resolving function.
@@ -103,6 +116,13 @@ impl Parse for Attr {
                         .replace(SpanContainer::new(ident.span(), Some(val.span()), val))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "default_with" => {
+                    input.parse::<token::Eq>()?;
+                    let path = input.parse::<syn::ExprPath>()?;
+                    out.default_with
+                        .replace(SpanContainer::new(ident.span(), Some(path.span()), path))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "ctx" | "context" | "Context" => {
                     let span = ident.span();
                     out.context
@@ -133,6 +153,7 @@ impl Attr {
             name: try_merge_opt!(name: self, another),
             description: try_merge_opt!(description: self, another),
             default: try_merge_opt!(default: self, another),
+            default_with: try_merge_opt!(default_with: self, another),
             context: try_merge_opt!(context: self, another),
             executor: try_merge_opt!(executor: self, another),
         })
@@ -149,6 +170,7 @@ impl Attr {
             if attr.name.is_some()
                 || attr.description.is_some()
                 || attr.default.is_some()
+                || attr.default_with.is_some()
                 || attr.executor.is_some()
             {
                 return Err(syn::Error::new(
@@ -162,6 +184,7 @@ impl Attr {
             if attr.name.is_some()
                 || attr.description.is_some()
                 || attr.default.is_some()
+                || attr.default_with.is_some()
                 || attr.context.is_some()
             {
                 return Err(syn::Error::new(
@@ -171,6 +194,13 @@ impl Attr {
             }
         }
 
+        if let (Some(_), Some(default_with)) = (&attr.default, &attr.default_with) {
+            return Err(syn::Error::new(
+                default_with.span_ident(),
+                "`default` and `default_with` attribute arguments are mutually exclusive",
+            ));
+        }
+
         Ok(attr)
     }
 
@@ -186,6 +216,9 @@ impl Attr {
         if let Some(span) = &self.default {
             return Err(Self::err_disallowed(&span, "default"));
         }
+        if let Some(span) = &self.default_with {
+            return Err(Self::err_disallowed(&span, "default_with"));
+        }
         Ok(())
     }
 
@@ -229,6 +262,33 @@ pub(crate) struct OnField {
     /// [1]: https://spec.graphql.org/October2021#sec-Language.Arguments
     /// [2]: https://spec.graphql.org/October2021#sec-Required-Arguments
     pub(crate) default: Option<default::Value>,
+
+    /// Function computing a value of this [GraphQL field argument][1] from a
+    /// [`Context`], when it's omitted.
+    ///
+    /// [`Context`]: juniper::Context
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Arguments
+    pub(crate) default_with: Option<syn::ExprPath>,
+}
+
+impl OnField {
+    /// Returns Rust type representing this [GraphQL field argument][1] in
+    /// GraphQL schema.
+    ///
+    /// It differs from the [`OnField::ty`] for arguments with a
+    /// [`OnField::default_with`] function, which are always nullable in
+    /// GraphQL schema.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Arguments
+    #[must_use]
+    pub(crate) fn schema_ty(&self) -> syn::Type {
+        let ty = &self.ty;
+        if self.default_with.is_some() {
+            parse_quote! { ::std::option::Option<#ty> }
+        } else {
+            ty.clone()
+        }
+    }
 }
 
 /// Possible kinds of Rust method arguments for code generation.
@@ -249,7 +309,7 @@ pub(crate) enum OnMethod {
     ///
     /// [`Executor`]: juniper::Executor
     /// [2]: https://spec.graphql.org/October2021#sec-Language.Fields
-    Executor,
+    Executor(Box<syn::Type>),
 }
 
 impl OnMethod {
@@ -274,6 +334,17 @@ impl OnMethod {
         }
     }
 
+    /// Returns [`syn::Type`] of this [`OnMethod::Executor`], if it represents
+    /// the one.
+    #[must_use]
+    pub(crate) fn executor_ty(&self) -> Option<&syn::Type> {
+        if let Self::Executor(ty) = self {
+            Some(&*ty)
+        } else {
+            None
+        }
+    }
+
     /// Returns generated code for the [`marker::IsOutputType::mark`] method,
     /// which performs static checks for this argument, if it represents an
     /// [`OnField`] one.
@@ -297,7 +368,7 @@ impl OnMethod {
     pub(crate) fn method_meta_tokens(&self) -> Option<TokenStream> {
         let arg = self.as_regular()?;
 
-        let (name, ty) = (&arg.name, &arg.ty);
+        let (name, ty) = (&arg.name, arg.schema_ty());
 
         let description = &arg.description;
 
@@ -328,14 +399,26 @@ impl OnMethod {
                 let (name, ty) = (&arg.name, &arg.ty);
                 let err_text = format!("Missing argument `{name}`: {{}}");
 
-                let arg = quote! {
-                    args.get::<#ty>(#name).and_then(|opt| opt.map_or_else(|| {
-                        <#ty as ::juniper::FromInputValue<#scalar>>::from_implicit_null()
-                            .map_err(|e| {
-                                ::juniper::IntoFieldError::<#scalar>::into_field_error(e)
-                                    .map_message(|m| format!(#err_text, m))
-                            })
-                    }, Ok))
+                // `Nullable` is used here, so a `null` is never passed to the
+                // `FromInputValue` implementation accepting it, and the
+                // `default_with` function is called regardless.
+                let arg = if let Some(default_with) = &arg.default_with {
+                    quote! {
+                        args.get::<::juniper::Nullable<#ty>>(#name).map(|opt| {
+                            opt.and_then(::juniper::Nullable::some)
+                                .unwrap_or_else(|| #default_with(executor.context()))
+                        })
+                    }
+                } else {
+                    quote! {
+                        args.get::<#ty>(#name).and_then(|opt| opt.map_or_else(|| {
+                            <#ty as ::juniper::FromInputValue<#scalar>>::from_implicit_null()
+                                .map_err(|e| {
+                                    ::juniper::IntoFieldError::<#scalar>::into_field_error(e)
+                                        .map_message(|m| format!(#err_text, m))
+                                })
+                        }, Ok))
+                    }
                 };
                 if for_async {
                     quote! {
@@ -353,7 +436,7 @@ impl OnMethod {
                 ::juniper::FromContext::from(executor.context())
             },
 
-            Self::Executor => quote! { &executor },
+            Self::Executor(_) => quote! { &executor },
         }
     }
 
@@ -383,14 +466,16 @@ impl OnMethod {
             return Some(Self::Context(Box::new(argument.ty.unreferenced().clone())));
         }
         if attr.executor.is_some() {
-            return Some(Self::Executor);
+            return Some(Self::Executor(Box::new(argument.ty.unreferenced().clone())));
         }
         if let syn::Pat::Ident(name) = &*argument.pat {
             let arg = match name.ident.unraw().to_string().as_str() {
                 "context" | "ctx" | "_context" | "_ctx" => {
                     Some(Self::Context(Box::new(argument.ty.unreferenced().clone())))
                 }
-                "executor" | "_executor" => Some(Self::Executor),
+                "executor" | "_executor" => {
+                    Some(Self::Executor(Box::new(argument.ty.unreferenced().clone())))
+                }
                 _ => None,
             };
             if arg.is_some() {
@@ -433,6 +518,7 @@ impl OnMethod {
             ty: argument.ty.as_ref().clone(),
             description: attr.description.map(SpanContainer::into_inner),
             default: attr.default.map(SpanContainer::into_inner),
+            default_with: attr.default_with.map(SpanContainer::into_inner),
         })))
     }
 }
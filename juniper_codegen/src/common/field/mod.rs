@@ -56,6 +56,22 @@ pub(crate) struct Attr {
     /// [2]: https://spec.graphql.org/October2021#sec-Deprecation
     pub(crate) deprecated: Option<SpanContainer<deprecation::Directive>>,
 
+    /// Explicitly specified fixed complexity (cost) of this [GraphQL field][1],
+    /// used by query cost analysis.
+    ///
+    /// If [`None`], then the field costs `1` plus the cost of its
+    /// sub-selection.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) complexity: Option<SpanContainer<syn::Expr>>,
+
+    /// Explicitly specified function computing the complexity (cost) of this
+    /// [GraphQL field][1] from its arguments and the cost of its
+    /// sub-selection.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) complexity_with: Option<SpanContainer<syn::ExprPath>>,
+
     /// Explicitly specified marker indicating that this method (or struct
     /// field) should be omitted by code generation and not considered as the
     /// [GraphQL field][1] definition.
@@ -94,6 +110,20 @@ impl Parse for Attr {
                         ))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "complexity" => {
+                    input.parse::<token::Eq>()?;
+                    let expr = input.parse::<syn::Expr>()?;
+                    out.complexity
+                        .replace(SpanContainer::new(ident.span(), Some(expr.span()), expr))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "complexity_with" => {
+                    input.parse::<token::Eq>()?;
+                    let path = input.parse::<syn::ExprPath>()?;
+                    out.complexity_with
+                        .replace(SpanContainer::new(ident.span(), Some(path.span()), path))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "ignore" | "skip" => out
                     .ignore
                     .replace(SpanContainer::new(ident.span(), None, ident.clone()))
@@ -116,6 +146,8 @@ impl Attr {
             name: try_merge_opt!(name: self, another),
             description: try_merge_opt!(description: self, another),
             deprecated: try_merge_opt!(deprecated: self, another),
+            complexity: try_merge_opt!(complexity: self, another),
+            complexity_with: try_merge_opt!(complexity_with: self, another),
             ignore: try_merge_opt!(ignore: self, another),
         })
     }
@@ -130,7 +162,12 @@ impl Attr {
             .try_fold(Self::default(), |prev, curr| prev.try_merge(curr?))?;
 
         if let Some(ignore) = &attr.ignore {
-            if attr.name.is_some() || attr.description.is_some() || attr.deprecated.is_some() {
+            if attr.name.is_some()
+                || attr.description.is_some()
+                || attr.deprecated.is_some()
+                || attr.complexity.is_some()
+                || attr.complexity_with.is_some()
+            {
                 return Err(syn::Error::new(
                     ignore.span(),
                     "`ignore` attribute argument is not composable with any other arguments",
@@ -138,6 +175,13 @@ impl Attr {
             }
         }
 
+        if let (Some(_), Some(complexity_with)) = (&attr.complexity, &attr.complexity_with) {
+            return Err(syn::Error::new(
+                complexity_with.span_ident(),
+                "`complexity` and `complexity_with` attribute arguments are mutually exclusive",
+            ));
+        }
+
         if attr.description.is_none() {
             attr.description = Description::parse_from_doc_attrs(attrs)?;
         }
@@ -178,6 +222,18 @@ pub(crate) struct Definition {
     /// [2]: https://spec.graphql.org/October2021#sec-Deprecation
     pub(crate) deprecated: Option<deprecation::Directive>,
 
+    /// Fixed complexity (cost) of this [GraphQL field][1] to put into GraphQL
+    /// schema.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) complexity: Option<syn::Expr>,
+
+    /// Function computing the complexity (cost) of this [GraphQL field][1] to
+    /// put into GraphQL schema.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) complexity_with: Option<syn::ExprPath>,
+
     /// Ident of the Rust method (or struct field) representing this
     /// [GraphQL field][1].
     ///
@@ -292,6 +348,15 @@ impl Definition {
 
         let description = &self.description;
         let deprecated = &self.deprecated;
+        let complexity = self
+            .complexity
+            .as_ref()
+            .map(|expr| quote! { .complexity(#expr) })
+            .or_else(|| {
+                self.complexity_with
+                    .as_ref()
+                    .map(|path| quote! { .complexity_with(#path) })
+            });
 
         let args = self
             .arguments
@@ -303,6 +368,7 @@ impl Definition {
                 #( #args )*
                 #description
                 #deprecated
+                #complexity
         }
     }
 
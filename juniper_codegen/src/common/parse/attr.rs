@@ -2,9 +2,9 @@
 //! this crate.
 
 use proc_macro2::{Span, TokenStream};
-use syn::parse_quote;
+use syn::{parse::ParseStream, parse_quote, punctuated::Punctuated, token};
 
-use crate::common::path_eq_single;
+use crate::common::{path_eq_single, SpanContainer};
 
 /// Prepends the given `attrs` collection with a new [`syn::Attribute`] generated from the given
 /// `attr_path` and `attr_args`.
@@ -32,6 +32,31 @@ pub(crate) fn strip(attr_path: &str, attrs: Vec<syn::Attribute>) -> Vec<syn::Att
         .collect()
 }
 
+/// Parses the value of a `bound = "..."` attribute argument (following the
+/// `=` token): a string literal containing a comma-separated list of
+/// [`syn::WherePredicate`]s, in the way `serde` does.
+pub(crate) fn parse_bound(
+    ident: &syn::Ident,
+    input: ParseStream<'_>,
+) -> syn::Result<SpanContainer<Vec<syn::WherePredicate>>> {
+    let lit = input.parse::<syn::LitStr>()?;
+    let predicates = lit
+        .parse_with(Punctuated::<syn::WherePredicate, token::Comma>::parse_terminated)?
+        .into_iter()
+        .collect::<Vec<_>>();
+    if predicates.is_empty() {
+        return Err(syn::Error::new(
+            lit.span(),
+            "expected at least 1 where predicate",
+        ));
+    }
+    Ok(SpanContainer::new(
+        ident.span(),
+        Some(lit.span()),
+        predicates,
+    ))
+}
+
 /// Common errors of parsing Rust attributes, appeared in this crate.
 pub(crate) mod err {
     use proc_macro2::Span;
@@ -348,9 +348,18 @@ impl<'a> VisitMut for ReplaceWithDefaults<'a> {
                     // Replace with `DefaultScalarValue` instead of `()`
                     // because generic parameter may be scalar.
                     *ty = parse_quote!(::juniper::DefaultScalarValue);
+                } else {
+                    syn::visit_mut::visit_type_mut(self, ty);
                 }
             }
             _ => {}
         }
     }
+
+    fn visit_type_reference_mut(&mut self, ty: &mut syn::TypeReference) {
+        if let Some(lf) = &mut ty.lifetime {
+            *lf = parse_quote! { 'static };
+        }
+        syn::visit_mut::visit_type_reference_mut(self, ty);
+    }
 }
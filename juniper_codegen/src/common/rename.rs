@@ -17,6 +17,9 @@ pub(crate) enum Policy {
     /// Rename in `camelCase` style.
     CamelCase,
 
+    /// Rename in `snake_case` style.
+    SnakeCase,
+
     /// Rename in `SCREAMING_SNAKE_CASE` style.
     ScreamingSnakeCase,
 }
@@ -27,6 +30,7 @@ impl Policy {
         match self {
             Self::None => name.into(),
             Self::CamelCase => to_camel_case(name),
+            Self::SnakeCase => to_snake_case(name),
             Self::ScreamingSnakeCase => to_upper_snake_case(name),
         }
     }
@@ -39,6 +43,7 @@ impl FromStr for Policy {
         match rule {
             "none" => Ok(Self::None),
             "camelCase" => Ok(Self::CamelCase),
+            "snake_case" => Ok(Self::SnakeCase),
             "SCREAMING_SNAKE_CASE" => Ok(Self::ScreamingSnakeCase),
             _ => Err(()),
         }
@@ -119,6 +124,30 @@ fn to_upper_snake_case(s: &str) -> String {
     upper
 }
 
+/// Converts the provided `s` into `snake_case`, starting a new word on every
+/// uppercase letter following a lowercase letter or a digit, and on the last
+/// uppercase letter of an acronym followed by a lowercase one.
+pub(crate) fn to_snake_case(s: &str) -> String {
+    let chars = s.chars().collect::<Vec<_>>();
+    let mut snake = String::with_capacity(s.len());
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() {
+            let prev = i.checked_sub(1).map(|p| chars[p]);
+            let next = chars.get(i + 1);
+            let is_word_start = prev.map_or(false, |p| p.is_lowercase() || p.is_numeric())
+                || (prev.map_or(false, char::is_uppercase)
+                    && next.map_or(false, |n| n.is_lowercase()));
+            if is_word_start {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
 #[cfg(test)]
 mod to_camel_case_tests {
     use super::to_camel_case;
@@ -162,3 +191,28 @@ mod to_upper_snake_case_tests {
         }
     }
 }
+
+#[cfg(test)]
+mod to_snake_case_tests {
+    use super::to_snake_case;
+
+    #[test]
+    fn converts_correctly() {
+        for (input, expected) in [
+            ("abc", "abc"),
+            ("a_bc", "a_bc"),
+            ("ABC", "abc"),
+            ("A_BC", "a_bc"),
+            ("Human", "human"),
+            ("SomeInput", "some_input"),
+            ("someInput", "some_input"),
+            ("HTTPServer", "http_server"),
+            ("field1Name", "field1_name"),
+            ("Field1", "field1"),
+            ("v2API", "v2_api"),
+            ("", ""),
+        ] {
+            assert_eq!(to_snake_case(input), expected);
+        }
+    }
+}
@@ -11,6 +11,8 @@ use syn::{
     spanned::Spanned,
 };
 
+use crate::common::parse::TypeExt as _;
+
 /// Possible values of `#[graphql(scalar = ...)]` attribute.
 #[derive(Clone, Debug)]
 pub(crate) enum AttrValue {
@@ -155,3 +157,39 @@ impl Type {
         }
     }
 }
+
+/// Infers a concrete [`ScalarValue`] type from the given [`Executor`] type
+/// (like `Executor<'_, '_, (), MyScalarValue>`), if it specifies one.
+///
+/// Type parameters declared in any of the given `generics` are not concrete,
+/// so are never inferred.
+///
+/// [`Executor`]: juniper::Executor
+/// [`ScalarValue`]: juniper::ScalarValue
+#[must_use]
+pub(crate) fn infer_from_executor(
+    executor_ty: &syn::Type,
+    generics: &[&syn::Generics],
+) -> Option<syn::Type> {
+    let segment = match executor_ty.unparenthesized() {
+        syn::Type::Path(ty) => ty.path.segments.last()?,
+        _ => return None,
+    };
+    let scalar = match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) => args
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                syn::GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .nth(1)?,
+        syn::PathArguments::None | syn::PathArguments::Parenthesized(_) => return None,
+    };
+
+    let is_generic = generics.iter().any(|gens| {
+        gens.type_params()
+            .any(|param| matches!(scalar, syn::Type::Path(ty) if ty.path.is_ident(&param.ident)))
+    });
+    (!is_generic).then(|| scalar.clone())
+}
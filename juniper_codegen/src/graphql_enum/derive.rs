@@ -88,6 +88,7 @@ pub(crate) fn expand(input: TokenStream) -> syn::Result<TokenStream> {
         context,
         scalar,
         values,
+        bound: attr.bound.map(SpanContainer::into_inner),
         has_ignored_variants,
     };
 
@@ -17,7 +17,7 @@ use syn::{
 use crate::common::{
     deprecation, filter_attrs,
     parse::{
-        attr::{err, OptionExt as _},
+        attr::{err, parse_bound, OptionExt as _},
         ParseBufferExt as _,
     },
     rename, scalar, Description, SpanContainer,
@@ -75,6 +75,15 @@ struct ContainerAttr {
     /// [1]: https://spec.graphql.org/October2021#EnumValuesDefinition
     rename_values: Option<SpanContainer<rename::Policy>>,
 
+    /// Explicitly specified bounds of the generated implementations for this
+    /// [GraphQL enum][0].
+    ///
+    /// If [`Some`], then these [`syn::WherePredicate`]s are added to the
+    /// generated implementations instead of the auto-inferred `Self: Sync` one.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Enums
+    bound: Option<SpanContainer<Vec<syn::WherePredicate>>>,
+
     /// Indicator whether the generated code is intended to be used only inside
     /// the [`juniper`] library.
     is_internal: bool,
@@ -129,6 +138,12 @@ impl Parse for ContainerAttr {
                         ))
                         .none_or_else(|_| err::dup_arg(&ident))?;
                 }
+                "bound" => {
+                    input.parse::<token::Eq>()?;
+                    out.bound
+                        .replace(parse_bound(&ident, input)?)
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "internal" => {
                     out.is_internal = true;
                 }
@@ -152,6 +167,7 @@ impl ContainerAttr {
             context: try_merge_opt!(context: self, another),
             scalar: try_merge_opt!(scalar: self, another),
             rename_values: try_merge_opt!(rename_values: self, another),
+            bound: try_merge_opt!(bound: self, another),
             is_internal: self.is_internal || another.is_internal,
         })
     }
@@ -370,6 +386,12 @@ struct Definition {
     /// [1]: https://spec.graphql.org/October2021#EnumValuesDefinition
     values: Vec<ValueDefinition>,
 
+    /// Explicitly specified bounds of the generated implementations for this
+    /// [GraphQL enum][0], replacing the auto-inferred ones.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Enums
+    bound: Option<Vec<syn::WherePredicate>>,
+
     /// Indicates whether the Rust enum behind this [GraphQL enum][0] contains
     /// ignored variants.
     ///
@@ -708,29 +730,37 @@ impl Definition {
         if let Some(bound) = scalar.bounds() {
             generics.make_where_clause().predicates.push(bound);
         }
+        if let Some(bound) = &self.bound {
+            generics
+                .make_where_clause()
+                .predicates
+                .extend(bound.iter().cloned());
+        }
 
         if for_async {
-            let self_ty = if self.generics.lifetimes().next().is_some() {
-                // Modify lifetime names to omit "lifetime name `'a` shadows a
-                // lifetime name that is already in scope" error.
-                let mut generics = self.generics.clone();
-                for lt in generics.lifetimes_mut() {
-                    let ident = lt.lifetime.ident.unraw();
-                    lt.lifetime.ident = format_ident!("__fa__{ident}");
-                }
+            if self.bound.is_none() {
+                let self_ty = if self.generics.lifetimes().next().is_some() {
+                    // Modify lifetime names to omit "lifetime name `'a` shadows a
+                    // lifetime name that is already in scope" error.
+                    let mut generics = self.generics.clone();
+                    for lt in generics.lifetimes_mut() {
+                        let ident = lt.lifetime.ident.unraw();
+                        lt.lifetime.ident = format_ident!("__fa__{ident}");
+                    }
 
-                let lifetimes = generics.lifetimes().map(|lt| &lt.lifetime);
-                let ident = &self.ident;
-                let (_, ty_generics, _) = generics.split_for_impl();
+                    let lifetimes = generics.lifetimes().map(|lt| &lt.lifetime);
+                    let ident = &self.ident;
+                    let (_, ty_generics, _) = generics.split_for_impl();
 
-                quote! { for<#( #lifetimes ),*> #ident #ty_generics }
-            } else {
-                quote! { Self }
-            };
-            generics
-                .make_where_clause()
-                .predicates
-                .push(parse_quote! { #self_ty: Sync });
+                    quote! { for<#( #lifetimes ),*> #ident #ty_generics }
+                } else {
+                    quote! { Self }
+                };
+                generics
+                    .make_where_clause()
+                    .predicates
+                    .push(parse_quote! { #self_ty: Sync });
+            }
 
             if scalar.is_generic() {
                 generics
@@ -36,6 +36,20 @@ pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
         .filter_map(|f| parse_field(f, renaming, is_internal))
         .collect::<Vec<_>>();
 
+    if attr.one_of.is_some() {
+        for f in fields.iter().filter(|f| !f.ignored) {
+            if f.default.is_some() {
+                ERR.emit_custom(f.ident.span(), "`@oneOf` field cannot have a default value");
+            }
+            if !is_option(&f.ty) {
+                ERR.emit_custom(
+                    f.ty.span(),
+                    "`@oneOf` field expected to be of `Option` type",
+                );
+            }
+        }
+    }
+
     proc_macro_error::abort_if_dirty();
 
     if !fields.iter().any(|f| !f.ignored) {
@@ -49,6 +63,31 @@ pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
             "expected all fields to have unique names",
         ));
     }
+    for f in &fields {
+        for alias in &f.aliases {
+            if let Some(other) = fields.iter().find(|o| o.name == *alias) {
+                ERR.emit_custom(
+                    f.ident.span(),
+                    format!(
+                        "alias `{alias}` collides with the name of `{}` field",
+                        other.ident
+                    ),
+                );
+            } else if let Some(other) = fields
+                .iter()
+                .find(|o| o.ident != f.ident && o.aliases.contains(alias))
+            {
+                ERR.emit_custom(
+                    f.ident.span(),
+                    format!(
+                        "alias `{alias}` collides with an alias of `{}` field",
+                        other.ident
+                    ),
+                );
+            }
+        }
+    }
+    proc_macro_error::abort_if_dirty();
 
     let name = attr
         .name
@@ -81,6 +120,8 @@ pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
         context,
         scalar,
         fields,
+        validate_with: attr.validate_with.map(SpanContainer::into_inner),
+        is_one_of: attr.one_of.is_some(),
     };
 
     Ok(definition.into_token_stream())
@@ -111,16 +152,50 @@ fn parse_field(
         ERR.no_double_underscore(f.span());
     }
 
+    let aliases = field_attr
+        .aliases
+        .into_iter()
+        .map(|alias| {
+            if !is_internal && alias.starts_with("__") {
+                ERR.no_double_underscore(alias.span_ident());
+            }
+            alias.into_inner().into_boxed_str()
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(deprecated) = &field_attr.deprecated {
+        if field_attr.ignore.is_none() && field_attr.default.is_none() && !is_option(&f.ty) {
+            ERR.emit_custom(
+                deprecated.span_ident(),
+                "required field (non-`Option` and without a `default` value) cannot be deprecated",
+            );
+        }
+    }
+
     Some(FieldDefinition {
         ident: ident.clone(),
         ty: f.ty.clone(),
         default: field_attr.default.map(SpanContainer::into_inner),
         name,
+        aliases,
         description: field_attr.description.map(SpanContainer::into_inner),
+        deprecated: field_attr.deprecated.map(SpanContainer::into_inner),
         ignored: field_attr.ignore.is_some(),
     })
 }
 
+/// Checks whether the provided [`syn::Type`] is an [`Option`].
+fn is_option(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(syn::TypePath { path, .. }) => {
+            path.segments.last().map_or(false, |s| s.ident == "Option")
+        }
+        syn::Type::Group(syn::TypeGroup { elem, .. })
+        | syn::Type::Paren(syn::TypeParen { elem, .. }) => is_option(elem),
+        _ => false,
+    }
+}
+
 /// Emits "expected named struct field" [`syn::Error`] pointing to the given
 /// `span`.
 pub(crate) fn err_unnamed_field<T, S: Spanned>(span: &S) -> Option<T> {
@@ -4,6 +4,8 @@
 
 pub(crate) mod derive;
 
+use std::mem;
+
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
 use syn::{
@@ -15,7 +17,7 @@ use syn::{
 };
 
 use crate::common::{
-    default, filter_attrs,
+    default, deprecation, filter_attrs,
     parse::{
         attr::{err, OptionExt as _},
         ParseBufferExt as _,
@@ -75,6 +77,19 @@ struct ContainerAttr {
     /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
     rename_fields: Option<SpanContainer<rename::Policy>>,
 
+    /// Explicitly specified function validating this [GraphQL input object][0]
+    /// once all its fields are coerced.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
+    validate_with: Option<SpanContainer<syn::ExprPath>>,
+
+    /// Explicitly specified marker of this [GraphQL input object][0] being a
+    /// [`@oneOf`][1] one.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
+    /// [1]: https://spec.graphql.org/draft#sec-OneOf-Input-Objects
+    one_of: Option<SpanContainer<syn::Ident>>,
+
     /// Indicator whether the generated code is intended to be used only inside
     /// the [`juniper`] library.
     is_internal: bool,
@@ -129,6 +144,17 @@ impl Parse for ContainerAttr {
                         ))
                         .none_or_else(|_| err::dup_arg(&ident))?;
                 }
+                "validate_with" => {
+                    input.parse::<token::Eq>()?;
+                    let path = input.parse::<syn::ExprPath>()?;
+                    out.validate_with
+                        .replace(SpanContainer::new(ident.span(), Some(path.span()), path))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "one_of" => out
+                    .one_of
+                    .replace(SpanContainer::new(ident.span(), None, ident.clone()))
+                    .none_or_else(|_| err::dup_arg(&ident))?,
                 "internal" => {
                     out.is_internal = true;
                 }
@@ -152,6 +178,8 @@ impl ContainerAttr {
             context: try_merge_opt!(context: self, another),
             scalar: try_merge_opt!(scalar: self, another),
             rename_fields: try_merge_opt!(rename_fields: self, another),
+            validate_with: try_merge_opt!(validate_with: self, another),
+            one_of: try_merge_opt!(one_of: self, another),
             is_internal: self.is_internal || another.is_internal,
         })
     }
@@ -185,6 +213,14 @@ struct FieldAttr {
     /// [1]: https://spec.graphql.org/October2021#InputValueDefinition
     name: Option<SpanContainer<String>>,
 
+    /// Explicitly specified aliases of this [GraphQL input object field][1],
+    /// which it's accepted under as well, besides its name.
+    ///
+    /// Aliases are not exposed via introspection.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#InputValueDefinition
+    aliases: Vec<SpanContainer<String>>,
+
     /// Explicitly specified [default value][2] of this
     /// [GraphQL input object field][1] to be used used in case a field value is
     /// not provided.
@@ -205,6 +241,16 @@ struct FieldAttr {
     /// [2]: https://spec.graphql.org/October2021#sec-Descriptions
     description: Option<SpanContainer<Description>>,
 
+    /// Explicitly specified [deprecation][2] of this
+    /// [GraphQL input object field][1].
+    ///
+    /// If [`None`], then Rust `#[deprecated]` attribute will be used as the
+    /// [deprecation][2], if any.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#InputValueDefinition
+    /// [2]: https://spec.graphql.org/October2021#sec--deprecated
+    deprecated: Option<SpanContainer<deprecation::Directive>>,
+
     /// Explicitly specified marker for the Rust struct field to be ignored and
     /// not included into the code generated for a [GraphQL input object][0]
     /// implementation.
@@ -234,6 +280,18 @@ impl Parse for FieldAttr {
                         ))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "alias" => {
+                    input.parse::<token::Eq>()?;
+                    let alias = input.parse::<syn::LitStr>()?;
+                    if out.aliases.iter().any(|a| **a == alias.value()) {
+                        return Err(err::dup_arg(&alias));
+                    }
+                    out.aliases.push(SpanContainer::new(
+                        ident.span(),
+                        Some(alias.span()),
+                        alias.value(),
+                    ));
+                }
                 "default" => {
                     let val = input.parse::<default::Value>()?;
                     out.default
@@ -247,6 +305,16 @@ impl Parse for FieldAttr {
                         .replace(SpanContainer::new(ident.span(), Some(desc.span()), desc))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "deprecated" => {
+                    let directive = input.parse::<deprecation::Directive>()?;
+                    out.deprecated
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            directive.reason.as_ref().map(|r| r.span()),
+                            directive,
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "ignore" | "skip" => out
                     .ignore
                     .replace(SpanContainer::new(ident.span(), None, ident.clone()))
@@ -265,10 +333,19 @@ impl FieldAttr {
     /// Tries to merge two [`FieldAttr`]s into a single one, reporting about
     /// duplicates, if any.
     fn try_merge(self, mut another: Self) -> syn::Result<Self> {
+        let mut aliases = self.aliases;
+        for alias in mem::take(&mut another.aliases) {
+            if aliases.contains(&alias) {
+                return Err(err::dup_arg(alias.span_ident()));
+            }
+            aliases.push(alias);
+        }
         Ok(Self {
             name: try_merge_opt!(name: self, another),
+            aliases,
             default: try_merge_opt!(default: self, another),
             description: try_merge_opt!(description: self, another),
+            deprecated: try_merge_opt!(deprecated: self, another),
             ignore: try_merge_opt!(ignore: self, another),
         })
     }
@@ -284,6 +361,10 @@ impl FieldAttr {
             attr.description = Description::parse_from_doc_attrs(attrs)?;
         }
 
+        if attr.deprecated.is_none() {
+            attr.deprecated = deprecation::Directive::parse_from_deprecated_attr(attrs)?;
+        }
+
         Ok(attr)
     }
 }
@@ -319,6 +400,13 @@ struct FieldDefinition {
     /// [1]: https://spec.graphql.org/October2021#InputValueDefinition
     name: Box<str>,
 
+    /// Aliases of this [GraphQL input object field][1], which it's accepted
+    /// under as well, besides its [`name`].
+    ///
+    /// [`name`]: Self::name
+    /// [1]: https://spec.graphql.org/October2021#InputValueDefinition
+    aliases: Vec<Box<str>>,
+
     /// [Description][2] of this [GraphQL input object field][1] to put into
     /// GraphQL schema.
     ///
@@ -326,6 +414,13 @@ struct FieldDefinition {
     /// [2]: https://spec.graphql.org/October2021#sec-Descriptions
     description: Option<Description>,
 
+    /// [Deprecation][2] of this [GraphQL input object field][1] to put into
+    /// GraphQL schema.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#InputValueDefinition
+    /// [2]: https://spec.graphql.org/October2021#sec--deprecated
+    deprecated: Option<deprecation::Directive>,
+
     /// Indicator whether the Rust struct field behinds this
     /// [GraphQL input object field][1] is being ignored and should not be
     /// included into the generated code.
@@ -388,6 +483,21 @@ struct Definition {
     /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
     /// [1]: https://spec.graphql.org/October2021#InputFieldsDefinition
     fields: Vec<FieldDefinition>,
+
+    /// Function validating this [GraphQL input object][0] once all its
+    /// [fields][1] are coerced, if any.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
+    /// [1]: https://spec.graphql.org/October2021#InputFieldsDefinition
+    validate_with: Option<syn::ExprPath>,
+
+    /// Indicator whether this [GraphQL input object][0] is a [`@oneOf`][1]
+    /// one, requiring exactly one of its [fields][2] to be provided.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
+    /// [1]: https://spec.graphql.org/draft#sec-OneOf-Input-Objects
+    /// [2]: https://spec.graphql.org/October2021#InputFieldsDefinition
+    is_one_of: bool,
 }
 
 impl ToTokens for Definition {
@@ -456,6 +566,7 @@ impl Definition {
         let (_, ty_generics, _) = self.generics.split_for_impl();
 
         let description = &self.description;
+        let one_of = self.is_one_of.then(|| quote! { .one_of() });
 
         let fields = self.fields.iter().filter_map(|f| {
             let ty = &f.ty;
@@ -468,8 +579,10 @@ impl Definition {
                     quote! { .arg::<#ty>(#name, info) }
                 };
                 let description = &f.description;
+                let deprecated = &f.deprecated;
+                let aliases = &f.aliases;
 
-                quote! { registry #arg #description }
+                quote! { registry #arg #description #deprecated #( .alias(#aliases) )* }
             })
         });
 
@@ -494,6 +607,7 @@ impl Definition {
                     registry
                         .build_input_object_type::<#ident #ty_generics>(info, &fields)
                         #description
+                        #one_of
                         .into_meta()
                 }
             }
@@ -592,13 +706,38 @@ impl Definition {
                     |expr| quote! { #expr },
                 );
 
-                quote! {
-                    match obj.get(#name) {
-                        Some(v) => {
-                            ::juniper::FromInputValue::<#scalar>::from_input_value(v)
-                                .map_err(::juniper::IntoFieldError::into_field_error)?
+                if f.aliases.is_empty() {
+                    quote! {
+                        match obj.get(#name) {
+                            Some(v) => {
+                                ::juniper::FromInputValue::<#scalar>::from_input_value(v)
+                                    .map_err(::juniper::IntoFieldError::into_field_error)?
+                            }
+                            None => { #fallback }
+                        }
+                    }
+                } else {
+                    let aliases = &f.aliases;
+
+                    quote! {
+                        let mut provided = [#name, #( #aliases ),*]
+                            .iter()
+                            .filter_map(|n| obj.get(*n).map(|v| (*n, v)));
+                        match (provided.next(), provided.next()) {
+                            (Some((_, v)), None) => {
+                                ::juniper::FromInputValue::<#scalar>::from_input_value(v)
+                                    .map_err(::juniper::IntoFieldError::into_field_error)?
+                            }
+                            (Some((n, _)), Some((alias, _))) => {
+                                return Err(::juniper::FieldError::<#scalar>::from(
+                                    ::std::format!(
+                                        "Field `{}` may not be provided along with its alias `{}`",
+                                        n, alias,
+                                    ),
+                                ));
+                            }
+                            (None, _) => { #fallback }
                         }
-                        None => { #fallback }
                     }
                 }
             };
@@ -606,7 +745,36 @@ impl Definition {
             quote! { #ident: { #construct }, }
         });
 
+        let check_one_of = self.is_one_of.then(|| {
+            let names = self
+                .fields
+                .iter()
+                .filter(|f| !f.ignored)
+                .flat_map(|f| std::iter::once(&f.name).chain(&f.aliases));
+            let err_msg = format!(
+                "Exactly one non-null field must be provided for `@oneOf` input object `{}`",
+                self.name,
+            );
+
+            quote! {
+                let provided = obj
+                    .iter()
+                    .filter(|(k, v)| [#( #names ),*].contains(k) && !v.is_null())
+                    .count();
+                if obj.len() != 1 || provided != 1 {
+                    return Err(::juniper::FieldError::<#scalar>::from(#err_msg));
+                }
+            }
+        });
+
+        let validate = self.validate_with.as_ref().map(|validate| {
+            quote! {
+                #validate(&input).map_err(::juniper::FieldError::<#scalar>::from)?;
+            }
+        });
+
         quote! {
+            #[allow(deprecated)]
             #[automatically_derived]
             impl #impl_generics ::juniper::FromInputValue<#scalar>
                 for #ident #ty_generics
@@ -622,10 +790,13 @@ impl Definition {
                         .ok_or_else(|| ::juniper::FieldError::<#scalar>::from(
                             ::std::format!("Expected input object, found: {}", value))
                         )?;
+                    #check_one_of
 
-                    Ok(#ident {
+                    let input = #ident {
                         #( #fields )*
-                    })
+                    };
+                    #validate
+                    Ok(input)
                 }
             }
         }
@@ -656,7 +827,13 @@ impl Definition {
             })
         });
 
+        // `@oneOf` input object has only the provided field represented.
+        let skip_nulls = self
+            .is_one_of
+            .then(|| quote! { .filter(|(_, v)| !v.is_null()) });
+
         quote! {
+            #[allow(deprecated)]
             #[automatically_derived]
             impl #impl_generics ::juniper::ToInputValue<#scalar>
                 for #ident #ty_generics
@@ -666,6 +843,7 @@ impl Definition {
                     ::juniper::InputValue::object(
                         #[allow(deprecated)]
                         ::std::array::IntoIter::new([#( #fields ),*])
+                            #skip_nulls
                             .collect()
                     )
                 }
@@ -62,7 +62,28 @@ fn expand_on_trait(
         );
     }
 
-    let scalar = scalar::Type::parse(attr.scalar.as_deref(), &ast.generics);
+    let mut scalar = scalar::Type::parse(attr.scalar.as_deref(), &ast.generics);
+    if let Some(dyn_arg) = &attr.r#dyn {
+        if !ast.generics.params.is_empty() {
+            ERR.emit_custom(
+                dyn_arg.span_ident(),
+                "`dyn` argument is not applicable to generic traits",
+            );
+        }
+        // Trait objects can be resolved with a single `ScalarValue` only.
+        match &scalar {
+            scalar::Type::ImplicitGeneric(None) => {
+                scalar = scalar::Type::Concrete(parse_quote! { ::juniper::DefaultScalarValue });
+            }
+            scalar::Type::ImplicitGeneric(Some(_)) | scalar::Type::ExplicitGeneric(_) => {
+                ERR.emit_custom(
+                    dyn_arg.span_ident(),
+                    "`dyn` argument requires a concrete `scalar` type",
+                );
+            }
+            scalar::Type::Concrete(_) => {}
+        }
+    }
 
     proc_macro_error::abort_if_dirty();
 
@@ -109,7 +130,17 @@ fn expand_on_trait(
         })
         .unwrap_or_else(|| parse_quote! { () });
 
-    let (enum_ident, enum_alias_ident) = enum_idents(trait_ident, attr.r#enum.as_deref());
+    let trait_object = attr.r#dyn.is_some().then(|| trait_ident.clone());
+    if trait_object.is_some() {
+        ast.supertraits.push(parse_quote! {
+            ::juniper::macros::helper::AsDynGraphQLValue<
+                #scalar, Context = #context, TypeInfo = (),
+            >
+        });
+        ast.colon_token.get_or_insert_with(Default::default);
+    }
+
+    let (enum_ident, enum_alias_ident) = enum_idents(&ast.ident, attr.r#enum.as_deref());
 
     let generated_code = Definition {
         generics: ast.generics.clone(),
@@ -132,7 +163,8 @@ fn expand_on_trait(
             .map(SpanContainer::into_inner)
             .collect(),
         suppress_dead_code: None,
-        src_intra_doc_link: format!("trait@{trait_ident}").into_boxed_str(),
+        src_intra_doc_link: format!("trait@{}", ast.ident).into_boxed_str(),
+        trait_object,
     };
 
     Ok(quote! {
@@ -149,7 +181,7 @@ fn parse_trait_method(
     method: &mut syn::TraitItemMethod,
     renaming: &rename::Policy,
 ) -> Option<field::Definition> {
-    let method_ident = &method.sig.ident;
+    let method_ident = method.sig.ident.clone();
     let method_attrs = method.attrs.clone();
 
     // Remove repeated attributes from the method, to omit incorrect expansion.
@@ -199,6 +231,13 @@ fn parse_trait_method(
         syn::ReturnType::Default => parse_quote! { () },
         syn::ReturnType::Type(_, ty) => ty.unparenthesized().clone(),
     };
+
+    let has_receiver = method.sig.receiver().is_some();
+    let is_async = method.sig.asyncness.is_some();
+    if is_async {
+        desugar_async_method(&mut method.sig, &ty);
+    }
+
     ty.lifetimes_anonymized();
 
     Some(field::Definition {
@@ -206,13 +245,36 @@ fn parse_trait_method(
         ty,
         description: attr.description.map(SpanContainer::into_inner),
         deprecated: attr.deprecated.map(SpanContainer::into_inner),
-        ident: method_ident.clone(),
+        complexity: attr.complexity.map(SpanContainer::into_inner),
+        complexity_with: attr.complexity_with.map(SpanContainer::into_inner),
+        ident: method_ident,
         arguments: Some(arguments),
-        has_receiver: method.sig.receiver().is_some(),
-        is_async: method.sig.asyncness.is_some(),
+        has_receiver,
+        is_async,
     })
 }
 
+/// Desugars the given `async fn` trait method signature into a regular `fn`
+/// returning a [`BoxFuture`] of its `output` type.
+///
+/// This keeps the trait compatible with Rust versions not supporting `async fn`
+/// in traits, and [object safe][1] (so it may be used with the `dyn`
+/// attribute's argument). The returned future borrows the receiver, if any,
+/// and so, is `'static` for the methods without a receiver.
+///
+/// [`BoxFuture`]: juniper::BoxFuture
+/// [1]: https://doc.rust-lang.org/stable/reference/items/traits.html#object-safety
+fn desugar_async_method(sig: &mut syn::Signature, output: &syn::Type) {
+    let lifetime: syn::Lifetime = if sig.receiver().is_some() {
+        parse_quote! { '_ }
+    } else {
+        parse_quote! { 'static }
+    };
+
+    sig.asyncness = None;
+    sig.output = parse_quote! { -> ::juniper::BoxFuture<#lifetime, #output> };
+}
+
 /// Expands `#[graphql_interface]` macro placed on the given struct.
 fn expand_on_derive_input(
     attrs: Vec<syn::Attribute>,
@@ -220,6 +282,13 @@ fn expand_on_derive_input(
 ) -> syn::Result<TokenStream> {
     let attr = Attr::from_attrs("graphql_interface", &attrs)?;
 
+    if let Some(dyn_arg) = &attr.r#dyn {
+        return Err(ERR.custom_error(
+            dyn_arg.span_ident(),
+            "`dyn` argument is applicable to trait definitions only",
+        ));
+    }
+
     let struct_ident = &ast.ident;
     let struct_span = ast.span();
 
@@ -314,6 +383,7 @@ fn expand_on_derive_input(
             .collect(),
         suppress_dead_code: None,
         src_intra_doc_link: format!("struct@{struct_ident}").into_boxed_str(),
+        trait_object: None,
     };
 
     Ok(quote! {
@@ -371,6 +441,8 @@ fn parse_struct_field(
         ty,
         description: attr.description.map(SpanContainer::into_inner),
         deprecated: attr.deprecated.map(SpanContainer::into_inner),
+        complexity: attr.complexity.map(SpanContainer::into_inner),
+        complexity_with: attr.complexity_with.map(SpanContainer::into_inner),
         ident: field_ident.clone(),
         arguments: None,
         has_receiver: false,
@@ -22,6 +22,13 @@ pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
         return Err(ERR.custom_error(ast.span(), "can only be derived on structs"));
     };
 
+    if let Some(dyn_arg) = &attr.r#dyn {
+        return Err(ERR.custom_error(
+            dyn_arg.span_ident(),
+            "`dyn` argument is applicable to `#[graphql_interface]` on traits only",
+        ));
+    }
+
     let struct_ident = &ast.ident;
     let struct_span = ast.span();
 
@@ -106,6 +113,7 @@ pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
             .collect(),
         suppress_dead_code: Some((ast.ident.clone(), data.fields.clone())),
         src_intra_doc_link: format!("struct@{struct_ident}").into_boxed_str(),
+        trait_object: None,
     }
     .into_token_stream())
 }
@@ -148,6 +156,8 @@ fn parse_field(field: &syn::Field, renaming: &rename::Policy) -> Option<field::D
         ty,
         description: attr.description.map(SpanContainer::into_inner),
         deprecated: attr.deprecated.map(SpanContainer::into_inner),
+        complexity: attr.complexity.map(SpanContainer::into_inner),
+        complexity_with: attr.complexity_with.map(SpanContainer::into_inner),
         ident: field_ident.clone(),
         arguments: None,
         has_receiver: false,
@@ -132,6 +132,12 @@ struct Attr {
     /// [1]: https://spec.graphql.org/October2021#sec-Interfaces
     rename_fields: Option<SpanContainer<rename::Policy>>,
 
+    /// Explicitly specified marker indicating that the [GraphQL interface][1]
+    /// type should be resolvable from trait objects of the Rust trait.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Interfaces
+    r#dyn: Option<SpanContainer<syn::Ident>>,
+
     /// Indicator whether the generated code is intended to be used only inside
     /// the [`juniper`] library.
     is_internal: bool,
@@ -212,6 +218,12 @@ impl Parse for Attr {
                         .replace(SpanContainer::new(span, Some(span), ident))
                         .none_or_else(|_| err::dup_arg(span))?;
                 }
+                "dyn" => {
+                    let span = ident.span();
+                    out.r#dyn
+                        .replace(SpanContainer::new(span, Some(span), ident))
+                        .none_or_else(|_| err::dup_arg(span))?;
+                }
                 "rename_all" => {
                     input.parse::<token::Eq>()?;
                     let val = input.parse::<syn::LitStr>()?;
@@ -250,6 +262,7 @@ impl Attr {
             r#enum: try_merge_opt!(r#enum: self, another),
             asyncness: try_merge_opt!(asyncness: self, another),
             rename_fields: try_merge_opt!(rename_fields: self, another),
+            r#dyn: try_merge_opt!(r#dyn: self, another),
             is_internal: self.is_internal || another.is_internal,
         })
     }
@@ -354,6 +367,12 @@ struct Definition {
     ///
     /// [0]: https://spec.graphql.org/October2021#sec-Interfaces
     src_intra_doc_link: Box<str>,
+
+    /// Identifier of the Rust trait describing this [GraphQL interface][0],
+    /// if it should be resolvable from the trait objects of this trait too.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Interfaces
+    trait_object: Option<syn::Ident>,
 }
 
 impl ToTokens for Definition {
@@ -368,6 +387,7 @@ impl ToTokens for Definition {
         self.impl_field_meta_tokens().to_tokens(into);
         self.impl_field_tokens().to_tokens(into);
         self.impl_async_field_tokens().to_tokens(into);
+        self.impl_trait_object_tokens().to_tokens(into);
     }
 }
 
@@ -485,6 +505,32 @@ impl Definition {
                 }
             });
 
+        let downcast_fns = self
+            .implemented_for
+            .iter()
+            .zip(variants_idents.clone())
+            .map(|(ty, ident)| {
+                let fn_ident =
+                    format_ident!("as_{}", rename::to_snake_case(&ident.unraw().to_string()));
+                let doc = format!(
+                    "Returns this opaque value of `{}` [GraphQL interface][0] as \
+                     its `{ident}` implementer, if it's the one.\
+                     \n\n\
+                     [0]: https://spec.graphql.org/October2021#sec-Interfaces",
+                    self.name,
+                );
+                quote! {
+                    #[allow(unreachable_patterns)]
+                    #[doc = #doc]
+                    #vis fn #fn_ident(&self) -> ::std::option::Option<&#ty> {
+                        match self {
+                            Self::#ident(v) => ::std::option::Option::Some(v),
+                            _ => ::std::option::Option::None,
+                        }
+                    }
+                }
+            });
+
         quote! {
             #[automatically_derived]
             #[derive(Clone, Copy, Debug)]
@@ -500,6 +546,14 @@ impl Definition {
                 #enum_ident<#( #enum_to_alias_gens ),*>;
 
             #( #from_impls )*
+
+            #[allow(dead_code)]
+            #[automatically_derived]
+            impl #interface_impl_gens #alias_ident #interface_ty_gens
+                #interface_where_clause
+            {
+                #( #downcast_fns )*
+            }
         }
     }
 
@@ -979,7 +1033,7 @@ impl Definition {
                     .iter()
                     .flat_map(|vec| vec.iter())
                     .filter_map(|arg| match arg {
-                        field::MethodArgument::Regular(arg) => Some((&arg.ty, &arg.name)),
+                        field::MethodArgument::Regular(arg) => Some((arg.schema_ty(), &arg.name)),
                         _ => None,
                     })
                     .unzip();
@@ -1174,6 +1228,173 @@ impl Definition {
             .collect()
     }
 
+    /// Returns generated code implementing [`GraphQLType`], [`GraphQLValue`],
+    /// [`GraphQLValueAsync`] and marker and reflection traits for the trait
+    /// objects of this [GraphQL interface][1], if it's resolvable from them.
+    ///
+    /// All the resolving is delegated to the concrete type behind the trait
+    /// object via its [`AsDynGraphQLValue`] supertrait, while the schema
+    /// definition is the same as the one of the generated enum.
+    ///
+    /// [`AsDynGraphQLValue`]: juniper::macros::helper::AsDynGraphQLValue
+    /// [`GraphQLType`]: juniper::GraphQLType
+    /// [`GraphQLValue`]: juniper::GraphQLValue
+    /// [`GraphQLValueAsync`]: juniper::GraphQLValueAsync
+    /// [1]: https://spec.graphql.org/October2021#sec-Interfaces
+    #[must_use]
+    fn impl_trait_object_tokens(&self) -> Option<TokenStream> {
+        let trait_ident = self.trait_object.as_ref()?;
+        let ty = &self.enum_alias_ident;
+        let context = &self.context;
+        let scalar = &self.scalar;
+
+        let dyn_ty = quote! { dyn #trait_ident + '__obj + Send + Sync };
+        let as_dyn = quote! {
+            <Self as ::juniper::macros::helper::AsDynGraphQLValue<#scalar>>
+                ::as_dyn_graphql_value(self)
+        };
+
+        Some(quote! {
+            #[automatically_derived]
+            impl<'__obj> ::juniper::marker::GraphQLInterface<#scalar> for #dyn_ty {
+                fn mark() {
+                    <#ty as ::juniper::marker::GraphQLInterface<#scalar>>::mark()
+                }
+            }
+
+            #[automatically_derived]
+            impl<'__obj> ::juniper::marker::IsOutputType<#scalar> for #dyn_ty {
+                fn mark() {
+                    <#ty as ::juniper::marker::IsOutputType<#scalar>>::mark()
+                }
+            }
+
+            #[automatically_derived]
+            impl<'__obj> ::juniper::GraphQLType<#scalar> for #dyn_ty {
+                fn name(info: &Self::TypeInfo) -> Option<&str> {
+                    <#ty as ::juniper::GraphQLType<#scalar>>::name(info)
+                }
+
+                fn meta<'r>(
+                    info: &Self::TypeInfo,
+                    registry: &mut ::juniper::Registry<'r, #scalar>
+                ) -> ::juniper::meta::MetaType<'r, #scalar>
+                where #scalar: 'r,
+                {
+                    <#ty as ::juniper::GraphQLType<#scalar>>::meta(info, registry)
+                }
+            }
+
+            #[allow(deprecated)]
+            #[automatically_derived]
+            impl<'__obj> ::juniper::GraphQLValue<#scalar> for #dyn_ty {
+                type Context = #context;
+                type TypeInfo = ();
+
+                fn type_name<'__i>(&self, info: &'__i Self::TypeInfo) -> Option<&'__i str> {
+                    <Self as ::juniper::GraphQLType<#scalar>>::name(info)
+                }
+
+                fn resolve_field(
+                    &self,
+                    info: &Self::TypeInfo,
+                    field: &str,
+                    args: &::juniper::Arguments<'_, #scalar>,
+                    executor: &::juniper::Executor<'_, '_, Self::Context, #scalar>,
+                ) -> ::juniper::ExecutionResult<#scalar> {
+                    ::juniper::GraphQLValue::resolve_field(
+                        #as_dyn,
+                        info,
+                        field,
+                        args,
+                        executor,
+                    )
+                }
+
+                fn concrete_type_name(
+                    &self,
+                    context: &Self::Context,
+                    info: &Self::TypeInfo,
+                ) -> String {
+                    ::juniper::GraphQLValue::concrete_type_name(
+                        #as_dyn,
+                        context,
+                        info,
+                    )
+                }
+
+                fn resolve_into_type(
+                    &self,
+                    info: &Self::TypeInfo,
+                    type_name: &str,
+                    selection_set: Option<&[::juniper::Selection<'_, #scalar>]>,
+                    executor: &::juniper::Executor<'_, '_, Self::Context, #scalar>,
+                ) -> ::juniper::ExecutionResult<#scalar> {
+                    ::juniper::GraphQLValue::resolve_into_type(
+                        #as_dyn,
+                        info,
+                        type_name,
+                        selection_set,
+                        executor,
+                    )
+                }
+            }
+
+            #[allow(deprecated)]
+            #[automatically_derived]
+            impl<'__obj> ::juniper::GraphQLValueAsync<#scalar> for #dyn_ty {
+                fn resolve_field_async<'b>(
+                    &'b self,
+                    info: &'b Self::TypeInfo,
+                    field: &'b str,
+                    args: &'b ::juniper::Arguments<'_, #scalar>,
+                    executor: &'b ::juniper::Executor<'_, '_, Self::Context, #scalar>,
+                ) -> ::juniper::BoxFuture<'b, ::juniper::ExecutionResult<#scalar>> {
+                    ::juniper::GraphQLValueAsync::resolve_field_async(
+                        #as_dyn,
+                        info,
+                        field,
+                        args,
+                        executor,
+                    )
+                }
+
+                fn resolve_into_type_async<'b>(
+                    &'b self,
+                    info: &'b Self::TypeInfo,
+                    type_name: &str,
+                    selection_set: Option<&'b [::juniper::Selection<'b, #scalar>]>,
+                    executor: &'b ::juniper::Executor<'b, 'b, Self::Context, #scalar>
+                ) -> ::juniper::BoxFuture<'b, ::juniper::ExecutionResult<#scalar>> {
+                    ::juniper::GraphQLValueAsync::resolve_into_type_async(
+                        #as_dyn,
+                        info,
+                        type_name,
+                        selection_set,
+                        executor,
+                    )
+                }
+            }
+
+            #[automatically_derived]
+            impl<'__obj> ::juniper::macros::reflect::BaseType<#scalar> for #dyn_ty {
+                const NAME: ::juniper::macros::reflect::Type =
+                    <#ty as ::juniper::macros::reflect::BaseType<#scalar>>::NAME;
+            }
+
+            #[automatically_derived]
+            impl<'__obj> ::juniper::macros::reflect::BaseSubTypes<#scalar> for #dyn_ty {
+                const NAMES: ::juniper::macros::reflect::Types =
+                    <#ty as ::juniper::macros::reflect::BaseSubTypes<#scalar>>::NAMES;
+            }
+
+            #[automatically_derived]
+            impl<'__obj> ::juniper::macros::reflect::WrappedType<#scalar> for #dyn_ty {
+                const VALUE: ::juniper::macros::reflect::WrappedValue = 1;
+            }
+        })
+    }
+
     /// Returns generated code for the [`GraphQLValue::concrete_type_name`][0]
     /// method, which returns name of the underlying [`implementers`][1] GraphQL
     /// type contained in this enum.
@@ -1,6 +1,6 @@
 //! Code generation for `#[graphql_object]` macro.
 
-use std::{any::TypeId, marker::PhantomData, mem};
+use std::{any::TypeId, iter, marker::PhantomData, mem};
 
 use proc_macro2::{Span, TokenStream};
 use quote::{quote, ToTokens};
@@ -61,7 +61,59 @@ where
         );
     }
 
-    let scalar = scalar::Type::parse(attr.scalar.as_deref(), &ast.generics);
+    if TypeId::of::<Operation>() != TypeId::of::<Query>() {
+        if let Some(policy) = &attr.resolve_policy {
+            ERR.emit_custom(
+                policy.span_ident(),
+                "`resolve` attribute argument is not applicable to subscriptions",
+            );
+        }
+        if let Some(typename_with) = &attr.typename_with {
+            ERR.emit_custom(
+                typename_with.span_ident(),
+                "`typename_with` attribute argument is not applicable to subscriptions",
+            );
+        }
+        if let Some(ext) = attr.extensions.iter().next() {
+            ERR.emit_custom(
+                ext.span_ident(),
+                "`extensions` attribute argument is not applicable to subscriptions",
+            );
+        }
+        if let Some(marker) = &attr.extension {
+            ERR.emit_custom(
+                marker.span_ident(),
+                "`extension` attribute argument is not applicable to subscriptions",
+            );
+        }
+    }
+
+    if attr.extension.is_some() {
+        let err_misplaced = |arg: &str, span| {
+            ERR.emit_custom(
+                span,
+                format!("`{arg}` attribute argument is not applicable to object extensions"),
+            )
+        };
+        if let Some(name) = &attr.name {
+            err_misplaced("name", name.span_ident());
+        }
+        if let Some(iface) = attr.interfaces.iter().next() {
+            err_misplaced("impl", iface.span_ident());
+        }
+        if let Some(ext) = attr.extensions.iter().next() {
+            err_misplaced("extensions", ext.span_ident());
+        }
+        if let Some(policy) = &attr.resolve_policy {
+            err_misplaced("resolve", policy.span_ident());
+        }
+        if let Some(typename_with) = &attr.typename_with {
+            err_misplaced("typename_with", typename_with.span_ident());
+        }
+        if let Some(bound) = &attr.bound {
+            err_misplaced("bound", bound.span_ident());
+        }
+    }
 
     proc_macro_error::abort_if_dirty();
 
@@ -86,7 +138,7 @@ where
 
     proc_macro_error::abort_if_dirty();
 
-    if fields.is_empty() {
+    if fields.is_empty() && attr.extensions.is_empty() {
         ERR.emit_custom(type_span, "must have at least one field");
     }
     if !field::all_different(&fields) {
@@ -110,6 +162,54 @@ where
         })
         .unwrap_or_else(|| parse_quote! { () });
 
+    let inferred_scalars = fields
+        .iter()
+        .flat_map(|f| {
+            let method_generics = ast.items.iter().find_map(|item| match item {
+                syn::ImplItem::Method(m) if m.sig.ident == f.ident => Some(&m.sig.generics),
+                _ => None,
+            });
+            let generics = iter::once(&ast.generics)
+                .chain(method_generics)
+                .collect::<Vec<_>>();
+            f.arguments
+                .iter()
+                .flatten()
+                .filter_map(field::MethodArgument::executor_ty)
+                .filter_map(move |ty| scalar::infer_from_executor(ty, &generics))
+        })
+        .collect::<Vec<_>>();
+    if attr.scalar.is_none() {
+        let first = inferred_scalars
+            .first()
+            .map(|ty| ty.to_token_stream().to_string());
+        if let Some(other) = inferred_scalars
+            .iter()
+            .find(|ty| Some(ty.to_token_stream().to_string()) != first)
+        {
+            ERR.custom(
+                other.span(),
+                "cannot infer its `ScalarValue` type, as `Executor` arguments \
+                 of its fields are parametrized with different ones",
+            )
+            .note(String::from(
+                "specify the `ScalarValue` type explicitly via \
+                 `#[graphql(scalar = ...)]` attribute argument",
+            ))
+            .emit();
+        }
+    }
+
+    proc_macro_error::abort_if_dirty();
+
+    let scalar = attr.scalar.as_deref().cloned().or_else(|| {
+        inferred_scalars
+            .into_iter()
+            .next()
+            .map(scalar::AttrValue::Concrete)
+    });
+    let scalar = scalar::Type::parse(scalar.as_ref(), &ast.generics);
+
     let generated_code = Definition::<Operation> {
         name,
         ty: ast.self_ty.unparenthesized().clone(),
@@ -123,6 +223,11 @@ where
             .iter()
             .map(|ty| ty.as_ref().clone())
             .collect(),
+        resolve_policy: attr.resolve_policy.as_deref().copied(),
+        typename_with: attr.typename_with.map(SpanContainer::into_inner),
+        bound: attr.bound.map(SpanContainer::into_inner),
+        extensions: super::sorted_extensions(&attr.extensions),
+        extension: attr.extension.map(SpanContainer::into_inner),
         _operation: PhantomData,
     };
 
@@ -217,6 +322,8 @@ fn parse_field(
         ty,
         description: attr.description.map(SpanContainer::into_inner),
         deprecated: attr.deprecated.map(SpanContainer::into_inner),
+        complexity: attr.complexity.map(SpanContainer::into_inner),
+        complexity_with: attr.complexity_with.map(SpanContainer::into_inner),
         ident: method_ident.clone(),
         arguments: Some(arguments),
         has_receiver: method.sig.receiver().is_some(),
@@ -52,6 +52,13 @@ fn expand_struct(ast: syn::DeriveInput) -> syn::Result<Definition<Query>> {
 
     let scalar = scalar::Type::parse(attr.scalar.as_deref(), &ast.generics);
 
+    if let Some(marker) = &attr.extension {
+        ERR.emit_custom(
+            marker.span_ident(),
+            "`extension` attribute argument is applicable to `impl` blocks only",
+        );
+    }
+
     proc_macro_error::abort_if_dirty();
 
     let renaming = attr
@@ -75,7 +82,7 @@ fn expand_struct(ast: syn::DeriveInput) -> syn::Result<Definition<Query>> {
 
     proc_macro_error::abort_if_dirty();
 
-    if fields.is_empty() {
+    if fields.is_empty() && attr.extensions.is_empty() {
         ERR.emit_custom(struct_span, "must have at least one field");
     }
     if !field::all_different(&fields) {
@@ -100,6 +107,11 @@ fn expand_struct(ast: syn::DeriveInput) -> syn::Result<Definition<Query>> {
             .iter()
             .map(|ty| ty.as_ref().clone())
             .collect(),
+        resolve_policy: attr.resolve_policy.as_deref().copied(),
+        typename_with: attr.typename_with.map(SpanContainer::into_inner),
+        bound: attr.bound.map(SpanContainer::into_inner),
+        extensions: super::sorted_extensions(&attr.extensions),
+        extension: None,
         _operation: PhantomData,
     })
 }
@@ -142,6 +154,8 @@ fn parse_field(field: &syn::Field, renaming: &rename::Policy) -> Option<field::D
         ty,
         description: attr.description.map(SpanContainer::into_inner),
         deprecated: attr.deprecated.map(SpanContainer::into_inner),
+        complexity: attr.complexity.map(SpanContainer::into_inner),
+        complexity_with: attr.complexity_with.map(SpanContainer::into_inner),
         ident: field_ident.clone(),
         arguments: None,
         has_receiver: false,
@@ -20,7 +20,7 @@ use syn::{
 use crate::common::{
     field, filter_attrs, gen,
     parse::{
-        attr::{err, OptionExt as _},
+        attr::{err, parse_bound, OptionExt as _},
         GenericsExt as _, ParseBufferExt as _, TypeExt,
     },
     rename, scalar, Description, SpanContainer,
@@ -87,6 +87,48 @@ pub(crate) struct Attr {
     /// [1]: https://spec.graphql.org/October2021#sec-Objects
     pub(crate) rename_fields: Option<SpanContainer<rename::Policy>>,
 
+    /// Explicitly specified [`ResolvePolicy`] of the fields of this
+    /// [GraphQL object][1] type.
+    ///
+    /// If [`None`], then the [`ResolvePolicy::Concurrent`] will be applied by
+    /// default.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    pub(crate) resolve_policy: Option<SpanContainer<ResolvePolicy>>,
+
+    /// Explicitly specified function overriding the `__typename` reported by
+    /// this [GraphQL object][1] type.
+    ///
+    /// If [`None`], then the name of this [GraphQL object][1] type is reported.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    pub(crate) typename_with: Option<SpanContainer<syn::ExprPath>>,
+
+    /// Explicitly specified bounds of the generated implementations for this
+    /// [GraphQL object][1] type.
+    ///
+    /// If [`Some`], then these [`syn::WherePredicate`]s are added to the
+    /// generated implementations instead of the auto-inferred `Self: Sync` one.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    pub(crate) bound: Option<SpanContainer<Vec<syn::WherePredicate>>>,
+
+    /// Explicitly specified [`ObjectExtension`]s defining additional fields of
+    /// this [GraphQL object][1] type.
+    ///
+    /// [`ObjectExtension`]: juniper::macros::reflect::ObjectExtension
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    pub(crate) extensions: HashSet<SpanContainer<syn::Type>>,
+
+    /// Explicitly specified name of the marker type to declare for the fields
+    /// of this `impl` block, making it an [`ObjectExtension`] of another
+    /// [GraphQL object][1] type, rather than a [GraphQL object][1] type on its
+    /// own.
+    ///
+    /// [`ObjectExtension`]: juniper::macros::reflect::ObjectExtension
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    pub(crate) extension: Option<SpanContainer<syn::Ident>>,
+
     /// Indicator whether the generated code is intended to be used only inside
     /// the [`juniper`] library.
     pub(crate) is_internal: bool,
@@ -153,6 +195,53 @@ impl Parse for Attr {
                         ))
                         .none_or_else(|_| err::dup_arg(&ident))?;
                 }
+                "resolve" => {
+                    input.parse::<token::Eq>()?;
+                    let val = input.parse::<syn::Ident>()?;
+                    out.resolve_policy
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(val.span()),
+                            val.try_into()?,
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?;
+                }
+                "typename_with" => {
+                    input.parse::<token::Eq>()?;
+                    let path = input.parse::<syn::ExprPath>()?;
+                    out.typename_with
+                        .replace(SpanContainer::new(ident.span(), Some(path.span()), path))
+                        .none_or_else(|_| err::dup_arg(&ident))?;
+                }
+                "bound" => {
+                    input.parse::<token::Eq>()?;
+                    out.bound
+                        .replace(parse_bound(&ident, input)?)
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "extensions" => {
+                    input.parse::<token::Eq>()?;
+                    for ext in input.parse_maybe_wrapped_and_punctuated::<
+                        syn::Type, token::Bracket, token::Comma,
+                    >()? {
+                        let ext_span = ext.span();
+                        out
+                            .extensions
+                            .replace(SpanContainer::new(ident.span(), Some(ext_span), ext))
+                            .none_or_else(|_| err::dup_arg(ext_span))?;
+                    }
+                }
+                "extension" => {
+                    input.parse::<token::Eq>()?;
+                    let marker = input.parse::<syn::Ident>()?;
+                    out.extension
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(marker.span()),
+                            marker,
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "internal" => {
                     out.is_internal = true;
                 }
@@ -177,6 +266,11 @@ impl Attr {
             scalar: try_merge_opt!(scalar: self, another),
             interfaces: try_merge_hashset!(interfaces: self, another => span_joined),
             rename_fields: try_merge_opt!(rename_fields: self, another),
+            resolve_policy: try_merge_opt!(resolve_policy: self, another),
+            typename_with: try_merge_opt!(typename_with: self, another),
+            bound: try_merge_opt!(bound: self, another),
+            extensions: try_merge_hashset!(extensions: self, another => span_joined),
+            extension: try_merge_opt!(extension: self, another),
             is_internal: self.is_internal || another.is_internal,
         })
     }
@@ -196,6 +290,54 @@ impl Attr {
     }
 }
 
+/// Collects the provided [`ObjectExtension`]s, sorted to preserve/guarantee the
+/// order of their fields registered in schema.
+///
+/// [`ObjectExtension`]: juniper::macros::reflect::ObjectExtension
+#[must_use]
+pub(crate) fn sorted_extensions(extensions: &HashSet<SpanContainer<syn::Type>>) -> Vec<syn::Type> {
+    let mut extensions: Vec<_> = extensions.iter().map(|ty| ty.as_ref().clone()).collect();
+    extensions.sort_unstable_by_key(|ty| ty.to_token_stream().to_string());
+    extensions
+}
+
+/// Policy of resolving sibling fields of a [GraphQL object][1] type.
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Objects
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum ResolvePolicy {
+    /// Resolve sibling fields concurrently.
+    Concurrent,
+
+    /// Resolve sibling fields one-by-one.
+    Serial,
+}
+
+impl TryFrom<syn::Ident> for ResolvePolicy {
+    type Error = syn::Error;
+
+    fn try_from(ident: syn::Ident) -> syn::Result<Self> {
+        match ident.to_string().as_str() {
+            "concurrent" => Ok(Self::Concurrent),
+            "serial" => Ok(Self::Serial),
+            _ => Err(syn::Error::new(
+                ident.span(),
+                "unknown resolve policy, expected `concurrent` or `serial`",
+            )),
+        }
+    }
+}
+
+impl ToTokens for ResolvePolicy {
+    fn to_tokens(&self, into: &mut TokenStream) {
+        let variant = match self {
+            Self::Concurrent => quote! { Concurrent },
+            Self::Serial => quote! { Serial },
+        };
+        quote! { ::juniper::meta::ResolvePolicy::#variant }.to_tokens(into);
+    }
+}
+
 /// Definition of [GraphQL object][1] for code generation.
 ///
 /// [1]: https://spec.graphql.org/October2021#sec-Objects
@@ -252,6 +394,39 @@ pub(crate) struct Definition<Operation: ?Sized> {
     /// [2]: https://spec.graphql.org/October2021#sec-Interfaces
     pub(crate) interfaces: HashSet<syn::Type>,
 
+    /// Explicitly specified [`ResolvePolicy`] of the fields of this
+    /// [GraphQL object][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    pub(crate) resolve_policy: Option<ResolvePolicy>,
+
+    /// Function overriding the `__typename` reported by this
+    /// [GraphQL object][1], if any.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    pub(crate) typename_with: Option<syn::ExprPath>,
+
+    /// Explicitly specified bounds of the generated implementations for this
+    /// [GraphQL object][1], replacing the auto-inferred ones.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    pub(crate) bound: Option<Vec<syn::WherePredicate>>,
+
+    /// [`ObjectExtension`]s defining additional fields of this
+    /// [GraphQL object][1].
+    ///
+    /// [`ObjectExtension`]: juniper::macros::reflect::ObjectExtension
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    pub(crate) extensions: Vec<syn::Type>,
+
+    /// Name of the marker type to generate an [`ObjectExtension`] for, in case
+    /// this [`Definition`] extends another [GraphQL object][1], rather than
+    /// being a [GraphQL object][1] on its own.
+    ///
+    /// [`ObjectExtension`]: juniper::macros::reflect::ObjectExtension
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    pub(crate) extension: Option<syn::Ident>,
+
     /// [GraphQL operation][1] this [`Definition`] should generate code for.
     ///
     /// Either [GraphQL query][2] or [GraphQL subscription][3].
@@ -289,28 +464,36 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
         if let Some(bound) = scalar.bounds() {
             generics.make_where_clause().predicates.push(bound);
         }
-
-        if for_async {
-            let self_ty = if self.generics.lifetimes().next().is_some() {
-                let mut lifetimes = vec![];
-
-                // Modify lifetime names to omit "lifetime name `'a` shadows a
-                // lifetime name that is already in scope" error.
-                let mut ty = self.ty.clone();
-                ty.lifetimes_iter_mut(&mut |lt| {
-                    let ident = lt.ident.unraw();
-                    lt.ident = format_ident!("__fa__{ident}");
-                    lifetimes.push(lt.clone());
-                });
-
-                quote! { for<#( #lifetimes ),*> #ty }
-            } else {
-                quote! { Self }
-            };
+        if let Some(bound) = &self.bound {
             generics
                 .make_where_clause()
                 .predicates
-                .push(parse_quote! { #self_ty: Sync });
+                .extend(bound.iter().cloned());
+        }
+
+        if for_async {
+            if self.bound.is_none() {
+                let self_ty = if self.generics.lifetimes().next().is_some() {
+                    let mut lifetimes = vec![];
+
+                    // Modify lifetime names to omit "lifetime name `'a` shadows a
+                    // lifetime name that is already in scope" error.
+                    let mut ty = self.ty.clone();
+                    ty.lifetimes_iter_mut(&mut |lt| {
+                        let ident = lt.ident.unraw();
+                        lt.ident = format_ident!("__fa__{ident}");
+                        lifetimes.push(lt.clone());
+                    });
+
+                    quote! { for<#( #lifetimes ),*> #ty }
+                } else {
+                    quote! { Self }
+                };
+                generics
+                    .make_where_clause()
+                    .predicates
+                    .push(parse_quote! { #self_ty: Sync });
+            }
 
             if scalar.is_generic() {
                 generics
@@ -373,6 +556,23 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
         let fields = self.fields.iter().map(|f| &f.name);
         let interfaces = self.interfaces.iter();
 
+        let field_names = if self.extensions.is_empty() {
+            quote! { &[#(#fields),*] }
+        } else {
+            let fields_count = self.fields.len();
+            let extensions = &self.extensions;
+            quote! {
+                &::juniper::macros::reflect::concat_names::<{
+                    #fields_count #(
+                        + <#extensions as ::juniper::macros::reflect::ExtensionFields>::NAMES.len()
+                    )*
+                }>(&[
+                    &[#(#fields),*],
+                    #( <#extensions as ::juniper::macros::reflect::ExtensionFields>::NAMES, )*
+                ])
+            }
+        };
+
         quote! {
             #[automatically_derived]
             impl #impl_generics ::juniper::macros::reflect::BaseType<#scalar>
@@ -413,7 +613,7 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
                 for #ty
                 #where_clause
             {
-                const NAMES: ::juniper::macros::reflect::Names = &[#(#fields),*];
+                const NAMES: ::juniper::macros::reflect::Names = #field_names;
             }
         }
     }
@@ -453,6 +653,28 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
             }
         });
 
+        let resolve_policy = self
+            .resolve_policy
+            .map(|policy| quote! { .resolve_policy(#policy) });
+
+        let fields = if self.extensions.is_empty() {
+            quote! {
+                let fields = [
+                    #( #fields_meta, )*
+                ];
+            }
+        } else {
+            let extensions = &self.extensions;
+            quote! {
+                let mut fields = ::std::vec![
+                    #( #fields_meta, )*
+                ];
+                #( fields.extend(<#extensions as ::juniper::macros::reflect::ObjectExtension<
+                    Self, #scalar,
+                >>::meta_fields(info, registry)); )*
+            }
+        };
+
         quote! {
             #[automatically_derived]
             impl #impl_generics ::juniper::GraphQLType<#scalar> for #ty #where_clause
@@ -467,12 +689,11 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
                 ) -> ::juniper::meta::MetaType<'r, #scalar>
                 where #scalar: 'r,
                 {
-                    let fields = [
-                        #( #fields_meta, )*
-                    ];
+                    #fields
                     registry.build_object_type::<#ty>(info, &fields)
                         #description
                         #interfaces
+                        #resolve_policy
                         .into_meta()
                 }
             }
@@ -487,6 +708,14 @@ struct Query;
 
 impl ToTokens for Definition<Query> {
     fn to_tokens(&self, into: &mut TokenStream) {
+        if let Some(marker) = &self.extension {
+            self.impl_object_extension_tokens(marker).to_tokens(into);
+            self.impl_field_meta_tokens().to_tokens(into);
+            self.impl_field_tokens().to_tokens(into);
+            self.impl_async_field_tokens().to_tokens(into);
+            return;
+        }
+
         self.impl_graphql_object_tokens().to_tokens(into);
         self.impl_output_type_tokens().to_tokens(into);
         self.impl_graphql_type_tokens().to_tokens(into);
@@ -548,6 +777,111 @@ impl Definition<Query> {
         }
     }
 
+    /// Returns generated code declaring the `marker` type and implementing
+    /// [`ObjectExtension`] and [`ExtensionFields`] traits for it, so the fields
+    /// of this [`Definition`] extend another [GraphQL object][1].
+    ///
+    /// [`ExtensionFields`]: juniper::macros::reflect::ExtensionFields
+    /// [`ObjectExtension`]: juniper::macros::reflect::ObjectExtension
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    #[must_use]
+    fn impl_object_extension_tokens(&self, marker: &syn::Ident) -> TokenStream {
+        let scalar = &self.scalar;
+        let context = &self.context;
+
+        let (impl_generics, where_clause) = self.impl_generics(false);
+        let ty = &self.ty;
+
+        let doc = format!(
+            "Fields of the `{}` GraphQL object, defined outside of its main definition.",
+            ty.to_token_stream(),
+        );
+
+        let names = self.fields.iter().map(|f| &f.name);
+        let fields_meta = self.fields.iter().map(|f| f.method_meta_tokens(None));
+
+        let fields_resolvers = self.fields.iter().map(|f| {
+            let name = &f.name;
+            quote! {
+                #name => ::std::option::Option::Some(::juniper::macros::reflect::Field::<
+                    #scalar,
+                    { ::juniper::macros::reflect::fnv1a128(#name) }
+                >::call(object, info, args, executor)),
+            }
+        });
+        let async_fields_resolvers = self.fields.iter().map(|f| {
+            let name = &f.name;
+            quote! {
+                #name => ::std::option::Option::Some(::juniper::macros::reflect::AsyncField::<
+                    #scalar,
+                    { ::juniper::macros::reflect::fnv1a128(#name) }
+                >::call(object, info, args, executor)),
+            }
+        });
+
+        quote! {
+            #[doc = #doc]
+            pub enum #marker {}
+
+            #[automatically_derived]
+            impl ::juniper::macros::reflect::ExtensionFields for #marker {
+                const NAMES: ::juniper::macros::reflect::Names = &[#(#names),*];
+            }
+
+            #[allow(deprecated, non_snake_case)]
+            #[automatically_derived]
+            impl #impl_generics ::juniper::macros::reflect::ObjectExtension<#ty, #scalar>
+                for #marker
+                #where_clause
+            {
+                type Context = #context;
+
+                fn meta_fields<'r>(
+                    info: &(),
+                    registry: &mut ::juniper::Registry<'r, #scalar>,
+                ) -> ::std::vec::Vec<::juniper::meta::Field<'r, #scalar>>
+                where #scalar: 'r,
+                {
+                    ::std::vec![
+                        #( #fields_meta, )*
+                    ]
+                }
+
+                fn resolve_field(
+                    object: &#ty,
+                    info: &(),
+                    field: &str,
+                    args: &::juniper::Arguments<'_, #scalar>,
+                    executor: &::juniper::Executor<'_, '_, Self::Context, #scalar>,
+                ) -> ::std::option::Option<::juniper::ExecutionResult<#scalar>> {
+                    match field {
+                        #( #fields_resolvers )*
+                        _ => ::std::option::Option::None,
+                    }
+                }
+
+                fn resolve_field_async<'b>(
+                    object: &'b #ty,
+                    info: &'b (),
+                    field: &'b str,
+                    args: &'b ::juniper::Arguments<'_, #scalar>,
+                    executor: &'b ::juniper::Executor<'_, '_, Self::Context, #scalar>,
+                ) -> ::std::option::Option<
+                    ::juniper::BoxFuture<'b, ::juniper::ExecutionResult<#scalar>>,
+                >
+                where
+                    #ty: Sync,
+                    #scalar: Send + Sync,
+                {
+                    match field {
+                        #( #async_fields_resolvers )*
+                        _ => ::std::option::Option::None,
+                    }
+                }
+            }
+        }
+    }
+
     /// Returns generated code implementing [`FieldMeta`] traits for each field
     /// of this [GraphQL object][1].
     ///
@@ -572,14 +906,16 @@ impl Definition<Query> {
                     .flat_map(|vec| vec.iter())
                     .filter_map(|arg| match arg {
                         field::MethodArgument::Regular(arg) => {
-                            let (name, ty) = (&arg.name, &arg.ty);
+                            let (name, ty) = (&arg.name, arg.schema_ty());
                             Some(quote! {(
                                 #name,
                                 <#ty as ::juniper::macros::reflect::BaseType<#scalar>>::NAME,
                                 <#ty as ::juniper::macros::reflect::WrappedType<#scalar>>::VALUE,
                             )})
                         }
-                        field::MethodArgument::Executor | field::MethodArgument::Context(_) => None,
+                        field::MethodArgument::Executor(_) | field::MethodArgument::Context(_) => {
+                            None
+                        }
                     })
                     .collect::<Vec<_>>();
 
@@ -626,11 +962,11 @@ impl Definition<Query> {
 
                 let resolve = if field.is_async {
                     quote! {
-                        ::std::panic!(
-                             "Tried to resolve async field `{}` on type `{}` with a sync resolver",
-                             #name,
-                             <Self as ::juniper::macros::reflect::BaseType<#scalar>>::NAME,
-                         );
+                        ::std::result::Result::Err(::juniper::FieldError::from(::std::format!(
+                            "Tried to resolve async field `{}` on type `{}` with a sync resolver",
+                            #name,
+                            <Self as ::juniper::macros::reflect::BaseType<#scalar>>::NAME,
+                        )))
                     }
                 } else {
                     let res = if field.is_method() {
@@ -760,6 +1096,18 @@ impl Definition<Query> {
 
         let name = &self.name;
 
+        let typename_override = self.typename_with.as_ref().map(|path| {
+            quote! {
+                fn typename_override(
+                    &self,
+                    context: &Self::Context,
+                    _: &Self::TypeInfo,
+                ) -> Option<String> {
+                    Some(#path(self, context).into())
+                }
+            }
+        });
+
         let fields_resolvers = self.fields.iter().map(|f| {
             let name = &f.name;
             quote! {
@@ -775,6 +1123,16 @@ impl Definition<Query> {
         let no_field_err =
             field::Definition::method_resolve_field_err_no_field_tokens(scalar, &ty_name);
 
+        let extensions_resolvers = self.extensions.iter().map(|ext| {
+            quote! {
+                if let Some(res) = <#ext as ::juniper::macros::reflect::ObjectExtension<
+                    Self, #scalar,
+                >>::resolve_field(self, info, field, args, executor) {
+                    return res;
+                }
+            }
+        });
+
         quote! {
             #[allow(deprecated)]
             #[automatically_derived]
@@ -796,7 +1154,10 @@ impl Definition<Query> {
                 ) -> ::juniper::ExecutionResult<#scalar> {
                     match field {
                         #( #fields_resolvers )*
-                        _ => #no_field_err,
+                        _ => {
+                            #( #extensions_resolvers )*
+                            #no_field_err
+                        }
                     }
                 }
 
@@ -807,6 +1168,8 @@ impl Definition<Query> {
                 ) -> String {
                     #name.into()
                 }
+
+                #typename_override
             }
         }
     }
@@ -839,6 +1202,16 @@ impl Definition<Query> {
         let no_field_err =
             field::Definition::method_resolve_field_err_no_field_tokens(scalar, &ty_name);
 
+        let extensions_resolvers = self.extensions.iter().map(|ext| {
+            quote! {
+                if let Some(fut) = <#ext as ::juniper::macros::reflect::ObjectExtension<
+                    Self, #scalar,
+                >>::resolve_field_async(self, info, field, args, executor) {
+                    return fut;
+                }
+            }
+        });
+
         quote! {
             #[allow(deprecated, non_snake_case)]
             #[automatically_derived]
@@ -853,7 +1226,10 @@ impl Definition<Query> {
                 ) -> ::juniper::BoxFuture<'b, ::juniper::ExecutionResult<#scalar>> {
                     match field {
                         #( #fields_resolvers )*
-                        _ => Box::pin(async move { #no_field_err }),
+                        _ => {
+                            #( #extensions_resolvers )*
+                            Box::pin(async move { #no_field_err })
+                        }
                     }
                 }
             }
@@ -1,12 +1,14 @@
 //! Code generation for `#[graphql_scalar]` macro.
 
+use std::mem;
+
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::{parse_quote, spanned::Spanned};
 
 use crate::common::{diagnostic, parse, scalar, SpanContainer};
 
-use super::{derive::parse_derived_methods, Attr, Definition, Methods, ParseToken, TypeOrIdent};
+use super::{derive::parse_derived_methods, Attr, Definition, Methods, TypeOrIdent};
 
 /// [`diagnostic::Scope`] of errors for `#[graphql_scalar]` macro.
 const ERR: diagnostic::Scope = diagnostic::Scope::ScalarAttr;
@@ -42,6 +44,13 @@ fn expand_on_type_alias(
             "`transparent` attribute argument isn't applicable to type aliases",
         ));
     }
+    if attr.with_context {
+        return Err(ERR.custom_error(
+            ast.span(),
+            "`with_context` attribute argument isn't applicable to type aliases, \
+             as it requires `transparent` one",
+        ));
+    }
 
     let methods = parse_type_alias_methods(&ast, &attr)?;
     let scalar = scalar::Type::parse(attr.scalar.as_deref(), &ast.generics);
@@ -51,6 +60,7 @@ fn expand_on_type_alias(
         where_clause: attr
             .where_clause
             .map_or_else(Vec::new, |cl| cl.into_inner()),
+        bound: attr.bound.map(SpanContainer::into_inner),
         generics: ast.generics.clone(),
         methods,
         name: attr
@@ -58,7 +68,14 @@ fn expand_on_type_alias(
             .map(SpanContainer::into_inner)
             .unwrap_or_else(|| ast.ident.to_string()),
         description: attr.description.map(SpanContainer::into_inner),
+        description_with: attr.description_with.map(SpanContainer::into_inner),
         specified_by_url: attr.specified_by_url.map(SpanContainer::into_inner),
+        context: attr
+            .context
+            .map_or_else(|| parse_quote! { () }, SpanContainer::into_inner),
+        with_context: attr.with_context,
+        accepts_null: attr.accepts_null,
+        structured: attr.structured,
         scalar,
     };
 
@@ -71,10 +88,15 @@ fn expand_on_type_alias(
 /// Expands `#[graphql_scalar]` macro placed on a struct, enum or union.
 fn expand_on_derive_input(
     attrs: Vec<syn::Attribute>,
-    ast: syn::DeriveInput,
+    mut ast: syn::DeriveInput,
 ) -> syn::Result<TokenStream> {
     let attr = Attr::from_attrs("graphql_scalar", &attrs)?;
     let methods = parse_derived_methods(&ast, &attr)?;
+    if let (Methods::Enum { .. }, syn::Data::Enum(data)) = (&methods, &mut ast.data) {
+        for v in &mut data.variants {
+            v.attrs = parse::attr::strip("graphql", mem::take(&mut v.attrs));
+        }
+    }
     let scalar = scalar::Type::parse(attr.scalar.as_deref(), &ast.generics);
 
     let def = Definition {
@@ -82,6 +104,7 @@ fn expand_on_derive_input(
         where_clause: attr
             .where_clause
             .map_or_else(Vec::new, |cl| cl.into_inner()),
+        bound: attr.bound.map(SpanContainer::into_inner),
         generics: ast.generics.clone(),
         methods,
         name: attr
@@ -89,7 +112,14 @@ fn expand_on_derive_input(
             .map(SpanContainer::into_inner)
             .unwrap_or_else(|| ast.ident.to_string()),
         description: attr.description.map(SpanContainer::into_inner),
+        description_with: attr.description_with.map(SpanContainer::into_inner),
         specified_by_url: attr.specified_by_url.map(SpanContainer::into_inner),
+        context: attr
+            .context
+            .map_or_else(|| parse_quote! { () }, SpanContainer::into_inner),
+        with_context: attr.with_context,
+        accepts_null: attr.accepts_null,
+        structured: attr.structured,
         scalar,
     };
 
@@ -112,12 +142,12 @@ fn parse_type_alias_methods(ast: &syn::ItemType, attr: &Attr) -> syn::Result<Met
             from_input,
             parse_token,
         }),
-        (to_output, from_input, parse_token, Some(module)) => Ok(Methods::Custom {
-            to_output: to_output.unwrap_or_else(|| parse_quote! { #module::to_output }),
-            from_input: from_input.unwrap_or_else(|| parse_quote! { #module::from_input }),
-            parse_token: parse_token
-                .unwrap_or_else(|| ParseToken::Custom(parse_quote! { #module::parse_token })),
-        }),
+        (to_output, from_input, parse_token, Some(module)) => Ok(Methods::with_module(
+            to_output,
+            from_input,
+            parse_token,
+            &module,
+        )),
         _ => Err(ERR.custom_error(
             ast.span(),
             "all the resolvers have to be provided via `with` attribute \
@@ -6,7 +6,7 @@ use syn::{parse_quote, spanned::Spanned};
 
 use crate::common::{diagnostic, scalar, SpanContainer};
 
-use super::{Attr, Definition, Field, Methods, ParseToken, TypeOrIdent};
+use super::{Attr, Definition, Field, Methods, TypeOrIdent, VariantAttr};
 
 /// [`diagnostic::Scope`] of errors for `#[derive(GraphQLScalar)]` macro.
 const ERR: diagnostic::Scope = diagnostic::Scope::ScalarDerive;
@@ -23,6 +23,7 @@ pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
         where_clause: attr
             .where_clause
             .map_or_else(Vec::new, |cl| cl.into_inner()),
+        bound: attr.bound.map(SpanContainer::into_inner),
         generics: ast.generics.clone(),
         methods,
         name: attr
@@ -30,7 +31,14 @@ pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
             .map(SpanContainer::into_inner)
             .unwrap_or_else(|| ast.ident.to_string()),
         description: attr.description.map(SpanContainer::into_inner),
+        description_with: attr.description_with.map(SpanContainer::into_inner),
         specified_by_url: attr.specified_by_url.map(SpanContainer::into_inner),
+        context: attr
+            .context
+            .map_or_else(|| parse_quote! { () }, SpanContainer::into_inner),
+        with_context: attr.with_context,
+        accepts_null: attr.accepts_null,
+        structured: attr.structured,
         scalar,
     }
     .to_token_stream())
@@ -39,6 +47,29 @@ pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
 /// Parses [`Methods`] from the provided [`Attr`] for the specified
 /// [`syn::DeriveInput`].
 pub(super) fn parse_derived_methods(ast: &syn::DeriveInput, attr: &Attr) -> syn::Result<Methods> {
+    let methods = parse_derived_methods_unchecked(ast, attr)?;
+    if attr.with_context && !methods.has_custom_to_output() {
+        return Err(ERR.custom_error(
+            ast.span(),
+            "`with_context` attribute argument requires a custom `to_output` \
+             function to be provided via `to_output_with` or `with` attribute \
+             arguments",
+        ));
+    }
+    if attr.with_context && !matches!(methods, Methods::Delegated { .. }) {
+        return Err(ERR.custom_error(
+            ast.span(),
+            "`with_context` attribute argument requires `transparent` one, as \
+             `ToInputValue` cannot be implemented without a `Context` otherwise",
+        ));
+    }
+    Ok(methods)
+}
+
+/// Parses [`Methods`] from the provided [`Attr`] for the specified
+/// [`syn::DeriveInput`] without checking their compatibility with the
+/// `with_context` attribute argument.
+fn parse_derived_methods_unchecked(ast: &syn::DeriveInput, attr: &Attr) -> syn::Result<Methods> {
     match (
         attr.to_output.as_deref().cloned(),
         attr.from_input.as_deref().cloned(),
@@ -53,14 +84,19 @@ pub(super) fn parse_derived_methods(ast: &syn::DeriveInput, attr: &Attr) -> syn:
                 parse_token,
             })
         }
+        (None, None, None, None, false) if !attr.with_context && is_unit_enum(ast) => {
+            Ok(Methods::Enum {
+                variants: parse_enum_variants(ast)?,
+            })
+        }
         (to_output, from_input, parse_token, module, false) => {
             let module = module.unwrap_or_else(|| parse_quote! { Self });
-            Ok(Methods::Custom {
-                to_output: to_output.unwrap_or_else(|| parse_quote! { #module::to_output }),
-                from_input: from_input.unwrap_or_else(|| parse_quote! { #module::from_input }),
-                parse_token: parse_token
-                    .unwrap_or_else(|| ParseToken::Custom(parse_quote! { #module::parse_token })),
-            })
+            Ok(Methods::with_module(
+                to_output,
+                from_input,
+                parse_token,
+                &module,
+            ))
         }
         (to_output, from_input, parse_token, None, true) => {
             let data = if let syn::Data::Struct(data) = &ast.data {
@@ -120,3 +156,40 @@ pub(super) fn parse_derived_methods(ast: &syn::DeriveInput, attr: &Attr) -> syn:
         )),
     }
 }
+
+/// Checks whether the provided [`syn::DeriveInput`] is a non-empty enum with
+/// unit variants only.
+fn is_unit_enum(ast: &syn::DeriveInput) -> bool {
+    matches!(
+        &ast.data,
+        syn::Data::Enum(data) if !data.variants.is_empty()
+            && data.variants.iter().all(|v| matches!(v.fields, syn::Fields::Unit)),
+    )
+}
+
+/// Parses string literals of the unit enum variants, represented by
+/// [`Methods::Enum`].
+fn parse_enum_variants(ast: &syn::DeriveInput) -> syn::Result<Vec<(syn::Ident, String)>> {
+    let data = if let syn::Data::Enum(data) = &ast.data {
+        data
+    } else {
+        return Ok(Vec::new());
+    };
+
+    let mut variants = Vec::<(syn::Ident, String)>::with_capacity(data.variants.len());
+    for v in &data.variants {
+        let attr = VariantAttr::from_attrs("graphql", &v.attrs)?;
+        let name = attr
+            .name
+            .as_ref()
+            .map_or_else(|| v.ident.to_string(), |n| n.as_ref().clone());
+        if variants.iter().any(|(_, n)| *n == name) {
+            return Err(ERR.custom_error(
+                attr.name.map_or_else(|| v.span(), |n| n.span_ident()),
+                format!("enum variants must have unique string literals, `{name}` is duplicated"),
+            ));
+        }
+        variants.push((v.ident.clone(), name));
+    }
+    Ok(variants)
+}
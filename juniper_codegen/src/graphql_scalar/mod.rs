@@ -17,7 +17,7 @@ use url::Url;
 use crate::common::{
     filter_attrs,
     parse::{
-        attr::{err, OptionExt as _},
+        attr::{err, parse_bound, OptionExt as _},
         ParseBufferExt as _,
     },
     scalar, Description, SpanContainer,
@@ -42,6 +42,12 @@ struct Attr {
     /// [1]: https://spec.graphql.org/October2021#sec-Scalars
     description: Option<SpanContainer<Description>>,
 
+    /// Explicitly specified function computing the description of this
+    /// [GraphQL scalar][1] when building GraphQL schema.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Scalars
+    description_with: Option<SpanContainer<syn::ExprPath>>,
+
     /// Spec [`Url`] of this [GraphQL scalar][1] to put into GraphQL schema.
     ///
     /// [1]: https://spec.graphql.org/October2021#sec-Scalars
@@ -61,6 +67,15 @@ struct Attr {
     /// [1]: https://spec.graphql.org/October2021#sec-Scalars
     scalar: Option<SpanContainer<scalar::AttrValue>>,
 
+    /// Explicitly specified type of [`Context`] to use for resolving this
+    /// [GraphQL scalar][1] type with.
+    ///
+    /// If [`None`], then unit type `()` is assumed as a type of [`Context`].
+    ///
+    /// [`Context`]: juniper::Context
+    /// [1]: https://spec.graphql.org/October2021#sec-Scalars
+    context: Option<SpanContainer<syn::Type>>,
+
     /// Explicitly specified function to be used as
     /// [`ToInputValue::to_input_value`] implementation.
     ///
@@ -86,9 +101,29 @@ struct Attr {
     /// Explicit where clause added to [`syn::WhereClause`].
     where_clause: Option<SpanContainer<Vec<syn::WherePredicate>>>,
 
+    /// Explicitly specified bounds of the generated implementations, added to
+    /// them instead of the auto-inferred `Self: Sync` one.
+    bound: Option<SpanContainer<Vec<syn::WherePredicate>>>,
+
     /// Indicator for single-field structs allowing to delegate implmemntations
     /// of non-provided resolvers to that field.
     transparent: bool,
+
+    /// Indicator whether the [`Self::to_output`] function accepts the
+    /// [`Self::context`] as its second argument.
+    with_context: bool,
+
+    /// Indicator whether the [`Self::from_input`] function handles `null`s by
+    /// itself, when this [GraphQL scalar][1] is nullable.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Scalars
+    accepts_null: bool,
+
+    /// Indicator whether this [GraphQL scalar][1] accepts input lists and
+    /// objects, passing them to the [`Self::from_input`] function as is.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Scalars
+    structured: bool,
 }
 
 impl Parse for Attr {
@@ -115,6 +150,13 @@ impl Parse for Attr {
                         .replace(SpanContainer::new(ident.span(), Some(desc.span()), desc))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "desc_with" | "description_with" => {
+                    input.parse::<token::Eq>()?;
+                    let path = input.parse::<syn::ExprPath>()?;
+                    out.description_with
+                        .replace(SpanContainer::new(ident.span(), Some(path.span()), path))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "specified_by_url" => {
                     input.parse::<token::Eq>()?;
                     let lit = input.parse::<syn::LitStr>()?;
@@ -132,6 +174,13 @@ impl Parse for Attr {
                         .replace(SpanContainer::new(ident.span(), Some(scl.span()), scl))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "ctx" | "context" | "Context" => {
+                    input.parse::<token::Eq>()?;
+                    let ctx = input.parse::<syn::Type>()?;
+                    out.context
+                        .replace(SpanContainer::new(ident.span(), Some(ctx.span()), ctx))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "to_output_with" => {
                     input.parse::<token::Eq>()?;
                     let scl = input.parse::<syn::ExprPath>()?;
@@ -210,9 +259,24 @@ impl Parse for Attr {
                         ))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "bound" => {
+                    input.parse::<token::Eq>()?;
+                    out.bound
+                        .replace(parse_bound(&ident, input)?)
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "transparent" => {
                     out.transparent = true;
                 }
+                "with_context" => {
+                    out.with_context = true;
+                }
+                "accepts_null" => {
+                    out.accepts_null = true;
+                }
+                "structured" => {
+                    out.structured = true;
+                }
                 name => {
                     return Err(err::unknown_arg(&ident, name));
                 }
@@ -230,14 +294,20 @@ impl Attr {
         Ok(Self {
             name: try_merge_opt!(name: self, another),
             description: try_merge_opt!(description: self, another),
+            description_with: try_merge_opt!(description_with: self, another),
             specified_by_url: try_merge_opt!(specified_by_url: self, another),
             scalar: try_merge_opt!(scalar: self, another),
+            context: try_merge_opt!(context: self, another),
             to_output: try_merge_opt!(to_output: self, another),
             from_input: try_merge_opt!(from_input: self, another),
             parse_token: try_merge_opt!(parse_token: self, another),
             with: try_merge_opt!(with: self, another),
             where_clause: try_merge_opt!(where_clause: self, another),
+            bound: try_merge_opt!(bound: self, another),
             transparent: self.transparent || another.transparent,
+            with_context: self.with_context || another.with_context,
+            accepts_null: self.accepts_null || another.accepts_null,
+            structured: self.structured || another.structured,
         })
     }
 
@@ -248,7 +318,14 @@ impl Attr {
             .map(|attr| attr.parse_args())
             .try_fold(Self::default(), |prev, curr| prev.try_merge(curr?))?;
 
-        if attr.description.is_none() {
+        if let (Some(_), Some(description_with)) = (&attr.description, &attr.description_with) {
+            return Err(syn::Error::new(
+                description_with.span_ident(),
+                "`description` and `description_with` attribute arguments are mutually exclusive",
+            ));
+        }
+
+        if attr.description.is_none() && attr.description_with.is_none() {
             attr.description = Description::parse_from_doc_attrs(attrs)?;
         }
 
@@ -256,6 +333,65 @@ impl Attr {
     }
 }
 
+/// Available arguments behind `#[graphql]` attribute placed on a Rust enum
+/// variant, when generating code for a [GraphQL scalar][1] represented by a
+/// Rust enum with unit variants only.
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Scalars
+#[derive(Debug, Default)]
+struct VariantAttr {
+    /// Explicitly specified string literal this enum variant is represented
+    /// with.
+    ///
+    /// If [`None`], then Rust enum variant's name will be used by default.
+    name: Option<SpanContainer<String>>,
+}
+
+impl Parse for VariantAttr {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let mut out = Self::default();
+        while !input.is_empty() {
+            let ident = input.parse_any_ident()?;
+            match ident.to_string().as_str() {
+                "name" | "rename" => {
+                    input.parse::<token::Eq>()?;
+                    let name = input.parse::<syn::LitStr>()?;
+                    out.name
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(name.span()),
+                            name.value(),
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                name => {
+                    return Err(err::unknown_arg(&ident, name));
+                }
+            }
+            input.try_parse::<token::Comma>()?;
+        }
+        Ok(out)
+    }
+}
+
+impl VariantAttr {
+    /// Tries to merge two [`VariantAttr`]s into a single one, reporting about
+    /// duplicates, if any.
+    fn try_merge(self, mut another: Self) -> syn::Result<Self> {
+        Ok(Self {
+            name: try_merge_opt!(name: self, another),
+        })
+    }
+
+    /// Parses [`VariantAttr`] from the given multiple `name`d
+    /// [`syn::Attribute`]s placed on an enum variant.
+    fn from_attrs(name: &str, attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        filter_attrs(name, attrs)
+            .map(|attr| attr.parse_args())
+            .try_fold(Self::default(), |prev, curr| prev.try_merge(curr?))
+    }
+}
+
 /// [`syn::Type`] in case of `#[graphql_scalar]` or [`syn::Ident`] in case of
 /// `#[derive(GraphQLScalar)]`.
 #[derive(Clone)]
@@ -284,6 +420,10 @@ struct Definition {
     /// Additional [`Self::generics`] [`syn::WhereClause`] predicates.
     where_clause: Vec<syn::WherePredicate>,
 
+    /// Explicitly specified bounds of the generated implementations, replacing
+    /// the auto-inferred ones.
+    bound: Option<Vec<syn::WherePredicate>>,
+
     /// Generics of the Rust type that this [GraphQL scalar][1] is implemented
     /// for.
     ///
@@ -300,11 +440,46 @@ struct Definition {
     /// [1]: https://spec.graphql.org/October2021#sec-Scalars
     description: Option<Description>,
 
+    /// Function computing the description of this [GraphQL scalar][1] when
+    /// building GraphQL schema, instead of the static [`Self::description`].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Scalars
+    description_with: Option<syn::ExprPath>,
+
     /// Spec [`Url`] of this [GraphQL scalar][1] to put into GraphQL schema.
     ///
     /// [1]: https://spec.graphql.org/October2021#sec-Scalars
     specified_by_url: Option<Url>,
 
+    /// Rust type of [`Context`] to generate [`GraphQLType`] implementation with
+    /// for this [GraphQL scalar][1].
+    ///
+    /// [`Context`]: juniper::Context
+    /// [`GraphQLType`]: juniper::GraphQLType
+    /// [1]: https://spec.graphql.org/October2021#sec-Scalars
+    context: syn::Type,
+
+    /// Indicator whether the custom `to_output` function of this
+    /// [GraphQL scalar][1] accepts the [`Context`] as its second argument.
+    ///
+    /// [`Context`]: juniper::Context
+    /// [1]: https://spec.graphql.org/October2021#sec-Scalars
+    with_context: bool,
+
+    /// Indicator whether the [`FromInputValue`] implementation of this
+    /// [GraphQL scalar][1] handles `null`s by itself, when it's nullable.
+    ///
+    /// [`FromInputValue`]: juniper::FromInputValue
+    /// [1]: https://spec.graphql.org/October2021#sec-Scalars
+    accepts_null: bool,
+
+    /// Indicator whether this [GraphQL scalar][1] accepts input lists and
+    /// objects, passing them to its [`FromInputValue`] implementation as is.
+    ///
+    /// [`FromInputValue`]: juniper::FromInputValue
+    /// [1]: https://spec.graphql.org/October2021#sec-Scalars
+    structured: bool,
+
     /// [`ScalarValue`] parametrization to generate [`GraphQLType`]
     /// implementation with for this [GraphQL scalar][1].
     ///
@@ -361,11 +536,15 @@ impl Definition {
         let scalar = &self.scalar;
 
         let name = &self.name;
-        let description = &self.description;
+        let description = self.description_with.as_ref().map_or_else(
+            || self.description.to_token_stream(),
+            |describe| quote! { .description(&#describe()) },
+        );
         let specified_by_url = self.specified_by_url.as_ref().map(|url| {
             let url_lit = url.as_str();
             quote! { .specified_by_url(#url_lit) }
         });
+        let structured = self.structured.then(|| quote! { .structured() });
 
         let (ty, generics) = self.impl_self_and_generics(false);
         let (impl_gens, _, where_clause) = generics.split_for_impl();
@@ -389,6 +568,7 @@ impl Definition {
                     registry.build_scalar_type::<Self>(info)
                         #description
                         #specified_by_url
+                        #structured
                         .into_meta()
                 }
             }
@@ -402,8 +582,9 @@ impl Definition {
     /// [1]: https://spec.graphql.org/October2021#sec-Scalars
     fn impl_value_tokens(&self) -> TokenStream {
         let scalar = &self.scalar;
+        let context = &self.context;
 
-        let resolve = self.methods.expand_resolve(scalar);
+        let resolve = self.methods.expand_resolve(scalar, self.with_context);
 
         let (ty, generics) = self.impl_self_and_generics(false);
         let (impl_gens, _, where_clause) = generics.split_for_impl();
@@ -413,7 +594,7 @@ impl Definition {
             impl #impl_gens ::juniper::GraphQLValue<#scalar> for #ty
                 #where_clause
             {
-                type Context = ();
+                type Context = #context;
                 type TypeInfo = ();
 
                 fn type_name<'i>(&self, info: &'i Self::TypeInfo) -> Option<&'i str> {
@@ -470,7 +651,9 @@ impl Definition {
     fn impl_to_input_value_tokens(&self) -> TokenStream {
         let scalar = &self.scalar;
 
-        let to_input_value = self.methods.expand_to_input_value(scalar);
+        let to_input_value = self
+            .methods
+            .expand_to_input_value(scalar, self.with_context);
 
         let (ty, generics) = self.impl_self_and_generics(false);
         let (impl_gens, _, where_clause) = generics.split_for_impl();
@@ -497,6 +680,14 @@ impl Definition {
 
         let from_input_value = self.methods.expand_from_input_value(scalar);
 
+        let accepts_null = self.accepts_null.then(|| {
+            quote! {
+                fn accepts_null() -> bool {
+                    true
+                }
+            }
+        });
+
         let (ty, generics) = self.impl_self_and_generics(false);
         let (impl_gens, _, where_clause) = generics.split_for_impl();
 
@@ -511,6 +702,8 @@ impl Definition {
                     #from_input_value
                         .map_err(::juniper::executor::IntoFieldError::<#scalar>::into_field_error)
                 }
+
+                #accepts_null
             }
         }
     }
@@ -621,32 +814,40 @@ impl Definition {
         if let Some(bound) = scalar.bounds() {
             generics.make_where_clause().predicates.push(bound);
         }
-
-        if for_async {
-            let self_ty = if self.generics.lifetimes().next().is_some() {
-                let mut generics = self.generics.clone();
-                ModifyLifetimes.visit_generics_mut(&mut generics);
-
-                let lifetimes = generics.lifetimes().map(|lt| &lt.lifetime);
-                let ty = match self.ty.clone() {
-                    TypeOrIdent::Type(mut ty) => {
-                        ModifyLifetimes.visit_type_mut(&mut ty);
-                        ty.into_token_stream()
-                    }
-                    TypeOrIdent::Ident(ident) => {
-                        let (_, ty_gens, _) = generics.split_for_impl();
-                        quote! { #ident #ty_gens }
-                    }
-                };
-
-                quote! { for<#( #lifetimes ),*> #ty }
-            } else {
-                quote! { Self }
-            };
+        if let Some(bound) = &self.bound {
             generics
                 .make_where_clause()
                 .predicates
-                .push(parse_quote! { #self_ty: Sync });
+                .extend(bound.iter().cloned());
+        }
+
+        if for_async {
+            if self.bound.is_none() {
+                let self_ty = if self.generics.lifetimes().next().is_some() {
+                    let mut generics = self.generics.clone();
+                    ModifyLifetimes.visit_generics_mut(&mut generics);
+
+                    let lifetimes = generics.lifetimes().map(|lt| &lt.lifetime);
+                    let ty = match self.ty.clone() {
+                        TypeOrIdent::Type(mut ty) => {
+                            ModifyLifetimes.visit_type_mut(&mut ty);
+                            ty.into_token_stream()
+                        }
+                        TypeOrIdent::Ident(ident) => {
+                            let (_, ty_gens, _) = generics.split_for_impl();
+                            quote! { #ident #ty_gens }
+                        }
+                    };
+
+                    quote! { for<#( #lifetimes ),*> #ty }
+                } else {
+                    quote! { Self }
+                };
+                generics
+                    .make_where_clause()
+                    .predicates
+                    .push(parse_quote! { #self_ty: Sync });
+            }
 
             if scalar.is_generic() {
                 generics
@@ -707,20 +908,78 @@ enum Methods {
         /// [`Field`] to resolve not provided methods.
         field: Box<Field>,
     },
+
+    /// [GraphQL scalar][1] represented by a Rust enum with unit variants only,
+    /// each one mapped to a string literal.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Scalars
+    Enum {
+        /// Rust enum variants along with the string literals they're
+        /// represented with.
+        variants: Vec<(syn::Ident, String)>,
+    },
 }
 
 impl Methods {
+    /// Constructs [`Methods::Custom`] from the provided custom functions,
+    /// using the ones from the provided `module` for the missing ones.
+    ///
+    /// Paths to the `module` functions are spanned to the `module` itself, so
+    /// a missing function is reported at the `with` attribute argument.
+    fn with_module(
+        to_output: Option<syn::ExprPath>,
+        from_input: Option<syn::ExprPath>,
+        parse_token: Option<ParseToken>,
+        module: &syn::ExprPath,
+    ) -> Self {
+        let module_fn = |name: &str| {
+            let mut path = module.clone();
+            path.path
+                .segments
+                .push(syn::Ident::new(name, module.span()).into());
+            path
+        };
+        Self::Custom {
+            to_output: to_output.unwrap_or_else(|| module_fn("to_output")),
+            from_input: from_input.unwrap_or_else(|| module_fn("from_input")),
+            parse_token: parse_token
+                .unwrap_or_else(|| ParseToken::Custom(module_fn("parse_token"))),
+        }
+    }
+
+    /// Indicates whether these [`Methods`] contain a custom `to_output`
+    /// function.
+    fn has_custom_to_output(&self) -> bool {
+        matches!(
+            self,
+            Self::Custom { .. }
+                | Self::Delegated {
+                    to_output: Some(_),
+                    ..
+                },
+        )
+    }
+
     /// Expands [`GraphQLValue::resolve`] method.
     ///
     /// [`GraphQLValue::resolve`]: juniper::GraphQLValue::resolve
-    fn expand_resolve(&self, scalar: &scalar::Type) -> TokenStream {
+    ///
+    /// If `with_context` is `true`, then the custom `to_output` function is
+    /// called with the [`Executor::context`] as its second argument.
+    ///
+    /// [`Executor::context`]: juniper::Executor::context
+    fn expand_resolve(&self, scalar: &scalar::Type, with_context: bool) -> TokenStream {
         match self {
             Self::Custom { to_output, .. }
             | Self::Delegated {
                 to_output: Some(to_output),
                 ..
             } => {
-                quote! { Ok(#to_output(self)) }
+                if with_context {
+                    quote! { Ok(#to_output(self, executor.context())) }
+                } else {
+                    quote! { Ok(#to_output(self)) }
+                }
             }
             Self::Delegated { field, .. } => {
                 quote! {
@@ -732,27 +991,44 @@ impl Methods {
                     )
                 }
             }
+            Self::Enum { variants } => {
+                let as_str = Self::expand_enum_as_str(variants);
+                quote! {
+                    Ok(::juniper::Value::scalar(::std::string::String::from(#as_str)))
+                }
+            }
         }
     }
 
     /// Expands [`ToInputValue::to_input_value`] method.
     ///
     /// [`ToInputValue::to_input_value`]: juniper::ToInputValue::to_input_value
-    fn expand_to_input_value(&self, scalar: &scalar::Type) -> TokenStream {
+    ///
+    /// If `with_context` is `true`, then the custom `to_output` function cannot
+    /// be used, as there is no [`Context`] available, so the method is always
+    /// delegated to the [`Field`] (`with_context` requires `transparent`).
+    ///
+    /// [`Context`]: juniper::Context
+    fn expand_to_input_value(&self, scalar: &scalar::Type, with_context: bool) -> TokenStream {
         match self {
+            Self::Delegated { field, .. } if with_context => quote! {
+                ::juniper::ToInputValue::<#scalar>::to_input_value(&self.#field)
+            },
             Self::Custom { to_output, .. }
             | Self::Delegated {
                 to_output: Some(to_output),
                 ..
-            } => {
-                quote! {
-                    let v = #to_output(self);
-                    ::juniper::ToInputValue::to_input_value(&v)
-                }
-            }
-            Self::Delegated { field, .. } => {
+            } => quote! {
+                let v = #to_output(self);
+                ::juniper::ToInputValue::to_input_value(&v)
+            },
+            Self::Delegated { field, .. } => quote! {
+                ::juniper::ToInputValue::<#scalar>::to_input_value(&self.#field)
+            },
+            Self::Enum { variants } => {
+                let as_str = Self::expand_enum_as_str(variants);
                 quote! {
-                    ::juniper::ToInputValue::<#scalar>::to_input_value(&self.#field)
+                    ::juniper::InputValue::scalar(::std::string::String::from(#as_str))
                 }
             }
         }
@@ -778,6 +1054,26 @@ impl Methods {
                         .map(#self_constructor)
                 }
             }
+            Self::Enum { variants } => {
+                let arms = variants.iter().map(|(ident, name)| {
+                    quote! { #name => Ok(Self::#ident), }
+                });
+                let expected = variants
+                    .iter()
+                    .map(|(_, name)| format!("`{name}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let err_msg = format!("Expected one of {expected}, found: `{{}}`");
+                quote! {
+                    input
+                        .as_string_value()
+                        .ok_or_else(|| format!("Expected `String`, found: {}", input))
+                        .and_then(|s| match s {
+                            #( #arms )*
+                            _ => Err(format!(#err_msg, s)),
+                        })
+                }
+            }
         }
     }
 
@@ -800,6 +1096,24 @@ impl Methods {
                     <#field_ty as ::juniper::ParseScalarValue<#scalar>>::from_str(token)
                 }
             }
+            Self::Enum { .. } => {
+                quote! {
+                    <::std::string::String as ::juniper::ParseScalarValue<#scalar>>::from_str(token)
+                }
+            }
+        }
+    }
+
+    /// Expands `match` expression converting `self` into a string literal of
+    /// its variant for [`Methods::Enum`].
+    fn expand_enum_as_str(variants: &[(syn::Ident, String)]) -> TokenStream {
+        let arms = variants.iter().map(|(ident, name)| {
+            quote! { Self::#ident => #name, }
+        });
+        quote! {
+            match self {
+                #( #arms )*
+            }
         }
     }
 }
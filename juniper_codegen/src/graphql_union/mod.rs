@@ -15,13 +15,14 @@ use syn::{
     parse_quote,
     spanned::Spanned as _,
     token,
+    visit::Visit,
 };
 
 use crate::common::{
     filter_attrs, gen,
     parse::{
         attr::{err, OptionExt as _},
-        ParseBufferExt as _,
+        GenericsExt as _, ParseBufferExt as _,
     },
     scalar, Description, SpanContainer,
 };
@@ -412,8 +413,15 @@ impl Definition {
         let (impl_generics, ty_full, where_clause) = self.impl_generics(false);
 
         let variant_tys: Vec<_> = self.variants.iter().map(|var| &var.ty).collect();
+        // Static assertions are expanded into `const`s, which cannot use the
+        // generic parameters of the outer item, so defaults are substituted.
+        let const_variant_tys = variant_tys.iter().map(|ty| {
+            let mut ty = (*ty).clone();
+            self.generics.replace_type_with_defaults(&mut ty);
+            ty
+        });
         let all_variants_unique = (variant_tys.len() > 1).then(|| {
-            quote! { ::juniper::sa::assert_type_ne_all!(#( #variant_tys ),*); }
+            quote! { ::juniper::sa::assert_type_ne_all!(#( #const_variant_tys ),*); }
         });
 
         quote! {
@@ -569,7 +577,18 @@ impl Definition {
     fn impl_graphql_value_async_tokens(&self) -> TokenStream {
         let scalar = &self.scalar;
 
-        let (impl_generics, ty_full, where_clause) = self.impl_generics(true);
+        let (impl_generics, ty_full, mut where_clause) = self.impl_generics(true);
+        // Variants, depending on type parameters, are not guaranteed to be
+        // `Sync`, which is required for resolving them asynchronously.
+        for var in &self.variants {
+            if uses_type_params(&var.ty, &self.generics) {
+                let ty = &var.ty;
+                where_clause
+                    .get_or_insert_with(|| parse_quote! { where })
+                    .predicates
+                    .push(parse_quote! { #ty: Sync });
+            }
+        }
 
         let name = &self.name;
 
@@ -789,6 +808,35 @@ fn emerge_union_variants_from_attr(
     }
 }
 
+/// Checks whether the provided [`syn::Type`] uses any of the type parameters of
+/// the provided [`syn::Generics`].
+fn uses_type_params(ty: &syn::Type, generics: &syn::Generics) -> bool {
+    /// [`Visit`]or searching for the type parameters of [`syn::Generics`].
+    struct Search<'a> {
+        generics: &'a syn::Generics,
+        found: bool,
+    }
+
+    impl<'a, 'ast> Visit<'ast> for Search<'a> {
+        fn visit_path(&mut self, path: &'ast syn::Path) {
+            if let Some(ident) = path.get_ident() {
+                if self.generics.type_params().any(|p| p.ident == *ident) {
+                    self.found = true;
+                    return;
+                }
+            }
+            syn::visit::visit_path(self, path);
+        }
+    }
+
+    let mut search = Search {
+        generics,
+        found: false,
+    };
+    search.visit_type(ty);
+    search.found
+}
+
 /// Checks whether all [GraphQL union][1] `variants` represent a different Rust
 /// type.
 ///
@@ -0,0 +1,90 @@
+//! Code generation for `#[derive(GraphQLVariables)]` macro.
+
+use std::collections::HashSet;
+
+use proc_macro2::TokenStream;
+use quote::ToTokens as _;
+use syn::{ext::IdentExt as _, spanned::Spanned as _};
+
+use crate::common::{diagnostic, rename, scalar, SpanContainer};
+
+use super::{ContainerAttr, Definition, FieldAttr, FieldDefinition};
+
+/// [`diagnostic::Scope`] of errors for `#[derive(GraphQLVariables)]` macro.
+const ERR: diagnostic::Scope = diagnostic::Scope::VariablesDerive;
+
+/// Expands `#[derive(GraphQLVariables)]` macro into generated code.
+pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
+    let ast = syn::parse2::<syn::DeriveInput>(input)?;
+    let attr = ContainerAttr::from_attrs("graphql", &ast.attrs)?;
+
+    let data = if let syn::Data::Struct(data) = &ast.data {
+        data
+    } else {
+        return Err(ERR.custom_error(ast.span(), "can only be derived on structs"));
+    };
+
+    let renaming = attr
+        .rename_fields
+        .map(SpanContainer::into_inner)
+        .unwrap_or(rename::Policy::CamelCase);
+
+    let fields = data
+        .fields
+        .iter()
+        .filter_map(|f| parse_field(f, renaming))
+        .collect::<Vec<_>>();
+
+    proc_macro_error::abort_if_dirty();
+
+    let unique_fields = fields.iter().map(|f| &f.name).collect::<HashSet<_>>();
+    if unique_fields.len() != fields.len() {
+        return Err(ERR.custom_error(
+            data.fields.span(),
+            "expected all fields to have unique names",
+        ));
+    }
+
+    let scalar = scalar::Type::parse(attr.scalar.as_deref(), &ast.generics);
+
+    let definition = Definition {
+        ident: ast.ident,
+        generics: ast.generics,
+        scalar,
+        fields,
+    };
+
+    Ok(definition.into_token_stream())
+}
+
+/// Parses a [`FieldDefinition`] from the given struct field definition.
+///
+/// Returns [`None`] if the parsing fails, or the field is ignored.
+fn parse_field(f: &syn::Field, renaming: rename::Policy) -> Option<FieldDefinition> {
+    let field_attr = FieldAttr::from_attrs("graphql", &f.attrs)
+        .map_err(|e| proc_macro_error::emit_error!(e))
+        .ok()?;
+
+    let ident = f.ident.as_ref().or_else(|| {
+        ERR.emit_custom(f.span(), "expected named struct field");
+        None
+    })?;
+
+    if field_attr.ignore.is_some() {
+        return None;
+    }
+
+    let name = field_attr
+        .name
+        .map_or_else(
+            || renaming.apply(&ident.unraw().to_string()),
+            SpanContainer::into_inner,
+        )
+        .into_boxed_str();
+
+    Some(FieldDefinition {
+        ident: ident.clone(),
+        ty: f.ty.clone(),
+        name,
+    })
+}
@@ -0,0 +1,292 @@
+//! Code generation for typed [GraphQL variables][0].
+//!
+//! [0]: https://spec.graphql.org/October2021#sec-Language.Variables
+
+pub(crate) mod derive;
+
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_quote,
+    spanned::Spanned as _,
+    token,
+};
+
+use crate::common::{
+    filter_attrs,
+    parse::{
+        attr::{err, OptionExt as _},
+        ParseBufferExt as _,
+    },
+    rename, scalar, SpanContainer,
+};
+
+/// Available arguments behind `#[graphql]` attribute placed on a Rust struct
+/// definition, when generating code for [GraphQL variables][0].
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Language.Variables
+#[derive(Debug, Default)]
+struct ContainerAttr {
+    /// Explicitly specified type (or type parameter with its bounds) of
+    /// [`ScalarValue`] to produce [GraphQL variables][0] with.
+    ///
+    /// If [`None`], then generated code will be generic over any
+    /// [`ScalarValue`] type.
+    ///
+    /// [`ScalarValue`]: juniper::ScalarValue
+    /// [0]: https://spec.graphql.org/October2021#sec-Language.Variables
+    scalar: Option<SpanContainer<scalar::AttrValue>>,
+
+    /// Explicitly specified [`rename::Policy`] for all [GraphQL variables][0].
+    ///
+    /// If [`None`], then the [`rename::Policy::CamelCase`] will be applied by
+    /// default.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Language.Variables
+    rename_fields: Option<SpanContainer<rename::Policy>>,
+}
+
+impl Parse for ContainerAttr {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let mut out = Self::default();
+        while !input.is_empty() {
+            let ident = input.parse_any_ident()?;
+            match ident.to_string().as_str() {
+                "scalar" | "Scalar" | "ScalarValue" => {
+                    input.parse::<token::Eq>()?;
+                    let scl = input.parse::<scalar::AttrValue>()?;
+                    out.scalar
+                        .replace(SpanContainer::new(ident.span(), Some(scl.span()), scl))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "rename_all" => {
+                    input.parse::<token::Eq>()?;
+                    let val = input.parse::<syn::LitStr>()?;
+                    out.rename_fields
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(val.span()),
+                            val.try_into()?,
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?;
+                }
+                name => {
+                    return Err(err::unknown_arg(&ident, name));
+                }
+            }
+            input.try_parse::<token::Comma>()?;
+        }
+        Ok(out)
+    }
+}
+
+impl ContainerAttr {
+    /// Tries to merge two [`ContainerAttr`]s into a single one, reporting about
+    /// duplicates, if any.
+    fn try_merge(self, mut another: Self) -> syn::Result<Self> {
+        Ok(Self {
+            scalar: try_merge_opt!(scalar: self, another),
+            rename_fields: try_merge_opt!(rename_fields: self, another),
+        })
+    }
+
+    /// Parses [`ContainerAttr`] from the given multiple `name`d
+    /// [`syn::Attribute`]s placed on a struct definition.
+    fn from_attrs(name: &str, attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        filter_attrs(name, attrs)
+            .map(|attr| attr.parse_args())
+            .try_fold(Self::default(), |prev, curr| prev.try_merge(curr?))
+    }
+}
+
+/// Available arguments behind `#[graphql]` attribute when generating code for
+/// a single [GraphQL variable][0].
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Language.Variables
+#[derive(Debug, Default)]
+struct FieldAttr {
+    /// Explicitly specified name of this [GraphQL variable][0].
+    ///
+    /// If [`None`], then Rust struct field name will be used by default.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Language.Variables
+    name: Option<SpanContainer<String>>,
+
+    /// Explicitly specified marker for the Rust struct field to be ignored and
+    /// not included into the produced [GraphQL variables][0].
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Language.Variables
+    ignore: Option<SpanContainer<syn::Ident>>,
+}
+
+impl Parse for FieldAttr {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let mut out = Self::default();
+        while !input.is_empty() {
+            let ident = input.parse_any_ident()?;
+            match ident.to_string().as_str() {
+                "name" => {
+                    input.parse::<token::Eq>()?;
+                    let name = input.parse::<syn::LitStr>()?;
+                    out.name
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(name.span()),
+                            name.value(),
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "ignore" | "skip" => out
+                    .ignore
+                    .replace(SpanContainer::new(ident.span(), None, ident.clone()))
+                    .none_or_else(|_| err::dup_arg(&ident))?,
+                name => {
+                    return Err(err::unknown_arg(&ident, name));
+                }
+            }
+            input.try_parse::<token::Comma>()?;
+        }
+        Ok(out)
+    }
+}
+
+impl FieldAttr {
+    /// Tries to merge two [`FieldAttr`]s into a single one, reporting about
+    /// duplicates, if any.
+    fn try_merge(self, mut another: Self) -> syn::Result<Self> {
+        Ok(Self {
+            name: try_merge_opt!(name: self, another),
+            ignore: try_merge_opt!(ignore: self, another),
+        })
+    }
+
+    /// Parses [`FieldAttr`] from the given multiple `name`d [`syn::Attribute`]s
+    /// placed on a struct field.
+    fn from_attrs(name: &str, attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        filter_attrs(name, attrs)
+            .map(|attr| attr.parse_args())
+            .try_fold(Self::default(), |prev, curr| prev.try_merge(curr?))
+    }
+}
+
+/// Representation of a single [GraphQL variable][0] for code generation.
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Language.Variables
+#[derive(Debug)]
+struct FieldDefinition {
+    /// [`Ident`] of the Rust struct field behind this [GraphQL variable][0].
+    ///
+    /// [`Ident`]: syn::Ident
+    /// [0]: https://spec.graphql.org/October2021#sec-Language.Variables
+    ident: syn::Ident,
+
+    /// Rust type of the struct field behind this [GraphQL variable][0].
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Language.Variables
+    ty: syn::Type,
+
+    /// Name of this [GraphQL variable][0] (without the leading `$`).
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Language.Variables
+    name: Box<str>,
+}
+
+/// Representation of typed [GraphQL variables][0] for code generation.
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Language.Variables
+#[derive(Debug)]
+struct Definition {
+    /// [`Ident`] of the Rust struct behind these [GraphQL variables][0].
+    ///
+    /// [`Ident`]: syn::Ident
+    /// [0]: https://spec.graphql.org/October2021#sec-Language.Variables
+    ident: syn::Ident,
+
+    /// [`Generics`] of the Rust struct behind these [GraphQL variables][0].
+    ///
+    /// [`Generics`]: syn::Generics
+    /// [0]: https://spec.graphql.org/October2021#sec-Language.Variables
+    generics: syn::Generics,
+
+    /// [`ScalarValue`] parametrization to produce [GraphQL variables][0] with.
+    ///
+    /// [`ScalarValue`]: juniper::ScalarValue
+    /// [0]: https://spec.graphql.org/October2021#sec-Language.Variables
+    scalar: scalar::Type,
+
+    /// Non-ignored fields of these [GraphQL variables][0].
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Language.Variables
+    fields: Vec<FieldDefinition>,
+}
+
+impl ToTokens for Definition {
+    fn to_tokens(&self, into: &mut TokenStream) {
+        self.impl_from_tokens().to_tokens(into);
+    }
+}
+
+impl Definition {
+    /// Returns generated code implementing [`From`] conversions of the Rust
+    /// struct (and a reference to it) into [`Variables`].
+    ///
+    /// [`Variables`]: juniper::Variables
+    #[must_use]
+    fn impl_from_tokens(&self) -> TokenStream {
+        let ident = &self.ident;
+        let scalar = &self.scalar;
+
+        let mut generics = self.generics.clone();
+        if scalar.is_implicit_generic() {
+            generics.params.push(parse_quote! { #scalar });
+        }
+        if scalar.is_generic() {
+            generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote! { #scalar: ::juniper::ScalarValue });
+        }
+        if let Some(bound) = scalar.bounds() {
+            generics.make_where_clause().predicates.push(bound);
+        }
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+
+        let fields = self.fields.iter().map(|f| {
+            let ident = &f.ident;
+            let name = &f.name;
+
+            // Spanning the conversion to the field type makes the compiler
+            // point to the field, if its type doesn't implement `ToInputValue`.
+            let value = quote_spanned! { f.ty.span() =>
+                ::juniper::ToInputValue::<#scalar>::to_input_value(&vars.#ident)
+            };
+            quote! {
+                (::std::string::String::from(#name), #value)
+            }
+        });
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::std::convert::From<&#ident #ty_generics>
+                for ::juniper::Variables<#scalar>
+                #where_clause
+            {
+                fn from(vars: &#ident #ty_generics) -> Self {
+                    ::std::iter::IntoIterator::into_iter([#( #fields ),*]).collect()
+                }
+            }
+
+            #[automatically_derived]
+            impl #impl_generics ::std::convert::From<#ident #ty_generics>
+                for ::juniper::Variables<#scalar>
+                #where_clause
+            {
+                fn from(vars: #ident #ty_generics) -> Self {
+                    Self::from(&vars)
+                }
+            }
+        }
+    }
+}
@@ -105,6 +105,7 @@ mod graphql_object;
 mod graphql_scalar;
 mod graphql_subscription;
 mod graphql_union;
+mod graphql_variables;
 mod scalar_value;
 
 use proc_macro::TokenStream;
@@ -159,6 +160,50 @@ use proc_macro_error::{proc_macro_error, ResultExt as _};
 /// }
 /// ```
 ///
+/// # Deprecation
+///
+/// A [GraphQL input object field][1] may be deprecated by specifying the
+/// `deprecated` attribute's argument, or with a regular Rust `#[deprecated]`
+/// attribute. Deprecated fields are still accepted in queries, but are hidden
+/// from introspection unless `inputFields(includeDeprecated: true)` is
+/// requested. As required fields cannot be deprecated, only `Option`al fields
+/// or the ones having a `default` value may be.
+///
+/// ```rust
+/// # use juniper::GraphQLInputObject;
+/// #
+/// #[derive(GraphQLInputObject)]
+/// struct Point2D {
+///     x: f64,
+///     #[graphql(deprecated = "Use `x` instead.")]
+///     abscissa: Option<f64>,
+///     #[deprecated]
+///     shift: Option<f64>,
+/// }
+/// ```
+///
+/// # Aliases
+///
+/// To rename a [GraphQL input object field][1] without breaking the clients
+/// still using its old name, specify the old name with the `alias` attribute's
+/// argument (multiple ones are allowed). The field is accepted under any of
+/// its names, while only the canonical one is exposed via introspection.
+/// Providing the field under several names at once results in an input
+/// coercion error. Aliases colliding with a name or an alias of any field
+/// are rejected at compile time.
+///
+/// ```rust
+/// # use juniper::GraphQLInputObject;
+/// #
+/// #[derive(GraphQLInputObject)]
+/// struct Point2D {
+///     #[graphql(alias = "abscissa")]
+///     x: f64,
+///     #[graphql(alias = "ordinate", alias = "yCoord")]
+///     y: f64,
+/// }
+/// ```
+///
 /// # Renaming policy
 ///
 /// By default, all [GraphQL input object fields][1] are renamed in a
@@ -169,7 +214,7 @@ use proc_macro_error::{proc_macro_error, ResultExt as _};
 /// However, if you need for some reason another naming convention, it's
 /// possible to do so by using the `rename_all` attribute's argument. At the
 /// moment, it supports the following policies only: `SCREAMING_SNAKE_CASE`,
-/// `camelCase`, `none` (disables any renaming).
+/// `camelCase`, `snake_case`, `none` (disables any renaming).
 ///
 /// ```rust
 /// # use juniper::GraphQLInputObject;
@@ -209,10 +254,78 @@ use proc_macro_error::{proc_macro_error, ResultExt as _};
 /// }
 /// ```
 ///
+/// # Absent and `null` fields
+///
+/// A field of [`Nullable`] type distinguishes a field not provided at all
+/// ([`Nullable::ImplicitNull`]) from the one explicitly set to `null`
+/// ([`Nullable::ExplicitNull`]), which is handy for partial updates. Both are
+/// collapsed into [`None`] by [`Option`] fields.
+///
+/// ```rust
+/// # use juniper::{GraphQLInputObject, Nullable};
+/// #
+/// #[derive(GraphQLInputObject)]
+/// struct UserPatch {
+///     name: Option<String>,
+///     // `ImplicitNull` keeps the current value, `ExplicitNull` erases it.
+///     nickname: Nullable<String>,
+/// }
+/// ```
+///
+/// # `@oneOf`
+///
+/// To define a [`@oneOf`][3] input object, requiring exactly one of its fields
+/// to be provided with a non-null value, use the `one_of` attribute's argument.
+/// Its fields must be [`Option`]s without a `default` value, so exactly one of
+/// them is [`Some`] once coerced. Providing none or multiple of them results in
+/// an input coercion error.
+///
+/// ```rust
+/// # use juniper::GraphQLInputObject;
+/// #
+/// #[derive(GraphQLInputObject)]
+/// #[graphql(one_of)]
+/// struct UserBy {
+///     id: Option<i32>,
+///     email: Option<String>,
+/// }
+/// ```
+///
+/// # Validation
+///
+/// To check invariants spanning multiple fields, use the `validate_with`
+/// attribute's argument, specifying a function of `fn(&Self) -> Result<(), E>`
+/// signature, where `E` implements [`Display`]. It's called once all the
+/// fields are coerced, so a value rejected by it never reaches a resolver, and
+/// the returned error is reported as the input object coercion failure.
+///
+/// ```rust
+/// # use juniper::GraphQLInputObject;
+/// #
+/// #[derive(GraphQLInputObject)]
+/// #[graphql(validate_with = validate_range)]
+/// struct Range {
+///     start: i32,
+///     end: i32,
+/// }
+///
+/// fn validate_range(range: &Range) -> Result<(), String> {
+///     if range.start > range.end {
+///         return Err(format!("`start` ({}) exceeds `end` ({})", range.start, range.end));
+///     }
+///     Ok(())
+/// }
+/// ```
+///
+/// [`Display`]: std::fmt::Display
+/// [`Nullable`]: juniper::Nullable
+/// [`Nullable::ExplicitNull`]: juniper::Nullable::ExplicitNull
+/// [`Nullable::ImplicitNull`]: juniper::Nullable::ImplicitNull
 /// [`ScalarValue`]: juniper::ScalarValue
 /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
 /// [1]: https://spec.graphql.org/October2021#InputFieldsDefinition
 /// [2]: https://spec.graphql.org/October2021#sec-Scalars
+/// [3]: https://spec.graphql.org/draft#sec-OneOf-Input-Objects
 #[proc_macro_error]
 #[proc_macro_derive(GraphQLInputObject, attributes(graphql))]
 pub fn derive_input_object(input: TokenStream) -> TokenStream {
@@ -221,6 +334,119 @@ pub fn derive_input_object(input: TokenStream) -> TokenStream {
         .into()
 }
 
+/// `#[derive(GraphQLVariables)]` macro for deriving conversion of a Rust
+/// struct into [GraphQL variables][0] (`juniper::Variables`), allowing to
+/// provide variables to an executed operation in a type-safe manner. Each
+/// non-ignored field type must implement `juniper::ToInputValue` (so be a
+/// [GraphQL input object][1], [GraphQL enum][2] or [GraphQL scalar][3], or a
+/// list or `Option` of them), which is checked at compile time.
+///
+/// `From` conversions are derived for both an owned struct and a reference to
+/// it. `None` fields are provided as explicit `null` variable values.
+///
+/// ```rust
+/// use juniper::{
+///     execute_sync, graphql_object, graphql_value, EmptyMutation, EmptySubscription,
+///     GraphQLVariables, RootNode, Variables,
+/// };
+///
+/// #[derive(GraphQLVariables)]
+/// struct SearchVars {
+///     term: String,
+///     max_results: Option<i32>, // will be `$maxResults` in GraphQL operation
+/// }
+///
+/// struct Query;
+///
+/// #[graphql_object]
+/// impl Query {
+///     fn search(term: String, max_results: Option<i32>) -> String {
+///         format!("{term}: {}", max_results.unwrap_or(10))
+///     }
+/// }
+///
+/// let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+/// let vars = SearchVars { term: "juniper".into(), max_results: Some(5) };
+///
+/// let (res, _errors) = execute_sync(
+///     "query($term: String!, $maxResults: Int) { \
+///         search(term: $term, maxResults: $maxResults) \
+///     }",
+///     None,
+///     &schema,
+///     &vars.into(),
+///     &(),
+/// )
+/// .unwrap();
+/// assert_eq!(res, graphql_value!({"search": "juniper: 5"}));
+/// ```
+///
+/// # Custom `ScalarValue`
+///
+/// By default, the derived conversions are generic over any [`ScalarValue`]
+/// type. To produce variables of a concrete [`ScalarValue`] type only (when
+/// some field type implements `juniper::ToInputValue` for it only, for
+/// example), use a `scalar` attribute argument.
+///
+/// ```rust
+/// # use juniper::{DefaultScalarValue, GraphQLVariables};
+/// #
+/// #[derive(GraphQLVariables)]
+/// #[graphql(scalar = DefaultScalarValue)]
+/// struct UserVars {
+///     id: i32,
+/// }
+/// ```
+///
+/// # Renaming policy
+///
+/// By default, all variables are named after the struct fields via `camelCase`
+/// policy. Another policy may be applied via a `rename_all` attribute argument
+/// (supporting `SCREAMING_SNAKE_CASE`, `camelCase`, `snake_case` and `none`
+/// policies), while a single variable may be named explicitly via a `name`
+/// attribute argument on its field.
+///
+/// ```rust
+/// # use juniper::GraphQLVariables;
+/// #
+/// #[derive(GraphQLVariables)]
+/// #[graphql(rename_all = "none")]
+/// struct PageVars {
+///     page_size: i32, // will be `$page_size`
+///     #[graphql(name = "after")]
+///     cursor: Option<String>, // will be `$after`
+/// }
+/// ```
+///
+/// # Ignoring fields
+///
+/// To omit providing a Rust struct field as a variable, use an `ignore` (or
+/// `skip`) attribute argument directly on that field.
+///
+/// ```rust
+/// # use juniper::GraphQLVariables;
+/// #
+/// #[derive(GraphQLVariables)]
+/// struct UserVars {
+///     id: i32,
+///     #[graphql(ignore)]
+///     request_started_at: std::time::Instant,
+/// }
+/// ```
+///
+/// [`ScalarValue`]: juniper::ScalarValue
+/// [0]: https://spec.graphql.org/October2021#sec-Language.Variables
+/// [1]: https://spec.graphql.org/October2021#sec-Input-Objects
+/// [2]: https://spec.graphql.org/October2021#sec-Enums
+/// [3]: https://spec.graphql.org/October2021#sec-Scalars
+#[proc_macro_error]
+#[proc_macro_derive(GraphQLVariables, attributes(graphql))]
+pub fn derive_variables(input: TokenStream) -> TokenStream {
+    graphql_variables::derive::expand(input.into())
+        .unwrap_or_abort()
+        .into()
+}
+
 /// `#[derive(GraphQLEnum)]` macro for deriving a [GraphQL enum][0]
 /// implementation for Rust enums.
 ///
@@ -251,6 +477,9 @@ pub fn derive_input_object(input: TokenStream) -> TokenStream {
 ///
 /// [GraphQL enum value][1] may be deprecated by specifying the `deprecated`
 /// attribute's argument, or with regular a Rust `#[deprecated]` attribute.
+/// Deprecated values are still accepted as input and produced as output, but
+/// are only listed in introspection when requested via
+/// `enumValues(includeDeprecated: true)`.
 ///
 /// ```rust
 /// # #![allow(deprecated)]
@@ -288,7 +517,9 @@ pub fn derive_input_object(input: TokenStream) -> TokenStream {
 /// However, if you need for some reason another naming convention, it's
 /// possible to do so by using the `rename_all` attribute's argument. At the
 /// moment, it supports the following policies only: `SCREAMING_SNAKE_CASE`,
-/// `camelCase`, `none` (disables any renaming).
+/// `camelCase`, `snake_case`, `none` (disables any renaming). An explicitly
+/// specified `name` of an enum variant always takes precedence over the
+/// `rename_all` policy.
 ///
 /// ```rust
 /// # use juniper::GraphQLEnum;
@@ -339,6 +570,26 @@ pub fn derive_input_object(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// # Custom bounds
+///
+/// Additional bounds of the generated implementations may be specified with a
+/// `bound` attribute's argument (in the same way as `serde` does), replacing
+/// the auto-inferred `Self: Sync` one.
+///
+/// ```rust
+/// # use juniper::GraphQLEnum;
+/// #
+/// #[derive(GraphQLEnum)]
+/// #[graphql(bound = "T: Sync")]
+/// enum Episode<'a, T> {
+///     NewHope,
+///     Empire,
+///     Jedi,
+///     #[graphql(ignore)]
+///     Legends(&'a T),
+/// }
+/// ```
+///
 /// [`ScalarValue`]: juniper::ScalarValue
 /// [0]: https://spec.graphql.org/October2021#sec-Enums
 /// [1]: https://spec.graphql.org/October2021#sec-Enum-Value
@@ -400,6 +651,25 @@ pub fn derive_enum(input: TokenStream) -> TokenStream {
 /// struct UserId(String);
 /// ```
 ///
+/// Description, computed when building the schema, may be provided via
+/// `#[graphql(description_with = <fn path>)]` attribute instead, with the
+/// function returning a [`String`]:
+/// ```rust
+/// # use juniper::GraphQLScalar;
+/// #
+/// #[derive(GraphQLScalar)]
+/// #[graphql(
+///     description_with = describe,
+///     specified_by_url = "https://tools.ietf.org/html/rfc4122",
+///     transparent,
+/// )]
+/// struct UserId(String);
+///
+/// fn describe() -> String {
+///     format!("User ID (v{})", env!("CARGO_PKG_VERSION"))
+/// }
+/// ```
+///
 /// All of the methods inherited from `Newtype`'s field may also be overridden
 /// with the attributes described below.
 ///
@@ -421,6 +691,37 @@ pub fn derive_enum(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// If the output depends on the execution [`Context`], the custom function may
+/// accept it as its second argument, once `#[graphql(with_context)]` attribute
+/// is specified along with the `#[graphql(context = <type>)]` one:
+/// ```rust
+/// # use juniper::{GraphQLScalar, ScalarValue, Value};
+/// #
+/// struct Locale {
+///     currency_sign: &'static str,
+/// }
+///
+/// impl juniper::Context for Locale {}
+///
+/// #[derive(GraphQLScalar)]
+/// #[graphql(
+///     context = Locale,
+///     to_output_with = to_output,
+///     with_context,
+///     transparent,
+/// )]
+/// struct Money(i32);
+///
+/// fn to_output<S: ScalarValue>(v: &Money, ctx: &Locale) -> Value<S> {
+///     Value::scalar(format!("{}{}", ctx.currency_sign, v.0))
+/// }
+/// ```
+/// > __NOTE:__ As there is no [`Context`] available when converting a
+/// >           [GraphQL scalar][0] into an [`InputValue`] (for example, for an
+/// >           argument's default value), the [`ToInputValue`] implementation
+/// >           is delegated to the inner field, so `with_context` attribute
+/// >           argument requires the `transparent` one.
+///
 /// # Custom parsing
 ///
 /// Customization of a [GraphQL scalar][0] type parsing is possible via
@@ -502,6 +803,58 @@ pub fn derive_enum(input: TokenStream) -> TokenStream {
 /// > __NOTE:__ Once we provide all 3 custom functions, there is no sense to
 /// >           follow [`Newtype` pattern][1] anymore.
 ///
+/// # Accepting `null`
+///
+/// By default, a `null` (either explicit or implicit) is never passed to the
+/// `from_input()` function: a nullable [GraphQL scalar][0] (represented with an
+/// [`Option`]) is resolved as [`None`] right away. To compute a value from a
+/// `null` instead (for example, to provide a default for an omitted nullable
+/// argument), the `#[graphql(accepts_null)]` attribute argument may be
+/// specified:
+/// ```rust
+/// # use juniper::{GraphQLScalar, InputValue, ScalarValue};
+/// #
+/// #[derive(GraphQLScalar)]
+/// #[graphql(from_input_with = Self::from_input, accepts_null, transparent)]
+/// struct Limit(i32);
+///
+/// impl Limit {
+///     fn from_input<S: ScalarValue>(input: &InputValue<S>) -> Result<Self, String> {
+///         if input.is_null() {
+///             return Ok(Self(10));
+///         }
+///         input.as_int_value()
+///             .map(Self)
+///             .ok_or_else(|| format!("Expected `Int`, found: {input}"))
+///     }
+/// }
+/// ```
+/// > __NOTE:__ A `Limit` argument is still a non-null one in GraphQL schema, so
+/// >           only the `Option<Limit>` one will receive `null`s, always being
+/// >           resolved as [`Some`].
+///
+/// # Accepting lists and objects
+///
+/// By default, a [GraphQL scalar][0] accepts neither input lists nor objects,
+/// rejecting them during validation. To have them passed to the `from_input()`
+/// function as is, the `#[graphql(structured)]` attribute argument may be
+/// specified:
+/// ```rust
+/// # use juniper::{GraphQLScalar, InputValue, ScalarValue};
+/// #
+/// #[derive(GraphQLScalar)]
+/// #[graphql(from_input_with = Self::from_input, structured, transparent)]
+/// struct Raw(String);
+///
+/// impl Raw {
+///     fn from_input<S: ScalarValue>(input: &InputValue<S>) -> Result<Self, String> {
+///         Ok(Self(input.to_string()))
+///     }
+/// }
+/// ```
+/// > __WARNING:__ Such [GraphQL scalar][0] bypasses the type safety guaranteed
+/// >              by GraphQL, so should be used sparingly.
+///
 /// # All at once
 ///
 /// Instead of providing all custom functions separately, it's possible to
@@ -590,7 +943,9 @@ pub fn derive_enum(input: TokenStream) -> TokenStream {
 /// # fn main() {}
 /// ```
 ///
-/// At the same time, any custom function still may be specified separately:
+/// At the same time, any custom function still may be specified separately,
+/// taking precedence over the one from the module (so the latter may be
+/// omitted):
 /// ```rust
 /// # use juniper::{
 /// #     GraphQLScalar, InputValue, ParseScalarResult, ScalarValue,
@@ -636,6 +991,29 @@ pub fn derive_enum(input: TokenStream) -> TokenStream {
 /// # fn main() {}
 /// ```
 ///
+/// # Enums with unit variants
+///
+/// If none of the custom functions is provided (and `with_context` attribute
+/// argument isn't specified), an enum with unit variants only is represented
+/// as a [GraphQL scalar][0] accepting and producing string literals only. Each
+/// variant is mapped to its name, unless renamed with a
+/// `#[graphql(rename = "...")]` attribute:
+/// ```rust
+/// # use juniper::GraphQLScalar;
+/// #
+/// #[derive(GraphQLScalar)]
+/// enum Currency {
+///     #[graphql(rename = "USD")]
+///     Usd,
+///     #[graphql(rename = "EUR")]
+///     Eur,
+///     // Represented as "Gbp".
+///     Gbp,
+/// }
+/// ```
+/// Unlike a [GraphQL enum][2], such type remains a `SCALAR` in the schema, and
+/// an unknown string is reported with an error listing all the valid values.
+///
 /// # Custom `ScalarValue`
 ///
 /// By default, this macro generates code, which is generic over a
@@ -651,9 +1029,18 @@ pub fn derive_enum(input: TokenStream) -> TokenStream {
 /// trait bounds via `#[graphql(where(<bounds>))]` attribute, like the
 /// following: `#[graphql(where(S: Trait, Self: fmt::Debug + fmt::Display))]`.
 ///
+/// Alternatively, they may be specified as a string with a `bound` attribute's
+/// argument (in the same way as `serde` does), like the following:
+/// `#[graphql(bound = "T: Trait + Sync")]`. Unlike `where`, this replaces the
+/// auto-inferred `Self: Sync` bound of the generated asynchronous code.
+///
 /// [0]: https://spec.graphql.org/October2021#sec-Scalars
 /// [1]: https://rust-unofficial.github.io/patterns/patterns/behavioural/newtype.html
+/// [2]: https://spec.graphql.org/October2021#sec-Enums
+/// [`Context`]: juniper::Context
+/// [`InputValue`]: juniper::InputValue
 /// [`ScalarValue`]: juniper::ScalarValue
+/// [`ToInputValue`]: juniper::ToInputValue
 #[proc_macro_error]
 #[proc_macro_derive(GraphQLScalar, attributes(graphql))]
 pub fn derive_scalar(input: TokenStream) -> TokenStream {
@@ -762,7 +1149,10 @@ pub fn graphql_scalar(attr: TokenStream, body: TokenStream) -> TokenStream {
 /// To derive a [`ScalarValue`] on enum you should mark the corresponding enum
 /// variants with `as_int`, `as_float`, `as_string`, `into_string`, `as_str` and
 /// `as_bool` attribute argumentes (names correspond to [`ScalarValue`] required
-/// methods).
+/// methods). Variants representing integers wider than 32 bits may be marked
+/// with the optional `as_i64`, `as_u64` and `as_i128` attribute arguments
+/// additionally (falling back to the [`ScalarValue`] default methods, if
+/// omitted), so the stored values are accessible without losing precision.
 ///
 /// ```rust
 /// # use std::fmt;
@@ -773,8 +1163,9 @@ pub fn graphql_scalar(attr: TokenStream, body: TokenStream) -> TokenStream {
 /// #[derive(Clone, Debug, PartialEq, ScalarValue, Serialize)]
 /// #[serde(untagged)]
 /// enum MyScalarValue {
-///     #[value(as_float, as_int)]
+///     #[value(as_float, as_int, as_i64)]
 ///     Int(i32),
+///     #[value(as_i64, as_u64, as_i128)]
 ///     Long(i64),
 ///     #[value(as_float)]
 ///     Float(f64),
@@ -929,6 +1320,174 @@ pub fn derive_scalar_value(input: TokenStream) -> TokenStream {
 /// >           actually used at a runtime. But no-one is stopping you from
 /// >           implementing trait manually for your own usage.
 ///
+/// # Downcasting
+///
+/// The generated enum provides an `as_*()` method for each implementer (named
+/// after its type in `snake_case`), returning a reference to it, if the value
+/// is the one:
+///
+/// ```rust
+/// # use juniper::{graphql_interface, GraphQLObject};
+/// #
+/// #[graphql_interface(for = [Human, Droid])]
+/// struct Character {
+///     id: String,
+/// }
+///
+/// #[derive(GraphQLObject)]
+/// #[graphql(impl = CharacterValue)]
+/// struct Human {
+///     id: String,
+///     home_planet: String,
+/// }
+///
+/// #[derive(GraphQLObject)]
+/// #[graphql(impl = CharacterValue)]
+/// struct Droid {
+///     id: String,
+///     primary_function: String,
+/// }
+///
+/// let character = CharacterValue::from(Human {
+///     id: "human-32".into(),
+///     home_planet: "earth".into(),
+/// });
+///
+/// assert_eq!(
+///     character.as_human().map(|h| h.home_planet.as_str()),
+///     Some("earth"),
+/// );
+/// assert!(character.as_droid().is_none());
+/// ```
+///
+/// # Trait objects
+///
+/// With a `dyn` attribute's argument, a [GraphQL interface][1] defined on a
+/// trait may also be resolved from [trait objects][3] of this trait (like
+/// `Box<dyn Character + Send + Sync>`), in addition to the generated enum.
+/// The trait should be [object safe][2] then and is implemented by the
+/// implementers manually.
+///
+/// To determine the concrete implementer (the `__typename`) and to resolve
+/// fields, the macro adds a hidden supertrait to the trait, exposing the
+/// value behind the [trait object][3] as its concrete [GraphQL object][5].
+/// So, all the fields (including the ones of the [GraphQL interface][1]) are
+/// resolved by the implementer's own resolvers, exactly as if it was returned
+/// directly. This requires implementers to have the same [`Context`], and the
+/// trait to be non-generic and to use a concrete [`ScalarValue`] type
+/// ([`DefaultScalarValue`] if no `scalar` is specified).
+///
+/// ```rust
+/// # use juniper::{graphql_interface, graphql_object, DefaultScalarValue, GraphQLObject};
+/// #
+/// #[graphql_interface(dyn, for = [Human, Droid])]
+/// trait Character {
+///     fn id(&self) -> &str;
+/// }
+///
+/// #[derive(GraphQLObject)]
+/// #[graphql(impl = CharacterValue, scalar = DefaultScalarValue)]
+/// struct Human {
+///     id: String,
+///     home_planet: String,
+/// }
+/// impl Character for Human {
+///     fn id(&self) -> &str {
+///         &self.id
+///     }
+/// }
+///
+/// #[derive(GraphQLObject)]
+/// #[graphql(impl = CharacterValue, scalar = DefaultScalarValue)]
+/// struct Droid {
+///     id: String,
+///     primary_function: String,
+/// }
+/// impl Character for Droid {
+///     fn id(&self) -> &str {
+///         &self.id
+///     }
+/// }
+///
+/// struct Query;
+///
+/// #[graphql_object(scalar = DefaultScalarValue)]
+/// impl Query {
+///     fn hero(is_human: bool) -> Box<dyn Character + Send + Sync> {
+///         if is_human {
+///             Box::new(Human {
+///                 id: "human-32".into(),
+///                 home_planet: "earth".into(),
+///             })
+///         } else {
+///             Box::new(Droid {
+///                 id: "droid-99".into(),
+///                 primary_function: "run".into(),
+///             })
+///         }
+///     }
+/// }
+/// ```
+///
+/// # Async fields
+///
+/// Trait methods may be declared as `async fn`. Such a method is desugared
+/// into a regular `fn` returning a [`BoxFuture`] (borrowing the receiver),
+/// which keeps the trait [object safe][2] and compatible with Rust versions
+/// lacking `async fn` in traits. So, the manual implementations of the trait
+/// return a boxed future as well.
+///
+/// Note, that [GraphQL interface][1] fields are resolved by the implementers'
+/// own resolvers, which may be `async fn`s regardless of whether the trait
+/// method is `async`, and so, are executed with [`execute()`] only (resolving
+/// them with [`execute_sync()`] results in a field error).
+///
+/// As async execution may happen on multiple threads, the futures returned by
+/// the resolvers must be [`Send`]. This requires implementers and the
+/// [`Context`] to be [`Sync`], the values held across `.await` points to be
+/// [`Send`], and [trait objects][3] to be declared as
+/// `dyn Character + Send + Sync`.
+///
+/// ```rust
+/// # use juniper::{graphql_interface, graphql_object, BoxFuture};
+/// #
+/// #[graphql_interface(for = [Human, Droid])]
+/// trait Character {
+///     async fn name(&self) -> String;
+/// }
+///
+/// struct Human {
+///     id: i32,
+/// }
+///
+/// #[graphql_object(impl = CharacterValue)]
+/// impl Human {
+///     async fn name(&self) -> String {
+///         // Some DB lookup here...
+///         format!("Human #{}", self.id)
+///     }
+/// }
+///
+/// struct Droid {
+///     id: i32,
+/// }
+///
+/// #[graphql_object(impl = CharacterValue)]
+/// impl Droid {
+///     async fn name(&self) -> String {
+///         // Some DB lookup here...
+///         format!("Droid #{}", self.id)
+///     }
+/// }
+///
+/// // Implementing the trait manually is optional.
+/// impl Character for Droid {
+///     fn name(&self) -> BoxFuture<'_, String> {
+///         Box::pin(async move { format!("Droid #{}", self.id) })
+///     }
+/// }
+/// ```
+///
 /// # Custom name, description, deprecation and argument defaults
 ///
 /// The name of [GraphQL interface][1], its field, or a field argument may be overridden with a
@@ -1098,7 +1657,7 @@ pub fn derive_scalar_value(input: TokenStream) -> TokenStream {
 /// However, if you need for some reason apply another naming convention, it's
 /// possible to do by using `rename_all` attribute's argument. At the moment it
 /// supports the following policies only: `SCREAMING_SNAKE_CASE`, `camelCase`,
-/// `none` (disables any renaming).
+/// `snake_case`, `none` (disables any renaming).
 ///
 /// ```rust
 /// # use juniper::{graphql_interface, graphql_object};
@@ -1281,7 +1840,11 @@ pub fn derive_scalar_value(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// [`BoxFuture`]: juniper::BoxFuture
 /// [`Context`]: juniper::Context
+/// [`DefaultScalarValue`]: juniper::DefaultScalarValue
+/// [`execute()`]: juniper::execute
+/// [`execute_sync()`]: juniper::execute_sync
 /// [`Executor`]: juniper::Executor
 /// [`ScalarValue`]: juniper::ScalarValue
 /// [0]: https://spec.graphql.org/October2021
@@ -1289,6 +1852,7 @@ pub fn derive_scalar_value(input: TokenStream) -> TokenStream {
 /// [2]: https://doc.rust-lang.org/stable/reference/items/traits.html#object-safety
 /// [3]: https://doc.rust-lang.org/stable/reference/types/trait-object.html
 /// [4]: https://doc.rust-lang.org/stable/std/primitive.unit.html
+/// [5]: https://spec.graphql.org/October2021#sec-Objects
 #[proc_macro_error]
 #[proc_macro_attribute]
 pub fn graphql_interface(attr: TokenStream, body: TokenStream) -> TokenStream {
@@ -1405,7 +1969,7 @@ pub fn derive_interface(body: TokenStream) -> TokenStream {
 /// However, if you need for some reason apply another naming convention, it's
 /// possible to do by using `rename_all` attribute's argument. At the moment it
 /// supports the following policies only: `SCREAMING_SNAKE_CASE`, `camelCase`,
-/// `none` (disables any renaming).
+/// `snake_case`, `none` (disables any renaming).
 ///
 /// ```
 /// # use juniper::GraphQLObject;
@@ -1461,6 +2025,48 @@ pub fn derive_interface(body: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// Unlike `#[graphql_object]` macro, the [`ScalarValue`] type is never inferred
+/// here, as struct fields cannot accept an [`Executor`]. So, if any field has a
+/// type implementing [`GraphQLType`] for a concrete [`ScalarValue`] only, the
+/// `scalar` attribute's argument should be specified explicitly. Otherwise,
+/// compilation fails with unsatisfied trait bound errors (like
+/// `Droid: IsOutputType<__S>`), which don't mention the `scalar` argument.
+///
+/// # Custom bounds
+///
+/// Generic parameters are not bound automatically in the generated code, while
+/// its asynchronous parts require `Self: Sync`. Additional bounds of the
+/// generated implementations may be specified with a `bound` attribute's
+/// argument (in the same way as `serde` does), so they're not required to be
+/// put on the struct itself. When specified, these bounds replace the
+/// auto-inferred `Self: Sync` one.
+///
+/// ```
+/// # use juniper::{
+/// #     macros::reflect::{BaseSubTypes, BaseType, WrappedType},
+/// #     marker::IsOutputType,
+/// #     DefaultScalarValue, GraphQLObject, GraphQLType, GraphQLValueAsync,
+/// # };
+/// #
+/// trait Item:
+///     GraphQLType<DefaultScalarValue, Context = (), TypeInfo = ()>
+///     + GraphQLValueAsync<DefaultScalarValue>
+///     + IsOutputType<DefaultScalarValue>
+///     + BaseType<DefaultScalarValue>
+///     + BaseSubTypes<DefaultScalarValue>
+///     + WrappedType<DefaultScalarValue>
+/// {
+/// }
+///
+/// #[derive(GraphQLObject)]
+/// #[graphql(scalar = DefaultScalarValue, bound = "T: Item + Sync")]
+/// struct Page<'i, T> {
+///     items: &'i [T],
+/// }
+/// ```
+///
+/// [`Executor`]: juniper::Executor
+/// [`GraphQLType`]: juniper::GraphQLType
 /// [`ScalarValue`]: juniper::ScalarValue
 /// [1]: https://spec.graphql.org/October2021#sec-Objects
 #[proc_macro_error]
@@ -1571,6 +2177,14 @@ pub fn derive_object(body: TokenStream) -> TokenStream {
 /// attribute argument (if no exact value is specified then [`Default::default`]
 /// is used).
 ///
+/// Alternatively, a `default_with` attribute argument specifies a function,
+/// computing the value from the [`Context`] lazily, only when the argument is
+/// omitted or `null`. Such an argument is represented as a nullable one
+/// without any default value in GraphQL schema, as its default is dynamic.
+/// The `default_with` function takes precedence over a scalar accepting
+/// `null`s (see `accepts_null` attribute argument of the
+/// `#[derive(GraphQLScalar)]` macro).
+///
 /// ```
 /// # use juniper::graphql_object;
 /// #
@@ -1615,6 +2229,152 @@ pub fn derive_object(body: TokenStream) -> TokenStream {
 ///         "Deprecated"
 ///     }
 /// }
+///
+/// struct Context {
+///     page_size: i32,
+/// }
+///
+/// impl juniper::Context for Context {}
+///
+/// fn default_page_size(ctx: &Context) -> i32 {
+///     ctx.page_size
+/// }
+///
+/// struct Page;
+///
+/// #[graphql_object(context = Context)]
+/// impl Page {
+///     fn size(
+///         // Called only when the `size` argument is omitted.
+///         #[graphql(default_with = default_page_size)] size: i32,
+///     ) -> i32 {
+///         size
+///     }
+/// }
+/// ```
+///
+/// # Complexity
+///
+/// For query cost analysis (see `RootNode::estimate_cost()`), a field of
+/// [GraphQL object][1] costs `1` plus the cost of its sub-selection by default.
+/// A fixed weight may be specified instead with a `complexity` attribute
+/// argument, while a `complexity_with` attribute argument specifies a function
+/// computing the whole cost of the field from its arguments and the cost of its
+/// sub-selection (to account the number of requested list items, for example).
+///
+/// ```
+/// # use juniper::{graphql_object, Arguments, ScalarValue};
+/// #
+/// fn per_item<S: ScalarValue>(args: &Arguments<S>, child_cost: u64) -> u64 {
+///     let first = args.get::<i32>("first").ok().flatten().unwrap_or_default();
+///     u64::try_from(first).unwrap_or_default() * child_cost + 1
+/// }
+///
+/// struct Query;
+///
+/// #[graphql_object]
+/// impl Query {
+///     #[graphql(complexity = 10)]
+///     fn report() -> &'static str {
+///         "expensive"
+///     }
+///
+///     #[graphql(complexity_with = per_item)]
+///     fn numbers(first: i32) -> Vec<i32> {
+///         (0..first).collect()
+///     }
+/// }
+/// ```
+///
+/// # Resolution policy
+///
+/// By default, when executing asynchronously, sibling fields of a
+/// [GraphQL object][1] are resolved concurrently. If its resolvers mutate some
+/// shared state, and so should run one-by-one in the order they're selected,
+/// use a `resolve = serial` attribute argument (`resolve = concurrent` is the
+/// default). Serial resolution slows down resolving the [GraphQL object][1],
+/// as its fields (and their sub-selections) don't overlap while being awaited
+/// anymore, so should be used only where really required. Fields of nested
+/// [GraphQL objects][1] are not affected, and synchronous execution always
+/// resolves fields serially.
+///
+/// ```
+/// # use std::sync::Mutex;
+/// # use juniper::graphql_object;
+/// #
+/// struct Context {
+///     log: Mutex<Vec<&'static str>>,
+/// }
+///
+/// impl juniper::Context for Context {}
+///
+/// struct Query;
+///
+/// #[graphql_object(context = Context, resolve = serial)]
+/// impl Query {
+///     async fn first(context: &Context) -> i32 {
+///         let mut log = context.log.lock().unwrap();
+///         log.push("first");
+///         log.len() as i32
+///     }
+///
+///     async fn second(context: &Context) -> i32 {
+///         let mut log = context.log.lock().unwrap();
+///         log.push("second");
+///         log.len() as i32
+///     }
+/// }
+/// ```
+///
+/// # Overriding `__typename`
+///
+/// A [GraphQL object][1] adapting data of several types (a polymorphic
+/// adapter, for example) may report a `__typename` different from its own
+/// name, computed by a function specified with a `typename_with` attribute
+/// argument (it works for `#[derive(GraphQLObject)]` too). The function accepts
+/// the [GraphQL object][1] and its context, and must return the name of an
+/// object type registered in the schema, being a possible type of the same
+/// interface or union the [GraphQL object][1] belongs to, otherwise resolving
+/// `__typename` fails with a field error. Only the reported `__typename` is
+/// affected, while fields and fragments are still resolved against the
+/// [GraphQL object][1] itself.
+///
+/// ```
+/// # use juniper::{graphql_object, GraphQLUnion};
+/// #
+/// fn typename(record: &Record, _: &()) -> &'static str {
+///     if record.archived {
+///         "ArchivedRecord"
+///     } else {
+///         "Record"
+///     }
+/// }
+///
+/// struct Record {
+///     archived: bool,
+/// }
+///
+/// #[graphql_object(typename_with = typename)]
+/// impl Record {
+///     fn archived(&self) -> bool {
+///         self.archived
+///     }
+/// }
+///
+/// struct ArchivedRecord;
+///
+/// #[graphql_object]
+/// impl ArchivedRecord {
+///     fn archived() -> bool {
+///         true
+///     }
+/// }
+///
+/// #[derive(GraphQLUnion)]
+/// enum AnyRecord {
+///     Record(Record),
+///     Archived(ArchivedRecord),
+/// }
 /// ```
 ///
 /// # Renaming policy
@@ -1627,7 +2387,7 @@ pub fn derive_object(body: TokenStream) -> TokenStream {
 /// However, if you need for some reason apply another naming convention, it's
 /// possible to do by using `rename_all` attribute's argument. At the moment it
 /// supports the following policies only: `SCREAMING_SNAKE_CASE`, `camelCase`,
-/// `none` (disables any renaming).
+/// `snake_case`, `none` (disables any renaming).
 ///
 /// ```
 /// # use juniper::graphql_object;
@@ -1653,7 +2413,13 @@ pub fn derive_object(body: TokenStream) -> TokenStream {
 /// # Ignoring methods
 ///
 /// To omit some method to be assumed as a [GraphQL object][1] field and ignore
-/// it, use an `ignore` attribute's argument directly on that method.
+/// it, use an `ignore` (or `skip`) attribute's argument directly on that
+/// method.
+///
+/// Ignored methods are left as regular Rust methods of the type, so may have
+/// any signature (a `&mut self` receiver, arguments not being GraphQL input
+/// types, generics, etc.) and still be called from the other methods,
+/// including the ones resolving GraphQL fields.
 ///
 /// ```
 /// # use juniper::graphql_object;
@@ -1662,13 +2428,62 @@ pub fn derive_object(body: TokenStream) -> TokenStream {
 ///
 /// #[graphql_object]
 /// impl Human {
-///     fn id(&self) -> &str {
-///         &self.0
+///     fn id(&self) -> String {
+///         Self::prefixed("human", &self.0)
 ///     }
 ///
 ///     #[graphql(ignore)]
 ///     fn kaboom(&mut self) {}
+///
+///     #[graphql(skip)]
+///     fn prefixed(prefix: &str, id: impl AsRef<str>) -> String {
+///         format!("{prefix}-{}", id.as_ref())
+///     }
+/// }
+/// ```
+///
+/// # Splitting fields across `impl` blocks
+///
+/// Fields of a [GraphQL object][1] may be defined in several `impl` blocks
+/// (placed in different modules, for example). An additional block is marked
+/// with an `extension` attribute argument naming a marker type, which the
+/// macro declares as `pub` alongside the block. The [GraphQL object][1] lists
+/// all its marker types in an `extensions` attribute argument (it works for
+/// `#[derive(GraphQLObject)]` too), and exposes the fields of all the blocks,
+/// both in introspection and execution, and when implementing
+/// [GraphQL interfaces][2].
+///
+/// Every block infers or specifies the [`Context`] and [`ScalarValue`] on its
+/// own, so they must match the ones of the [GraphQL object][1]. Field names
+/// must be unique across all the blocks, otherwise the code fails to compile
+/// with "conflicting implementations" errors.
+///
+/// ```
+/// # use juniper::graphql_object;
+/// #
+/// mod billing {
+///     use juniper::graphql_object;
+///
+///     #[graphql_object(extension = UserBilling)]
+///     impl super::User {
+///         fn balance(&self) -> f64 {
+///             100.0
+///         }
+///     }
 /// }
+///
+/// struct User {
+///     id: i32,
+/// }
+///
+/// #[graphql_object(extensions = [billing::UserBilling])]
+/// impl User {
+///     fn id(&self) -> i32 {
+///         self.id
+///     }
+/// }
+/// #
+/// # fn main() {}
 /// ```
 ///
 /// # Custom context
@@ -1781,6 +2596,46 @@ pub fn derive_object(body: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// Similarly to the [`Context`] type, the [`ScalarValue`] type is inferred
+/// from signatures of `impl` block methods, whenever any of them accepts an
+/// [`Executor`] parametrized with a concrete [`ScalarValue`] type. Types of
+/// fields are not considered, as it cannot be determined which
+/// [`ScalarValue`] types they implement [`GraphQLType`] for. Compilation fails
+/// if different methods accept [`Executor`]s of different [`ScalarValue`]
+/// types, so the `scalar` attribute's argument should be specified then.
+///
+/// The `scalar` attribute's argument should be specified as well, whenever a
+/// field has a type implementing [`GraphQLType`] for a concrete
+/// [`ScalarValue`] only, and nothing infers it. Otherwise, compilation fails
+/// with unsatisfied trait bound errors (like `Droid: IsOutputType<__S>`),
+/// which don't mention the `scalar` argument.
+///
+/// ```
+/// # use juniper::{graphql_object, DefaultScalarValue, Executor, GraphQLObject};
+/// #
+/// struct Human(String);
+///
+/// // NOTICE: `scalar = DefaultScalarValue` is inferred from `Executor` here.
+/// #[graphql_object]
+/// impl Human {
+///     fn id(&self, _executor: &Executor<'_, '_, (), DefaultScalarValue>) -> &str {
+///         &self.0
+///     }
+///
+///     fn helper(&self) -> Droid {
+///         Droid {
+///             id: self.0.clone(),
+///         }
+///     }
+/// }
+/// #
+/// # #[derive(GraphQLObject)]
+/// # #[graphql(scalar = DefaultScalarValue)]
+/// # struct Droid {
+/// #     id: String,
+/// # }
+/// ```
+///
 /// [`Context`]: juniper::Context
 /// [`Executor`]: juniper::Executor
 /// [`GraphQLType`]: juniper::GraphQLType
@@ -1788,6 +2643,7 @@ pub fn derive_object(body: TokenStream) -> TokenStream {
 /// [`ScalarValue`]: juniper::ScalarValue
 /// [0]: https://spec.graphql.org/October2021
 /// [1]: https://spec.graphql.org/October2021#sec-Objects
+/// [2]: https://spec.graphql.org/October2021#sec-Interfaces
 #[proc_macro_error]
 #[proc_macro_attribute]
 pub fn graphql_object(attr: TokenStream, body: TokenStream) -> TokenStream {
@@ -1837,10 +2693,45 @@ pub fn graphql_object(attr: TokenStream, body: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// # Fallible streams
+///
+/// Both the [`Stream`] itself and its items may be fallible. [`Stream`] items
+/// of a `Result<T, E>` type (where `E` implements [`IntoFieldError`]) are
+/// resolved one by one, in the order they're yielded: an `Err` item is
+/// reported in the `errors` of its own event (with a `null` in place of the
+/// field's data), while the subsequent items continue to flow. Returning an
+/// `Err` instead of the [`Stream`] (`Result<impl Stream, E>`) is a terminal
+/// error, ending the subscription with a single event containing it.
+///
+/// Note, that the `null` is placed in the field even if the field's type is
+/// non-`null`able, so the event's data is not nulled out as a whole, as the
+/// [null propagation][2] of GraphQL spec would require.
+///
+/// ```
+/// # use futures::stream::{self, BoxStream};
+/// use juniper::{graphql_subscription, FieldError, FieldResult};
+///
+/// struct Subscription;
+///
+/// #[graphql_subscription]
+/// impl Subscription {
+///     async fn events() -> FieldResult<BoxStream<'static, FieldResult<i32>>> {
+///         Ok(Box::pin(stream::iter(vec![
+///             Ok(1),
+///             // Reported in the second event only. Third event is still sent.
+///             Err(FieldError::from("malformed event")),
+///             Ok(3),
+///         ])))
+///     }
+/// }
+/// ```
+///
 /// [`GraphQLType`]: juniper::GraphQLType
 /// [`GraphQLSubscriptionValue`]: juniper::GraphQLSubscriptionValue
+/// [`IntoFieldError`]: juniper::IntoFieldError
 /// [`Stream`]: futures::Stream
 /// [1]: https://spec.graphql.org/October2021#sec-Subscription
+/// [2]: https://spec.graphql.org/October2021#sec-Handling-Field-Errors
 #[proc_macro_error]
 #[proc_macro_attribute]
 pub fn graphql_subscription(attr: TokenStream, body: TokenStream) -> TokenStream {
@@ -1879,6 +2770,41 @@ pub fn graphql_subscription(attr: TokenStream, body: TokenStream) -> TokenStream
 /// }
 /// ```
 ///
+/// The active enum variant determines both the `__typename` and the fields the union is resolved
+/// with. Variants may hold their [GraphQL objects][2] behind references or smart pointers, and may
+/// depend on the generic parameters of the enum.
+///
+/// ```
+/// # use std::{marker::PhantomData, sync::Arc};
+/// # use juniper::{GraphQLObject, GraphQLUnion};
+/// #
+/// # #[derive(GraphQLObject)]
+/// # struct Human {
+/// #    id: String,
+/// #    home_planet: String,
+/// # }
+/// #
+/// # #[derive(GraphQLObject)]
+/// # struct Droid {
+/// #     id: String,
+/// #     primary_function: String,
+/// # }
+/// #
+/// #[derive(GraphQLObject)]
+/// struct Ewok<T> {
+///     id: String,
+///     #[graphql(ignore)]
+///     _tribe: PhantomData<T>,
+/// }
+///
+/// #[derive(GraphQLUnion)]
+/// enum Character<'a, T> {
+///     Human(&'a Human),
+///     Droid(Arc<Droid>),
+///     Ewok(Ewok<T>),
+/// }
+/// ```
+///
 /// # Custom name and description
 ///
 /// The name of [GraphQL union][1] may be overriden with a `name` attribute's argument. By default,
@@ -2148,6 +3074,7 @@ pub fn graphql_subscription(attr: TokenStream, body: TokenStream) -> TokenStream
 /// [`Context`]: juniper::Context
 /// [`ScalarValue`]: juniper::ScalarValue
 /// [1]: https://spec.graphql.org/October2021#sec-Unions
+/// [2]: https://spec.graphql.org/October2021#sec-Objects
 /// [4]: https://doc.rust-lang.org/stable/std/primitive.unit.html
 #[proc_macro_error]
 #[proc_macro_derive(GraphQLUnion, attributes(graphql))]
@@ -147,6 +147,15 @@ enum Method {
 
     /// `#[value(as_bool)]`.
     AsBool,
+
+    /// `#[value(as_i64)]`.
+    AsI64,
+
+    /// `#[value(as_u64)]`.
+    AsU64,
+
+    /// `#[value(as_i128)]`.
+    AsI128,
 }
 
 /// Available arguments behind `#[value]` attribute when generating code for an
@@ -166,6 +175,9 @@ impl Parse for VariantAttr {
                 "as_string" => Method::AsString,
                 "into_string" => Method::IntoString,
                 "as_bool" => Method::AsBool,
+                "as_i64" => Method::AsI64,
+                "as_u64" => Method::AsU64,
+                "as_i128" => Method::AsI128,
                 name => {
                     return Err(err::unknown_arg(&ident, name));
                 }
@@ -251,48 +263,73 @@ impl Definition {
             (
                 Method::AsInt,
                 quote! { fn as_int(&self) -> Option<i32> },
-                quote! { i32::from(*v) },
+                quote! { Some(i32::from(*v)) },
             ),
             (
                 Method::AsFloat,
                 quote! { fn as_float(&self) -> Option<f64> },
-                quote! { f64::from(*v) },
+                quote! { Some(f64::from(*v)) },
             ),
             (
                 Method::AsStr,
                 quote! { fn as_str(&self) -> Option<&str> },
-                quote! { ::std::convert::AsRef::as_ref(v) },
+                quote! { Some(::std::convert::AsRef::as_ref(v)) },
             ),
             (
                 Method::AsString,
                 quote! { fn as_string(&self) -> Option<String> },
-                quote! { ::std::string::ToString::to_string(v) },
+                quote! { Some(::std::string::ToString::to_string(v)) },
             ),
             (
                 Method::IntoString,
                 quote! { fn into_string(self) -> Option<String> },
-                quote! { ::std::string::String::from(v) },
+                quote! { Some(::std::string::String::from(v)) },
             ),
             (
                 Method::AsBool,
                 quote! { fn as_bool(&self) -> Option<bool> },
-                quote! { bool::from(*v) },
+                quote! { Some(bool::from(*v)) },
+            ),
+            (
+                Method::AsI64,
+                quote! { fn as_i64(&self) -> Option<i64> },
+                quote! { ::std::convert::TryFrom::try_from(*v).ok() },
+            ),
+            (
+                Method::AsU64,
+                quote! { fn as_u64(&self) -> Option<u64> },
+                quote! { ::std::convert::TryFrom::try_from(*v).ok() },
+            ),
+            (
+                Method::AsI128,
+                quote! { fn as_i128(&self) -> Option<i128> },
+                quote! { ::std::convert::TryFrom::try_from(*v).ok() },
             ),
         ];
-        let methods = methods.iter().map(|(m, sig, def)| {
+        let methods = methods.iter().filter_map(|(m, sig, def)| {
+            // Wide integer methods have default implementations in the
+            // `ScalarValue` trait, so are generated only when requested.
+            let is_optional = matches!(m, Method::AsI64 | Method::AsU64 | Method::AsI128);
+            if is_optional && !self.methods.contains_key(m) {
+                return None;
+            }
+
             let arms = self.methods.get(m).into_iter().flatten().map(|v| {
                 let arm = v.match_arm();
-                let call = v.expr.as_ref().map_or(def.clone(), |f| quote! { #f(v) });
-                quote! { #arm => Some(#call), }
+                let call = v
+                    .expr
+                    .as_ref()
+                    .map_or(def.clone(), |f| quote! { Some(#f(v)) });
+                quote! { #arm => #call, }
             });
-            quote! {
+            Some(quote! {
                 #sig {
                     match self {
                         #(#arms)*
                         _ => None,
                     }
                 }
-            }
+            })
         });
 
         quote! {
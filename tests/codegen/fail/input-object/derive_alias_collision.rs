@@ -0,0 +1,10 @@
+use juniper::GraphQLInputObject;
+
+#[derive(GraphQLInputObject)]
+struct Object {
+    test: String,
+    #[graphql(alias = "test")]
+    test2: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use juniper::GraphQLInputObject;
+
+#[derive(GraphQLInputObject)]
+struct Object {
+    #[graphql(deprecated = "Use `id` instead.")]
+    old_id: String,
+    id: Option<String>,
+}
+
+fn main() {}
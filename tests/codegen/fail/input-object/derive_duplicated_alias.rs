@@ -0,0 +1,10 @@
+use juniper::GraphQLInputObject;
+
+#[derive(GraphQLInputObject)]
+struct Object {
+    #[graphql(alias = "other")]
+    #[graphql(alias = "other")]
+    test: String,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use juniper::GraphQLInputObject;
+
+#[derive(GraphQLInputObject)]
+#[graphql(one_of)]
+struct Object {
+    #[graphql(default = 1)]
+    id: Option<i32>,
+    email: Option<String>,
+}
+
+fn main() {}
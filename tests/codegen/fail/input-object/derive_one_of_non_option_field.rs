@@ -0,0 +1,10 @@
+use juniper::GraphQLInputObject;
+
+#[derive(GraphQLInputObject)]
+#[graphql(one_of)]
+struct Object {
+    id: i32,
+    email: Option<String>,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use juniper::graphql_interface;
+
+#[graphql_interface(dyn)]
+struct Character {
+    id: String,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use juniper::graphql_interface;
+
+#[graphql_interface(dyn)]
+trait Character<T> {
+    fn id(&self) -> T;
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use juniper::graphql_object;
+
+struct Obj;
+
+fn default_num(_: &()) -> i32 {
+    5
+}
+
+#[graphql_object]
+impl Obj {
+    fn id(&self, #[graphql(default = 5, default_with = default_num)] num: i32) -> &str {
+        "funA"
+    }
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use juniper::graphql_object;
+
+struct MyScalarValue;
+
+struct Human;
+
+#[graphql_object]
+impl Human {
+    fn id(_executor: &juniper::Executor<'_, '_, (), juniper::DefaultScalarValue>) -> &'static str {
+        "human-32"
+    }
+
+    fn name(_executor: &juniper::Executor<'_, '_, (), MyScalarValue>) -> &'static str {
+        "Han Solo"
+    }
+}
+
+fn main() {}
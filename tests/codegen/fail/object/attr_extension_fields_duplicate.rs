@@ -0,0 +1,20 @@
+use juniper::graphql_object;
+
+struct ObjA;
+
+#[graphql_object(extension = ObjAExt)]
+impl ObjA {
+    #[graphql(name = "id")]
+    fn ext_id(&self) -> &str {
+        "ext"
+    }
+}
+
+#[graphql_object(extensions = [ObjAExt])]
+impl ObjA {
+    fn id(&self) -> &str {
+        "funA"
+    }
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use juniper::graphql_object;
+
+struct ObjA;
+
+#[graphql_object(extension = ObjAExt, name = "Other")]
+impl ObjA {
+    fn id(&self) -> &str {
+        "funA"
+    }
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use juniper::{graphql_object, Arguments, ScalarValue};
+
+struct Obj;
+
+fn cost<S: ScalarValue>(_: &Arguments<S>, child_cost: u64) -> u64 {
+    child_cost
+}
+
+#[graphql_object]
+impl Obj {
+    #[graphql(complexity = 5, complexity_with = cost)]
+    fn id(&self) -> &str {
+        "funA"
+    }
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use juniper::{graphql_object, DefaultScalarValue, GraphQLObject};
+
+#[derive(GraphQLObject)]
+#[graphql(scalar = DefaultScalarValue)]
+struct Droid {
+    id: String,
+}
+
+struct Human;
+
+#[graphql_object]
+impl Human {
+    fn droid() -> Droid {
+        Droid {
+            id: "droid-2000".into(),
+        }
+    }
+}
+
+fn main() {}
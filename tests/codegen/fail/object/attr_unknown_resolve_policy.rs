@@ -0,0 +1,12 @@
+use juniper::graphql_object;
+
+struct Obj;
+
+#[graphql_object(resolve = parallel)]
+impl Obj {
+    fn id(&self) -> &str {
+        "funA"
+    }
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use juniper::{DefaultScalarValue, GraphQLObject};
+
+#[derive(GraphQLObject)]
+#[graphql(scalar = DefaultScalarValue)]
+struct Droid {
+    id: String,
+}
+
+#[derive(GraphQLObject)]
+struct Human {
+    droid: Droid,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use juniper::GraphQLScalar;
+
+#[derive(GraphQLScalar)]
+#[graphql(description = "Static", description_with = describe, transparent)]
+struct ScalarDescription(i64);
+
+fn describe() -> String {
+    "Computed".into()
+}
+
+fn main() {}
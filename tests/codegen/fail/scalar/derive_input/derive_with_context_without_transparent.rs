@@ -0,0 +1,27 @@
+use juniper::{GraphQLScalar, InputValue, ParseScalarResult, ScalarToken, ScalarValue, Value};
+
+struct Context;
+
+impl juniper::Context for Context {}
+
+#[derive(GraphQLScalar)]
+#[graphql(context = Context, with = Self, with_context)]
+struct Scalar(i32);
+
+impl Scalar {
+    fn to_output<S: ScalarValue>(&self, _: &Context) -> Value<S> {
+        Value::scalar(self.0)
+    }
+
+    fn from_input<S: ScalarValue>(v: &InputValue<S>) -> Result<Self, String> {
+        v.as_int_value()
+            .map(Self)
+            .ok_or_else(|| format!("Expected `Int`, found: {v}"))
+    }
+
+    fn parse_token<S: ScalarValue>(t: ScalarToken<'_>) -> ParseScalarResult<S> {
+        <i32 as juniper::ParseScalarValue<S>>::from_str(t)
+    }
+}
+
+fn main() {}
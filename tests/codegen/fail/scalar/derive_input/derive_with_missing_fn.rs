@@ -0,0 +1,19 @@
+use juniper::{GraphQLScalar, InputValue, ScalarValue, Value};
+
+#[derive(GraphQLScalar)]
+#[graphql(with = counter)]
+struct Counter(i32);
+
+mod counter {
+    use super::*;
+
+    pub(super) fn to_output<S: ScalarValue>(v: &Counter) -> Value<S> {
+        Value::scalar(v.0)
+    }
+
+    pub(super) fn from_input<S: ScalarValue>(v: &InputValue<S>) -> Result<Counter, String> {
+        v.as_int_value().map(Counter).ok_or_else(|| "bad".into())
+    }
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+use juniper::{graphql_scalar, InputValue, ParseScalarResult, ScalarToken, ScalarValue, Value};
+
+struct Context;
+
+impl juniper::Context for Context {}
+
+struct Scalar;
+
+#[graphql_scalar(context = Context, with = impl_scalar, with_context)]
+type CustomScalar = Scalar;
+
+mod impl_scalar {
+    use super::*;
+
+    pub(super) fn to_output<S: ScalarValue>(_: &Scalar, _: &Context) -> Value<S> {
+        Value::null()
+    }
+
+    pub(super) fn from_input<S: ScalarValue>(_: &InputValue<S>) -> Result<Scalar, String> {
+        Ok(Scalar)
+    }
+
+    pub(super) fn parse_token<S: ScalarValue>(t: ScalarToken<'_>) -> ParseScalarResult<S> {
+        <String as juniper::ParseScalarValue<S>>::from_str(t)
+    }
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use juniper::{graphql_scalar, InputValue, ScalarValue, Value};
+
+struct Counter(i32);
+
+#[graphql_scalar(with = counter)]
+type CounterScalar = Counter;
+
+mod counter {
+    use super::*;
+
+    pub(super) fn to_output<S: ScalarValue>(v: &Counter) -> Value<S> {
+        Value::scalar(v.0)
+    }
+
+    pub(super) fn from_input<S: ScalarValue>(v: &InputValue<S>) -> Result<Counter, String> {
+        v.as_int_value().map(Counter).ok_or_else(|| "bad".into())
+    }
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use std::pin::Pin;
+
+use juniper::graphql_subscription;
+
+type Stream<'a, I> = Pin<Box<dyn futures::Stream<Item = I> + Send + 'a>>;
+
+struct Obj;
+
+#[graphql_subscription(resolve = serial)]
+impl Obj {
+    async fn id(&self) -> Stream<'static, &'static str> {
+        Box::pin(futures::stream::once(futures::future::ready("funA")))
+    }
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use std::pin::Pin;
+
+use juniper::graphql_subscription;
+
+type Stream<'a, I> = Pin<Box<dyn futures::Stream<Item = I> + Send + 'a>>;
+
+fn typename(_: &Obj, _: &()) -> &'static str {
+    "Obj"
+}
+
+struct Obj;
+
+#[graphql_subscription(typename_with = typename)]
+impl Obj {
+    async fn id(&self) -> Stream<'static, &'static str> {
+        Box::pin(futures::stream::once(futures::future::ready("funA")))
+    }
+}
+
+fn main() {}
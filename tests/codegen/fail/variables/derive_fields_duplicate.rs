@@ -0,0 +1,10 @@
+use juniper::GraphQLVariables;
+
+#[derive(GraphQLVariables)]
+struct Vars {
+    id: i32,
+    #[graphql(name = "id")]
+    other_id: i32,
+}
+
+fn main() {}
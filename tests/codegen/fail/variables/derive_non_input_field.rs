@@ -0,0 +1,11 @@
+use juniper::GraphQLVariables;
+
+struct NotInput;
+
+#[derive(GraphQLVariables)]
+struct Vars {
+    id: i32,
+    other: NotInput,
+}
+
+fn main() {}
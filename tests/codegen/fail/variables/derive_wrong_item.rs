@@ -0,0 +1,8 @@
+use juniper::GraphQLVariables;
+
+#[derive(GraphQLVariables)]
+enum Vars {
+    Id(i32),
+}
+
+fn main() {}
@@ -1,6 +1,9 @@
-use std::sync::Arc;
+use std::{rc::Rc, sync::Arc};
 
-use juniper::{graphql_object, GraphQLInputObject};
+use juniper::{
+    execute, execute_sync, graphql_interface, graphql_object, graphql_value, graphql_vars,
+    EmptyMutation, EmptySubscription, GraphQLInputObject, GraphQLObject, RootNode,
+};
 
 struct Query;
 
@@ -15,3 +18,127 @@ impl Query {
 struct Ping {
     expect_result: Arc<bool>,
 }
+
+mod resolution {
+    use super::*;
+
+    #[graphql_interface(for = Human)]
+    struct Character {
+        id: String,
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(impl = CharacterValue)]
+    struct Human {
+        id: String,
+    }
+
+    fn human(id: &str) -> Arc<Human> {
+        Arc::new(Human { id: id.into() })
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn human() -> Arc<Human> {
+            human("human-32")
+        }
+
+        fn humans() -> Vec<Arc<Human>> {
+            vec![human("human-1"), human("human-2")]
+        }
+
+        fn maybe_human(is_some: bool) -> Option<Arc<Human>> {
+            is_some.then(|| human("human-3"))
+        }
+
+        fn character() -> Arc<CharacterValue> {
+            Arc::new(
+                Human {
+                    id: "human-4".into(),
+                }
+                .into(),
+            )
+        }
+    }
+
+    fn schema<Q>(query_root: Q) -> RootNode<'static, Q, EmptyMutation, EmptySubscription>
+    where
+        Q: juniper::GraphQLType<TypeInfo = (), Context = ()> + 'static,
+    {
+        RootNode::new(query_root, EmptyMutation::new(), EmptySubscription::new())
+    }
+
+    #[tokio::test]
+    async fn resolves_through_arc() {
+        const DOC: &str = r#"{
+            human { __typename id }
+            humans { id }
+            some: maybeHuman(isSome: true) { id }
+            none: maybeHuman(isSome: false) { id }
+            character { __typename ... on Human { id } }
+        }"#;
+
+        assert_eq!(
+            execute(DOC, None, &schema(QueryRoot), &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({
+                    "human": {"__typename": "Human", "id": "human-32"},
+                    "humans": [{"id": "human-1"}, {"id": "human-2"}],
+                    "some": {"id": "human-3"},
+                    "none": null,
+                    "character": {"__typename": "Human", "id": "human-4"},
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn uses_inner_type_name() {
+        const DOC: &str = r#"{
+            __type(name: "QueryRoot") {
+                fields { name type { name ofType { name } } }
+            }
+        }"#;
+
+        assert_eq!(
+            execute(DOC, None, &schema(QueryRoot), &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [
+                    {"name": "human", "type": {"name": null, "ofType": {"name": "Human"}}},
+                    {"name": "humans", "type": {"name": null, "ofType": {"name": null}}},
+                    {"name": "maybeHuman", "type": {"name": "Human", "ofType": null}},
+                    {"name": "character", "type": {"name": null, "ofType": {"name": "Character"}}},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn resolves_through_rc_synchronously() {
+        const DOC: &str = r#"{
+            __typename
+            human { id }
+        }"#;
+
+        assert_eq!(
+            execute_sync(
+                DOC,
+                None,
+                &schema(Rc::new(QueryRoot)),
+                &graphql_vars! {},
+                &()
+            ),
+            Ok((
+                graphql_value!({
+                    "__typename": "QueryRoot",
+                    "human": {"id": "human-32"},
+                }),
+                vec![],
+            )),
+        );
+    }
+}
@@ -4,7 +4,7 @@ pub mod common;
 
 use juniper::{
     execute, graphql_object, graphql_value, graphql_vars, parser::SourcePosition,
-    DefaultScalarValue, ExecutionError, FieldError, GraphQLEnum, ScalarValue,
+    DefaultScalarValue, ExecutionError, FieldError, GraphQLEnum, GraphQLError, ScalarValue,
 };
 
 use self::common::util::{schema, schema_with_scalar};
@@ -260,6 +260,43 @@ mod ignored_generic_variant {
     }
 }
 
+mod explicit_bound {
+    use super::*;
+
+    #[derive(GraphQLEnum)]
+    #[graphql(bound = "T: Sync")]
+    enum Character<'a, T> {
+        Human,
+        Droid,
+        #[allow(dead_code)]
+        #[graphql(ignore)]
+        Ignored(&'a T),
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn pass_as_is(character: Character<'static, ()>) -> Character<'static, ()> {
+            character
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        const DOC: &str = r#"{
+            passAsIs(character: DROID)
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"passAsIs": "DROID"}), vec![])),
+        );
+    }
+}
+
 mod description_from_doc_comment {
     use super::*;
 
@@ -513,6 +550,21 @@ mod deprecation_from_graphql_attr {
         }
     }
 
+    #[tokio::test]
+    async fn resolves_deprecated() {
+        const DOC: &str = r#"{
+            human: passAsIs(character: HUMAN)
+            droid: passAsIs(character: DROID)
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"human": "HUMAN", "droid": "DROID"}), vec![])),
+        );
+    }
+
     #[tokio::test]
     async fn has_description() {
         const DOC: &str = r#"{
@@ -752,6 +804,98 @@ mod renamed_all_fields {
     }
 }
 
+mod renamed_all_fields_in_screaming_snake_case {
+    use super::*;
+
+    #[derive(GraphQLEnum)]
+    #[graphql(rename_all = "SCREAMING_SNAKE_CASE")]
+    enum Episode {
+        NewHope,
+        EmpireStrikesBack,
+        #[graphql(name = "jedi")]
+        ReturnOfTheJedi,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn pass_as_is(episode: Episode) -> Episode {
+            episode
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        const DOC: &str = r#"{
+            newHope: passAsIs(episode: NEW_HOPE)
+            empire: passAsIs(episode: EMPIRE_STRIKES_BACK)
+            jedi: passAsIs(episode: jedi)
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({
+                    "newHope": "NEW_HOPE",
+                    "empire": "EMPIRE_STRIKES_BACK",
+                    "jedi": "jedi",
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn matches_case_sensitively() {
+        let schema = schema(QueryRoot);
+
+        for (doc, vars) in [
+            ("{ passAsIs(episode: new_hope) }", graphql_vars! {}),
+            ("{ passAsIs(episode: JEDI) }", graphql_vars! {}),
+            (
+                "query($e: Episode!) { passAsIs(episode: $e) }",
+                graphql_vars! {"e": "NewHope"},
+            ),
+        ] {
+            assert!(
+                matches!(
+                    execute(doc, None, &schema, &vars, &()).await,
+                    Err(GraphQLError::ValidationError(_)),
+                ),
+                "doc: {doc}",
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn has_enum_values() {
+        const DOC: &str = r#"{
+            __type(name: "Episode") {
+                enumValues {
+                    name
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"enumValues": [
+                    {"name": "NEW_HOPE"},
+                    {"name": "EMPIRE_STRIKES_BACK"},
+                    {"name": "jedi"},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
 mod explicit_scalar {
     use super::*;
 
@@ -931,3 +1075,125 @@ mod explicit_custom_context {
         );
     }
 }
+
+mod coercion_from_variables {
+    use juniper::{GraphQLInputObject, Variables};
+
+    use super::*;
+
+    #[derive(Debug, GraphQLEnum)]
+    enum Character {
+        Human,
+        Droid,
+    }
+
+    #[derive(GraphQLInputObject)]
+    struct Filter {
+        characters: Vec<Character>,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn pass_as_is(character: Character) -> Character {
+            character
+        }
+
+        fn count(filter: Filter) -> i32 {
+            filter.characters.len() as i32
+        }
+    }
+
+    fn json_vars(json: &str) -> Variables {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[tokio::test]
+    async fn accepts_inline_literal_and_string_variable() {
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(
+                "{ passAsIs(character: DROID) }",
+                None,
+                &schema,
+                &graphql_vars! {},
+                &()
+            )
+            .await,
+            Ok((graphql_value!({"passAsIs": "DROID"}), vec![])),
+        );
+        assert_eq!(
+            execute(
+                "query($c: Character!) { passAsIs(character: $c) }",
+                None,
+                &schema,
+                &json_vars(r#"{"c": "DROID"}"#),
+                &(),
+            )
+            .await,
+            Ok((graphql_value!({"passAsIs": "DROID"}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn accepts_string_variable_in_lists_and_input_objects() {
+        const DOC: &str = r#"query($f: Filter!, $c: Character!) {
+            a: count(filter: $f)
+            b: count(filter: {characters: [HUMAN, $c]})
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(
+                DOC,
+                None,
+                &schema,
+                &json_vars(r#"{"f": {"characters": ["HUMAN", "DROID", "HUMAN"]}, "c": "DROID"}"#),
+                &(),
+            )
+            .await,
+            Ok((graphql_value!({"a": 3, "b": 2}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_unknown_string_variable() {
+        let schema = schema(QueryRoot);
+
+        let res = execute(
+            "query($c: Character!) { passAsIs(character: $c) }",
+            None,
+            &schema,
+            &json_vars(r#"{"c": "WOOKIEE"}"#),
+            &(),
+        )
+        .await;
+
+        assert!(
+            matches!(res, Err(GraphQLError::ValidationError(_))),
+            "wrong result: {res:?}",
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_inline_string_literal() {
+        let schema = schema(QueryRoot);
+
+        let res = execute(
+            r#"{ passAsIs(character: "DROID") }"#,
+            None,
+            &schema,
+            &graphql_vars! {},
+            &(),
+        )
+        .await;
+
+        assert!(
+            matches!(res, Err(GraphQLError::ValidationError(_))),
+            "wrong result: {res:?}",
+        );
+    }
+}
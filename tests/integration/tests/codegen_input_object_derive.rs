@@ -3,8 +3,8 @@
 pub mod common;
 
 use juniper::{
-    execute, graphql_object, graphql_value, graphql_vars, parser::SourcePosition, GraphQLError,
-    GraphQLInputObject, RuleError,
+    execute, graphql_object, graphql_value, graphql_vars, parser::SourcePosition, ExecutionError,
+    FieldError, GraphQLError, GraphQLInputObject, RuleError,
 };
 
 use self::common::util::schema;
@@ -795,6 +795,98 @@ mod description_from_graphql_attr {
     }
 }
 
+mod deprecated_field {
+    use super::*;
+
+    #[derive(GraphQLInputObject)]
+    struct Point2D {
+        x: f64,
+        #[graphql(deprecated = "Use `x` instead.")]
+        abscissa: Option<f64>,
+        #[deprecated]
+        ordinate: Option<f64>,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn x(point: Point2D) -> f64 {
+            point.abscissa.unwrap_or(point.x)
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        const DOC: &str = r#"{
+            x(point: { x: 10, abscissa: 20, ordinate: 30 })
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"x": 20.0}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn hides_deprecated_input_fields_by_default() {
+        const DOC: &str = r#"{
+            __type(name: "Point2D") {
+                inputFields {
+                    name
+                    isDeprecated
+                    deprecationReason
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"inputFields": [
+                    {"name": "x", "isDeprecated": false, "deprecationReason": null},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn has_deprecated_input_fields() {
+        const DOC: &str = r#"{
+            __type(name: "Point2D") {
+                inputFields(includeDeprecated: true) {
+                    name
+                    isDeprecated
+                    deprecationReason
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"inputFields": [
+                    {"name": "x", "isDeprecated": false, "deprecationReason": null},
+                    {
+                        "name": "abscissa",
+                        "isDeprecated": true,
+                        "deprecationReason": "Use `x` instead.",
+                    },
+                    {"name": "ordinate", "isDeprecated": true, "deprecationReason": null},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
 mod renamed_all_fields {
     use super::*;
 
@@ -885,3 +977,479 @@ mod renamed_all_fields {
         );
     }
 }
+
+mod renamed_all_fields_in_screaming_snake_case {
+    use super::*;
+
+    #[derive(GraphQLInputObject)]
+    #[graphql(rename_all = "SCREAMING_SNAKE_CASE")]
+    struct Point2D {
+        x_coord: f64,
+        #[graphql(name = "y")]
+        y_coord: f64,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn x(point: Point2D) -> f64 {
+            point.x_coord + point.y_coord
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        const DOC: &str = r#"{
+            x(point: { X_COORD: 10, y: 20 })
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"x": 30.0}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn has_input_fields() {
+        const DOC: &str = r#"{
+            __type(name: "Point2D") {
+                inputFields {
+                    name
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"inputFields": [
+                    {"name": "X_COORD"},
+                    {"name": "y"},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod validated {
+    use super::*;
+
+    #[derive(GraphQLInputObject)]
+    #[graphql(validate_with = validate_range)]
+    struct Range {
+        start: i32,
+        end: i32,
+    }
+
+    fn validate_range(range: &Range) -> Result<(), String> {
+        if range.start > range.end {
+            return Err(format!(
+                "`start` ({}) exceeds `end` ({})",
+                range.start, range.end,
+            ));
+        }
+        Ok(())
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn len(range: Range) -> i32 {
+            assert!(range.start <= range.end, "invalid `Range` reached resolver");
+            range.end - range.start
+        }
+
+        fn total(ranges: Vec<Range>) -> i32 {
+            ranges.iter().map(|r| r.end - r.start).sum()
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_valid() {
+        const DOC: &str = r#"{
+            len(range: { start: 1, end: 5 })
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"len": 4}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_literal() {
+        const DOC: &str = r#"{
+            len(range: { start: 5, end: 1 })
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!(null),
+                vec![ExecutionError::new(
+                    SourcePosition::new(14, 1, 12),
+                    &["len"],
+                    FieldError::from("`start` (5) exceeds `end` (1)"),
+                )],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_variable() {
+        const DOC: &str = r#"query($range: Range!) {
+            len(range: $range)
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(
+                DOC,
+                None,
+                &schema,
+                &graphql_vars! {"range": {"start": 5, "end": 1}},
+                &(),
+            )
+            .await,
+            Err(GraphQLError::ValidationError(vec![RuleError::new(
+                "Variable \"$range\" got invalid value. \
+                 Expected input of type `Range`. \
+                 Got: `{start: 5, end: 1}`. \
+                 Details: `start` (5) exceeds `end` (1).",
+                &[SourcePosition::new(6, 0, 6)],
+            )])),
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_nested_variable() {
+        const DOC: &str = r#"query($ranges: [Range!]!) {
+            total(ranges: $ranges)
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(
+                DOC,
+                None,
+                &schema,
+                &graphql_vars! {"ranges": [{"start": 1, "end": 2}, {"start": 5, "end": 1}]},
+                &(),
+            )
+            .await,
+            Err(GraphQLError::ValidationError(vec![RuleError::new(
+                "Variable \"$ranges\" got invalid value. In element #1: \
+                 Expected input of type `Range`. \
+                 Got: `{start: 5, end: 1}`. \
+                 Details: `start` (5) exceeds `end` (1).",
+                &[SourcePosition::new(6, 0, 6)],
+            )])),
+        );
+    }
+}
+
+mod one_of {
+    use super::*;
+
+    #[derive(GraphQLInputObject)]
+    #[graphql(one_of)]
+    struct UserBy {
+        id: Option<i32>,
+        email: Option<String>,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn user(by: UserBy) -> String {
+            match by {
+                UserBy {
+                    id: Some(id),
+                    email: None,
+                } => format!("id: {id}"),
+                UserBy {
+                    id: None,
+                    email: Some(email),
+                } => format!("email: {email}"),
+                _ => panic!("invalid `UserBy` reached resolver"),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_single_field() {
+        const DOC: &str = r#"{
+            byId: user(by: { id: 1 })
+            byEmail: user(by: { email: "luke@example.com" })
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"byId": "id: 1", "byEmail": "email: luke@example.com"}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_single_field_variable() {
+        const DOC: &str = r#"query($by: UserBy!) {
+            user(by: $by)
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {"by": {"id": 7}}, &()).await,
+            Ok((graphql_value!({"user": "id: 7"}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_literal() {
+        for literal in ["{}", "{ id: 1, email: \"a\" }", "{ id: null }"] {
+            let doc = format!("{{\n            user(by: {literal})\n        }}");
+
+            let schema = schema(QueryRoot);
+
+            assert_eq!(
+                execute(&doc, None, &schema, &graphql_vars! {}, &()).await,
+                Err(GraphQLError::ValidationError(vec![RuleError::new(
+                    "Invalid value for argument \"by\", expected type \"UserBy!\"",
+                    &[SourcePosition::new(23, 1, 21)],
+                )])),
+                "literal: {literal}",
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_variable() {
+        const DOC: &str = r#"query($by: UserBy!) {
+            user(by: $by)
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(
+                DOC,
+                None,
+                &schema,
+                &graphql_vars! {"by": {"id": 1, "email": "a"}},
+                &(),
+            )
+            .await,
+            Err(GraphQLError::ValidationError(vec![RuleError::new(
+                "Variable \"$by\" got invalid value. \
+                 Expected input of type `UserBy`. \
+                 Got: `{id: 1, email: \"a\"}`. \
+                 Details: Exactly one non-null field must be provided for `@oneOf` input object `UserBy`.",
+                &[SourcePosition::new(6, 0, 6)],
+            )])),
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_nullable_variable_field() {
+        const DOC: &str = r#"query($id: Int) {
+            user(by: { id: $id })
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {"id": 1}, &()).await,
+            Err(GraphQLError::ValidationError(vec![RuleError::new(
+                "Variable \"id\" of type \"Int\" used in position expecting type \"Int!\"",
+                &[SourcePosition::new(6, 0, 6), SourcePosition::new(45, 1, 27)],
+            )])),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_non_null_variable_field() {
+        const DOC: &str = r#"query($id: Int!) {
+            user(by: { id: $id })
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {"id": 1}, &()).await,
+            Ok((graphql_value!({"user": "id: 1"}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn is_introspected() {
+        const DOC: &str = r#"{
+            __type(name: "UserBy") {
+                isOneOf
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"__type": {"isOneOf": true}}), vec![])),
+        );
+    }
+}
+
+mod aliased_field {
+    use super::*;
+
+    #[derive(GraphQLInputObject)]
+    struct Point2D {
+        #[graphql(alias = "abscissa")]
+        x: f64,
+        #[graphql(alias = "ordinate", alias = "yCoord")]
+        y: Option<f64>,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn x(point: Point2D) -> f64 {
+            point.x
+        }
+
+        fn y(point: Point2D) -> Option<f64> {
+            point.y
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_canonical_name() {
+        const DOC: &str = r#"{
+            x(point: { x: 10, y: 20 })
+            y(point: { x: 10, y: 20 })
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"x": 10.0, "y": 20.0}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_alias() {
+        const DOC: &str = r#"{
+            x(point: { abscissa: 10 })
+            y(point: { x: 10, yCoord: 20 })
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"x": 10.0, "y": 20.0}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_alias_in_variable() {
+        const DOC: &str = r#"query($point: Point2D!) {
+            y(point: $point)
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(
+                DOC,
+                None,
+                &schema,
+                &graphql_vars! {"point": {"abscissa": 10, "ordinate": 20}},
+                &(),
+            )
+            .await,
+            Ok((graphql_value!({"y": 20.0}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_both_names() {
+        const DOC: &str = r#"{
+            x(point: { x: 10, abscissa: 20 })
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Err(GraphQLError::ValidationError(vec![RuleError::new(
+                "Invalid value for argument \"point\", expected type \"Point2D!\"",
+                &[SourcePosition::new(23, 1, 21)],
+            )])),
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_both_names_in_variable() {
+        const DOC: &str = r#"query($point: Point2D!) {
+            y(point: $point)
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(
+                DOC,
+                None,
+                &schema,
+                &graphql_vars! {"point": {"x": 10, "ordinate": 20, "yCoord": 30}},
+                &(),
+            )
+            .await,
+            Err(GraphQLError::ValidationError(vec![RuleError::new(
+                "Variable \"$point\" got invalid value. In field \"y\": \
+                 Field \"ordinate\" provided along with its alias \"yCoord\".",
+                &[SourcePosition::new(6, 0, 6)],
+            )])),
+        );
+    }
+
+    #[tokio::test]
+    async fn hides_aliases_from_introspection() {
+        const DOC: &str = r#"{
+            __type(name: "Point2D") {
+                inputFields {
+                    name
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"inputFields": [{"name": "x"}, {"name": "y"}]}}),
+                vec![],
+            )),
+        );
+    }
+}
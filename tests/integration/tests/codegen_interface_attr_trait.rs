@@ -3,9 +3,9 @@
 pub mod common;
 
 use juniper::{
-    execute, graphql_interface, graphql_object, graphql_value, graphql_vars, DefaultScalarValue,
-    Executor, FieldError, FieldResult, GraphQLInputObject, GraphQLObject, GraphQLUnion,
-    IntoFieldError, ScalarValue, ID,
+    execute, execute_sync, graphql_interface, graphql_object, graphql_value, graphql_vars,
+    DefaultScalarValue, Executor, FieldError, FieldResult, GraphQLInputObject, GraphQLObject,
+    GraphQLUnion, IntoFieldError, ScalarValue, ID,
 };
 
 use self::common::util::{schema, schema_with_scalar};
@@ -302,6 +302,29 @@ mod trivial {
             Ok((graphql_value!({"__type": {"description": null}}), vec![])),
         );
     }
+
+    #[test]
+    fn downcasts_to_implementers() {
+        let human = CharacterValue::from(Human {
+            id: "human-32".into(),
+            home_planet: "earth".into(),
+        });
+        let droid = CharacterValue::from(Droid {
+            id: "droid-99".into(),
+            primary_function: "run".into(),
+        });
+
+        assert_eq!(
+            human.as_human().map(|h| h.home_planet.as_str()),
+            Some("earth"),
+        );
+        assert!(human.as_droid().is_none());
+        assert_eq!(
+            droid.as_droid().map(|d| d.primary_function.as_str()),
+            Some("run"),
+        );
+        assert!(droid.as_human().is_none());
+    }
 }
 
 mod explicit_alias {
@@ -479,6 +502,252 @@ mod explicit_alias {
     }
 }
 
+mod trait_object {
+    use super::*;
+
+    #[graphql_interface(dyn, for = [Human, Droid])]
+    trait Character {
+        fn id(&self) -> &str;
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(impl = CharacterValue, scalar = DefaultScalarValue)]
+    struct Human {
+        id: String,
+        home_planet: String,
+    }
+
+    impl Character for Human {
+        fn id(&self) -> &str {
+            &self.id
+        }
+    }
+
+    struct Droid {
+        id: String,
+        primary_function: String,
+    }
+
+    #[graphql_object(impl = CharacterValue, scalar = DefaultScalarValue)]
+    impl Droid {
+        fn id(&self) -> &str {
+            &self.id
+        }
+
+        async fn primary_function(&self) -> &str {
+            &self.primary_function
+        }
+    }
+
+    impl Character for Droid {
+        fn id(&self) -> &str {
+            &self.id
+        }
+    }
+
+    #[derive(Clone, Copy)]
+    enum QueryRoot {
+        Human,
+        Droid,
+    }
+
+    #[graphql_object(scalar = DefaultScalarValue)]
+    impl QueryRoot {
+        fn character(&self) -> Box<dyn Character + Send + Sync> {
+            match self {
+                Self::Human => Box::new(Human {
+                    id: "human-32".into(),
+                    home_planet: "earth".into(),
+                }),
+                Self::Droid => Box::new(Droid {
+                    id: "droid-99".into(),
+                    primary_function: "run".into(),
+                }),
+            }
+        }
+
+        fn characters() -> Vec<Box<dyn Character + Send + Sync>> {
+            vec![
+                Box::new(Human {
+                    id: "human-32".into(),
+                    home_planet: "earth".into(),
+                }),
+                Box::new(Droid {
+                    id: "droid-99".into(),
+                    primary_function: "run".into(),
+                }),
+            ]
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_human() {
+        const DOC: &str = r#"{
+            character {
+                ... on Human {
+                    humanId: id
+                    homePlanet
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot::Human);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"character": {
+                    "humanId": "human-32",
+                    "homePlanet": "earth",
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_droid() {
+        const DOC: &str = r#"{
+            character {
+                ... on Droid {
+                    droidId: id
+                    primaryFunction
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot::Droid);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"character": {
+                    "droidId": "droid-99",
+                    "primaryFunction": "run",
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_id_field() {
+        const DOC: &str = r#"{
+            character {
+                id
+            }
+        }"#;
+
+        for (root, expected_id) in [
+            (QueryRoot::Human, "human-32"),
+            (QueryRoot::Droid, "droid-99"),
+        ] {
+            let schema = schema(root);
+
+            assert_eq!(
+                execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+                Ok((graphql_value!({"character": {"id": expected_id}}), vec![])),
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_typename_of_concrete_type() {
+        const DOC: &str = r#"{
+            characters {
+                __typename
+                id
+                ... on Human {
+                    homePlanet
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot::Human);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"characters": [
+                    {"__typename": "Human", "id": "human-32", "homePlanet": "earth"},
+                    {"__typename": "Droid", "id": "droid-99"},
+                ]}),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn resolves_synchronously() {
+        const DOC: &str = r#"{
+            characters {
+                __typename
+                id
+            }
+        }"#;
+
+        let schema = schema(QueryRoot::Human);
+
+        assert_eq!(
+            execute_sync(DOC, None, &schema, &graphql_vars! {}, &()),
+            Ok((
+                graphql_value!({"characters": [
+                    {"__typename": "Human", "id": "human-32"},
+                    {"__typename": "Droid", "id": "droid-99"},
+                ]}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn is_the_same_graphql_interface() {
+        const DOC: &str = r#"{
+            __type(name: "Character") {
+                kind
+                possibleTypes {
+                    name
+                }
+            }
+            query: __type(name: "QueryRoot") {
+                fields {
+                    type {
+                        kind
+                        name
+                        ofType {
+                            name
+                        }
+                    }
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot::Human);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({
+                    "__type": {
+                        "kind": "INTERFACE",
+                        "possibleTypes": [{"name": "Droid"}, {"name": "Human"}],
+                    },
+                    "query": {"fields": [{
+                        "type": {"kind": "NON_NULL", "name": null, "ofType": {
+                            "name": "Character",
+                        }},
+                    }, {
+                        "type": {"kind": "NON_NULL", "name": null, "ofType": {
+                            "name": null,
+                        }},
+                    }]},
+                }),
+                vec![],
+            )),
+        );
+    }
+}
+
 mod trivial_async {
     use super::*;
 
@@ -707,6 +976,281 @@ mod trivial_async {
     }
 }
 
+mod async_method {
+    use super::*;
+
+    #[graphql_interface(for = [Human, Droid])]
+    trait Character {
+        fn id(&self) -> &str;
+
+        async fn info(&self, verbose: bool) -> String;
+    }
+
+    struct Human {
+        id: String,
+        home_planet: String,
+    }
+
+    #[graphql_object(impl = CharacterValue)]
+    impl Human {
+        fn id(&self) -> &str {
+            &self.id
+        }
+
+        async fn info(&self, verbose: bool) -> String {
+            // Emulates a DB lookup.
+            tokio::task::yield_now().await;
+            if verbose {
+                format!("{} from {}", self.id, self.home_planet)
+            } else {
+                self.id.clone()
+            }
+        }
+    }
+
+    struct Droid {
+        id: String,
+        primary_function: String,
+    }
+
+    #[graphql_object(impl = CharacterValue)]
+    impl Droid {
+        fn id(&self) -> &str {
+            &self.id
+        }
+
+        async fn info(&self, verbose: bool) -> String {
+            // Emulates a DB lookup.
+            tokio::task::yield_now().await;
+            if verbose {
+                format!("{} for {}", self.id, self.primary_function)
+            } else {
+                self.id.clone()
+            }
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn characters() -> Vec<CharacterValue> {
+            vec![
+                Human {
+                    id: "human-32".into(),
+                    home_planet: "earth".into(),
+                }
+                .into(),
+                Droid {
+                    id: "droid-99".into(),
+                    primary_function: "run".into(),
+                }
+                .into(),
+            ]
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_async_field() {
+        const DOC: &str = r#"{
+            characters {
+                short: info(verbose: false)
+                long: info(verbose: true)
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"characters": [
+                    {"short": "human-32", "long": "human-32 from earth"},
+                    {"short": "droid-99", "long": "droid-99 for run"},
+                ]}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_async_field_on_implementers() {
+        const DOC: &str = r#"{
+            characters {
+                ... on Human {
+                    info(verbose: true)
+                    homePlanet: info(verbose: false)
+                }
+                ... on Droid {
+                    info(verbose: true)
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"characters": [
+                    {"info": "human-32 from earth", "homePlanet": "human-32"},
+                    {"info": "droid-99 for run"},
+                ]}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn errors_on_sync_execution() {
+        const DOC: &str = r#"{
+            characters {
+                info(verbose: false)
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        let (res, errs) = execute_sync(DOC, None, &schema, &graphql_vars! {}, &()).unwrap();
+
+        assert_eq!(res, graphql_value!(null));
+        assert!(
+            errs[0]
+                .error()
+                .message()
+                .contains("Tried to resolve async field `info`"),
+            "wrong error: {errs:?}",
+        );
+    }
+
+    #[tokio::test]
+    async fn has_correct_field_type() {
+        const DOC: &str = r#"{
+            __type(name: "Character") {
+                fields {
+                    name
+                    type {
+                        kind
+                        ofType {
+                            name
+                        }
+                    }
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [
+                    {"name": "id", "type": {"kind": "NON_NULL", "ofType": {"name": "String"}}},
+                    {"name": "info", "type": {"kind": "NON_NULL", "ofType": {"name": "String"}}},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod async_method_trait_object {
+    use juniper::BoxFuture;
+
+    use super::*;
+
+    #[graphql_interface(dyn, for = [Human, Droid])]
+    trait Character {
+        async fn id(&self) -> String;
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(impl = CharacterValue, scalar = DefaultScalarValue)]
+    struct Human {
+        id: String,
+        home_planet: String,
+    }
+
+    impl Character for Human {
+        fn id(&self) -> BoxFuture<'_, String> {
+            Box::pin(async move { self.id.clone() })
+        }
+    }
+
+    struct Droid {
+        id: String,
+        primary_function: String,
+    }
+
+    #[graphql_object(impl = CharacterValue, scalar = DefaultScalarValue)]
+    impl Droid {
+        async fn id(&self) -> String {
+            tokio::task::yield_now().await;
+            self.id.clone()
+        }
+
+        fn primary_function(&self) -> &str {
+            &self.primary_function
+        }
+    }
+
+    impl Character for Droid {
+        fn id(&self) -> BoxFuture<'_, String> {
+            Box::pin(async move {
+                tokio::task::yield_now().await;
+                self.id.clone()
+            })
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object(scalar = DefaultScalarValue)]
+    impl QueryRoot {
+        fn characters() -> Vec<Box<dyn Character + Send + Sync>> {
+            vec![
+                Box::new(Human {
+                    id: "human-32".into(),
+                    home_planet: "earth".into(),
+                }),
+                Box::new(Droid {
+                    id: "droid-99".into(),
+                    primary_function: "run".into(),
+                }),
+            ]
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_async_field() {
+        const DOC: &str = r#"{
+            characters {
+                id
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"characters": [{"id": "human-32"}, {"id": "droid-99"}]}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn is_callable_on_trait_object() {
+        let character: Box<dyn Character + Send + Sync> = Box::new(Droid {
+            id: "droid-99".into(),
+            primary_function: "run".into(),
+        });
+
+        assert_eq!(character.id().await, "droid-99");
+    }
+}
+
 mod fallible_field {
     use super::*;
 
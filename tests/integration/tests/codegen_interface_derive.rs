@@ -304,6 +304,29 @@ mod trivial {
             Ok((graphql_value!({"__type": {"description": null}}), vec![])),
         );
     }
+
+    #[test]
+    fn downcasts_to_implementers() {
+        let human = CharacterValue::from(Human {
+            id: "human-32".into(),
+            home_planet: "earth".into(),
+        });
+        let droid = CharacterValue::from(Droid {
+            id: "droid-99".into(),
+            primary_function: "run".into(),
+        });
+
+        assert_eq!(
+            human.as_human().map(|h| h.home_planet.as_str()),
+            Some("earth"),
+        );
+        assert!(human.as_droid().is_none());
+        assert_eq!(
+            droid.as_droid().map(|d| d.primary_function.as_str()),
+            Some("run"),
+        );
+        assert!(droid.as_human().is_none());
+    }
 }
 
 mod explicit_alias {
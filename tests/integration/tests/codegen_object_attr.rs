@@ -3,8 +3,9 @@
 pub mod common;
 
 use juniper::{
-    execute, graphql_object, graphql_value, graphql_vars, DefaultScalarValue, Executor, FieldError,
-    FieldResult, GraphQLInputObject, GraphQLObject, IntoFieldError, ScalarValue,
+    execute, execute_sync, graphql_interface, graphql_object, graphql_value, graphql_vars,
+    parser::SourcePosition, DefaultScalarValue, ExecutionError, Executor, FieldError, FieldResult,
+    GraphQLInputObject, GraphQLObject, GraphQLUnion, IntoFieldError, ScalarValue,
 };
 
 use self::common::util::{schema, schema_with_scalar};
@@ -181,6 +182,31 @@ mod trivial_async {
             Ok((graphql_value!({"__type": {"description": null}}), vec![])),
         );
     }
+
+    #[test]
+    fn errors_on_sync_execution() {
+        const DOC: &str = r#"{
+            human {
+                id
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute_sync(DOC, None, &schema, &graphql_vars! {}, &()),
+            Ok((
+                graphql_value!(null),
+                vec![ExecutionError::new(
+                    SourcePosition::new(38, 2, 16),
+                    &["human", "id"],
+                    FieldError::from(
+                        "Tried to resolve async field `id` on type `Human` with a sync resolver",
+                    ),
+                )],
+            )),
+        );
+    }
 }
 
 mod raw_method {
@@ -321,6 +347,103 @@ mod ignored_method {
     }
 }
 
+mod ignored_helper_methods {
+    use super::*;
+
+    struct Punctuation(char);
+
+    struct Human {
+        name: String,
+    }
+
+    #[graphql_object]
+    impl Human {
+        fn name(&self) -> String {
+            self.capitalized()
+        }
+
+        fn greeting(&self) -> String {
+            Self::greet(&self.capitalized(), &Punctuation('!'))
+        }
+
+        #[graphql(ignore)]
+        fn new(name: &str) -> Self {
+            Self { name: name.into() }
+        }
+
+        #[graphql(ignore)]
+        fn capitalized(&self) -> String {
+            let mut chars = self.name.chars();
+            chars
+                .next()
+                .map(|c| c.to_uppercase().chain(chars).collect())
+                .unwrap_or_default()
+        }
+
+        #[graphql(skip)]
+        fn greet(name: &str, punctuation: &Punctuation) -> String {
+            format!("Hello, {name}{}", punctuation.0)
+        }
+
+        #[graphql(skip)]
+        fn rename(&mut self, name: impl Into<String>) {
+            self.name = name.into();
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn human() -> Human {
+            let mut human = Human::new("luke");
+            human.rename("leia");
+            human
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        const DOC: &str = r#"{
+            human {
+                name
+                greeting
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"human": {"name": "Leia", "greeting": "Hello, Leia!"}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn are_not_fields() {
+        const DOC: &str = r#"{
+            __type(name: "Human") {
+                fields {
+                    name
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [{"name": "name"}, {"name": "greeting"}]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
 mod fallible_method {
     use super::*;
 
@@ -419,6 +542,229 @@ mod fallible_method {
     }
 }
 
+mod fallible_nullable_method {
+    use super::*;
+
+    fn outcome(value: Option<i32>, fail: bool) -> Result<Option<i32>, &'static str> {
+        if fail {
+            Err("Failed")
+        } else {
+            Ok(value)
+        }
+    }
+
+    struct Human;
+
+    #[graphql_object]
+    impl Human {
+        fn result_option(value: Option<i32>, fail: bool) -> Result<Option<i32>, &'static str> {
+            outcome(value, fail)
+        }
+
+        fn option_result(value: Option<i32>, fail: bool) -> Option<Result<i32, &'static str>> {
+            outcome(value, fail).transpose()
+        }
+
+        async fn async_option_result(
+            value: Option<i32>,
+            fail: bool,
+        ) -> Option<Result<i32, &'static str>> {
+            outcome(value, fail).transpose()
+        }
+
+        fn option_result_option(
+            value: Option<i32>,
+            fail: bool,
+        ) -> Option<Result<Option<i32>, &'static str>> {
+            Some(outcome(value, fail))
+        }
+
+        fn result(fail: bool) -> Result<i32, &'static str> {
+            outcome(Some(1), fail).map(Option::unwrap)
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn human() -> Option<Human> {
+            Some(Human)
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_value() {
+        const DOC: &str = r#"{
+            human {
+                resultOption(value: 1, fail: false)
+                optionResult(value: 2, fail: false)
+                asyncOptionResult(value: 3, fail: false)
+                optionResultOption(value: 4, fail: false)
+                result(fail: false)
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"human": {
+                    "resultOption": 1,
+                    "optionResult": 2,
+                    "asyncOptionResult": 3,
+                    "optionResultOption": 4,
+                    "result": 1,
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_null() {
+        const DOC: &str = r#"{
+            human {
+                resultOption(fail: false)
+                optionResult(fail: false)
+                asyncOptionResult(fail: false)
+                optionResultOption(fail: false)
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"human": {
+                    "resultOption": null,
+                    "optionResult": null,
+                    "asyncOptionResult": null,
+                    "optionResultOption": null,
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_nullable_error() {
+        const DOC: &str = r#"{
+            human {
+                resultOption(value: 1, fail: true)
+                optionResult(value: 2, fail: true)
+                asyncOptionResult(value: 3, fail: true)
+                optionResultOption(value: 4, fail: true)
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"human": {
+                    "resultOption": null,
+                    "optionResult": null,
+                    "asyncOptionResult": null,
+                    "optionResultOption": null,
+                }}),
+                vec![
+                    ExecutionError::new(
+                        SourcePosition::new(38, 2, 16),
+                        &["human", "resultOption"],
+                        FieldError::from("Failed"),
+                    ),
+                    ExecutionError::new(
+                        SourcePosition::new(89, 3, 16),
+                        &["human", "optionResult"],
+                        FieldError::from("Failed"),
+                    ),
+                    ExecutionError::new(
+                        SourcePosition::new(140, 4, 16),
+                        &["human", "asyncOptionResult"],
+                        FieldError::from("Failed"),
+                    ),
+                    ExecutionError::new(
+                        SourcePosition::new(196, 5, 16),
+                        &["human", "optionResultOption"],
+                        FieldError::from("Failed"),
+                    ),
+                ],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn propagates_non_null_error() {
+        const DOC: &str = r#"{
+            human {
+                optionResult(value: 2, fail: false)
+                result(fail: true)
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"human": null}),
+                vec![ExecutionError::new(
+                    SourcePosition::new(90, 3, 16),
+                    &["human", "result"],
+                    FieldError::from("Failed"),
+                )],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn has_correct_graphql_type() {
+        const DOC: &str = r#"{
+            __type(name: "Human") {
+                fields {
+                    name
+                    type {
+                        kind
+                        name
+                        ofType {
+                            name
+                        }
+                    }
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [{
+                    "name": "resultOption",
+                    "type": {"kind": "SCALAR", "name": "Int", "ofType": null},
+                }, {
+                    "name": "optionResult",
+                    "type": {"kind": "SCALAR", "name": "Int", "ofType": null},
+                }, {
+                    "name": "asyncOptionResult",
+                    "type": {"kind": "SCALAR", "name": "Int", "ofType": null},
+                }, {
+                    "name": "optionResultOption",
+                    "type": {"kind": "SCALAR", "name": "Int", "ofType": null},
+                }, {
+                    "name": "result",
+                    "type": {"kind": "NON_NULL", "name": null, "ofType": {"name": "Int"}},
+                }]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
 mod generic {
     use super::*;
 
@@ -1180,46 +1526,164 @@ mod default_argument {
     }
 }
 
-mod description_from_doc_comment {
+mod default_with_argument {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
     use super::*;
 
-    struct Human;
+    struct Context {
+        page_size: i32,
+        calls: AtomicUsize,
+    }
 
-    /// Rust docs.
-    #[graphql_object]
-    impl Human {
-        /// Rust `id` docs.
-        fn id() -> &'static str {
-            "human-32"
-        }
+    impl juniper::Context for Context {}
+
+    fn default_page_size(ctx: &Context) -> i32 {
+        ctx.calls.fetch_add(1, Ordering::SeqCst);
+        ctx.page_size
+    }
+
+    fn default_title(ctx: &Context) -> Option<String> {
+        Some(format!("page of {}", ctx.page_size))
     }
 
     struct QueryRoot;
 
-    #[graphql_object]
+    #[graphql_object(context = Context)]
     impl QueryRoot {
-        fn human() -> Human {
-            Human
+        fn page(#[graphql(default_with = default_page_size)] size: i32) -> i32 {
+            size
+        }
+
+        async fn title(#[graphql(default_with = default_title)] title: Option<String>) -> String {
+            title.unwrap_or_default()
+        }
+    }
+
+    fn context() -> Context {
+        Context {
+            page_size: 10,
+            calls: AtomicUsize::new(0),
         }
     }
 
     #[tokio::test]
-    async fn uses_doc_comment_as_description() {
-        const DOC: &str = r#"{
-            __type(name: "Human") {
-                description
-                fields {
-                    description
-                }
-            }
-        }"#;
+    async fn resolves_page_field() {
+        let schema = schema(QueryRoot);
+
+        for (input, expected, calls) in [
+            ("{ page }", 10, 1),
+            ("{ page(size: null) }", 10, 1),
+            ("{ page(size: 3) }", 3, 0),
+        ] {
+            let ctx = context();
+
+            assert_eq!(
+                execute(input, None, &schema, &graphql_vars! {}, &ctx).await,
+                Ok((graphql_value!({"page": expected}), vec![])),
+            );
+            assert_eq!(ctx.calls.load(Ordering::SeqCst), calls, "input: {input}");
+        }
+    }
 
+    #[tokio::test]
+    async fn resolves_title_field() {
         let schema = schema(QueryRoot);
 
-        assert_eq!(
-            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
-            Ok((
-                graphql_value!({"__type": {
+        for (input, expected) in [
+            ("{ title }", "page of 10"),
+            (r#"{ title(title: "other") }"#, "other"),
+        ] {
+            assert_eq!(
+                execute(input, None, &schema, &graphql_vars! {}, &context()).await,
+                Ok((graphql_value!({"title": expected}), vec![])),
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn has_no_defaults_and_is_nullable() {
+        const DOC: &str = r#"{
+            __type(name: "QueryRoot") {
+                fields {
+                    args {
+                        name
+                        defaultValue
+                        type {
+                            name
+                            ofType {
+                                name
+                            }
+                        }
+                    }
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &context()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [{
+                    "args": [{
+                        "name": "size",
+                        "defaultValue": null,
+                        "type": {"name": "Int", "ofType": null},
+                    }],
+                }, {
+                    "args": [{
+                        "name": "title",
+                        "defaultValue": null,
+                        "type": {"name": "String", "ofType": null},
+                    }],
+                }]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod description_from_doc_comment {
+    use super::*;
+
+    struct Human;
+
+    /// Rust docs.
+    #[graphql_object]
+    impl Human {
+        /// Rust `id` docs.
+        fn id() -> &'static str {
+            "human-32"
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human
+        }
+    }
+
+    #[tokio::test]
+    async fn uses_doc_comment_as_description() {
+        const DOC: &str = r#"{
+            __type(name: "Human") {
+                description
+                fields {
+                    description
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {
                     "description": "Rust docs.",
                     "fields": [{"description": "Rust `id` docs."}],
                 }}),
@@ -1598,6 +2062,93 @@ mod renamed_all_fields_and_args {
     }
 }
 
+mod renamed_all_fields_and_args_snake_case {
+    use super::*;
+
+    struct Human;
+
+    #[graphql_object(rename_all = "snake_case")]
+    impl Human {
+        fn id() -> &'static str {
+            "human-32"
+        }
+
+        async fn home_planet(&self, planet_name: String) -> String {
+            planet_name
+        }
+
+        #[graphql(name = "asyncInfo")]
+        async fn r#async_info(#[graphql(name = "myNum")] r#my_num: i32, other_num: i32) -> i32 {
+            r#my_num + other_num
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_fields() {
+        const DOC: &str = r#"{
+            human {
+                id
+                home_planet(planet_name: "earth")
+                asyncInfo(myNum: 3, other_num: 4)
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"human": {
+                    "id": "human-32",
+                    "home_planet": "earth",
+                    "asyncInfo": 7,
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn uses_correct_fields_and_args_names() {
+        const DOC: &str = r#"{
+            __type(name: "Human") {
+                fields {
+                    name
+                    args {
+                        name
+                    }
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [
+                    {"name": "id", "args": []},
+                    {"name": "home_planet", "args": [{"name": "planet_name"}]},
+                    {"name": "asyncInfo", "args": [
+                        {"name": "myNum"},
+                        {"name": "other_num"},
+                    ]},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
 mod explicit_scalar {
     use super::*;
 
@@ -1692,6 +2243,103 @@ mod custom_scalar {
     }
 }
 
+mod inferred_custom_scalar_from_executor {
+    use juniper::LookAheadMethods as _;
+
+    use crate::common::MyScalarValue;
+
+    use super::*;
+
+    struct Human;
+
+    #[graphql_object(scalar = MyScalarValue)]
+    impl Human {
+        fn id() -> &'static str {
+            "human-32"
+        }
+    }
+
+    struct QueryRoot;
+
+    // NOTICE: No `scalar` argument, as it's inferred from the `Executor`.
+    #[graphql_object]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human
+        }
+
+        async fn field_name<'e>(executor: &'e Executor<'_, '_, (), MyScalarValue>) -> &'e str {
+            executor.look_ahead().field_name()
+        }
+
+        fn info(
+            arg: String,
+            #[graphql(executor)] _another: &Executor<'_, '_, (), MyScalarValue>,
+        ) -> String {
+            arg
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_fields() {
+        const DOC: &str = r#"{
+            human {
+                id
+            }
+            fieldName
+            info(arg: "input!")
+        }"#;
+
+        let schema = schema_with_scalar::<MyScalarValue, _, _>(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({
+                    "human": {"id": "human-32"},
+                    "fieldName": "fieldName",
+                    "info": "input!",
+                }),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod not_inferred_generic_scalar_from_executor {
+    use crate::common::MyScalarValue;
+
+    use super::*;
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn id<S: ScalarValue>(_executor: &Executor<'_, '_, (), S>) -> &'static str {
+            "id"
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_with_any_scalar() {
+        const DOC: &str = "{ id }";
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"id": "id"}), vec![])),
+        );
+
+        let schema = schema_with_scalar::<MyScalarValue, _, _>(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"id": "id"}), vec![])),
+        );
+    }
+}
+
 mod explicit_generic_scalar {
     use std::marker::PhantomData;
 
@@ -2146,3 +2794,790 @@ mod switched_context {
         );
     }
 }
+
+mod look_ahead_arguments {
+    use juniper::{LookAheadMethods as _, LookAheadValue};
+
+    use super::*;
+
+    struct Human {
+        batch_size: Option<i32>,
+    }
+
+    #[graphql_object]
+    impl Human {
+        fn batch_size(&self) -> Option<i32> {
+            self.batch_size
+        }
+
+        fn friends(#[graphql(default = 2)] first: i32) -> Vec<Human> {
+            (0..first).map(|_| Human { batch_size: None }).collect()
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn human<S: ScalarValue>(executor: &Executor<'_, '_, (), S>) -> Human {
+            let look_ahead = executor.look_ahead();
+            let batch_size = look_ahead
+                .select_child("friends")
+                .and_then(|f| f.argument("first"))
+                .and_then(|a| match a.value() {
+                    LookAheadValue::Scalar(s) => s.as_int(),
+                    _ => None,
+                });
+            Human { batch_size }
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_explicit_argument() {
+        const DOC: &str = r#"{
+            human {
+                batchSize
+                friends(first: 1) {
+                    batchSize
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"human": {
+                    "batchSize": 1,
+                    "friends": [{"batchSize": null}],
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_argument_from_variable() {
+        const DOC: &str = r#"query Friends($first: Int!) {
+            human {
+                batchSize
+                friends(first: $first) {
+                    batchSize
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {"first": 3}, &()).await,
+            Ok((
+                graphql_value!({"human": {
+                    "batchSize": 3,
+                    "friends": [{"batchSize": null}, {"batchSize": null}, {"batchSize": null}],
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_default_argument() {
+        const DOC: &str = r#"{
+            human {
+                batchSize
+                friends {
+                    batchSize
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"human": {
+                    "batchSize": 2,
+                    "friends": [{"batchSize": null}, {"batchSize": null}],
+                }}),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod look_ahead_from_root {
+    use juniper::{LookAheadMethods as _, LookAheadValue};
+
+    use super::*;
+
+    struct Comment;
+
+    #[graphql_object]
+    impl Comment {
+        /// Returns the path to this field, as seen from the operation root.
+        fn path<S: ScalarValue>(executor: &Executor<'_, '_, (), S>) -> Vec<String> {
+            executor
+                .look_ahead_path()
+                .into_iter()
+                .map(Into::into)
+                .collect()
+        }
+
+        /// Returns the `first` argument of the `friends` field, selected by the
+        /// `user` this comment belongs to.
+        fn friends_first<S: ScalarValue>(executor: &Executor<'_, '_, (), S>) -> Option<i32> {
+            let root = executor.root_look_ahead();
+            let path = executor.look_ahead_path();
+            // Go up from `friendsFirst`, `comments` and `posts` to the `user`.
+            root.select_path(&path[..path.len() - 3])?
+                .select_child("friends")?
+                .argument("first")
+                .and_then(|a| match a.value() {
+                    LookAheadValue::Scalar(s) => s.as_int(),
+                    _ => None,
+                })
+        }
+    }
+
+    struct Post;
+
+    #[graphql_object]
+    impl Post {
+        fn comments() -> Vec<Comment> {
+            vec![Comment]
+        }
+    }
+
+    struct User;
+
+    #[graphql_object]
+    impl User {
+        fn posts() -> Vec<Post> {
+            vec![Post, Post]
+        }
+
+        fn friends(#[graphql(default = 2)] first: i32) -> Vec<i32> {
+            (0..first).collect()
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn user() -> User {
+            User
+        }
+
+        fn root_fields<S: ScalarValue>(executor: &Executor<'_, '_, (), S>) -> Vec<String> {
+            executor
+                .root_look_ahead()
+                .child_names()
+                .into_iter()
+                .map(Into::into)
+                .collect()
+        }
+    }
+
+    #[tokio::test]
+    async fn traverses_to_sibling_of_parent() {
+        const DOC: &str = r#"{
+            user {
+                posts { comments { friendsFirst path } }
+                friends(first: 3)
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"user": {
+                    "posts": [
+                        {"comments": [{
+                            "friendsFirst": 3,
+                            "path": ["user", "posts", "comments", "path"],
+                        }]},
+                        {"comments": [{
+                            "friendsFirst": 3,
+                            "path": ["user", "posts", "comments", "path"],
+                        }]},
+                    ],
+                    "friends": [0, 1, 2],
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn traverses_aliases_and_fragments() {
+        const DOC: &str = r#"{
+            me: user {
+                ...UserPosts
+                ... on User { friends }
+            }
+        }
+
+        fragment UserPosts on User {
+            posts { comments { friendsFirst } }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"me": {
+                    "posts": [
+                        {"comments": [{"friendsFirst": 2}]},
+                        {"comments": [{"friendsFirst": 2}]},
+                    ],
+                    "friends": [0, 1],
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn misses_unselected_sibling() {
+        const DOC: &str = r#"{
+            user { posts { comments { friendsFirst } } }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"user": {"posts": [
+                    {"comments": [{"friendsFirst": null}]},
+                    {"comments": [{"friendsFirst": null}]},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn sees_all_root_fields() {
+        const DOC: &str = r#"{
+            rootFields
+            u: user { friends }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({
+                    "rootFields": ["rootFields", "u"],
+                    "u": {"friends": [0, 1]},
+                }),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod resolve_policy {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct Log(Mutex<Vec<&'static str>>);
+
+    impl juniper::Context for Log {}
+
+    impl Log {
+        fn push(&self, entry: &'static str) {
+            self.0.lock().unwrap().push(entry);
+        }
+
+        fn take(&self) -> Vec<&'static str> {
+            std::mem::take(&mut self.0.lock().unwrap())
+        }
+    }
+
+    struct Concurrent;
+
+    #[graphql_object(context = Log, resolve = concurrent)]
+    impl Concurrent {
+        async fn slow(context: &Log) -> &'static str {
+            tokio::task::yield_now().await;
+            context.push("slow");
+            "slow"
+        }
+
+        async fn fast(context: &Log) -> &'static str {
+            context.push("fast");
+            "fast"
+        }
+    }
+
+    struct Serial;
+
+    #[graphql_object(context = Log, resolve = serial)]
+    impl Serial {
+        async fn slow(context: &Log) -> &'static str {
+            tokio::task::yield_now().await;
+            context.push("slow");
+            "slow"
+        }
+
+        async fn fast(context: &Log) -> &'static str {
+            context.push("fast");
+            "fast"
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object(context = Log)]
+    impl QueryRoot {
+        fn concurrent() -> Concurrent {
+            Concurrent
+        }
+
+        fn serial() -> Serial {
+            Serial
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_fields_concurrently() {
+        const DOC: &str = r#"{
+            concurrent {
+                slow
+                fast
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+        let log = Log::default();
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &log).await,
+            Ok((
+                graphql_value!({"concurrent": {"slow": "slow", "fast": "fast"}}),
+                vec![],
+            )),
+        );
+        assert_eq!(log.take(), ["fast", "slow"]);
+    }
+
+    #[tokio::test]
+    async fn resolves_fields_serially() {
+        const DOC: &str = r#"{
+            serial {
+                slow
+                fast
+                ... on Serial {
+                    again: slow
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+        let log = Log::default();
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &log).await,
+            Ok((
+                graphql_value!({"serial": {"slow": "slow", "fast": "fast", "again": "slow"}}),
+                vec![],
+            )),
+        );
+        assert_eq!(log.take(), ["slow", "fast", "slow"]);
+    }
+}
+
+mod typename_override {
+    use super::*;
+
+    struct Context {
+        legacy: bool,
+    }
+
+    impl juniper::Context for Context {}
+
+    fn record_typename(record: &Record, context: &Context) -> &'static str {
+        if context.legacy && record.id < 10 {
+            "LegacyRecord"
+        } else {
+            "Record"
+        }
+    }
+
+    struct Record {
+        id: i32,
+    }
+
+    #[graphql_object(context = Context, typename_with = record_typename)]
+    impl Record {
+        fn id(&self) -> i32 {
+            self.id
+        }
+    }
+
+    struct LegacyRecord;
+
+    #[graphql_object(context = Context)]
+    impl LegacyRecord {
+        fn id() -> i32 {
+            0
+        }
+    }
+
+    #[derive(GraphQLUnion)]
+    #[graphql(context = Context)]
+    enum AnyRecord {
+        Record(Record),
+        Legacy(LegacyRecord),
+    }
+
+    fn unknown_typename(_: &Broken, _: &Context) -> &'static str {
+        "Unknown"
+    }
+
+    struct Broken;
+
+    #[graphql_object(context = Context, typename_with = unknown_typename)]
+    impl Broken {
+        fn id() -> i32 {
+            0
+        }
+    }
+
+    fn stray_typename(_: &Stray, _: &Context) -> &'static str {
+        "Record"
+    }
+
+    struct Stray;
+
+    #[graphql_object(context = Context, typename_with = stray_typename)]
+    impl Stray {
+        fn id() -> i32 {
+            0
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object(context = Context)]
+    impl QueryRoot {
+        fn records() -> Vec<Record> {
+            vec![Record { id: 1 }, Record { id: 20 }]
+        }
+
+        fn legacy() -> LegacyRecord {
+            LegacyRecord
+        }
+
+        fn latest() -> AnyRecord {
+            AnyRecord::Record(Record { id: 30 })
+        }
+
+        fn broken() -> Option<Broken> {
+            Some(Broken)
+        }
+
+        fn stray() -> Option<Stray> {
+            Some(Stray)
+        }
+    }
+
+    #[tokio::test]
+    async fn overrides_typename() {
+        const DOC: &str = r#"{
+            records {
+                __typename
+                ... on Record {
+                    id
+                    kind: __typename
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        for (legacy, expected) in [
+            (
+                true,
+                graphql_value!({"records": [
+                    {"__typename": "LegacyRecord", "id": 1, "kind": "LegacyRecord"},
+                    {"__typename": "Record", "id": 20, "kind": "Record"},
+                ]}),
+            ),
+            (
+                false,
+                graphql_value!({"records": [
+                    {"__typename": "Record", "id": 1, "kind": "Record"},
+                    {"__typename": "Record", "id": 20, "kind": "Record"},
+                ]}),
+            ),
+        ] {
+            let ctx = Context { legacy };
+
+            assert_eq!(
+                execute(DOC, None, &schema, &graphql_vars! {}, &ctx).await,
+                Ok((expected.clone(), vec![])),
+                "legacy: {legacy}",
+            );
+            assert_eq!(
+                execute_sync(DOC, None, &schema, &graphql_vars! {}, &ctx),
+                Ok((expected, vec![])),
+                "legacy: {legacy}",
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn errors_on_unknown_typename() {
+        const DOC: &str = r#"{
+            broken {
+                id
+                __typename
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+        let ctx = Context { legacy: false };
+        let expected = Ok((
+            graphql_value!({"broken": null}),
+            vec![ExecutionError::new_with_path(
+                SourcePosition::new(58, 3, 16),
+                &["broken".into(), "__typename".into()],
+                FieldError::from(
+                    "Type `Unknown` reported by `__typename` of `Broken` doesn't exist in the \
+                     schema",
+                ),
+            )],
+        ));
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &ctx).await,
+            expected,
+        );
+        assert_eq!(
+            execute_sync(DOC, None, &schema, &graphql_vars! {}, &ctx),
+            expected,
+        );
+    }
+
+    #[tokio::test]
+    async fn errors_on_impossible_typename() {
+        const DOC: &str = r#"{
+            stray {
+                id
+                __typename
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+        let ctx = Context { legacy: false };
+        let expected = Ok((
+            graphql_value!({"stray": null}),
+            vec![ExecutionError::new_with_path(
+                SourcePosition::new(57, 3, 16),
+                &["stray".into(), "__typename".into()],
+                FieldError::from(
+                    "Type `Record` reported by `__typename` of `Stray` isn't a possible \
+                     type of its abstract parent",
+                ),
+            )],
+        ));
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &ctx).await,
+            expected,
+        );
+        assert_eq!(
+            execute_sync(DOC, None, &schema, &graphql_vars! {}, &ctx),
+            expected,
+        );
+    }
+}
+
+mod extensions {
+    use super::*;
+
+    pub struct Database {
+        balance: i32,
+    }
+
+    impl juniper::Context for Database {}
+
+    #[graphql_interface(for = User, context = Database)]
+    trait Account {
+        fn balance(&self) -> i32;
+    }
+
+    mod profile {
+        use super::{Database, User};
+
+        #[juniper::graphql_object(context = Database, extension = UserProfile)]
+        impl User {
+            fn display_name(&self) -> String {
+                format!("@{}", self.name)
+            }
+
+            async fn avatar(&self, size: i32) -> String {
+                format!("{}_{size}.png", self.name)
+            }
+        }
+    }
+
+    mod billing {
+        use super::{Database, User};
+
+        #[juniper::graphql_object(context = Database, extension = UserBilling)]
+        impl User {
+            fn balance(&self, ctx: &Database) -> i32 {
+                ctx.balance * self.id
+            }
+        }
+    }
+
+    pub struct User {
+        id: i32,
+        name: String,
+    }
+
+    #[graphql_object(
+        context = Database,
+        impl = AccountValue,
+        extensions = [profile::UserProfile, billing::UserBilling],
+    )]
+    impl User {
+        fn id(&self) -> i32 {
+            self.id
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object(context = Database)]
+    impl QueryRoot {
+        fn user() -> User {
+            User {
+                id: 2,
+                name: "alice".into(),
+            }
+        }
+
+        fn account() -> AccountValue {
+            QueryRoot::user().into()
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_extension_fields() {
+        const DOC: &str = r#"{
+            user {
+                id
+                displayName
+                avatar(size: 32)
+                balance
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+        let db = Database { balance: 50 };
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &db).await,
+            Ok((
+                graphql_value!({"user": {
+                    "id": 2,
+                    "displayName": "@alice",
+                    "avatar": "alice_32.png",
+                    "balance": 100,
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_sync_extension_fields() {
+        const DOC: &str = r#"{
+            user {
+                id
+                displayName
+                balance
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+        let db = Database { balance: 50 };
+
+        assert_eq!(
+            execute_sync(DOC, None, &schema, &graphql_vars! {}, &db),
+            Ok((
+                graphql_value!({"user": {"id": 2, "displayName": "@alice", "balance": 100}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn implements_interface_via_extension() {
+        const DOC: &str = r#"{
+            account {
+                balance
+                ... on User {
+                    displayName
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+        let db = Database { balance: 50 };
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &db).await,
+            Ok((
+                graphql_value!({"account": {"balance": 100, "displayName": "@alice"}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn has_all_fields() {
+        const DOC: &str = r#"{
+            __type(name: "User") {
+                fields {
+                    name
+                    args {
+                        name
+                    }
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+        let db = Database { balance: 50 };
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &db).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [
+                    {"name": "id", "args": []},
+                    {"name": "balance", "args": []},
+                    {"name": "displayName", "args": []},
+                    {"name": "avatar", "args": [{"name": "size"}]},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+}
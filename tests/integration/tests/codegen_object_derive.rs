@@ -4,7 +4,7 @@ pub mod common;
 
 use juniper::{
     execute, graphql_object, graphql_value, graphql_vars, DefaultScalarValue, GraphQLObject,
-    ScalarValue,
+    GraphQLUnion, ScalarValue,
 };
 
 use self::common::util::{schema, schema_with_scalar};
@@ -404,6 +404,99 @@ mod nested_generic_lifetime_async {
     }
 }
 
+mod explicit_bound {
+    use juniper::{
+        macros::reflect::{BaseSubTypes, BaseType, WrappedType},
+        marker::IsOutputType,
+        GraphQLType, GraphQLValueAsync,
+    };
+
+    use super::*;
+
+    // Everything required from a type to be resolved as an object field.
+    trait Item:
+        GraphQLType<DefaultScalarValue, Context = (), TypeInfo = ()>
+        + GraphQLValueAsync<DefaultScalarValue>
+        + IsOutputType<DefaultScalarValue>
+        + BaseType<DefaultScalarValue>
+        + BaseSubTypes<DefaultScalarValue>
+        + WrappedType<DefaultScalarValue>
+    {
+    }
+
+    impl<T> Item for T where
+        T: GraphQLType<DefaultScalarValue, Context = (), TypeInfo = ()>
+            + GraphQLValueAsync<DefaultScalarValue>
+            + IsOutputType<DefaultScalarValue>
+            + BaseType<DefaultScalarValue>
+            + BaseSubTypes<DefaultScalarValue>
+            + WrappedType<DefaultScalarValue>
+    {
+    }
+
+    #[derive(GraphQLObject)]
+    struct Droid {
+        id: i32,
+    }
+
+    // No bounds on the struct itself, so they're required by the generated
+    // implementations only.
+    #[derive(GraphQLObject)]
+    #[graphql(scalar = DefaultScalarValue, bound = "T: Item + Sync")]
+    struct Page<'i, T> {
+        items: &'i [T],
+        total: i32,
+    }
+
+    struct QueryRoot(Vec<Droid>);
+
+    #[graphql_object(scalar = DefaultScalarValue)]
+    impl QueryRoot {
+        fn droids(&self) -> Page<'_, Droid> {
+            Page {
+                items: &self.0,
+                total: 3,
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        const DOC: &str = r#"{
+            droids {
+                items { id }
+                total
+            }
+        }"#;
+
+        let schema = schema(QueryRoot(vec![Droid { id: 1 }, Droid { id: 2 }]));
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"droids": {"items": [{"id": 1}, {"id": 2}], "total": 3}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn uses_type_name_without_type_params() {
+        const DOC: &str = r#"{
+            __type(name: "Page") {
+                name
+            }
+        }"#;
+
+        let schema = schema(QueryRoot(vec![]));
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"__type": {"name": "Page"}}), vec![])),
+        );
+    }
+}
+
 mod description_from_doc_comment {
     use super::*;
 
@@ -794,6 +887,82 @@ mod renamed_all_fields {
     }
 }
 
+mod renamed_all_fields_in_screaming_snake_case {
+    use super::*;
+
+    #[derive(GraphQLObject)]
+    #[graphql(rename_all = "SCREAMING_SNAKE_CASE")]
+    struct Human {
+        id: &'static str,
+        home_planet: String,
+        #[graphql(name = "asyncInfo")]
+        r#async_info: i32,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human {
+                id: "human-32",
+                home_planet: "earth".into(),
+                r#async_info: 3,
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_fields() {
+        const DOC: &str = r#"{
+            human {
+                ID
+                HOME_PLANET
+                asyncInfo
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"human": {
+                    "ID": "human-32",
+                    "HOME_PLANET": "earth",
+                    "asyncInfo": 3,
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn uses_correct_fields_names() {
+        const DOC: &str = r#"{
+            __type(name: "Human") {
+                fields {
+                    name
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [
+                    {"name": "ID"},
+                    {"name": "HOME_PLANET"},
+                    {"name": "asyncInfo"},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
 mod explicit_scalar {
     use super::*;
 
@@ -982,3 +1151,161 @@ mod explicit_custom_context {
         );
     }
 }
+
+mod typename_override {
+    use super::*;
+
+    fn human_typename(human: &Human, _: &()) -> &'static str {
+        if human.droid {
+            "Droid"
+        } else {
+            "Human"
+        }
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(typename_with = human_typename)]
+    struct Human {
+        id: &'static str,
+        droid: bool,
+    }
+
+    #[derive(GraphQLObject)]
+    struct Droid {
+        id: &'static str,
+    }
+
+    #[derive(GraphQLUnion)]
+    enum Character {
+        Human(Human),
+        Droid(Droid),
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn humans() -> Vec<Human> {
+            vec![
+                Human {
+                    id: "luke",
+                    droid: false,
+                },
+                Human {
+                    id: "r2d2",
+                    droid: true,
+                },
+            ]
+        }
+
+        fn droid() -> Droid {
+            Droid { id: "c3po" }
+        }
+
+        fn hero() -> Character {
+            Character::Droid(Droid { id: "r2d2" })
+        }
+    }
+
+    #[tokio::test]
+    async fn overrides_typename() {
+        const DOC: &str = r#"{
+            humans {
+                id
+                __typename
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"humans": [
+                    {"id": "luke", "__typename": "Human"},
+                    {"id": "r2d2", "__typename": "Droid"},
+                ]}),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod extensions {
+    use super::*;
+
+    mod stats {
+        use super::Human;
+
+        #[juniper::graphql_object(extension = HumanStats)]
+        impl Human {
+            fn name_length(&self) -> i32 {
+                self.name.len() as i32
+            }
+        }
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(extensions = stats::HumanStats)]
+    pub struct Human {
+        id: &'static str,
+        name: String,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human {
+                id: "human-32",
+                name: "Han Solo".into(),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_extension_fields() {
+        const DOC: &str = r#"{
+            human {
+                id
+                nameLength
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"human": {"id": "human-32", "nameLength": 8}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn has_all_fields() {
+        const DOC: &str = r#"{
+            __type(name: "Human") {
+                fields {
+                    name
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [
+                    {"name": "id"},
+                    {"name": "name"},
+                    {"name": "nameLength"},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+}
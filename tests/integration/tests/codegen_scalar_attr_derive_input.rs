@@ -300,6 +300,59 @@ mod all_custom_resolvers {
     }
 }
 
+mod unit_enum {
+    use super::*;
+
+    #[graphql_scalar]
+    #[derive(Clone, Copy)]
+    enum Currency {
+        #[graphql(rename = "USD")]
+        Usd,
+        #[graphql(rename = "EUR")]
+        Eur,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn currency(value: Currency) -> Currency {
+            value
+        }
+    }
+
+    #[tokio::test]
+    async fn is_graphql_scalar() {
+        const DOC: &str = r#"{
+            __type(name: "Currency") {
+                kind
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"__type": {"kind": "SCALAR"}}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_currency() {
+        const DOC: &str = r#"{
+            usd: currency(value: "USD")
+            eur: currency(value: "EUR")
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"usd": "USD", "eur": "EUR"}), vec![])),
+        );
+    }
+}
+
 mod explicit_name {
     use super::*;
 
@@ -6,8 +6,10 @@ use std::fmt;
 
 use chrono::{DateTime, TimeZone, Utc};
 use juniper::{
-    execute, graphql_object, graphql_value, graphql_vars, GraphQLScalar, InputValue,
-    ParseScalarResult, ParseScalarValue, ScalarToken, ScalarValue, Value,
+    execute, graphql_object, graphql_value, graphql_vars,
+    parser::{ParseError, SourcePosition, Spanning, Token},
+    FieldError, GraphQLError, GraphQLScalar, InputValue, ParseScalarResult, ParseScalarValue,
+    RuleError, ScalarToken, ScalarValue, Value,
 };
 
 use self::common::{
@@ -150,6 +152,83 @@ mod transparent {
             Ok((graphql_value!({"__type": {"description": null}}), vec![])),
         );
     }
+
+    #[tokio::test]
+    async fn errs_on_wrong_literal_kind() {
+        const DOC: &str = r#"{ counter(value: "0") }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Err(GraphQLError::ValidationError(vec![RuleError::new(
+                "Invalid value for argument \"value\", expected type \"Counter!\"",
+                &[SourcePosition::new(17, 0, 17)],
+            )])),
+        );
+    }
+}
+
+mod transparent_named_field {
+    use super::*;
+
+    #[derive(GraphQLScalar)]
+    #[graphql(transparent)]
+    struct Counter {
+        value: i32,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn counter(value: Counter) -> Counter {
+            value
+        }
+    }
+
+    #[tokio::test]
+    async fn is_graphql_scalar() {
+        const DOC: &str = r#"{
+            __type(name: "Counter") {
+                kind
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"__type": {"kind": "SCALAR"}}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_counter() {
+        const DOC: &str = r#"{ counter(value: 0) }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"counter": 0}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn errs_on_wrong_literal_kind() {
+        const DOC: &str = r#"{ counter(value: "0") }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Err(GraphQLError::ValidationError(vec![RuleError::new(
+                "Invalid value for argument \"value\", expected type \"Counter!\"",
+                &[SourcePosition::new(17, 0, 17)],
+            )])),
+        );
+    }
 }
 
 mod transparent_with_resolver {
@@ -298,6 +377,395 @@ mod all_custom_resolvers {
     }
 }
 
+mod field_error_from_input {
+    use super::*;
+
+    #[derive(GraphQLScalar)]
+    #[graphql(from_input_with = Self::from_input, transparent)]
+    struct Counter(i32);
+
+    impl Counter {
+        fn from_input<S: ScalarValue>(v: &InputValue<S>) -> Result<Self, FieldError<S>> {
+            v.as_int_value()
+                .filter(|i| *i >= 0)
+                .map(Self)
+                .ok_or_else(|| {
+                    FieldError::new(
+                        format!("Expected non-negative `Counter`, found: {v}"),
+                        graphql_value!({"code": "NEGATIVE_COUNTER"}),
+                    )
+                })
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn counter(value: Counter) -> Counter {
+            value
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_counter() {
+        const DOC: &str = r#"{ counter(value: 0) }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"counter": 0}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn errs_with_extensions_on_literal() {
+        const DOC: &str = r#"{ counter(value: -1) }"#;
+
+        let schema = schema(QueryRoot);
+
+        let errs = match execute(DOC, None, &schema, &graphql_vars! {}, &()).await {
+            Err(GraphQLError::ValidationError(errs)) => errs,
+            res => panic!("expected validation error, found: {res:?}"),
+        };
+        assert_eq!(
+            errs,
+            vec![RuleError::new(
+                "Invalid value for argument \"value\", expected type \"Counter!\"",
+                &[SourcePosition::new(17, 0, 17)],
+            )],
+        );
+        assert_eq!(
+            errs[0].extensions(),
+            &graphql_value!({"code": "NEGATIVE_COUNTER"}),
+        );
+    }
+
+    #[tokio::test]
+    async fn errs_with_extensions_on_variable() {
+        const DOC: &str = r#"query q($value: Counter!) { counter(value: $value) }"#;
+
+        let schema = schema(QueryRoot);
+
+        let errs = match execute(DOC, None, &schema, &graphql_vars! {"value": -1}, &()).await {
+            Err(GraphQLError::ValidationError(errs)) => errs,
+            res => panic!("expected validation error, found: {res:?}"),
+        };
+        assert_eq!(
+            errs,
+            vec![RuleError::new(
+                "Variable \"$value\" got invalid value. \
+                 Expected input scalar `Counter`. Got: `-1`. \
+                 Details: Expected non-negative `Counter`, found: -1.",
+                &[SourcePosition::new(8, 0, 8)],
+            )],
+        );
+        assert_eq!(
+            errs[0].extensions(),
+            &graphql_value!({"code": "NEGATIVE_COUNTER"}),
+        );
+    }
+}
+
+mod unit_enum {
+    use super::*;
+
+    #[derive(Clone, Copy, GraphQLScalar)]
+    enum Currency {
+        #[graphql(rename = "USD")]
+        Usd,
+        #[graphql(name = "EUR")]
+        Eur,
+        Gbp,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn currency(value: Currency) -> Currency {
+            value
+        }
+    }
+
+    #[tokio::test]
+    async fn is_graphql_scalar() {
+        const DOC: &str = r#"{
+            __type(name: "Currency") {
+                kind
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"__type": {"kind": "SCALAR"}}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_currency() {
+        const DOC: &str = r#"{
+            usd: currency(value: "USD")
+            eur: currency(value: "EUR")
+            gbp: currency(value: "Gbp")
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"usd": "USD", "eur": "EUR", "gbp": "Gbp"}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn errs_on_unknown_string() {
+        const DOC: &str = r#"query q($value: Currency!) { currency(value: $value) }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {"value": "JPY"}, &()).await,
+            Err(GraphQLError::ValidationError(vec![RuleError::new(
+                "Variable \"$value\" got invalid value. \
+                 Expected input scalar `Currency`. Got: `\"JPY\"`. \
+                 Details: Expected one of `USD`, `EUR`, `Gbp`, found: `JPY`.",
+                &[SourcePosition::new(8, 0, 8)],
+            )])),
+        );
+    }
+
+    #[tokio::test]
+    async fn errs_on_non_string_literal() {
+        const DOC: &str = r#"{ currency(value: true) }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Err(GraphQLError::ValidationError(vec![RuleError::new(
+                "Invalid value for argument \"value\", expected type \"Currency!\"",
+                &[SourcePosition::new(18, 0, 18)],
+            )])),
+        );
+    }
+}
+
+mod with_context {
+    use super::*;
+
+    struct Locale {
+        currency_sign: &'static str,
+    }
+
+    impl juniper::Context for Locale {}
+
+    #[derive(GraphQLScalar)]
+    #[graphql(
+        context = Locale,
+        to_output_with = to_output,
+        with_context,
+        transparent,
+    )]
+    struct Money(i32);
+
+    fn to_output<S: ScalarValue>(v: &Money, ctx: &Locale) -> Value<S> {
+        Value::scalar(format!("{}{}", ctx.currency_sign, v.0))
+    }
+
+    #[derive(GraphQLScalar)]
+    #[graphql(
+        context = Locale,
+        to_output_with = Self::to_output,
+        with_context,
+        transparent,
+    )]
+    struct Signed(i32);
+
+    impl Signed {
+        fn to_output<S: ScalarValue>(&self, ctx: &Locale) -> Value<S> {
+            let sign = if self.0 < 0 { '-' } else { '+' };
+            Value::scalar(format!("{sign}{}{}", ctx.currency_sign, self.0.abs()))
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object(context = Locale)]
+    impl QueryRoot {
+        fn money(#[graphql(default = Money(0))] value: Money) -> Money {
+            value
+        }
+
+        fn sign(value: Signed) -> Signed {
+            value
+        }
+    }
+
+    #[tokio::test]
+    async fn is_graphql_scalar() {
+        const DOC: &str = r#"{
+            __type(name: "Money") {
+                kind
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+        let ctx = Locale { currency_sign: "$" };
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &ctx).await,
+            Ok((graphql_value!({"__type": {"kind": "SCALAR"}}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_with_context() {
+        const DOC: &str = r#"{
+            money(value: 42)
+            default: money
+            sign(value: -3)
+        }"#;
+
+        let schema = schema(QueryRoot);
+        let ctx = Locale {
+            currency_sign: "€"
+        };
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &ctx).await,
+            Ok((
+                graphql_value!({"money": "€42", "default": "€0", "sign": "-€3"}),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod accepts_null {
+    use super::*;
+
+    /// Parses an `Int`, treating `null` as `10`.
+    #[derive(GraphQLScalar)]
+    #[graphql(from_input_with = Self::from_input, accepts_null, transparent)]
+    struct Limit(i32);
+
+    impl Limit {
+        fn from_input<S: ScalarValue>(v: &InputValue<S>) -> Result<Self, String> {
+            if v.is_null() {
+                return Ok(Self(10));
+            }
+            v.as_int_value()
+                .map(Self)
+                .ok_or_else(|| format!("Expected `Int`, found: {v}"))
+        }
+    }
+
+    /// Fails on `null`s, so it's noticeable once they're passed.
+    #[derive(GraphQLScalar)]
+    #[graphql(from_input_with = Self::from_input, transparent)]
+    struct Offset(i32);
+
+    impl Offset {
+        fn from_input<S: ScalarValue>(v: &InputValue<S>) -> Result<Self, String> {
+            v.as_int_value()
+                .map(Self)
+                .ok_or_else(|| format!("Expected `Int`, found: {v}"))
+        }
+    }
+
+    fn default_limit(_: &()) -> Limit {
+        Limit(5)
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn limit(value: Option<Limit>) -> Option<i32> {
+            value.map(|v| v.0)
+        }
+
+        fn offset(value: Option<Offset>) -> Option<i32> {
+            value.map(|v| v.0)
+        }
+
+        fn limit_with_default(#[graphql(default_with = default_limit)] value: Limit) -> i32 {
+            value.0
+        }
+    }
+
+    #[tokio::test]
+    async fn passes_null_to_from_input() {
+        let schema = schema(QueryRoot);
+
+        for (input, expected) in [
+            ("{ limit }", 10),
+            ("{ limit(value: null) }", 10),
+            ("{ limit(value: 3) }", 3),
+        ] {
+            assert_eq!(
+                execute(input, None, &schema, &graphql_vars! {}, &()).await,
+                Ok((graphql_value!({"limit": expected}), vec![])),
+                "input: {input}",
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn passes_null_variable_to_from_input() {
+        const DOC: &str = r#"query($value: Limit) { limit(value: $value) }"#;
+
+        let schema = schema(QueryRoot);
+
+        for vars in [graphql_vars! {}, graphql_vars! {"value": null}] {
+            assert_eq!(
+                execute(DOC, None, &schema, &vars, &()).await,
+                Ok((graphql_value!({"limit": 10}), vec![])),
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn never_passes_null_to_from_input_by_default() {
+        let schema = schema(QueryRoot);
+
+        for (input, expected) in [
+            ("{ offset }", graphql_value!(null)),
+            ("{ offset(value: null) }", graphql_value!(null)),
+            ("{ offset(value: 3) }", graphql_value!(3)),
+        ] {
+            assert_eq!(
+                execute(input, None, &schema, &graphql_vars! {}, &()).await,
+                Ok((graphql_value!({ "offset": expected }), vec![])),
+                "input: {input}",
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn prefers_default_with() {
+        let schema = schema(QueryRoot);
+
+        for (input, expected) in [
+            ("{ limitWithDefault }", 5),
+            ("{ limitWithDefault(value: null) }", 5),
+            ("{ limitWithDefault(value: 3) }", 3),
+        ] {
+            assert_eq!(
+                execute(input, None, &schema, &graphql_vars! {}, &()).await,
+                Ok((graphql_value!({"limitWithDefault": expected}), vec![])),
+                "input: {input}",
+            );
+        }
+    }
+}
 mod explicit_name {
     use super::*;
 
@@ -508,6 +976,108 @@ mod multiple_delegated_parse_token {
     }
 }
 
+mod multiple_literal_kinds_parse_token_with {
+    use super::*;
+
+    #[derive(GraphQLScalar)]
+    #[graphql(
+        to_output_with = to_output,
+        from_input_with = from_input,
+        parse_token_with = parse_token,
+    )]
+    enum StringOrInt {
+        String(String),
+        Int(i32),
+    }
+
+    fn to_output<S: ScalarValue>(v: &StringOrInt) -> Value<S> {
+        match v {
+            StringOrInt::String(s) => Value::scalar(s.to_owned()),
+            StringOrInt::Int(i) => Value::scalar(*i),
+        }
+    }
+
+    fn from_input<S: ScalarValue>(v: &InputValue<S>) -> Result<StringOrInt, String> {
+        v.as_string_value()
+            .map(|s| StringOrInt::String(s.into()))
+            .or_else(|| v.as_int_value().map(StringOrInt::Int))
+            .ok_or_else(|| format!("Expected `String` or `Int`, found: {v}"))
+    }
+
+    fn parse_token<S: ScalarValue>(value: ScalarToken<'_>) -> ParseScalarResult<S> {
+        match value {
+            ScalarToken::String(_) => <String as ParseScalarValue<S>>::from_str(value),
+            ScalarToken::Int(_) => <i32 as ParseScalarValue<S>>::from_str(value),
+            ScalarToken::Float(_) => Err(ParseError::unexpected_token(Token::Scalar(value))),
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn kind(value: StringOrInt) -> String {
+            match value {
+                StringOrInt::String(s) => format!("String: {s}"),
+                StringOrInt::Int(i) => format!("Int: {i}"),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_both_literal_kinds() {
+        const DOC: &str = r#"{
+            string: kind(value: "5")
+            int: kind(value: 5)
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"string": "String: 5", "int": "Int: 5"}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_both_variable_kinds() {
+        const DOC: &str = r#"query($s: StringOrInt!, $i: StringOrInt!) {
+            string: kind(value: $s)
+            int: kind(value: $i)
+        }"#;
+
+        let schema = schema(QueryRoot);
+        let vars = graphql_vars! {"s": "5", "i": 5};
+
+        assert_eq!(
+            execute(DOC, None, &schema, &vars, &()).await,
+            Ok((
+                graphql_value!({"string": "String: 5", "int": "Int: 5"}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn errs_on_unaccepted_literal_kind() {
+        const DOC: &str = r#"{ kind(value: 5.5) }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Err(GraphQLError::ParseError(Spanning::start_end(
+                &SourcePosition::new(14, 0, 14),
+                &SourcePosition::new(17, 0, 17),
+                ParseError::ExpectedScalarError("There needs to be a Float type"),
+            ))),
+        );
+    }
+}
+
 mod where_attribute {
     use super::*;
 
@@ -589,6 +1159,69 @@ mod where_attribute {
     }
 }
 
+mod bound_attribute {
+    use super::*;
+
+    // Generated implementations require `Sync` offsets, while the struct
+    // itself doesn't.
+    #[derive(GraphQLScalar)]
+    #[graphql(
+        to_output_with = to_output,
+        from_input_with = from_input,
+        parse_token(String),
+        bound = "Tz: From<Utc>, Tz::Offset: fmt::Display + Sync",
+    )]
+    struct CustomDateTime<Tz: TimeZone>(DateTime<Tz>);
+
+    fn to_output<S, Tz>(v: &CustomDateTime<Tz>) -> Value<S>
+    where
+        S: ScalarValue,
+        Tz: From<Utc> + TimeZone,
+        Tz::Offset: fmt::Display,
+    {
+        Value::scalar(v.0.to_rfc3339())
+    }
+
+    fn from_input<S, Tz>(v: &InputValue<S>) -> Result<CustomDateTime<Tz>, String>
+    where
+        S: ScalarValue,
+        Tz: From<Utc> + TimeZone,
+        Tz::Offset: fmt::Display,
+    {
+        v.as_string_value()
+            .ok_or_else(|| format!("Expected `String`, found: {v}"))
+            .and_then(|s| {
+                DateTime::parse_from_rfc3339(s)
+                    .map(|dt| CustomDateTime(dt.with_timezone(&Tz::from(Utc))))
+                    .map_err(|e| format!("Failed to parse `CustomDateTime`: {e}"))
+            })
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn date_time(value: CustomDateTime<Utc>) -> CustomDateTime<Utc> {
+            value
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_custom_date_time() {
+        const DOC: &str = r#"{ dateTime(value: "1996-12-19T16:39:57-08:00") }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"dateTime": "1996-12-20T00:39:57+00:00"}),
+                vec![],
+            )),
+        );
+    }
+}
+
 mod with_self {
     use super::*;
 
@@ -904,6 +1537,79 @@ mod description_from_attribute {
     }
 }
 
+mod description_from_function {
+    use super::*;
+
+    /// Doc comment
+    #[derive(GraphQLScalar)]
+    #[graphql(
+        description_with = describe,
+        specified_by_url = "https://example.com/counter",
+        parse_token(i32),
+    )]
+    struct Counter(i32);
+
+    impl Counter {
+        fn to_output<S: ScalarValue>(&self) -> Value<S> {
+            Value::scalar(self.0)
+        }
+
+        fn from_input<S: ScalarValue>(v: &InputValue<S>) -> Result<Self, String> {
+            v.as_int_value()
+                .map(Self)
+                .ok_or_else(|| format!("Expected `Counter`, found: {v}"))
+        }
+    }
+
+    fn describe() -> String {
+        format!("Counter v{}", 1 + 1)
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn counter(value: Counter) -> Counter {
+            value
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_counter() {
+        const DOC: &str = r#"{ counter(value: 0) }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"counter": 0}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn has_description_and_specified_by_url() {
+        const DOC: &str = r#"{
+            __type(name: "Counter") {
+                description
+                specifiedByUrl
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {
+                    "description": "Counter v2",
+                    "specifiedByUrl": "https://example.com/counter",
+                }}),
+                vec![],
+            )),
+        );
+    }
+}
+
 mod custom_scalar {
     use super::*;
 
@@ -136,3 +136,68 @@ mod allow_missing_attributes {
             .is_type::<bool>());
     }
 }
+
+mod wide_integers {
+    use super::*;
+
+    #[derive(Clone, Debug, Deserialize, PartialEq, ScalarValue, Serialize)]
+    #[serde(untagged)]
+    pub enum CustomScalarValue {
+        #[value(as_float, as_int, as_i64, as_u64, as_i128)]
+        Int(i32),
+        #[value(as_i64, as_u64, as_i128)]
+        Long(i64),
+        #[value(as_i64, as_u64, as_i128)]
+        UnsignedLong(u64),
+        #[value(as_i128 = i128::clone)]
+        Huge(i128),
+        #[value(as_float)]
+        Float(f64),
+        #[value(as_str, as_string, into_string)]
+        String(String),
+        #[value(as_bool)]
+        Boolean(bool),
+    }
+
+    #[test]
+    fn as_i64() {
+        assert_eq!(CustomScalarValue::Int(-5).as_i64(), Some(-5));
+        assert_eq!(CustomScalarValue::Long(i64::MIN).as_i64(), Some(i64::MIN));
+        assert_eq!(CustomScalarValue::UnsignedLong(5).as_i64(), Some(5));
+        assert_eq!(CustomScalarValue::UnsignedLong(u64::MAX).as_i64(), None);
+        assert_eq!(CustomScalarValue::Huge(5).as_i64(), None);
+        assert_eq!(CustomScalarValue::Float(0.5).as_i64(), None);
+    }
+
+    #[test]
+    fn as_u64() {
+        assert_eq!(CustomScalarValue::Int(5).as_u64(), Some(5));
+        assert_eq!(CustomScalarValue::Int(-5).as_u64(), None);
+        assert_eq!(CustomScalarValue::Long(-5).as_u64(), None);
+        assert_eq!(
+            CustomScalarValue::UnsignedLong(u64::MAX).as_u64(),
+            Some(u64::MAX),
+        );
+    }
+
+    #[test]
+    fn as_i128() {
+        assert_eq!(CustomScalarValue::Int(-5).as_i128(), Some(-5));
+        assert_eq!(
+            CustomScalarValue::UnsignedLong(u64::MAX).as_i128(),
+            Some(u64::MAX.into()),
+        );
+        assert_eq!(
+            CustomScalarValue::Huge(i128::MAX).as_i128(),
+            Some(i128::MAX)
+        );
+        assert_eq!(CustomScalarValue::String("5".into()).as_i128(), None);
+    }
+
+    #[test]
+    fn defaults_to_none_when_not_marked() {
+        assert_eq!(super::trivial::CustomScalarValue::Int(5).as_i64(), None);
+        assert_eq!(super::trivial::CustomScalarValue::Int(5).as_u64(), None);
+        assert_eq!(super::trivial::CustomScalarValue::Int(5).as_i128(), None);
+    }
+}
@@ -373,6 +373,127 @@ mod fallible_method {
     }
 }
 
+mod fallible_stream_items {
+    use futures::StreamExt as _;
+    use juniper::{parser::SourcePosition, ExecutionError, Value};
+    use juniper_subscriptions::Connection;
+
+    use super::*;
+
+    struct Human;
+
+    #[graphql_subscription]
+    impl Human {
+        async fn id() -> Stream<'static, FieldResult<String>> {
+            Box::pin(stream::iter(vec![
+                Ok("human-32".into()),
+                Err(FieldError::new("Malformed event", graphql_value!(null))),
+                Ok("human-33".into()),
+            ]))
+        }
+
+        async fn home_planet() -> FieldResult<Stream<'static, FieldResult<String>>> {
+            Err(FieldError::new("Unavailable", graphql_value!(null)))
+        }
+    }
+
+    async fn collect_events<'a>(
+        doc: &'a str,
+        schema: &'a RootNode<'static, Query, EmptyMutation<()>, Human>,
+    ) -> Vec<(Value, Vec<ExecutionError<DefaultScalarValue>>)> {
+        let (stream, errs) = resolve_into_stream(doc, None, schema, &graphql_vars! {}, &())
+            .await
+            .expect("failed to resolve into stream");
+        Connection::from_stream(stream, errs)
+            .map(|out| (out.data, out.errors))
+            .collect()
+            .await
+    }
+
+    #[tokio::test]
+    async fn continues_after_erroneous_event() {
+        const DOC: &str = r#"subscription {
+            id
+        }"#;
+
+        let schema = schema(Query, Human);
+
+        assert_eq!(
+            collect_events(DOC, &schema).await,
+            vec![
+                (graphql_value!({"id": "human-32"}), vec![]),
+                // NOTICE: Despite `id` being a `String!`, the event's data is
+                //         not nulled out as a whole, as the spec would require.
+                (
+                    graphql_value!({"id": null}),
+                    vec![ExecutionError::new(
+                        SourcePosition::new(27, 1, 12),
+                        &["id"],
+                        FieldError::new("Malformed event", graphql_value!(null)),
+                    )],
+                ),
+                (graphql_value!({"id": "human-33"}), vec![]),
+            ],
+        );
+    }
+
+    #[tokio::test]
+    async fn ends_on_stream_level_error() {
+        const DOC: &str = r#"subscription {
+            homePlanet
+        }"#;
+
+        let schema = schema(Query, Human);
+
+        assert_eq!(
+            collect_events(DOC, &schema).await,
+            vec![(
+                graphql_value!(null),
+                vec![ExecutionError::new(
+                    SourcePosition::new(27, 1, 12),
+                    &["homePlanet"],
+                    FieldError::new("Unavailable", graphql_value!(null)),
+                )],
+            )],
+        );
+    }
+
+    #[tokio::test]
+    async fn has_correct_graphql_type() {
+        const DOC: &str = r#"{
+            __type(name: "Human") {
+                fields {
+                    name
+                    type {
+                        kind
+                        ofType {
+                            name
+                        }
+                    }
+                }
+            }
+        }"#;
+
+        let schema = schema(Query, Human);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {
+                    "fields": [{
+                        "name": "id",
+                        "type": {"kind": "NON_NULL", "ofType": {"name": "String"}},
+                    }, {
+                        "name": "homePlanet",
+                        "type": {"kind": "NON_NULL", "ofType": {"name": "String"}},
+                    }]
+                }}),
+                vec![],
+            )),
+        );
+    }
+}
+
 mod argument {
     use super::*;
 
@@ -1790,3 +1911,111 @@ mod executor {
         );
     }
 }
+
+mod look_ahead {
+    use juniper::{GraphQLObject, LookAheadMethods as _, LookAheadValue, ID};
+
+    use super::*;
+
+    #[derive(GraphQLObject)]
+    struct Message {
+        channel: ID,
+        text: String,
+        requested: Vec<String>,
+    }
+
+    struct Human;
+
+    #[graphql_subscription(scalar = S: ScalarValue)]
+    impl Human {
+        async fn messages<S: ScalarValue>(
+            channel: ID,
+            executor: &Executor<'_, '_, (), S>,
+        ) -> Stream<'static, Message> {
+            let look_ahead = executor.look_ahead();
+            let requested = look_ahead
+                .children()
+                .into_iter()
+                .map(|c| {
+                    let args = c
+                        .arguments()
+                        .iter()
+                        .map(|a| a.name())
+                        .collect::<Vec<_>>()
+                        .join(",");
+                    format!("{}({args})", c.field_name())
+                })
+                .collect::<Vec<_>>();
+            let channel_arg = look_ahead
+                .argument("channel")
+                .and_then(|a| match a.value() {
+                    LookAheadValue::Scalar(s) => s.as_str(),
+                    _ => None,
+                })
+                .unwrap_or_default()
+                .to_owned();
+
+            Box::pin(stream::once(future::ready(Message {
+                text: format!("`{channel_arg}` via `{}`", look_ahead.field_name()),
+                channel,
+                requested,
+            })))
+        }
+    }
+
+    #[tokio::test]
+    async fn reflects_selection_below_field() {
+        const DOC: &str = r#"subscription {
+            messages(channel: "general") {
+                requested
+                channel
+            }
+        }"#;
+
+        let schema = schema(Query, Human);
+
+        assert_eq!(
+            resolve_into_stream(DOC, None, &schema, &graphql_vars! {}, &())
+                .then(|s| extract_next(s))
+                .await,
+            Ok((
+                graphql_value!({"messages": {
+                    "requested": ["requested()", "channel()"],
+                    "channel": "general",
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn reflects_arguments_and_aliases() {
+        const DOC: &str = r#"subscription($channel: ID!) {
+            general: messages(channel: $channel) {
+                text
+                ... on Message { requested }
+            }
+        }"#;
+
+        let schema = schema(Query, Human);
+
+        assert_eq!(
+            resolve_into_stream(
+                DOC,
+                None,
+                &schema,
+                &graphql_vars! {"channel": "random"},
+                &(),
+            )
+            .then(|s| extract_next(s))
+            .await,
+            Ok((
+                graphql_value!({"general": {
+                    "text": "`random` via `general`",
+                    "requested": ["text()", "requested()"],
+                }}),
+                vec![],
+            )),
+        );
+    }
+}
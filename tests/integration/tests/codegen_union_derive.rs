@@ -263,7 +263,6 @@ mod generic_enum {
     }
 }
 
-/* TODO: make it work
 mod generic_lifetime_enum {
     use super::*;
 
@@ -356,7 +355,134 @@ mod generic_lifetime_enum {
         );
     }
 }
-*/
+
+mod wrapped_generic_enum {
+    use super::*;
+
+    #[derive(GraphQLObject)]
+    struct GenericEwok<T = ()> {
+        id: String,
+        #[graphql(ignore)]
+        _t: PhantomData<T>,
+    }
+
+    #[derive(GraphQLUnion)]
+    enum Character<'a, T = ()> {
+        A(&'a Human),
+        B(Box<Droid>),
+        C(GenericEwok<T>),
+    }
+
+    enum QueryRoot {
+        Human(Human),
+        Droid,
+        Ewok,
+    }
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn character(&self) -> Character<'_> {
+            match self {
+                Self::Human(h) => Character::A(h),
+                Self::Droid => Character::B(Box::new(Droid {
+                    id: "droid-99".into(),
+                    primary_function: "run".into(),
+                })),
+                Self::Ewok => Character::C(GenericEwok {
+                    id: "ewok-1".into(),
+                    _t: PhantomData,
+                }),
+            }
+        }
+    }
+
+    const DOC: &str = r#"{
+        character {
+            __typename
+            ... on Human {
+                humanId: id
+                homePlanet
+            }
+            ... on Droid {
+                droidId: id
+            }
+            ... on GenericEwok {
+                ewokId: id
+            }
+        }
+    }"#;
+
+    #[tokio::test]
+    async fn resolves_human() {
+        let schema = schema(QueryRoot::Human(Human {
+            id: "human-32".into(),
+            home_planet: "earth".into(),
+        }));
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"character": {
+                    "__typename": "Human",
+                    "humanId": "human-32",
+                    "homePlanet": "earth",
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_droid() {
+        let schema = schema(QueryRoot::Droid);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"character": {"__typename": "Droid", "droidId": "droid-99"}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_ewok() {
+        let schema = schema(QueryRoot::Ewok);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"character": {"__typename": "GenericEwok", "ewokId": "ewok-1"}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn lists_possible_types() {
+        const DOC: &str = r#"{
+            __type(name: "Character") {
+                possibleTypes {
+                    name
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot::Droid);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"possibleTypes": [
+                    {"name": "Human"},
+                    {"name": "Droid"},
+                    {"name": "GenericEwok"},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+}
 
 mod description_from_doc_comments {
     use super::*;
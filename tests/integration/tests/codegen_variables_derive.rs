@@ -0,0 +1,249 @@
+//! Tests for `#[derive(GraphQLVariables)]` macro.
+
+pub mod common;
+
+use juniper::{
+    execute, graphql_object, graphql_value, graphql_vars, DefaultScalarValue, GraphQLEnum,
+    GraphQLInputObject, GraphQLVariables, Variables,
+};
+
+use self::common::{
+    util::{schema, schema_with_scalar},
+    MyScalarValue,
+};
+
+mod trivial {
+    use super::*;
+
+    #[derive(GraphQLVariables)]
+    struct Vars {
+        id: i32,
+        filter: Option<String>,
+        user_name: String,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn echo(id: i32, filter: Option<String>, user_name: String) -> String {
+            format!(
+                "{id}:{}:{user_name}",
+                filter.unwrap_or_else(|| "none".into())
+            )
+        }
+    }
+
+    #[test]
+    fn converts_into_variables() {
+        let vars: Variables = Vars {
+            id: 1,
+            filter: Some("active".into()),
+            user_name: "Alice".into(),
+        }
+        .into();
+
+        assert_eq!(
+            vars,
+            graphql_vars! {"id": 1, "filter": "active", "userName": "Alice"},
+        );
+    }
+
+    #[test]
+    fn converts_none_into_null() {
+        let vars = Variables::<DefaultScalarValue>::from(&Vars {
+            id: 1,
+            filter: None,
+            user_name: "Bob".into(),
+        });
+
+        assert_eq!(
+            vars,
+            graphql_vars! {"id": 1, "filter": null, "userName": "Bob"},
+        );
+    }
+
+    #[tokio::test]
+    async fn executes() {
+        const DOC: &str = r#"query($id: Int!, $filter: String, $userName: String!) {
+            echo(id: $id, filter: $filter, userName: $userName)
+        }"#;
+
+        let schema = schema(QueryRoot);
+        let vars = Vars {
+            id: 42,
+            filter: None,
+            user_name: "Alice".into(),
+        };
+
+        assert_eq!(
+            execute(DOC, None, &schema, &vars.into(), &()).await,
+            Ok((graphql_value!({"echo": "42:none:Alice"}), vec![])),
+        );
+    }
+}
+
+mod nested_input {
+    use super::*;
+
+    #[derive(GraphQLEnum)]
+    enum Order {
+        Asc,
+        Desc,
+    }
+
+    #[derive(GraphQLInputObject)]
+    struct Page {
+        first: i32,
+        after: Option<String>,
+    }
+
+    #[derive(GraphQLVariables)]
+    struct Vars {
+        page: Page,
+        order: Order,
+        tags: Vec<String>,
+    }
+
+    #[test]
+    fn converts_into_variables() {
+        let vars = Variables::<DefaultScalarValue>::from(Vars {
+            page: Page {
+                first: 10,
+                after: None,
+            },
+            order: Order::Desc,
+            tags: vec!["a".into(), "b".into()],
+        });
+
+        assert_eq!(
+            vars,
+            graphql_vars! {
+                "page": {"first": 10, "after": null},
+                "order": "DESC",
+                "tags": ["a", "b"],
+            },
+        );
+    }
+}
+
+mod renamed {
+    use super::*;
+
+    #[derive(GraphQLVariables)]
+    #[graphql(rename_all = "none")]
+    struct Vars {
+        user_id: i32,
+        #[graphql(name = "after")]
+        cursor: String,
+    }
+
+    #[derive(GraphQLVariables)]
+    #[graphql(rename_all = "SCREAMING_SNAKE_CASE")]
+    struct ScreamingVars {
+        user_id: i32,
+    }
+
+    #[test]
+    fn uses_explicit_names() {
+        let vars = Variables::<DefaultScalarValue>::from(Vars {
+            user_id: 1,
+            cursor: "c".into(),
+        });
+
+        assert_eq!(vars, graphql_vars! {"user_id": 1, "after": "c"});
+
+        let vars = Variables::<DefaultScalarValue>::from(ScreamingVars { user_id: 2 });
+
+        assert_eq!(vars, graphql_vars! {"USER_ID": 2});
+    }
+}
+
+mod ignored_field {
+    use super::*;
+
+    #[derive(GraphQLVariables)]
+    struct Vars {
+        r#id: i32,
+        #[graphql(ignore)]
+        _internal: std::time::Duration,
+        #[graphql(skip)]
+        _marker: (),
+    }
+
+    #[test]
+    fn omits_ignored_fields() {
+        let vars = Variables::<DefaultScalarValue>::from(Vars {
+            r#id: 3,
+            _internal: std::time::Duration::from_secs(1),
+            _marker: (),
+        });
+
+        assert_eq!(vars, graphql_vars! {"id": 3});
+    }
+}
+
+mod empty {
+    use super::*;
+
+    #[derive(GraphQLVariables)]
+    struct Vars {}
+
+    #[test]
+    fn converts_into_empty_variables() {
+        assert_eq!(
+            Variables::<DefaultScalarValue>::from(Vars {}),
+            graphql_vars! {},
+        );
+    }
+}
+
+mod generic_lifetime {
+    use super::*;
+
+    #[derive(GraphQLVariables)]
+    struct Vars<'a> {
+        name: &'a str,
+    }
+
+    #[test]
+    fn converts_into_variables() {
+        let name = String::from("Alice");
+
+        assert_eq!(
+            Variables::<DefaultScalarValue>::from(Vars { name: &name }),
+            graphql_vars! {"name": "Alice"},
+        );
+    }
+}
+
+mod custom_scalar {
+    use super::*;
+
+    #[derive(GraphQLVariables)]
+    #[graphql(scalar = MyScalarValue)]
+    struct Vars {
+        id: i32,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object(scalar = MyScalarValue)]
+    impl QueryRoot {
+        fn id(id: i32) -> i32 {
+            id
+        }
+    }
+
+    #[tokio::test]
+    async fn executes() {
+        const DOC: &str = r#"query($id: Int!) { id(id: $id) }"#;
+
+        let schema = schema_with_scalar::<MyScalarValue, _, _>(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &Vars { id: 7 }.into(), &()).await,
+            Ok((graphql_value!({"id": 7}), vec![])),
+        );
+    }
+}
@@ -0,0 +1,157 @@
+//! Tests for objects using narrower contexts, converted from the root one via
+//! [`FromContext`].
+
+use std::collections::HashMap;
+
+use juniper::{
+    execute, execute_sync, graphql_object, graphql_value, graphql_vars, EmptyMutation,
+    EmptySubscription, FieldResult, FromContext, RootNode,
+};
+
+pub struct Users {
+    names: HashMap<i32, &'static str>,
+}
+
+impl juniper::Context for Users {}
+
+pub struct Billing {
+    balances: HashMap<i32, i32>,
+}
+
+impl juniper::Context for Billing {}
+
+pub struct AppContext {
+    users: Users,
+    billing: Billing,
+    request_id: &'static str,
+}
+
+impl juniper::Context for AppContext {}
+
+impl FromContext<AppContext> for Users {
+    fn from(ctx: &AppContext) -> &Self {
+        &ctx.users
+    }
+}
+
+impl FromContext<AppContext> for Billing {
+    fn from(ctx: &AppContext) -> &Self {
+        &ctx.billing
+    }
+}
+
+struct User {
+    id: i32,
+}
+
+#[graphql_object(context = Users)]
+impl User {
+    fn name(&self, context: &Users) -> Option<&'static str> {
+        context.names.get(&self.id).copied()
+    }
+
+    fn friend(&self) -> User {
+        User { id: self.id + 1 }
+    }
+}
+
+struct Account {
+    user_id: i32,
+}
+
+#[graphql_object(context = Billing)]
+impl Account {
+    async fn balance(&self, context: &Billing) -> i32 {
+        context
+            .balances
+            .get(&self.user_id)
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+struct Query;
+
+#[graphql_object(context = AppContext)]
+impl Query {
+    fn request_id(context: &AppContext) -> &'static str {
+        context.request_id
+    }
+
+    fn user(id: i32) -> User {
+        User { id }
+    }
+
+    fn users() -> Vec<User> {
+        vec![User { id: 1 }, User { id: 2 }]
+    }
+
+    fn account(user_id: i32) -> FieldResult<Option<Account>> {
+        Ok((user_id > 0).then_some(Account { user_id }))
+    }
+}
+
+type Schema = RootNode<'static, Query, EmptyMutation<AppContext>, EmptySubscription<AppContext>>;
+
+fn schema() -> Schema {
+    Schema::new(Query, EmptyMutation::new(), EmptySubscription::new())
+}
+
+fn context() -> AppContext {
+    AppContext {
+        users: Users {
+            names: [(1, "Alice"), (2, "Bob")].into_iter().collect(),
+        },
+        billing: Billing {
+            balances: [(1, 100)].into_iter().collect(),
+        },
+        request_id: "req-1",
+    }
+}
+
+#[test]
+fn converts_root_context_for_objects() {
+    let query = r#"{
+        requestId
+        user(id: 1) { name friend { name friend { name } } }
+        users { name }
+    }"#;
+
+    assert_eq!(
+        execute_sync(query, None, &schema(), &graphql_vars! {}, &context()),
+        Ok((
+            graphql_value!({
+                "requestId": "req-1",
+                "user": {
+                    "name": "Alice",
+                    "friend": {"name": "Bob", "friend": {"name": null}},
+                },
+                "users": [{"name": "Alice"}, {"name": "Bob"}],
+            }),
+            vec![],
+        )),
+    );
+}
+
+#[tokio::test]
+async fn uses_different_contexts_in_one_query() {
+    let query = r#"{
+        user(id: 1) { name }
+        account(userId: 1) { balance }
+        none: account(userId: 0) { balance }
+        other: account(userId: 2) { balance }
+    }"#;
+
+    assert_eq!(
+        execute(query, None, &schema(), &graphql_vars! {}, &context()).await,
+        Ok((
+            graphql_value!({
+                "user": {"name": "Alice"},
+                "account": {"balance": 100},
+                "none": null,
+                "other": {"balance": 0},
+            }),
+            vec![],
+        )),
+    );
+}
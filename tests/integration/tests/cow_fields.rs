@@ -0,0 +1,121 @@
+//! Tests for [`Cow`]s being used as GraphQL output types.
+
+use std::borrow::Cow;
+
+use juniper::{
+    execute, execute_sync, graphql_object, graphql_value, graphql_vars, EmptyMutation,
+    EmptySubscription, RootNode,
+};
+
+struct Context {
+    name: String,
+}
+
+impl juniper::Context for Context {}
+
+struct QueryRoot;
+
+#[graphql_object(context = Context)]
+impl QueryRoot {
+    fn borrowed<'c>(ctx: &'c Context) -> Cow<'c, str> {
+        Cow::Borrowed(&ctx.name)
+    }
+
+    fn owned(ctx: &Context) -> Cow<'static, str> {
+        Cow::Owned(ctx.name.to_uppercase())
+    }
+
+    fn maybe(is_some: bool) -> Option<Cow<'static, str>> {
+        is_some.then(|| Cow::Borrowed("some"))
+    }
+
+    fn list<'c>(ctx: &'c Context) -> Vec<Cow<'c, str>> {
+        vec![Cow::Borrowed(&ctx.name), Cow::Owned(ctx.name.repeat(2))]
+    }
+}
+
+fn schema() -> RootNode<'static, QueryRoot, EmptyMutation<Context>, EmptySubscription<Context>> {
+    RootNode::new(QueryRoot, EmptyMutation::new(), EmptySubscription::new())
+}
+
+fn context() -> Context {
+    Context {
+        name: "Luke".into(),
+    }
+}
+
+#[tokio::test]
+async fn resolves_borrowed_and_owned() {
+    const DOC: &str = r#"{
+        borrowed
+        owned
+        some: maybe(isSome: true)
+        none: maybe(isSome: false)
+        list
+    }"#;
+
+    let expected = graphql_value!({
+        "borrowed": "Luke",
+        "owned": "LUKE",
+        "some": "some",
+        "none": null,
+        "list": ["Luke", "LukeLuke"],
+    });
+
+    assert_eq!(
+        execute(DOC, None, &schema(), &graphql_vars! {}, &context()).await,
+        Ok((expected.clone(), vec![])),
+    );
+    assert_eq!(
+        execute_sync(DOC, None, &schema(), &graphql_vars! {}, &context()),
+        Ok((expected, vec![])),
+    );
+}
+
+#[tokio::test]
+async fn is_string_type() {
+    const DOC: &str = r#"{
+        __type(name: "QueryRoot") {
+            fields {
+                name
+                type {
+                    kind
+                    name
+                    ofType {
+                        kind
+                        name
+                        ofType { kind name }
+                    }
+                }
+            }
+        }
+    }"#;
+
+    assert_eq!(
+        execute(DOC, None, &schema(), &graphql_vars! {}, &context()).await,
+        Ok((
+            graphql_value!({"__type": {"fields": [{
+                "name": "borrowed",
+                "type": {"kind": "NON_NULL", "name": null, "ofType": {
+                    "kind": "SCALAR", "name": "String", "ofType": null,
+                }},
+            }, {
+                "name": "owned",
+                "type": {"kind": "NON_NULL", "name": null, "ofType": {
+                    "kind": "SCALAR", "name": "String", "ofType": null,
+                }},
+            }, {
+                "name": "maybe",
+                "type": {"kind": "SCALAR", "name": "String", "ofType": null},
+            }, {
+                "name": "list",
+                "type": {"kind": "NON_NULL", "name": null, "ofType": {
+                    "kind": "LIST", "name": null, "ofType": {
+                        "kind": "NON_NULL", "name": null,
+                    },
+                }},
+            }]}}),
+            vec![],
+        )),
+    );
+}
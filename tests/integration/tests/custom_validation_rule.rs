@@ -0,0 +1,172 @@
+//! Tests for user-defined validation rules registered via
+//! `RootNode::with_validation_rule()`.
+
+pub mod common;
+
+use juniper::{
+    execute, graphql_object, graphql_value, graphql_vars,
+    parser::SourcePosition,
+    validation::{ValidationRule, ValidatorContext, Visitor},
+    DefaultScalarValue, Field, GraphQLError, RuleError, ScalarValue, Spanning,
+};
+
+use self::common::util::schema;
+
+/// Rule forbidding queries selecting more than `limit` fields.
+struct MaxSelectedFields {
+    limit: usize,
+}
+
+struct MaxSelectedFieldsVisitor {
+    limit: usize,
+    count: usize,
+}
+
+impl ValidationRule<DefaultScalarValue> for MaxSelectedFields {
+    fn visitor<'a>(&self) -> Box<dyn Visitor<'a, DefaultScalarValue> + 'a> {
+        Box::new(MaxSelectedFieldsVisitor {
+            limit: self.limit,
+            count: 0,
+        })
+    }
+}
+
+impl<'a, S: ScalarValue> Visitor<'a, S> for MaxSelectedFieldsVisitor {
+    fn enter_field(&mut self, ctx: &mut ValidatorContext<'a, S>, field: &'a Spanning<Field<S>>) {
+        self.count += 1;
+        if self.count == self.limit + 1 {
+            ctx.report_error(
+                &format!("Query selects more than {} fields", self.limit),
+                &[field.start],
+            );
+        }
+    }
+}
+
+/// Rule forbidding selecting a field with the `name`.
+struct BannedField {
+    name: &'static str,
+}
+
+impl ValidationRule<DefaultScalarValue> for BannedField {
+    fn visitor<'a>(&self) -> Box<dyn Visitor<'a, DefaultScalarValue> + 'a> {
+        Box::new(BannedFieldVisitor(self.name))
+    }
+}
+
+struct BannedFieldVisitor(&'static str);
+
+impl<'a, S: ScalarValue> Visitor<'a, S> for BannedFieldVisitor {
+    fn enter_field(&mut self, ctx: &mut ValidatorContext<'a, S>, field: &'a Spanning<Field<S>>) {
+        if field.item.name.item == self.0 {
+            ctx.report_error(&format!("Field \"{}\" is banned", self.0), &[field.start]);
+        }
+    }
+}
+
+struct Human;
+
+#[graphql_object]
+impl Human {
+    fn id() -> &'static str {
+        "human-32"
+    }
+
+    fn name() -> &'static str {
+        "Han Solo"
+    }
+
+    fn home_planet() -> &'static str {
+        "Corellia"
+    }
+}
+
+struct QueryRoot;
+
+#[graphql_object]
+impl QueryRoot {
+    fn human() -> Human {
+        Human
+    }
+}
+
+#[tokio::test]
+async fn passes_within_limit() {
+    const DOC: &str = "{ human { id name } }";
+
+    let schema = schema(QueryRoot).with_validation_rule(MaxSelectedFields { limit: 3 });
+
+    assert_eq!(
+        execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+        Ok((
+            graphql_value!({"human": {"id": "human-32", "name": "Han Solo"}}),
+            vec![],
+        )),
+    );
+}
+
+#[tokio::test]
+async fn fails_beyond_limit() {
+    const DOC: &str = "{ human { id name homePlanet } }";
+
+    let schema = schema(QueryRoot).with_validation_rule(MaxSelectedFields { limit: 3 });
+
+    assert_eq!(
+        execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+        Err(GraphQLError::ValidationError(vec![RuleError::new(
+            "Query selects more than 3 fields",
+            &[SourcePosition::new(18, 0, 18)],
+        )])),
+    );
+}
+
+#[tokio::test]
+async fn counts_fields_per_query() {
+    const DOC: &str = "{ human { id name } }";
+
+    let schema = schema(QueryRoot).with_validation_rule(MaxSelectedFields { limit: 3 });
+
+    for _ in 0..2 {
+        assert!(execute(DOC, None, &schema, &graphql_vars! {}, &())
+            .await
+            .is_ok());
+    }
+}
+
+#[tokio::test]
+async fn runs_all_registered_rules() {
+    const DOC: &str = "{ human { id name homePlanet } }";
+
+    let schema = schema(QueryRoot)
+        .with_validation_rule(BannedField { name: "name" })
+        .with_validation_rule(MaxSelectedFields { limit: 3 });
+
+    assert_eq!(
+        execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+        Err(GraphQLError::ValidationError(vec![
+            RuleError::new(
+                r#"Field "name" is banned"#,
+                &[SourcePosition::new(13, 0, 13)],
+            ),
+            RuleError::new(
+                "Query selects more than 3 fields",
+                &[SourcePosition::new(18, 0, 18)],
+            ),
+        ])),
+    );
+}
+
+#[tokio::test]
+async fn runs_after_builtin_rules() {
+    const DOC: &str = "{ human { id name unknown } }";
+
+    let schema = schema(QueryRoot).with_validation_rule(BannedField { name: "name" });
+
+    assert_eq!(
+        execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+        Err(GraphQLError::ValidationError(vec![RuleError::new(
+            r#"Unknown field "unknown" on type "Human""#,
+            &[SourcePosition::new(18, 0, 18)],
+        )])),
+    );
+}
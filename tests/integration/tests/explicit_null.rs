@@ -23,6 +23,22 @@ impl Query {
     fn object_field_is_explicit_null(obj: ObjectInput) -> bool {
         obj.field.is_explicit_null()
     }
+
+    fn arg(arg: Nullable<i32>) -> String {
+        describe(arg)
+    }
+
+    fn object_field(obj: ObjectInput) -> String {
+        describe(obj.field)
+    }
+}
+
+fn describe(v: Nullable<i32>) -> String {
+    match v {
+        Nullable::ImplicitNull => "implicit null".into(),
+        Nullable::ExplicitNull => "explicit null".into(),
+        Nullable::Some(v) => format!("some {v}"),
+    }
 }
 
 type Schema = juniper::RootNode<'static, Query, EmptyMutation<Context>, EmptySubscription<Context>>;
@@ -70,3 +86,90 @@ async fn explicit_null() {
         )),
     );
 }
+
+#[tokio::test]
+async fn distinguishes_inline_values() {
+    let query = r#"
+        {
+            argSome: arg(arg: 1)
+            argExplicitNull: arg(arg: null)
+            argImplicitNull: arg
+            fieldSome: objectField(obj: {field: 1})
+            fieldExplicitNull: objectField(obj: {field: null})
+            fieldImplicitNull: objectField(obj: {})
+        }
+    "#;
+
+    let schema = &Schema::new(
+        Query,
+        EmptyMutation::<Context>::new(),
+        EmptySubscription::<Context>::new(),
+    );
+
+    assert_eq!(
+        juniper::execute(query, None, &schema, &graphql_vars! {}, &Context).await,
+        Ok((
+            graphql_value!({
+                "argSome": "some 1",
+                "argExplicitNull": "explicit null",
+                "argImplicitNull": "implicit null",
+                "fieldSome": "some 1",
+                "fieldExplicitNull": "explicit null",
+                "fieldImplicitNull": "implicit null",
+            }),
+            vec![],
+        )),
+    );
+}
+
+#[tokio::test]
+async fn distinguishes_variables() {
+    let query = r#"
+        query Foo(
+            $one: Int, $null: Int, $absent: Int,
+            $someObj: ObjectInput!, $nullObj: ObjectInput!, $emptyObj: ObjectInput!,
+        ) {
+            argSome: arg(arg: $one)
+            argExplicitNull: arg(arg: $null)
+            argImplicitNull: arg(arg: $absent)
+            fieldSome: objectField(obj: {field: $one})
+            fieldExplicitNull: objectField(obj: {field: $null})
+            fieldImplicitNull: objectField(obj: {field: $absent})
+            objSome: objectField(obj: $someObj)
+            objExplicitNull: objectField(obj: $nullObj)
+            objImplicitNull: objectField(obj: $emptyObj)
+        }
+    "#;
+
+    let schema = &Schema::new(
+        Query,
+        EmptyMutation::<Context>::new(),
+        EmptySubscription::<Context>::new(),
+    );
+
+    let vars: Variables = graphql_vars! {
+        "one": 1,
+        "null": null,
+        "someObj": {"field": 1},
+        "nullObj": {"field": null},
+        "emptyObj": {},
+    };
+
+    assert_eq!(
+        juniper::execute(query, None, &schema, &vars, &Context).await,
+        Ok((
+            graphql_value!({
+                "argSome": "some 1",
+                "argExplicitNull": "explicit null",
+                "argImplicitNull": "implicit null",
+                "fieldSome": "some 1",
+                "fieldExplicitNull": "explicit null",
+                "fieldImplicitNull": "implicit null",
+                "objSome": "some 1",
+                "objExplicitNull": "explicit null",
+                "objImplicitNull": "implicit null",
+            }),
+            vec![],
+        )),
+    );
+}
@@ -0,0 +1,329 @@
+//! Tests for field middlewares registered via
+//! `RootNode::with_field_middleware()`.
+
+pub mod common;
+
+use std::{
+    pin::Pin,
+    sync::{Arc, Mutex},
+};
+
+use futures::{stream, FutureExt as _, Stream};
+use juniper::{
+    execute, execute_sync, graphql_object, graphql_subscription, graphql_value, graphql_vars,
+    parser::SourcePosition, resolve_into_stream, BoxFuture, DefaultScalarValue, EmptyMutation,
+    ExecutionError, ExecutionResult, FieldError, FieldInfo, FieldMiddleware, PathSegment, RootNode,
+    Value,
+};
+
+use self::common::util::{extract_next, schema};
+
+/// Middleware recording every field it sees along with the outcome of its
+/// resolution.
+#[derive(Clone, Default)]
+struct Recorder {
+    name: &'static str,
+    log: Arc<Mutex<Vec<String>>>,
+}
+
+impl Recorder {
+    fn record(&self, field: &FieldInfo<'_>, res: &ExecutionResult) {
+        let path = field
+            .path()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(".");
+        let outcome = if res.is_ok() { "ok" } else { "err" };
+        self.log.lock().unwrap().push(format!(
+            "{}:{}.{}@{path}:{outcome}",
+            self.name,
+            field.parent_type_name(),
+            field.field_name(),
+        ));
+    }
+
+    fn log(&self) -> Vec<String> {
+        self.log.lock().unwrap().clone()
+    }
+}
+
+impl FieldMiddleware<DefaultScalarValue> for Recorder {
+    fn around_field(
+        &self,
+        field: &FieldInfo<'_>,
+        next: &mut dyn FnMut() -> ExecutionResult,
+    ) -> ExecutionResult {
+        let res = next();
+        self.record(field, &res);
+        res
+    }
+
+    fn around_field_async<'f>(
+        &'f self,
+        field: &'f FieldInfo<'f>,
+        next: BoxFuture<'f, ExecutionResult>,
+    ) -> BoxFuture<'f, ExecutionResult> {
+        async move {
+            let res = next.await;
+            self.record(field, &res);
+            res
+        }
+        .boxed()
+    }
+}
+
+/// Middleware replacing the result of every `name` field.
+struct Censor;
+
+impl FieldMiddleware<DefaultScalarValue> for Censor {
+    fn around_field(
+        &self,
+        field: &FieldInfo<'_>,
+        next: &mut dyn FnMut() -> ExecutionResult,
+    ) -> ExecutionResult {
+        let res = next();
+        if field.field_name() == "name" {
+            Ok(Value::scalar("<censored>"))
+        } else {
+            res
+        }
+    }
+}
+
+struct Human;
+
+#[graphql_object]
+impl Human {
+    fn name() -> &'static str {
+        "Han Solo"
+    }
+
+    fn secret() -> Result<Option<i32>, FieldError> {
+        Err("Forbidden".into())
+    }
+}
+
+struct QueryRoot;
+
+#[graphql_object]
+impl QueryRoot {
+    fn humans() -> Vec<Human> {
+        vec![Human, Human]
+    }
+
+    async fn hero() -> Human {
+        Human
+    }
+}
+
+type HumanStream = Pin<Box<dyn Stream<Item = Human> + Send>>;
+
+struct SubscriptionRoot;
+
+#[graphql_subscription]
+impl SubscriptionRoot {
+    async fn heroes() -> HumanStream {
+        Box::pin(stream::iter(vec![Human]))
+    }
+}
+
+#[test]
+fn wraps_sync_resolution() {
+    let recorder = Recorder {
+        name: "rec",
+        ..Recorder::default()
+    };
+    let schema = schema(QueryRoot).with_field_middleware(recorder.clone());
+
+    let (res, errors) =
+        execute_sync("{ humans { name } }", None, &schema, &graphql_vars! {}, &()).unwrap();
+
+    assert_eq!(
+        res,
+        graphql_value!({"humans": [{"name": "Han Solo"}, {"name": "Han Solo"}]}),
+    );
+    assert_eq!(errors, vec![]);
+    assert_eq!(
+        recorder.log(),
+        [
+            "rec:Human.name@humans.0.name:ok",
+            "rec:Human.name@humans.1.name:ok",
+            "rec:QueryRoot.humans@humans:ok",
+        ],
+    );
+}
+
+#[tokio::test]
+async fn wraps_async_resolution() {
+    let recorder = Recorder {
+        name: "rec",
+        ..Recorder::default()
+    };
+    let schema = schema(QueryRoot).with_field_middleware(recorder.clone());
+
+    let (res, errors) = execute(
+        "{ hero { alias: name } }",
+        None,
+        &schema,
+        &graphql_vars! {},
+        &(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(res, graphql_value!({"hero": {"alias": "Han Solo"}}));
+    assert_eq!(errors, vec![]);
+    assert_eq!(
+        recorder.log(),
+        ["rec:Human.name@hero.alias:ok", "rec:QueryRoot.hero@hero:ok"],
+    );
+}
+
+#[tokio::test]
+async fn observes_errors() {
+    let recorder = Recorder {
+        name: "rec",
+        ..Recorder::default()
+    };
+    let schema = schema(QueryRoot).with_field_middleware(recorder.clone());
+
+    let (res, errors) = execute("{ hero { secret } }", None, &schema, &graphql_vars! {}, &())
+        .await
+        .unwrap();
+
+    assert_eq!(res, graphql_value!({"hero": {"secret": null}}));
+    assert_eq!(
+        errors,
+        [ExecutionError::new(
+            SourcePosition::new(9, 0, 9),
+            &["hero", "secret"],
+            FieldError::from("Forbidden"),
+        )],
+    );
+    assert_eq!(
+        recorder.log(),
+        [
+            "rec:Human.secret@hero.secret:err",
+            "rec:QueryRoot.hero@hero:ok",
+        ],
+    );
+}
+
+#[test]
+fn replaces_result() {
+    let schema = schema(QueryRoot).with_field_middleware(Censor);
+
+    assert_eq!(
+        execute_sync("{ humans { name } }", None, &schema, &graphql_vars! {}, &()),
+        Ok((
+            graphql_value!({"humans": [{"name": "<censored>"}, {"name": "<censored>"}]}),
+            vec![],
+        )),
+    );
+}
+
+#[test]
+fn nests_in_registration_order() {
+    let log = Arc::new(Mutex::new(vec![]));
+    let (outer, inner) = (
+        Recorder {
+            name: "outer",
+            log: Arc::clone(&log),
+        },
+        Recorder {
+            name: "inner",
+            log: Arc::clone(&log),
+        },
+    );
+    let schema = schema(QueryRoot)
+        .with_field_middleware(outer)
+        .with_field_middleware(inner);
+
+    execute_sync("{ humans { name } }", None, &schema, &graphql_vars! {}, &()).unwrap();
+
+    assert_eq!(
+        log.lock().unwrap()[..2],
+        [
+            "inner:Human.name@humans.0.name:ok",
+            "outer:Human.name@humans.0.name:ok",
+        ],
+    );
+}
+
+#[test]
+fn exposes_response_path_segments() {
+    type Log = Vec<(String, Vec<PathSegment>)>;
+
+    #[derive(Clone, Default)]
+    struct Paths(Arc<Mutex<Log>>);
+
+    impl FieldMiddleware<DefaultScalarValue> for Paths {
+        fn around_field(
+            &self,
+            field: &FieldInfo<'_>,
+            next: &mut dyn FnMut() -> ExecutionResult,
+        ) -> ExecutionResult {
+            self.0
+                .lock()
+                .unwrap()
+                .push((field.response_name().into(), field.path()));
+            next()
+        }
+    }
+
+    let paths = Paths::default();
+    let schema = schema(QueryRoot).with_field_middleware(paths.clone());
+
+    execute_sync(
+        "{ people: humans { n: name } }",
+        None,
+        &schema,
+        &graphql_vars! {},
+        &(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        paths.0.lock().unwrap()[..2],
+        [
+            ("people".into(), vec![PathSegment::Field("people".into())]),
+            (
+                "n".into(),
+                vec![
+                    PathSegment::Field("people".into()),
+                    PathSegment::Index(0),
+                    PathSegment::Field("n".into()),
+                ],
+            ),
+        ],
+    );
+}
+
+#[tokio::test]
+async fn wraps_fields_yielded_by_subscription() {
+    let recorder = Recorder {
+        name: "rec",
+        ..Recorder::default()
+    };
+    let schema = RootNode::new(QueryRoot, EmptyMutation::new(), SubscriptionRoot)
+        .with_field_middleware(recorder.clone());
+
+    let res = extract_next(
+        resolve_into_stream(
+            "subscription { heroes { name } }",
+            None,
+            &schema,
+            &graphql_vars! {},
+            &(),
+        )
+        .await,
+    )
+    .await;
+
+    assert_eq!(
+        res,
+        Ok((graphql_value!({"heroes": {"name": "Han Solo"}}), vec![])),
+    );
+    assert_eq!(recorder.log(), ["rec:Human.name@heroes.name:ok"]);
+}
@@ -0,0 +1,716 @@
+//! Tests for delivering responses incrementally via the `@stream` and `@defer`
+//! directives.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use futures::StreamExt as _;
+use juniper::{
+    execute, execute_incremental, graphql_object, graphql_value, graphql_vars,
+    parser::SourcePosition, DefaultScalarValue, EmptyMutation, EmptySubscription, ExecutionError,
+    FieldError, FieldResult, GraphQLError, IncrementalData, IncrementalItems, IncrementalPayload,
+    IncrementalResult, PathSegment, RootNode, Variables,
+};
+
+struct Pet {
+    name: &'static str,
+}
+
+#[graphql_object]
+impl Pet {
+    fn name(&self) -> FieldResult<Option<&str>> {
+        if self.name.is_empty() {
+            return Err("unnamed".into());
+        }
+        Ok(Some(self.name))
+    }
+
+    fn friends(&self) -> Vec<Pet> {
+        vec![Pet { name: "Rex" }, Pet { name: "Tom" }]
+    }
+}
+
+struct Query;
+
+#[graphql_object]
+impl Query {
+    async fn pets() -> Vec<Pet> {
+        vec![
+            Pet { name: "Fluffy" },
+            Pet { name: "" },
+            Pet { name: "Ace" },
+        ]
+    }
+
+    fn owner() -> &'static str {
+        "Alice"
+    }
+}
+
+type Schema = RootNode<'static, Query, EmptyMutation, EmptySubscription>;
+
+fn schema() -> Schema {
+    Schema::new(Query, EmptyMutation::new(), EmptySubscription::new())
+        .with_stream_directive()
+        .with_defer_directive()
+}
+
+async fn run(
+    query: &str,
+    vars: Variables,
+) -> Result<Vec<IncrementalPayload<DefaultScalarValue>>, GraphQLError> {
+    let schema = schema();
+    let stream = execute_incremental(query, None, &schema, &vars, &()).await?;
+    Ok(stream.collect().await)
+}
+
+fn items(
+    items: Vec<juniper::Value>,
+    path: &[PathSegment],
+    label: Option<&str>,
+    errors: Vec<ExecutionError<DefaultScalarValue>>,
+) -> IncrementalResult {
+    IncrementalResult::Items(IncrementalItems {
+        items,
+        path: path.to_vec(),
+        label: label.map(Into::into),
+        errors,
+    })
+}
+
+fn data(
+    data: juniper::Value,
+    path: &[PathSegment],
+    label: Option<&str>,
+    errors: Vec<ExecutionError<DefaultScalarValue>>,
+) -> IncrementalResult {
+    IncrementalResult::Data(IncrementalData {
+        data,
+        path: path.to_vec(),
+        label: label.map(Into::into),
+        errors,
+    })
+}
+
+#[tokio::test]
+async fn streams_list_items_after_initial_count() {
+    assert_eq!(
+        run(
+            r#"{ owner pets @stream(initialCount: 1, label: "pets") { name } }"#,
+            graphql_vars! {},
+        )
+        .await,
+        Ok(vec![
+            IncrementalPayload::Initial {
+                data: graphql_value!({"owner": "Alice", "pets": [{"name": "Fluffy"}]}),
+                errors: vec![],
+                has_next: true,
+            },
+            IncrementalPayload::Subsequent {
+                incremental: vec![items(
+                    vec![graphql_value!({"name": null})],
+                    &["pets".into(), 1.into()],
+                    Some("pets"),
+                    vec![ExecutionError::new_with_path(
+                        SourcePosition::new(55, 0, 55),
+                        &["pets".into(), 1.into(), "name".into()],
+                        FieldError::from("unnamed"),
+                    )],
+                )],
+                has_next: true,
+            },
+            IncrementalPayload::Subsequent {
+                incremental: vec![items(
+                    vec![graphql_value!({"name": "Ace"})],
+                    &["pets".into(), 2.into()],
+                    Some("pets"),
+                    vec![],
+                )],
+                has_next: false,
+            },
+        ]),
+    );
+}
+
+#[tokio::test]
+async fn delivers_everything_at_once_without_stream() {
+    assert_eq!(
+        run("{ owner }", graphql_vars! {}).await,
+        Ok(vec![IncrementalPayload::Initial {
+            data: graphql_value!({"owner": "Alice"}),
+            errors: vec![],
+            has_next: false,
+        }]),
+    );
+}
+
+#[tokio::test]
+async fn respects_if_argument_and_variables() {
+    let query = r#"
+        query($stream: Boolean!, $count: Int = 2) {
+            pets @stream(if: $stream, initialCount: $count) { friends { name } }
+        }
+    "#;
+
+    let payloads = run(query, graphql_vars! {"stream": false}).await.unwrap();
+    assert_eq!(payloads.len(), 1);
+
+    let payloads = run(query, graphql_vars! {"stream": true}).await.unwrap();
+    assert_eq!(payloads.len(), 2);
+    assert_eq!(
+        payloads[1],
+        IncrementalPayload::Subsequent {
+            incremental: vec![items(
+                vec![graphql_value!({"friends": [{"name": "Rex"}, {"name": "Tom"}]})],
+                &["pets".into(), 2.into()],
+                None,
+                vec![],
+            )],
+            has_next: false,
+        },
+    );
+}
+
+#[tokio::test]
+async fn streams_nested_lists_after_their_parent_item() {
+    let payloads = run(
+        r#"{
+            pets @stream(initialCount: 2) {
+                ... on Pet { friends @stream(initialCount: 1) { name } }
+            }
+        }"#,
+        graphql_vars! {},
+    )
+    .await
+    .unwrap();
+
+    let paths = payloads
+        .iter()
+        .map(|p| match p {
+            IncrementalPayload::Initial { .. } => vec![],
+            IncrementalPayload::Subsequent { incremental, .. } => incremental[0].path().to_vec(),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        paths,
+        vec![
+            vec![],
+            vec!["pets".into(), 0.into(), "friends".into(), 1.into()],
+            vec!["pets".into(), 1.into(), "friends".into(), 1.into()],
+            vec!["pets".into(), 2.into()],
+            vec!["pets".into(), 2.into(), "friends".into(), 1.into()],
+        ],
+    );
+    assert_eq!(
+        payloads[3],
+        IncrementalPayload::Subsequent {
+            incremental: vec![items(
+                vec![graphql_value!({"friends": [{"name": "Rex"}]})],
+                &["pets".into(), 2.into()],
+                None,
+                vec![],
+            )],
+            has_next: true,
+        },
+    );
+}
+
+#[tokio::test]
+async fn serializes_payloads() {
+    let payloads = run(
+        r#"{ pets @stream(initialCount: 2, label: "rest") { name } }"#,
+        graphql_vars! {},
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&payloads).unwrap(),
+        serde_json::json!([
+            {
+                "data": {"pets": [{"name": "Fluffy"}, {"name": null}]},
+                "errors": [{
+                    "message": "unnamed",
+                    "locations": [{"line": 1, "column": 50}],
+                    "path": ["pets", 1, "name"],
+                }],
+                "hasNext": true,
+            },
+            {
+                "incremental": [{
+                    "items": [{"name": "Ace"}],
+                    "path": ["pets", 2],
+                    "label": "rest",
+                }],
+                "hasNext": false,
+            },
+        ]),
+    );
+}
+
+#[tokio::test]
+async fn defers_fragment_fields() {
+    assert_eq!(
+        run(
+            r#"{ owner ... @defer(label: "pets") { pets { name } } }"#,
+            graphql_vars! {},
+        )
+        .await,
+        Ok(vec![
+            IncrementalPayload::Initial {
+                data: graphql_value!({"owner": "Alice"}),
+                errors: vec![],
+                has_next: true,
+            },
+            IncrementalPayload::Subsequent {
+                incremental: vec![data(
+                    graphql_value!({"pets": [{"name": "Fluffy"}, {"name": null}, {"name": "Ace"}]}),
+                    &[],
+                    Some("pets"),
+                    vec![ExecutionError::new_with_path(
+                        SourcePosition::new(43, 0, 43),
+                        &["pets".into(), 1.into(), "name".into()],
+                        FieldError::from("unnamed"),
+                    )],
+                )],
+                has_next: false,
+            },
+        ]),
+    );
+}
+
+#[tokio::test]
+async fn defers_nested_fragments_per_object() {
+    let payloads = run(
+        r#"
+            query($defer: Boolean!) {
+                pets { name ...Friends @defer(if: $defer) }
+            }
+
+            fragment Friends on Pet { friends { name } }
+        "#,
+        graphql_vars! {"defer": true},
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(payloads.len(), 4);
+    assert!(matches!(
+        &payloads[0],
+        IncrementalPayload::Initial { data, errors, has_next: true }
+            if *data == graphql_value!({"pets": [
+                {"name": "Fluffy"},
+                {"name": null},
+                {"name": "Ace"},
+            ]}) && errors.len() == 1,
+    ));
+    for (i, payload) in payloads[1..].iter().enumerate() {
+        assert_eq!(
+            *payload,
+            IncrementalPayload::Subsequent {
+                incremental: vec![data(
+                    graphql_value!({"friends": [{"name": "Rex"}, {"name": "Tom"}]}),
+                    &["pets".into(), i.into()],
+                    None,
+                    vec![],
+                )],
+                has_next: i < 2,
+            },
+        );
+    }
+
+    let payloads = run(
+        r#"
+            query($defer: Boolean!) {
+                pets { name ...Friends @defer(if: $defer) }
+            }
+
+            fragment Friends on Pet { friends { name } }
+        "#,
+        graphql_vars! {"defer": false},
+    )
+    .await
+    .unwrap();
+    assert_eq!(payloads.len(), 1);
+}
+
+#[tokio::test]
+async fn omits_already_delivered_fields_from_deferred() {
+    assert_eq!(
+        run(
+            "{ owner ... @defer { owner pets { name } } ... @defer { owner } }",
+            graphql_vars! {},
+        )
+        .await
+        .map(|payloads| payloads.into_iter().skip(1).collect::<Vec<_>>()),
+        Ok(vec![IncrementalPayload::Subsequent {
+            incremental: vec![data(
+                graphql_value!({"pets": [{"name": "Fluffy"}, {"name": null}, {"name": "Ace"}]}),
+                &[],
+                None,
+                vec![ExecutionError::new_with_path(
+                    SourcePosition::new(34, 0, 34),
+                    &["pets".into(), 1.into(), "name".into()],
+                    FieldError::from("unnamed"),
+                )],
+            )],
+            has_next: false,
+        }]),
+    );
+}
+
+#[tokio::test]
+async fn ends_response_when_deferred_fields_are_already_delivered() {
+    assert_eq!(
+        run("{ owner ... @defer { owner } }", graphql_vars! {}).await,
+        Ok(vec![
+            IncrementalPayload::Initial {
+                data: graphql_value!({"owner": "Alice"}),
+                errors: vec![],
+                has_next: true,
+            },
+            IncrementalPayload::Subsequent {
+                incremental: vec![],
+                has_next: false,
+            },
+        ]),
+    );
+}
+
+#[tokio::test]
+async fn streams_lists_inside_deferred_fragments() {
+    let payloads = run(
+        "{ ... @defer { pets @stream(initialCount: 2) { name } } }",
+        graphql_vars! {},
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(payloads.len(), 3);
+    assert_eq!(
+        payloads[1],
+        IncrementalPayload::Subsequent {
+            incremental: vec![data(
+                graphql_value!({"pets": [{"name": "Fluffy"}, {"name": null}]}),
+                &[],
+                None,
+                vec![ExecutionError::new_with_path(
+                    SourcePosition::new(47, 0, 47),
+                    &["pets".into(), 1.into(), "name".into()],
+                    FieldError::from("unnamed"),
+                )],
+            )],
+            has_next: true,
+        },
+    );
+    assert_eq!(
+        payloads[2],
+        IncrementalPayload::Subsequent {
+            incremental: vec![items(
+                vec![graphql_value!({"name": "Ace"})],
+                &["pets".into(), 2.into()],
+                None,
+                vec![],
+            )],
+            has_next: false,
+        },
+    );
+}
+
+#[tokio::test]
+async fn serializes_deferred_payloads() {
+    let payloads = run(
+        r#"{ pets { ... on Pet @defer(label: "name") { name } } }"#,
+        graphql_vars! {},
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&payloads[..3]).unwrap(),
+        serde_json::json!([
+            {
+                "data": {"pets": [{}, {}, {}]},
+                "hasNext": true,
+            },
+            {
+                "incremental": [{
+                    "data": {"name": "Fluffy"},
+                    "path": ["pets", 0],
+                    "label": "name",
+                }],
+                "hasNext": true,
+            },
+            {
+                "incremental": [{
+                    "data": {"name": null},
+                    "path": ["pets", 1],
+                    "label": "name",
+                    "errors": [{
+                        "message": "unnamed",
+                        "locations": [{"line": 1, "column": 45}],
+                        "path": ["pets", 1, "name"],
+                    }],
+                }],
+                "hasNext": true,
+            },
+        ]),
+    );
+}
+
+pub struct Calls(AtomicUsize);
+
+impl juniper::Context for Calls {}
+
+struct Counted(i32);
+
+#[graphql_object(context = Calls)]
+impl Counted {
+    fn value(&self, #[graphql(context)] calls: &Calls) -> i32 {
+        calls.0.fetch_add(1, Ordering::SeqCst);
+        self.0
+    }
+
+    async fn delayed(&self, #[graphql(context)] calls: &Calls) -> i32 {
+        tokio::task::yield_now().await;
+        calls.0.fetch_add(1, Ordering::SeqCst);
+        self.0
+    }
+}
+
+struct CountedQuery;
+
+#[graphql_object(context = Calls)]
+impl CountedQuery {
+    fn counted(value: i32) -> Counted {
+        Counted(value)
+    }
+
+    fn list() -> Vec<Counted> {
+        vec![Counted(1), Counted(2), Counted(3)]
+    }
+}
+
+#[tokio::test]
+async fn yields_initial_payload_before_resolving_postponed_parts() {
+    let schema = RootNode::new(
+        CountedQuery,
+        EmptyMutation::<Calls>::new(),
+        EmptySubscription::<Calls>::new(),
+    )
+    .with_stream_directive()
+    .with_defer_directive();
+    let calls = Calls(AtomicUsize::new(0));
+    let vars = graphql_vars! {};
+
+    let mut stream = execute_incremental(
+        "{ counted(value: 0) { __typename ... @defer { value } } }",
+        None,
+        &schema,
+        &vars,
+        &calls,
+    )
+    .await
+    .unwrap();
+    assert!(matches!(
+        stream.next().await,
+        Some(IncrementalPayload::Initial { has_next: true, .. }),
+    ));
+    assert_eq!(calls.0.load(Ordering::SeqCst), 0);
+    assert!(stream.next().await.is_some());
+    assert_eq!(calls.0.load(Ordering::SeqCst), 1);
+
+    calls.0.store(0, Ordering::SeqCst);
+    let mut stream = execute_incremental(
+        "{ list @stream(initialCount: 1) { value } }",
+        None,
+        &schema,
+        &vars,
+        &calls,
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        stream.next().await,
+        Some(IncrementalPayload::Initial {
+            data: graphql_value!({"list": [{"value": 1}]}),
+            errors: vec![],
+            has_next: true,
+        }),
+    );
+    assert_eq!(calls.0.load(Ordering::SeqCst), 1);
+    assert_eq!(stream.count().await, 2);
+}
+
+#[tokio::test]
+async fn resolves_every_field_once() {
+    let schema = RootNode::new(
+        CountedQuery,
+        EmptyMutation::<Calls>::new(),
+        EmptySubscription::<Calls>::new(),
+    )
+    .with_stream_directive()
+    .with_defer_directive();
+    let calls = Calls(AtomicUsize::new(0));
+    let vars = graphql_vars! {};
+
+    let payloads = execute_incremental(
+        "{ counted(value: 1) { delayed } list @stream(initialCount: 1) { value delayed } }",
+        None,
+        &schema,
+        &vars,
+        &calls,
+    )
+    .await
+    .unwrap()
+    .collect::<Vec<_>>()
+    .await;
+
+    assert_eq!(
+        payloads[0],
+        IncrementalPayload::Initial {
+            data: graphql_value!({
+                "counted": {"delayed": 1},
+                "list": [{"value": 1, "delayed": 1}],
+            }),
+            errors: vec![],
+            has_next: true,
+        },
+    );
+    assert_eq!(payloads.len(), 3);
+    assert_eq!(calls.0.load(Ordering::SeqCst), 7);
+}
+
+#[tokio::test]
+async fn resolves_deferred_field_once() {
+    let schema = RootNode::new(
+        CountedQuery,
+        EmptyMutation::<Calls>::new(),
+        EmptySubscription::<Calls>::new(),
+    )
+    .with_stream_directive()
+    .with_defer_directive();
+    let calls = Calls(AtomicUsize::new(0));
+    let vars = graphql_vars! {};
+
+    let payloads = execute_incremental(
+        "{ counted(value: 2) { ... @defer { delayed } } }",
+        None,
+        &schema,
+        &vars,
+        &calls,
+    )
+    .await
+    .unwrap()
+    .collect::<Vec<_>>()
+    .await;
+
+    assert_eq!(
+        payloads,
+        vec![
+            IncrementalPayload::Initial {
+                data: graphql_value!({"counted": {}}),
+                errors: vec![],
+                has_next: true,
+            },
+            IncrementalPayload::Subsequent {
+                incremental: vec![data(
+                    graphql_value!({"delayed": 2}),
+                    &["counted".into()],
+                    None,
+                    vec![],
+                )],
+                has_next: false,
+            },
+        ],
+    );
+    assert_eq!(calls.0.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn validates_stream_usage() {
+    let err = run("{ owner @stream }", graphql_vars! {})
+        .await
+        .unwrap_err();
+    assert!(matches!(err, GraphQLError::ValidationError(_)));
+
+    let err = run(
+        "{ pets @stream(initialCount: -1) { name } }",
+        graphql_vars! {},
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(err, GraphQLError::ValidationError(_)));
+}
+
+#[tokio::test]
+async fn stream_is_unknown_unless_enabled() {
+    let schema = Schema::new(Query, EmptyMutation::new(), EmptySubscription::new());
+
+    let res = execute(
+        "{ pets @stream { name } }",
+        None,
+        &schema,
+        &graphql_vars! {},
+        &(),
+    )
+    .await;
+    assert!(matches!(res, Err(GraphQLError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn defer_is_unknown_unless_enabled() {
+    let schema = Schema::new(Query, EmptyMutation::new(), EmptySubscription::new());
+
+    let res = execute(
+        "{ ... @defer { owner } }",
+        None,
+        &schema,
+        &graphql_vars! {},
+        &(),
+    )
+    .await;
+    assert!(matches!(res, Err(GraphQLError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn regular_execution_ignores_defer() {
+    let schema = schema();
+
+    assert_eq!(
+        execute(
+            "{ owner ... @defer { pets { friends { name } } } }",
+            None,
+            &schema,
+            &graphql_vars! {},
+            &(),
+        )
+        .await
+        .map(|(data, _)| data.as_object_value().unwrap().field_count()),
+        Ok(2),
+    );
+}
+
+#[tokio::test]
+async fn regular_execution_ignores_stream() {
+    let schema = schema();
+
+    assert_eq!(
+        execute(
+            "{ pets @stream(initialCount: 1) { friends { name } } }",
+            None,
+            &schema,
+            &graphql_vars! {},
+            &(),
+        )
+        .await
+        .map(|(data, _)| data
+            .as_object_value()
+            .unwrap()
+            .get_field_value("pets")
+            .unwrap()
+            .as_list_value()
+            .unwrap()
+            .len()),
+        Ok(3),
+    );
+}
@@ -0,0 +1,311 @@
+//! Tests for composing schemas via [`merge_schemas()`].
+
+pub mod common;
+
+use futures::{stream, FutureExt as _};
+use juniper::{
+    execute, graphql_object, graphql_subscription, graphql_value, graphql_vars, merge_schemas,
+    resolve_into_stream, DefaultScalarValue, EmptyMutation, EmptySubscription, FieldError,
+    GraphQLObject, MergeError, RootNode,
+};
+
+use self::common::util::extract_next;
+
+#[derive(Clone, Copy)]
+pub struct Context {
+    greeting: &'static str,
+}
+
+impl juniper::Context for Context {}
+
+mod users {
+    use super::*;
+
+    #[derive(GraphQLObject)]
+    pub struct Money {
+        pub cents: i32,
+    }
+
+    pub struct User;
+
+    #[graphql_object(context = Context)]
+    impl User {
+        fn name(context: &Context) -> String {
+            format!("{}, Alice", context.greeting)
+        }
+
+        fn balance() -> Money {
+            Money { cents: 100 }
+        }
+    }
+
+    pub struct Query;
+
+    #[graphql_object(context = Context)]
+    impl Query {
+        async fn user() -> User {
+            User
+        }
+    }
+
+    pub struct Mutation;
+
+    #[graphql_object(context = Context)]
+    impl Mutation {
+        fn rename(name: String) -> String {
+            name
+        }
+    }
+
+    pub struct Subscription;
+
+    type Stream<T> = stream::BoxStream<'static, Result<T, FieldError>>;
+
+    #[graphql_subscription(context = Context)]
+    impl Subscription {
+        async fn user_names() -> Stream<String> {
+            Box::pin(stream::once(async { Ok("Alice".into()) }))
+        }
+    }
+
+    pub type Schema = RootNode<'static, Query, Mutation, Subscription>;
+
+    pub fn schema() -> Schema {
+        Schema::new(Query, Mutation, Subscription)
+    }
+}
+
+mod products {
+    use super::*;
+
+    // Same definition as the `users::Money`.
+    #[derive(GraphQLObject)]
+    #[graphql(name = "Money")]
+    pub struct Price {
+        pub cents: i32,
+    }
+
+    #[derive(GraphQLObject)]
+    pub struct Product {
+        pub title: String,
+        pub price: Price,
+    }
+
+    pub struct Query;
+
+    #[graphql_object(context = Context)]
+    impl Query {
+        fn products() -> Vec<Product> {
+            vec![Product {
+                title: "Ball".into(),
+                price: Price { cents: 250 },
+            }]
+        }
+    }
+
+    pub type Schema = RootNode<'static, Query, EmptyMutation<Context>, EmptySubscription<Context>>;
+
+    pub fn schema() -> Schema {
+        Schema::new(Query, EmptyMutation::new(), EmptySubscription::new())
+    }
+}
+
+mod conflicting {
+    use super::*;
+
+    // Different definition than the `users::Money`.
+    #[derive(GraphQLObject)]
+    #[graphql(name = "Money")]
+    pub struct Money {
+        pub amount: f64,
+    }
+
+    pub struct Query;
+
+    #[graphql_object(context = Context)]
+    impl Query {
+        fn money() -> Money {
+            Money { amount: 1.0 }
+        }
+
+        fn user() -> bool {
+            true
+        }
+    }
+
+    pub struct QueryWithMoney;
+
+    #[graphql_object(context = Context, name = "Query")]
+    impl QueryWithMoney {
+        fn money() -> Money {
+            Money { amount: 1.0 }
+        }
+    }
+
+    pub fn schema<Q>(
+        query: Q,
+    ) -> RootNode<'static, Q, EmptyMutation<Context>, EmptySubscription<Context>>
+    where
+        Q: juniper::GraphQLType<Context = Context, TypeInfo = ()>,
+    {
+        RootNode::new(query, EmptyMutation::new(), EmptySubscription::new())
+    }
+}
+
+const CONTEXT: Context = Context { greeting: "Hello" };
+
+#[tokio::test]
+async fn resolves_fields_of_all_schemas() {
+    let (users, products) = (users::schema(), products::schema());
+    let schema = merge_schemas(&[&users, &products]).unwrap();
+
+    assert_eq!(
+        execute(
+            "{ user { name balance { cents } } products { title price { cents } } }",
+            None,
+            &schema,
+            &graphql_vars! {},
+            &CONTEXT,
+        )
+        .await,
+        Ok((
+            graphql_value!({
+                "user": {"name": "Hello, Alice", "balance": {"cents": 100}},
+                "products": [{"title": "Ball", "price": {"cents": 250}}],
+            }),
+            vec![],
+        )),
+    );
+}
+
+#[tokio::test]
+async fn resolves_mutations() {
+    let (users, products) = (users::schema(), products::schema());
+    let schema = merge_schemas(&[&products, &users]).unwrap();
+
+    assert_eq!(
+        execute(
+            r#"mutation { rename(name: "Bob") }"#,
+            None,
+            &schema,
+            &graphql_vars! {},
+            &CONTEXT,
+        )
+        .await,
+        Ok((graphql_value!({"rename": "Bob"}), vec![])),
+    );
+}
+
+#[tokio::test]
+async fn resolves_subscriptions() {
+    let (users, products) = (users::schema(), products::schema());
+    let schema = merge_schemas(&[&users, &products]).unwrap();
+
+    assert_eq!(
+        resolve_into_stream(
+            "subscription { userNames }",
+            None,
+            &schema,
+            &graphql_vars! {},
+            &CONTEXT,
+        )
+        .then(|s| extract_next(s))
+        .await,
+        Ok((graphql_value!({"userNames": "Alice"}), vec![])),
+    );
+}
+
+#[tokio::test]
+async fn is_introspectable() {
+    let (users, products) = (users::schema(), products::schema());
+    let schema = merge_schemas(&[&users, &products]).unwrap();
+
+    let (res, errors) = execute(
+        r#"{
+            __schema {
+                queryType { name fields { name } }
+                mutationType { name }
+                subscriptionType { name }
+            }
+            __type(name: "Money") { fields { name } }
+        }"#,
+        None,
+        &schema,
+        &graphql_vars! {},
+        &CONTEXT,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(errors, vec![]);
+    assert_eq!(
+        res,
+        graphql_value!({
+            "__schema": {
+                "queryType": {
+                    "name": "Query",
+                    "fields": [{"name": "user"}, {"name": "products"}],
+                },
+                "mutationType": {"name": "Mutation"},
+                "subscriptionType": {"name": "Subscription"},
+            },
+            "__type": {"fields": [{"name": "cents"}]},
+        }),
+    );
+}
+
+#[tokio::test]
+async fn omits_empty_roots() {
+    let products = products::schema();
+    let schema = merge_schemas(&[&products]).unwrap();
+
+    assert_eq!(
+        execute(
+            "{ __schema { mutationType { name } subscriptionType { name } } }",
+            None,
+            &schema,
+            &graphql_vars! {},
+            &CONTEXT,
+        )
+        .await,
+        Ok((
+            graphql_value!({
+                "__schema": {"mutationType": null, "subscriptionType": null},
+            }),
+            vec![],
+        )),
+    );
+}
+
+#[test]
+fn errors_on_conflicting_field() {
+    let users = users::schema();
+    let other = conflicting::schema(conflicting::Query);
+
+    assert_eq!(
+        merge_schemas(&[&users, &other]).err(),
+        Some(MergeError::ConflictingField {
+            type_name: "Query".into(),
+            field_name: "user".into(),
+        }),
+    );
+}
+
+#[test]
+fn errors_on_conflicting_type() {
+    let users = users::schema();
+    let other = conflicting::schema(conflicting::QueryWithMoney);
+
+    assert_eq!(
+        merge_schemas(&[&users, &other]).err(),
+        Some(MergeError::ConflictingType("Money".into())),
+    );
+}
+
+#[test]
+fn errors_on_no_schemas() {
+    assert_eq!(
+        merge_schemas::<Context, DefaultScalarValue>(&[]).err(),
+        Some(MergeError::NoSchemas),
+    );
+}
@@ -0,0 +1,186 @@
+//! Tests for resolving values via [`Executor::for_test()`] without a
+//! [`RootNode`].
+//!
+//! [`RootNode`]: juniper::RootNode
+
+use juniper::{
+    graphql_object, graphql_value, graphql_vars, parser::SourcePosition, ExecutionError, Executor,
+    FieldError, FieldResult, GraphQLEnum, GraphQLError, NaiveExecutor, PathSegment, RuleError,
+    Value,
+};
+
+struct Context {
+    prefix: &'static str,
+}
+
+impl juniper::Context for Context {}
+
+#[derive(Clone, Copy, Debug, GraphQLEnum, PartialEq)]
+enum Role {
+    Admin,
+    Guest,
+}
+
+struct User {
+    id: i32,
+}
+
+#[graphql_object(context = Context)]
+impl User {
+    fn id(&self) -> i32 {
+        self.id
+    }
+
+    fn name(&self, context: &Context) -> String {
+        format!("{}{}", context.prefix, self.id)
+    }
+
+    fn friends(&self, first: i32) -> Vec<User> {
+        (1..=first).map(|id| User { id: self.id + id }).collect()
+    }
+
+    fn secret(&self) -> FieldResult<Option<String>> {
+        Err("forbidden".into())
+    }
+
+    async fn role(&self) -> Role {
+        Role::Guest
+    }
+}
+
+#[test]
+fn resolves_scalars_and_enums_without_selection() {
+    let executor: NaiveExecutor<'_, ()> = Executor::for_test(&(), graphql_vars! {});
+
+    assert_eq!(
+        executor.resolve(&(), &42, None),
+        Ok((graphql_value!(42), vec![])),
+    );
+    assert_eq!(
+        executor.resolve(&(), &Role::Admin, None),
+        Ok((graphql_value!("ADMIN"), vec![])),
+    );
+}
+
+#[test]
+fn resolves_object_with_context_and_variables() {
+    let context = Context { prefix: "user-" };
+    let executor: NaiveExecutor<'_, Context> =
+        Executor::for_test(&context, graphql_vars! {"first": 2});
+
+    assert_eq!(
+        executor.resolve(
+            &(),
+            &User { id: 1 },
+            Some("query($first: Int!) { __typename id friends(first: $first) { name } }"),
+        ),
+        Ok((
+            graphql_value!({
+                "__typename": "User",
+                "id": 1,
+                "friends": [{"name": "user-2"}, {"name": "user-3"}],
+            }),
+            vec![],
+        )),
+    );
+}
+
+#[test]
+fn collects_errors() {
+    let context = Context { prefix: "" };
+    let executor: NaiveExecutor<'_, Context> = Executor::for_test(&context, graphql_vars! {});
+
+    assert_eq!(
+        executor.resolve(
+            &(),
+            &User { id: 1 },
+            Some("{ friends(first: 1) { secret } }")
+        ),
+        Ok((
+            graphql_value!({"friends": [{"secret": null}]}),
+            vec![ExecutionError::new_with_path(
+                SourcePosition::new(22, 0, 22),
+                &[
+                    PathSegment::Field("friends".into()),
+                    PathSegment::Index(0),
+                    PathSegment::Field("secret".into()),
+                ],
+                FieldError::from("forbidden"),
+            )],
+        )),
+    );
+}
+
+#[test]
+fn errors_on_invalid_selection() {
+    let context = Context { prefix: "" };
+    let executor: NaiveExecutor<'_, Context> = Executor::for_test(&context, graphql_vars! {});
+
+    assert!(matches!(
+        executor.resolve(&(), &User { id: 1 }, Some("{ id")),
+        Err(GraphQLError::ParseError(_)),
+    ));
+    assert_eq!(
+        executor.resolve(&(), &User { id: 1 }, Some("query A { id } query B { id }")),
+        Err::<(Value, _), _>(GraphQLError::MultipleOperationsProvided),
+    );
+}
+
+#[test]
+fn resolves_fragment_spreads() {
+    let context = Context { prefix: "user-" };
+    let executor: NaiveExecutor<'_, Context> = Executor::for_test(&context, graphql_vars! {});
+
+    assert_eq!(
+        executor.resolve(
+            &(),
+            &User { id: 1 },
+            Some(
+                "{ ...UserFields friends(first: 1) { ...UserFields } } \
+                 fragment UserFields on User { id name }",
+            ),
+        ),
+        Ok((
+            graphql_value!({
+                "id": 1,
+                "name": "user-1",
+                "friends": [{"id": 2, "name": "user-2"}],
+            }),
+            vec![],
+        )),
+    );
+}
+
+#[tokio::test]
+async fn errors_on_unknown_fragment() {
+    let context = Context { prefix: "" };
+    let executor: NaiveExecutor<'_, Context> = Executor::for_test(&context, graphql_vars! {});
+
+    let expected = Err::<(Value, _), _>(GraphQLError::ValidationError(vec![RuleError::new(
+        r#"Unknown fragment: "Missing""#,
+        &[SourcePosition::new(5, 0, 5)],
+    )]));
+    assert_eq!(
+        executor.resolve(&(), &User { id: 1 }, Some("{ ...Missing }")),
+        expected,
+    );
+    assert_eq!(
+        executor
+            .resolve_async(&(), &User { id: 1 }, Some("{ ...Missing }"))
+            .await,
+        expected,
+    );
+}
+
+#[tokio::test]
+async fn resolves_asynchronously() {
+    let context = Context { prefix: "user-" };
+    let executor: NaiveExecutor<'_, Context> = Executor::for_test(&context, graphql_vars! {});
+
+    assert_eq!(
+        executor
+            .resolve_async(&(), &User { id: 1 }, Some("{ name role }"))
+            .await,
+        Ok((graphql_value!({"name": "user-1", "role": "GUEST"}), vec![])),
+    );
+}
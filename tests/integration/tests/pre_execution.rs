@@ -0,0 +1,261 @@
+//! Tests for pre-execution hooks registered via
+//! `RootNode::with_pre_execution()`.
+
+use std::{
+    collections::HashMap,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use futures::{stream, Stream};
+use juniper::{
+    execute, execute_sync, graphql_object, graphql_subscription, graphql_value, graphql_vars,
+    parser::SourcePosition, resolve_into_stream, DefaultScalarValue, EmptyMutation, ExecutionError,
+    FieldError, OperationInfo, PreExecution, RootNode, Value,
+};
+
+pub struct Context {
+    client: &'static str,
+    resolved: AtomicUsize,
+}
+
+impl juniper::Context for Context {}
+
+impl Context {
+    fn new(client: &'static str) -> Self {
+        Self {
+            client,
+            resolved: AtomicUsize::new(0),
+        }
+    }
+
+    fn resolved(&self) -> usize {
+        self.resolved.load(Ordering::SeqCst)
+    }
+}
+
+struct Query;
+
+#[graphql_object(context = Context)]
+impl Query {
+    #[graphql(complexity = 5)]
+    fn report(ctx: &Context) -> &'static str {
+        ctx.resolved.fetch_add(1, Ordering::SeqCst);
+        "expensive"
+    }
+
+    fn ping(ctx: &Context) -> bool {
+        ctx.resolved.fetch_add(1, Ordering::SeqCst);
+        true
+    }
+}
+
+struct Subscription;
+
+type PingStream = Pin<Box<dyn Stream<Item = bool> + Send>>;
+
+#[graphql_subscription(context = Context)]
+impl Subscription {
+    async fn pings(ctx: &Context) -> PingStream {
+        ctx.resolved.fetch_add(1, Ordering::SeqCst);
+        Box::pin(stream::once(async { true }))
+    }
+}
+
+// Rate limiter granting every client a budget of query cost, which is spent by
+// the estimated cost of every executed operation.
+struct RateLimiter {
+    budget: u64,
+    spent: Mutex<HashMap<&'static str, u64>>,
+}
+
+impl RateLimiter {
+    fn new(budget: u64) -> Self {
+        Self {
+            budget,
+            spent: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl PreExecution<Context, DefaultScalarValue> for RateLimiter {
+    fn before_execution(
+        &self,
+        operation: &OperationInfo<'_, DefaultScalarValue>,
+        context: &Context,
+    ) -> Result<(), FieldError> {
+        let mut spent = self.spent.lock().unwrap();
+        let spent = spent.entry(context.client).or_default();
+        let cost = operation.estimated_cost();
+        if *spent + cost > self.budget {
+            let (cost, remaining) = (cost as i32, (self.budget - *spent) as i32);
+            return Err(FieldError::new(
+                "Rate limit exceeded",
+                graphql_value!({"cost": cost, "remaining": remaining}),
+            ));
+        }
+        *spent += cost;
+        Ok(())
+    }
+}
+
+// Hook recording the operations it sees.
+#[derive(Clone, Default)]
+struct Recorder(Arc<Mutex<Vec<String>>>);
+
+impl PreExecution<Context, DefaultScalarValue> for Recorder {
+    fn before_execution(
+        &self,
+        operation: &OperationInfo<'_, DefaultScalarValue>,
+        _: &Context,
+    ) -> Result<(), FieldError> {
+        let name = operation.operation().item.name.map(|n| n.item);
+        self.0.lock().unwrap().push(format!(
+            "{}:{}:{}",
+            name.unwrap_or("<anonymous>"),
+            operation.document().len(),
+            operation.variables().len(),
+        ));
+        Ok(())
+    }
+}
+
+type Schema = RootNode<'static, Query, EmptyMutation<Context>, Subscription>;
+
+fn schema() -> Schema {
+    RootNode::new(Query, EmptyMutation::new(), Subscription)
+}
+
+fn rate_limited(
+    at: SourcePosition,
+    cost: i32,
+    remaining: i32,
+) -> ExecutionError<DefaultScalarValue> {
+    ExecutionError::new(
+        at,
+        &[],
+        FieldError::new(
+            "Rate limit exceeded",
+            graphql_value!({"cost": cost, "remaining": remaining}),
+        ),
+    )
+}
+
+#[test]
+fn executes_within_budget() {
+    let schema = schema().with_pre_execution(RateLimiter::new(7));
+    let ctx = Context::new("alice");
+
+    assert_eq!(
+        execute_sync("{ report ping }", None, &schema, &graphql_vars! {}, &ctx),
+        Ok((
+            graphql_value!({"report": "expensive", "ping": true}),
+            vec![]
+        )),
+    );
+    assert_eq!(ctx.resolved(), 2);
+}
+
+#[test]
+fn aborts_before_resolving() {
+    let schema = schema().with_pre_execution(RateLimiter::new(7));
+    let ctx = Context::new("alice");
+
+    assert!(execute_sync("{ report }", None, &schema, &graphql_vars! {}, &ctx).is_ok());
+    assert_eq!(
+        execute_sync("{ report }", None, &schema, &graphql_vars! {}, &ctx),
+        Ok((
+            Value::null(),
+            vec![rate_limited(SourcePosition::new(0, 0, 0), 5, 2)],
+        )),
+    );
+    assert_eq!(ctx.resolved(), 1);
+
+    // Budget is not spent by the aborted operation.
+    assert!(execute_sync("{ ping }", None, &schema, &graphql_vars! {}, &ctx).is_ok());
+    assert_eq!(ctx.resolved(), 2);
+
+    // Budgets of clients are independent.
+    let other = Context::new("bob");
+    assert!(execute_sync("{ report }", None, &schema, &graphql_vars! {}, &other).is_ok());
+    assert_eq!(other.resolved(), 1);
+}
+
+#[tokio::test]
+async fn aborts_async_execution() {
+    let schema = schema().with_pre_execution(RateLimiter::new(3));
+    let ctx = Context::new("alice");
+
+    assert_eq!(
+        execute(
+            "query Report { report }",
+            None,
+            &schema,
+            &graphql_vars! {},
+            &ctx,
+        )
+        .await,
+        Ok((
+            Value::null(),
+            vec![rate_limited(SourcePosition::new(0, 0, 0), 5, 3)],
+        )),
+    );
+    assert_eq!(ctx.resolved(), 0);
+}
+
+#[tokio::test]
+async fn aborts_subscription() {
+    let schema = schema().with_pre_execution(RateLimiter::new(0));
+    let ctx = Context::new("alice");
+
+    let (res, errors) = resolve_into_stream(
+        "subscription { pings }",
+        None,
+        &schema,
+        &graphql_vars! {},
+        &ctx,
+    )
+    .await
+    .unwrap();
+
+    assert!(res.is_null());
+    assert_eq!(
+        errors,
+        vec![rate_limited(SourcePosition::new(0, 0, 0), 1, 0)]
+    );
+    assert_eq!(ctx.resolved(), 0);
+}
+
+#[test]
+fn calls_hooks_in_registration_order() {
+    let recorder = Recorder::default();
+    let schema = schema()
+        .with_pre_execution(recorder.clone())
+        .with_pre_execution(RateLimiter::new(0))
+        .with_pre_execution(recorder.clone());
+    let ctx = Context::new("alice");
+
+    let (res, errors) = execute_sync(
+        "query Ping($x: Boolean!) { ...Pings @include(if: $x) } fragment Pings on Query { ping }",
+        None,
+        &schema,
+        &graphql_vars! {"x": true},
+        &ctx,
+    )
+    .unwrap();
+
+    assert!(res.is_null());
+    assert_eq!(errors.len(), 1);
+    assert_eq!(*recorder.0.lock().unwrap(), ["Ping:2:1"]);
+}
+
+#[test]
+fn validates_before_calling_hooks() {
+    let schema = schema().with_pre_execution(RateLimiter::new(0));
+    let ctx = Context::new("alice");
+
+    assert!(execute_sync("{ unknown }", None, &schema, &graphql_vars! {}, &ctx).is_err());
+}
@@ -0,0 +1,182 @@
+//! Tests for query cost analysis via [`RootNode::estimate_cost()`] and
+//! [`RootNode::with_max_cost()`].
+
+use juniper::{
+    execute, execute_sync, graphql_interface, graphql_object, graphql_value, graphql_vars,
+    parser::{parse_document_source, SourcePosition},
+    Arguments, CostError, EmptyMutation, EmptySubscription, GraphQLError, GraphQLObject, RootNode,
+    RuleError, ScalarValue, Variables,
+};
+
+#[graphql_interface(for = User)]
+struct Node {
+    #[graphql(complexity = 2)]
+    id: i32,
+}
+
+#[derive(GraphQLObject)]
+#[graphql(impl = NodeValue)]
+struct User {
+    id: i32,
+    #[graphql(complexity = 3)]
+    name: String,
+}
+
+fn user(id: i32) -> User {
+    User {
+        id,
+        name: format!("user-{id}"),
+    }
+}
+
+/// Multiplies the cost of a single item by the number of requested items.
+fn paginated<S: ScalarValue>(args: &Arguments<S>, child_cost: u64) -> u64 {
+    let first = args.get::<i32>("first").ok().flatten().unwrap_or_default();
+    u64::try_from(first)
+        .unwrap_or_default()
+        .saturating_mul(child_cost)
+        .saturating_add(1)
+}
+
+struct QueryRoot;
+
+#[graphql_object]
+impl QueryRoot {
+    fn user() -> User {
+        user(1)
+    }
+
+    #[graphql(complexity = 10)]
+    fn expensive() -> i32 {
+        42
+    }
+
+    #[graphql(complexity_with = paginated)]
+    fn users(#[graphql(default = 10)] first: i32) -> Vec<User> {
+        (1..=first).map(user).collect()
+    }
+
+    fn node() -> NodeValue {
+        user(2).into()
+    }
+}
+
+type Schema = RootNode<'static, QueryRoot, EmptyMutation, EmptySubscription>;
+
+fn schema() -> Schema {
+    RootNode::new(QueryRoot, EmptyMutation::new(), EmptySubscription::new())
+}
+
+fn estimate(schema: &Schema, doc: &str, vars: &Variables) -> Result<u64, CostError> {
+    let document = parse_document_source(doc, &schema.schema).expect("invalid document");
+    schema.estimate_cost(&document, vars)
+}
+
+#[test]
+fn counts_one_per_field_by_default() {
+    const DOC: &str = "{ user { id } node { __typename } }";
+
+    assert_eq!(estimate(&schema(), DOC, &graphql_vars! {}), Ok(4));
+}
+
+#[test]
+fn uses_fixed_complexity() {
+    const DOC: &str = "{ expensive user { id name } node { id } }";
+
+    assert_eq!(estimate(&schema(), DOC, &graphql_vars! {}), Ok(10 + 5 + 3));
+}
+
+#[test]
+fn multiplies_paginated_lists() {
+    const DOC: &str = "query($n: Int!) { users(first: $n) { id name } }";
+
+    assert_eq!(
+        estimate(&schema(), DOC, &graphql_vars! {"n": 100}),
+        Ok(1 + 100 * 4),
+    );
+    assert_eq!(
+        estimate(&schema(), "{ users { id } }", &graphql_vars! {}),
+        Ok(1 + 10),
+    );
+}
+
+#[test]
+fn follows_fragments_and_directives() {
+    const DOC: &str = r#"query($skip: Boolean!) {
+        user { ...UserFields }
+        ... on QueryRoot { expensive @skip(if: $skip) }
+        node { ... on User { name } }
+    }
+
+    fragment UserFields on User { id name }"#;
+
+    assert_eq!(
+        estimate(&schema(), DOC, &graphql_vars! {"skip": true}),
+        Ok(5 + 4),
+    );
+    assert_eq!(
+        estimate(&schema(), DOC, &graphql_vars! {"skip": false}),
+        Ok(5 + 10 + 4),
+    );
+}
+
+#[test]
+fn estimates_reused_fragments_once() {
+    // Every fragment spreads the previous one twice, so walking each spread
+    // separately would take 2^40 steps.
+    let fragments = (0..40)
+        .map(|i| format!("fragment F{} on QueryRoot {{ ...F{i} ...F{i} }}", i + 1))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let doc = format!("{{ ...F40 }}\nfragment F0 on QueryRoot {{ user {{ id }} }}\n{fragments}");
+
+    assert_eq!(estimate(&schema(), &doc, &graphql_vars! {}), Ok(2 << 40));
+}
+
+#[test]
+fn takes_most_expensive_operation() {
+    const DOC: &str = "query A { user { id } } query B { expensive }";
+
+    assert_eq!(estimate(&schema(), DOC, &graphql_vars! {}), Ok(10));
+}
+
+#[test]
+fn errors_on_invalid_fragments() {
+    assert_eq!(
+        estimate(&schema(), "{ ...Unknown }", &graphql_vars! {}),
+        Err(CostError::UnknownFragment("Unknown".into())),
+    );
+    assert_eq!(
+        estimate(
+            &schema(),
+            "{ ...A } fragment A on QueryRoot { user { id } ...A }",
+            &graphql_vars! {},
+        ),
+        Err(CostError::FragmentCycle("A".into())),
+    );
+}
+
+#[tokio::test]
+async fn rejects_operations_exceeding_max_cost() {
+    const DOC: &str = "query($n: Int!) { users(first: $n) { id } }";
+
+    let schema = schema().with_max_cost(50);
+
+    assert_eq!(
+        execute(DOC, None, &schema, &graphql_vars! {"n": 2}, &()).await,
+        Ok((graphql_value!({"users": [{"id": 1}, {"id": 2}]}), vec![])),
+    );
+
+    let expected = Err(GraphQLError::ValidationError(vec![RuleError::new(
+        "Query cost 101 exceeds maximum allowed cost 50",
+        &[SourcePosition::new(0, 0, 0)],
+    )]));
+    assert_eq!(
+        execute(DOC, None, &schema, &graphql_vars! {"n": 100}, &()).await,
+        expected,
+    );
+    assert_eq!(
+        execute_sync(DOC, None, &schema, &graphql_vars! {"n": 100}, &()),
+        expected,
+    );
+}
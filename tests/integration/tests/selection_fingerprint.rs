@@ -0,0 +1,282 @@
+//! Tests for selection fingerprints via [`RootNode::selection_fingerprint()`].
+
+use juniper::{
+    graphql_interface, graphql_object, graphql_vars, parser::parse_document_source, EmptyMutation,
+    EmptySubscription, GraphQLError, GraphQLInputObject, GraphQLObject, RootNode, Variables,
+};
+
+#[graphql_interface(for = [Human, Droid])]
+struct Character {
+    id: i32,
+}
+
+#[derive(GraphQLObject)]
+#[graphql(impl = CharacterValue)]
+struct Human {
+    id: i32,
+    home_planet: String,
+}
+
+#[derive(GraphQLObject)]
+#[graphql(impl = CharacterValue)]
+struct Droid {
+    id: i32,
+    primary_function: String,
+}
+
+#[derive(GraphQLInputObject)]
+struct Filter {
+    name: Option<String>,
+    limit: Option<i32>,
+}
+
+struct QueryRoot;
+
+#[graphql_object]
+impl QueryRoot {
+    fn hero(episode: Option<i32>) -> CharacterValue {
+        let _ = episode;
+        Human {
+            id: 1,
+            home_planet: "Tatooine".into(),
+        }
+        .into()
+    }
+
+    fn search(filter: Option<Filter>) -> Vec<CharacterValue> {
+        let _ = filter;
+        vec![]
+    }
+
+    fn version() -> i32 {
+        1
+    }
+}
+
+type Schema = RootNode<'static, QueryRoot, EmptyMutation, EmptySubscription>;
+
+fn schema() -> Schema {
+    RootNode::new(QueryRoot, EmptyMutation::new(), EmptySubscription::new())
+}
+
+fn fingerprint(schema: &Schema, query: &str, vars: &Variables) -> u64 {
+    try_fingerprint(schema, query, vars, None).unwrap()
+}
+
+fn try_fingerprint(
+    schema: &Schema,
+    query: &str,
+    vars: &Variables,
+    operation_name: Option<&str>,
+) -> Result<u64, GraphQLError> {
+    let doc = parse_document_source(query, &schema.schema).unwrap();
+    schema.selection_fingerprint(&doc, vars, operation_name)
+}
+
+#[test]
+fn ignores_formatting_and_field_order() {
+    let schema = schema();
+
+    assert_eq!(
+        fingerprint(&schema, "{ version hero { id } }", &graphql_vars! {}),
+        fingerprint(
+            &schema,
+            "query Named {\n  # comment\n  hero { id, }\n  version\n}",
+            &graphql_vars! {},
+        ),
+    );
+}
+
+#[test]
+fn substitutes_variables() {
+    let schema = schema();
+    let inline = fingerprint(&schema, "{ hero(episode: 4) { id } }", &graphql_vars! {});
+
+    assert_eq!(
+        fingerprint(
+            &schema,
+            "query($ep: Int) { hero(episode: $ep) { id } }",
+            &graphql_vars! {"ep": 4},
+        ),
+        inline,
+    );
+    assert_eq!(
+        fingerprint(
+            &schema,
+            "query($other: Int) { hero(episode: $other) { id } }",
+            &graphql_vars! {"other": 4},
+        ),
+        inline,
+    );
+    assert_eq!(
+        fingerprint(
+            &schema,
+            "query($ep: Int = 4) { hero(episode: $ep) { id } }",
+            &graphql_vars! {},
+        ),
+        inline,
+    );
+    assert_ne!(
+        fingerprint(
+            &schema,
+            "query($ep: Int) { hero(episode: $ep) { id } }",
+            &graphql_vars! {"ep": 5},
+        ),
+        inline,
+    );
+}
+
+#[test]
+fn distinguishes_argument_values() {
+    let schema = schema();
+
+    assert_ne!(
+        fingerprint(&schema, "{ hero(episode: 4) { id } }", &graphql_vars! {}),
+        fingerprint(&schema, "{ hero(episode: 5) { id } }", &graphql_vars! {}),
+    );
+    assert_ne!(
+        fingerprint(&schema, "{ hero(episode: 4) { id } }", &graphql_vars! {}),
+        fingerprint(&schema, "{ hero { id } }", &graphql_vars! {}),
+    );
+    assert_eq!(
+        fingerprint(
+            &schema,
+            r#"{ search(filter: {name: "R2", limit: 1}) { id } }"#,
+            &graphql_vars! {},
+        ),
+        fingerprint(
+            &schema,
+            r#"{ search(filter: {limit: 1, name: "R2"}) { id } }"#,
+            &graphql_vars! {},
+        ),
+    );
+    assert_ne!(
+        fingerprint(
+            &schema,
+            r#"{ search(filter: {name: "R2", limit: 1}) { id } }"#,
+            &graphql_vars! {},
+        ),
+        fingerprint(
+            &schema,
+            r#"{ search(filter: {name: "R2", limit: 2}) { id } }"#,
+            &graphql_vars! {},
+        ),
+    );
+}
+
+#[test]
+fn distinguishes_aliases() {
+    let schema = schema();
+
+    assert_ne!(
+        fingerprint(&schema, "{ version }", &graphql_vars! {}),
+        fingerprint(&schema, "{ v: version }", &graphql_vars! {}),
+    );
+    assert_eq!(
+        fingerprint(&schema, "{ a: version b: hero { id } }", &graphql_vars! {}),
+        fingerprint(&schema, "{ b: hero { id } a: version }", &graphql_vars! {}),
+    );
+}
+
+#[test]
+fn flattens_fragments() {
+    let schema = schema();
+
+    assert_eq!(
+        fingerprint(&schema, "{ version hero { id } }", &graphql_vars! {}),
+        fingerprint(
+            &schema,
+            "{ ...Root } fragment Root on QueryRoot { version ... { hero { ...Id } } } \
+             fragment Id on Character { id }",
+            &graphql_vars! {},
+        ),
+    );
+    assert_eq!(
+        fingerprint(
+            &schema,
+            "{ hero { ... on Human { homePlanet } } }",
+            &graphql_vars! {},
+        ),
+        fingerprint(
+            &schema,
+            "{ hero { ...H } } fragment H on Human { homePlanet }",
+            &graphql_vars! {},
+        ),
+    );
+    assert_ne!(
+        fingerprint(&schema, "{ hero { id } }", &graphql_vars! {}),
+        fingerprint(
+            &schema,
+            "{ hero { ... on Human { id } } }",
+            &graphql_vars! {}
+        ),
+    );
+}
+
+#[test]
+fn evaluates_skip_and_include() {
+    let schema = schema();
+    let query = "query($v: Boolean!) { hero { id } version @include(if: $v) }";
+
+    assert_eq!(
+        fingerprint(&schema, query, &graphql_vars! {"v": false}),
+        fingerprint(&schema, "{ hero { id } }", &graphql_vars! {}),
+    );
+    assert_eq!(
+        fingerprint(&schema, query, &graphql_vars! {"v": true}),
+        fingerprint(&schema, "{ hero { id } version }", &graphql_vars! {}),
+    );
+}
+
+#[test]
+fn selects_operation() {
+    let schema = schema();
+    let query = "query A { version } query B { hero { id } }";
+
+    assert_eq!(
+        try_fingerprint(&schema, query, &graphql_vars! {}, Some("B")),
+        Ok(fingerprint(&schema, "{ hero { id } }", &graphql_vars! {})),
+    );
+    assert_eq!(
+        try_fingerprint(&schema, query, &graphql_vars! {}, None),
+        Err(GraphQLError::MultipleOperationsProvided),
+    );
+    assert_eq!(
+        try_fingerprint(&schema, query, &graphql_vars! {}, Some("C")),
+        Err(GraphQLError::UnknownOperationName),
+    );
+}
+
+#[test]
+fn fails_on_invalid_fragments() {
+    let schema = schema();
+
+    assert!(matches!(
+        try_fingerprint(&schema, "{ ...Unknown }", &graphql_vars! {}, None),
+        Err(GraphQLError::ValidationError(_)),
+    ));
+    assert!(matches!(
+        try_fingerprint(
+            &schema,
+            "{ ...A } fragment A on QueryRoot { ...A }",
+            &graphql_vars! {},
+            None,
+        ),
+        Err(GraphQLError::ValidationError(_)),
+    ));
+}
+
+#[cfg(all(target_pointer_width = "64", target_endian = "little"))]
+#[test]
+fn is_stable() {
+    let schema = schema();
+
+    assert_eq!(
+        fingerprint(
+            &schema,
+            "{ hero(episode: 4) { id ... on Human { homePlanet } } version }",
+            &graphql_vars! {},
+        ),
+        6370296650105614370,
+    );
+}
@@ -0,0 +1,144 @@
+use juniper::{
+    execute, execute_sync, graphql_object, graphql_value, graphql_vars, EmptyMutation,
+    EmptySubscription, RootNode,
+};
+
+mod as_output_field {
+    use super::*;
+
+    struct Point(i32);
+
+    #[graphql_object]
+    impl Point {
+        fn x(&self) -> i32 {
+            self.0
+        }
+    }
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn coordinates() -> (f64, f64, f64) {
+            (1.5, -2.0, 0.0)
+        }
+
+        fn maybe_coordinates() -> Option<(Option<f64>, Option<f64>, Option<f64>)> {
+            Some((Some(1.5), None, Some(0.0)))
+        }
+
+        fn points() -> (Point, Point, Point, Point) {
+            (Point(1), Point(2), Point(3), Point(4))
+        }
+
+        async fn async_points() -> (Point, Point, Point) {
+            (Point(1), Point(2), Point(3))
+        }
+    }
+
+    #[test]
+    fn resolves_as_list() {
+        let query = r#"
+            query Query {
+                coordinates
+                maybeCoordinates
+                points { x }
+            }
+        "#;
+
+        let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+        let (res, errors) = execute_sync(query, None, &schema, &graphql_vars! {}, &()).unwrap();
+
+        assert_eq!(errors.len(), 0);
+        assert_eq!(
+            res,
+            graphql_value!({
+                "coordinates": [1.5, -2.0, 0.0],
+                "maybeCoordinates": [1.5, null, 0.0],
+                "points": [{"x": 1}, {"x": 2}, {"x": 3}, {"x": 4}],
+            }),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_as_list_asynchronously() {
+        let query = r#"
+            query Query {
+                coordinates
+                asyncPoints { x }
+            }
+        "#;
+
+        let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+        let (res, errors) = execute(query, None, &schema, &graphql_vars! {}, &())
+            .await
+            .unwrap();
+
+        assert_eq!(errors.len(), 0);
+        assert_eq!(
+            res,
+            graphql_value!({
+                "coordinates": [1.5, -2.0, 0.0],
+                "asyncPoints": [{"x": 1}, {"x": 2}, {"x": 3}],
+            }),
+        );
+    }
+
+    #[tokio::test]
+    async fn is_list_of_element_type() {
+        let query = r#"
+            query Query {
+                __type(name: "Query") {
+                    fields {
+                        name
+                        type {
+                            kind
+                            ofType {
+                                kind
+                                ofType {
+                                    kind
+                                    ofType { name }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+        let (res, errors) = execute(query, None, &schema, &graphql_vars! {}, &())
+            .await
+            .unwrap();
+
+        assert_eq!(errors.len(), 0);
+        assert_eq!(
+            res,
+            graphql_value!({"__type": {"fields": [{
+                "name": "coordinates",
+                "type": {"kind": "NON_NULL", "ofType": {
+                    "kind": "LIST",
+                    "ofType": {"kind": "NON_NULL", "ofType": {"name": "Float"}},
+                }},
+            }, {
+                "name": "maybeCoordinates",
+                "type": {"kind": "LIST", "ofType": {
+                    "kind": "SCALAR",
+                    "ofType": null,
+                }},
+            }, {
+                "name": "points",
+                "type": {"kind": "NON_NULL", "ofType": {
+                    "kind": "LIST",
+                    "ofType": {"kind": "NON_NULL", "ofType": {"name": "Point"}},
+                }},
+            }, {
+                "name": "asyncPoints",
+                "type": {"kind": "NON_NULL", "ofType": {
+                    "kind": "LIST",
+                    "ofType": {"kind": "NON_NULL", "ofType": {"name": "Point"}},
+                }},
+            }]}}),
+        );
+    }
+}